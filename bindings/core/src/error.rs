@@ -25,6 +25,13 @@ pub enum Error {
     /// SerdeJson errors.
     #[error("{0}")]
     SerdeJson(#[from] serde_json::error::Error),
+    /// None of the interface versions of a binding is supported.
+    #[error(
+        "unsupported interface versions {0:?}, supported versions are {} to {}",
+        crate::MIN_INTERFACE_VERSION,
+        crate::INTERFACE_VERSION
+    )]
+    UnsupportedInterfaceVersions(Vec<u32>),
     /// Unpack errors.
     #[error("{0}")]
     Unpack(#[from] packable::error::UnpackError<iota_sdk::types::block::Error, UnexpectedEOF>),
@@ -8,6 +8,7 @@ mod method;
 mod method_handler;
 mod panic;
 mod response;
+mod version;
 
 use std::fmt::{Formatter, Result as FmtResult};
 
@@ -16,7 +17,11 @@ use fern_logger::{logger_init, LoggerConfig, LoggerOutputConfigBuilder};
 pub use iota_sdk;
 use iota_sdk::{
     client::secret::{SecretManager, SecretManagerDto},
-    wallet::{ClientOptions, Wallet},
+    wallet::{
+        account::ServiceFee,
+        core::{NetworkValidation, SendDefaults},
+        ClientOptions, Wallet,
+    },
 };
 use serde::Deserialize;
 
@@ -27,8 +32,12 @@ pub use self::method_handler::CallMethod;
 pub use self::{
     error::{Error, Result},
     method::{AccountMethod, ClientMethod, SecretManagerMethod, UtilsMethod, WalletMethod},
-    method_handler::{call_client_method, call_secret_manager_method, call_utils_method, call_wallet_method},
+    method_handler::{
+        call_client_method, call_secret_manager_method, call_utils_method, call_wallet_method, FoundryIdParts,
+        MethodTrace, OutputIdParts,
+    },
     response::Response,
+    version::{deserialize_method, negotiate_interface_version, INTERFACE_VERSION, MIN_INTERFACE_VERSION},
 };
 
 pub fn init_logger(config: String) -> std::result::Result<(), fern_logger::Error> {
@@ -46,6 +55,11 @@ pub struct WalletOptions {
     pub coin_type: Option<u32>,
     #[derivative(Debug(format_with = "OmittedDebug::omitted_fmt"))]
     pub secret_manager: Option<SecretManagerDto>,
+    pub output_maturity: Option<u32>,
+    pub service_fee: Option<ServiceFee>,
+    pub network_validation: Option<NetworkValidation>,
+    pub send_defaults: Option<SendDefaults>,
+    pub account_index: Option<u32>,
 }
 
 impl WalletOptions {
@@ -69,11 +83,40 @@ impl WalletOptions {
         self
     }
 
+    pub fn with_output_maturity(mut self, output_maturity: impl Into<Option<u32>>) -> Self {
+        self.output_maturity = output_maturity.into();
+        self
+    }
+
+    pub fn with_service_fee(mut self, service_fee: impl Into<Option<ServiceFee>>) -> Self {
+        self.service_fee = service_fee.into();
+        self
+    }
+
+    pub fn with_network_validation(mut self, network_validation: impl Into<Option<NetworkValidation>>) -> Self {
+        self.network_validation = network_validation.into();
+        self
+    }
+
+    pub fn with_send_defaults(mut self, send_defaults: impl Into<Option<SendDefaults>>) -> Self {
+        self.send_defaults = send_defaults.into();
+        self
+    }
+
+    pub fn with_account_index(mut self, account_index: impl Into<Option<u32>>) -> Self {
+        self.account_index = account_index.into();
+        self
+    }
+
     pub async fn build(self) -> iota_sdk::wallet::Result<Wallet> {
         log::debug!("wallet options: {self:?}");
         let mut builder = Wallet::builder()
             .with_client_options(self.client_options)
-            .with_coin_type(self.coin_type);
+            .with_coin_type(self.coin_type)
+            .with_output_maturity(self.output_maturity)
+            .with_service_fee(self.service_fee)
+            .with_network_validation(self.network_validation)
+            .with_send_defaults(self.send_defaults);
 
         #[cfg(feature = "storage")]
         if let Some(storage_path) = &self.storage_path {
@@ -84,6 +127,10 @@ impl WalletOptions {
             builder = builder.with_secret_manager(SecretManager::try_from(secret_manager)?);
         }
 
+        if let Some(account_index) = self.account_index {
+            builder = builder.for_account_index(account_index);
+        }
+
         builder.finish().await
     }
 }
@@ -95,6 +142,7 @@ pub(crate) trait OmittedDebug {
 }
 impl OmittedDebug for String {}
 impl OmittedDebug for SecretManagerDto {}
+impl OmittedDebug for Vec<(usize, String)> {}
 impl<T: OmittedDebug> OmittedDebug for Option<T> {
     fn omitted_fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
         match self {
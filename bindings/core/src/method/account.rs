@@ -14,13 +14,14 @@ use iota_sdk::{
     },
     types::block::{
         address::Bech32Address,
-        output::{dto::OutputDto, OutputId, TokenId},
+        output::{dto::OutputDto, AliasId, OutputId, TokenId},
         payload::transaction::TransactionId,
     },
     wallet::{
         account::{
-            ConsolidationParams, CreateAliasParams, CreateNativeTokenParams, FilterOptions, MintNftParams,
-            OutputParams, OutputsToClaim, SyncOptions, TransactionOptionsDto,
+            types::OutputFilter, ConsolidationParams, CreateAliasParams, CreateNativeTokenParams, FilterOptions,
+            MintNftParams, OutputParams, OutputsToClaim, SwapAcceptance, SwapAsset, SwapOffer, SyncOptions,
+            TransactionOptionsDto, VestingSchedule,
         },
         SendNativeTokensParams, SendNftParams, SendParams,
     },
@@ -33,6 +34,9 @@ use serde::{Deserialize, Serialize};
 #[serde(tag = "name", content = "data", rename_all = "camelCase")]
 #[non_exhaustive]
 pub enum AccountMethod {
+    /// Accept a swap offer of another account by building the swap transaction and signing the inputs of the account.
+    /// Expected response: [`SwapAcceptance`](crate::Response::SwapAcceptance)
+    AcceptSwapOffer { offer: SwapOffer },
     /// List addresses.
     /// Expected response: [`Addresses`](crate::Response::Addresses)
     Addresses,
@@ -40,6 +44,18 @@ pub enum AccountMethod {
     /// Expected response:
     /// [`AddressesWithUnspentOutputs`](crate::Response::AddressesWithUnspentOutputs)
     AddressesWithUnspentOutputs,
+    /// Send base coins to any number of recipients, split into as many transactions as needed.
+    /// Expected response: [`BulkSendReport`](crate::Response::BulkSendReport)
+    BulkSend {
+        params: Vec<SendParams>,
+        options: Option<TransactionOptionsDto>,
+    },
+    /// Cancel a swap offer of the account by unlocking its inputs.
+    /// Expected response: [`Ok`](crate::Response::Ok)
+    CancelSwap { offer: SwapOffer },
+    /// Get the dependencies between the aliases, foundries and NFTs of the account and the outputs they own.
+    /// Expected response: [`ChainGraph`](crate::Response::ChainGraph)
+    ChainGraph,
     /// Get outputs with additional unlock conditions
     /// Expected response: [`OutputIds`](crate::Response::OutputIds)
     #[serde(rename_all = "camelCase")]
@@ -48,12 +64,48 @@ pub enum AccountMethod {
     /// Expected response: [`SentTransaction`](crate::Response::SentTransaction)
     #[serde(rename_all = "camelCase")]
     ClaimOutputs { output_ids_to_claim: Vec<OutputId> },
+    /// Complete a swap offer of the account which was accepted by another account and submit the transaction.
+    /// Expected response: [`SentTransaction`](crate::Response::SentTransaction)
+    CompleteSwap {
+        offer: SwapOffer,
+        acceptance: SwapAcceptance,
+    },
+    /// Preview what consolidating outputs would do, without creating a transaction.
+    /// Expected response: [`ConsolidationPreview`](crate::Response::ConsolidationPreview)
+    ConsolidationPreview { params: ConsolidationParams },
+    /// Send base coins to a one-time key and get a link with which anyone can claim them.
+    /// Expected response: [`ClaimLinkTransaction`](crate::Response::ClaimLinkTransaction)
+    CreateClaimLink {
+        #[serde(with = "iota_sdk::utils::serde::string")]
+        amount: u64,
+        /// Expiration in seconds, after which the funds are available for the account again. Default: one day.
+        expiration: Option<u32>,
+        options: Option<TransactionOptionsDto>,
+    },
+    /// Create an offer to swap an asset of the account for an asset of another account and lock its inputs.
+    /// Expected response: [`SwapOffer`](crate::Response::SwapOffer)
+    CreateSwapOffer {
+        offered: SwapAsset,
+        asked: SwapAsset,
+        /// Expiration in seconds, after which the swap isn't completed anymore. Default: one day.
+        expiration: Option<u32>,
+    },
     /// Removes a previously registered participation event from local storage.
     /// Expected response: [`Ok`](crate::Response::Ok)
     #[cfg(feature = "participation")]
     #[cfg_attr(docsrs, doc(cfg(feature = "participation")))]
     #[serde(rename_all = "camelCase")]
     DeregisterParticipationEvent { event_id: ParticipationEventId },
+    /// Send any number of NFTs of the account, split into as many transactions as needed.
+    /// Expected response: [`BulkSendReport`](crate::Response::BulkSendReport)
+    DistributeNfts {
+        params: Vec<SendNftParams>,
+        options: Option<TransactionOptionsDto>,
+    },
+    /// Export a proof of a confirmed transaction of the account, which can be verified without the wallet.
+    /// Expected response: [`TransactionProof`](crate::Response::TransactionProof)
+    #[serde(rename_all = "camelCase")]
+    ExportTransactionProof { transaction_id: TransactionId },
     /// Generate new Ed25519 addresses.
     /// Expected response: [`GeneratedEd25519Addresses`](crate::Response::GeneratedEd25519Addresses)
     GenerateEd25519Addresses {
@@ -76,6 +128,10 @@ pub enum AccountMethod {
     /// Expected response: [`OutputData`](crate::Response::OutputData)
     #[serde(rename_all = "camelCase")]
     GetOutput { output_id: OutputId },
+    /// Explain whether an output stored in the account can currently be unlocked, and if not, why not.
+    /// Expected response: [`Unlockability`](crate::Response::Unlockability)
+    #[serde(rename_all = "camelCase")]
+    GetOutputUnlockability { output_id: OutputId },
     /// Expected response: [`ParticipationEvent`](crate::Response::ParticipationEvent)
     #[cfg(feature = "participation")]
     #[cfg_attr(docsrs, doc(cfg(feature = "participation")))]
@@ -140,6 +196,13 @@ pub enum AccountMethod {
         burn: BurnDto,
         options: Option<TransactionOptionsDto>,
     },
+    /// Consume quarantined spam outputs, burning their native tokens and NFTs.
+    /// Expected response: [`PreparedTransaction`](crate::Response::PreparedTransaction)
+    #[serde(rename_all = "camelCase")]
+    PrepareBurnQuarantinedOutputs {
+        output_ids: Vec<OutputId>,
+        options: Option<TransactionOptionsDto>,
+    },
     /// Claim outputs.
     /// Expected response: [`PreparedTransaction`](crate::Response::PreparedTransaction)
     #[serde(rename_all = "camelCase")]
@@ -153,6 +216,16 @@ pub enum AccountMethod {
         params: Option<CreateAliasParams>,
         options: Option<TransactionOptionsDto>,
     },
+    /// Prepare to send an amount to an address in timelocked outputs, which are released according to a vesting
+    /// schedule.
+    /// Expected response: [`PreparedTransaction`](crate::Response::PreparedTransaction)
+    PrepareCreateVesting {
+        address: Bech32Address,
+        #[serde(with = "iota_sdk::utils::serde::string")]
+        amount: u64,
+        schedule: VestingSchedule,
+        options: Option<TransactionOptionsDto>,
+    },
     /// Prepare to create a native token.
     /// Expected response:
     /// [`PreparedCreateNativeTokenTransaction`](crate::Response::PreparedCreateNativeTokenTransaction)
@@ -208,6 +281,9 @@ pub enum AccountMethod {
         params: Box<OutputParams>,
         transaction_options: Option<TransactionOptionsDto>,
     },
+    /// Reclaim the outputs sent with a storage deposit return whose expiration lapsed.
+    /// Expected response: [`PreparedTransaction`](crate::Response::PreparedTransaction)
+    PrepareReclaimExpiredOutputs,
     /// Prepare to send base coins.
     /// Expected response: [`PreparedTransaction`](crate::Response::PreparedTransaction)
     PrepareSend {
@@ -247,6 +323,30 @@ pub enum AccountMethod {
         event_id: Option<ParticipationEventId>,
         answers: Option<Vec<u8>>,
     },
+    /// Returns the unspent outputs which were quarantined as spam while syncing.
+    /// Expected response: [`OutputsData`](crate::Response::OutputsData)
+    QuarantinedOutputs,
+    /// Returns the outputs of the account matching the filter.
+    /// Expected response: [`OutputsData`](crate::Response::OutputsData)
+    QueryOutputs { filter: OutputFilter },
+    /// Returns the outputs sent with a storage deposit return whose expiration lapsed, so they can be reclaimed.
+    /// Expected response: [`OutputsData`](crate::Response::OutputsData)
+    ReclaimableOutputs,
+    /// Rebuild a transaction that was rejected because some of its inputs got spent by another transaction.
+    /// Expected response: [`PreparedTransaction`](crate::Response::PreparedTransaction)
+    #[serde(rename_all = "camelCase")]
+    RebuildConflictingTransaction { transaction_id: TransactionId },
+    /// Discards the outputs and transactions of the account and syncs them from the node again, to recover from a
+    /// corrupted local state.
+    /// Expected response: [`Balance`](crate::Response::Balance)
+    RebuildLedgerFromHistory {
+        /// Sync options
+        options: Option<SyncOptions>,
+    },
+    /// Releases quarantined outputs which aren't spam, so they're part of the balance again.
+    /// Expected response: [`Ok`](crate::Response::Ok)
+    #[serde(rename_all = "camelCase")]
+    ReleaseQuarantinedOutputs { output_ids: Vec<OutputId> },
     /// Stores participation information locally and returns the event.
     ///
     /// This will NOT store the node url and auth inside the client options.
@@ -256,6 +356,14 @@ pub enum AccountMethod {
     RegisterParticipationEvents {
         options: ParticipationEventRegistrationOptions,
     },
+    /// Claim the funds of a claim link by sending them to the first address of the account.
+    /// Expected response: [`SentTransaction`](crate::Response::SentTransaction)
+    #[serde(rename_all = "camelCase")]
+    RedeemClaimLink { claim_link: String },
+    /// Remove a local annotation from an output of the account.
+    /// Expected response: [`Ok`](crate::Response::Ok)
+    #[serde(rename_all = "camelCase")]
+    RemoveOutputAnnotation { output_id: OutputId, key: String },
     /// Retries (promotes or reattaches) a transaction sent from the account for a provided transaction id until it's
     /// included (referenced by a milestone). Returns the included block id.
     /// Expected response: [`BlockId`](crate::Response::BlockId)
@@ -282,6 +390,12 @@ pub enum AccountMethod {
         params: Vec<SendParams>,
         options: Option<TransactionOptionsDto>,
     },
+    /// Send native tokens and get how the storage deposit of each output was funded.
+    /// Expected response: [`NativeTokensTransaction`](crate::Response::NativeTokensTransaction)
+    SendNativeTokensWithStorageDeposits {
+        params: Vec<SendNativeTokensParams>,
+        options: Option<TransactionOptionsDto>,
+    },
     /// Send outputs in a transaction.
     /// Expected response: [`SentTransaction`](crate::Response::SentTransaction)
     SendOutputs {
@@ -295,6 +409,19 @@ pub enum AccountMethod {
     /// If storage is enabled, will persist during restarts.
     /// Expected response: [`Ok`](crate::Response::Ok)
     SetDefaultSyncOptions { options: SyncOptions },
+    /// Set the alias output used as identity of the account, `None` removes it.
+    /// Expected response: [`Ok`](crate::Response::Ok)
+    #[serde(rename_all = "camelCase")]
+    SetIdentityAlias { alias_id: Option<AliasId> },
+    /// Set a local annotation of an output of the account, replacing a previous value of the key.
+    /// If storage is enabled, will persist during restarts.
+    /// Expected response: [`Ok`](crate::Response::Ok)
+    #[serde(rename_all = "camelCase")]
+    SetOutputAnnotation {
+        output_id: OutputId,
+        key: String,
+        value: String,
+    },
     /// Validate the transaction, sign it, submit it to a node and store it in the account.
     /// Expected response: [`SentTransaction`](crate::Response::SentTransaction)
     #[serde(rename_all = "camelCase")]
@@ -327,4 +454,13 @@ pub enum AccountMethod {
     /// Expected response: [`OutputsData`](crate::Response::OutputsData)
     #[serde(rename_all = "camelCase")]
     UnspentOutputs { filter_options: Option<FilterOptions> },
+    /// Returns the unspent vesting outputs of the account, grouped by the transaction which created them.
+    /// Expected response: [`VestingStatus`](crate::Response::VestingStatus)
+    VestingStatus,
+    /// Display an address of the account on the Ledger Nano device for the user to confirm it.
+    /// Expected response: [`Bool`](crate::Response::Bool)
+    #[cfg(feature = "ledger_nano")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "ledger_nano")))]
+    #[serde(rename_all = "camelCase")]
+    VerifyAddressOnDevice { address_index: u32, internal: bool },
 }
@@ -7,11 +7,11 @@ use iota_sdk::client::mqtt::Topic;
 use iota_sdk::{
     client::{
         api::{
-            ClientBlockBuilderOptions as BuildBlockOptions, GetAddressesOptions as GenerateAddressesOptions,
-            PreparedTransactionDataDto,
+            BlockIssuanceContext, ClientBlockBuilderOptions as BuildBlockOptions,
+            GetAddressesOptions as GenerateAddressesOptions, PreparedTransactionDataDto,
         },
         node_api::indexer::query_parameters::QueryParameter,
-        node_manager::node::NodeAuth,
+        node_manager::{node::NodeAuth, request_options::RequestOptions},
         secret::SecretManagerDto,
     },
     types::block::{
@@ -125,6 +125,9 @@ pub enum ClientMethod {
     GetLocalPow,
     /// Get fallback to local proof of work timeout
     GetFallbackToLocalPow,
+    /// Returns the current block issuance context, to store it alongside a built block.
+    /// Expected response: [`BlockIssuanceContext`](crate::Response::BlockIssuanceContext)
+    GetBlockIssuanceContext,
     /// Returns the unhealthy nodes.
     #[cfg(not(target_family = "wasm"))]
     UnhealthyNodes,
@@ -160,6 +163,14 @@ pub enum ClientMethod {
         /// The payload to send
         payload: PayloadDto,
     },
+    /// Re-validates a stored block against the issuance context it was built with.
+    /// Expected response: [`Ok`](crate::Response::Ok)
+    RevalidateBlock {
+        /// The block to re-validate
+        block: BlockDto,
+        /// The issuance context of the block
+        context: BlockIssuanceContext,
+    },
     //////////////////////////////////////////////////////////////////////
     // Node core API
     //////////////////////////////////////////////////////////////////////
@@ -182,15 +193,20 @@ pub enum ClientMethod {
     /// Get tips
     GetTips,
     /// Post block (JSON)
+    #[serde(rename_all = "camelCase")]
     PostBlock {
         /// Block
         block: BlockDto,
+        /// Options to send the block to a specific node instead of the node pool
+        request_options: Option<RequestOptions>,
     },
     /// Post block (raw)
     #[serde(rename_all = "camelCase")]
     PostBlockRaw {
         /// Block
         block_bytes: Vec<u8>,
+        /// Options to send the block to a specific node instead of the node pool
+        request_options: Option<RequestOptions>,
     },
     /// Get block
     #[serde(rename_all = "camelCase")]
@@ -286,12 +302,16 @@ pub enum ClientMethod {
     OutputIds {
         /// Query parameters for output requests
         query_parameters: Vec<QueryParameter>,
+        /// Options to query a specific node instead of the node pool
+        request_options: Option<RequestOptions>,
     },
     /// Fetch basic output IDs
     #[serde(rename_all = "camelCase")]
     BasicOutputIds {
         /// Query parameters for output requests
         query_parameters: Vec<QueryParameter>,
+        /// Options to query a specific node instead of the node pool
+        request_options: Option<RequestOptions>,
     },
     /// Fetch alias output IDs
     #[serde(rename_all = "camelCase")]
@@ -352,6 +372,48 @@ pub enum ClientMethod {
         /// BlockIDs
         block_ids: Vec<BlockId>,
     },
+    /// Finds the blocks with a tagged data payload with the tag, which were referenced by the milestones from
+    /// `start_milestone_index` to `end_milestone_index`, both inclusive. Uses the explorer API of a permanode.
+    /// Expected response: [`Blocks`](crate::Response::Blocks)
+    #[serde(rename_all = "camelCase")]
+    FindBlocksByTag {
+        /// The tag, hex encoded
+        tag: String,
+        /// The first milestone index, the one after the pruning index of the node if not set
+        start_milestone_index: Option<u32>,
+        /// The last milestone index, the latest milestone if not set
+        end_milestone_index: Option<u32>,
+    },
+    /// Builds and posts a block with a tagged data payload, then retries it until it's referenced by a milestone.
+    /// Expected response: [`Block`](crate::Response::Block)
+    BuildTaggedDataBlock {
+        /// The tag, hex encoded
+        tag: String,
+        /// The data, hex encoded
+        data: String,
+    },
+    /// Resolves an alias or NFT issuer and returns its immutable features.
+    /// Expected response: [`IssuerInfo`](crate::Response::IssuerInfo)
+    ResolveIssuer {
+        /// The address of the alias or NFT
+        issuer: Bech32Address,
+    },
+    /// Returns whether the NFT was issued by the issuer, e.g. the collection NFT.
+    /// Expected response: [`Bool`](crate::Response::Bool)
+    #[serde(rename_all = "camelCase")]
+    VerifyIssuer {
+        /// The NFT to verify
+        nft_id: NftId,
+        /// The address of the expected issuer
+        issuer: Bech32Address,
+    },
+    /// Resolves the ownership history of an NFT, starting with the output which minted it if it wasn't pruned.
+    /// Expected response: [`NftHistory`](crate::Response::NftHistory)
+    #[serde(rename_all = "camelCase")]
+    NftHistory {
+        /// The NFT
+        nft_id: NftId,
+    },
     /// Retries (promotes or reattaches) a block for provided block id. Block should only be
     /// retried only if they are valid and haven't been confirmed for a while.
     #[serde(rename_all = "camelCase")]
@@ -386,6 +448,7 @@ pub enum ClientMethod {
         /// Addresses
         addresses: Vec<Bech32Address>,
         /// Amount
+        #[serde(with = "iota_sdk::utils::serde::string")]
         amount: u64,
     },
     /// Reattaches blocks for provided block id. Blocks can be reattached only if they are valid and haven't been
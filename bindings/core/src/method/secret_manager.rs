@@ -4,12 +4,14 @@
 use crypto::keys::bip44::Bip44;
 use derivative::Derivative;
 use iota_sdk::{
-    client::api::{GetAddressesOptions, PreparedTransactionDataDto},
+    client::{
+        api::{GetAddressesOptions, PreparedTransactionDataDto},
+        MnemonicStrength,
+    },
     utils::serde::bip44::Bip44Def,
 };
 use serde::{Deserialize, Serialize};
 
-#[cfg(feature = "stronghold")]
 use crate::OmittedDebug;
 
 /// Each public secret manager method.
@@ -18,6 +20,29 @@ use crate::OmittedDebug;
 #[serde(tag = "name", content = "data", rename_all = "camelCase")]
 #[non_exhaustive]
 pub enum SecretManagerMethod {
+    /// Delete the mnemonic stored in the Stronghold vault.
+    /// Expected response: [`Ok`](crate::Response::Ok)
+    #[cfg(feature = "stronghold")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "stronghold")))]
+    DeleteStoredMnemonic,
+    /// Delete an entry of the Stronghold key-value store.
+    /// Expected response: [`Ok`](crate::Response::Ok)
+    #[cfg(feature = "stronghold")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "stronghold")))]
+    DeleteStoreEntry {
+        /// The key of the entry
+        key: String,
+    },
+    /// Derive a child mnemonic from the seed, as specified in BIP-85.
+    /// Expected response: [`GeneratedMnemonic`](crate::Response::GeneratedMnemonic)
+    #[serde(rename_all = "camelCase")]
+    DeriveChildMnemonic {
+        /// The index of the child mnemonic
+        index: u32,
+        /// The number of words of the child mnemonic, defaults to 24
+        #[serde(default)]
+        word_count: MnemonicStrength,
+    },
     /// Generate Ed25519 addresses.
     GenerateEd25519Addresses {
         /// Addresses generation options
@@ -30,6 +55,11 @@ pub enum SecretManagerMethod {
     #[cfg(feature = "ledger_nano")]
     #[cfg_attr(docsrs, doc(cfg(feature = "ledger_nano")))]
     GetLedgerNanoStatus,
+    /// List what is stored in the Stronghold snapshot, without any secret.
+    /// Expected response: [`StrongholdInventory`](crate::Response::StrongholdInventory)
+    #[cfg(feature = "stronghold")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "stronghold")))]
+    GetStrongholdInventory,
     /// Create a single Signature Unlock.
     #[serde(rename_all = "camelCase")]
     SignatureUnlock {
@@ -68,6 +98,21 @@ pub enum SecretManagerMethod {
         /// Mnemonic
         #[derivative(Debug(format_with = "OmittedDebug::omitted_fmt"))]
         mnemonic: String,
+        /// Optional BIP-39 passphrase
+        #[derivative(Debug(format_with = "OmittedDebug::omitted_fmt"))]
+        #[serde(default)]
+        passphrase: Option<String>,
+    },
+    /// Checks if a re-entered mnemonic and BIP-39 passphrase belong to the seed of the secret manager.
+    /// Expected response: [`Bool`](crate::Response::Bool)
+    VerifyBackup {
+        /// Mnemonic
+        #[derivative(Debug(format_with = "OmittedDebug::omitted_fmt"))]
+        mnemonic: String,
+        /// Optional BIP-39 passphrase
+        #[derivative(Debug(format_with = "OmittedDebug::omitted_fmt"))]
+        #[serde(default)]
+        passphrase: Option<String>,
     },
 }
 
@@ -3,10 +3,10 @@
 
 use derivative::Derivative;
 use iota_sdk::{
-    client::secret::types::InputSigningDataDto,
+    client::{secret::types::InputSigningDataDto, TransactionProof},
     types::block::{
         address::{Bech32Address, Hrp},
-        output::{dto::OutputDto, AliasId, NftId, OutputId, RentStructure},
+        output::{dto::OutputDto, AliasId, FoundryId, NftId, OutputId, RentStructure, TokenId},
         payload::{
             dto::MilestonePayloadDto,
             transaction::{
@@ -130,6 +130,46 @@ pub enum UtilsMethod {
         serial_number: u32,
         token_scheme_type: u8,
     },
+    /// Returns the transaction ID and the output index of an output ID.
+    /// Expected response: [`OutputIdParts`](crate::Response::OutputIdParts)
+    #[serde(rename_all = "camelCase")]
+    ParseOutputId {
+        /// Output ID
+        output_id: OutputId,
+    },
+    /// Returns the alias ID, the serial number and the token scheme type of a foundry ID.
+    /// Expected response: [`FoundryIdParts`](crate::Response::FoundryIdParts)
+    #[serde(rename_all = "camelCase")]
+    ParseFoundryId {
+        /// Foundry ID
+        foundry_id: FoundryId,
+    },
+    /// Returns the foundry ID of the foundry which controls the supply of a native token.
+    /// Expected response: [`FoundryId`](crate::Response::FoundryId)
+    #[serde(rename_all = "camelCase")]
+    TokenIdToFoundryId {
+        /// Token ID
+        token_id: TokenId,
+    },
+    /// Returns the token ID of the native token of a foundry.
+    /// Expected response: [`TokenId`](crate::Response::TokenId)
+    #[serde(rename_all = "camelCase")]
+    FoundryIdToTokenId {
+        /// Foundry ID
+        foundry_id: FoundryId,
+    },
+    /// Returns the alias ID of a bech32 encoded alias address.
+    /// Expected response: [`AliasId`](crate::Response::AliasId)
+    Bech32ToAliasId {
+        /// Alias address
+        bech32: Bech32Address,
+    },
+    /// Returns the NFT ID of a bech32 encoded NFT address.
+    /// Expected response: [`NftId`](crate::Response::NftId)
+    Bech32ToNftId {
+        /// NFT address
+        bech32: Bech32Address,
+    },
     /// Computes the hash of a transaction essence.
     HashTransactionEssence {
         /// The transaction essence
@@ -139,12 +179,37 @@ pub enum UtilsMethod {
     ComputeInputsCommitment { inputs: Vec<OutputDto> },
     /// Computes the required storage deposit of an output.
     ComputeStorageDeposit { output: OutputDto, rent: RentStructure },
+    /// Computes the minimum amount of an output, which is its required storage deposit, after checking it against the
+    /// protocol parameters. It's the offline counterpart of
+    /// [`MinimumRequiredStorageDeposit`](crate::method::ClientMethod::MinimumRequiredStorageDeposit).
+    /// Expected response:
+    /// [`MinimumRequiredStorageDeposit`](crate::Response::MinimumRequiredStorageDeposit)
+    #[serde(rename_all = "camelCase")]
+    ComputeMinimumOutputAmount {
+        output: OutputDto,
+        protocol_parameters: ProtocolParameters,
+    },
+    /// Computes the Proof of Work score of a block, which has to reach the minimum PoW score of the network.
+    /// Expected response: [`PowScore`](crate::Response::PowScore)
+    ComputePowScore {
+        /// Block
+        block: BlockDto,
+    },
     /// Checks if the given mnemonic is valid.
     /// Expected response: [`Ok`](crate::Response::Ok)
     VerifyMnemonic {
         #[derivative(Debug(format_with = "OmittedDebug::omitted_fmt"))]
         mnemonic: String,
     },
+    /// Checks if the words entered by a user match the words of a mnemonic at the given zero-based positions.
+    /// Expected response: [`Bool`](crate::Response::Bool)
+    #[serde(rename_all = "camelCase")]
+    VerifyMnemonicWords {
+        #[derivative(Debug(format_with = "OmittedDebug::omitted_fmt"))]
+        mnemonic: String,
+        #[derivative(Debug(format_with = "OmittedDebug::omitted_fmt"))]
+        words_with_positions: Vec<(usize, String)>,
+    },
     /// Verify an ed25519 signature against a message.
     VerifyEd25519Signature {
         signature: Ed25519SignatureDto,
@@ -163,12 +228,16 @@ pub enum UtilsMethod {
     /// Returns the hex representation of the serialized output bytes.
     #[serde(rename_all = "camelCase")]
     OutputHexBytes { output: OutputDto },
-    /// Verifies the semantic of a transaction.
+    /// Verifies the semantic of a transaction, with the inputs in any order. If protocol parameters are provided, the
+    /// transaction and the inputs are also checked against them.
     /// Expected response: [`ConflictReason`](crate::Response::ConflictReason)
+    #[serde(rename_all = "camelCase")]
     VerifyTransactionSemantic {
         inputs: Vec<InputSigningDataDto>,
         transaction: TransactionPayloadDto,
         time: u32,
+        #[serde(default)]
+        protocol_parameters: Option<ProtocolParameters>,
     },
     /// Verifies the syntax of a transaction.
     /// Expected response: [`Ok`](crate::Response::Ok)
@@ -177,6 +246,9 @@ pub enum UtilsMethod {
         transaction: TransactionPayloadDto,
         protocol_parameters: ProtocolParameters,
     },
+    /// Verifies a proof of a confirmed transaction and returns the transaction ID.
+    /// Expected response: [`TransactionId`](crate::Response::TransactionId)
+    VerifyTransactionProof { proof: TransactionProof },
     /// Returns the serialized bytes of a block.
     /// Expected response: [`Raw`](crate::Response::Raw)
     BlockBytes {
@@ -189,4 +261,58 @@ pub enum UtilsMethod {
         /// Block
         block: BlockDto,
     },
+    /// Returns the hex encoded serialized bytes of an output. If protocol parameters are provided, the output is
+    /// checked against them, including its storage deposit.
+    /// Expected response: [`HexBytes`](crate::Response::HexBytes)
+    #[serde(rename_all = "camelCase")]
+    PackOutput {
+        output: OutputDto,
+        #[serde(default)]
+        protocol_parameters: Option<ProtocolParameters>,
+    },
+    /// Deserializes hex encoded output bytes, checking them against the protocol parameters and rejecting trailing
+    /// bytes.
+    /// Expected response: [`Output`](crate::Response::Output)
+    #[serde(rename_all = "camelCase")]
+    UnpackOutput {
+        bytes: String,
+        protocol_parameters: ProtocolParameters,
+    },
+    /// Returns the hex encoded serialized bytes of a block. If protocol parameters are provided, the block is checked
+    /// against them.
+    /// Expected response: [`HexBytes`](crate::Response::HexBytes)
+    #[serde(rename_all = "camelCase")]
+    PackBlock {
+        block: BlockDto,
+        #[serde(default)]
+        protocol_parameters: Option<ProtocolParameters>,
+    },
+    /// Deserializes hex encoded block bytes, checking them against the protocol parameters and rejecting trailing
+    /// bytes.
+    /// Expected response: [`Block`](crate::Response::Block)
+    #[serde(rename_all = "camelCase")]
+    UnpackBlock {
+        bytes: String,
+        protocol_parameters: ProtocolParameters,
+    },
+    /// Returns the highest of the given message interface versions that is supported, to be set as `version` of the
+    /// methods sent by a binding.
+    /// Expected response: [`InterfaceVersion`](crate::Response::InterfaceVersion)
+    NegotiateInterfaceVersion {
+        /// Interface versions supported by the binding
+        versions: Vec<u32>,
+    },
+    /// Starts recording all method calls with their execution time and response type, to debug the bindings. Secrets
+    /// are omitted. Only the latest calls are kept, a previous trace is dropped.
+    /// Expected response: [`Ok`](crate::Response::Ok)
+    StartDebugTrace {
+        /// The amount of kept method calls. Default: 100
+        capacity: Option<usize>,
+    },
+    /// Stops recording method calls and drops the recorded ones.
+    /// Expected response: [`Ok`](crate::Response::Ok)
+    StopDebugTrace,
+    /// Returns the method calls recorded since the debug trace was started, oldest first.
+    /// Expected response: [`DebugTrace`](crate::Response::DebugTrace)
+    GetDebugTrace,
 }
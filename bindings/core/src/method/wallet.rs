@@ -6,7 +6,10 @@ use std::path::PathBuf;
 
 use derivative::Derivative;
 #[cfg(feature = "events")]
-use iota_sdk::wallet::events::types::{WalletEvent, WalletEventType};
+use iota_sdk::wallet::events::{
+    types::{WalletEvent, WalletEventType},
+    ListenerId,
+};
 use iota_sdk::{
     client::{node_manager::node::NodeAuth, secret::GenerateAddressOptions},
     types::block::address::Hrp,
@@ -15,6 +18,7 @@ use iota_sdk::{
             types::{AccountAddress, AccountIdentifier},
             SyncOptions,
         },
+        core::SendDefaults,
         ClientOptions,
     },
     Url,
@@ -57,6 +61,20 @@ pub enum WalletMethod {
     #[cfg(feature = "storage")]
     #[cfg_attr(docsrs, doc(cfg(feature = "storage")))]
     GetChrysalisData,
+    /// Returns the wallet-wide defaults for the return address and expiration of outputs with a storage deposit
+    /// return.
+    /// Expected response: [`SendDefaults`](crate::Response::SendDefaults)
+    GetSendDefaults,
+    /// Analyze the stored transactions of all accounts for privacy issues.
+    /// Expected response: [`PrivacyReport`](crate::Response::PrivacyReport)
+    PrivacyReport,
+    /// Returns the accounts, their default sync options, the labels and the client options of the wallet as JSON,
+    /// without secrets.
+    /// Expected response: [`CustomJson`](crate::Response::CustomJson)
+    DumpStateJson,
+    /// Loads a state returned by [`DumpStateJson`](Self::DumpStateJson) into a wallet without accounts.
+    /// Expected response: [`Ok`](crate::Response::Ok)
+    LoadStateJson { state: serde_json::Value },
     /// Consume an account method.
     /// Returns [`Response`](crate::Response)
     #[serde(rename_all = "camelCase")]
@@ -148,6 +166,10 @@ pub enum WalletMethod {
     /// Expected response: [`Ok`](crate::Response::Ok)
     #[serde(rename_all = "camelCase")]
     SetClientOptions { client_options: Box<ClientOptions> },
+    /// Sets the wallet-wide defaults for the return address and expiration of outputs with a storage deposit return.
+    /// Expected response: [`Ok`](crate::Response::Ok)
+    #[serde(rename_all = "camelCase")]
+    SetSendDefaults { send_defaults: SendDefaults },
     /// Generate an address without storing it
     /// Expected response: [`Bech32Address`](crate::Response::Bech32Address)
     #[serde(rename_all = "camelCase")]
@@ -187,6 +209,10 @@ pub enum WalletMethod {
     StoreMnemonic {
         #[derivative(Debug(format_with = "OmittedDebug::omitted_fmt"))]
         mnemonic: String,
+        /// Optional BIP-39 passphrase
+        #[derivative(Debug(format_with = "OmittedDebug::omitted_fmt"))]
+        #[serde(default)]
+        passphrase: Option<String>,
     },
     /// Start background syncing.
     /// Expected response: [`Ok`](crate::Response::Ok)
@@ -200,6 +226,9 @@ pub enum WalletMethod {
     /// Stop background syncing.
     /// Expected response: [`Ok`](crate::Response::Ok)
     StopBackgroundSync,
+    /// Cancel long running operations, stop the background tasks and wait until the operations in flight stopped.
+    /// Expected response: [`Ok`](crate::Response::Ok)
+    Shutdown,
     /// Emits an event for testing if the event system is working
     /// Expected response: [`Ok`](crate::Response::Ok)
     #[cfg(feature = "events")]
@@ -211,6 +240,17 @@ pub enum WalletMethod {
     #[cfg_attr(docsrs, doc(cfg(feature = "events")))]
     #[serde(rename_all = "camelCase")]
     ClearListeners { event_types: Vec<WalletEventType> },
+    /// Remove a single listener by the id returned when it was registered.
+    /// Expected response: [`Bool`](crate::Response::Bool)
+    #[cfg(feature = "events")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "events")))]
+    #[serde(rename_all = "camelCase")]
+    RemoveListener { listener_id: ListenerId },
+    /// Get the number of listeners for each event type that has any.
+    /// Expected response: [`ListenerCounts`](crate::Response::ListenerCounts)
+    #[cfg(feature = "events")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "events")))]
+    ListenerCounts,
     /// Update the authentication for the provided node.
     /// Expected response: [`Ok`](crate::Response::Ok)
     UpdateNodeAuth {
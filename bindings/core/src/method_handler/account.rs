@@ -13,7 +13,8 @@ use iota_sdk::{
         TryFromDto,
     },
     wallet::account::{
-        types::TransactionDto, Account, OutputDataDto, PreparedCreateNativeTokenTransactionDto, TransactionOptions,
+        types::TransactionDto, Account, ClaimLinkTransactionDto, NativeTokensTransactionDto, OutputDataDto,
+        PreparedCreateNativeTokenTransactionDto, TransactionOptions,
     },
 };
 
@@ -21,6 +22,7 @@ use crate::{method::AccountMethod, Response, Result};
 
 pub(crate) async fn call_account_method_internal(account: &Account, method: AccountMethod) -> Result<Response> {
     let response = match method {
+        AccountMethod::AcceptSwapOffer { offer } => Response::SwapAcceptance(account.accept_swap_offer(&offer).await?),
         AccountMethod::Addresses => {
             let addresses = account.addresses().await?;
             Response::Addresses(addresses)
@@ -29,6 +31,17 @@ pub(crate) async fn call_account_method_internal(account: &Account, method: Acco
             let addresses = account.addresses_with_unspent_outputs().await?;
             Response::AddressesWithUnspentOutputs(addresses)
         }
+        AccountMethod::BulkSend { params, options } => {
+            let report = account
+                .bulk_send(params, options.map(TransactionOptions::try_from_dto).transpose()?)
+                .await?;
+            Response::BulkSendReport(report)
+        }
+        AccountMethod::CancelSwap { offer } => {
+            account.cancel_swap(&offer).await?;
+            Response::Ok
+        }
+        AccountMethod::ChainGraph => Response::ChainGraph(account.chain_graph().await),
         AccountMethod::ClaimableOutputs { outputs_to_claim } => {
             let output_ids = account.claimable_outputs(outputs_to_claim).await?;
             Response::OutputIds(output_ids)
@@ -37,11 +50,47 @@ pub(crate) async fn call_account_method_internal(account: &Account, method: Acco
             let transaction = account.claim_outputs(output_ids_to_claim.to_vec()).await?;
             Response::SentTransaction(TransactionDto::from(&transaction))
         }
+        AccountMethod::CompleteSwap { offer, acceptance } => {
+            let transaction = account.complete_swap(&offer, &acceptance).await?;
+            Response::SentTransaction(TransactionDto::from(&transaction))
+        }
+        AccountMethod::ConsolidationPreview { params } => {
+            let preview = account.consolidation_preview(params).await?;
+            Response::ConsolidationPreview(preview)
+        }
+        AccountMethod::CreateClaimLink {
+            amount,
+            expiration,
+            options,
+        } => {
+            let transaction = account
+                .create_claim_link(
+                    amount,
+                    expiration,
+                    options.map(TransactionOptions::try_from_dto).transpose()?,
+                )
+                .await?;
+            Response::ClaimLinkTransaction(ClaimLinkTransactionDto::from(&transaction))
+        }
+        AccountMethod::CreateSwapOffer {
+            offered,
+            asked,
+            expiration,
+        } => Response::SwapOffer(account.create_swap_offer(offered, asked, expiration).await?),
         #[cfg(feature = "participation")]
         AccountMethod::DeregisterParticipationEvent { event_id } => {
             account.deregister_participation_event(&event_id).await?;
             Response::Ok
         }
+        AccountMethod::DistributeNfts { params, options } => {
+            let report = account
+                .distribute_nfts(params, options.map(TransactionOptions::try_from_dto).transpose()?)
+                .await?;
+            Response::BulkSendReport(report)
+        }
+        AccountMethod::ExportTransactionProof { transaction_id } => {
+            Response::TransactionProof(account.export_transaction_proof(&transaction_id).await?)
+        }
         AccountMethod::GenerateEd25519Addresses { amount, options } => {
             let address = account.generate_ed25519_addresses(amount, options).await?;
             Response::GeneratedAccountAddresses(address)
@@ -63,6 +112,9 @@ pub(crate) async fn call_account_method_internal(account: &Account, method: Acco
             let output_data = account.get_output(&output_id).await;
             Response::OutputData(output_data.as_ref().map(OutputDataDto::from).map(Box::new))
         }
+        AccountMethod::GetOutputUnlockability { output_id } => {
+            Response::Unlockability(account.output_unlockability(&output_id).await?)
+        }
         #[cfg(feature = "participation")]
         AccountMethod::GetParticipationEvent { event_id } => {
             let event_and_nodes = account.get_participation_event(event_id).await?;
@@ -115,6 +167,15 @@ pub(crate) async fn call_account_method_internal(account: &Account, method: Acco
                 .await?;
             Response::PreparedTransaction(PreparedTransactionDataDto::from(&data))
         }
+        AccountMethod::PrepareBurnQuarantinedOutputs { output_ids, options } => {
+            let data = account
+                .prepare_burn_quarantined_outputs(
+                    output_ids,
+                    options.map(TransactionOptions::try_from_dto).transpose()?,
+                )
+                .await?;
+            Response::PreparedTransaction(PreparedTransactionDataDto::from(&data))
+        }
         AccountMethod::PrepareClaimOutputs { output_ids_to_claim } => {
             let data = account.prepare_claim_outputs(output_ids_to_claim).await?;
             Response::PreparedTransaction(PreparedTransactionDataDto::from(&data))
@@ -181,6 +242,22 @@ pub(crate) async fn call_account_method_internal(account: &Account, method: Acco
                 .await?;
             Response::PreparedTransaction(PreparedTransactionDataDto::from(&data))
         }
+        AccountMethod::PrepareCreateVesting {
+            address,
+            amount,
+            schedule,
+            options,
+        } => {
+            let data = account
+                .prepare_create_vesting(
+                    address,
+                    amount,
+                    schedule,
+                    options.map(TransactionOptions::try_from_dto).transpose()?,
+                )
+                .await?;
+            Response::PreparedTransaction(PreparedTransactionDataDto::from(&data))
+        }
         AccountMethod::PrepareCreateNativeToken { params, options } => {
             let data = account
                 .prepare_create_native_token(params, options.map(TransactionOptions::try_from_dto).transpose()?)
@@ -199,6 +276,10 @@ pub(crate) async fn call_account_method_internal(account: &Account, method: Acco
                 .await?;
             Response::Output(OutputDto::from(&output))
         }
+        AccountMethod::PrepareReclaimExpiredOutputs => {
+            let data = account.prepare_reclaim_expired_outputs().await?;
+            Response::PreparedTransaction(PreparedTransactionDataDto::from(&data))
+        }
         AccountMethod::PrepareSend { params, options } => {
             let data = account
                 .prepare_send(params, options.map(TransactionOptions::try_from_dto).transpose()?)
@@ -251,6 +332,30 @@ pub(crate) async fn call_account_method_internal(account: &Account, method: Acco
             let events = account.register_participation_events(&options).await?;
             Response::ParticipationEvents(events)
         }
+        AccountMethod::QueryOutputs { filter } => {
+            let outputs = account.query_outputs(filter).await;
+            Response::OutputsData(outputs.iter().map(OutputDataDto::from).collect())
+        }
+        AccountMethod::QuarantinedOutputs => {
+            let outputs = account.quarantined_outputs().await;
+            Response::OutputsData(outputs.iter().map(OutputDataDto::from).collect())
+        }
+        AccountMethod::ReclaimableOutputs => {
+            let outputs = account.reclaimable_outputs().await?;
+            Response::OutputsData(outputs.iter().map(OutputDataDto::from).collect())
+        }
+        AccountMethod::RebuildConflictingTransaction { transaction_id } => {
+            let data = account.rebuild_conflicting_transaction(&transaction_id).await?;
+            Response::PreparedTransaction(PreparedTransactionDataDto::from(&data))
+        }
+        AccountMethod::RedeemClaimLink { claim_link } => {
+            let transaction = account.redeem_claim_link(&claim_link.parse()?).await?;
+            Response::SentTransaction(TransactionDto::from(&transaction))
+        }
+        AccountMethod::RemoveOutputAnnotation { output_id, key } => {
+            account.remove_output_annotation(&output_id, &key).await?;
+            Response::Ok
+        }
         AccountMethod::RetryTransactionUntilIncluded {
             transaction_id,
             interval,
@@ -281,6 +386,15 @@ pub(crate) async fn call_account_method_internal(account: &Account, method: Acco
                 .await?;
             Response::SentTransaction(TransactionDto::from(&transaction))
         }
+        AccountMethod::SendNativeTokensWithStorageDeposits { params, options } => {
+            let transaction = account
+                .send_native_tokens_with_storage_deposits(
+                    params,
+                    options.map(TransactionOptions::try_from_dto).transpose()?,
+                )
+                .await?;
+            Response::NativeTokensTransaction(NativeTokensTransactionDto::from(&transaction))
+        }
         AccountMethod::SendOutputs { outputs, options } => {
             let token_supply = account.client().get_token_supply().await?;
             let transaction = account
@@ -302,6 +416,14 @@ pub(crate) async fn call_account_method_internal(account: &Account, method: Acco
             account.set_default_sync_options(options).await?;
             Response::Ok
         }
+        AccountMethod::SetOutputAnnotation { output_id, key, value } => {
+            account.set_output_annotation(&output_id, key, value).await?;
+            Response::Ok
+        }
+        AccountMethod::SetIdentityAlias { alias_id } => {
+            account.set_identity_alias(alias_id).await?;
+            Response::Ok
+        }
         AccountMethod::SignAndSubmitTransaction {
             prepared_transaction_data,
         } => {
@@ -337,6 +459,13 @@ pub(crate) async fn call_account_method_internal(account: &Account, method: Acco
             Response::SentTransaction(TransactionDto::from(&transaction))
         }
         AccountMethod::Sync { options } => Response::Balance(account.sync(options).await?),
+        AccountMethod::RebuildLedgerFromHistory { options } => {
+            Response::Balance(account.rebuild_ledger_from_history(options).await?)
+        }
+        AccountMethod::ReleaseQuarantinedOutputs { output_ids } => {
+            account.release_quarantined_outputs(&output_ids).await?;
+            Response::Ok
+        }
         AccountMethod::Transactions => {
             let transactions = account.transactions().await;
             Response::Transactions(transactions.iter().map(TransactionDto::from).collect())
@@ -345,6 +474,12 @@ pub(crate) async fn call_account_method_internal(account: &Account, method: Acco
             let outputs = account.unspent_outputs(filter_options).await?;
             Response::OutputsData(outputs.iter().map(OutputDataDto::from).collect())
         }
+        AccountMethod::VestingStatus => Response::VestingStatus(account.vesting_status().await?),
+        #[cfg(feature = "ledger_nano")]
+        AccountMethod::VerifyAddressOnDevice {
+            address_index,
+            internal,
+        } => Response::Bool(account.verify_address_on_device(address_index, internal).await?),
     };
     Ok(response)
 }
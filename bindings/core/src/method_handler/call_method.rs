@@ -13,7 +13,7 @@ use tokio::sync::RwLock;
 use crate::{
     method::{ClientMethod, SecretManagerMethod, WalletMethod},
     method_handler::{
-        client::call_client_method_internal, secret_manager::call_secret_manager_method_internal,
+        client::call_client_method_internal, secret_manager::call_secret_manager_method_internal, trace::PendingTrace,
         utils::call_utils_method_internal, wallet::call_wallet_method_internal,
     },
     panic::{convert_async_panics, convert_panics},
@@ -47,33 +47,45 @@ impl CallMethod for Wallet {
 /// Call a client method.
 pub async fn call_client_method(client: &Client, method: ClientMethod) -> Response {
     log::debug!("Client method: {method:?}");
+    let trace = PendingTrace::start(&method);
     let result = convert_async_panics(|| async { call_client_method_internal(client, method).await }).await;
 
     let response = result.unwrap_or_else(Response::Error);
 
     log::debug!("Client response: {response:?}");
+    if let Some(trace) = trace {
+        trace.finish(&response);
+    }
     response
 }
 
 /// Call a wallet method.
 pub async fn call_wallet_method(wallet: &Wallet, method: WalletMethod) -> Response {
     log::debug!("Wallet method: {method:?}");
+    let trace = PendingTrace::start(&method);
     let result = convert_async_panics(|| async { call_wallet_method_internal(wallet, method).await }).await;
 
     let response = result.unwrap_or_else(Response::Error);
 
     log::debug!("Wallet response: {response:?}");
+    if let Some(trace) = trace {
+        trace.finish(&response);
+    }
     response
 }
 
 /// Call a utils method.
 pub fn call_utils_method(method: UtilsMethod) -> Response {
     log::debug!("Utils method: {method:?}");
+    let trace = PendingTrace::start(&method);
     let result = convert_panics(|| call_utils_method_internal(method));
 
     let response = result.unwrap_or_else(Response::Error);
 
     log::debug!("Utils response: {response:?}");
+    if let Some(trace) = trace {
+        trace.finish(&response);
+    }
     response
 }
 
@@ -83,11 +95,15 @@ pub async fn call_secret_manager_method(
     method: SecretManagerMethod,
 ) -> Response {
     log::debug!("Secret manager method: {method:?}");
+    let trace = PendingTrace::start(&method);
     let result =
         convert_async_panics(|| async { call_secret_manager_method_internal(secret_manager, method).await }).await;
 
     let response = result.unwrap_or_else(Response::Error);
 
     log::debug!("Secret manager response: {response:?}");
+    if let Some(trace) = trace {
+        trace.finish(&response);
+    }
     response
 }
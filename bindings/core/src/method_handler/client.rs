@@ -1,6 +1,8 @@
 // Copyright 2023 IOTA Stiftung
 // SPDX-License-Identifier: Apache-2.0
 
+use std::ops::Bound;
+
 #[cfg(feature = "mqtt")]
 use iota_sdk::client::mqtt::{MqttPayload, Topic};
 use iota_sdk::{
@@ -50,6 +52,15 @@ where
                 MqttPayload::Receipt(receipt) => {
                     serde_json::to_string(receipt).expect("failed to serialize MqttPayload::Receipt")
                 }
+                MqttPayload::MilestoneInfo(info) => {
+                    serde_json::to_string(info).expect("failed to serialize MqttPayload::MilestoneInfo")
+                }
+                MqttPayload::BlockMetadata(metadata) => {
+                    serde_json::to_string(metadata).expect("failed to serialize MqttPayload::BlockMetadata")
+                }
+                MqttPayload::Output(output) => {
+                    serde_json::to_string(output).expect("failed to serialize MqttPayload::Output")
+                }
                 e => panic!("received unknown mqtt type: {e:?}"),
             };
             let response = MqttResponse {
@@ -205,6 +216,7 @@ pub(crate) async fn call_client_method_internal(client: &Client, method: ClientM
         ClientMethod::GetProtocolParameters => Response::ProtocolParameters(client.get_protocol_parameters().await?),
         ClientMethod::GetLocalPow => Response::Bool(client.get_local_pow().await),
         ClientMethod::GetFallbackToLocalPow => Response::Bool(client.get_fallback_to_local_pow().await),
+        ClientMethod::GetBlockIssuanceContext => Response::BlockIssuanceContext(client.block_issuance_context().await?),
         ClientMethod::PrepareTransaction {
             secret_manager,
             options,
@@ -259,6 +271,10 @@ pub(crate) async fn call_client_method_internal(client: &Client, method: ClientM
 
             Response::BlockIdWithBlock(block_id, BlockDto::from(&block))
         }
+        ClientMethod::RevalidateBlock { block, context } => {
+            Client::revalidate_block(&Block::try_from_dto(block)?, &context)?;
+            Response::Ok
+        }
         #[cfg(not(target_family = "wasm"))]
         ClientMethod::UnhealthyNodes => Response::UnhealthyNodes(client.unhealthy_nodes().await.into_iter().collect()),
         ClientMethod::GetHealth { url } => Response::Bool(client.get_health(&url).await?),
@@ -266,20 +282,23 @@ pub(crate) async fn call_client_method_internal(client: &Client, method: ClientM
         ClientMethod::GetInfo => Response::Info(client.get_info().await?),
         ClientMethod::GetPeers => Response::Peers(client.get_peers().await?),
         ClientMethod::GetTips => Response::Tips(client.get_tips().await?),
-        ClientMethod::PostBlockRaw { block_bytes } => Response::BlockId(
+        ClientMethod::PostBlockRaw {
+            block_bytes,
+            request_options,
+        } => Response::BlockId(
             client
-                .post_block_raw(&Block::unpack_strict(
-                    &block_bytes[..],
-                    &client.get_protocol_parameters().await?,
-                )?)
+                .post_block_raw_with_options(
+                    &Block::unpack_strict(&block_bytes[..], &client.get_protocol_parameters().await?)?,
+                    &request_options.unwrap_or_default(),
+                )
                 .await?,
         ),
-        ClientMethod::PostBlock { block } => Response::BlockId(
+        ClientMethod::PostBlock { block, request_options } => Response::BlockId(
             client
-                .post_block(&Block::try_from_dto_with_params(
-                    block,
-                    client.get_protocol_parameters().await?,
-                )?)
+                .post_block_with_options(
+                    &Block::try_from_dto_with_params(block, client.get_protocol_parameters().await?)?,
+                    &request_options.unwrap_or_default(),
+                )
                 .await?,
         ),
         ClientMethod::GetBlock { block_id } => Response::Block(BlockDto::from(&client.get_block(&block_id).await?)),
@@ -325,12 +344,22 @@ pub(crate) async fn call_client_method_internal(client: &Client, method: ClientM
         ClientMethod::GetIncludedBlockMetadata { transaction_id } => {
             Response::BlockMetadata(client.get_included_block_metadata(&transaction_id).await?)
         }
-        ClientMethod::OutputIds { query_parameters } => {
-            Response::OutputIdsResponse(client.output_ids(query_parameters).await?)
-        }
-        ClientMethod::BasicOutputIds { query_parameters } => {
-            Response::OutputIdsResponse(client.basic_output_ids(query_parameters).await?)
-        }
+        ClientMethod::OutputIds {
+            query_parameters,
+            request_options,
+        } => Response::OutputIdsResponse(
+            client
+                .output_ids_with_options(query_parameters, &request_options.unwrap_or_default())
+                .await?,
+        ),
+        ClientMethod::BasicOutputIds {
+            query_parameters,
+            request_options,
+        } => Response::OutputIdsResponse(
+            client
+                .basic_output_ids_with_options(query_parameters, &request_options.unwrap_or_default())
+                .await?,
+        ),
         ClientMethod::AliasOutputIds { query_parameters } => {
             Response::OutputIdsResponse(client.alias_output_ids(query_parameters).await?)
         }
@@ -369,6 +398,38 @@ pub(crate) async fn call_client_method_internal(client: &Client, method: ClientM
                 .map(BlockDto::from)
                 .collect(),
         ),
+        ClientMethod::FindBlocksByTag {
+            tag,
+            start_milestone_index,
+            end_milestone_index,
+        } => {
+            let range = (
+                start_milestone_index.map_or(Bound::Unbounded, Bound::Included),
+                end_milestone_index.map_or(Bound::Unbounded, Bound::Included),
+            );
+            Response::Blocks(
+                client
+                    .find_blocks_by_tag(&prefix_hex::decode::<Vec<u8>>(tag)?, range)
+                    .await?
+                    .iter()
+                    .map(BlockDto::from)
+                    .collect(),
+            )
+        }
+        ClientMethod::BuildTaggedDataBlock { tag, data } => {
+            let block = client
+                .build_tagged_data_block(
+                    prefix_hex::decode::<Vec<u8>>(tag)?,
+                    prefix_hex::decode::<Vec<u8>>(data)?,
+                )
+                .await?;
+            Response::Block(BlockDto::from(&block))
+        }
+        ClientMethod::ResolveIssuer { issuer } => Response::IssuerInfo(client.resolve_issuer(*issuer.inner()).await?),
+        ClientMethod::VerifyIssuer { nft_id, issuer } => {
+            Response::Bool(client.verify_issuer(nft_id, *issuer.inner()).await?)
+        }
+        ClientMethod::NftHistory { nft_id } => Response::NftHistory(client.nft_history(nft_id).await?),
         ClientMethod::Retry { block_id } => {
             let (block_id, block) = client.retry(&block_id).await?;
             Response::BlockIdWithBlock(block_id, BlockDto::from(&block))
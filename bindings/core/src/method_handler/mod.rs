@@ -5,6 +5,7 @@ mod account;
 mod call_method;
 mod client;
 mod secret_manager;
+mod trace;
 mod utils;
 mod wallet;
 
@@ -13,3 +14,5 @@ pub use call_method::{
 };
 #[cfg(feature = "mqtt")]
 pub use client::listen_mqtt;
+pub use trace::MethodTrace;
+pub use utils::{FoundryIdParts, OutputIdParts};
@@ -22,6 +22,30 @@ pub(crate) async fn call_secret_manager_method_internal(
 ) -> Result<Response> {
     let secret_manager = secret_manager.read().await;
     let response = match method {
+        #[cfg(feature = "stronghold")]
+        SecretManagerMethod::DeleteStoredMnemonic => {
+            if let SecretManager::Stronghold(secret_manager) = &*secret_manager {
+                secret_manager.delete_mnemonic().await?;
+                Response::Ok
+            } else {
+                return Err(iota_sdk::client::Error::SecretManagerMismatch.into());
+            }
+        }
+        #[cfg(feature = "stronghold")]
+        SecretManagerMethod::DeleteStoreEntry { key } => {
+            if let SecretManager::Stronghold(secret_manager) = &*secret_manager {
+                secret_manager.delete_store_entry(&key).await?;
+                Response::Ok
+            } else {
+                return Err(iota_sdk::client::Error::SecretManagerMismatch.into());
+            }
+        }
+        SecretManagerMethod::DeriveChildMnemonic { index, word_count } => Response::GeneratedMnemonic(
+            secret_manager
+                .derive_child_mnemonic_with_strength(index, word_count)
+                .await?
+                .to_string(),
+        ),
         SecretManagerMethod::GenerateEd25519Addresses { options } => {
             let addresses = secret_manager.generate_ed25519_addresses(options).await?;
             Response::GeneratedEd25519Addresses(addresses)
@@ -38,6 +62,14 @@ pub(crate) async fn call_secret_manager_method_internal(
                 return Err(iota_sdk::client::Error::SecretManagerMismatch.into());
             }
         }
+        #[cfg(feature = "stronghold")]
+        SecretManagerMethod::GetStrongholdInventory => {
+            if let SecretManager::Stronghold(secret_manager) = &*secret_manager {
+                Response::StrongholdInventory(secret_manager.inventory().await?)
+            } else {
+                return Err(iota_sdk::client::Error::SecretManagerMismatch.into());
+            }
+        }
         SecretManagerMethod::SignTransaction {
             prepared_transaction_data,
         } => {
@@ -71,15 +103,22 @@ pub(crate) async fn call_secret_manager_method_internal(
             }
         }
         #[cfg(feature = "stronghold")]
-        SecretManagerMethod::StoreMnemonic { mnemonic } => {
+        SecretManagerMethod::StoreMnemonic { mnemonic, passphrase } => {
             let mnemonic = crypto::keys::bip39::Mnemonic::from(mnemonic);
             if let SecretManager::Stronghold(secret_manager) = &*secret_manager {
-                secret_manager.store_mnemonic(mnemonic).await?;
+                secret_manager
+                    .store_mnemonic_with_passphrase(mnemonic, passphrase.unwrap_or_default())
+                    .await?;
                 Response::Ok
             } else {
                 return Err(iota_sdk::client::Error::SecretManagerMismatch.into());
             }
         }
+        SecretManagerMethod::VerifyBackup { mnemonic, passphrase } => Response::Bool(
+            secret_manager
+                .verify_backup(mnemonic, passphrase.unwrap_or_default())
+                .await?,
+        ),
     };
     Ok(response)
 }
@@ -0,0 +1,122 @@
+// Copyright 2024 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use std::{
+    collections::VecDeque,
+    fmt::Debug,
+    sync::{Mutex, MutexGuard, PoisonError},
+};
+
+use iota_sdk::utils::unix_timestamp_now;
+use serde::Serialize;
+
+use crate::response::Response;
+
+/// The amount of method calls kept by the debug trace if no capacity is provided.
+pub(crate) const DEFAULT_DEBUG_TRACE_CAPACITY: usize = 100;
+
+// The recorded method calls, `None` while tracing is disabled
+static DEBUG_TRACE: Mutex<Option<DebugTrace>> = Mutex::new(None);
+
+struct DebugTrace {
+    capacity: usize,
+    calls: VecDeque<MethodTrace>,
+}
+
+/// A method call recorded by the debug trace.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct MethodTrace {
+    /// The UNIX timestamp in milliseconds at which the method was called.
+    pub timestamp: u64,
+    /// The method with its parameters, secrets are omitted.
+    pub method: String,
+    /// The execution time in milliseconds.
+    pub duration: u64,
+    /// The type of the response.
+    pub response: String,
+    /// The error message, if the method failed or panicked.
+    pub error: Option<String>,
+}
+
+fn debug_trace_lock() -> MutexGuard<'static, Option<DebugTrace>> {
+    DEBUG_TRACE.lock().unwrap_or_else(PoisonError::into_inner)
+}
+
+/// Starts recording method calls, keeping the latest `capacity` ones. Calls recorded before are dropped.
+pub(crate) fn start_debug_trace(capacity: usize) {
+    debug_trace_lock().replace(DebugTrace {
+        capacity,
+        calls: VecDeque::with_capacity(capacity),
+    });
+}
+
+/// Stops recording method calls and drops the recorded ones.
+pub(crate) fn stop_debug_trace() {
+    debug_trace_lock().take();
+}
+
+/// Returns the recorded method calls, oldest first.
+pub(crate) fn debug_trace() -> Vec<MethodTrace> {
+    debug_trace_lock()
+        .as_ref()
+        .map(|trace| trace.calls.iter().cloned().collect())
+        .unwrap_or_default()
+}
+
+/// A method call that is being executed while tracing is enabled.
+pub(crate) struct PendingTrace {
+    timestamp: u64,
+    method: String,
+}
+
+impl PendingTrace {
+    /// Starts tracing a method call, returns `None` if tracing is disabled.
+    pub(crate) fn start(method: &impl Debug) -> Option<Self> {
+        // Don't format the method if it isn't recorded anyway
+        debug_trace_lock().as_ref()?;
+        Some(Self {
+            timestamp: unix_timestamp_now().as_millis() as u64,
+            method: format!("{method:?}"),
+        })
+    }
+
+    /// Records the method call with its response, if tracing wasn't disabled in the meantime.
+    pub(crate) fn finish(self, response: &Response) {
+        let duration = (unix_timestamp_now().as_millis() as u64).saturating_sub(self.timestamp);
+        let error = match response {
+            Response::Error(error) => Some(error.to_string()),
+            // Skip the backtrace
+            Response::Panic(message) => message.lines().next().map(ToOwned::to_owned),
+            _ => None,
+        };
+        let call = MethodTrace {
+            timestamp: self.timestamp,
+            method: self.method,
+            duration,
+            response: response_type(response),
+            error,
+        };
+
+        if let Some(trace) = debug_trace_lock().as_mut() {
+            if trace.capacity == 0 {
+                return;
+            }
+            if trace.calls.len() == trace.capacity {
+                trace.calls.pop_front();
+            }
+            trace.calls.push_back(call);
+        }
+    }
+}
+
+// Returns the type of the response as it's serialized, e.g. `generatedMnemonic`, without its content
+fn response_type(response: &Response) -> String {
+    let debug = format!("{response:?}");
+    let variant = debug.split(|c: char| !c.is_alphanumeric()).next().unwrap_or_default();
+    let mut chars = variant.chars();
+    chars
+        .next()
+        .map(|first| first.to_lowercase().chain(chars).collect())
+        .unwrap_or_default()
+}
@@ -5,23 +5,48 @@ use crypto::keys::bip39::Mnemonic;
 use iota_sdk::{
     client::{
         api::verify_semantic, hex_public_key_to_bech32_address, hex_to_bech32, secret::types::InputSigningData,
-        verify_mnemonic, Client,
+        verify_mnemonic, verify_mnemonic_words, verify_transaction_proof, Client,
     },
     types::{
         block::{
             address::{dto::AddressDto, Address, AliasAddress, ToBech32Ext},
             input::UtxoInput,
-            output::{AliasId, FoundryId, InputsCommitment, NftId, Output, OutputId, Rent, TokenId},
-            payload::{transaction::TransactionEssence, MilestonePayload, TransactionPayload},
+            output::{
+                dto::OutputDto, AliasId, FoundryId, InputsCommitment, NftId, Output, OutputId, Rent, SimpleTokenScheme,
+                TokenId,
+            },
+            payload::{
+                transaction::{TransactionEssence, TransactionId},
+                MilestonePayload, TransactionPayload,
+            },
             signature::Ed25519Signature,
-            Block, Error,
+            Block, BlockDto, Error,
         },
-        TryFromDto,
+        TryFromDto, ValidationParams,
     },
 };
 use packable::PackableExt;
+use serde::Serialize;
 
-use crate::{method::UtilsMethod, response::Response, Result};
+use super::trace::{debug_trace, start_debug_trace, stop_debug_trace, DEFAULT_DEBUG_TRACE_CAPACITY};
+use crate::{method::UtilsMethod, response::Response, version::negotiate_interface_version, Result};
+
+/// The parts of an [`OutputId`].
+#[derive(Clone, Debug, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct OutputIdParts {
+    pub transaction_id: TransactionId,
+    pub output_index: u16,
+}
+
+/// The parts of a [`FoundryId`].
+#[derive(Clone, Debug, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct FoundryIdParts {
+    pub alias_id: AliasId,
+    pub serial_number: u32,
+    pub token_scheme_type: u8,
+}
 
 /// Call a utils method.
 pub(crate) fn call_utils_method_internal(method: UtilsMethod) -> Result<Response> {
@@ -74,6 +99,35 @@ pub(crate) fn call_utils_method_internal(method: UtilsMethod) -> Result<Response
             let foundry_id = FoundryId::build(&AliasAddress::new(alias_id), serial_number, token_scheme_type);
             Response::TokenId(TokenId::from(foundry_id))
         }
+        UtilsMethod::ParseOutputId { output_id } => Response::OutputIdParts(OutputIdParts {
+            transaction_id: *output_id.transaction_id(),
+            output_index: output_id.index(),
+        }),
+        UtilsMethod::ParseFoundryId { foundry_id } => {
+            verify_foundry_id(&foundry_id)?;
+            Response::FoundryIdParts(FoundryIdParts {
+                alias_id: *foundry_id.alias_address().alias_id(),
+                serial_number: foundry_id.serial_number(),
+                token_scheme_type: foundry_id.token_scheme_kind(),
+            })
+        }
+        UtilsMethod::TokenIdToFoundryId { token_id } => {
+            let foundry_id = FoundryId::from(token_id);
+            verify_foundry_id(&foundry_id)?;
+            Response::FoundryId(foundry_id)
+        }
+        UtilsMethod::FoundryIdToTokenId { foundry_id } => {
+            verify_foundry_id(&foundry_id)?;
+            Response::TokenId(TokenId::from(foundry_id))
+        }
+        UtilsMethod::Bech32ToAliasId { bech32 } => match bech32.inner() {
+            Address::Alias(address) => Response::AliasId(*address.alias_id()),
+            address => return Err(Error::InvalidAddressKind(address.kind()).into()),
+        },
+        UtilsMethod::Bech32ToNftId { bech32 } => match bech32.inner() {
+            Address::Nft(address) => Response::NftId(*address.nft_id()),
+            address => return Err(Error::InvalidAddressKind(address.kind()).into()),
+        },
         UtilsMethod::HashTransactionEssence { essence } => {
             Response::Hash(prefix_hex::encode(TransactionEssence::try_from_dto(essence)?.hash()))
         }
@@ -88,11 +142,29 @@ pub(crate) fn call_utils_method_internal(method: UtilsMethod) -> Result<Response
             let out = Output::try_from_dto(output)?;
             Response::MinimumRequiredStorageDeposit(out.rent_cost(&rent).to_string())
         }
+        UtilsMethod::ComputeMinimumOutputAmount {
+            output,
+            protocol_parameters,
+        } => {
+            let output = Output::try_from_dto_with_params(output, &protocol_parameters)?;
+            Response::MinimumRequiredStorageDeposit(output.rent_cost(protocol_parameters.rent_structure()).to_string())
+        }
+        UtilsMethod::ComputePowScore { block } => {
+            let block = Block::try_from_dto(block)?;
+            Response::PowScore(block.pow_score())
+        }
         UtilsMethod::VerifyMnemonic { mnemonic } => {
             let mnemonic = Mnemonic::from(mnemonic);
             verify_mnemonic(mnemonic)?;
             Response::Ok
         }
+        UtilsMethod::VerifyMnemonicWords {
+            mnemonic,
+            words_with_positions,
+        } => {
+            let mnemonic = Mnemonic::from(mnemonic);
+            Response::Bool(verify_mnemonic_words(mnemonic, &words_with_positions)?)
+        }
         UtilsMethod::VerifyEd25519Signature { signature, message } => {
             let signature = Ed25519Signature::try_from(signature)?;
             let message: Vec<u8> = prefix_hex::decode(message)?;
@@ -120,16 +192,18 @@ pub(crate) fn call_utils_method_internal(method: UtilsMethod) -> Result<Response
             inputs,
             transaction,
             time,
+            protocol_parameters,
         } => {
-            let conflict = verify_semantic(
-                &inputs
-                    .into_iter()
-                    .map(InputSigningData::try_from_dto)
-                    .collect::<iota_sdk::client::Result<Vec<InputSigningData>>>()?,
-                &TransactionPayload::try_from_dto(transaction)?,
-                time,
-            )?;
-            Response::ConflictReason(conflict)
+            let params = protocol_parameters
+                .as_ref()
+                .map(ValidationParams::from)
+                .unwrap_or_default();
+            let mut inputs_data = Vec::with_capacity(inputs.len());
+            for input in inputs {
+                inputs_data.push(InputSigningData::try_from_dto_with_params(input, &params)?);
+            }
+            let transaction = TransactionPayload::try_from_dto_with_params(transaction, &params)?;
+            Response::ConflictReason(verify_semantic(&inputs_data, &transaction, time)?)
         }
         UtilsMethod::VerifyTransactionSyntax {
             transaction,
@@ -138,6 +212,7 @@ pub(crate) fn call_utils_method_internal(method: UtilsMethod) -> Result<Response
             TransactionPayload::try_from_dto_with_params(transaction, protocol_parameters)?;
             Response::Ok
         }
+        UtilsMethod::VerifyTransactionProof { proof } => Response::TransactionId(verify_transaction_proof(&proof)?),
         UtilsMethod::BlockBytes { block } => {
             let block = Block::try_from_dto(block)?;
             Response::Raw(block.pack_to_vec())
@@ -146,6 +221,82 @@ pub(crate) fn call_utils_method_internal(method: UtilsMethod) -> Result<Response
             let block = Block::try_from_dto(block)?;
             Response::Hash(prefix_hex::encode(block.hash_without_nonce()))
         }
+        UtilsMethod::PackOutput {
+            output,
+            protocol_parameters,
+        } => {
+            let output = match protocol_parameters {
+                Some(protocol_parameters) => {
+                    let output = Output::try_from_dto_with_params(output, &protocol_parameters)?;
+                    output.verify_storage_deposit(
+                        *protocol_parameters.rent_structure(),
+                        protocol_parameters.token_supply(),
+                    )?;
+                    output
+                }
+                None => Output::try_from_dto(output)?,
+            };
+            Response::HexBytes(prefix_hex::encode(output.pack_to_vec()))
+        }
+        UtilsMethod::UnpackOutput {
+            bytes,
+            protocol_parameters,
+        } => {
+            let output = Output::unpack_strict(prefix_hex::decode::<Vec<u8>>(bytes)?, &protocol_parameters)?;
+            output.verify_storage_deposit(
+                *protocol_parameters.rent_structure(),
+                protocol_parameters.token_supply(),
+            )?;
+            Response::Output(OutputDto::from(&output))
+        }
+        UtilsMethod::PackBlock {
+            block,
+            protocol_parameters,
+        } => {
+            let bytes = match protocol_parameters {
+                Some(protocol_parameters) => {
+                    let bytes = Block::try_from_dto_with_params(block, &protocol_parameters)?.pack_to_vec();
+                    // Unpacking checks what the DTO conversion doesn't, like the protocol version
+                    Block::unpack_strict(&bytes, &protocol_parameters)?;
+                    bytes
+                }
+                None => Block::try_from_dto(block)?.pack_to_vec(),
+            };
+            Response::HexBytes(prefix_hex::encode(bytes))
+        }
+        UtilsMethod::UnpackBlock {
+            bytes,
+            protocol_parameters,
+        } => {
+            let block = Block::unpack_strict(prefix_hex::decode::<Vec<u8>>(bytes)?, &protocol_parameters)?;
+            Response::Block(BlockDto::from(&block))
+        }
+        UtilsMethod::NegotiateInterfaceVersion { versions } => Response::InterfaceVersion(
+            negotiate_interface_version(&versions).ok_or(crate::Error::UnsupportedInterfaceVersions(versions))?,
+        ),
+        UtilsMethod::StartDebugTrace { capacity } => {
+            start_debug_trace(capacity.unwrap_or(DEFAULT_DEBUG_TRACE_CAPACITY));
+            Response::Ok
+        }
+        UtilsMethod::StopDebugTrace => {
+            stop_debug_trace();
+            Response::Ok
+        }
+        UtilsMethod::GetDebugTrace => Response::DebugTrace(debug_trace()),
     };
     Ok(response)
 }
+
+// Checks that a foundry ID is made of an alias address and a known token scheme
+fn verify_foundry_id(foundry_id: &FoundryId) -> std::result::Result<(), Error> {
+    let address_kind = foundry_id[0];
+    if address_kind != AliasAddress::KIND {
+        return Err(Error::InvalidAddressKind(address_kind));
+    }
+    let token_scheme_kind = foundry_id.token_scheme_kind();
+    if token_scheme_kind != SimpleTokenScheme::KIND {
+        return Err(Error::InvalidTokenSchemeKind(token_scheme_kind));
+    }
+
+    Ok(())
+}
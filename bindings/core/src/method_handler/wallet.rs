@@ -64,6 +64,13 @@ pub(crate) async fn call_wallet_method_internal(wallet: &Wallet, method: WalletM
             Response::Accounts(account_dtos)
         }
         WalletMethod::GetChrysalisData => Response::ChrysalisData(wallet.get_chrysalis_data().await?),
+        WalletMethod::GetSendDefaults => Response::SendDefaults(wallet.send_defaults().await),
+        WalletMethod::PrivacyReport => Response::PrivacyReport(wallet.privacy_report().await?),
+        WalletMethod::DumpStateJson => Response::CustomJson(wallet.dump_state_json().await?),
+        WalletMethod::LoadStateJson { state } => {
+            wallet.load_state_json(state).await?;
+            Response::Ok
+        }
         WalletMethod::CallAccountMethod { account_id, method } => {
             let account = wallet.get_account(account_id).await?;
             call_account_method_internal(&account, method).await?
@@ -134,6 +141,10 @@ pub(crate) async fn call_wallet_method_internal(wallet: &Wallet, method: WalletM
             wallet.set_client_options(*client_options).await?;
             Response::Ok
         }
+        WalletMethod::SetSendDefaults { send_defaults } => {
+            wallet.set_send_defaults(send_defaults).await?;
+            Response::Ok
+        }
         #[cfg(feature = "ledger_nano")]
         WalletMethod::GetLedgerNanoStatus => {
             let ledger_nano_status = wallet.get_ledger_nano_status().await?;
@@ -170,8 +181,10 @@ pub(crate) async fn call_wallet_method_internal(wallet: &Wallet, method: WalletM
             Response::Ok
         }
         #[cfg(feature = "stronghold")]
-        WalletMethod::StoreMnemonic { mnemonic } => {
-            wallet.store_mnemonic(mnemonic.into()).await?;
+        WalletMethod::StoreMnemonic { mnemonic, passphrase } => {
+            wallet
+                .store_mnemonic_with_passphrase(mnemonic.into(), passphrase.unwrap_or_default())
+                .await?;
             Response::Ok
         }
         WalletMethod::StartBackgroundSync {
@@ -186,6 +199,10 @@ pub(crate) async fn call_wallet_method_internal(wallet: &Wallet, method: WalletM
             wallet.stop_background_syncing().await?;
             Response::Ok
         }
+        WalletMethod::Shutdown => {
+            wallet.shutdown().await?;
+            Response::Ok
+        }
         #[cfg(feature = "events")]
         WalletMethod::EmitTestEvent { event } => {
             wallet.emit_test_event(event.clone()).await;
@@ -196,6 +213,10 @@ pub(crate) async fn call_wallet_method_internal(wallet: &Wallet, method: WalletM
             wallet.clear_listeners(event_types).await;
             Response::Ok
         }
+        #[cfg(feature = "events")]
+        WalletMethod::RemoveListener { listener_id } => Response::Bool(wallet.remove_listener(listener_id).await),
+        #[cfg(feature = "events")]
+        WalletMethod::ListenerCounts => Response::ListenerCounts(wallet.listener_counts().await),
         WalletMethod::UpdateNodeAuth { url, auth } => {
             wallet.update_node_auth(url, auth).await?;
             Response::Ok
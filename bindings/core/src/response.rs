@@ -8,11 +8,15 @@ use std::collections::HashSet;
 use derivative::Derivative;
 #[cfg(feature = "ledger_nano")]
 use iota_sdk::client::secret::LedgerNanoStatus;
+#[cfg(feature = "stronghold")]
+use iota_sdk::client::stronghold::StrongholdInventory;
+#[cfg(feature = "events")]
+use iota_sdk::wallet::events::types::WalletEventType;
 use iota_sdk::{
     client::{
-        api::{PreparedTransactionDataDto, SignedTransactionDataDto},
+        api::{BlockIssuanceContext, IssuerInfo, NftHistory, PreparedTransactionDataDto, SignedTransactionDataDto},
         node_manager::node::Node,
-        NetworkInfo, NodeInfoWrapper,
+        NetworkInfo, NodeInfoWrapper, TransactionProof,
     },
     types::{
         api::{
@@ -38,9 +42,14 @@ use iota_sdk::{
             BlockDto, BlockId,
         },
     },
-    wallet::account::{
-        types::{AccountAddress, AddressWithUnspentOutputs, Balance, OutputDataDto, TransactionDto},
-        AccountDetailsDto, PreparedCreateNativeTokenTransactionDto,
+    wallet::{
+        account::{
+            types::{AccountAddress, AddressWithUnspentOutputs, Balance, OutputDataDto, TransactionDto, Unlockability},
+            AccountDetailsDto, BulkSendReport, ChainGraph, ClaimLinkTransactionDto, ConsolidationPreview,
+            NativeTokensTransactionDto, PreparedCreateNativeTokenTransactionDto, SwapAcceptance, SwapOffer,
+            VestingStatus,
+        },
+        core::{PrivacyReport, SendDefaults},
     },
 };
 use serde::Serialize;
@@ -50,7 +59,11 @@ use {
     iota_sdk::wallet::account::{AccountParticipationOverview, ParticipationEventWithNodes},
 };
 
-use crate::{error::Error, OmittedDebug};
+use crate::{
+    error::Error,
+    method_handler::{FoundryIdParts, MethodTrace, OutputIdParts},
+    OmittedDebug,
+};
 
 /// The response message.
 #[derive(Serialize, Derivative)]
@@ -80,12 +93,30 @@ pub enum Response {
     /// - [`GetMinPowScore`](crate::method::ClientMethod::GetMinPowScore)
     MinPowScore(u32),
     /// Response for:
+    /// - [`ComputePowScore`](crate::method::UtilsMethod::ComputePowScore)
+    PowScore(f64),
+    /// Response for:
+    /// - [`NegotiateInterfaceVersion`](crate::method::UtilsMethod::NegotiateInterfaceVersion)
+    InterfaceVersion(u32),
+    /// Response for:
+    /// - [`GetDebugTrace`](crate::method::UtilsMethod::GetDebugTrace)
+    DebugTrace(Vec<MethodTrace>),
+    /// Response for:
     /// - [`GetTipsInterval`](crate::method::ClientMethod::GetTipsInterval)
     TipsInterval(u64),
     /// Response for:
     /// - [`GetProtocolParameters`](crate::method::ClientMethod::GetProtocolParameters)
     ProtocolParameters(ProtocolParameters),
     /// Response for:
+    /// - [`GetBlockIssuanceContext`](crate::method::ClientMethod::GetBlockIssuanceContext)
+    BlockIssuanceContext(BlockIssuanceContext),
+    /// Response for:
+    /// - [`ResolveIssuer`](crate::method::ClientMethod::ResolveIssuer)
+    IssuerInfo(IssuerInfo),
+    /// Response for:
+    /// - [`NftHistory`](crate::method::ClientMethod::NftHistory)
+    NftHistory(NftHistory),
+    /// Response for:
     /// - [`PrepareTransaction`](crate::method::ClientMethod::PrepareTransaction)
     PreparedTransactionData(PreparedTransactionDataDto),
     /// Response for:
@@ -120,6 +151,8 @@ pub enum Response {
     /// Response for:
     /// - [`GetBlock`](crate::method::ClientMethod::GetBlock)
     /// - [`GetIncludedBlock`](crate::method::ClientMethod::GetIncludedBlock)
+    /// - [`BuildTaggedDataBlock`](crate::method::ClientMethod::BuildTaggedDataBlock)
+    /// - [`UnpackBlock`](crate::method::UtilsMethod::UnpackBlock)
     Block(BlockDto),
     /// Response for:
     /// - [`BuildAndPostBlock`](crate::method::ClientMethod::BuildAndPostBlock)
@@ -174,6 +207,7 @@ pub enum Response {
     OutputIdsResponse(OutputIdsResponse),
     /// Response for:
     /// - [`FindBlocks`](crate::method::ClientMethod::FindBlocks)
+    /// - [`FindBlocksByTag`](crate::method::ClientMethod::FindBlocksByTag)
     Blocks(Vec<BlockDto>),
     /// Response for:
     /// - [`RetryUntilIncluded`](crate::method::ClientMethod::RetryUntilIncluded)
@@ -209,20 +243,31 @@ pub enum Response {
     MilestoneId(MilestoneId),
     /// Response for:
     /// - [`ComputeTokenId`](crate::method::UtilsMethod::ComputeTokenId)
+    /// - [`FoundryIdToTokenId`](crate::method::UtilsMethod::FoundryIdToTokenId)
     TokenId(TokenId),
     /// Response for:
     /// - [`TransactionId`](crate::method::UtilsMethod::TransactionId)
+    /// - [`VerifyTransactionProof`](crate::method::UtilsMethod::VerifyTransactionProof)
     TransactionId(TransactionId),
     /// Response for:
     /// - [`ComputeAliasId`](crate::method::UtilsMethod::ComputeAliasId)
+    /// - [`Bech32ToAliasId`](crate::method::UtilsMethod::Bech32ToAliasId)
     AliasId(AliasId),
     /// Response for:
     /// - [`ComputeNftId`](crate::method::UtilsMethod::ComputeNftId)
+    /// - [`Bech32ToNftId`](crate::method::UtilsMethod::Bech32ToNftId)
     NftId(NftId),
     /// Response for:
     /// - [`ComputeFoundryId`](crate::method::UtilsMethod::ComputeFoundryId)
+    /// - [`TokenIdToFoundryId`](crate::method::UtilsMethod::TokenIdToFoundryId)
     FoundryId(FoundryId),
     /// Response for:
+    /// - [`ParseOutputId`](crate::method::UtilsMethod::ParseOutputId)
+    OutputIdParts(OutputIdParts),
+    /// Response for:
+    /// - [`ParseFoundryId`](crate::method::UtilsMethod::ParseFoundryId)
+    FoundryIdParts(FoundryIdParts),
+    /// Response for:
     /// - [`HashTransactionEssence`](crate::method::UtilsMethod::HashTransactionEssence)
     /// - [`ComputeInputsCommitment`](crate::method::UtilsMethod::ComputeInputsCommitment)
     /// - [`BlockHashWithoutNonce`](crate::method::UtilsMethod::BlockHashWithoutNonce)
@@ -231,9 +276,14 @@ pub enum Response {
     NodeInfoWrapper(NodeInfoWrapper),
     /// Response for [`Bech32ToHex`](crate::method::UtilsMethod::Bech32ToHex)
     HexAddress(String),
-    /// Response for [`OutputHexBytes`](crate::method::UtilsMethod::OutputHexBytes)
+    /// Response for:
+    /// - [`OutputHexBytes`](crate::method::UtilsMethod::OutputHexBytes)
+    /// - [`PackOutput`](crate::method::UtilsMethod::PackOutput)
+    /// - [`PackBlock`](crate::method::UtilsMethod::PackBlock)
     HexBytes(String),
-    /// Response for [`CallPluginRoute`](crate::method::ClientMethod::CallPluginRoute)
+    /// Response for:
+    /// - [`CallPluginRoute`](crate::method::ClientMethod::CallPluginRoute)
+    /// - [`DumpStateJson`](crate::method::WalletMethod::DumpStateJson)
     CustomJson(serde_json::Value),
     /// Response for [`VerifyTransactionSemantic`](crate::method::UtilsMethod::VerifyTransactionSemantic).
     ConflictReason(ConflictReason),
@@ -246,6 +296,7 @@ pub enum Response {
     /// - [`BuildNftOutput`](crate::method::ClientMethod::BuildNftOutput)
     /// - [`GetFoundryOutput`](crate::method::AccountMethod::GetFoundryOutput)
     /// - [`PrepareOutput`](crate::method::AccountMethod::PrepareOutput)
+    /// - [`UnpackOutput`](crate::method::UtilsMethod::UnpackOutput)
     Output(OutputDto),
     /// Response for:
     /// - [`AliasIdToBech32`](crate::method::ClientMethod::AliasIdToBech32)
@@ -257,6 +308,7 @@ pub enum Response {
     Faucet(String),
     /// Response for:
     /// - [`GenerateMnemonic`](crate::method::UtilsMethod::GenerateMnemonic)
+    /// - [`DeriveChildMnemonic`](crate::method::SecretManagerMethod::DeriveChildMnemonic)
     GeneratedMnemonic(#[derivative(Debug(format_with = "OmittedDebug::omitted_fmt"))] String),
     /// Response for
     /// - [`GetLedgerNanoStatus`](crate::method::SecretManagerMethod::GetLedgerNanoStatus)
@@ -264,6 +316,11 @@ pub enum Response {
     #[cfg_attr(docsrs, doc(cfg(feature = "ledger_nano")))]
     LedgerNanoStatus(LedgerNanoStatus),
     /// Response for:
+    /// - [`GetStrongholdInventory`](crate::method::SecretManagerMethod::GetStrongholdInventory)
+    #[cfg(feature = "stronghold")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "stronghold")))]
+    StrongholdInventory(StrongholdInventory),
+    /// Response for:
     /// - [`BlockId`](crate::method::UtilsMethod::BlockId)
     /// - [`PostBlock`](crate::method::ClientMethod::PostBlock)
     /// - [`PostBlockRaw`](crate::method::ClientMethod::PostBlockRaw)
@@ -273,25 +330,38 @@ pub enum Response {
     /// - [`GetLocalPow`](crate::method::ClientMethod::GetLocalPow)
     /// - [`GetFallbackToLocalPow`](crate::method::ClientMethod::GetFallbackToLocalPow)
     /// - [`GetHealth`](crate::method::ClientMethod::GetHealth)
+    /// - [`VerifyIssuer`](crate::method::ClientMethod::VerifyIssuer)
     /// - [`IsAddressValid`](crate::method::UtilsMethod::IsAddressValid)
     /// - [`VerifyEd25519Signature`](crate::method::UtilsMethod::VerifyEd25519Signature)
     /// - [`VerifySecp256k1EcdsaSignature`](crate::method::UtilsMethod::VerifySecp256k1EcdsaSignature)
+    /// - [`VerifyMnemonicWords`](crate::method::UtilsMethod::VerifyMnemonicWords)
+    /// - [`VerifyBackup`](crate::method::SecretManagerMethod::VerifyBackup)
+    /// - [`VerifyAddressOnDevice`](crate::method::AccountMethod::VerifyAddressOnDevice)
+    /// - [`RemoveListener`](crate::method::WalletMethod::RemoveListener)
     Bool(bool),
     /// Response for:
     /// - [`Backup`](crate::method::WalletMethod::Backup),
+    /// - [`CancelSwap`](crate::method::AccountMethod::CancelSwap),
     /// - [`ClearListeners`](crate::method::WalletMethod::ClearListeners)
     /// - [`ClearStrongholdPassword`](crate::method::WalletMethod::ClearStrongholdPassword),
     /// - [`DeregisterParticipationEvent`](crate::method::AccountMethod::DeregisterParticipationEvent),
     /// - [`EmitTestEvent`](crate::method::WalletMethod::EmitTestEvent),
+    /// - [`LoadStateJson`](crate::method::WalletMethod::LoadStateJson),
+    /// - [`RemoveOutputAnnotation`](crate::method::AccountMethod::RemoveOutputAnnotation),
     /// - [`RestoreBackup`](crate::method::WalletMethod::RestoreBackup),
+    /// - [`RevalidateBlock`](crate::method::ClientMethod::RevalidateBlock),
     /// - [`SetAlias`](crate::method::AccountMethod::SetAlias),
     /// - [`SetClientOptions`](crate::method::WalletMethod::SetClientOptions),
     /// - [`SetDefaultSyncOptions`](crate::method::AccountMethod::SetDefaultSyncOptions),
+    /// - [`SetOutputAnnotation`](crate::method::AccountMethod::SetOutputAnnotation),
+    /// - [`SetSendDefaults`](crate::method::WalletMethod::SetSendDefaults),
     /// - [`SetStrongholdPassword`](crate::method::WalletMethod::SetStrongholdPassword),
     /// - [`SetStrongholdPasswordClearInterval`](crate::method::WalletMethod::SetStrongholdPasswordClearInterval),
     /// - [`StartBackgroundSync`](crate::method::WalletMethod::StartBackgroundSync),
     /// - [`StoreMnemonic`](crate::method::WalletMethod::StoreMnemonic),
     /// - [`StopBackgroundSync`](crate::method::WalletMethod::StopBackgroundSync),
+    /// - [`StartDebugTrace`](crate::method::UtilsMethod::StartDebugTrace),
+    /// - [`StopDebugTrace`](crate::method::UtilsMethod::StopDebugTrace),
     /// - [`VerifyTransactionSyntax`](crate::method::UtilsMethod::VerifyTransactionSyntax),
     Ok,
     /// Response for any method that returns an error.
@@ -320,8 +390,20 @@ pub enum Response {
     /// - [`GetChrysalisData`](crate::method::WalletMethod::GetChrysalisData)
     ChrysalisData(Option<HashMap<String, String>>),
     /// Response for:
+    /// - [`PrivacyReport`](crate::method::WalletMethod::PrivacyReport)
+    PrivacyReport(PrivacyReport),
+    /// Response for:
+    /// - [`ListenerCounts`](crate::method::WalletMethod::ListenerCounts)
+    #[cfg(feature = "events")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "events")))]
+    ListenerCounts(HashMap<WalletEventType, usize>),
+    /// Response for:
+    /// - [`GetSendDefaults`](crate::method::WalletMethod::GetSendDefaults)
+    SendDefaults(SendDefaults),
+    /// Response for:
     /// - [`MinimumRequiredStorageDeposit`](crate::method::ClientMethod::MinimumRequiredStorageDeposit)
     /// - [`ComputeStorageDeposit`](crate::method::UtilsMethod::ComputeStorageDeposit)
+    /// - [`ComputeMinimumOutputAmount`](crate::method::UtilsMethod::ComputeMinimumOutputAmount)
     MinimumRequiredStorageDeposit(String),
     /// Response for:
     /// - [`ClaimableOutputs`](crate::method::AccountMethod::ClaimableOutputs)
@@ -330,19 +412,28 @@ pub enum Response {
     /// - [`GetOutput`](crate::method::AccountMethod::GetOutput)
     OutputData(Option<Box<OutputDataDto>>),
     /// Response for:
+    /// - [`GetOutputUnlockability`](crate::method::AccountMethod::GetOutputUnlockability)
+    Unlockability(Option<Unlockability>),
+    /// Response for:
     /// - [`Outputs`](crate::method::AccountMethod::Outputs),
+    /// - [`QuarantinedOutputs`](crate::method::AccountMethod::QuarantinedOutputs),
+    /// - [`QueryOutputs`](crate::method::AccountMethod::QueryOutputs),
+    /// - [`ReclaimableOutputs`](crate::method::AccountMethod::ReclaimableOutputs),
     /// - [`UnspentOutputs`](crate::method::AccountMethod::UnspentOutputs)
     OutputsData(Vec<OutputDataDto>),
     /// Response for:
     /// - [`PrepareBurn`](crate::method::AccountMethod::PrepareBurn),
+    /// - [`PrepareBurnQuarantinedOutputs`](crate::method::AccountMethod::PrepareBurnQuarantinedOutputs),
     /// - [`PrepareClaimOutputs`](crate::method::AccountMethod::PrepareClaimOutputs)
     /// - [`PrepareConsolidateOutputs`](crate::method::AccountMethod::PrepareConsolidateOutputs)
     /// - [`PrepareCreateAliasOutput`](crate::method::AccountMethod::PrepareCreateAliasOutput)
+    /// - [`PrepareCreateVesting`](crate::method::AccountMethod::PrepareCreateVesting)
     /// - [`PrepareDecreaseVotingPower`](crate::method::AccountMethod::PrepareDecreaseVotingPower)
     /// - [`PrepareIncreaseVotingPower`](crate::method::AccountMethod::PrepareIncreaseVotingPower)
     /// - [`PrepareMeltNativeToken`](crate::method::AccountMethod::PrepareMeltNativeToken)
     /// - [`PrepareMintNativeToken`](crate::method::AccountMethod::PrepareMintNativeToken),
     /// - [`PrepareMintNfts`](crate::method::AccountMethod::PrepareMintNfts),
+    /// - [`PrepareReclaimExpiredOutputs`](crate::method::AccountMethod::PrepareReclaimExpiredOutputs),
     /// - [`PrepareSend`](crate::method::AccountMethod::PrepareSend),
     /// - [`PrepareSendNativeTokens`](crate::method::AccountMethod::PrepareSendNativeTokens),
     /// - [`PrepareSendNft`](crate::method::AccountMethod::PrepareSendNft),
@@ -371,16 +462,47 @@ pub enum Response {
     GeneratedAccountAddresses(Vec<AccountAddress>),
     /// Response for:
     /// - [`GetBalance`](crate::method::AccountMethod::GetBalance),
+    /// - [`RebuildLedgerFromHistory`](crate::method::AccountMethod::RebuildLedgerFromHistory),
     /// - [`Sync`](crate::method::AccountMethod::Sync)
     Balance(Balance),
     /// Response for:
     /// - [`ClaimOutputs`](crate::method::AccountMethod::ClaimOutputs)
+    /// - [`CompleteSwap`](crate::method::AccountMethod::CompleteSwap)
+    /// - [`RedeemClaimLink`](crate::method::AccountMethod::RedeemClaimLink)
     /// - [`Send`](crate::method::AccountMethod::Send)
     /// - [`SendOutputs`](crate::method::AccountMethod::SendOutputs)
     /// - [`SignAndSubmitTransaction`](crate::method::AccountMethod::SignAndSubmitTransaction)
     /// - [`SubmitAndStoreTransaction`](crate::method::AccountMethod::SubmitAndStoreTransaction)
     SentTransaction(TransactionDto),
     /// Response for:
+    /// - [`BulkSend`](crate::method::AccountMethod::BulkSend)
+    /// - [`DistributeNfts`](crate::method::AccountMethod::DistributeNfts)
+    BulkSendReport(BulkSendReport),
+    /// Response for:
+    /// - [`CreateClaimLink`](crate::method::AccountMethod::CreateClaimLink)
+    ClaimLinkTransaction(ClaimLinkTransactionDto),
+    /// Response for:
+    /// - [`SendNativeTokensWithStorageDeposits`](crate::method::AccountMethod::SendNativeTokensWithStorageDeposits)
+    NativeTokensTransaction(NativeTokensTransactionDto),
+    /// Response for:
+    /// - [`ExportTransactionProof`](crate::method::AccountMethod::ExportTransactionProof)
+    TransactionProof(TransactionProof),
+    /// Response for:
+    /// - [`CreateSwapOffer`](crate::method::AccountMethod::CreateSwapOffer)
+    SwapOffer(SwapOffer),
+    /// Response for:
+    /// - [`AcceptSwapOffer`](crate::method::AccountMethod::AcceptSwapOffer)
+    SwapAcceptance(SwapAcceptance),
+    /// Response for:
+    /// - [`VestingStatus`](crate::method::AccountMethod::VestingStatus)
+    VestingStatus(Vec<VestingStatus>),
+    /// Response for:
+    /// - [`ConsolidationPreview`](crate::method::AccountMethod::ConsolidationPreview)
+    ConsolidationPreview(ConsolidationPreview),
+    /// Response for:
+    /// - [`ChainGraph`](crate::method::AccountMethod::ChainGraph)
+    ChainGraph(ChainGraph),
+    /// Response for:
     /// - [`GetParticipationEvent`](crate::method::AccountMethod::GetParticipationEvent)
     #[cfg(feature = "participation")]
     #[cfg_attr(docsrs, doc(cfg(feature = "participation")))]
@@ -0,0 +1,56 @@
+// Copyright 2024 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use serde::de::{DeserializeOwned, Error as _};
+use serde_json::Value;
+
+/// The version of the message interface, increased with every breaking change of the methods or responses.
+pub const INTERFACE_VERSION: u32 = 1;
+/// The oldest version of the message interface for which methods are still accepted.
+/// Version 0 are the methods sent before the interface was versioned, without a `version` field.
+pub const MIN_INTERFACE_VERSION: u32 = 0;
+
+/// Deserializes a method sent by a binding, like a [`WalletMethod`](crate::WalletMethod), after upgrading it from the
+/// interface version set in its `version` field to [`INTERFACE_VERSION`].
+///
+/// ```json
+/// { "name": "getAccountIndexes", "version": 1 }
+/// ```
+pub fn deserialize_method<M: DeserializeOwned>(method: &str) -> serde_json::Result<M> {
+    let mut method = serde_json::from_str::<Value>(method)?;
+    let version = match method.as_object_mut().and_then(|method| method.remove("version")) {
+        Some(version) => serde_json::from_value(version)?,
+        None => 0,
+    };
+
+    if !(MIN_INTERFACE_VERSION..=INTERFACE_VERSION).contains(&version) {
+        return Err(serde_json::Error::custom(format!(
+            "unsupported interface version {version}, expected {MIN_INTERFACE_VERSION} to {INTERFACE_VERSION}"
+        )));
+    }
+
+    for from_version in version..INTERFACE_VERSION {
+        upgrade_method(from_version, &mut method);
+    }
+
+    serde_json::from_value(method)
+}
+
+/// Returns the highest interface version that is supported by both the binding and the core, if any.
+pub fn negotiate_interface_version(versions: &[u32]) -> Option<u32> {
+    versions
+        .iter()
+        .copied()
+        .filter(|version| (MIN_INTERFACE_VERSION..=INTERFACE_VERSION).contains(version))
+        .max()
+}
+
+// Upgrades a method from `from_version` to the next interface version.
+// Every breaking change of a method has to add a shim here, mapping the old method layout to the new one.
+fn upgrade_method(from_version: u32, _method: &mut Value) {
+    match from_version {
+        // Version 1 only introduced the `version` field, unversioned methods have the same layout.
+        0 => {}
+        _ => unreachable!("no upgrade from interface version {from_version}"),
+    }
+}
@@ -0,0 +1,53 @@
+// Copyright 2024 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use iota_sdk_bindings_core::{AccountMethod, ClientMethod};
+use pretty_assertions::assert_eq;
+
+const ADDRESS: &str = "rms1qpllaj0pyveqfkwxmnngz2c488hfdtmfrj3wfkgxtk4gtyrax0jaxzt70zy";
+
+#[test]
+fn amounts_as_strings() {
+    let method = serde_json::from_str::<ClientMethod>(&format!(
+        r#"{{"name":"findInputs","data":{{"addresses":["{ADDRESS}"],"amount":"18446744073709551615"}}}}"#
+    ))
+    .unwrap();
+    match method {
+        ClientMethod::FindInputs { amount, .. } => assert_eq!(amount, u64::MAX),
+        _ => panic!("unexpected method"),
+    }
+
+    let method = serde_json::from_str::<AccountMethod>(&format!(
+        r#"{{"name":"send","data":{{"amount":"9007199254740993","address":"{ADDRESS}"}}}}"#
+    ))
+    .unwrap();
+    match method {
+        AccountMethod::Send { amount, .. } => assert_eq!(amount, 9_007_199_254_740_993),
+        _ => panic!("unexpected method"),
+    }
+}
+
+#[test]
+fn invalid_amounts() {
+    // Numbers would lose precision in JavaScript above 2^53
+    assert!(serde_json::from_str::<ClientMethod>(&format!(
+        r#"{{"name":"findInputs","data":{{"addresses":["{ADDRESS}"],"amount":1000000}}}}"#
+    ))
+    .is_err());
+
+    let error = serde_json::from_str::<AccountMethod>(&format!(
+        r#"{{"name":"send","data":{{"amount":"18446744073709551616","address":"{ADDRESS}"}}}}"#
+    ))
+    .unwrap_err();
+    assert!(error
+        .to_string()
+        .starts_with("can't parse `18446744073709551616`: number too large to fit in target type"));
+
+    let error = serde_json::from_str::<AccountMethod>(&format!(
+        r#"{{"name":"send","data":{{"amount":"-1","address":"{ADDRESS}"}}}}"#
+    ))
+    .unwrap_err();
+    assert!(error
+        .to_string()
+        .starts_with("can't parse `-1`: invalid digit found in string"));
+}
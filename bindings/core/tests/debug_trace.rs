@@ -0,0 +1,46 @@
+// Copyright 2024 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use iota_sdk_bindings_core::{call_utils_method, MethodTrace, Response, UtilsMethod};
+use pretty_assertions::assert_eq;
+
+fn get_debug_trace() -> Vec<MethodTrace> {
+    match call_utils_method(UtilsMethod::GetDebugTrace) {
+        Response::DebugTrace(trace) => trace,
+        response => panic!("unexpected response: {response:?}"),
+    }
+}
+
+#[test]
+fn debug_trace() {
+    // Not recorded before the trace is started
+    call_utils_method(UtilsMethod::GenerateMnemonic);
+    assert!(get_debug_trace().is_empty());
+
+    call_utils_method(UtilsMethod::StartDebugTrace { capacity: Some(2) });
+    call_utils_method(UtilsMethod::GenerateMnemonic);
+    call_utils_method(UtilsMethod::VerifyMnemonic {
+        mnemonic: "secret words".to_string(),
+    });
+
+    let trace = get_debug_trace();
+    assert_eq!(trace.len(), 2);
+    assert_eq!(trace[0].method, "GenerateMnemonic");
+    assert_eq!(trace[0].response, "generatedMnemonic");
+    assert_eq!(trace[0].error, None);
+    assert_eq!(trace[1].method, "VerifyMnemonic { mnemonic: <omitted> }");
+    assert_eq!(trace[1].response, "error");
+    assert!(trace[1].error.is_some());
+    assert!(!serde_json::to_string(&trace).unwrap().contains("secret words"));
+
+    // Only the latest calls are kept
+    let trace = get_debug_trace();
+    assert_eq!(trace.len(), 2);
+    assert_eq!(trace[0].method, "VerifyMnemonic { mnemonic: <omitted> }");
+    assert_eq!(trace[1].method, "GetDebugTrace");
+    assert_eq!(trace[1].response, "debugTrace");
+
+    call_utils_method(UtilsMethod::StopDebugTrace);
+    call_utils_method(UtilsMethod::GenerateMnemonic);
+    assert!(get_debug_trace().is_empty());
+}
@@ -0,0 +1,109 @@
+// Copyright 2024 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use iota_sdk::types::block::{
+    address::Ed25519Address,
+    output::{dto::OutputDto, unlock_condition::AddressUnlockCondition, BasicOutputBuilder},
+    parent::Parents,
+    protocol::ProtocolParameters,
+    BlockBuilder, BlockDto, BlockId, Error as BlockError,
+};
+use iota_sdk_bindings_core::{call_utils_method, Error, Response, UtilsMethod};
+use packable::error::UnpackError;
+use pretty_assertions::assert_eq;
+
+#[test]
+fn pack_unpack_output() {
+    let protocol_parameters = ProtocolParameters::default();
+    let output = BasicOutputBuilder::new_with_amount(1_000_000)
+        .add_unlock_condition(AddressUnlockCondition::new(Ed25519Address::new([1; 32])))
+        .finish_output(protocol_parameters.token_supply())
+        .unwrap();
+    let output_dto = OutputDto::from(&output);
+
+    let bytes = match call_utils_method(UtilsMethod::PackOutput {
+        output: output_dto.clone(),
+        protocol_parameters: Some(protocol_parameters.clone()),
+    }) {
+        Response::HexBytes(bytes) => bytes,
+        response => panic!("unexpected response {response:?}"),
+    };
+    match call_utils_method(UtilsMethod::UnpackOutput {
+        bytes: bytes.clone(),
+        protocol_parameters: protocol_parameters.clone(),
+    }) {
+        Response::Output(output) => assert_eq!(output, output_dto),
+        response => panic!("unexpected response {response:?}"),
+    }
+
+    let response = call_utils_method(UtilsMethod::UnpackOutput {
+        bytes: format!("{bytes}00"),
+        protocol_parameters: protocol_parameters.clone(),
+    });
+    assert!(matches!(
+        response,
+        Response::Error(Error::Unpack(UnpackError::Packable(
+            BlockError::RemainingBytesAfterOutput
+        )))
+    ));
+
+    // An output without enough storage deposit can only be packed without protocol parameters
+    let output = BasicOutputBuilder::new_with_amount(1)
+        .add_unlock_condition(AddressUnlockCondition::new(Ed25519Address::new([1; 32])))
+        .finish_output(protocol_parameters.token_supply())
+        .unwrap();
+    let response = call_utils_method(UtilsMethod::PackOutput {
+        output: OutputDto::from(&output),
+        protocol_parameters: None,
+    });
+    assert!(matches!(response, Response::HexBytes(_)));
+    let response = call_utils_method(UtilsMethod::PackOutput {
+        output: OutputDto::from(&output),
+        protocol_parameters: Some(protocol_parameters),
+    });
+    assert!(matches!(
+        response,
+        Response::Error(Error::Block(BlockError::InsufficientStorageDepositAmount { .. }))
+    ));
+}
+
+#[test]
+fn pack_unpack_block() {
+    let protocol_parameters = ProtocolParameters::default();
+    let block = BlockBuilder::new(Parents::from_vec(vec![BlockId::new([2; 32])]).unwrap())
+        .with_protocol_version(protocol_parameters.protocol_version())
+        .finish()
+        .unwrap();
+    let block_dto = BlockDto::from(&block);
+
+    let bytes = match call_utils_method(UtilsMethod::PackBlock {
+        block: block_dto.clone(),
+        protocol_parameters: Some(protocol_parameters.clone()),
+    }) {
+        Response::HexBytes(bytes) => bytes,
+        response => panic!("unexpected response {response:?}"),
+    };
+    match call_utils_method(UtilsMethod::UnpackBlock {
+        bytes: bytes.clone(),
+        protocol_parameters: protocol_parameters.clone(),
+    }) {
+        Response::Block(block) => assert_eq!(block, block_dto),
+        response => panic!("unexpected response {response:?}"),
+    }
+
+    // The block has to have the protocol version of the protocol parameters
+    let block = BlockBuilder::new(Parents::from_vec(vec![BlockId::new([2; 32])]).unwrap())
+        .with_protocol_version(protocol_parameters.protocol_version() + 1)
+        .finish()
+        .unwrap();
+    let response = call_utils_method(UtilsMethod::PackBlock {
+        block: BlockDto::from(&block),
+        protocol_parameters: Some(protocol_parameters),
+    });
+    assert!(matches!(
+        response,
+        Response::Error(Error::Unpack(UnpackError::Packable(
+            BlockError::ProtocolVersionMismatch { .. }
+        )))
+    ));
+}
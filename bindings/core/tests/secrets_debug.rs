@@ -47,6 +47,6 @@ fn method_interface_secrets_debug() {
     let wallet_options = WalletOptions::default().with_secret_manager(SecretManagerDto::Placeholder);
     assert_eq!(
         format!("{:?}", wallet_options),
-        "WalletOptions { storage_path: None, client_options: None, coin_type: None, secret_manager: Some(<omitted>) }"
+        "WalletOptions { storage_path: None, client_options: None, coin_type: None, secret_manager: Some(<omitted>), output_maturity: None, service_fee: None, network_validation: None, send_defaults: None, account_index: None }"
     );
 }
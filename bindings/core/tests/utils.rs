@@ -1,8 +1,19 @@
 // Copyright 2023 IOTA Stiftung
 // SPDX-License-Identifier: Apache-2.0
 
-use iota_sdk::types::block::address::{Bech32Address, Hrp};
-use iota_sdk_bindings_core::{call_utils_method, Response, Result, UtilsMethod};
+use std::str::FromStr;
+
+use iota_sdk::types::block::{
+    address::{AliasAddress, Bech32Address, Ed25519Address, Hrp, NftAddress},
+    output::{
+        dto::OutputDto, unlock_condition::AddressUnlockCondition, AliasId, BasicOutputBuilder, FoundryId, NftId,
+        OutputId, Rent, SimpleTokenScheme, TokenId,
+    },
+    parent::Parents,
+    protocol::ProtocolParameters,
+    BlockBuilder, BlockDto, BlockId, Error as BlockError,
+};
+use iota_sdk_bindings_core::{call_utils_method, Error, FoundryIdParts, Response, Result, UtilsMethod};
 use pretty_assertions::assert_eq;
 
 #[tokio::test]
@@ -35,3 +46,95 @@ async fn utils() -> Result<()> {
 
     Ok(())
 }
+
+#[test]
+fn minimum_output_amount_and_pow_score() {
+    let protocol_parameters = ProtocolParameters::default();
+    let output = BasicOutputBuilder::new_with_amount(1)
+        .add_unlock_condition(AddressUnlockCondition::new(Ed25519Address::new([1; 32])))
+        .finish_output(protocol_parameters.token_supply())
+        .unwrap();
+    let expected_amount = output.rent_cost(protocol_parameters.rent_structure());
+
+    match call_utils_method(UtilsMethod::ComputeMinimumOutputAmount {
+        output: OutputDto::from(&output),
+        protocol_parameters,
+    }) {
+        Response::MinimumRequiredStorageDeposit(amount) => assert_eq!(amount, expected_amount.to_string()),
+        response => panic!("unexpected response {response:?}"),
+    }
+
+    let block = BlockBuilder::new(Parents::from_vec(vec![BlockId::new([2; 32])]).unwrap())
+        .finish()
+        .unwrap();
+    match call_utils_method(UtilsMethod::ComputePowScore {
+        block: BlockDto::from(&block),
+    }) {
+        Response::PowScore(score) => assert_eq!(score, block.pow_score()),
+        response => panic!("unexpected response {response:?}"),
+    }
+}
+
+#[test]
+fn identifier_conversions() {
+    let output_id =
+        OutputId::from_str("0x52fdfc072182654f163f5f0f9a621d729566c74d10037c4d7bbb0407d1e2c6492a00").unwrap();
+    match call_utils_method(UtilsMethod::ParseOutputId { output_id }) {
+        Response::OutputIdParts(parts) => {
+            assert_eq!(parts.transaction_id, *output_id.transaction_id());
+            assert_eq!(parts.output_index, 42);
+        }
+        response => panic!("unexpected response {response:?}"),
+    }
+
+    let alias_id = AliasId::new([3; 32]);
+    let foundry_id = FoundryId::build(&AliasAddress::new(alias_id), 5, SimpleTokenScheme::KIND);
+    match call_utils_method(UtilsMethod::ParseFoundryId { foundry_id }) {
+        Response::FoundryIdParts(parts) => assert_eq!(
+            parts,
+            FoundryIdParts {
+                alias_id,
+                serial_number: 5,
+                token_scheme_type: SimpleTokenScheme::KIND,
+            }
+        ),
+        response => panic!("unexpected response {response:?}"),
+    }
+    let token_id = match call_utils_method(UtilsMethod::FoundryIdToTokenId { foundry_id }) {
+        Response::TokenId(token_id) => token_id,
+        response => panic!("unexpected response {response:?}"),
+    };
+    match call_utils_method(UtilsMethod::TokenIdToFoundryId { token_id }) {
+        Response::FoundryId(id) => assert_eq!(id, foundry_id),
+        response => panic!("unexpected response {response:?}"),
+    }
+
+    // A foundry ID has to start with an alias address
+    let mut bytes = *foundry_id;
+    bytes[0] = NftAddress::KIND;
+    let response = call_utils_method(UtilsMethod::TokenIdToFoundryId {
+        token_id: TokenId::new(bytes),
+    });
+    assert!(matches!(
+        response,
+        Response::Error(Error::Block(BlockError::InvalidAddressKind(NftAddress::KIND)))
+    ));
+
+    let hrp = Hrp::from_str_unchecked("rms");
+    let alias_address = Bech32Address::new(hrp, AliasAddress::new(alias_id));
+    match call_utils_method(UtilsMethod::Bech32ToAliasId { bech32: alias_address }) {
+        Response::AliasId(id) => assert_eq!(id, alias_id),
+        response => panic!("unexpected response {response:?}"),
+    }
+    let nft_id = NftId::new([4; 32]);
+    let nft_address = Bech32Address::new(hrp, NftAddress::new(nft_id));
+    match call_utils_method(UtilsMethod::Bech32ToNftId { bech32: nft_address }) {
+        Response::NftId(id) => assert_eq!(id, nft_id),
+        response => panic!("unexpected response {response:?}"),
+    }
+    let response = call_utils_method(UtilsMethod::Bech32ToAliasId { bech32: nft_address });
+    assert!(matches!(
+        response,
+        Response::Error(Error::Block(BlockError::InvalidAddressKind(NftAddress::KIND)))
+    ));
+}
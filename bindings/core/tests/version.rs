@@ -0,0 +1,46 @@
+// Copyright 2024 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use iota_sdk_bindings_core::{
+    call_utils_method, deserialize_method, Response, UtilsMethod, WalletMethod, INTERFACE_VERSION,
+};
+use pretty_assertions::assert_eq;
+
+#[test]
+fn deserialize_versioned_method() {
+    let method =
+        deserialize_method::<WalletMethod>(&format!(r#"{{"name":"getAccountIndexes","version":{INTERFACE_VERSION}}}"#))
+            .unwrap();
+    assert!(matches!(method, WalletMethod::GetAccountIndexes));
+
+    // Methods without a version are upgraded from the unversioned interface
+    let method = deserialize_method::<UtilsMethod>(r#"{"name":"generateMnemonic"}"#).unwrap();
+    assert!(matches!(method, UtilsMethod::GenerateMnemonic));
+
+    let error = deserialize_method::<WalletMethod>(&format!(
+        r#"{{"name":"getAccountIndexes","version":{}}}"#,
+        INTERFACE_VERSION + 1
+    ))
+    .unwrap_err();
+    assert!(error.to_string().starts_with("unsupported interface version"));
+}
+
+#[test]
+fn negotiate_interface_version() {
+    match call_utils_method(UtilsMethod::NegotiateInterfaceVersion {
+        versions: vec![0, INTERFACE_VERSION, INTERFACE_VERSION + 1],
+    }) {
+        Response::InterfaceVersion(version) => assert_eq!(version, INTERFACE_VERSION),
+        _ => panic!("Unexpected response type"),
+    }
+
+    match call_utils_method(UtilsMethod::NegotiateInterfaceVersion {
+        versions: vec![INTERFACE_VERSION + 1],
+    }) {
+        Response::Error(error) => assert_eq!(
+            serde_json::to_value(error).unwrap()["type"],
+            serde_json::json!("unsupportedInterfaceVersions")
+        ),
+        _ => panic!("Unexpected response type"),
+    }
+}
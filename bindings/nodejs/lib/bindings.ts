@@ -5,6 +5,7 @@ import type { WalletEventType } from './types/wallet';
 import { Event } from './types/wallet';
 import type { WalletMethodHandler } from './wallet/wallet-method-handler';
 import { __UtilsMethods__ } from './types/utils';
+import { INTERFACE_VERSION } from './types';
 import type { SecretManagerMethodHandler } from './secret_manager/secret-manager-method-handler';
 import type { ClientMethodHandler } from './client/client-method-handler';
 
@@ -28,6 +29,8 @@ const {
     getSecretManagerFromWallet,
     migrateStrongholdSnapshotV2ToV3,
     migrateDbChrysalisToStardust,
+    setApprovalHandler,
+    clearApprovalHandler,
 } = addon;
 
 const callClientMethodAsync = (
@@ -63,7 +66,11 @@ const callSecretManagerMethodAsync = (
     });
 
 const callUtilsMethod = (method: __UtilsMethods__): any => {
-    const response = JSON.parse(callUtilsMethodRust(JSON.stringify(method)));
+    const response = JSON.parse(
+        callUtilsMethodRust(
+            JSON.stringify({ ...method, version: INTERFACE_VERSION }),
+        ),
+    );
     if (response.type == 'error' || response.type == 'panic') {
         throw response;
     } else {
@@ -75,7 +82,7 @@ const listenWalletAsync = (
     eventTypes: WalletEventType[],
     callback: (error: Error, event: Event) => void,
     handler: WalletMethodHandler,
-): Promise<void> => {
+): Promise<number> =>
     listenWallet(
         eventTypes,
         function (err: any, data: string) {
@@ -84,8 +91,6 @@ const listenWalletAsync = (
         },
         handler,
     );
-    return Promise.resolve();
-};
 
 const callWalletMethodAsync = (
     method: string,
@@ -118,4 +123,6 @@ export {
     listenMqtt,
     migrateStrongholdSnapshotV2ToV3,
     migrateDbChrysalisToStardust,
+    setApprovalHandler,
+    clearApprovalHandler,
 };
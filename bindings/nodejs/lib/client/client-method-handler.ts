@@ -8,6 +8,7 @@ import {
     destroyClient,
 } from '../bindings';
 import type { IClientOptions, __ClientMethods__ } from '../types/client';
+import { INTERFACE_VERSION } from '../types';
 
 /**
  * The MethodHandler which sends the commands to the Rust side.
@@ -39,7 +40,7 @@ export class ClientMethodHandler {
      */
     async callMethod(method: __ClientMethods__): Promise<string> {
         return callClientMethodAsync(
-            JSON.stringify(method),
+            JSON.stringify({ ...method, version: INTERFACE_VERSION }),
             this.methodHandler,
         );
     }
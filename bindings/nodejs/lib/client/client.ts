@@ -10,6 +10,10 @@ import {
     PreparedTransactionData,
     INetworkInfo,
     INode,
+    IRequestOptions,
+    BlockIssuanceContext,
+    IssuerInfo,
+    NftHistory,
     IAuth,
     BasicOutputBuilderParams,
     AliasOutputBuilderParams,
@@ -103,14 +107,19 @@ export class Client {
 
     /**
      * Fetch alias/basic/NFT/foundry output IDs based on the given query parameters.
+     *
+     * @param queryParameters The query parameters.
+     * @param requestOptions Options to query a specific node instead of the node pool.
      */
     async outputIds(
         queryParameters: GenericQueryParameter[],
+        requestOptions?: IRequestOptions,
     ): Promise<IOutputsResponse> {
         const response = await this.methodHandler.callMethod({
             name: 'outputIds',
             data: {
                 queryParameters,
+                requestOptions,
             },
         });
 
@@ -119,14 +128,19 @@ export class Client {
 
     /**
      * Fetch basic output IDs based on the given query parameters.
+     *
+     * @param queryParameters The query parameters.
+     * @param requestOptions Options to query a specific node instead of the node pool.
      */
     async basicOutputIds(
         queryParameters: QueryParameter[],
+        requestOptions?: IRequestOptions,
     ): Promise<IOutputsResponse> {
         const response = await this.methodHandler.callMethod({
             name: 'basicOutputIds',
             data: {
                 queryParameters,
+                requestOptions,
             },
         });
 
@@ -217,13 +231,18 @@ export class Client {
      * Post a block in JSON format.
      *
      * @param block The block to post.
+     * @param requestOptions Options to send the block to a specific node instead of the node pool.
      * @returns The block ID once the block has been posted.
      */
-    async postBlock(block: Block): Promise<BlockId> {
+    async postBlock(
+        block: Block,
+        requestOptions?: IRequestOptions,
+    ): Promise<BlockId> {
         const response = await this.methodHandler.callMethod({
             name: 'postBlock',
             data: {
                 block,
+                requestOptions,
             },
         });
 
@@ -280,7 +299,7 @@ export class Client {
             name: 'findInputs',
             data: {
                 addresses,
-                amount: Number(amount),
+                amount: amount.toString(10),
             },
         });
 
@@ -474,6 +493,36 @@ export class Client {
         return JSON.parse(response).payload;
     }
 
+    /**
+     * Get the current block issuance context, to store it alongside a built block.
+     */
+    async getBlockIssuanceContext(): Promise<BlockIssuanceContext> {
+        const response = await this.methodHandler.callMethod({
+            name: 'getBlockIssuanceContext',
+        });
+
+        return JSON.parse(response).payload;
+    }
+
+    /**
+     * Re-validate a stored block against the issuance context it was built with.
+     *
+     * @param block The block to re-validate.
+     * @param context The issuance context of the block.
+     */
+    async revalidateBlock(
+        block: Block,
+        context: BlockIssuanceContext,
+    ): Promise<void> {
+        await this.methodHandler.callMethod({
+            name: 'revalidateBlock',
+            data: {
+                block,
+                context,
+            },
+        });
+    }
+
     /**
      * Get the health of a node.
      *
@@ -934,6 +983,107 @@ export class Client {
         return plainToInstance(Block, parsed.payload);
     }
 
+    /**
+     * Find the blocks with a tagged data payload with the given tag, also as payload of a transaction, which were
+     * referenced by the milestones in the given range. Uses the explorer API of a permanode running INX-Chronicle.
+     *
+     * @param tag The hex encoded tag.
+     * @param startMilestoneIndex The first milestone index, the one after the pruning index of the node if not set.
+     * @param endMilestoneIndex The last milestone index, the latest milestone if not set.
+     * @returns The found blocks.
+     */
+    async findBlocksByTag(
+        tag: HexEncodedString,
+        startMilestoneIndex?: number,
+        endMilestoneIndex?: number,
+    ): Promise<Block[]> {
+        const response = await this.methodHandler.callMethod({
+            name: 'findBlocksByTag',
+            data: {
+                tag,
+                startMilestoneIndex,
+                endMilestoneIndex,
+            },
+        });
+        const parsed = JSON.parse(response) as Response<Block[]>;
+        return plainToInstance(Block, parsed.payload);
+    }
+
+    /**
+     * Build and post a block with a tagged data payload, then retry (promote or reattach) it until it's referenced by
+     * a milestone.
+     *
+     * @param tag The hex encoded tag.
+     * @param data The hex encoded data.
+     * @returns The referenced block, a reattachment if the posted block couldn't be referenced anymore.
+     */
+    async buildTaggedDataBlock(
+        tag: HexEncodedString,
+        data: HexEncodedString,
+    ): Promise<Block> {
+        const response = await this.methodHandler.callMethod({
+            name: 'buildTaggedDataBlock',
+            data: {
+                tag,
+                data,
+            },
+        });
+        const parsed = JSON.parse(response) as Response<Block>;
+        return plainToInstance(Block, parsed.payload);
+    }
+
+    /**
+     * Resolve an alias or NFT issuer from its current output. Results are cached if the client has cache options,
+     * also after the issuer was destroyed.
+     *
+     * @param issuer The address of the alias or NFT.
+     * @returns The issuer with its immutable features.
+     */
+    async resolveIssuer(issuer: Bech32Address): Promise<IssuerInfo> {
+        const response = await this.methodHandler.callMethod({
+            name: 'resolveIssuer',
+            data: {
+                issuer,
+            },
+        });
+        return JSON.parse(response).payload;
+    }
+
+    /**
+     * Check whether an NFT was issued by the given issuer, e.g. the collection NFT, with its immutable issuer feature.
+     *
+     * @param nftId The ID of the NFT to verify.
+     * @param issuer The address of the expected issuer.
+     * @returns Whether the NFT was issued by the issuer.
+     */
+    async verifyIssuer(nftId: NftId, issuer: Bech32Address): Promise<boolean> {
+        const response = await this.methodHandler.callMethod({
+            name: 'verifyIssuer',
+            data: {
+                nftId,
+                issuer,
+            },
+        });
+        return JSON.parse(response).payload;
+    }
+
+    /**
+     * Resolve the ownership history of an NFT by walking its outputs backwards from the current one.
+     * Spent outputs are pruned by nodes, so a permanode should be configured for NFTs with a longer history.
+     *
+     * @param nftId The ID of the NFT.
+     * @returns The outputs of the NFT with their owners, oldest first.
+     */
+    async nftHistory(nftId: NftId): Promise<NftHistory> {
+        const response = await this.methodHandler.callMethod({
+            name: 'nftHistory',
+            data: {
+                nftId,
+            },
+        });
+        return JSON.parse(response).payload;
+    }
+
     /**
      * Retry (promote or reattach) a block given its block ID.
      *
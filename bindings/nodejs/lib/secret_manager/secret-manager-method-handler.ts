@@ -10,6 +10,7 @@ import {
     SecretManagerType,
     __SecretManagerMethods__,
 } from '../types/secret_manager';
+import { INTERFACE_VERSION } from '../types';
 
 /** The MethodHandler which sends the commands to the Rust backend. */
 export class SecretManagerMethodHandler {
@@ -35,7 +36,7 @@ export class SecretManagerMethodHandler {
      */
     async callMethod(method: __SecretManagerMethods__): Promise<string> {
         return callSecretManagerMethodAsync(
-            JSON.stringify(method),
+            JSON.stringify({ ...method, version: INTERFACE_VERSION }),
             this.methodHandler,
         );
     }
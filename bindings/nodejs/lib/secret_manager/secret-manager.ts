@@ -11,6 +11,7 @@ import {
     Bip44,
     Secp256k1EcdsaSignature,
     SecretManagerType,
+    StrongholdInventory,
 } from '../types/secret_manager';
 import {
     Ed25519Signature,
@@ -75,18 +76,102 @@ export class SecretManager {
      * Store a mnemonic in the Stronghold vault.
      *
      * @param mnemonic The mnemonic to store.
+     * @param passphrase An optional BIP-39 passphrase, also known as "25th word".
      */
-    async storeMnemonic(mnemonic: string): Promise<void> {
+    async storeMnemonic(mnemonic: string, passphrase?: string): Promise<void> {
         const response = await this.methodHandler.callMethod({
             name: 'storeMnemonic',
             data: {
                 mnemonic,
+                passphrase,
             },
         });
 
         return JSON.parse(response).payload;
     }
 
+    /**
+     * Derive a child mnemonic from the seed, as specified in BIP-85, so an app can use its own mnemonic that's
+     * recoverable from the backup of this one.
+     *
+     * @param index The index of the child mnemonic.
+     * @param wordCount The number of words of the child mnemonic, defaults to 24.
+     * @returns The child mnemonic.
+     */
+    async deriveChildMnemonic(
+        index: number,
+        wordCount?: 12 | 18 | 24,
+    ): Promise<string> {
+        const response = await this.methodHandler.callMethod({
+            name: 'deriveChildMnemonic',
+            data: {
+                index,
+                wordCount,
+            },
+        });
+
+        return JSON.parse(response).payload;
+    }
+
+    /**
+     * Check if a re-entered mnemonic belongs to the seed of the secret manager. The whole mnemonic is needed, because
+     * only the seed is stored.
+     *
+     * @param mnemonic The re-entered mnemonic.
+     * @param passphrase An optional BIP-39 passphrase, also known as "25th word".
+     * @returns True if the mnemonic and passphrase match the stored seed.
+     */
+    async verifyBackup(
+        mnemonic: string,
+        passphrase?: string,
+    ): Promise<boolean> {
+        const response = await this.methodHandler.callMethod({
+            name: 'verifyBackup',
+            data: {
+                mnemonic,
+                passphrase,
+            },
+        });
+
+        return JSON.parse(response).payload;
+    }
+
+    /**
+     * List what is stored in the Stronghold snapshot, without any secret.
+     *
+     * @returns If a mnemonic is stored and the keys of the key-value store.
+     */
+    async getStrongholdInventory(): Promise<StrongholdInventory> {
+        const response = await this.methodHandler.callMethod({
+            name: 'getStrongholdInventory',
+        });
+
+        return JSON.parse(response).payload;
+    }
+
+    /**
+     * Delete the mnemonic stored in the Stronghold vault.
+     */
+    async deleteStoredMnemonic(): Promise<void> {
+        await this.methodHandler.callMethod({
+            name: 'deleteStoredMnemonic',
+        });
+    }
+
+    /**
+     * Delete an entry of the Stronghold key-value store.
+     *
+     * @param key The key of the entry.
+     */
+    async deleteStoreEntry(key: string): Promise<void> {
+        await this.methodHandler.callMethod({
+            name: 'deleteStoreEntry',
+            data: {
+                key,
+            },
+        });
+    }
+
     /**
      * Sign a transaction.
      *
@@ -9,6 +9,7 @@ import type { IGenerateAddressesOptions } from '../generate-addresses-options';
 import type { IBuildBlockOptions } from '../build-block-options';
 import type {
     AliasId,
+    Bech32Address,
     Block,
     BlockId,
     FoundryId,
@@ -26,12 +27,12 @@ import type {
     NftQueryParameter,
     QueryParameter,
 } from '../query-parameters';
-import type { IAuth } from '../network';
+import type { BlockIssuanceContext, IAuth, IRequestOptions } from '../network';
 import type { BasicOutputBuilderParams } from '../output_builder_params/basic-output-params';
 import type { AliasOutputBuilderParams } from '../output_builder_params/alias-output-params';
 import type { FoundryOutputBuilderParams } from '../output_builder_params/foundry-output-params';
 import type { NftOutputBuilderParams } from '../output_builder_params/nft-output-params';
-import { HexEncodedString } from '../../utils';
+import { HexEncodedString, NumericString } from '../../utils';
 import { TransactionId } from '../..';
 
 export interface __GetInfoMethod__ {
@@ -49,6 +50,7 @@ export interface __GetOutputIdsMethod__ {
     name: 'outputIds';
     data: {
         queryParameters: GenericQueryParameter[];
+        requestOptions?: IRequestOptions;
     };
 }
 
@@ -56,6 +58,7 @@ export interface __GetBasicOutputIdsMethod__ {
     name: 'basicOutputIds';
     data: {
         queryParameters: QueryParameter[];
+        requestOptions?: IRequestOptions;
     };
 }
 
@@ -70,6 +73,7 @@ export interface __PostBlockMethod__ {
     name: 'postBlock';
     data: {
         block: Block;
+        requestOptions?: IRequestOptions;
     };
 }
 
@@ -107,7 +111,7 @@ export interface __FindInputsMethod__ {
     name: 'findInputs';
     data: {
         addresses: string[];
-        amount: number;
+        amount: NumericString;
     };
 }
 
@@ -175,6 +179,18 @@ export interface __GetFallbackToLocalPowMethod__ {
     name: 'getFallbackToLocalPow';
 }
 
+export interface __GetBlockIssuanceContextMethod__ {
+    name: 'getBlockIssuanceContext';
+}
+
+export interface __RevalidateBlockMethod__ {
+    name: 'revalidateBlock';
+    data: {
+        block: Block;
+        context: BlockIssuanceContext;
+    };
+}
+
 export interface __GetHealthMethod__ {
     name: 'getHealth';
     data: {
@@ -352,6 +368,45 @@ export interface __FindBlocksMethod__ {
     };
 }
 
+export interface __FindBlocksByTagMethod__ {
+    name: 'findBlocksByTag';
+    data: {
+        tag: HexEncodedString;
+        startMilestoneIndex?: number;
+        endMilestoneIndex?: number;
+    };
+}
+
+export interface __BuildTaggedDataBlockMethod__ {
+    name: 'buildTaggedDataBlock';
+    data: {
+        tag: HexEncodedString;
+        data: HexEncodedString;
+    };
+}
+
+export interface __ResolveIssuerMethod__ {
+    name: 'resolveIssuer';
+    data: {
+        issuer: Bech32Address;
+    };
+}
+
+export interface __NftHistoryMethod__ {
+    name: 'nftHistory';
+    data: {
+        nftId: NftId;
+    };
+}
+
+export interface __VerifyIssuerMethod__ {
+    name: 'verifyIssuer';
+    data: {
+        nftId: NftId;
+        issuer: Bech32Address;
+    };
+}
+
 export interface __RetryMethod__ {
     name: 'retry';
     data: {
@@ -25,6 +25,8 @@ import type {
     __GetProtocolParametersMethod__,
     __GetLocalPowMethod__,
     __GetFallbackToLocalPowMethod__,
+    __GetBlockIssuanceContextMethod__,
+    __RevalidateBlockMethod__,
     __GetHealthMethod__,
     __GetNodeInfoMethod__,
     __GetPeersMethod__,
@@ -47,6 +49,11 @@ import type {
     __FoundryOutputIdMethod__,
     __GetOutputsIgnoreErrorsMethod__,
     __FindBlocksMethod__,
+    __FindBlocksByTagMethod__,
+    __BuildTaggedDataBlockMethod__,
+    __ResolveIssuerMethod__,
+    __VerifyIssuerMethod__,
+    __NftHistoryMethod__,
     __RetryMethod__,
     __RetryUntilIncludedMethod__,
     __ConsolidateFundsMethod__,
@@ -95,6 +102,8 @@ export type __ClientMethods__ =
     | __GetProtocolParametersMethod__
     | __GetLocalPowMethod__
     | __GetFallbackToLocalPowMethod__
+    | __GetBlockIssuanceContextMethod__
+    | __RevalidateBlockMethod__
     | __GetHealthMethod__
     | __GetNodeInfoMethod__
     | __GetPeersMethod__
@@ -121,6 +130,11 @@ export type __ClientMethods__ =
     | __FoundryOutputIdMethod__
     | __GetOutputsIgnoreErrorsMethod__
     | __FindBlocksMethod__
+    | __FindBlocksByTagMethod__
+    | __BuildTaggedDataBlockMethod__
+    | __ResolveIssuerMethod__
+    | __VerifyIssuerMethod__
+    | __NftHistoryMethod__
     | __RetryMethod__
     | __RetryUntilIncludedMethod__
     | __ConsolidateFundsMethod__
@@ -1,6 +1,6 @@
 // Copyright 2021-2023 IOTA Stiftung
 // SPDX-License-Identifier: Apache-2.0
-import type { IMqttBrokerOptions, INetworkInfo, INode } from './network';
+import type { IMqttBrokerOptions, INetworkInfo, INode, ITlsPin } from './network';
 
 /** Options for the client builder */
 export interface IClientOptions {
@@ -8,6 +8,9 @@ export interface IClientOptions {
     primaryNode?: string | INode;
     /** Node which will be tried first when using remote PoW, even before the primary_node */
     primaryPowNode?: string | INode;
+    /** Node to which blocks are submitted while it's healthy, the other nodes are used if it's unhealthy or fails. It's
+     * not used for other requests. */
+    issuerNode?: string | INode;
     /** A list of nodes. */
     nodes?: Array<string | INode>;
     /** A list of permanodes. */
@@ -24,6 +27,10 @@ export interface IClientOptions {
     minQuorumSize?: number;
     /** % of nodes that have to return the same response so it gets accepted */
     quorumThreshold?: number;
+    /** The proxy for the requests to nodes without their own proxy, e.g. `http://proxy.local:3128` */
+    proxy?: string;
+    /** Pinned TLS certificates or public keys, keyed by the host of the https node URLs */
+    tlsPins?: { [host: string]: ITlsPin[] };
     /** Data related to the used network */
     networkInfo?: INetworkInfo;
     /** Options for the MQTT broker */
@@ -38,6 +45,16 @@ export interface IClientOptions {
     localPow?: boolean;
     /** The maximum parallel API requests. */
     maxParallelApiRequests?: number;
+    /** Options to cache node responses which can't change anymore, disabled if not set. */
+    cache?: ICacheOptions;
+}
+
+/** Options to cache node responses which can't change anymore, like blocks and milestones */
+export interface ICacheOptions {
+    /** The maximum amount of responses kept in memory. Default: 1000. */
+    capacity?: number;
+    /** A directory in which the responses are also stored, so they are still available after a restart. */
+    storagePath?: string;
 }
 
 /** Time duration */
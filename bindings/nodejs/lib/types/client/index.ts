@@ -6,8 +6,10 @@ export * from './burn';
 export * from './client-options';
 export * from './constants';
 export * from './generate-addresses-options';
+export * from './issuer';
 export * from './ledger-nano-status';
 export * from './network';
+export * from './nft-history';
 export * from './nodeInfo';
 export * from './prepared-transaction-data';
 export * from './query-parameters';
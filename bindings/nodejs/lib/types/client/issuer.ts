@@ -0,0 +1,15 @@
+// Copyright 2024 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+import { Bech32Address } from '../block';
+import { HexEncodedString } from '../utils';
+
+/** An alias or NFT which issued outputs, with its immutable features. */
+export interface IssuerInfo {
+    /** The address of the issuer. */
+    address: Bech32Address;
+    /** The issuer of the issuer itself, e.g. the alias which minted a collection NFT. */
+    issuer?: Bech32Address;
+    /** The hex encoded immutable metadata, e.g. the IRC-27 metadata of a collection NFT. */
+    immutableMetadata?: HexEncodedString;
+}
@@ -21,6 +21,8 @@ export interface IAuth {
     jwt?: string;
     /** Basic authentication parameters. */
     basicAuthNamePwd?: [string, string];
+    /** Custom headers sent with every request, e.g. the API key of a node provider. */
+    headers?: { [name: string]: string };
 }
 
 /**
@@ -49,6 +51,27 @@ export interface INode {
     auth?: IAuth;
     /** Whether the node is disabled or not. */
     disabled?: boolean;
+    /** The proxy to connect to the node with instead of the proxy of the client, e.g. `http://proxy.local:3128`. */
+    proxy?: string;
+}
+
+/**
+ * A pin of the TLS certificate of a node, the connection is rejected if the certificate doesn't match any pin of the
+ * host.
+ */
+export interface ITlsPin {
+    /** Whether the hash is of the DER encoded certificate or of its subject public key info. */
+    type: 'certificate' | 'publicKey';
+    /** The hex encoded SHA-256 hash. */
+    sha256: string;
+}
+
+/**
+ * Options for a single request.
+ */
+export interface IRequestOptions {
+    /** The node to send the request to, bypassing the node pool. Its health isn't checked and quorum isn't used. */
+    node?: string | INode;
 }
 
 /**
@@ -66,3 +89,15 @@ export interface INetworkInfo {
     /** Tips request interval during PoW in seconds */
     tipsInterval: number;
 }
+
+/** The network state a block was built with, stored alongside the block to re-validate it later. */
+export interface BlockIssuanceContext {
+    /** The protocol parameters the block was built with. */
+    protocolParameters: INodeInfoProtocol;
+    /** Whether the proof of work of the block was done locally, else the node did it. */
+    localPow: boolean;
+    /** The latest milestone timestamp known to the client when the block was built. */
+    latestMilestoneTimestamp?: number;
+    /** The local unix timestamp in seconds when the block was built. */
+    issuingTime: number;
+}
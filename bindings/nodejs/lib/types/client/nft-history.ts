@@ -0,0 +1,29 @@
+// Copyright 2024 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+import { Bech32Address, NftId, OutputId } from '../block';
+import type { TransactionId } from '../wallet/event';
+
+/** The ownership history of an NFT. */
+export interface NftHistory {
+    /** The ID of the NFT. */
+    nftId: NftId;
+    /** The outputs of the NFT, starting with the oldest known one and ending with the current one. */
+    outputs: NftHistoryEntry[];
+    /** Whether the history starts with the output which minted the NFT. If not, older outputs or transactions were pruned by the nodes. */
+    complete: boolean;
+}
+
+/** An output of an NFT with the owner at that time. */
+export interface NftHistoryEntry {
+    /** The ID of the output. */
+    outputId: OutputId;
+    /** The transaction which created the output, transferring the NFT to the owner or minting it. */
+    transactionId: TransactionId;
+    /** The address of the address unlock condition of the output. */
+    owner: Bech32Address;
+    /** The milestone index at which the output was booked. */
+    milestoneIndexBooked: number;
+    /** The milestone timestamp at which the output was booked. */
+    milestoneTimestampBooked: number;
+}
@@ -9,6 +9,11 @@ export * from './utils';
 export * from './wallet';
 export * from './logger-config';
 
+/**
+ * The version of the message interface that the methods are sent with.
+ */
+export const INTERFACE_VERSION = 1;
+
 /**
  * Response from the message interface
  */
@@ -1,20 +1,30 @@
 import type {
+    __DeleteStoreEntryMethod__,
+    __DeleteStoredMnemonicMethod__,
+    __DeriveChildMnemonicMethod__,
     __GenerateEd25519AddressesMethod__,
     __GenerateEvmAddressesMethod__,
     __GetLedgerNanoStatusMethod__,
+    __GetStrongholdInventoryMethod__,
     __SignTransactionMethod__,
     __StoreMnemonicMethod__,
     __SignatureUnlockMethod__,
     __SignEd25519Method__,
     __SignSecp256k1EcdsaMethod__,
+    __VerifyBackupMethod__,
 } from './secret-manager';
 
 export type __SecretManagerMethods__ =
+    | __DeleteStoreEntryMethod__
+    | __DeleteStoredMnemonicMethod__
+    | __DeriveChildMnemonicMethod__
     | __GenerateEd25519AddressesMethod__
     | __GenerateEvmAddressesMethod__
     | __GetLedgerNanoStatusMethod__
+    | __GetStrongholdInventoryMethod__
     | __SignTransactionMethod__
     | __SignatureUnlockMethod__
     | __StoreMnemonicMethod__
     | __SignEd25519Method__
-    | __SignSecp256k1EcdsaMethod__;
+    | __SignSecp256k1EcdsaMethod__
+    | __VerifyBackupMethod__;
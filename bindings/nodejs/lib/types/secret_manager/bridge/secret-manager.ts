@@ -39,6 +39,38 @@ export interface __StoreMnemonicMethod__ {
     name: 'storeMnemonic';
     data: {
         mnemonic: string;
+        passphrase?: string;
+    };
+}
+
+export interface __VerifyBackupMethod__ {
+    name: 'verifyBackup';
+    data: {
+        mnemonic: string;
+        passphrase?: string;
+    };
+}
+
+export interface __DeriveChildMnemonicMethod__ {
+    name: 'deriveChildMnemonic';
+    data: {
+        index: number;
+        wordCount?: 12 | 18 | 24;
+    };
+}
+
+export interface __GetStrongholdInventoryMethod__ {
+    name: 'getStrongholdInventory';
+}
+
+export interface __DeleteStoredMnemonicMethod__ {
+    name: 'deleteStoredMnemonic';
+}
+
+export interface __DeleteStoreEntryMethod__ {
+    name: 'deleteStoreEntry';
+    data: {
+        key: string;
     };
 }
 
@@ -15,6 +15,16 @@ export interface MnemonicSecretManager {
     mnemonic: string;
 }
 
+/** Secret manager that uses a mnemonic with a BIP-39 passphrase. */
+export interface MnemonicWithPassphraseSecretManager {
+    mnemonicWithPassphrase: {
+        /** The underlying mnemonic. */
+        mnemonic: string;
+        /** The BIP-39 passphrase, also known as "25th word". */
+        passphrase: string;
+    };
+}
+
 /** Secret manager that uses a seed. */
 export interface SeedSecretManager {
     /** The underlying seed. */
@@ -33,6 +43,22 @@ export interface StrongholdSecretManager {
     };
 }
 
+/** A key of the Stronghold key-value store. */
+export interface StrongholdStoreKey {
+    /** The Stronghold client the store belongs to. */
+    namespace: string;
+    /** The key of the entry. */
+    key: string;
+}
+
+/** The content of a Stronghold snapshot, without any secret. */
+export interface StrongholdInventory {
+    /** Whether a mnemonic has been stored in the secret vault. */
+    mnemonic: boolean;
+    /** The keys of the key-value store, sorted by namespace and key. */
+    storeKeys: StrongholdStoreKey[];
+}
+
 /** Secret manager based on a single ED25519 private key. */
 export interface PrivateKeySecretManager {
     privateKey: HexEncodedString;
@@ -42,6 +68,7 @@ export interface PrivateKeySecretManager {
 export type SecretManagerType =
     | LedgerNanoSecretManager
     | MnemonicSecretManager
+    | MnemonicWithPassphraseSecretManager
     | SeedSecretManager
     | StrongholdSecretManager
     | PrivateKeySecretManager
@@ -4,10 +4,18 @@ import type {
     __ComputeAliasIdMethod__,
     __ComputeOutputIdMethod__,
     __ComputeTokenIdMethod__,
+    __ParseOutputIdMethod__,
+    __ParseFoundryIdMethod__,
+    __TokenIdToFoundryIdMethod__,
+    __FoundryIdToTokenIdMethod__,
+    __Bech32ToAliasIdMethod__,
+    __Bech32ToNftIdMethod__,
     __ComputeNftIdMethod__,
     __ComputeFoundryIdMethod__,
     __ComputeInputsCommitmentMethod__,
     __ComputeStorageDepositMethod__,
+    __ComputeMinimumOutputAmountMethod__,
+    __ComputePowScoreMethod__,
     __ParseBech32AddressMethod__,
     __BlockIdMethod__,
     __MilestoneIdMethod__,
@@ -22,13 +30,23 @@ import type {
     __VerifyEd25519SignatureMethod__,
     __VerifySecp256k1EcdsaSignatureMethod__,
     __VerifyMnemonicMethod__,
+    __VerifyMnemonicWordsMethod__,
     __FaucetMethod__,
     __OutputIdToUtxoInput__,
     __OutputHexBytes__,
     __VerifyTransactionSemantic__,
     __VerifyTransactionSyntax__,
+    __VerifyTransactionProof__,
     __BlockBytes__,
     __BlockHashWithoutNonce__,
+    __PackOutput__,
+    __UnpackOutput__,
+    __PackBlock__,
+    __UnpackBlock__,
+    __NegotiateInterfaceVersion__,
+    __StartDebugTrace__,
+    __StopDebugTrace__,
+    __GetDebugTrace__,
 } from './utils';
 
 export type __UtilsMethods__ =
@@ -39,8 +57,16 @@ export type __UtilsMethods__ =
     | __ComputeFoundryIdMethod__
     | __ComputeOutputIdMethod__
     | __ComputeTokenIdMethod__
+    | __ParseOutputIdMethod__
+    | __ParseFoundryIdMethod__
+    | __TokenIdToFoundryIdMethod__
+    | __FoundryIdToTokenIdMethod__
+    | __Bech32ToAliasIdMethod__
+    | __Bech32ToNftIdMethod__
     | __ComputeInputsCommitmentMethod__
     | __ComputeStorageDepositMethod__
+    | __ComputeMinimumOutputAmountMethod__
+    | __ComputePowScoreMethod__
     | __ParseBech32AddressMethod__
     | __BlockIdMethod__
     | __MilestoneIdMethod__
@@ -55,10 +81,20 @@ export type __UtilsMethods__ =
     | __VerifyEd25519SignatureMethod__
     | __VerifySecp256k1EcdsaSignatureMethod__
     | __VerifyMnemonicMethod__
+    | __VerifyMnemonicWordsMethod__
     | __FaucetMethod__
     | __OutputIdToUtxoInput__
     | __OutputHexBytes__
     | __VerifyTransactionSemantic__
     | __VerifyTransactionSyntax__
+    | __VerifyTransactionProof__
     | __BlockBytes__
-    | __BlockHashWithoutNonce__;
+    | __BlockHashWithoutNonce__
+    | __PackOutput__
+    | __UnpackOutput__
+    | __PackBlock__
+    | __UnpackBlock__
+    | __NegotiateInterfaceVersion__
+    | __StartDebugTrace__
+    | __StopDebugTrace__
+    | __GetDebugTrace__;
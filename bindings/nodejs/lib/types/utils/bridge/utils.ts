@@ -13,8 +13,9 @@ import {
     NftId,
     Bech32Address,
     INodeInfoProtocol,
+    TransactionProof,
 } from '../../';
-import { AliasId } from '../../block/id';
+import { AliasId, FoundryId, TokenId } from '../../block/id';
 import { InputSigningData } from '../../client';
 
 export interface __GenerateMnemonicMethod__ {
@@ -74,6 +75,21 @@ export interface __ComputeStorageDepositMethod__ {
     };
 }
 
+export interface __ComputeMinimumOutputAmountMethod__ {
+    name: 'computeMinimumOutputAmount';
+    data: {
+        output: Output;
+        protocolParameters: INodeInfoProtocol;
+    };
+}
+
+export interface __ComputePowScoreMethod__ {
+    name: 'computePowScore';
+    data: {
+        block: Block;
+    };
+}
+
 export interface __ComputeTokenIdMethod__ {
     name: 'computeTokenId';
     data: {
@@ -83,6 +99,48 @@ export interface __ComputeTokenIdMethod__ {
     };
 }
 
+export interface __ParseOutputIdMethod__ {
+    name: 'parseOutputId';
+    data: {
+        outputId: OutputId;
+    };
+}
+
+export interface __ParseFoundryIdMethod__ {
+    name: 'parseFoundryId';
+    data: {
+        foundryId: FoundryId;
+    };
+}
+
+export interface __TokenIdToFoundryIdMethod__ {
+    name: 'tokenIdToFoundryId';
+    data: {
+        tokenId: TokenId;
+    };
+}
+
+export interface __FoundryIdToTokenIdMethod__ {
+    name: 'foundryIdToTokenId';
+    data: {
+        foundryId: FoundryId;
+    };
+}
+
+export interface __Bech32ToAliasIdMethod__ {
+    name: 'bech32ToAliasId';
+    data: {
+        bech32: Bech32Address;
+    };
+}
+
+export interface __Bech32ToNftIdMethod__ {
+    name: 'bech32ToNftId';
+    data: {
+        bech32: Bech32Address;
+    };
+}
+
 export interface __ParseBech32AddressMethod__ {
     name: 'parseBech32Address';
     data: {
@@ -186,6 +244,11 @@ export type __VerifyMnemonicMethod__ = {
     data: { mnemonic: string };
 };
 
+export type __VerifyMnemonicWordsMethod__ = {
+    name: 'verifyMnemonicWords';
+    data: { mnemonic: string; wordsWithPositions: [number, string][] };
+};
+
 export type __FaucetMethod__ = {
     name: 'faucet';
     data: {
@@ -214,6 +277,7 @@ export interface __VerifyTransactionSemantic__ {
         inputs: InputSigningData[];
         transaction: TransactionPayload;
         time: number;
+        protocolParameters?: INodeInfoProtocol;
     };
 }
 
@@ -225,6 +289,13 @@ export interface __VerifyTransactionSyntax__ {
     };
 }
 
+export interface __VerifyTransactionProof__ {
+    name: 'verifyTransactionProof';
+    data: {
+        proof: TransactionProof;
+    };
+}
+
 export interface __BlockBytes__ {
     name: 'blockBytes';
     data: {
@@ -238,3 +309,57 @@ export interface __BlockHashWithoutNonce__ {
         block: Block;
     };
 }
+
+export interface __PackOutput__ {
+    name: 'packOutput';
+    data: {
+        output: Output;
+        protocolParameters?: INodeInfoProtocol;
+    };
+}
+
+export interface __UnpackOutput__ {
+    name: 'unpackOutput';
+    data: {
+        bytes: HexEncodedString;
+        protocolParameters: INodeInfoProtocol;
+    };
+}
+
+export interface __PackBlock__ {
+    name: 'packBlock';
+    data: {
+        block: Block;
+        protocolParameters?: INodeInfoProtocol;
+    };
+}
+
+export interface __UnpackBlock__ {
+    name: 'unpackBlock';
+    data: {
+        bytes: HexEncodedString;
+        protocolParameters: INodeInfoProtocol;
+    };
+}
+
+export interface __NegotiateInterfaceVersion__ {
+    name: 'negotiateInterfaceVersion';
+    data: {
+        versions: number[];
+    };
+}
+
+export interface __StartDebugTrace__ {
+    name: 'startDebugTrace';
+    data: {
+        capacity?: number;
+    };
+}
+
+export interface __StopDebugTrace__ {
+    name: 'stopDebugTrace';
+}
+
+export interface __GetDebugTrace__ {
+    name: 'getDebugTrace';
+}
@@ -0,0 +1,16 @@
+// Copyright 2024 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+/** A method call recorded by the debug trace. */
+export interface MethodTrace {
+    /** The UNIX timestamp in milliseconds at which the method was called. */
+    timestamp: number;
+    /** The method with its parameters, secrets are omitted. */
+    method: string;
+    /** The execution time in milliseconds. */
+    duration: number;
+    /** The type of the response. */
+    response: string;
+    /** The error message, if the method failed or panicked. */
+    error?: string;
+}
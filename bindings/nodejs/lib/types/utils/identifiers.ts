@@ -0,0 +1,23 @@
+// Copyright 2024 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+import { AliasId } from '../block/id';
+import { TransactionId } from '../wallet/event';
+
+/** The parts of an output ID. */
+export interface OutputIdParts {
+    /** The ID of the transaction which created the output. */
+    transactionId: TransactionId;
+    /** The index of the output in the transaction. */
+    outputIndex: number;
+}
+
+/** The parts of a foundry ID. */
+export interface FoundryIdParts {
+    /** The ID of the alias which controls the foundry. */
+    aliasId: AliasId;
+    /** The serial number of the foundry. */
+    serialNumber: number;
+    /** The type of the token scheme of the foundry. */
+    tokenSchemeType: number;
+}
@@ -2,5 +2,7 @@
 // SPDX-License-Identifier: Apache-2.0
 
 export * from './bridge';
+export * from './debug-trace';
 export * from './hex-encoding';
+export * from './identifiers';
 export * from './numeric';
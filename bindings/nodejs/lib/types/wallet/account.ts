@@ -2,11 +2,11 @@
 // SPDX-License-Identifier: Apache-2.0
 
 import type { AccountAddress, AddressWithUnspentOutputs } from './address';
-import { AliasId, FoundryId, NftId } from '../block/id';
+import { AliasId, FoundryId, NftId, TokenId } from '../block/id';
 import type { OutputData } from './output';
 import type { Transaction } from './transaction';
 import { CoinType } from '../../client';
-import { HexEncodedString } from '../utils';
+import { HexEncodedString, NumericString } from '../utils';
 import { Bech32Address } from '../block/address';
 
 /**
@@ -34,6 +34,26 @@ export interface Balance {
      * TimelockUnlockCondition or ExpirationUnlockCondition this can change at any time
      */
     potentiallyLockedOutputs: { [outputId: string]: boolean };
+    /** The base coin amounts split by why they can or can't be spent right now */
+    breakdown: BalanceBreakdown;
+}
+
+/** The base coin amounts of a balance split by their spendability */
+export interface BalanceBreakdown {
+    /** The amount which can be spent right now, equal to `BaseCoinBalance.available` */
+    spendable: bigint;
+    /** The amount of outputs owned by the account, which are still timelocked */
+    timelocked: bigint;
+    /** The Unix timestamp in seconds at which the next timelock expires */
+    nextUnlock?: number;
+    /** The amount of unexpired outputs sent by the account which the recipient didn't claim yet */
+    expiringToUs: bigint;
+    /** The amount of outputs received with an expiration, which have to be claimed before they expire */
+    expiringAway: bigint;
+    /** The amount locked as storage deposit of outputs with native tokens, NFTs, aliases and foundries */
+    storageDepositLocked: bigint;
+    /** The amount of outputs used as inputs by pending transactions */
+    pendingReserved: bigint;
 }
 
 /** The balance of the base coin */
@@ -108,6 +128,39 @@ export interface SyncOptions {
     syncOnlyMostBasicOutputs?: boolean;
     /** Sync native token foundries, so their metadata can be returned in the balance. Default: false. */
     syncNativeTokenFoundries?: boolean;
+    /**
+     * Only request the outputs which changed since the previous sync of an address, based on the milestone at which it
+     * was synced. Falls back to a full sync for addresses which weren't synced with the same options before, or if too
+     * many milestones passed in between. Default: false.
+     */
+    incremental?: boolean;
+    /**
+     * Maximum amount of addresses, transactions and foundries which are synced at the same time. The amount of API
+     * requests sent at the same time is also limited by the `maxParallelApiRequests` of the client. Default: 500.
+     */
+    parallelRequests?: number;
+    /**
+     * Ignores basic outputs with native tokens which aren't accepted by the filter, e.g. airdropped spam tokens. Tracked
+     * outputs which are ignored after changing the filter are removed from the account.
+     */
+    nativeTokenFilter?: NativeTokenFilter;
+    /**
+     * Quarantine new outputs which look like spam: basic and NFT outputs with a dust amount, without a known sender
+     * and with links in their metadata or tag. Quarantined outputs aren't part of the balance and aren't used as
+     * inputs. Default: false.
+     */
+    quarantineSpamOutputs?: boolean;
+}
+
+/** Filter for the native tokens of the outputs received by an account. */
+export interface NativeTokenFilter {
+    /**
+     * `allowlist` tracks only outputs whose native tokens are all listed, `denylist` ignores outputs with a listed
+     * native token.
+     */
+    type: 'allowlist' | 'denylist';
+    /** The IDs of the native tokens. */
+    tokenIds: HexEncodedString[];
 }
 
 /** Specifies what outputs should be synced for the ed25519 addresses from the account. */
@@ -170,6 +223,10 @@ export interface AccountMeta {
     incomingTransactions: {
         [transactionId: string]: [Transaction];
     };
+    /** The ID of the alias output used as identity of the account. */
+    identityAlias?: AliasId;
+    /** The IDs of the transactions sent with an idempotency key. */
+    idempotencyKeys?: { [idempotencyKey: string]: string };
 }
 
 /** The account metadata. */
@@ -207,3 +264,44 @@ export interface FilterOptions {
     /** Return all NFT outputs matching these IDs. */
     nftIds?: NftId[];
 }
+
+/** Lower and upper bound of a milestone timestamp, both are inclusive. */
+export interface TimestampBounds {
+    lowerBound?: number;
+    upperBound?: number;
+}
+
+/** A composable filter to query the outputs of an account. */
+export type OutputFilter =
+    /** Matches if all of the filters match, an empty list matches every output. */
+    | { type: 'all'; value: OutputFilter[] }
+    /** Matches if any of the filters matches, an empty list matches no output. */
+    | { type: 'any'; value: OutputFilter[] }
+    /** Matches if the filter doesn't match. */
+    | { type: 'not'; value: OutputFilter }
+    /** Matches outputs of the provided types (Basic = 3, Alias = 4, Foundry = 5, NFT = 6). */
+    | { type: 'outputTypes'; value: number[] }
+    /** Matches outputs with at least this amount. */
+    | { type: 'minAmount'; value: NumericString }
+    /** Matches outputs with at most this amount. */
+    | { type: 'maxAmount'; value: NumericString }
+    /** Matches outputs holding the native token. */
+    | { type: 'nativeToken'; value: TokenId }
+    /** Matches alias outputs with one of these IDs. */
+    | { type: 'aliasIds'; value: AliasId[] }
+    /** Matches foundry outputs with one of these IDs. */
+    | { type: 'foundryIds'; value: FoundryId[] }
+    /** Matches NFT outputs with one of these IDs. */
+    | { type: 'nftIds'; value: NftId[] }
+    /** Matches outputs booked in a milestone with a timestamp within the bounds. */
+    | { type: 'bookedTimestamp'; value: TimestampBounds }
+    /** Matches unspent outputs. */
+    | { type: 'unspent' }
+    /** Matches outputs that can be unlocked by the account at the provided milestone timestamp. */
+    | { type: 'unlockableAt'; value: number }
+    /** Matches outputs with a metadata feature containing a JSON object with this key. */
+    | { type: 'metadataKey'; value: string }
+    /** Matches outputs with an expiration unlock condition expiring within the bounds. */
+    | { type: 'expiration'; value: TimestampBounds }
+    /** Matches outputs with a local annotation with this key and, if provided, this value. */
+    | { type: 'annotation'; value: { key: string; value?: string } };
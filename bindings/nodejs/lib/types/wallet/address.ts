@@ -25,13 +25,13 @@ export interface SendParams {
     /**
      * Bech32 encoded address, to which the storage deposit will be returned if one is necessary
      * given the provided amount. If a storage deposit is needed and a return address is not provided, it will
-     * default to the first address of the account.
+     * default to the one of the wallet's send defaults, or the first address of the account.
      */
     returnAddress?: string;
     /**
      * Expiration in seconds, after which the output will be available for the sender again, if not spent by the
      * receiver already. The expiration will only be used if one is necessary given the provided amount. If an
-     * expiration is needed but not provided, it will default to one day.
+     * expiration is needed but not provided, it will default to the one of the wallet's send defaults, or one day.
      */
     expiration?: number;
 }
@@ -56,14 +56,20 @@ export interface SendNativeTokensParams {
     nativeTokens: [TokenId, bigint][];
     /**
      * Bech32 encoded address, to which the storage deposit will be returned.
-     * Default will use the first address of the account.
+     * Default will use the one of the wallet's send defaults, or the first address of the account.
      */
     returnAddress?: Bech32Address;
     /**
      * Expiration in seconds, after which the output will be available for the sender again, if not spent by the
-     * receiver before. Default is 1 day.
+     * receiver before. Default is the one of the wallet's send defaults, or 1 day.
      */
     expiration?: number;
+    /**
+     * The maximum storage deposit which is returned to the sender, the rest of it is gifted to the recipient.
+     * If it's too low for a storage deposit return, the whole storage deposit is gifted and the output has no
+     * expiration. Default is to return the whole storage deposit.
+     */
+    maxStorageDepositReturn?: bigint | NumericString;
 }
 
 /** Address with an NftId */
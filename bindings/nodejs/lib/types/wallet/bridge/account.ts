@@ -1,7 +1,7 @@
 // Copyright 2023 IOTA Stiftung
 // SPDX-License-Identifier: Apache-2.0
 
-import type { SyncOptions, FilterOptions } from '../account';
+import type { SyncOptions, FilterOptions, OutputFilter } from '../account';
 import type {
     SendParams,
     SendNativeTokensParams,
@@ -30,7 +30,11 @@ import type {
     ParticipationEventType,
 } from '../participation';
 import type { ConsolidationParams } from '../consolidation-params';
+import type { SwapAcceptance, SwapAsset, SwapOffer } from '../swap';
+import type { VestingSchedule } from '../vesting';
 import {
+    AliasId,
+    Bech32Address,
     HexEncodedAmount,
     NumericString,
     Output,
@@ -67,6 +71,14 @@ export type __PrepareBurnMethod__ = {
     };
 };
 
+export type __PrepareBurnQuarantinedOutputsMethod__ = {
+    name: 'prepareBurnQuarantinedOutputs';
+    data: {
+        outputIds: OutputId[];
+        options?: TransactionOptions;
+    };
+};
+
 export type __PrepareClaimOutputsMethod__ = {
     name: 'prepareClaimOutputs';
     data: {
@@ -81,6 +93,64 @@ export type __ClaimOutputsMethod__ = {
     };
 };
 
+export type __CreateClaimLinkMethod__ = {
+    name: 'createClaimLink';
+    data: {
+        amount: NumericString;
+        expiration?: number;
+        options?: TransactionOptions;
+    };
+};
+
+export type __RedeemClaimLinkMethod__ = {
+    name: 'redeemClaimLink';
+    data: {
+        claimLink: string;
+    };
+};
+
+export type __CreateSwapOfferMethod__ = {
+    name: 'createSwapOffer';
+    data: {
+        offered: SwapAsset;
+        asked: SwapAsset;
+        expiration?: number;
+    };
+};
+
+export type __AcceptSwapOfferMethod__ = {
+    name: 'acceptSwapOffer';
+    data: {
+        offer: SwapOffer;
+    };
+};
+
+export type __CompleteSwapMethod__ = {
+    name: 'completeSwap';
+    data: {
+        offer: SwapOffer;
+        acceptance: SwapAcceptance;
+    };
+};
+
+export type __CancelSwapMethod__ = {
+    name: 'cancelSwap';
+    data: {
+        offer: SwapOffer;
+    };
+};
+
+export type __ChainGraphMethod__ = {
+    name: 'chainGraph';
+};
+
+export type __ConsolidationPreviewMethod__ = {
+    name: 'consolidationPreview';
+    data: {
+        params: ConsolidationParams;
+    };
+};
+
 export type __PrepareConsolidateOutputsMethod__ = {
     name: 'prepareConsolidateOutputs';
     data: {
@@ -138,6 +208,13 @@ export type __GetOutputMethod__ = {
     };
 };
 
+export type __GetOutputUnlockabilityMethod__ = {
+    name: 'getOutputUnlockability';
+    data: {
+        outputId: OutputId;
+    };
+};
+
 export type __GetFoundryOutputMethod__ = {
     name: 'getFoundryOutput';
     data: {
@@ -152,6 +229,13 @@ export type __ClaimableOutputsMethod__ = {
     };
 };
 
+export type __ExportTransactionProofMethod__ = {
+    name: 'exportTransactionProof';
+    data: {
+        transactionId: TransactionId;
+    };
+};
+
 export type __GetTransactionMethod__ = {
     name: 'getTransaction';
     data: {
@@ -210,6 +294,16 @@ export type __PrepareCreateNativeTokenMethod__ = {
     };
 };
 
+export type __PrepareCreateVestingMethod__ = {
+    name: 'prepareCreateVesting';
+    data: {
+        address: Bech32Address;
+        amount: NumericString;
+        schedule: VestingSchedule;
+        options?: TransactionOptions;
+    };
+};
+
 export type __PrepareMintNftsMethod__ = {
     name: 'prepareMintNfts';
     data: {
@@ -242,6 +336,46 @@ export type __PrepareTransactionMethod__ = {
     };
 };
 
+export type __QuarantinedOutputsMethod__ = {
+    name: 'quarantinedOutputs';
+};
+
+export type __QueryOutputsMethod__ = {
+    name: 'queryOutputs';
+    data: {
+        filter: OutputFilter;
+    };
+};
+
+export type __ReclaimableOutputsMethod__ = {
+    name: 'reclaimableOutputs';
+};
+
+export type __PrepareReclaimExpiredOutputsMethod__ = {
+    name: 'prepareReclaimExpiredOutputs';
+};
+
+export type __RebuildConflictingTransactionMethod__ = {
+    name: 'rebuildConflictingTransaction';
+    data: {
+        transactionId: TransactionId;
+    };
+};
+
+export type __RebuildLedgerFromHistoryMethod__ = {
+    name: 'rebuildLedgerFromHistory';
+    data: {
+        options?: SyncOptions;
+    };
+};
+
+export type __ReleaseQuarantinedOutputsMethod__ = {
+    name: 'releaseQuarantinedOutputs';
+    data: {
+        outputIds: OutputId[];
+    };
+};
+
 export type __RegisterParticipationEventsMethod__ = {
     name: 'registerParticipationEvents';
     data: {
@@ -249,6 +383,14 @@ export type __RegisterParticipationEventsMethod__ = {
     };
 };
 
+export type __RemoveOutputAnnotationMethod__ = {
+    name: 'removeOutputAnnotation';
+    data: {
+        outputId: OutputId;
+        key: string;
+    };
+};
+
 export type __RetryTransactionUntilIncludedMethod__ = {
     name: 'retryTransactionUntilIncluded';
     data: {
@@ -267,6 +409,22 @@ export type __SendMethod__ = {
     };
 };
 
+export type __BulkSendMethod__ = {
+    name: 'bulkSend';
+    data: {
+        params: SendParams[];
+        options?: TransactionOptions;
+    };
+};
+
+export type __DistributeNftsMethod__ = {
+    name: 'distributeNfts';
+    data: {
+        params: SendNftParams[];
+        options?: TransactionOptions;
+    };
+};
+
 export type __SendWithParamsMethod__ = {
     name: 'sendWithParams';
     data: {
@@ -283,6 +441,14 @@ export type __PrepareSendNativeTokensMethod__ = {
     };
 };
 
+export type __SendNativeTokensWithStorageDepositsMethod__ = {
+    name: 'sendNativeTokensWithStorageDeposits';
+    data: {
+        params: SendNativeTokensParams[];
+        options?: TransactionOptions;
+    };
+};
+
 export type __PrepareSendNftMethod__ = {
     name: 'prepareSendNft';
     data: {
@@ -313,6 +479,30 @@ export type __SetDefaultSyncOptionsMethod__ = {
     };
 };
 
+export type __SetOutputAnnotationMethod__ = {
+    name: 'setOutputAnnotation';
+    data: {
+        outputId: OutputId;
+        key: string;
+        value: string;
+    };
+};
+
+export type __SetIdentityAliasMethod__ = {
+    name: 'setIdentityAlias';
+    data: {
+        aliasId?: AliasId;
+    };
+};
+
+export type __VerifyAddressOnDeviceMethod__ = {
+    name: 'verifyAddressOnDevice';
+    data: {
+        addressIndex: number;
+        internal: boolean;
+    };
+};
+
 export type __SignTransactionEssenceMethod__ = {
     name: 'signTransactionEssence';
     data: {
@@ -402,3 +592,7 @@ export type __PrepareDecreaseVotingPowerMethod__ = {
         amount: NumericString;
     };
 };
+
+export type __VestingStatusMethod__ = {
+    name: 'vestingStatus';
+};
@@ -5,14 +5,24 @@ import type {
     __BuildFoundryOutputMethod__,
     __BuildNftOutputMethod__,
     __PrepareBurnMethod__,
+    __PrepareBurnQuarantinedOutputsMethod__,
     __PrepareClaimOutputsMethod__,
     __ClaimOutputsMethod__,
+    __CreateClaimLinkMethod__,
+    __RedeemClaimLinkMethod__,
+    __CreateSwapOfferMethod__,
+    __AcceptSwapOfferMethod__,
+    __CompleteSwapMethod__,
+    __CancelSwapMethod__,
+    __ChainGraphMethod__,
+    __ConsolidationPreviewMethod__,
     __PrepareConsolidateOutputsMethod__,
     __PrepareCreateAliasOutputMethod__,
     __DeregisterParticipationEventMethod__,
     __GenerateEd25519AddressesMethod__,
     __GetBalanceMethod__,
     __GetOutputMethod__,
+    __GetOutputUnlockabilityMethod__,
     __GetFoundryOutputMethod__,
     __ClaimableOutputsMethod__,
     __GetTransactionMethod__,
@@ -24,27 +34,43 @@ import type {
     __TransactionsMethod__,
     __UnspentOutputsMethod__,
     __PrepareCreateNativeTokenMethod__,
+    __PrepareCreateVestingMethod__,
     __PrepareMeltNativeTokenMethod__,
     __PrepareMintNativeTokenMethod__,
     __PrepareMintNftsMethod__,
     __PrepareOutputMethod__,
     __PrepareSendMethod__,
     __PrepareTransactionMethod__,
+    __QuarantinedOutputsMethod__,
+    __QueryOutputsMethod__,
+    __ReclaimableOutputsMethod__,
+    __PrepareReclaimExpiredOutputsMethod__,
+    __RebuildConflictingTransactionMethod__,
+    __RebuildLedgerFromHistoryMethod__,
+    __ReleaseQuarantinedOutputsMethod__,
     __RegisterParticipationEventsMethod__,
+    __RemoveOutputAnnotationMethod__,
     __RetryTransactionUntilIncludedMethod__,
     __SendMethod__,
     __SendWithParamsMethod__,
+    __BulkSendMethod__,
+    __DistributeNftsMethod__,
     __PrepareSendNativeTokensMethod__,
+    __SendNativeTokensWithStorageDepositsMethod__,
     __PrepareSendNftMethod__,
     __SendOutputsMethod__,
     __SetAliasMethod__,
     __SetDefaultSyncOptionsMethod__,
+    __SetIdentityAliasMethod__,
+    __SetOutputAnnotationMethod__,
     __SignTransactionEssenceMethod__,
     __SignAndSubmitTransactionMethod__,
     __SubmitAndStoreTransactionMethod__,
     __SyncAccountMethod__,
+    __VerifyAddressOnDeviceMethod__,
     __PrepareVoteMethod__,
     __GetIncomingTransactionMethod__,
+    __ExportTransactionProofMethod__,
     __GetParticipationOverviewMethod__,
     __GetParticipationEventMethod__,
     __GetParticipationEventsMethod__,
@@ -53,12 +79,15 @@ import type {
     __PrepareIncreaseVotingPowerMethod__,
     __PrepareDecreaseVotingPowerMethod__,
     __PrepareStopParticipatingMethod__,
+    __VestingStatusMethod__,
 } from './account';
 import type {
     __BackupMethod__,
     __ChangeStrongholdPasswordMethod__,
     __ClearStrongholdPasswordMethod__,
     __ClearListenersMethod__,
+    __RemoveListenerMethod__,
+    __ListenerCountsMethod__,
     __CreateAccountMethod__,
     __EmitTestEventMethod__,
     __GenerateMnemonicMethod__,
@@ -66,6 +95,10 @@ import type {
     __GetAccountIndexesMethod__,
     __GetAccountsMethod__,
     __GetChrysalisDataMethod__,
+    __GetSendDefaultsMethod__,
+    __PrivacyReportMethod__,
+    __DumpStateJsonMethod__,
+    __LoadStateJsonMethod__,
     __GetLedgerNanoStatusMethod__,
     __GenerateEd25519AddressMethod__,
     __IsStrongholdPasswordAvailableMethod__,
@@ -73,10 +106,12 @@ import type {
     __RemoveLatestAccountMethod__,
     __RestoreBackupMethod__,
     __SetClientOptionsMethod__,
+    __SetSendDefaultsMethod__,
     __SetStrongholdPasswordClearIntervalMethod__,
     __SetStrongholdPasswordMethod__,
     __StartBackgroundSyncMethod__,
     __StopBackgroundSyncMethod__,
+    __ShutdownMethod__,
     __StoreMnemonicMethod__,
     __UpdateNodeAuthMethod__,
 } from './wallet';
@@ -87,15 +122,26 @@ export type __AccountMethod__ =
     | __BuildFoundryOutputMethod__
     | __BuildNftOutputMethod__
     | __PrepareBurnMethod__
+    | __PrepareBurnQuarantinedOutputsMethod__
     | __ClaimOutputsMethod__
+    | __CreateClaimLinkMethod__
+    | __RedeemClaimLinkMethod__
+    | __CreateSwapOfferMethod__
+    | __AcceptSwapOfferMethod__
+    | __CompleteSwapMethod__
+    | __CancelSwapMethod__
     | __PrepareClaimOutputsMethod__
+    | __ChainGraphMethod__
+    | __ConsolidationPreviewMethod__
     | __PrepareConsolidateOutputsMethod__
     | __PrepareCreateAliasOutputMethod__
     | __DeregisterParticipationEventMethod__
     | __GenerateEd25519AddressesMethod__
     | __GetBalanceMethod__
     | __GetOutputMethod__
+    | __GetOutputUnlockabilityMethod__
     | __GetIncomingTransactionMethod__
+    | __ExportTransactionProofMethod__
     | __GetFoundryOutputMethod__
     | __ClaimableOutputsMethod__
     | __GetParticipationEventMethod__
@@ -111,30 +157,46 @@ export type __AccountMethod__ =
     | __TransactionsMethod__
     | __UnspentOutputsMethod__
     | __PrepareCreateNativeTokenMethod__
+    | __PrepareCreateVestingMethod__
     | __PrepareMeltNativeTokenMethod__
     | __PrepareMintNativeTokenMethod__
     | __PrepareMintNftsMethod__
     | __PrepareOutputMethod__
     | __PrepareSendMethod__
     | __PrepareTransactionMethod__
+    | __QuarantinedOutputsMethod__
+    | __QueryOutputsMethod__
+    | __ReclaimableOutputsMethod__
+    | __PrepareReclaimExpiredOutputsMethod__
+    | __RebuildConflictingTransactionMethod__
+    | __RebuildLedgerFromHistoryMethod__
+    | __ReleaseQuarantinedOutputsMethod__
     | __RegisterParticipationEventsMethod__
+    | __RemoveOutputAnnotationMethod__
     | __RetryTransactionUntilIncludedMethod__
     | __SendMethod__
     | __SendWithParamsMethod__
+    | __BulkSendMethod__
+    | __DistributeNftsMethod__
     | __PrepareSendNativeTokensMethod__
+    | __SendNativeTokensWithStorageDepositsMethod__
     | __PrepareSendNftMethod__
     | __SendOutputsMethod__
     | __SetAliasMethod__
     | __SetDefaultSyncOptionsMethod__
+    | __SetIdentityAliasMethod__
+    | __SetOutputAnnotationMethod__
     | __SignTransactionEssenceMethod__
     | __SignAndSubmitTransactionMethod__
     | __SubmitAndStoreTransactionMethod__
     | __SyncAccountMethod__
+    | __VerifyAddressOnDeviceMethod__
     | __PrepareVoteMethod__
     | __PrepareStopParticipatingMethod__
     | __GetParticipationOverviewMethod__
     | __PrepareIncreaseVotingPowerMethod__
-    | __PrepareDecreaseVotingPowerMethod__;
+    | __PrepareDecreaseVotingPowerMethod__
+    | __VestingStatusMethod__;
 
 export type __CallAccountMethodMethod__ = {
     name: 'callAccountMethod';
@@ -149,6 +211,8 @@ export type __Method__ =
     | __CallAccountMethodMethod__
     | __ChangeStrongholdPasswordMethod__
     | __ClearListenersMethod__
+    | __RemoveListenerMethod__
+    | __ListenerCountsMethod__
     | __ClearStrongholdPasswordMethod__
     | __CreateAccountMethod__
     | __EmitTestEventMethod__
@@ -157,6 +221,10 @@ export type __Method__ =
     | __GetAccountIndexesMethod__
     | __GetAccountsMethod__
     | __GetChrysalisDataMethod__
+    | __GetSendDefaultsMethod__
+    | __PrivacyReportMethod__
+    | __DumpStateJsonMethod__
+    | __LoadStateJsonMethod__
     | __GetLedgerNanoStatusMethod__
     | __GenerateEd25519AddressMethod__
     | __IsStrongholdPasswordAvailableMethod__
@@ -164,9 +232,11 @@ export type __Method__ =
     | __RemoveLatestAccountMethod__
     | __RestoreBackupMethod__
     | __SetClientOptionsMethod__
+    | __SetSendDefaultsMethod__
     | __SetStrongholdPasswordClearIntervalMethod__
     | __SetStrongholdPasswordMethod__
     | __StartBackgroundSyncMethod__
     | __StopBackgroundSyncMethod__
+    | __ShutdownMethod__
     | __StoreMnemonicMethod__
     | __UpdateNodeAuthMethod__;
@@ -2,6 +2,7 @@ import type { AccountId, CreateAccountPayload, SyncOptions } from '../account';
 import type { GenerateAddressOptions } from '../address';
 import type { WalletEventType, WalletEvent } from '../event';
 import type { IAuth, IClientOptions } from '../../client';
+import type { SendDefaults } from '../wallet';
 
 export type __BackupMethod__ = {
     name: 'backup';
@@ -28,6 +29,15 @@ export type __ClearListenersMethod__ = {
     data: { eventTypes: WalletEventType[] };
 };
 
+export type __RemoveListenerMethod__ = {
+    name: 'removeListener';
+    data: { listenerId: number };
+};
+
+export type __ListenerCountsMethod__ = {
+    name: 'listenerCounts';
+};
+
 export type __CreateAccountMethod__ = {
     name: 'createAccount';
     data: CreateAccountPayload;
@@ -59,6 +69,25 @@ export type __GetChrysalisDataMethod__ = {
     name: 'getChrysalisData';
 };
 
+export type __GetSendDefaultsMethod__ = {
+    name: 'getSendDefaults';
+};
+
+export type __PrivacyReportMethod__ = {
+    name: 'privacyReport';
+};
+
+export type __DumpStateJsonMethod__ = {
+    name: 'dumpStateJson';
+};
+
+export type __LoadStateJsonMethod__ = {
+    name: 'loadStateJson';
+    data: {
+        state: object;
+    };
+};
+
 export type __GetLedgerNanoStatusMethod__ = {
     name: 'getLedgerNanoStatus';
 };
@@ -106,6 +135,11 @@ export type __SetClientOptionsMethod__ = {
     data: { clientOptions: IClientOptions };
 };
 
+export type __SetSendDefaultsMethod__ = {
+    name: 'setSendDefaults';
+    data: { sendDefaults: SendDefaults };
+};
+
 export type __SetStrongholdPasswordMethod__ = {
     name: 'setStrongholdPassword';
     data: { password: string };
@@ -128,9 +162,13 @@ export type __StopBackgroundSyncMethod__ = {
     name: 'stopBackgroundSync';
 };
 
+export type __ShutdownMethod__ = {
+    name: 'shutdown';
+};
+
 export type __StoreMnemonicMethod__ = {
     name: 'storeMnemonic';
-    data: { mnemonic: string };
+    data: { mnemonic: string; passphrase?: string };
 };
 
 export type __UpdateNodeAuthMethod__ = {
@@ -0,0 +1,42 @@
+// Copyright 2024 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+import type { OutputId } from '../block';
+import type { HexEncodedString, NumericString } from '../utils';
+
+/** The kind of an output in a chain graph */
+export type ChainNodeKind = 'alias' | 'foundry' | 'nft' | 'basic';
+
+/** How the `owner` output of a chain edge is related to the `dependent` output */
+export type ChainRelation =
+    | 'controlsFoundry'
+    | 'owns'
+    | 'stateController'
+    | 'governor';
+
+/** An unspent output of the account in a chain graph */
+export interface ChainNode {
+    /** The output ID */
+    outputId: OutputId;
+    /** The kind of the output, basic outputs are only included if they're owned by an alias or NFT */
+    kind: ChainNodeKind;
+    /** The alias, foundry or NFT ID keyed by its kind, e.g. `{ "Alias": "0x..." }`, not set for basic outputs */
+    chainId?: { [kind: string]: HexEncodedString };
+    /** The amount of the output */
+    amount: NumericString;
+}
+
+/** A dependency between two outputs, the `dependent` output can only be unlocked with the `owner` output */
+export interface ChainEdge {
+    /** The alias or NFT output the other output depends on */
+    owner: OutputId;
+    /** The foundry or output owned by the alias or NFT address */
+    dependent: OutputId;
+    relation: ChainRelation;
+}
+
+/** The dependencies between the unspent alias, foundry and NFT outputs of an account and the outputs they own */
+export interface ChainGraph {
+    nodes: ChainNode[];
+    edges: ChainEdge[];
+}
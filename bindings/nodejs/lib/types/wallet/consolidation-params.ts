@@ -1,6 +1,8 @@
 // Copyright 2023 IOTA Stiftung
 // SPDX-License-Identifier: Apache-2.0
 
+import type { NumericString } from '../utils';
+
 /** Parameters for consolidation */
 export interface ConsolidationParams {
     /** Ignores the output threshold if set to `true`. */
@@ -10,3 +12,25 @@ export interface ConsolidationParams {
     /** Address to which the consolidated output should be sent. */
     targetAddress?: string;
 }
+
+/** What consolidating outputs with the same params would do, without creating a transaction */
+export interface ConsolidationPreview {
+    /** The number of outputs which can be consolidated now */
+    consolidatableOutputs: number;
+    /** The output count from which outputs are consolidated, unless forced */
+    outputThreshold: number;
+    /** Whether outputs would be consolidated with the params */
+    wouldConsolidate: boolean;
+    /** The number of outputs consumed by the next consolidation transaction */
+    nextTransactionInputs: number;
+    /** The number of transactions needed to consolidate all consolidatable outputs */
+    transactionsNeeded: number;
+    /** The number of unspent outputs of the account after all consolidation transactions */
+    resultingOutputCount: number;
+    /** The base coin amount of the consolidatable outputs */
+    amount: NumericString;
+    /** The storage deposit of the consolidatable outputs */
+    storageDepositBefore: NumericString;
+    /** The storage deposit of the outputs created by the consolidation transactions */
+    storageDepositAfter: NumericString;
+}
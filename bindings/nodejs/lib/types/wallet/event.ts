@@ -4,7 +4,8 @@
 import type { OutputData } from './output';
 import { InclusionState } from './transaction';
 import { InputSigningData, Remainder } from '../client';
-import { TransactionEssence, TransactionPayload } from '../block';
+import { Output, TransactionEssence, TransactionPayload } from '../block';
+import type { BlockId } from '../block/id';
 import { OutputResponse } from '../models';
 
 /**
@@ -51,6 +52,10 @@ enum WalletEventType {
     TransactionInclusion = 4,
     /** A progress update while submitting a transaction. */
     TransactionProgress = 5,
+    /** A pending transaction was reissued by the inclusion monitor. */
+    TransactionReissue = 6,
+    /** A progress update while rebuilding the ledger of an account from the node. */
+    LedgerRebuild = 7,
 }
 
 /**
@@ -167,6 +172,8 @@ enum TransactionProgressType {
     PerformingPow = 5,
     /** Broadcasting. */
     Broadcasting = 6,
+    /** Adding the output paying the service fee of the wallet. */
+    AddingServiceFee = 7,
 }
 
 /**
@@ -184,6 +191,64 @@ class TransactionProgressWalletEvent extends WalletEvent {
     }
 }
 
+/**
+ * The state of a pending transaction that is handled by the inclusion monitor, `reissued` when its payload was
+ * reissued in a new block, `givenUp` when it still wasn't included after the maximum number of reissues.
+ */
+type TransactionReissueState =
+    | { state: 'reissued'; blockId: BlockId; attempt: number }
+    | { state: 'givenUp' };
+
+/**
+ * A 'transaction reissue' wallet event.
+ */
+class TransactionReissueWalletEvent extends WalletEvent {
+    transactionId: TransactionId;
+    reissueState: TransactionReissueState;
+
+    /**
+     * @param transactionId The transaction ID.
+     * @param reissueState The reissue state of the transaction.
+     */
+    constructor(
+        transactionId: TransactionId,
+        reissueState: TransactionReissueState,
+    ) {
+        super(WalletEventType.TransactionReissue);
+        this.transactionId = transactionId;
+        this.reissueState = reissueState;
+    }
+}
+
+/**
+ * A 'ledger rebuild' wallet event.
+ */
+class LedgerRebuildWalletEvent extends WalletEvent {
+    state: 'cleared' | 'finished';
+    unspentOutputs?: number;
+    transactions?: number;
+    incomingTransactions?: number;
+
+    /**
+     * @param state `cleared` once the local ledger is discarded, `finished` once it is synced again.
+     * @param unspentOutputs The number of found unspent outputs, only when finished.
+     * @param transactions The number of found sent transactions, only when finished.
+     * @param incomingTransactions The number of found incoming transactions, only when finished.
+     */
+    constructor(
+        state: 'cleared' | 'finished',
+        unspentOutputs?: number,
+        transactions?: number,
+        incomingTransactions?: number,
+    ) {
+        super(WalletEventType.LedgerRebuild);
+        this.state = state;
+        this.unspentOutputs = unspentOutputs;
+        this.transactions = transactions;
+        this.incomingTransactions = incomingTransactions;
+    }
+}
+
 /**
  * The base class for transaction progresses.
  */
@@ -289,6 +354,21 @@ class BroadcastingProgress extends TransactionProgress {
     }
 }
 
+/**
+ * An 'adding service fee' progress.
+ */
+class AddingServiceFeeProgress extends TransactionProgress {
+    output: Output;
+
+    /**
+     * @param output The output paying the service fee.
+     */
+    constructor(output: Output) {
+        super(TransactionProgressType.AddingServiceFee);
+        this.output = output;
+    }
+}
+
 export {
     Event,
     WalletEventType,
@@ -299,6 +379,9 @@ export {
     SpentOutputWalletEvent,
     TransactionInclusionWalletEvent,
     TransactionProgressWalletEvent,
+    TransactionReissueWalletEvent,
+    TransactionReissueState,
+    LedgerRebuildWalletEvent,
     TransactionProgress,
     SelectingInputsProgress,
     GeneratingRemainderDepositAddressProgress,
@@ -307,5 +390,6 @@ export {
     SigningTransactionProgress,
     PerformingPowProgress,
     BroadcastingProgress,
+    AddingServiceFeeProgress,
     TransactionProgressType,
 };
@@ -6,14 +6,18 @@ export * from './wallet';
 export * from './address';
 export * from './bridge';
 export * from './build-output-data';
+export * from './chain-graph';
 export * from './event';
+export * from './listener-handle';
 export * from './output';
 export * from './output-params';
 export * from './participation';
 export * from './prepared-create-token-transaction';
 export * from './prepared-transaction';
 export * from './signed-transaction-essence';
+export * from './swap';
 export * from './transaction';
 export * from './transaction-options';
+export * from './vesting';
 export * from './prepared-create-token-transaction-data';
 export * from './consolidation-params';
@@ -0,0 +1,31 @@
+// Copyright 2024 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+import type { Wallet } from '../..';
+
+/**
+ * A handle to a listener registered with `Wallet.listen()`, with which it can be removed again.
+ */
+export class ListenerHandle {
+    /** The id of the listener. */
+    readonly id: number;
+    private readonly _wallet: Wallet;
+
+    /**
+     * @param id The id of the listener.
+     * @param wallet The wallet the listener is registered with.
+     */
+    constructor(id: number, wallet: Wallet) {
+        this.id = id;
+        this._wallet = wallet;
+    }
+
+    /**
+     * Remove the listener for all its event types, so its callback can be garbage collected.
+     *
+     * @returns `false` if the listener was already removed.
+     */
+    async unsubscribe(): Promise<boolean> {
+        return this._wallet.removeListener(this.id);
+    }
+}
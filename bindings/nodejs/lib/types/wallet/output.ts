@@ -2,9 +2,10 @@
 // SPDX-License-Identifier: Apache-2.0
 
 import { Type } from 'class-transformer';
-import { Address, AddressDiscriminator } from '../block/address';
+import { Address, AddressDiscriminator, Bech32Address } from '../block/address';
 import { Output, OutputDiscriminator, OutputId } from '../block/output';
 import { IOutputMetadataResponse } from '../models/api';
+import { NumericString } from '../utils';
 
 /** Output to claim */
 export enum OutputsToClaim {
@@ -39,8 +40,29 @@ export class OutputData {
     remainder!: boolean;
     /** BIP32 path */
     chain?: Segment[];
+    /** Local annotations set by the user, they aren't part of the output on the ledger */
+    annotations?: { [key: string]: string };
 }
 
+/** Whether an output of an account can currently be unlocked, and if not, why not */
+export type Unlockability =
+    /** The output is already spent */
+    | { type: 'spent' }
+    /** The output can be unlocked by the account */
+    | {
+          type: 'unlockable';
+          /** The timestamp from which on the output can only be unlocked by its return address, if it isn't owned by the account anymore then */
+          expiresAt?: number;
+          /** The amount which has to be returned with a storage deposit return output */
+          storageDepositReturn?: NumericString;
+      }
+    /** The output is timelocked */
+    | { type: 'timelockedUntil'; timestamp: number }
+    /** The output expired and can now only be unlocked by its return address, which isn't owned by the account */
+    | { type: 'expired'; timestamp: number; returnAddress: Bech32Address }
+    /** The output has to be unlocked by an address which isn't owned by the account */
+    | { type: 'requiresUnlockBy'; address: Bech32Address };
+
 /** A Segment of the BIP32 path*/
 export interface Segment {
     /** Whether the segment is hardened. */
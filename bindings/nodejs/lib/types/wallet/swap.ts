@@ -0,0 +1,61 @@
+// Copyright 2024 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+import type {
+    Bech32Address,
+    NftId,
+    TokenId,
+    TransactionEssence,
+    Unlock,
+} from '../block';
+import type { OutputResponse } from '../models/api';
+import type { HexEncodedAmount, NumericString } from '../utils';
+
+/** An asset which is exchanged in a swap */
+export type SwapAsset =
+    | {
+          type: 'baseCoin';
+          /** The amount of base coins */
+          amount: NumericString;
+      }
+    | {
+          type: 'nativeToken';
+          /** The ID of the native token */
+          tokenId: TokenId;
+          /** The amount of the native token */
+          amount: HexEncodedAmount;
+      }
+    | {
+          type: 'nft';
+          /** The ID of the NFT, which is transferred with its storage deposit */
+          nftId: NftId;
+      };
+
+/**
+ * An offer to swap assets, which is sent to the taker out-of-band as JSON.
+ * It has to be passed back unchanged, so it's not converted to class instances.
+ */
+export interface SwapOffer {
+    /** The asset the maker gives */
+    offered: SwapAsset;
+    /** The asset the maker asks for in return */
+    asked: SwapAsset;
+    /** The address of the maker, which receives the asked asset and the rest of its inputs */
+    address: Bech32Address;
+    /** The outputs of the maker which are consumed by the swap */
+    inputs: OutputResponse[];
+    /** Unix timestamp in seconds after which the maker doesn't complete the swap anymore */
+    expiration: number;
+}
+
+/** The transaction of an accepted swap offer, with the unlocks of the taker */
+export interface SwapAcceptance {
+    /** The accepted offer */
+    offer: SwapOffer;
+    /** The transaction, which consumes the inputs of the offer followed by the inputs of the taker */
+    essence: TransactionEssence;
+    /** The outputs of the taker which are consumed by the swap */
+    inputs: OutputResponse[];
+    /** The unlocks of the inputs of the taker */
+    unlocks: Unlock[];
+}
@@ -27,8 +27,23 @@ export interface TransactionOptions {
     note?: string;
     /** Whether to allow sending a micro amount. */
     allowMicroAmount?: boolean;
+    /** The strategy applied for the automatic selection of inputs. */
+    coinSelectionStrategy?: CoinSelectionStrategy;
+    /**
+     * A key to send the transaction only once: if a transaction was already sent with the same key, it's returned
+     * instead of sending a new one. A key is removed when syncing seven days after its transaction was sent, if the
+     * transaction is confirmed or conflicting by then.
+     */
+    idempotencyKey?: string;
 }
 
+/**
+ * The possible coin selection strategies.
+ * `SingleAddressOnly` only selects inputs of a single address, so a transaction doesn't link multiple addresses of
+ * the account. Explicitly provided custom inputs are allowed to belong to distinct addresses.
+ */
+export type CoinSelectionStrategy = 'AnyAddress' | 'SingleAddressOnly';
+
 /** The possible remainder value strategies. */
 export type RemainderValueStrategy =
     | ChangeAddress
@@ -4,6 +4,9 @@
 import { Type } from 'class-transformer';
 import { TransactionPayload } from '../block/payload/transaction';
 import { OutputResponse } from '../models/api';
+import type { TransactionId } from './event';
+import type { BlockId } from '../block/id';
+import type { NumericString } from '../utils';
 
 /** Possible InclusionStates of transactions sent with the wallet */
 export enum InclusionState {
@@ -43,3 +46,61 @@ export class Transaction {
     @Type(() => OutputResponse)
     inputs!: OutputResponse[];
 }
+
+/** The result of creating a claim link */
+export class ClaimLinkTransaction {
+    /** The encoded claim link, anyone knowing it can claim the funds */
+    claimLink!: string;
+    /** The transaction sending the funds to the one-time key of the claim link */
+    @Type(() => Transaction)
+    transaction!: Transaction;
+}
+
+/** A proof of a confirmed transaction, which can be verified without access to the wallet which sent it */
+export class TransactionProof {
+    /** The signed transaction */
+    @Type(() => TransactionPayload)
+    transaction!: TransactionPayload;
+    /** The outputs consumed by the transaction in the order of its inputs, with their metadata */
+    @Type(() => OutputResponse)
+    inputs!: OutputResponse[];
+    /** The block which included the transaction */
+    blockId!: BlockId;
+    /** The index of the milestone which confirmed the transaction */
+    milestoneIndex!: number;
+    /** The timestamp of the milestone which confirmed the transaction */
+    milestoneTimestamp!: number;
+}
+
+/** How the storage deposit of an output sending native tokens was funded */
+export interface StorageDepositChoice {
+    /**
+     * `return` if the storage deposit is returned to the sender, except the part above the maximum,
+     * `gift` if all of it is gifted.
+     */
+    type: 'return' | 'gift';
+    /** The storage deposit returned to the sender */
+    returned?: NumericString;
+    /** The storage deposit gifted to the recipient */
+    gifted: NumericString;
+}
+
+/** The result of sending native tokens with the chosen storage deposits */
+export class NativeTokensTransaction {
+    /** The transaction sending the native tokens */
+    @Type(() => Transaction)
+    transaction!: Transaction;
+    /** How the storage deposit of each output was funded, in the order of the params */
+    storageDeposits!: StorageDepositChoice[];
+}
+
+/** The result of sending to many recipients in multiple transactions */
+export interface BulkSendReport {
+    /** The IDs of the sent transactions, in the order they were sent */
+    transactionIds: TransactionId[];
+    /**
+     * The ID of the transaction sending to each recipient, in the order of the provided params.
+     * Shorter than the params if not all recipients were sent to.
+     */
+    recipients: TransactionId[];
+}
@@ -0,0 +1,42 @@
+// Copyright 2024 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+import type { OutputId } from '../block';
+import type { NumericString } from '../utils';
+import type { TransactionId } from './event';
+
+/** The schedule after which vested funds are released: nothing until the cliff, then an equal part every period */
+export interface VestingSchedule {
+    /** Unix timestamp in seconds at which the vesting starts, the current time if not set */
+    start?: number;
+    /** Seconds from the start until the first release */
+    cliff: number;
+    /** Seconds between two releases */
+    period: number;
+    /** The number of releases, including the one at the cliff */
+    releases: number;
+}
+
+/** A release of vested funds */
+export interface VestingRelease {
+    /** The output holding the funds */
+    outputId: OutputId;
+    /** The released amount */
+    amount: NumericString;
+    /** Unix timestamp in seconds at which the funds are released */
+    unlockTime: number;
+}
+
+/** The unspent vesting outputs of an account which were created in the same transaction */
+export interface VestingStatus {
+    /** The transaction which created the vesting outputs */
+    transactionId: TransactionId;
+    /** The releases, ordered by their unlock time */
+    releases: VestingRelease[];
+    /** The amount which is released, but not spent yet */
+    releasedAmount: NumericString;
+    /** The amount which is still locked */
+    lockedAmount: NumericString;
+    /** Unix timestamp in seconds of the next release, if any funds are still locked */
+    nextRelease?: number;
+}
@@ -1,5 +1,9 @@
 import { IClientOptions, CoinType } from '../client';
 import { SecretManagerType } from '../secret_manager/secret-manager';
+import { Bech32Address } from '../block/address';
+import { TokenId } from '../block/id';
+import { TransactionId } from './event';
+import { HexEncodedAmount, NumericString } from '../utils';
 
 /** Options for the Wallet builder. */
 export interface WalletOptions {
@@ -11,4 +15,79 @@ export interface WalletOptions {
     coinType?: CoinType;
     /** The secret manager to use. */
     secretManager?: SecretManagerType;
+    /** The time in seconds after which received outputs are selected as inputs, counted from their booking milestone. */
+    outputMaturity?: number;
+    /** A fee added as an additional output to every transaction sending funds out of an account. */
+    serviceFee?: ServiceFee;
+    /** How the wallet reacts if the network of the node doesn't match the coin type or the addresses of its accounts, `warn` by default. */
+    networkValidation?: NetworkValidation;
+    /** Defaults for the return address and expiration of outputs with a storage deposit return, stored with the wallet. */
+    sendDefaults?: SendDefaults;
+    /** Bind the wallet to the single account with this account index, to host wallets of multiple users off one seed. */
+    accountIndex?: number;
 }
+
+/** Wallet-wide defaults for the storage deposit return and expiration of outputs whose amount doesn't cover their storage deposit. */
+export interface SendDefaults {
+    /** The address to which the storage deposit is returned, the first address of the sending account if not set. */
+    returnAddress?: Bech32Address;
+    /** The expiration in seconds, after which the output is available for the sender again, one day if not set. */
+    expiration?: number;
+}
+
+/** How the wallet reacts if the network of the node doesn't match its accounts. */
+export type NetworkValidation =
+    /** Fail with an error. */
+    | 'strict'
+    /** Log a warning and continue. */
+    | 'warn'
+    /** Don't validate the network. */
+    | 'disabled';
+
+/** A fee paid with every transaction of a wallet, e.g. to a wallet-as-a-service provider. */
+export interface ServiceFee {
+    /** The address receiving the fee. */
+    address: Bech32Address;
+    /** The amount of the fee. */
+    amount: ServiceFeeAmount;
+    /** The native token in which the fee is paid, base coins if not set. */
+    tokenId?: TokenId;
+}
+
+/** The amount of a service fee. */
+export type ServiceFeeAmount =
+    /** A fixed amount per transaction. */
+    | { type: 'fixed'; value: HexEncodedAmount }
+    /** A share of the amount sent with the transaction, in basis points: 100 are 1%. */
+    | { type: 'basisPoints'; value: number };
+
+/** Privacy issues found in the stored transactions of a wallet. */
+export interface PrivacyReport {
+    /** The found issues, ordered by account. */
+    warnings: PrivacyWarning[];
+}
+
+/** A privacy issue found in the stored transactions of an account. */
+export type PrivacyWarning =
+    /** An address received outputs in multiple transactions, which links these transactions. */
+    | {
+          type: 'addressReuse';
+          accountIndex: number;
+          address: Bech32Address;
+          transactionIds: TransactionId[];
+      }
+    /** A transaction sent a round amount and returned a remainder which isn't round, which reveals the remainder. */
+    | {
+          type: 'roundAmount';
+          accountIndex: number;
+          transactionId: TransactionId;
+          outputIndex: number;
+          amount: NumericString;
+      }
+    /** Outputs of multiple addresses were spent in the same transactions, which links these addresses. */
+    | {
+          type: 'coSpendCluster';
+          accountIndex: number;
+          addresses: Bech32Address[];
+          transactionIds: TransactionId[];
+      };
@@ -19,9 +19,15 @@ import {
     Bech32Address,
     InputSigningData,
     INodeInfoProtocol,
+    TransactionProof,
+    MethodTrace,
+    OutputIdParts,
+    FoundryIdParts,
 } from '../types';
 import { AliasId, BlockId, FoundryId, NftId, TokenId } from '../types/block/id';
 
+import { plainToInstance } from 'class-transformer';
+
 /** Utils class for utils. */
 export class Utils {
     /**
@@ -151,6 +157,43 @@ export class Utils {
         return BigInt(minStorageDepositAmount);
     }
 
+    /**
+     * Compute the minimum amount of an output, which is its required storage deposit, after checking it against the
+     * protocol parameters.
+     *
+     * @param output The output.
+     * @param protocolParameters The protocol parameters of the network.
+     * @returns The minimum amount of the output.
+     */
+    static computeMinimumOutputAmount(
+        output: Output,
+        protocolParameters: INodeInfoProtocol,
+    ): bigint {
+        const minimumAmount = callUtilsMethod({
+            name: 'computeMinimumOutputAmount',
+            data: {
+                output,
+                protocolParameters,
+            },
+        });
+        return BigInt(minimumAmount);
+    }
+
+    /**
+     * Compute the Proof of Work score of a block, which has to reach the minimum PoW score of the network.
+     *
+     * @param block The block.
+     * @returns The PoW score of the block.
+     */
+    static computePowScore(block: Block): number {
+        return callUtilsMethod({
+            name: 'computePowScore',
+            data: {
+                block,
+            },
+        });
+    }
+
     /**
      * Compute a Token iD from the aliasId, serial number and token scheme type.
      *
@@ -174,6 +217,96 @@ export class Utils {
         });
     }
 
+    /**
+     * Split an output ID into its transaction ID and output index.
+     *
+     * @param outputId The output ID.
+     * @returns The parts of the output ID.
+     */
+    static parseOutputId(outputId: OutputId): OutputIdParts {
+        return callUtilsMethod({
+            name: 'parseOutputId',
+            data: {
+                outputId,
+            },
+        });
+    }
+
+    /**
+     * Split a foundry ID into its alias ID, serial number and token scheme type.
+     *
+     * @param foundryId The foundry ID.
+     * @returns The parts of the foundry ID.
+     */
+    static parseFoundryId(foundryId: FoundryId): FoundryIdParts {
+        return callUtilsMethod({
+            name: 'parseFoundryId',
+            data: {
+                foundryId,
+            },
+        });
+    }
+
+    /**
+     * Get the ID of the foundry which controls the supply of a native token.
+     *
+     * @param tokenId The token ID.
+     * @returns The foundry ID.
+     */
+    static tokenIdToFoundryId(tokenId: TokenId): FoundryId {
+        return callUtilsMethod({
+            name: 'tokenIdToFoundryId',
+            data: {
+                tokenId,
+            },
+        });
+    }
+
+    /**
+     * Get the token ID of the native token of a foundry.
+     *
+     * @param foundryId The foundry ID.
+     * @returns The token ID.
+     */
+    static foundryIdToTokenId(foundryId: FoundryId): TokenId {
+        return callUtilsMethod({
+            name: 'foundryIdToTokenId',
+            data: {
+                foundryId,
+            },
+        });
+    }
+
+    /**
+     * Get the alias ID of a Bech32 encoded alias address.
+     *
+     * @param bech32 The Bech32 encoded alias address.
+     * @returns The alias ID.
+     */
+    static bech32ToAliasId(bech32: Bech32Address): AliasId {
+        return callUtilsMethod({
+            name: 'bech32ToAliasId',
+            data: {
+                bech32,
+            },
+        });
+    }
+
+    /**
+     * Get the NFT ID of a Bech32 encoded NFT address.
+     *
+     * @param bech32 The Bech32 encoded NFT address.
+     * @returns The NFT ID.
+     */
+    static bech32ToNftId(bech32: Bech32Address): NftId {
+        return callUtilsMethod({
+            name: 'bech32ToNftId',
+            data: {
+                bech32,
+            },
+        });
+    }
+
     /**
      * Parse a Bech32 address from a string.
      *
@@ -407,6 +540,24 @@ export class Utils {
         });
     }
 
+    /**
+     * Check if the words entered by a user match the words of a mnemonic, to confirm a backup before the mnemonic is
+     * stored.
+     *
+     * @param mnemonic A mnemonic string.
+     * @param wordsWithPositions The entered words with their zero-based positions in the mnemonic.
+     * @returns True if all words match.
+     */
+    static verifyMnemonicWords(
+        mnemonic: string,
+        wordsWithPositions: [number, string][],
+    ): boolean {
+        return callUtilsMethod({
+            name: 'verifyMnemonicWords',
+            data: { mnemonic, wordsWithPositions },
+        });
+    }
+
     /**
      * Returns the hex representation of the serialized output bytes.
      *
@@ -426,15 +577,17 @@ export class Utils {
     /**
      * Verifies the semantic of a transaction.
      *
-     * @param inputs The inputs data.
+     * @param inputs The inputs data, in any order.
      * @param transaction The transaction payload.
      * @param time The unix time for which to do the validation, should be roughly the one of the milestone that will reference the transaction.
+     * @param protocolParameters The protocol parameters to also check the transaction and the inputs against.
      * @returns The conflict reason.
      */
     static verifyTransactionSemantic(
         inputs: InputSigningData[],
         transaction: TransactionPayload,
         time: number,
+        protocolParameters?: INodeInfoProtocol,
     ): string {
         const conflictReason = callUtilsMethod({
             name: 'verifyTransactionSemantic',
@@ -442,6 +595,7 @@ export class Utils {
                 inputs,
                 transaction,
                 time,
+                protocolParameters,
             },
         });
         return conflictReason;
@@ -467,6 +621,22 @@ export class Utils {
         });
     }
 
+    /**
+     * Verifies a proof of a confirmed transaction exported with `Account.exportTransactionProof()`.
+     * Its milestone index and timestamp and block ID still have to be compared with a trusted node or explorer.
+     *
+     * @param proof The transaction proof.
+     * @returns The ID of the proven transaction.
+     */
+    static verifyTransactionProof(proof: TransactionProof): TransactionId {
+        return callUtilsMethod({
+            name: 'verifyTransactionProof',
+            data: {
+                proof,
+            },
+        });
+    }
+
     /**
      * Returns the serialized bytes of a block.
      *
@@ -498,4 +668,136 @@ export class Utils {
         });
         return hash;
     }
+
+    /**
+     * Returns the hex encoded serialized bytes of an output.
+     *
+     * @param output The output.
+     * @param protocolParameters The protocol parameters to check the output against, including its storage deposit.
+     * @returns The hex encoded output bytes.
+     */
+    static packOutput(
+        output: Output,
+        protocolParameters?: INodeInfoProtocol,
+    ): HexEncodedString {
+        return callUtilsMethod({
+            name: 'packOutput',
+            data: {
+                output,
+                protocolParameters,
+            },
+        });
+    }
+
+    /**
+     * Deserializes hex encoded output bytes, trailing bytes are rejected.
+     *
+     * @param bytes The hex encoded output bytes.
+     * @param protocolParameters The protocol parameters to check the output against.
+     * @returns The output.
+     */
+    static unpackOutput(
+        bytes: HexEncodedString,
+        protocolParameters: INodeInfoProtocol,
+    ): Output {
+        const output = callUtilsMethod({
+            name: 'unpackOutput',
+            data: {
+                bytes,
+                protocolParameters,
+            },
+        });
+        return Output.parse(output);
+    }
+
+    /**
+     * Returns the hex encoded serialized bytes of a block.
+     *
+     * @param block The block.
+     * @param protocolParameters The protocol parameters to check the block against.
+     * @returns The hex encoded block bytes.
+     */
+    static packBlock(
+        block: Block,
+        protocolParameters?: INodeInfoProtocol,
+    ): HexEncodedString {
+        return callUtilsMethod({
+            name: 'packBlock',
+            data: {
+                block,
+                protocolParameters,
+            },
+        });
+    }
+
+    /**
+     * Deserializes hex encoded block bytes, trailing bytes are rejected.
+     *
+     * @param bytes The hex encoded block bytes.
+     * @param protocolParameters The protocol parameters to check the block against.
+     * @returns The block.
+     */
+    static unpackBlock(
+        bytes: HexEncodedString,
+        protocolParameters: INodeInfoProtocol,
+    ): Block {
+        const block = callUtilsMethod({
+            name: 'unpackBlock',
+            data: {
+                bytes,
+                protocolParameters,
+            },
+        });
+        return plainToInstance(Block, block);
+    }
+
+    /**
+     * Returns the highest of the given message interface versions that is supported by the Rust library.
+     *
+     * @param versions The interface versions supported by the caller.
+     * @returns The negotiated interface version.
+     */
+    static negotiateInterfaceVersion(versions: number[]): number {
+        return callUtilsMethod({
+            name: 'negotiateInterfaceVersion',
+            data: {
+                versions,
+            },
+        });
+    }
+
+    /**
+     * Start recording all method calls with their execution time and response type, to debug the bindings. Secrets
+     * are omitted. A previous trace is dropped.
+     *
+     * @param capacity The amount of kept method calls, only the latest ones are kept. Default: 100.
+     */
+    static startDebugTrace(capacity?: number): void {
+        callUtilsMethod({
+            name: 'startDebugTrace',
+            data: {
+                capacity,
+            },
+        });
+    }
+
+    /**
+     * Stop recording method calls and drop the recorded ones.
+     */
+    static stopDebugTrace(): void {
+        callUtilsMethod({
+            name: 'stopDebugTrace',
+        });
+    }
+
+    /**
+     * Get the method calls recorded since the debug trace was started, oldest first.
+     *
+     * @returns The recorded method calls.
+     */
+    static getDebugTrace(): MethodTrace[] {
+        return callUtilsMethod({
+            name: 'getDebugTrace',
+        });
+    }
 }
@@ -4,6 +4,9 @@
 import type { WalletMethodHandler } from './wallet-method-handler';
 import {
     Balance,
+    BulkSendReport,
+    ClaimLinkTransaction,
+    NativeTokensTransaction,
     AccountMetadata,
     SyncOptions,
     AccountMeta,
@@ -14,14 +17,17 @@ import {
     AddressWithUnspentOutputs,
     AliasOutputParams,
     FilterOptions,
+    OutputFilter,
     GenerateAddressOptions,
     CreateNativeTokenParams,
     MintNftParams,
     OutputData,
     OutputParams,
     OutputsToClaim,
+    Unlockability,
     Transaction,
     TransactionOptions,
+    TransactionProof,
     ParticipationOverview,
     ParticipationEventId,
     ParticipationEventStatus,
@@ -34,9 +40,16 @@ import {
     BuildFoundryOutputData,
     BuildNftOutputData,
     SignedTransactionEssence,
+    SwapAcceptance,
+    SwapAsset,
+    SwapOffer,
+    VestingSchedule,
+    VestingStatus,
     PreparedTransaction,
     PreparedCreateNativeTokenTransactionData,
     ConsolidationParams,
+    ConsolidationPreview,
+    ChainGraph,
 } from '../types/wallet';
 import { INode, Burn, PreparedTransactionData } from '../client';
 import {
@@ -272,6 +285,146 @@ export class Account {
         );
     }
 
+    /**
+     * Send base coins to a freshly generated one-time key and get a link with which anyone can claim them.
+     * If they aren't claimed before the expiration, they are available for the account again.
+     * @param amount The amount of base coins to send, has to cover the storage deposit.
+     * @param expiration The expiration in seconds, one day if not provided.
+     * @param transactionOptions Additional transaction options
+     * or custom inputs.
+     * @returns The claim link and the transaction.
+     */
+    async createClaimLink(
+        amount: bigint | NumericString,
+        expiration?: number,
+        transactionOptions?: TransactionOptions,
+    ): Promise<ClaimLinkTransaction> {
+        if (typeof amount === 'bigint') {
+            amount = amount.toString(10);
+        }
+        const response = await this.methodHandler.callAccountMethod(
+            this.meta.index,
+            {
+                name: 'createClaimLink',
+                data: {
+                    amount,
+                    expiration,
+                    options: transactionOptions,
+                },
+            },
+        );
+        const parsed = JSON.parse(response) as Response<ClaimLinkTransaction>;
+        return plainToInstance(ClaimLinkTransaction, parsed.payload);
+    }
+
+    /**
+     * Claim the funds of a claim link by sending them to the first address of the account.
+     * @param claimLink The encoded claim link, or a URL with it in the fragment.
+     * @returns The resulting transaction.
+     */
+    async redeemClaimLink(claimLink: string): Promise<Transaction> {
+        const response = await this.methodHandler.callAccountMethod(
+            this.meta.index,
+            {
+                name: 'redeemClaimLink',
+                data: {
+                    claimLink,
+                },
+            },
+        );
+        const parsed = JSON.parse(response) as Response<Transaction>;
+        return plainToInstance(Transaction, parsed.payload);
+    }
+
+    /**
+     * Create an offer to swap an asset of the account for an asset of another account.
+     * The outputs holding the offered asset are locked until the swap is completed or cancelled.
+     * @param offered The asset the account gives.
+     * @param asked The asset the account asks for in return.
+     * @param expiration The expiration in seconds, after which the swap isn't completed anymore.
+     * One day if not provided.
+     * @returns The offer, which has to be sent to the taker.
+     */
+    async createSwapOffer(
+        offered: SwapAsset,
+        asked: SwapAsset,
+        expiration?: number,
+    ): Promise<SwapOffer> {
+        const response = await this.methodHandler.callAccountMethod(
+            this.meta.index,
+            {
+                name: 'createSwapOffer',
+                data: {
+                    offered,
+                    asked,
+                    expiration,
+                },
+            },
+        );
+        return (JSON.parse(response) as Response<SwapOffer>).payload;
+    }
+
+    /**
+     * Accept a swap offer of another account by building the swap transaction and signing the inputs of the account.
+     * The inputs aren't locked, spending them before the maker completed the swap makes the acceptance invalid.
+     * @param offer The offer received from the maker.
+     * @returns The acceptance, which has to be sent back to the maker.
+     */
+    async acceptSwapOffer(offer: SwapOffer): Promise<SwapAcceptance> {
+        const response = await this.methodHandler.callAccountMethod(
+            this.meta.index,
+            {
+                name: 'acceptSwapOffer',
+                data: {
+                    offer,
+                },
+            },
+        );
+        return (JSON.parse(response) as Response<SwapAcceptance>).payload;
+    }
+
+    /**
+     * Complete a swap offer of the account which was accepted by another account: check that the account receives
+     * the asked asset, sign its inputs and submit the transaction.
+     * @param offer The offer created by the account.
+     * @param acceptance The acceptance received from the taker.
+     * @returns The swap transaction.
+     */
+    async completeSwap(
+        offer: SwapOffer,
+        acceptance: SwapAcceptance,
+    ): Promise<Transaction> {
+        const response = await this.methodHandler.callAccountMethod(
+            this.meta.index,
+            {
+                name: 'completeSwap',
+                data: {
+                    offer,
+                    acceptance,
+                },
+            },
+        );
+        const parsed = JSON.parse(response) as Response<Transaction>;
+        return plainToInstance(Transaction, parsed.payload);
+    }
+
+    /**
+     * Cancel a swap offer of the account by unlocking its inputs, so the swap can't be completed anymore.
+     * @param offer The offer created by the account.
+     */
+    async cancelSwap(offer: SwapOffer): Promise<void> {
+        const response = await this.methodHandler.callAccountMethod(
+            this.meta.index,
+            {
+                name: 'cancelSwap',
+                data: {
+                    offer,
+                },
+            },
+        );
+        return JSON.parse(response).payload;
+    }
+
     /**
      * Consolidate basic outputs with only an `AddressUnlockCondition` from an account
      * by sending them to an own address again if the output amount is greater or
@@ -285,6 +438,43 @@ export class Account {
         return (await this.prepareConsolidateOutputs(params)).send();
     }
 
+    /**
+     * Get the dependencies between the aliases, foundries and NFTs of the account and the outputs they own,
+     * e.g. the foundries to destroy before an alias can be destroyed.
+     * @returns The chain graph.
+     */
+    async chainGraph(): Promise<ChainGraph> {
+        const response = await this.methodHandler.callAccountMethod(
+            this.meta.index,
+            {
+                name: 'chainGraph',
+            },
+        );
+        return (JSON.parse(response) as Response<ChainGraph>).payload;
+    }
+
+    /**
+     * Preview how many transactions consolidating outputs with the same params needs,
+     * how many outputs remain and how the storage deposit changes, without creating a transaction.
+     * @param params Consolidation options.
+     * @returns The consolidation preview.
+     */
+    async consolidationPreview(
+        params: ConsolidationParams,
+    ): Promise<ConsolidationPreview> {
+        const response = await this.methodHandler.callAccountMethod(
+            this.meta.index,
+            {
+                name: 'consolidationPreview',
+                data: {
+                    params,
+                },
+            },
+        );
+        return (JSON.parse(response) as Response<ConsolidationPreview>)
+            .payload;
+    }
+
     /**
      * Consolidate basic outputs with only an `AddressUnlockCondition` from an account
      * by sending them to an own address again if the output amount is greater or
@@ -572,6 +762,17 @@ export class Account {
             payload.requiredStorageDeposit.nft,
         );
 
+        for (const key of [
+            'spendable',
+            'timelocked',
+            'expiringToUs',
+            'expiringAway',
+            'storageDepositLocked',
+            'pendingReserved',
+        ]) {
+            payload.breakdown[key] = BigInt(payload.breakdown[key]);
+        }
+
         return payload;
     }
 
@@ -594,6 +795,29 @@ export class Account {
         return plainToInstance(OutputData, parsed.payload);
     }
 
+    /**
+     * Explain whether an output can currently be unlocked, and if not, why not.
+     * @param outputId The output to explain.
+     * @returns The `Unlockability`, or undefined if the output isn't stored in the account.
+     */
+    async getOutputUnlockability(
+        outputId: OutputId,
+    ): Promise<Unlockability | undefined> {
+        const response = await this.methodHandler.callAccountMethod(
+            this.meta.index,
+            {
+                name: 'getOutputUnlockability',
+                data: {
+                    outputId,
+                },
+            },
+        );
+        const parsed = JSON.parse(response) as Response<
+            Unlockability | undefined
+        >;
+        return parsed.payload;
+    }
+
     /**
      * Get a participation event.
      *
@@ -752,6 +976,30 @@ export class Account {
         return plainToInstance(Transaction, parsed.payload);
     }
 
+    /**
+     * Export a proof of a confirmed transaction of the account, which can be verified with
+     * `Utils.verifyTransactionProof()` without access to the wallet.
+     * The consumed outputs are requested from the node, so this fails once the node pruned them.
+     *
+     * @param transactionId The ID of the transaction to prove.
+     * @returns The transaction proof.
+     */
+    async exportTransactionProof(
+        transactionId: TransactionId,
+    ): Promise<TransactionProof> {
+        const response = await this.methodHandler.callAccountMethod(
+            this.meta.index,
+            {
+                name: 'exportTransactionProof',
+                data: {
+                    transactionId,
+                },
+            },
+        );
+        const parsed = JSON.parse(response) as Response<TransactionProof>;
+        return plainToInstance(TransactionProof, parsed.payload);
+    }
+
     /**
      * List all the addresses of the account.
      *
@@ -869,6 +1117,27 @@ export class Account {
         return plainToInstance(OutputData, parsed.payload);
     }
 
+    /**
+     * Display an address of the account on the Ledger Nano device for the user to confirm it.
+     *
+     * @param addressIndex The index of the address in the account.
+     * @param internal Whether the address is an internal one.
+     * @returns Whether the user confirmed the address on the device.
+     */
+    async verifyAddressOnDevice(
+        addressIndex: number,
+        internal = false,
+    ): Promise<boolean> {
+        const response = await this.methodHandler.callAccountMethod(
+            this.meta.index,
+            {
+                name: 'verifyAddressOnDevice',
+                data: { addressIndex, internal },
+            },
+        );
+        return JSON.parse(response).payload;
+    }
+
     /**
      * Get the accounts metadata.
      *
@@ -998,6 +1267,90 @@ export class Account {
         );
     }
 
+    /**
+     * Send an amount to an address in timelocked outputs, which are released
+     * according to a vesting schedule.
+     *
+     * @param address The address of the recipient.
+     * @param amount The total amount, every release has to cover the storage deposit of its output.
+     * @param schedule The cliff, period and number of releases.
+     * @param transactionOptions Additional transaction options
+     * or custom inputs.
+     * @returns The sent transaction.
+     */
+    async createVesting(
+        address: Bech32Address,
+        amount: bigint | NumericString,
+        schedule: VestingSchedule,
+        transactionOptions?: TransactionOptions,
+    ): Promise<Transaction> {
+        return (
+            await this.prepareCreateVesting(
+                address,
+                amount,
+                schedule,
+                transactionOptions,
+            )
+        ).send();
+    }
+
+    /**
+     * Prepare to send an amount to an address in timelocked outputs, which
+     * are released according to a vesting schedule.
+     *
+     * @param address The address of the recipient.
+     * @param amount The total amount, every release has to cover the storage deposit of its output.
+     * @param schedule The cliff, period and number of releases.
+     * @param transactionOptions Additional transaction options
+     * or custom inputs.
+     * @returns The prepared transaction.
+     */
+    async prepareCreateVesting(
+        address: Bech32Address,
+        amount: bigint | NumericString,
+        schedule: VestingSchedule,
+        transactionOptions?: TransactionOptions,
+    ): Promise<PreparedTransaction> {
+        if (typeof amount === 'bigint') {
+            amount = amount.toString(10);
+        }
+        const response = await this.methodHandler.callAccountMethod(
+            this.meta.index,
+            {
+                name: 'prepareCreateVesting',
+                data: {
+                    address,
+                    amount,
+                    schedule,
+                    options: transactionOptions,
+                },
+            },
+        );
+        const parsed = JSON.parse(
+            response,
+        ) as Response<PreparedTransactionData>;
+        return new PreparedTransaction(
+            plainToInstance(PreparedTransactionData, parsed.payload),
+            this,
+        );
+    }
+
+    /**
+     * Get the unspent vesting outputs of the account, grouped by the
+     * transaction which created them.
+     *
+     * @returns The vesting status of every vesting transaction.
+     */
+    async vestingStatus(): Promise<VestingStatus[]> {
+        const response = await this.methodHandler.callAccountMethod(
+            this.meta.index,
+            {
+                name: 'vestingStatus',
+            },
+        );
+        return (JSON.parse(response) as Response<VestingStatus[]>).payload;
+    }
+
     /**
      * Mint NFTs.
      *
@@ -1163,6 +1516,182 @@ export class Account {
         );
     }
 
+    /**
+     * Query the outputs of the account.
+     *
+     * @param filter The filter the returned outputs have to match.
+     * @returns The outputs with metadata.
+     */
+    async queryOutputs(filter: OutputFilter): Promise<OutputData[]> {
+        const response = await this.methodHandler.callAccountMethod(
+            this.meta.index,
+            {
+                name: 'queryOutputs',
+                data: { filter },
+            },
+        );
+
+        const parsed = JSON.parse(response) as Response<OutputData[]>;
+        return plainToInstance(OutputData, parsed.payload);
+    }
+
+    /**
+     * Get the basic outputs sent by the account with a storage deposit return, which the receiver didn't claim
+     * before their expiration lapsed.
+     *
+     * @returns The outputs with metadata.
+     */
+    async reclaimableOutputs(): Promise<OutputData[]> {
+        const response = await this.methodHandler.callAccountMethod(
+            this.meta.index,
+            {
+                name: 'reclaimableOutputs',
+            },
+        );
+
+        const parsed = JSON.parse(response) as Response<OutputData[]>;
+        return plainToInstance(OutputData, parsed.payload);
+    }
+
+    /**
+     * Reclaim the outputs returned by `reclaimableOutputs()`.
+     *
+     * @returns The resulting transaction.
+     */
+    async reclaimExpiredOutputs(): Promise<Transaction> {
+        return (await this.prepareReclaimExpiredOutputs()).send();
+    }
+
+    /**
+     * Reclaim the outputs returned by `reclaimableOutputs()`.
+     *
+     * @returns The prepared transaction.
+     */
+    async prepareReclaimExpiredOutputs(): Promise<PreparedTransaction> {
+        const response = await this.methodHandler.callAccountMethod(
+            this.meta.index,
+            {
+                name: 'prepareReclaimExpiredOutputs',
+            },
+        );
+        const parsed = JSON.parse(
+            response,
+        ) as Response<PreparedTransactionData>;
+        return new PreparedTransaction(
+            plainToInstance(PreparedTransactionData, parsed.payload),
+            this,
+        );
+    }
+
+    /**
+     * Get the unspent outputs which were quarantined as spam while syncing with `quarantineSpamOutputs`. They aren't
+     * part of the balance and aren't used as inputs.
+     *
+     * @returns The outputs with metadata.
+     */
+    async quarantinedOutputs(): Promise<OutputData[]> {
+        const response = await this.methodHandler.callAccountMethod(
+            this.meta.index,
+            {
+                name: 'quarantinedOutputs',
+            },
+        );
+
+        const parsed = JSON.parse(response) as Response<OutputData[]>;
+        return plainToInstance(OutputData, parsed.payload);
+    }
+
+    /**
+     * Release quarantined outputs which aren't spam, so they're part of the balance again.
+     *
+     * @param outputIds The IDs of the quarantined outputs.
+     */
+    async releaseQuarantinedOutputs(outputIds: OutputId[]): Promise<void> {
+        await this.methodHandler.callAccountMethod(this.meta.index, {
+            name: 'releaseQuarantinedOutputs',
+            data: {
+                outputIds,
+            },
+        });
+    }
+
+    /**
+     * Consume quarantined outputs, burning their native tokens and NFTs. Their base coins are kept.
+     *
+     * @param outputIds The IDs of the quarantined outputs.
+     * @param transactionOptions Additional transaction options.
+     * @returns The resulting transaction.
+     */
+    async burnQuarantinedOutputs(
+        outputIds: OutputId[],
+        transactionOptions?: TransactionOptions,
+    ): Promise<Transaction> {
+        return (
+            await this.prepareBurnQuarantinedOutputs(
+                outputIds,
+                transactionOptions,
+            )
+        ).send();
+    }
+
+    /**
+     * Prepare to consume quarantined outputs, burning their native tokens and NFTs. Their base coins are kept.
+     *
+     * @param outputIds The IDs of the quarantined outputs.
+     * @param transactionOptions Additional transaction options.
+     * @returns The prepared transaction.
+     */
+    async prepareBurnQuarantinedOutputs(
+        outputIds: OutputId[],
+        transactionOptions?: TransactionOptions,
+    ): Promise<PreparedTransaction> {
+        const response = await this.methodHandler.callAccountMethod(
+            this.meta.index,
+            {
+                name: 'prepareBurnQuarantinedOutputs',
+                data: {
+                    outputIds,
+                    options: transactionOptions,
+                },
+            },
+        );
+        const parsed = JSON.parse(
+            response,
+        ) as Response<PreparedTransactionData>;
+        return new PreparedTransaction(
+            plainToInstance(PreparedTransactionData, parsed.payload),
+            this,
+        );
+    }
+
+    /**
+     * Rebuild a transaction that was rejected because some of its inputs got spent by another transaction.
+     * Still unspent inputs are reused and replacements for the spent ones are selected, the outputs are kept.
+     *
+     * @param transactionId The ID of the conflicting transaction.
+     * @returns The prepared transaction data.
+     */
+    async rebuildConflictingTransaction(
+        transactionId: TransactionId,
+    ): Promise<PreparedTransaction> {
+        const response = await this.methodHandler.callAccountMethod(
+            this.meta.index,
+            {
+                name: 'rebuildConflictingTransaction',
+                data: {
+                    transactionId,
+                },
+            },
+        );
+        const parsed = JSON.parse(
+            response,
+        ) as Response<PreparedTransactionData>;
+        return new PreparedTransaction(
+            plainToInstance(PreparedTransactionData, parsed.payload),
+            this,
+        );
+    }
+
     /**
      * Register participation events.
      *
@@ -1184,6 +1713,22 @@ export class Account {
         return JSON.parse(response).payload;
     }
 
+    /**
+     * Remove a local annotation from an output of the account.
+     *
+     * @param outputId The ID of the output.
+     * @param key The key of the annotation to remove.
+     */
+    async removeOutputAnnotation(outputId: OutputId, key: string): Promise<void> {
+        await this.methodHandler.callAccountMethod(this.meta.index, {
+            name: 'removeOutputAnnotation',
+            data: {
+                outputId,
+                key,
+            },
+        });
+    }
+
     /**
      * Retries (promotes or reattaches) a transaction sent from the account for a provided transaction id until it's
      * included (referenced by a milestone). Returns the included block id.
@@ -1270,6 +1815,63 @@ export class Account {
         return plainToInstance(Transaction, parsed.payload);
     }
 
+    /**
+     * Send base coins to any number of recipients, split into as many transactions as needed.
+     * If the funds are locked in a previously sent transaction, its inclusion is awaited before the next one is sent.
+     *
+     * @param params Addresses with amounts.
+     * @param transactionOptions Additional transaction options
+     * or custom inputs.
+     * @returns The IDs of the sent transactions and the transaction ID for each recipient.
+     */
+    async bulkSend(
+        params: SendParams[],
+        transactionOptions?: TransactionOptions,
+    ): Promise<BulkSendReport> {
+        for (let i = 0; i < params.length; i++) {
+            if (typeof params[i].amount === 'bigint') {
+                params[i].amount = params[i].amount.toString(10);
+            }
+        }
+        const response = await this.methodHandler.callAccountMethod(
+            this.meta.index,
+            {
+                name: 'bulkSend',
+                data: {
+                    params,
+                    options: transactionOptions,
+                },
+            },
+        );
+        return JSON.parse(response).payload;
+    }
+
+    /**
+     * Send any number of NFTs, split into as many transactions as needed.
+     * All NFTs are checked to be owned by the account before the first transaction is sent.
+     *
+     * @param params Addresses and NFT IDs.
+     * @param transactionOptions Additional transaction options
+     * or custom inputs.
+     * @returns The IDs of the sent transactions and the transaction ID for each NFT.
+     */
+    async distributeNfts(
+        params: SendNftParams[],
+        transactionOptions?: TransactionOptions,
+    ): Promise<BulkSendReport> {
+        const response = await this.methodHandler.callAccountMethod(
+            this.meta.index,
+            {
+                name: 'distributeNfts',
+                data: {
+                    params,
+                    options: transactionOptions,
+                },
+            },
+        );
+        return JSON.parse(response).payload;
+    }
+
     /**
      * Send native tokens.
      *
@@ -1287,6 +1889,35 @@ export class Account {
         ).send();
     }
 
+    /**
+     * Send native tokens and get how the storage deposit of each output was funded,
+     * which depends on the `maxStorageDepositReturn` of the params.
+     *
+     * @param params Addresses amounts and native tokens.
+     * @param transactionOptions Additional transaction options
+     * or custom inputs.
+     * @returns The transaction and the storage deposit choices.
+     */
+    async sendNativeTokensWithStorageDeposits(
+        params: SendNativeTokensParams[],
+        transactionOptions?: TransactionOptions,
+    ): Promise<NativeTokensTransaction> {
+        const response = await this.methodHandler.callAccountMethod(
+            this.meta.index,
+            {
+                name: 'sendNativeTokensWithStorageDeposits',
+                data: {
+                    params,
+                    options: transactionOptions,
+                },
+            },
+        );
+        const parsed = JSON.parse(
+            response,
+        ) as Response<NativeTokensTransaction>;
+        return plainToInstance(NativeTokensTransaction, parsed.payload);
+    }
+
     /**
      * Send native tokens.
      *
@@ -1420,6 +2051,44 @@ export class Account {
         });
     }
 
+    /**
+     * Set the alias output used as identity of the account. Outputs owned by its address are synced and unlocked by
+     * transitioning the alias, remainders are sent to it.
+     *
+     * @param aliasId The ID of an alias output controlled by the account, or undefined to remove the identity.
+     */
+    async setIdentityAlias(aliasId?: AliasId): Promise<void> {
+        await this.methodHandler.callAccountMethod(this.meta.index, {
+            name: 'setIdentityAlias',
+            data: {
+                aliasId,
+            },
+        });
+    }
+
+    /**
+     * Set a local annotation of an output of the account, replacing a previous value of the key.
+     * If storage is enabled, will persist during restarts.
+     *
+     * @param outputId The ID of the output.
+     * @param key The key of the annotation.
+     * @param value The value of the annotation.
+     */
+    async setOutputAnnotation(
+        outputId: OutputId,
+        key: string,
+        value: string,
+    ): Promise<void> {
+        await this.methodHandler.callAccountMethod(this.meta.index, {
+            name: 'setOutputAnnotation',
+            data: {
+                outputId,
+                key,
+                value,
+            },
+        });
+    }
+
     /**
      * Sign a prepared transaction, useful for offline signing.
      *
@@ -1510,6 +2179,27 @@ export class Account {
         return this.adjustBalancePayload(payload);
     }
 
+    /**
+     * Discard the outputs and transactions of the account and sync them from the node again, to recover from a
+     * corrupted local state. Only the addresses and the annotations of outputs which are found again are kept.
+     *
+     * @param options Optional synchronization options.
+     * @returns The account balance.
+     */
+    async rebuildLedgerFromHistory(options?: SyncOptions): Promise<Balance> {
+        const response = await this.methodHandler.callAccountMethod(
+            this.meta.index,
+            {
+                name: 'rebuildLedgerFromHistory',
+                data: {
+                    options,
+                },
+            },
+        );
+        const payload = JSON.parse(response).payload;
+        return this.adjustBalancePayload(payload);
+    }
+
     /**
      * Prepare a vote.
      *
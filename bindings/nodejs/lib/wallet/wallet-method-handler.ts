@@ -8,6 +8,8 @@ import {
     destroyWallet,
     getClientFromWallet,
     getSecretManagerFromWallet,
+    setApprovalHandler,
+    clearApprovalHandler,
 } from '../bindings';
 import type {
     WalletEventType,
@@ -17,6 +19,7 @@ import type {
     AccountId,
     Event,
 } from '../types/wallet';
+import { INTERFACE_VERSION } from '../types';
 import { Client } from '../client';
 import { SecretManager } from '../secret_manager';
 
@@ -33,6 +36,10 @@ export class WalletMethodHandler {
             clientOptions: options?.clientOptions,
             coinType: options?.coinType,
             secretManager: options?.secretManager,
+            outputMaturity: options?.outputMaturity,
+            serviceFee: options?.serviceFee,
+            networkValidation: options?.networkValidation,
+            sendDefaults: options?.sendDefaults,
         };
 
         this.methodHandler = createWallet(JSON.stringify(walletOptions));
@@ -46,13 +53,16 @@ export class WalletMethodHandler {
     async callMethod(method: __Method__): Promise<string> {
         return callWalletMethodAsync(
             // mapToObject is required to convert maps to array since they otherwise get serialized as `[{}]` even if not empty
-            JSON.stringify(method, function mapToObject(_key, value) {
-                if (value instanceof Map) {
-                    return Object.fromEntries(value);
-                } else {
-                    return value;
-                }
-            }),
+            JSON.stringify(
+                { ...method, version: INTERFACE_VERSION },
+                function mapToObject(_key, value) {
+                    if (value instanceof Map) {
+                        return Object.fromEntries(value);
+                    } else {
+                        return value;
+                    }
+                },
+            ),
             this.methodHandler,
         ).catch((error: Error) => {
             try {
@@ -92,14 +102,33 @@ export class WalletMethodHandler {
      *
      * @param eventTypes The wallet event types to listen for.
      * @param callback The callback function to call when an event is received.
+     * @returns The id of the listener.
      */
     async listen(
         eventTypes: WalletEventType[],
         callback: (error: Error, event: Event) => void,
-    ): Promise<void> {
+    ): Promise<number> {
         return listenWalletAsync(eventTypes, callback, this.methodHandler);
     }
 
+    /**
+     * Set a handler which has to approve every transaction before it gets signed.
+     *
+     * @param callback The callback function called with the account index and the JSON encoded prepared transaction data.
+     */
+    setApprovalHandler(
+        callback: (accountIndex: number, preparedTransaction: string) => boolean,
+    ): void {
+        setApprovalHandler(callback, this.methodHandler);
+    }
+
+    /**
+     * Remove the approval handler.
+     */
+    clearApprovalHandler(): void {
+        clearApprovalHandler(this.methodHandler);
+    }
+
     async destroy(): Promise<void> {
         return destroyWallet(this.methodHandler);
     }
@@ -13,10 +13,14 @@ import type {
     SyncOptions,
     WalletEvent,
     Event,
+    PrivacyReport,
+    SendDefaults,
 } from '../types/wallet';
+import { ListenerHandle } from '../types/wallet';
 import { IAuth, IClientOptions, LedgerNanoStatus } from '../types/client';
-import { Client } from '../client';
+import { Client, PreparedTransactionData } from '../client';
 import { SecretManager } from '../secret_manager';
+import { plainToInstance } from 'class-transformer';
 
 /** The Wallet class. */
 export class Wallet {
@@ -159,6 +163,55 @@ export class Wallet {
         return JSON.parse(response).payload;
     }
 
+    /**
+     * Get the wallet-wide defaults for the return address and expiration of outputs with a storage deposit return.
+     */
+    async getSendDefaults(): Promise<SendDefaults> {
+        const response = await this.methodHandler.callMethod({
+            name: 'getSendDefaults',
+        });
+
+        return JSON.parse(response).payload;
+    }
+
+    /**
+     * Analyze the stored transactions of all accounts for address reuse, round amounts and addresses linked by
+     * spending their outputs together. The accounts should be synced first.
+     */
+    async privacyReport(): Promise<PrivacyReport> {
+        const response = await this.methodHandler.callMethod({
+            name: 'privacyReport',
+        });
+
+        return JSON.parse(response).payload;
+    }
+
+    /**
+     * Returns the accounts, their default sync options, the labels and the client options of the wallet as JSON.
+     * Secrets are excluded, so the state can be attached to bug reports or loaded into a wallet with another storage.
+     */
+    async dumpStateJson(): Promise<object> {
+        const response = await this.methodHandler.callMethod({
+            name: 'dumpStateJson',
+        });
+
+        return JSON.parse(response).payload;
+    }
+
+    /**
+     * Loads a state returned by `dumpStateJson()` into a wallet without accounts.
+     *
+     * @param state The dumped wallet state.
+     */
+    async loadStateJson(state: object): Promise<void> {
+        await this.methodHandler.callMethod({
+            name: 'loadStateJson',
+            data: {
+                state,
+            },
+        });
+    }
+
     /**
      * Get secret manager.
      */
@@ -209,12 +262,15 @@ export class Wallet {
 
     /**
      * Listen to wallet events with a callback. An empty array will listen to all possible events.
+     *
+     * @returns A handle with which the listener can be removed again.
      */
     async listen(
         eventTypes: WalletEventType[],
         callback: (error: Error, event: Event) => void,
-    ): Promise<void> {
-        return this.methodHandler.listen(eventTypes, callback);
+    ): Promise<ListenerHandle> {
+        const id = await this.methodHandler.listen(eventTypes, callback);
+        return new ListenerHandle(id, this);
     }
 
     /**
@@ -228,6 +284,61 @@ export class Wallet {
         return JSON.parse(response).payload;
     }
 
+    /**
+     * Remove a single listener for all its event types.
+     *
+     * @param listenerId The id of the listener, returned by `listen()`.
+     * @returns `false` if the listener was already removed.
+     */
+    async removeListener(listenerId: number): Promise<boolean> {
+        const response = await this.methodHandler.callMethod({
+            name: 'removeListener',
+            data: { listenerId },
+        });
+        return JSON.parse(response).payload;
+    }
+
+    /**
+     * Get the number of listeners for each event type that has any, keyed by the name of the event type.
+     */
+    async listenerCounts(): Promise<{ [eventType: string]: number }> {
+        const response = await this.methodHandler.callMethod({
+            name: 'listenerCounts',
+        });
+        return JSON.parse(response).payload;
+    }
+
+    /**
+     * Set a handler which has to approve every transaction before it gets signed, replacing a previous one.
+     * The handler blocks signing until it returned, transactions that aren't approved fail.
+     *
+     * @param handler The handler called with the account index and the prepared transaction data.
+     */
+    setApprovalHandler(
+        handler: (
+            accountIndex: number,
+            preparedTransactionData: PreparedTransactionData,
+        ) => boolean,
+    ): void {
+        this.methodHandler.setApprovalHandler(
+            (accountIndex: number, preparedTransactionData: string) =>
+                handler(
+                    accountIndex,
+                    plainToInstance(
+                        PreparedTransactionData,
+                        JSON.parse(preparedTransactionData),
+                    ),
+                ),
+        );
+    }
+
+    /**
+     * Remove the approval handler, transactions get signed without approval again.
+     */
+    clearApprovalHandler(): void {
+        this.methodHandler.clearApprovalHandler();
+    }
+
     /**
      * Find accounts with unspent outputs.
      */
@@ -300,6 +411,17 @@ export class Wallet {
         });
     }
 
+    /**
+     * Set the wallet-wide defaults for the return address and expiration of outputs with a storage deposit return,
+     * used if they aren't provided per call. They are stored with the wallet.
+     */
+    async setSendDefaults(sendDefaults: SendDefaults): Promise<void> {
+        await this.methodHandler.callMethod({
+            name: 'setSendDefaults',
+            data: { sendDefaults },
+        });
+    }
+
     /**
      * Set the Stronghold password.
      */
@@ -348,12 +470,22 @@ export class Wallet {
     }
 
     /**
-     * Store a mnemonic in the Stronghold snapshot.
+     * Cancel long running operations like syncing, stop the background tasks and wait until the operations in flight
+     * stopped. Operations started afterwards fail.
+     */
+    async shutdown(): Promise<void> {
+        await this.methodHandler.callMethod({
+            name: 'shutdown',
+        });
+    }
+
+    /**
+     * Store a mnemonic in the Stronghold snapshot, optionally with a BIP-39 passphrase.
      */
-    async storeMnemonic(mnemonic: string): Promise<void> {
+    async storeMnemonic(mnemonic: string, passphrase?: string): Promise<void> {
         await this.methodHandler.callMethod({
             name: 'storeMnemonic',
-            data: { mnemonic },
+            data: { mnemonic, passphrase },
         });
     }
 
@@ -4,7 +4,7 @@
 use std::sync::Arc;
 
 use iota_sdk_bindings_core::{
-    call_client_method as rust_call_client_method,
+    call_client_method as rust_call_client_method, deserialize_method,
     iota_sdk::client::{mqtt::Topic, Client, ClientBuilder},
     listen_mqtt as rust_listen_mqtt, ClientMethod, Response, Result,
 };
@@ -37,7 +37,7 @@ impl ClientMethodHandler {
     }
 
     async fn call_method(&self, serialized_method: String) -> (String, bool) {
-        match serde_json::from_str::<ClientMethod>(&serialized_method) {
+        match deserialize_method::<ClientMethod>(&serialized_method) {
             Ok(method) => {
                 let res = rust_call_client_method(&self.client, method).await;
                 let mut is_err = matches!(res, Response::Error(_) | Response::Panic(_));
@@ -6,7 +6,8 @@ mod secret_manager;
 mod wallet;
 
 use iota_sdk_bindings_core::{
-    call_utils_method as rust_call_utils_method, init_logger as rust_init_logger, Response, UtilsMethod,
+    call_utils_method as rust_call_utils_method, deserialize_method, init_logger as rust_init_logger, Response,
+    UtilsMethod,
 };
 use neon::prelude::*;
 use once_cell::sync::Lazy;
@@ -26,7 +27,7 @@ pub fn init_logger(mut cx: FunctionContext) -> JsResult<JsUndefined> {
 
 pub fn call_utils_method(mut cx: FunctionContext) -> JsResult<JsString> {
     let method = cx.argument::<JsString>(0)?.value(&mut cx);
-    let method = match serde_json::from_str::<UtilsMethod>(&method) {
+    let method = match deserialize_method::<UtilsMethod>(&method) {
         Ok(method) => method,
         Err(err) => {
             return Ok(cx.string(serde_json::to_string(&Response::Error(err.into())).expect("json to string error")));
@@ -60,12 +61,14 @@ fn main(mut cx: ModuleContext) -> NeonResult<()> {
 
     // Wallet
     cx.export_function("callWalletMethod", wallet::call_wallet_method)?;
+    cx.export_function("clearApprovalHandler", wallet::clear_approval_handler)?;
     cx.export_function("createWallet", wallet::create_wallet)?;
     cx.export_function("destroyWallet", wallet::destroy_wallet)?;
     cx.export_function("getClientFromWallet", wallet::get_client)?;
     cx.export_function("getSecretManagerFromWallet", wallet::get_secret_manager)?;
     cx.export_function("listenWallet", wallet::listen_wallet)?;
     cx.export_function("migrateDbChrysalisToStardust", wallet::migrate_db_chrysalis_to_stardust)?;
+    cx.export_function("setApprovalHandler", wallet::set_approval_handler)?;
 
     Ok(())
 }
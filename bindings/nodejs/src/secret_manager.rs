@@ -4,7 +4,7 @@
 use std::{ops::Deref, sync::Arc};
 
 use iota_sdk_bindings_core::{
-    call_secret_manager_method as rust_call_secret_manager_method,
+    call_secret_manager_method as rust_call_secret_manager_method, deserialize_method,
     iota_sdk::client::{
         secret::{SecretManager, SecretManagerDto},
         stronghold::StrongholdAdapter,
@@ -40,7 +40,7 @@ impl SecretManagerMethodHandler {
     }
 
     async fn call_method(&self, method: String) -> (String, bool) {
-        match serde_json::from_str::<SecretManagerMethod>(&method) {
+        match deserialize_method::<SecretManagerMethod>(&method) {
             Ok(method) => {
                 let res = rust_call_secret_manager_method(&self.secret_manager, method).await;
                 let mut is_err = matches!(res, Response::Error(_) | Response::Panic(_));
@@ -3,12 +3,17 @@
 
 use std::sync::Arc;
 
+use async_trait::async_trait;
 use iota_sdk_bindings_core::{
-    call_wallet_method as rust_call_wallet_method,
-    iota_sdk::wallet::{
-        events::types::{Event, WalletEventType},
-        migration::migrate_db_chrysalis_to_stardust as rust_migrate_db_chrysalis_to_stardust,
-        Wallet,
+    call_wallet_method as rust_call_wallet_method, deserialize_method,
+    iota_sdk::{
+        client::api::{PreparedTransactionData, PreparedTransactionDataDto},
+        wallet::{
+            core::TransactionApprover,
+            events::types::{Event, WalletEventType},
+            migration::migrate_db_chrysalis_to_stardust as rust_migrate_db_chrysalis_to_stardust,
+            Wallet,
+        },
     },
     Response, Result, WalletMethod, WalletOptions,
 };
@@ -43,7 +48,7 @@ impl WalletMethodHandler {
     }
 
     async fn call_method(&self, method: String) -> (String, bool) {
-        match serde_json::from_str::<WalletMethod>(&method) {
+        match deserialize_method::<WalletMethod>(&method) {
             Ok(method) => {
                 let res = rust_call_wallet_method(&self.wallet, method).await;
                 let mut is_err = matches!(res, Response::Error(_) | Response::Panic(_));
@@ -131,7 +136,8 @@ pub fn call_wallet_method(mut cx: FunctionContext) -> JsResult<JsUndefined> {
     Ok(cx.undefined())
 }
 
-pub fn listen_wallet(mut cx: FunctionContext) -> JsResult<JsUndefined> {
+// Resolves with the id of the listener, with which it can be removed again.
+pub fn listen_wallet(mut cx: FunctionContext) -> JsResult<JsPromise> {
     let js_arr_handle: Handle<JsArray> = cx.argument(0)?;
     let vec: Vec<Handle<JsValue>> = js_arr_handle.to_vec(&mut cx)?;
     let mut event_types = Vec::with_capacity(vec.len());
@@ -144,16 +150,96 @@ pub fn listen_wallet(mut cx: FunctionContext) -> JsResult<JsUndefined> {
 
     let callback = Arc::new(cx.argument::<JsFunction>(1)?.root(&mut cx));
     let method_handler = Arc::clone(&cx.argument::<JsBox<WalletMethodHandlerWrapper>>(2)?.0);
+    let (deferred, promise) = cx.promise();
 
     crate::RUNTIME.spawn(async move {
         if let Some(method_handler) = &*method_handler.read().await {
             let channel = method_handler.channel.clone();
-            method_handler
+            let handle = method_handler
                 .wallet
                 .listen(event_types, move |event_data| {
                     call_event_callback(&channel, event_data.clone(), callback.clone())
                 })
                 .await;
+
+            let listener_id = handle.id().value();
+            deferred.settle_with(&method_handler.channel, move |mut cx| Ok(cx.number(listener_id as f64)));
+        } else {
+            panic!("Wallet got destroyed")
+        }
+    });
+
+    Ok(promise)
+}
+
+pub fn set_approval_handler(mut cx: FunctionContext) -> JsResult<JsUndefined> {
+    let callback = Arc::new(cx.argument::<JsFunction>(0)?.root(&mut cx));
+    let method_handler = Arc::clone(&cx.argument::<JsBox<WalletMethodHandlerWrapper>>(1)?.0);
+
+    crate::RUNTIME.spawn(async move {
+        if let Some(method_handler) = &*method_handler.read().await {
+            method_handler
+                .wallet
+                .set_approval_handler(JsApprover {
+                    channel: method_handler.channel.clone(),
+                    callback,
+                })
+                .await;
+        } else {
+            panic!("Wallet got destroyed")
+        }
+    });
+
+    Ok(cx.undefined())
+}
+
+// Approves transactions with a JS callback, which is called on the event loop. The result is awaited without blocking
+// a runtime thread, a failing callback rejects the transaction.
+struct JsApprover {
+    channel: Channel,
+    callback: Arc<JsCallback>,
+}
+
+#[async_trait]
+impl TransactionApprover for JsApprover {
+    async fn approve_transaction(
+        &self,
+        account_index: u32,
+        prepared_transaction_data: &PreparedTransactionData,
+    ) -> bool {
+        let prepared_transaction_data =
+            serde_json::to_string(&PreparedTransactionDataDto::from(prepared_transaction_data))
+                .expect("json to string error");
+        let callback = self.callback.clone();
+        let (sender, receiver) = tokio::sync::oneshot::channel();
+
+        self.channel.send(move |mut cx| {
+            let cb = (*callback).to_inner(&mut cx);
+            let this = cx.undefined();
+            let args = [
+                cx.number(account_index).upcast::<JsValue>(),
+                cx.string(prepared_transaction_data).upcast::<JsValue>(),
+            ];
+
+            let approved = cb
+                .call(&mut cx, this, args)?
+                .downcast_or_throw::<JsBoolean, _>(&mut cx)?
+                .value(&mut cx);
+            let _ = sender.send(approved);
+
+            Ok(())
+        });
+
+        receiver.await.unwrap_or(false)
+    }
+}
+
+pub fn clear_approval_handler(mut cx: FunctionContext) -> JsResult<JsUndefined> {
+    let method_handler = Arc::clone(&cx.argument::<JsBox<WalletMethodHandlerWrapper>>(0)?.0);
+
+    crate::RUNTIME.spawn(async move {
+        if let Some(method_handler) = &*method_handler.read().await {
+            method_handler.wallet.clear_approval_handler().await;
         } else {
             panic!("Wallet got destroyed")
         }
@@ -5,9 +5,9 @@ from .client.client import Client, NodeIndexerAPI, ClientError
 from .client._high_level_api import GenerateAddressesOptions, GenerateAddressOptions
 from .external import *
 from .utils import Utils
-from .wallet.wallet import Wallet, Account
+from .wallet.wallet import Wallet, Account, ListenerHandle
 from .wallet.common import WalletError
-from .wallet.sync_options import AccountSyncOptions, NftSyncOptions, AliasSyncOptions, SyncOptions
+from .wallet.sync_options import AccountSyncOptions, NftSyncOptions, AliasSyncOptions, NativeTokenFilter, SyncOptions
 from .secret_manager.secret_manager import *
 from .prefix_hex import *
 from .types.address import *
@@ -21,9 +21,11 @@ from .types.event import *
 from .types.feature import *
 from .types.irc_27 import *
 from .types.irc_30 import *
+from .types.issuer import *
 from .types.filter_options import *
 from .types.input import *
 from .types.native_token import *
+from .types.nft_history import *
 from .types.network_info import *
 from .types.node_info import *
 from .types.output import *
@@ -31,7 +33,9 @@ from .types.output_data import *
 from .types.output_id import *
 from .types.output_params import *
 from .types.payload import *
+from .types.privacy_report import *
 from .types.send_params import *
+from .types.swap import *
 from .types.token_scheme import *
 from .types.transaction import *
 from .types.transaction_data import *
@@ -39,4 +43,8 @@ from .types.transaction_options import *
 from .types.unlock import *
 from .types.unlock_condition import *
 from .types.utxo_changes import *
+from .types.vesting import *
 from .types.consolidation_params import *
+from .types.chain_graph import *
+from .types.debug_trace import *
+from .types.foundry_id import *
@@ -6,6 +6,8 @@ from abc import ABCMeta, abstractmethod
 from dacite import from_dict
 from iota_sdk.types.block import Block
 from iota_sdk.types.common import CoinType, HexStr
+from iota_sdk.types.issuer import IssuerInfo
+from iota_sdk.types.nft_history import NftHistory
 from iota_sdk.types.output import OutputWithMetadata
 from iota_sdk.types.output_id import OutputId
 from iota_sdk.secret_manager.secret_manager import LedgerNanoSecretManager, MnemonicSecretManager, StrongholdSecretManager, SeedSecretManager
@@ -152,6 +154,86 @@ class HighLevelAPI(metaclass=ABCMeta):
         })
         return [Block.from_dict(block) for block in blocks]
 
+    def find_blocks_by_tag(self, tag: HexStr, start_milestone_index: Optional[int] = None,
+                           end_milestone_index: Optional[int] = None) -> List[Block]:
+        """Find the blocks with a tagged data payload with the tag, also as payload of a transaction, which were
+        referenced by the milestones in the range. Uses the explorer API of a permanode running INX-Chronicle.
+
+        Args:
+            tag: The hex encoded tag.
+            start_milestone_index: The first milestone index, the one after the pruning index of the node if not set.
+            end_milestone_index: The last milestone index, the latest milestone if not set.
+
+        Returns:
+            A list of the found `Block`s.
+        """
+        blocks = self._call_method('findBlocksByTag', {
+            'tag': tag,
+            'startMilestoneIndex': start_milestone_index,
+            'endMilestoneIndex': end_milestone_index
+        })
+        return [Block.from_dict(block) for block in blocks]
+
+    def build_tagged_data_block(self, tag: HexStr, data: HexStr) -> Block:
+        """Build and post a block with a tagged data payload, then retry (promote or reattach) it until it's
+        referenced by a milestone.
+
+        Args:
+            tag: The hex encoded tag.
+            data: The hex encoded data.
+
+        Returns:
+            The referenced `Block`, a reattachment if the posted block couldn't be referenced anymore.
+        """
+        block = self._call_method('buildTaggedDataBlock', {
+            'tag': tag,
+            'data': data
+        })
+        return Block.from_dict(block)
+
+    def resolve_issuer(self, issuer: str) -> IssuerInfo:
+        """Resolve an alias or NFT issuer from its current output. Results are cached if the client has cache options,
+        also after the issuer was destroyed.
+
+        Args:
+            issuer: The address of the alias or NFT.
+
+        Returns:
+            The `IssuerInfo` with the immutable features of the issuer.
+        """
+        return from_dict(IssuerInfo, self._call_method('resolveIssuer', {
+            'issuer': issuer
+        }))
+
+    def verify_issuer(self, nft_id: HexStr, issuer: str) -> bool:
+        """Check whether an NFT was issued by the issuer, e.g. the collection NFT, with its immutable issuer feature.
+
+        Args:
+            nft_id: The ID of the NFT to verify.
+            issuer: The address of the expected issuer.
+
+        Returns:
+            Whether the NFT was issued by the issuer.
+        """
+        return self._call_method('verifyIssuer', {
+            'nftId': nft_id,
+            'issuer': issuer
+        })
+
+    def nft_history(self, nft_id: HexStr) -> NftHistory:
+        """Resolve the ownership history of an NFT by walking its outputs backwards from the current one.
+        Spent outputs are pruned by nodes, so a permanode should be configured for NFTs with a longer history.
+
+        Args:
+            nft_id: The ID of the NFT.
+
+        Returns:
+            The `NftHistory` with the outputs of the NFT and their owners, oldest first.
+        """
+        return from_dict(NftHistory, self._call_method('nftHistory', {
+            'nftId': nft_id
+        }))
+
     def retry(self, block_id: HexStr) -> List[Union[HexStr, Block]]:
         """Retries (promotes or reattaches) a block for provided block id. Block should only be
         retried only if they are valid and haven't been confirmed for a while.
@@ -219,7 +301,7 @@ class HighLevelAPI(metaclass=ABCMeta):
         """
         return self._call_method('findInputs', {
             'addresses': addresses,
-            'amount': amount
+            'amount': str(amount)
         })
 
     def reattach(self, block_id: HexStr) -> List[Union[HexStr, Block]]:
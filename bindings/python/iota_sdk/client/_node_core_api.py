@@ -1,12 +1,12 @@
 # Copyright 2023 IOTA Stiftung
 # SPDX-License-Identifier: Apache-2.0
 
-from typing import List, Union
+from typing import List, Optional, Union
 from abc import ABCMeta, abstractmethod
 from dacite import from_dict
 
 from iota_sdk.types.block import Block, BlockMetadata
-from iota_sdk.types.common import HexStr
+from iota_sdk.types.common import HexStr, RequestOptions
 from iota_sdk.types.node_info import NodeInfo, NodeInfoWrapper
 from iota_sdk.types.output import OutputWithMetadata, OutputMetadata
 from iota_sdk.types.output_id import OutputId
@@ -76,17 +76,20 @@ class NodeCoreAPI(metaclass=ABCMeta):
         """
         return self._call_method('getTips')
 
-    def post_block(self, block: Block) -> HexStr:
+    def post_block(self, block: Block,
+                   request_options: Optional[RequestOptions] = None) -> HexStr:
         """Post a block.
 
         Args:
             block: The block to post.
+            request_options: Options to send the block to a specific node instead of the node pool.
 
         Returns:
             The block id of the posted block.
         """
         return self._call_method('postBlock', {
-            'block': block.__dict__
+            'block': block.__dict__,
+            'requestOptions': request_options.as_dict() if request_options else None
         })
 
     def get_block_data(self, block_id: HexStr) -> Block:
@@ -6,7 +6,7 @@ from typing import Dict, Optional
 from abc import ABCMeta, abstractmethod
 import humps
 
-from iota_sdk.types.common import HexStr
+from iota_sdk.types.common import HexStr, RequestOptions
 from iota_sdk.types.output_id import OutputId
 
 
@@ -140,10 +140,15 @@ class NodeIndexerAPI(metaclass=ABCMeta):
         """
 
     def output_ids(
-            self, query_parameters: QueryParameters) -> OutputIdsResponse:
+            self, query_parameters: QueryParameters,
+            request_options: Optional[RequestOptions] = None) -> OutputIdsResponse:
         """Fetch alias/basic/NFT/foundry output IDs from the given query parameters.
         Supported query parameters are: "hasNativeTokens", "minNativeTokenCount", "maxNativeTokenCount", "unlockableByAddress", "createdBefore", "createdAfter", "cursor", "pageSize".
 
+        Args:
+            query_parameters: The query parameters.
+            request_options: Options to query a specific node instead of the node pool.
+
         Returns:
             The corresponding output IDs of the outputs.
         """
@@ -152,13 +157,19 @@ class NodeIndexerAPI(metaclass=ABCMeta):
 
         response = self._call_method('outputIds', {
             'queryParameters': query_parameters_camelized,
+            'requestOptions': request_options.as_dict() if request_options else None,
         })
         return OutputIdsResponse(response)
 
     def basic_output_ids(
-            self, query_parameters: QueryParameters) -> OutputIdsResponse:
+            self, query_parameters: QueryParameters,
+            request_options: Optional[RequestOptions] = None) -> OutputIdsResponse:
         """Fetch basic output IDs from the given query parameters.
 
+        Args:
+            query_parameters: The query parameters.
+            request_options: Options to query a specific node instead of the node pool.
+
         Returns:
             The corresponding output IDs of the basic outputs.
         """
@@ -167,6 +178,7 @@ class NodeIndexerAPI(metaclass=ABCMeta):
 
         response = self._call_method('basicOutputIds', {
             'queryParameters': query_parameters_camelized,
+            'requestOptions': request_options.as_dict() if request_options else None,
         })
         return OutputIdsResponse(response)
 
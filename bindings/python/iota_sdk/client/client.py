@@ -7,13 +7,14 @@ from typing import Any, Dict, List, Optional, Union
 import humps
 from dacite import from_dict
 
-from iota_sdk.external import create_client, call_client_method, listen_mqtt
+from iota_sdk.external import create_client, call_client_method, listen_mqtt, INTERFACE_VERSION
 from iota_sdk.client._node_core_api import NodeCoreAPI
 from iota_sdk.client._node_indexer_api import NodeIndexerAPI
 from iota_sdk.client._high_level_api import HighLevelAPI
 from iota_sdk.client._utils import ClientUtils
 from iota_sdk.secret_manager.secret_manager import LedgerNanoSecretManager, MnemonicSecretManager, StrongholdSecretManager, SeedSecretManager
 from iota_sdk.types.block import Block
+from iota_sdk.types.client_options import CacheOptions
 from iota_sdk.types.common import HexStr, Node, AddressAndAmount
 from iota_sdk.types.feature import Feature
 from iota_sdk.types.native_token import NativeToken
@@ -42,6 +43,7 @@ class Client(NodeCoreAPI, NodeIndexerAPI, HighLevelAPI, ClientUtils):
         nodes: Optional[Union[str, List[str]]] = None,
         primary_node: Optional[str] = None,
         primary_pow_node: Optional[str] = None,
+        issuer_node: Optional[Union[str, Node]] = None,
         permanode: Optional[str] = None,
         ignore_node_health: Optional[bool] = None,
         api_timeout: Optional[timedelta] = None,
@@ -52,10 +54,13 @@ class Client(NodeCoreAPI, NodeIndexerAPI, HighLevelAPI, ClientUtils):
         min_quorum_size: Optional[int] = None,
         quorum_threshold: Optional[int] = None,
         user_agent: Optional[str] = None,
+        proxy: Optional[str] = None,
+        tls_pins: Optional[Dict[str, List[Dict[str, str]]]] = None,
         local_pow: Optional[bool] = None,
         fallback_to_local_pow: Optional[bool] = None,
         pow_worker_count: Optional[int] = None,
         max_parallel_api_requests: Optional[int] = None,
+        cache: Optional[CacheOptions] = None,
         client_handle=None
     ):
         """Initialize the IOTA Client.
@@ -67,6 +72,8 @@ class Client(NodeCoreAPI, NodeIndexerAPI, HighLevelAPI, ClientUtils):
             Node which will be tried first for all requests.
         primary_pow_node :
             Node which will be tried first when using remote PoW, even before the primary_node.
+        issuer_node :
+            Node to which blocks are submitted while it's healthy, the other nodes are used if it's unhealthy or fails. It's not used for other requests.
         permanode :
             Permanode URL.
         ignore_node_health :
@@ -87,6 +94,11 @@ class Client(NodeCoreAPI, NodeIndexerAPI, HighLevelAPI, ClientUtils):
             % of nodes that have to return the same response so it gets accepted.
         user_agent :
             The User-Agent header for requests.
+        proxy :
+            The proxy for the requests to nodes without their own proxy, e.g. `http://proxy.local:3128`.
+        tls_pins :
+            Pinned TLS certificates or public keys, keyed by the host of the https node URLs, e.g.
+            `{'node.example.com': [{'type': 'publicKey', 'sha256': '0x...'}]}`.
         local_pow :
             Local proof of work.
         fallback_to_local_pow :
@@ -95,6 +107,8 @@ class Client(NodeCoreAPI, NodeIndexerAPI, HighLevelAPI, ClientUtils):
             The amount of threads to be used for proof of work.
         max_parallel_api_requests :
             Set maximum parallel API requests.
+        cache :
+            Options to cache node responses which can't change anymore, like blocks and milestones.
         client_handle :
             An instance of a node client.
         """
@@ -114,6 +128,8 @@ class Client(NodeCoreAPI, NodeIndexerAPI, HighLevelAPI, ClientUtils):
             else:
                 nodes = [nodes]
         client_config['nodes'] = nodes
+        if isinstance(issuer_node, Node):
+            client_config['issuer_node'] = issuer_node.as_dict()
 
         client_config = {
             k: v for k,
@@ -132,8 +148,13 @@ class Client(NodeCoreAPI, NodeIndexerAPI, HighLevelAPI, ClientUtils):
         if 'remote_pow_timeout' in client_config:
             client_config['remote_pow_timeout'] = {'secs': int(client_config['remote_pow_timeout'].total_seconds(
             )), 'nanos': get_remaining_nano_seconds(client_config['remote_pow_timeout'])}
+        if 'cache' in client_config:
+            client_config['cache'] = client_config['cache'].as_dict()
 
         client_config = humps.camelize(client_config)
+        # The hosts aren't camelized
+        if tls_pins is not None:
+            client_config['tlsPins'] = tls_pins
         client_config_str = dumps(client_config)
 
         # Create the message handler
@@ -146,7 +167,8 @@ class Client(NodeCoreAPI, NodeIndexerAPI, HighLevelAPI, ClientUtils):
         """Dumps json string and calls `call_client_method()`
         """
         message = {
-            'name': name
+            'name': name,
+            'version': INTERFACE_VERSION
         }
         if data:
             message['data'] = data
@@ -469,6 +491,23 @@ class Client(NodeCoreAPI, NodeIndexerAPI, HighLevelAPI, ClientUtils):
         """
         return self._call_method('getFallbackToLocalPow')
 
+    def get_block_issuance_context(self) -> Dict[str, Any]:
+        """Get the current block issuance context, to store it alongside a built block.
+        """
+        return self._call_method('getBlockIssuanceContext')
+
+    def revalidate_block(self, block: Block, context: Dict[str, Any]):
+        """Re-validate a stored block against the issuance context it was built with.
+
+        Args:
+            block: The block to re-validate.
+            context: The issuance context of the block, as returned by `get_block_issuance_context()`.
+        """
+        return self._call_method('revalidateBlock', {
+            'block': block.as_dict(),
+            'context': context
+        })
+
     def unhealthy_nodes(self) -> List[Dict[str, Any]]:
         """Returns the unhealthy nodes.
         """
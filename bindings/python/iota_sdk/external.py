@@ -2,4 +2,7 @@
 # SPDX-License-Identifier: Apache-2.0
 
 # pylint: disable=import-error, unused-import
-from .iota_sdk import call_utils_method, call_secret_manager_method, create_secret_manager, destroy_wallet, create_client, create_wallet, listen_wallet, get_client_from_wallet, get_secret_manager_from_wallet, call_wallet_method, call_client_method, init_logger, listen_mqtt
+from .iota_sdk import call_utils_method, call_secret_manager_method, create_secret_manager, destroy_wallet, create_client, create_wallet, listen_wallet, set_approval_handler, clear_approval_handler, get_client_from_wallet, get_secret_manager_from_wallet, call_wallet_method, call_client_method, init_logger, listen_mqtt
+
+# The version of the message interface that the methods are sent with.
+INTERFACE_VERSION = 1
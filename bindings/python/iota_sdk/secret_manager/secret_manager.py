@@ -6,7 +6,7 @@ from typing import Optional, Union
 from dacite import from_dict
 import humps
 
-from iota_sdk.external import create_secret_manager, call_secret_manager_method
+from iota_sdk.external import create_secret_manager, call_secret_manager_method, INTERFACE_VERSION
 from iota_sdk.types.common import HexStr
 from iota_sdk.types.signature import Ed25519Signature, Bip44
 from iota_sdk.types.transaction_data import PreparedTransactionData
@@ -32,14 +32,19 @@ class MnemonicSecretManager(dict):
     This is not recommended in production. Use LedgerNano or Stronghold instead.
     """
 
-    def __init__(self, mnemonic):
+    def __init__(self, mnemonic, passphrase=None):
         """Initialize a mnemonic secret manager.
 
         Args:
             mnemonic: The root secret of this type of secret manager.
+            passphrase: An optional BIP-39 passphrase, also known as "25th word".
         """
 
-        dict.__init__(self, mnemonic=mnemonic)
+        if passphrase is None:
+            dict.__init__(self, mnemonic=mnemonic)
+        else:
+            dict.__init__(self, mnemonicWithPassphrase={
+                'mnemonic': mnemonic, 'passphrase': passphrase})
 
 
 class SeedSecretManager(dict):
@@ -106,7 +111,8 @@ class SecretManager():
         """Dumps json string and call call_secret_manager_method()
         """
         message = {
-            'name': name
+            'name': name,
+            'version': INTERFACE_VERSION
         }
         if data:
             message['data'] = data
@@ -230,14 +236,73 @@ class SecretManager():
         """
         return self._call_method('getLedgerNanoStatus')
 
-    def store_mnemonic(self, mnemonic: str):
+    def store_mnemonic(self, mnemonic: str, passphrase: Optional[str] = None):
         """Store a mnemonic.
 
         Args:
             mnemonic: A mnemonic to store in the secret manager.
+            passphrase: An optional BIP-39 passphrase, also known as "25th word".
         """
         return self._call_method('storeMnemonic', {
-            'mnemonic': mnemonic
+            'mnemonic': mnemonic,
+            'passphrase': passphrase
+        })
+
+    def derive_child_mnemonic(
+            self, index: int, word_count: Optional[int] = None) -> str:
+        """Derive a child mnemonic from the seed, as specified in BIP-85, so an app can use its own mnemonic that's
+        recoverable from the backup of this one.
+
+        Args:
+            index: The index of the child mnemonic.
+            word_count: The number of words of the child mnemonic, 12, 18 or 24. Defaults to 24.
+
+        Returns:
+            The child mnemonic.
+        """
+        data = {'index': index}
+        if word_count is not None:
+            data['wordCount'] = word_count
+        return self._call_method('deriveChildMnemonic', data)
+
+    def verify_backup(self, mnemonic: str,
+                      passphrase: Optional[str] = None) -> bool:
+        """Check if a re-entered mnemonic belongs to the seed of the secret manager. The whole mnemonic is needed,
+        because only the seed is stored.
+
+        Args:
+            mnemonic: The re-entered mnemonic.
+            passphrase: An optional BIP-39 passphrase, also known as "25th word".
+
+        Returns:
+            True if the mnemonic and passphrase match the stored seed.
+        """
+        return self._call_method('verifyBackup', {
+            'mnemonic': mnemonic,
+            'passphrase': passphrase
+        })
+
+    def get_stronghold_inventory(self):
+        """List what is stored in the Stronghold snapshot, without any secret.
+
+        Returns:
+            If a mnemonic is stored and the keys of the key-value store with their namespace.
+        """
+        return self._call_method('getStrongholdInventory')
+
+    def delete_stored_mnemonic(self):
+        """Delete the mnemonic stored in the Stronghold vault.
+        """
+        return self._call_method('deleteStoredMnemonic')
+
+    def delete_store_entry(self, key: str):
+        """Delete an entry of the Stronghold key-value store.
+
+        Args:
+            key: The key of the entry.
+        """
+        return self._call_method('deleteStoreEntry', {
+            'key': key
         })
 
     def sign_ed25519(self, message: HexStr, chain: Bip44) -> Ed25519Signature:
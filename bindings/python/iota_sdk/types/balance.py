@@ -51,6 +51,28 @@ class NativeTokensBalance:
     metadata: Optional[HexStr]
 
 
+@dataclass
+class BalanceBreakdown:
+    """Base coin amounts of a balance split by their spendability.
+
+    Attributes:
+        spendable: The amount which can be spent right now, equal to the available base coin balance.
+        timelocked: The amount of outputs owned by the account, which are still timelocked.
+        expiringToUs: The amount of unexpired outputs sent by the account which the recipient didn't claim yet.
+        expiringAway: The amount of outputs received with an expiration, which have to be claimed before they expire.
+        storageDepositLocked: The amount locked as storage deposit of outputs with native tokens, NFTs, aliases and foundries.
+        pendingReserved: The amount of outputs used as inputs by pending transactions.
+        nextUnlock: The Unix timestamp in seconds at which the next timelock expires.
+    """
+    spendable: str
+    timelocked: str
+    expiringToUs: str
+    expiringAway: str
+    storageDepositLocked: str
+    pendingReserved: str
+    nextUnlock: Optional[int] = None
+
+
 @dataclass
 class Balance:
     """The balance of an account.
@@ -63,6 +85,7 @@ class Balance:
         aliases: All owned aliases.
         foundries: All owned foundries.
         potentiallyLockedOutputs: A list of potentially locked outputs.
+        breakdown: The base coin amounts split by why they can or can't be spent right now.
     """
     baseCoin: BaseCoinBalance
     requiredStorageDeposit: RequiredStorageDeposit
@@ -71,6 +94,7 @@ class Balance:
     aliases: List[HexStr]
     foundries: List[HexStr]
     potentiallyLockedOutputs: dict[HexStr, bool]
+    breakdown: BalanceBreakdown
 
     def as_dict(self):
         """Converts this object to a dict.
@@ -79,6 +103,7 @@ class Balance:
 
         config['baseCoin'] = config['baseCoin'].__dict__
         config['requiredStorageDeposit'] = config['requiredStorageDeposit'].__dict__
+        config['breakdown'] = config['breakdown'].__dict__
         config['nativeTokens'] = [nt.__dict__
                                   for nt in config['nativeTokens']]
 
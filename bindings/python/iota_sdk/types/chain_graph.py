@@ -0,0 +1,52 @@
+# Copyright 2024 IOTA Stiftung
+# SPDX-License-Identifier: Apache-2.0
+
+from __future__ import annotations
+from dataclasses import dataclass
+from typing import Dict, List, Optional
+from iota_sdk.types.common import HexStr
+
+
+@dataclass
+class ChainNode():
+    """An unspent output of the account in a chain graph.
+
+    Attributes:
+        outputId: The output ID.
+        kind: The kind of the output, `alias`, `foundry`, `nft` or `basic`. Basic outputs are only included if they're owned by an alias or NFT.
+        amount: The amount of the output.
+        chainId: The alias, foundry or NFT ID keyed by its kind, e.g. `{"Alias": "0x..."}`, not set for basic outputs.
+    """
+
+    outputId: HexStr
+    kind: str
+    amount: str
+    chainId: Optional[Dict[str, HexStr]] = None
+
+
+@dataclass
+class ChainEdge():
+    """A dependency between two outputs, the `dependent` output can only be unlocked with the `owner` output.
+
+    Attributes:
+        owner: The alias or NFT output the other output depends on.
+        dependent: The foundry or output owned by the alias or NFT address.
+        relation: `controlsFoundry`, `owns`, `stateController` or `governor`.
+    """
+
+    owner: HexStr
+    dependent: HexStr
+    relation: str
+
+
+@dataclass
+class ChainGraph():
+    """The dependencies between the unspent alias, foundry and NFT outputs of an account and the outputs they own.
+
+    Attributes:
+        nodes: The outputs of the graph.
+        edges: The dependencies between the outputs.
+    """
+
+    nodes: List[ChainNode]
+    edges: List[ChainEdge]
@@ -43,6 +43,25 @@ class MqttBrokerOptions:
         return {k: v for k, v in self.__dict__.items() if v is not None}
 
 
+@dataclass
+class CacheOptions:
+    """Options to cache node responses which can't change anymore, like blocks and milestones.
+
+        Attributes:
+        capacity (int):
+            The maximum amount of responses kept in memory. Default: 1000.
+        storagePath (str):
+            A directory in which the responses are also stored, so they are still available after a restart.
+    """
+    capacity: Optional[int] = None
+    storagePath: Optional[str] = None
+
+    def as_dict(self):
+        """Converts this object to a dict.
+        """
+        return {k: v for k, v in self.__dict__.items() if v is not None}
+
+
 @dataclass
 class ClientOptions:
     """Client options.
@@ -53,6 +72,8 @@ class ClientOptions:
             Node which will be tried first for all requests.
         primary_pow_node (str):
             Node which will be tried first when using remote PoW, even before the primary_node.
+        issuerNode (str):
+            Node to which blocks are submitted while it's healthy, it's not used for other requests.
         nodes (List[str]):
             Array of Node URLs.
         permanode (str):
@@ -88,9 +109,12 @@ class ClientOptions:
             The amount of threads to be used for proof of work.
         maxParallelApiRequests (int):
             The maximum parallel API requests.
+        cache (CacheOptions):
+            Options to cache node responses which can't change anymore, disabled if not set.
     """
     primaryNode: Optional[str] = None
     primaryPowNode: Optional[str] = None
+    issuerNode: Optional[str] = None
     nodes: Optional[List[str]] = None
     permanodes: Optional[List[str]] = None
     ignoreNodeHealth: Optional[bool] = None
@@ -108,6 +132,7 @@ class ClientOptions:
     remotePowTimeout: Optional[Duration] = None
     powWorkerCount: Optional[int] = None
     maxParallelApiRequests: Optional[int] = None
+    cache: Optional[CacheOptions] = None
 
     def as_dict(self):
         """Converts this object to a dict.
@@ -117,4 +142,7 @@ class ClientOptions:
         if 'brokerOptions' in config:
             config['brokerOptions'] = config['brokerOptions'].as_dict()
 
+        if 'cache' in config:
+            config['cache'] = config['cache'].as_dict()
+
         return config
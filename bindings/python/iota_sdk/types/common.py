@@ -2,7 +2,7 @@
 # SPDX-License-Identifier: Apache-2.0
 
 from enum import IntEnum
-from typing import NewType
+from typing import NewType, Optional, Union
 
 HexStr = NewType("HexStr", str)
 
@@ -28,7 +28,7 @@ class Node():
     """
 
     def __init__(self, url=None, jwt=None, username=None,
-                 password=None, disabled=None):
+                 password=None, disabled=None, headers=None, proxy=None):
         """Initialize a Node.
 
         Args:
@@ -37,20 +37,26 @@ class Node():
             username: A username for basic authentication.
             password: A password for basic authentication.
             disabled: Whether the node should be used for API requests or not.
+            headers: Custom headers sent with every request, e.g. the API key of a node provider.
+            proxy: The proxy to connect to the node with instead of the proxy of the client.
         """
         self.url = url
         self.jwt = jwt
         self.username = username
         self.password = password
         self.disabled = disabled
+        self.headers = headers
+        self.proxy = proxy
 
     def as_dict(self):
         """Converts this object to a dict.
         """
         config = {k: v for k, v in self.__dict__.items() if v is not None}
 
-        if 'jwt' in config or 'username' in config or 'password' in config:
+        if 'jwt' in config or 'username' in config or 'password' in config or 'headers' in config:
             config['auth'] = {}
+            if 'headers' in config:
+                config['auth']['headers'] = config.pop('headers')
             if 'jwt' in config:
                 config['auth']['jwt'] = config.pop('jwt')
             if 'username' in config or 'password' in config:
@@ -63,6 +69,29 @@ class Node():
         return config
 
 
+class RequestOptions():
+    """Options for a single request.
+    """
+
+    def __init__(self, node: Optional[Union[str, Node]] = None):
+        """Initialize RequestOptions.
+
+        Args:
+            node: The node to send the request to, bypassing the node pool. Its health isn't checked and quorum isn't used.
+        """
+        self.node = node
+
+    def as_dict(self):
+        """Converts this object to a dict.
+        """
+        config = {}
+        if isinstance(self.node, Node):
+            config['node'] = self.node.as_dict()
+        elif self.node is not None:
+            config['node'] = self.node
+        return config
+
+
 class AddressAndAmount():
     """Parameters to send a certain amount of coins to an address.
     """
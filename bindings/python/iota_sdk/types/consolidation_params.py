@@ -21,3 +21,30 @@ class ConsolidationParams:
     force: bool
     outputThreshold: Optional[int] = None
     targetAddress: Optional[str] = None
+
+
+@dataclass
+class ConsolidationPreview:
+    """What consolidating outputs with the same params would do, without creating a transaction.
+
+    Attributes:
+        consolidatableOutputs: The number of outputs which can be consolidated now.
+        outputThreshold: The output count from which outputs are consolidated, unless forced.
+        wouldConsolidate: Whether outputs would be consolidated with the params.
+        nextTransactionInputs: The number of outputs consumed by the next consolidation transaction.
+        transactionsNeeded: The number of transactions needed to consolidate all consolidatable outputs.
+        resultingOutputCount: The number of unspent outputs of the account after all consolidation transactions.
+        amount: The base coin amount of the consolidatable outputs.
+        storageDepositBefore: The storage deposit of the consolidatable outputs.
+        storageDepositAfter: The storage deposit of the outputs created by the consolidation transactions.
+    """
+
+    consolidatableOutputs: int
+    outputThreshold: int
+    wouldConsolidate: bool
+    nextTransactionInputs: int
+    transactionsNeeded: int
+    resultingOutputCount: int
+    amount: str
+    storageDepositBefore: str
+    storageDepositAfter: str
@@ -0,0 +1,25 @@
+# Copyright 2024 IOTA Stiftung
+# SPDX-License-Identifier: Apache-2.0
+
+from __future__ import annotations
+from dataclasses import dataclass
+from typing import Optional
+
+
+@dataclass
+class MethodTrace():
+    """A method call recorded by the debug trace.
+
+    Attributes:
+        timestamp: The UNIX timestamp in milliseconds at which the method was called.
+        method: The method with its parameters, secrets are omitted.
+        duration: The execution time in milliseconds.
+        response: The type of the response.
+        error: The error message, if the method failed or panicked.
+    """
+
+    timestamp: int
+    method: str
+    duration: int
+    response: str
+    error: Optional[str] = None
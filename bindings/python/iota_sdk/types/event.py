@@ -14,6 +14,8 @@ class WalletEventType(IntEnum):
         SpentOutput (3): An output was spent.
         TransactionInclusion (4): A transaction was included into the ledger.
         TransactionProgress (5): A progress update while submitting a transaction.
+        TransactionReissue (6): A pending transaction was reissued by the inclusion monitor.
+        LedgerRebuild (7): A progress update while rebuilding the ledger of an account from the node.
     """
     ConsolidationRequired = 0
     LedgerAddressGeneration = 1
@@ -21,3 +23,5 @@ class WalletEventType(IntEnum):
     SpentOutput = 3
     TransactionInclusion = 4
     TransactionProgress = 5
+    TransactionReissue = 6
+    LedgerRebuild = 7
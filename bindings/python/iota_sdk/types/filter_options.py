@@ -3,7 +3,7 @@
 
 from __future__ import annotations
 from dataclasses import dataclass
-from typing import List, Optional
+from typing import Any, List, Optional
 
 
 @dataclass
@@ -17,3 +17,18 @@ class FilterOptions:
     aliasIds: Optional[List[str]] = None
     foundryIds: Optional[List[str]] = None
     nftIds: Optional[List[str]] = None
+
+
+@dataclass
+class OutputFilter:
+    """A composable filter to query the outputs of an account.
+
+    Attributes:
+        type: The type of the filter, for example `all`, `any`, `not`, `outputTypes`, `minAmount`, `maxAmount`,
+            `nativeToken`, `aliasIds`, `foundryIds`, `nftIds`, `bookedTimestamp`, `unspent`, `unlockableAt`,
+            `metadataKey`, `expiration` or `annotation`.
+        value: The value of the filter, nested filters for `all`, `any` and `not`.
+    """
+
+    type: str
+    value: Optional[Any] = None
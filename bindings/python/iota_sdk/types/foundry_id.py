@@ -0,0 +1,21 @@
+# Copyright 2024 IOTA Stiftung
+# SPDX-License-Identifier: Apache-2.0
+
+from __future__ import annotations
+from dataclasses import dataclass
+from iota_sdk.types.common import HexStr
+
+
+@dataclass
+class FoundryIdParts():
+    """The parts of a foundry ID.
+
+    Attributes:
+        aliasId: The ID of the alias which controls the foundry.
+        serialNumber: The serial number of the foundry.
+        tokenSchemeType: The type of the token scheme of the foundry.
+    """
+
+    aliasId: HexStr
+    serialNumber: int
+    tokenSchemeType: int
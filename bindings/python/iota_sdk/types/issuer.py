@@ -0,0 +1,22 @@
+# Copyright 2024 IOTA Stiftung
+# SPDX-License-Identifier: Apache-2.0
+
+from __future__ import annotations
+from dataclasses import dataclass
+from typing import Optional
+from iota_sdk.types.common import HexStr
+
+
+@dataclass
+class IssuerInfo():
+    """An alias or NFT which issued outputs, with its immutable features.
+
+    Attributes:
+        address: The address of the issuer.
+        issuer: The issuer of the issuer itself, e.g. the alias which minted a collection NFT.
+        immutableMetadata: The hex encoded immutable metadata, e.g. the IRC-27 metadata of a collection NFT.
+    """
+
+    address: str
+    issuer: Optional[str] = None
+    immutableMetadata: Optional[HexStr] = None
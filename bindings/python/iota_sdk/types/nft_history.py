@@ -0,0 +1,52 @@
+# Copyright 2024 IOTA Stiftung
+# SPDX-License-Identifier: Apache-2.0
+
+from __future__ import annotations
+from dataclasses import dataclass
+from typing import List
+from iota_sdk.types.common import HexStr
+
+
+@dataclass
+class NftHistoryEntry():
+    """An output of an NFT with the owner at that time.
+
+    Attributes:
+        outputId: The ID of the output.
+        transactionId: The transaction which created the output, transferring the NFT to the owner or minting it.
+        owner: The address of the address unlock condition of the output.
+        milestoneIndexBooked: The milestone index at which the output was booked.
+        milestoneTimestampBooked: The milestone timestamp at which the output was booked.
+    """
+
+    outputId: HexStr
+    transactionId: HexStr
+    owner: str
+    milestoneIndexBooked: int
+    milestoneTimestampBooked: int
+
+
+@dataclass
+class NftHistory():
+    """The ownership history of an NFT.
+
+    Attributes:
+        nftId: The ID of the NFT.
+        outputs: The outputs of the NFT, starting with the oldest known one and ending with the current one.
+        complete: Whether the history starts with the output which minted the NFT. If not, older outputs or
+            transactions were pruned by the nodes.
+    """
+
+    nftId: HexStr
+    outputs: List[NftHistoryEntry]
+    complete: bool
+
+    def owners(self) -> List[str]:
+        """Returns the owners of the NFT in chronological order, without repetitions from outputs that didn't change
+        the owner.
+        """
+        owners: List[str] = []
+        for entry in self.outputs:
+            if not owners or owners[-1] != entry.owner:
+                owners.append(entry.owner)
+        return owners
@@ -3,7 +3,7 @@
 
 from __future__ import annotations
 from dataclasses import dataclass
-from typing import Optional, Union
+from typing import Dict, Optional, Union
 from iota_sdk.types.address import Ed25519Address, AliasAddress, NFTAddress
 from iota_sdk.types.common import HexStr
 from iota_sdk.types.output import BasicOutput, AliasOutput, FoundryOutput, NftOutput, OutputMetadata
@@ -23,6 +23,7 @@ class OutputData():
         networkId: The network ID the output belongs to.
         remainder: Whether the output represents a remainder amount.
         chain: A list of chain state indexes.
+        annotations: Local annotations set by the user, they aren't part of the output on the ledger.
     """
 
     outputId: HexStr
@@ -33,3 +34,25 @@ class OutputData():
     networkId: str
     remainder: bool
     chain: Optional[Bip44] = None
+    annotations: Optional[Dict[str, str]] = None
+
+
+@dataclass
+class Unlockability():
+    """Whether an output of an account can currently be unlocked, and if not, why not.
+
+    Attributes:
+        type: One of `spent`, `unlockable`, `timelockedUntil`, `expired` and `requiresUnlockBy`.
+        expiresAt: For `unlockable` outputs, the timestamp from which on the output can only be unlocked by its return address.
+        storageDepositReturn: For `unlockable` outputs, the amount which has to be returned with a storage deposit return output.
+        timestamp: For `timelockedUntil` outputs the timestamp from which on they can be unlocked, for `expired` outputs the timestamp at which they expired.
+        returnAddress: For `expired` outputs, the return address they belong to since then.
+        address: For `requiresUnlockBy` outputs, the address which isn't owned by the account, but has to unlock them.
+    """
+
+    type: str
+    expiresAt: Optional[int] = None
+    storageDepositReturn: Optional[str] = None
+    timestamp: Optional[int] = None
+    returnAddress: Optional[str] = None
+    address: Optional[str] = None
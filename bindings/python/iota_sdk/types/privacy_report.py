@@ -0,0 +1,43 @@
+# Copyright 2024 IOTA Stiftung
+# SPDX-License-Identifier: Apache-2.0
+
+from __future__ import annotations
+from dataclasses import dataclass
+from typing import List, Optional
+from iota_sdk.types.common import HexStr
+
+
+@dataclass
+class PrivacyWarning():
+    """A privacy issue found in the stored transactions of an account.
+
+    Attributes:
+        type: One of `addressReuse`, `roundAmount` and `coSpendCluster`.
+        accountIndex: The index of the account the issue was found in.
+        address: For `addressReuse`, the address which received outputs in multiple transactions.
+        transactionIds: For `addressReuse` the transactions creating outputs on the address, for `coSpendCluster` the transactions linking the addresses.
+        transactionId: For `roundAmount`, the transaction sending the round amount.
+        outputIndex: For `roundAmount`, the index of the output with the round amount.
+        amount: For `roundAmount`, the round amount.
+        addresses: For `coSpendCluster`, the addresses linked by spending their outputs together.
+    """
+
+    type: str
+    accountIndex: int
+    address: Optional[str] = None
+    transactionIds: Optional[List[HexStr]] = None
+    transactionId: Optional[HexStr] = None
+    outputIndex: Optional[int] = None
+    amount: Optional[str] = None
+    addresses: Optional[List[str]] = None
+
+
+@dataclass
+class PrivacyReport():
+    """Privacy issues found in the stored transactions of a wallet.
+
+    Attributes:
+        warnings: The found issues, ordered by account.
+    """
+
+    warnings: List[PrivacyWarning]
@@ -18,7 +18,7 @@ class SendParams():
         returnAddress: The address to return the funds to if not claimed.
         expiration: Expiration in seconds, after which the output will be available for the sender again, if not spent by the
         receiver already. The expiration will only be used if one is necessary given the provided amount. If an
-        expiration is needed but not provided, it will default to one day.
+        expiration is needed but not provided, it will default to the one of the wallet's send defaults, or one day.
     """
     address: str
     amount: str
@@ -35,6 +35,23 @@ class SendParams():
         return config
 
 
+@dataclass
+class SendDefaults():
+    """Wallet-wide defaults for the storage deposit return and expiration of outputs whose amount doesn't cover their storage deposit.
+
+    Attributes:
+        returnAddress: The address to which the storage deposit is returned, the first address of the sending account if not set.
+        expiration: The expiration in seconds, after which the output is available for the sender again, one day if not set.
+    """
+    returnAddress: Optional[str] = None
+    expiration: Optional[int] = None
+
+    def as_dict(self):
+        """Converts this object to a dict.
+        """
+        return {k: v for k, v in self.__dict__.items() if v is not None}
+
+
 @dataclass
 class SendNativeTokensParams():
     """Parameters for sending native tokens
@@ -44,16 +61,25 @@ class SendNativeTokensParams():
         nativeTokens: The native tokens to send.
         returnAddress: The address to return the native tokens to if not claimed.
         expiration: The expiration timestamp until native tokens can be claimed.
+        maxStorageDepositReturn: The maximum storage deposit which is returned to the sender, the rest of it is gifted
+        to the recipient. If it's too low for a storage deposit return, the whole storage deposit is gifted and the
+        output has no expiration. Default is to return the whole storage deposit.
     """
     address: str
     nativeTokens: List[NativeToken]
     returnAddress: Optional[str] = None
     expiration: Optional[int] = None
+    maxStorageDepositReturn: Optional[str] = None
 
     def as_dict(self):
         """Converts this object to a dict.
         """
-        return {k: v for k, v in self.__dict__.items() if v is not None}
+        config = {k: v for k, v in self.__dict__.items() if v is not None}
+
+        if 'maxStorageDepositReturn' in config:
+            config['maxStorageDepositReturn'] = str(config['maxStorageDepositReturn'])
+
+        return config
 
 
 @dataclass
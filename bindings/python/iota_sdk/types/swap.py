@@ -0,0 +1,113 @@
+# Copyright 2024 IOTA Stiftung
+# SPDX-License-Identifier: Apache-2.0
+
+from __future__ import annotations
+from dataclasses import dataclass, field
+from typing import Dict, List, Union
+from iota_sdk.types.common import HexStr
+from iota_sdk.types.output import OutputWithMetadata
+from iota_sdk.types.unlock import Unlock
+
+
+@dataclass
+class BaseCoinSwapAsset:
+    """An amount of base coins which is exchanged in a swap.
+
+    Attributes:
+        amount: The amount of base coins.
+    """
+    amount: str
+    type: str = field(default='baseCoin', init=False)
+
+
+@dataclass
+class NativeTokenSwapAsset:
+    """An amount of a native token which is exchanged in a swap.
+
+    Attributes:
+        tokenId: The ID of the native token.
+        amount: The hex encoded amount of the native token.
+    """
+    tokenId: HexStr
+    amount: HexStr
+    type: str = field(default='nativeToken', init=False)
+
+
+@dataclass
+class NftSwapAsset:
+    """An NFT which is exchanged in a swap, together with its storage deposit.
+
+    Attributes:
+        nftId: The ID of the NFT.
+    """
+    nftId: HexStr
+    type: str = field(default='nft', init=False)
+
+
+SwapAsset = Union[BaseCoinSwapAsset, NativeTokenSwapAsset, NftSwapAsset]
+
+
+@dataclass
+class SwapOffer:
+    """An offer to swap assets, which is sent to the taker out-of-band as JSON and has to be passed back unchanged.
+
+    Attributes:
+        offered: The asset the maker gives.
+        asked: The asset the maker asks for in return.
+        address: The address of the maker, which receives the asked asset and the rest of its inputs.
+        inputs: The outputs of the maker which are consumed by the swap.
+        expiration: Unix timestamp in seconds after which the maker doesn't complete the swap anymore.
+    """
+    offered: SwapAsset
+    asked: SwapAsset
+    address: str
+    inputs: List[OutputWithMetadata]
+    expiration: int
+
+    # pylint: disable=redefined-builtin
+    @classmethod
+    def from_dict(cls, dict: Dict) -> SwapOffer:
+        """Converts a dict to a SwapOffer
+        """
+        obj = cls.__new__(cls)
+        super(SwapOffer, obj).__init__()
+        for k, v in dict.items():
+            setattr(obj, k, v)
+        return obj
+
+    def as_dict(self):
+        """Converts this object to a dict.
+        """
+        return dict(self.__dict__)
+
+
+@dataclass
+class SwapAcceptance:
+    """The transaction of an accepted swap offer with the unlocks of the taker, which is sent back to the maker.
+
+    Attributes:
+        offer: The accepted offer.
+        essence: The transaction, which consumes the inputs of the offer followed by the inputs of the taker.
+        inputs: The outputs of the taker which are consumed by the swap.
+        unlocks: The unlocks of the inputs of the taker.
+    """
+    offer: SwapOffer
+    essence: Dict
+    inputs: List[OutputWithMetadata]
+    unlocks: List[Unlock]
+
+    # pylint: disable=redefined-builtin
+    @classmethod
+    def from_dict(cls, dict: Dict) -> SwapAcceptance:
+        """Converts a dict to a SwapAcceptance
+        """
+        obj = cls.__new__(cls)
+        super(SwapAcceptance, obj).__init__()
+        for k, v in dict.items():
+            setattr(obj, k, SwapOffer.from_dict(v) if k == 'offer' else v)
+        return obj
+
+    def as_dict(self):
+        """Converts this object to a dict.
+        """
+        return dict(self.__dict__)
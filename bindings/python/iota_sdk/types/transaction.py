@@ -60,3 +60,89 @@ class Transaction:
         for k, v in dict.items():
             setattr(obj, k, v)
         return obj
+
+
+@dataclass
+class BulkSendReport:
+    """The result of sending to many recipients in multiple transactions.
+
+    Attributes:
+        transactionIds: The IDs of the sent transactions, in the order they were sent.
+        recipients: The ID of the transaction sending to each recipient, in the order of the provided params.
+            Shorter than the params if not all recipients were sent to.
+    """
+    transactionIds: List[HexStr]
+    recipients: List[HexStr]
+
+
+@dataclass
+class ClaimLinkTransaction:
+    """The result of creating a claim link.
+
+    Attributes:
+        claimLink: The encoded claim link, anyone knowing it can claim the funds.
+        transaction: The transaction sending the funds to the one-time key of the claim link.
+    """
+    claimLink: str
+    transaction: Transaction
+
+
+@dataclass
+class StorageDepositChoice:
+    """How the storage deposit of an output sending native tokens was funded.
+
+    Attributes:
+        type: `return` if the storage deposit is returned to the sender, except the part above the maximum, `gift` if
+            all of it is gifted.
+        gifted: The storage deposit gifted to the recipient.
+        returned: The storage deposit returned to the sender.
+    """
+    type: str
+    gifted: str
+    returned: Optional[str] = None
+
+
+@dataclass
+class NativeTokensTransaction:
+    """The result of sending native tokens with the chosen storage deposits.
+
+    Attributes:
+        transaction: The transaction sending the native tokens.
+        storageDeposits: How the storage deposit of each output was funded, in the order of the params.
+    """
+    transaction: Transaction
+    storageDeposits: List[StorageDepositChoice]
+
+
+@dataclass
+class TransactionProof:
+    """A proof of a confirmed transaction, which can be verified without access to the wallet which sent it.
+
+    Attributes:
+        transaction: The signed transaction.
+        inputs: The outputs consumed by the transaction in the order of its inputs, with their metadata.
+        blockId: The ID of the block which included the transaction.
+        milestoneIndex: The index of the milestone which confirmed the transaction.
+        milestoneTimestamp: The timestamp of the milestone which confirmed the transaction.
+    """
+    transaction: TransactionPayload
+    inputs: List[OutputWithMetadata]
+    blockId: HexStr
+    milestoneIndex: int
+    milestoneTimestamp: int
+
+    # pylint: disable=redefined-builtin
+    @classmethod
+    def from_dict(cls, dict: Dict) -> TransactionProof:
+        """Converts a dict to a TransactionProof
+        """
+        obj = cls.__new__(cls)
+        super(TransactionProof, obj).__init__()
+        for k, v in dict.items():
+            setattr(obj, k, v)
+        return obj
+
+    def as_dict(self):
+        """Converts this object to a dict.
+        """
+        return dict(self.__dict__)
@@ -50,6 +50,17 @@ class RemainderValueStrategy(Enum):
         return dict({"strategy": self.name, "value": self.value[0]})
 
 
+class CoinSelectionStrategy(str, Enum):
+    """Coin selection strategy variants.
+
+    Attributes:
+        AnyAddress: Select inputs of any addresses of the account.
+        SingleAddressOnly: Only select inputs of a single address, so a transaction doesn't link multiple addresses of the account. Explicitly provided custom inputs are allowed to belong to distinct addresses.
+    """
+    AnyAddress = 'AnyAddress'
+    SingleAddressOnly = 'SingleAddressOnly'
+
+
 class TransactionOptions():
     """Transaction options.
 
@@ -61,6 +72,8 @@ class TransactionOptions():
         burn: Specifies what needs to be burned during input selection.
         note: A string attached to the transaction.
         allow_micro_amount: Whether to allow sending a micro amount.
+        coin_selection_strategy: The strategy applied for the automatic selection of inputs.
+        idempotency_key: A key to send the transaction only once: if a transaction was already sent with the same key, it's returned instead of sending a new one. A key is removed when syncing seven days after its transaction was sent, if the transaction is confirmed or conflicting by then.
     """
 
     def __init__(self, remainder_value_strategy: Optional[Union[RemainderValueStrategy, RemainderValueStrategyCustomAddress]] = None,
@@ -69,7 +82,9 @@ class TransactionOptions():
                  mandatory_inputs: Optional[List[OutputId]] = None,
                  burn: Optional[Burn] = None,
                  note: Optional[str] = None,
-                 allow_micro_amount: Optional[bool] = None):
+                 allow_micro_amount: Optional[bool] = None,
+                 coin_selection_strategy: Optional[CoinSelectionStrategy] = None,
+                 idempotency_key: Optional[str] = None):
         """Initialize transaction options.
         """
         self.remainder_value_strategy = remainder_value_strategy
@@ -79,6 +94,8 @@ class TransactionOptions():
         self.burn = burn
         self.note = note
         self.allow_micro_amount = allow_micro_amount
+        self.coin_selection_strategy = coin_selection_strategy
+        self.idempotency_key = idempotency_key
 
     def as_dict(self):
         """Converts this object to a dict.
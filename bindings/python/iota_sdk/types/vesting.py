@@ -0,0 +1,58 @@
+# Copyright 2024 IOTA Stiftung
+# SPDX-License-Identifier: Apache-2.0
+
+from __future__ import annotations
+from dataclasses import dataclass
+from typing import List, Optional
+from iota_sdk.types.common import HexStr
+
+
+@dataclass
+class VestingSchedule():
+    """The schedule after which vested funds are released: nothing until the cliff, then an equal part every period.
+
+    Attributes:
+        cliff: Seconds from the start until the first release.
+        period: Seconds between two releases.
+        releases: The number of releases, including the one at the cliff.
+        start: Unix timestamp in seconds at which the vesting starts, the current time if not set.
+    """
+
+    cliff: int
+    period: int
+    releases: int
+    start: Optional[int] = None
+
+
+@dataclass
+class VestingRelease():
+    """A release of vested funds.
+
+    Attributes:
+        outputId: The output holding the funds.
+        amount: The released amount.
+        unlockTime: Unix timestamp in seconds at which the funds are released.
+    """
+
+    outputId: HexStr
+    amount: str
+    unlockTime: int
+
+
+@dataclass
+class VestingStatus():
+    """The unspent vesting outputs of an account which were created in the same transaction.
+
+    Attributes:
+        transactionId: The transaction which created the vesting outputs.
+        releases: The releases, ordered by their unlock time.
+        releasedAmount: The amount which is released, but not spent yet.
+        lockedAmount: The amount which is still locked.
+        nextRelease: Unix timestamp in seconds of the next release, if any funds are still locked.
+    """
+
+    transactionId: HexStr
+    releases: List[VestingRelease]
+    releasedAmount: str
+    lockedAmount: str
+    nextRelease: Optional[int] = None
@@ -3,22 +3,25 @@
 
 from __future__ import annotations
 from json import dumps, loads
-from typing import TYPE_CHECKING, List
+from typing import TYPE_CHECKING, List, Optional, Tuple
 from dacite import from_dict
 
 from iota_sdk.types.signature import Ed25519Signature
 from iota_sdk.types.address import Address, AddressType, Ed25519Address, AliasAddress, NFTAddress
 from iota_sdk.types.common import HexStr
+from iota_sdk.types.debug_trace import MethodTrace
+from iota_sdk.types.foundry_id import FoundryIdParts
 from iota_sdk.types.output_id import OutputId
-from iota_sdk.types.output import Output
+from iota_sdk.types.output import Output, output_from_dict
 from iota_sdk.types.transaction_data import InputSigningData
-from iota_sdk.external import call_utils_method
+from iota_sdk.external import call_utils_method, INTERFACE_VERSION
 from iota_sdk.types.node_info import NodeInfoProtocol
 from iota_sdk.types.payload import TransactionPayload
 
 # Required to prevent circular import
 if TYPE_CHECKING:
     from iota_sdk.types.block import Block
+    from iota_sdk.types.transaction import TransactionProof
 
 
 class Utils():
@@ -147,6 +150,25 @@ class Utils():
             'rent': rent
         })
 
+    @staticmethod
+    def compute_minimum_output_amount(
+            output: Output, protocol_parameters: NodeInfoProtocol) -> int:
+        """Compute the minimum amount of an output, which is its required storage deposit, after checking it against
+        the protocol parameters.
+        """
+        return int(_call_method('computeMinimumOutputAmount', {
+            'output': output.as_dict(),
+            'protocolParameters': protocol_parameters.as_dict(),
+        }))
+
+    @staticmethod
+    def compute_pow_score(block: Block) -> float:
+        """Compute the Proof of Work score of a block, which has to reach the minimum PoW score of the network.
+        """
+        return _call_method('computePowScore', {
+            'block': block.as_dict(),
+        })
+
     @staticmethod
     def compute_nft_id(output_id: OutputId) -> HexStr:
         """Compute the NFT id for the given NFT output id.
@@ -175,6 +197,55 @@ class Utils():
             'tokenSchemeType': token_scheme_type
         })
 
+    @staticmethod
+    def parse_output_id(output_id: OutputId) -> OutputId:
+        """Split an output ID into its transaction ID and output index, validated by the Rust library.
+        """
+        parts = _call_method('parseOutputId', {
+            'outputId': repr(output_id)
+        })
+        return OutputId(parts['transactionId'], parts['outputIndex'])
+
+    @staticmethod
+    def parse_foundry_id(foundry_id: HexStr) -> FoundryIdParts:
+        """Split a foundry ID into its alias ID, serial number and token scheme type.
+        """
+        return from_dict(FoundryIdParts, _call_method('parseFoundryId', {
+            'foundryId': foundry_id
+        }))
+
+    @staticmethod
+    def token_id_to_foundry_id(token_id: HexStr) -> HexStr:
+        """Get the ID of the foundry which controls the supply of a native token.
+        """
+        return _call_method('tokenIdToFoundryId', {
+            'tokenId': token_id
+        })
+
+    @staticmethod
+    def foundry_id_to_token_id(foundry_id: HexStr) -> HexStr:
+        """Get the token ID of the native token of a foundry.
+        """
+        return _call_method('foundryIdToTokenId', {
+            'foundryId': foundry_id
+        })
+
+    @staticmethod
+    def bech32_to_alias_id(bech32: str) -> HexStr:
+        """Get the alias ID of a Bech32 encoded alias address.
+        """
+        return _call_method('bech32ToAliasId', {
+            'bech32': bech32
+        })
+
+    @staticmethod
+    def bech32_to_nft_id(bech32: str) -> HexStr:
+        """Get the NFT ID of a Bech32 encoded NFT address.
+        """
+        return _call_method('bech32ToNftId', {
+            'bech32': bech32
+        })
+
     @staticmethod
     def block_id(block: Block) -> HexStr:
         """ Return a block ID (Blake2b256 hash of block bytes) from a block.
@@ -220,15 +291,29 @@ class Utils():
             'message': message,
         })
 
+    @staticmethod
+    def verify_mnemonic_words(
+            mnemonic: str, words_with_positions: List[Tuple[int, str]]) -> bool:
+        """Check if the words entered by a user match the words of a mnemonic at the given zero-based positions, to
+        confirm a backup before the mnemonic is stored.
+        """
+        return _call_method('verifyMnemonicWords', {
+            'mnemonic': mnemonic,
+            'wordsWithPositions': words_with_positions,
+        })
+
     @staticmethod
     def verify_transaction_semantic(
-            inputs: List[InputSigningData], transaction: TransactionPayload, time: int) -> str:
-        """Verifies the semantic of a transaction.
+            inputs: List[InputSigningData], transaction: TransactionPayload, time: int,
+            protocol_parameters: Optional[NodeInfoProtocol] = None) -> str:
+        """Verifies the semantic of a transaction, with the inputs in any order. If protocol parameters are provided,
+        the transaction and the inputs are also checked against them.
         """
         return _call_method('verifyTransactionSemantic', {
             'inputs': [i.as_dict() for i in inputs],
             'transaction': transaction.as_dict(),
             'time': time,
+            'protocolParameters': protocol_parameters.as_dict() if protocol_parameters else None,
         })
 
     @staticmethod
@@ -241,6 +326,15 @@ class Utils():
             'protocolParameters': protocol_parameters.as_dict(),
         })
 
+    @staticmethod
+    def verify_transaction_proof(proof: TransactionProof) -> HexStr:
+        """Verifies a proof of a confirmed transaction and returns the transaction ID. The milestone index and
+        timestamp and the block ID of the proof still have to be compared with a trusted node or explorer.
+        """
+        return _call_method('verifyTransactionProof', {
+            'proof': proof.as_dict(),
+        })
+
     @staticmethod
     def block_bytes(
             block: Block) -> bytes:
@@ -259,6 +353,82 @@ class Utils():
             'block': block.as_dict(),
         })
 
+    @staticmethod
+    def pack_output(output: Output,
+                    protocol_parameters: Optional[NodeInfoProtocol] = None) -> HexStr:
+        """Returns the hex encoded serialized bytes of an output. If protocol parameters are provided, the output is
+        checked against them, including its storage deposit.
+        """
+        return _call_method('packOutput', {
+            'output': output.as_dict(),
+            'protocolParameters': protocol_parameters.as_dict() if protocol_parameters else None,
+        })
+
+    @staticmethod
+    def unpack_output(hex_bytes: HexStr,
+                      protocol_parameters: NodeInfoProtocol) -> Output:
+        """Deserializes hex encoded output bytes, checking them against the protocol parameters and rejecting
+        trailing bytes.
+        """
+        return output_from_dict(_call_method('unpackOutput', {
+            'bytes': hex_bytes,
+            'protocolParameters': protocol_parameters.as_dict(),
+        }))
+
+    @staticmethod
+    def pack_block(block: Block,
+                   protocol_parameters: Optional[NodeInfoProtocol] = None) -> HexStr:
+        """Returns the hex encoded serialized bytes of a block. If protocol parameters are provided, the block is
+        checked against them.
+        """
+        return _call_method('packBlock', {
+            'block': block.as_dict(),
+            'protocolParameters': protocol_parameters.as_dict() if protocol_parameters else None,
+        })
+
+    @staticmethod
+    def unpack_block(hex_bytes: HexStr,
+                     protocol_parameters: NodeInfoProtocol) -> Block:
+        """Deserializes hex encoded block bytes, checking them against the protocol parameters and rejecting
+        trailing bytes.
+        """
+        # pylint: disable=import-outside-toplevel
+        from iota_sdk.types.block import Block
+        return Block.from_dict(_call_method('unpackBlock', {
+            'bytes': hex_bytes,
+            'protocolParameters': protocol_parameters.as_dict(),
+        }))
+
+    @staticmethod
+    def negotiate_interface_version(versions: List[int]) -> int:
+        """Returns the highest of the given message interface versions that is supported by the Rust library.
+        """
+        return _call_method('negotiateInterfaceVersion', {
+            'versions': versions,
+        })
+
+    @staticmethod
+    def start_debug_trace(capacity: Optional[int] = None):
+        """Start recording all method calls with their execution time and response type, to debug the bindings.
+        Secrets are omitted. Only the latest `capacity` calls are kept, 100 by default, a previous trace is dropped.
+        """
+        _call_method('startDebugTrace', {
+            'capacity': capacity,
+        })
+
+    @staticmethod
+    def stop_debug_trace():
+        """Stop recording method calls and drop the recorded ones.
+        """
+        _call_method('stopDebugTrace')
+
+    @staticmethod
+    def get_debug_trace() -> List[MethodTrace]:
+        """Get the method calls recorded since the debug trace was started, oldest first.
+        """
+        return [from_dict(MethodTrace, trace)
+                for trace in _call_method('getDebugTrace')]
+
 
 class UtilsError(Exception):
     """A utils error."""
@@ -268,7 +438,8 @@ def _call_method(name: str, data=None):
     """Dumps json string and call call_utils_method().
     """
     message = {
-        'name': name
+        'name': name,
+        'version': INTERFACE_VERSION
     }
     if data:
         message['data'] = data
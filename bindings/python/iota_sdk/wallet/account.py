@@ -11,17 +11,20 @@ from iota_sdk.types.address import AccountAddress, AddressWithUnspentOutputs
 from iota_sdk.types.balance import Balance
 from iota_sdk.types.burn import Burn
 from iota_sdk.types.common import HexStr
-from iota_sdk.types.filter_options import FilterOptions
+from iota_sdk.types.filter_options import FilterOptions, OutputFilter
 from iota_sdk.types.native_token import NativeToken
-from iota_sdk.types.output_data import OutputData
+from iota_sdk.types.output_data import OutputData, Unlockability
 from iota_sdk.types.output_id import OutputId
 from iota_sdk.types.output import BasicOutput, NftOutput, Output, output_from_dict
 from iota_sdk.types.output_params import OutputParams
 from iota_sdk.types.transaction_data import PreparedTransactionData, SignedTransactionData
 from iota_sdk.types.send_params import CreateAliasOutputParams, CreateNativeTokenParams, MintNftParams, SendNativeTokensParams, SendNftParams, SendParams
-from iota_sdk.types.transaction import Transaction
+from iota_sdk.types.swap import SwapAcceptance, SwapAsset, SwapOffer
+from iota_sdk.types.transaction import BulkSendReport, ClaimLinkTransaction, NativeTokensTransaction, StorageDepositChoice, Transaction, TransactionProof
 from iota_sdk.types.transaction_options import TransactionOptions
-from iota_sdk.types.consolidation_params import ConsolidationParams
+from iota_sdk.types.chain_graph import ChainGraph
+from iota_sdk.types.consolidation_params import ConsolidationParams, ConsolidationPreview
+from iota_sdk.types.vesting import VestingSchedule, VestingStatus
 
 
 @dataclass
@@ -132,6 +135,18 @@ class Account:
         """
         return self.prepare_consolidate_outputs(params).send()
 
+    def consolidation_preview(
+            self, params: ConsolidationParams) -> ConsolidationPreview:
+        """Preview how many transactions consolidating outputs with the same params needs, how many outputs remain
+        and how the storage deposit changes, without creating a transaction.
+        """
+        preview = self._call_account_method(
+            'consolidationPreview', {
+                'params': params
+            }
+        )
+        return from_dict(ConsolidationPreview, preview)
+
     def prepare_consolidate_outputs(
             self, params: ConsolidationParams) -> PreparedTransaction:
         """Consolidate outputs.
@@ -219,6 +234,19 @@ class Account:
             }
         ))
 
+    def get_output_unlockability(self, output_id: OutputId) -> Optional[Unlockability]:
+        """Explain whether an output can currently be unlocked, and if not, why not.
+        Returns `None` if the output isn't stored in the account.
+        """
+        unlockability = self._call_account_method(
+            'getOutputUnlockability', {
+                'outputId': output_id
+            }
+        )
+        if unlockability is None:
+            return None
+        return from_dict(Unlockability, unlockability)
+
     def get_transaction(self, transaction_id: HexStr) -> Transaction:
         """Get transaction.
         """
@@ -228,6 +256,17 @@ class Account:
             }
         ))
 
+    def export_transaction_proof(self, transaction_id: HexStr) -> TransactionProof:
+        """Export a proof of a confirmed transaction of the account, which can be verified with
+        `Utils.verify_transaction_proof()` without access to the wallet. The consumed outputs are requested from the
+        node, so this fails once the node pruned them.
+        """
+        return TransactionProof.from_dict(self._call_account_method(
+            'exportTransactionProof', {
+                'transactionId': transaction_id
+            }
+        ))
+
     def addresses(self) -> List[AccountAddress]:
         """List addresses.
         """
@@ -268,6 +307,42 @@ class Account:
         )
         return [from_dict(OutputData, o) for o in outputs]
 
+    def query_outputs(self, output_filter: OutputFilter) -> List[OutputData]:
+        """Returns all outputs of the account that match the filter.
+        """
+        outputs = self._call_account_method(
+            'queryOutputs', {
+                'filter': output_filter
+            }
+        )
+        return [from_dict(OutputData, o) for o in outputs]
+
+    def reclaimable_outputs(self) -> List[OutputData]:
+        """Returns the expired storage deposit return outputs sent by the account, which can be reclaimed.
+        """
+        outputs = self._call_account_method(
+            'reclaimableOutputs'
+        )
+        return [from_dict(OutputData, o) for o in outputs]
+
+    def quarantined_outputs(self) -> List[OutputData]:
+        """Returns the unspent outputs which were quarantined as spam while syncing with `quarantine_spam_outputs`.
+        They aren't part of the balance and aren't used as inputs.
+        """
+        outputs = self._call_account_method(
+            'quarantinedOutputs'
+        )
+        return [from_dict(OutputData, o) for o in outputs]
+
+    def release_quarantined_outputs(self, output_ids: List[OutputId]):
+        """Release quarantined outputs which aren't spam, so they're part of the balance again.
+        """
+        return self._call_account_method(
+            'releaseQuarantinedOutputs', {
+                'outputIds': output_ids
+            }
+        )
+
     def incoming_transactions(self) -> List[Transaction]:
         """Returns all incoming transactions of the account.
         """
@@ -311,6 +386,45 @@ class Account:
         return PreparedCreateTokenTransaction(
             account=self, prepared_transaction_data=prepared)
 
+    def create_vesting(self, address: str, amount: int, schedule: VestingSchedule,
+                       options: Optional[TransactionOptions] = None) -> Transaction:
+        """Send an amount to an address in timelocked outputs, which are released according to a vesting schedule.
+        Every release has to cover the storage deposit of its output.
+        """
+        return self.prepare_create_vesting(
+            address, amount, schedule, options).send()
+
+    def prepare_create_vesting(self, address: str, amount: int, schedule: VestingSchedule,
+                               options: Optional[TransactionOptions] = None) -> PreparedTransaction:
+        """Prepare to send an amount to an address in timelocked outputs, which are released according to a vesting
+        schedule.
+        """
+        prepared = self._call_account_method(
+            'prepareCreateVesting', {
+                'address': address,
+                'amount': str(amount),
+                'schedule': schedule,
+                'options': options
+            }
+        )
+        return PreparedTransaction(self, prepared)
+
+    def chain_graph(self) -> ChainGraph:
+        """Get the dependencies between the aliases, foundries and NFTs of the account and the outputs they own,
+        e.g. the foundries to destroy before an alias can be destroyed.
+        """
+        return from_dict(ChainGraph, self._call_account_method(
+            'chainGraph'
+        ))
+
+    def vesting_status(self) -> List[VestingStatus]:
+        """Get the unspent vesting outputs of the account, grouped by the transaction which created them.
+        """
+        statuses = self._call_account_method(
+            'vestingStatus'
+        )
+        return [from_dict(VestingStatus, status) for status in statuses]
+
     def melt_native_token(self,
                           token_id: HexStr,
                           melt_amount: int,
@@ -428,6 +542,28 @@ class Account:
         )
         return PreparedTransaction(self, prepared)
 
+    def rebuild_conflicting_transaction(
+            self, transaction_id: HexStr) -> PreparedTransaction:
+        """Rebuild a transaction that was rejected because some of its inputs got spent by another transaction.
+        Still unspent inputs are reused and replacements for the spent ones are selected, the outputs are kept.
+        """
+        prepared = self._call_account_method(
+            'rebuildConflictingTransaction', {
+                'transactionId': transaction_id
+            }
+        )
+        return PreparedTransaction(self, prepared)
+
+    def remove_output_annotation(self, output_id: OutputId, key: str):
+        """Remove a local annotation from an output of the account.
+        """
+        return self._call_account_method(
+            'removeOutputAnnotation', {
+                'outputId': output_id,
+                'key': key
+            }
+        )
+
     def retry_transaction_until_included(
             self, transaction_id: HexStr, interval=None, max_attempts=None) -> HexStr:
         """Retries (promotes or reattaches) a transaction sent from the account for a provided transaction id until it's
@@ -452,6 +588,17 @@ class Account:
             }
         ))
 
+    def rebuild_ledger_from_history(
+            self, options: Optional[SyncOptions] = None) -> Balance:
+        """Discard the outputs and transactions of the account and sync them from the node again, to recover from a
+        corrupted local state. Only the addresses and the annotations of outputs which are found again are kept.
+        """
+        return from_dict(Balance, self._call_account_method(
+            'rebuildLedgerFromHistory', {
+                'options': options,
+            }
+        ))
+
     def send(self, amount: str, address: str,
              options: Optional[TransactionOptions] = None) -> Transaction:
         """Send base coins.
@@ -475,12 +622,51 @@ class Account:
             }
         ))
 
+    def bulk_send(
+            self, params: List[SendParams], options: Optional[TransactionOptions] = None) -> BulkSendReport:
+        """Send base coins to any number of recipients, split into as many transactions as needed.
+        If the funds are locked in a previously sent transaction, its inclusion is awaited before the next one is sent.
+        """
+        return from_dict(BulkSendReport, self._call_account_method(
+            'bulkSend', {
+                'params': params,
+                'options': options
+            }
+        ))
+
+    def distribute_nfts(
+            self, params: List[SendNftParams], options: Optional[TransactionOptions] = None) -> BulkSendReport:
+        """Send any number of NFTs, split into as many transactions as needed.
+        All NFTs are checked to be owned by the account before the first transaction is sent.
+        """
+        return from_dict(BulkSendReport, self._call_account_method(
+            'distributeNfts', {
+                'params': params,
+                'options': options
+            }
+        ))
+
     def send_native_tokens(
             self, params: List[SendNativeTokensParams], options: Optional[TransactionOptions] = None) -> Transaction:
         """Send native tokens.
         """
         return self.prepare_send_native_tokens(params, options).send()
 
+    def send_native_tokens_with_storage_deposits(
+            self, params: List[SendNativeTokensParams], options: Optional[TransactionOptions] = None) -> NativeTokensTransaction:
+        """Send native tokens and get how the storage deposit of each output was funded, which depends on the
+        `maxStorageDepositReturn` of the params.
+        """
+        result = self._call_account_method(
+            'sendNativeTokensWithStorageDeposits', {
+                'params': params,
+                'options': options
+            }
+        )
+        return NativeTokensTransaction(
+            Transaction.from_dict(result['transaction']),
+            [StorageDepositChoice(**choice) for choice in result['storageDeposits']])
+
     def prepare_send_native_tokens(
             self, params: List[SendNativeTokensParams], options: Optional[TransactionOptions] = None) -> PreparedTransaction:
         """Send native tokens.
@@ -530,6 +716,40 @@ class Account:
             }
         )
 
+    def set_identity_alias(self, alias_id: Optional[HexStr] = None):
+        """Set the alias output used as identity of the account, `None` removes it.
+        Outputs owned by its address are synced and unlocked by transitioning the alias, remainders are sent to it.
+        """
+        return self._call_account_method(
+            'setIdentityAlias', {
+                'aliasId': alias_id
+            }
+        )
+
+    def set_output_annotation(self, output_id: OutputId, key: str, value: str):
+        """Set a local annotation of an output of the account, replacing a previous value of the key.
+        If storage is enabled, will persist during restarts.
+        """
+        return self._call_account_method(
+            'setOutputAnnotation', {
+                'outputId': output_id,
+                'key': key,
+                'value': value
+            }
+        )
+
+    def verify_address_on_device(
+            self, address_index: int, internal: bool = False) -> bool:
+        """Display an address of the account on the Ledger Nano device for the user to confirm it.
+        Returns whether the user confirmed the address.
+        """
+        return self._call_account_method(
+            'verifyAddressOnDevice', {
+                'addressIndex': address_index,
+                'internal': internal
+            }
+        )
+
     def sign_transaction_essence(
             self, prepared_transaction_data: PreparedTransactionData) -> SignedTransactionData:
         """Sign a transaction essence.
@@ -576,6 +796,104 @@ class Account:
             }
         ))
 
+    def create_claim_link(self, amount: str, expiration: Optional[int] = None,
+                          options: Optional[TransactionOptions] = None) -> ClaimLinkTransaction:
+        """Send base coins to a freshly generated one-time key and get a link with which anyone can claim them.
+        If they aren't claimed within `expiration` seconds (one day by default), they are available for the account again.
+        """
+        result = self._call_account_method(
+            'createClaimLink', {
+                'amount': str(amount),
+                'expiration': expiration,
+                'options': options
+            }
+        )
+        return ClaimLinkTransaction(
+            result['claimLink'], Transaction.from_dict(result['transaction']))
+
+    def redeem_claim_link(self, claim_link: str) -> Transaction:
+        """Claim the funds of a claim link by sending them to the first address of the account.
+        """
+        return Transaction.from_dict(self._call_account_method(
+            'redeemClaimLink', {
+                'claimLink': claim_link
+            }
+        ))
+
+    def create_swap_offer(self, offered: SwapAsset, asked: SwapAsset,
+                          expiration: Optional[int] = None) -> SwapOffer:
+        """Create an offer to swap an asset of the account for an asset of another account. The outputs holding the
+        offered asset are locked until the swap is completed or cancelled, it isn't completed after `expiration`
+        seconds (one day by default).
+        """
+        return SwapOffer.from_dict(self._call_account_method(
+            'createSwapOffer', {
+                'offered': offered,
+                'asked': asked,
+                'expiration': expiration
+            }
+        ))
+
+    def accept_swap_offer(self, offer: SwapOffer) -> SwapAcceptance:
+        """Accept a swap offer of another account by building the swap transaction and signing the inputs of the
+        account. The returned acceptance has to be sent back to the maker.
+        """
+        return SwapAcceptance.from_dict(self._call_account_method(
+            'acceptSwapOffer', {
+                'offer': offer
+            }
+        ))
+
+    def complete_swap(self, offer: SwapOffer,
+                      acceptance: SwapAcceptance) -> Transaction:
+        """Complete a swap offer of the account which was accepted by another account: check that the account receives
+        the asked asset, sign its inputs and submit the transaction.
+        """
+        return Transaction.from_dict(self._call_account_method(
+            'completeSwap', {
+                'offer': offer,
+                'acceptance': acceptance
+            }
+        ))
+
+    def cancel_swap(self, offer: SwapOffer):
+        """Cancel a swap offer of the account by unlocking its inputs, so the swap can't be completed anymore.
+        """
+        self._call_account_method(
+            'cancelSwap', {
+                'offer': offer
+            }
+        )
+
+    def reclaim_expired_outputs(self) -> Transaction:
+        """Reclaim the outputs returned by `reclaimable_outputs()`.
+        """
+        return self.prepare_reclaim_expired_outputs().send()
+
+    def prepare_reclaim_expired_outputs(self) -> PreparedTransaction:
+        """Reclaim the outputs returned by `reclaimable_outputs()`.
+        """
+        return PreparedTransaction(self, self._call_account_method(
+            'prepareReclaimExpiredOutputs'
+        ))
+
+    def burn_quarantined_outputs(
+            self, output_ids: List[OutputId], options: Optional[TransactionOptions] = None) -> Transaction:
+        """Consume quarantined outputs, burning their native tokens and NFTs. Their base coins are kept.
+        """
+        return self.prepare_burn_quarantined_outputs(output_ids, options).send()
+
+    def prepare_burn_quarantined_outputs(
+            self, output_ids: List[OutputId], options: Optional[TransactionOptions] = None) -> PreparedTransaction:
+        """Prepare to consume quarantined outputs, burning their native tokens and NFTs. Their base coins are kept.
+        """
+        return PreparedTransaction(self, self._call_account_method(
+            'prepareBurnQuarantinedOutputs', {
+                'outputIds': output_ids,
+                'options': options
+            }
+        ))
+
     def send_outputs(
             self, outputs: List[Output], options: Optional[TransactionOptions] = None) -> Transaction:
         """Send outputs in a transaction.
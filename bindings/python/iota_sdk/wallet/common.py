@@ -5,7 +5,7 @@ import json
 from json import dumps, JSONEncoder
 from enum import Enum
 import humps
-from iota_sdk import call_wallet_method
+from iota_sdk.external import call_wallet_method, INTERFACE_VERSION
 
 
 def _call_method_routine(func):
@@ -54,6 +54,7 @@ def _call_method_routine(func):
                                  for k, v in obj.items() if k is not None and v is not None)
             return obj
         message_null_filtered = remove_none(message)
+        message_null_filtered['version'] = INTERFACE_VERSION
         message = dumps(humps.camelize(message_null_filtered))
         # Send message to the Rust library
         response = call_wallet_method(args[0].handle, message)
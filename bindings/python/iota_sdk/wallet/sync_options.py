@@ -2,6 +2,7 @@
 # SPDX-License-Identifier: Apache-2.0
 
 from typing import List, Optional
+from iota_sdk.types.common import HexStr
 
 
 class AccountSyncOptions():
@@ -67,6 +68,22 @@ class NftSyncOptions():
         self.aliasOutputs = alias_outputs
 
 
+class NativeTokenFilter():
+    """Filter for the native tokens of the outputs received by an account.
+
+    Attributes:
+        filter_type: `allowlist` tracks only outputs whose native tokens are all listed, `denylist` ignores
+            outputs with a listed native token.
+        token_ids: The IDs of the native tokens.
+    """
+
+    def __init__(self, filter_type: str, token_ids: List[HexStr]):
+        """Initialize `Self`.
+        """
+        self.type = filter_type
+        self.tokenIds = token_ids
+
+
 class SyncOptions():
     """The synchronization options.
 
@@ -103,6 +120,21 @@ class SyncOptions():
         This will overwrite the `account`, `alias` and `nft` options.
     sync_native_token_foundries :
         Sync native token foundries, so their metadata can be returned in the balance.
+    incremental :
+        Only request the outputs which changed since the previous sync of an address, based on the
+        milestone at which it was synced. Falls back to a full sync for addresses which weren't synced
+        with the same options before, or if too many milestones passed in between.
+    parallel_requests :
+        Maximum amount of addresses, transactions and foundries which are synced at the same time.
+        The amount of API requests sent at the same time is also limited by the
+        `max_parallel_api_requests` of the client.
+    native_token_filter :
+        Ignores basic outputs with native tokens which aren't accepted by the filter, e.g. airdropped spam
+        tokens. Tracked outputs which are ignored after changing the filter are removed from the account.
+    quarantine_spam_outputs :
+        Quarantine new outputs which look like spam: basic and NFT outputs with a dust amount, without a known
+        sender and with links in their metadata or tag. Quarantined outputs aren't part of the balance and
+        aren't used as inputs.
     """
 
     def __init__(self,
@@ -116,7 +148,11 @@ class SyncOptions():
                  alias: Optional[AliasSyncOptions] = None,
                  nft: Optional[NftSyncOptions] = None,
                  sync_only_most_basic_outputs: Optional[bool] = None,
-                 sync_native_token_foundries: Optional[bool] = None):
+                 sync_native_token_foundries: Optional[bool] = None,
+                 incremental: Optional[bool] = None,
+                 parallel_requests: Optional[int] = None,
+                 native_token_filter: Optional[NativeTokenFilter] = None,
+                 quarantine_spam_outputs: Optional[bool] = None):
         """Initialize `Self`.
         """
         self.addresses = addresses
@@ -130,6 +166,10 @@ class SyncOptions():
         self.nft = nft
         self.syncOnlyMostBasicOutputs = sync_only_most_basic_outputs
         self.syncNativeTokenFoundries = sync_native_token_foundries
+        self.incremental = incremental
+        self.parallelRequests = parallel_requests
+        self.nativeTokenFilter = native_token_filter
+        self.quarantineSpamOutputs = quarantine_spam_outputs
 
     def as_dict(self):
         """Converts this object to a dict.
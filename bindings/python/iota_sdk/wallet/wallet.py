@@ -1,18 +1,46 @@
 # Copyright 2023 IOTA Stiftung
 # SPDX-License-Identifier: Apache-2.0
 
-from json import dumps
-from typing import Any, Dict, List, Optional, Union
-from iota_sdk import destroy_wallet, create_wallet, listen_wallet, get_client_from_wallet, get_secret_manager_from_wallet, Client
+from json import dumps, loads
+import weakref
+from typing import Any, Callable, Dict, List, Optional, Union
+from dacite import from_dict
+from iota_sdk import destroy_wallet, create_wallet, listen_wallet, set_approval_handler, clear_approval_handler, get_client_from_wallet, get_secret_manager_from_wallet, Client
 from iota_sdk.secret_manager.secret_manager import LedgerNanoSecretManager, MnemonicSecretManager, StrongholdSecretManager, SeedSecretManager, SecretManager
 from iota_sdk.types.address import AccountAddress
 from iota_sdk.types.client_options import ClientOptions
+from iota_sdk.types.privacy_report import PrivacyReport
+from iota_sdk.types.send_params import SendDefaults
+from iota_sdk.types.transaction_data import PreparedTransactionData
 from iota_sdk.wallet.account import Account, _call_method_routine
 from iota_sdk.wallet.sync_options import SyncOptions
 
 # pylint: disable=too-many-public-methods
 
 
+class ListenerHandle():
+    """A handle to a listener registered with `Wallet.listen()`, with which it can be removed again.
+    It doesn't keep the wallet alive.
+
+    Attributes:
+        id: The id of the listener.
+    """
+
+    def __init__(self, listener_id: int, wallet: 'Wallet'):
+        """Initialize `self`.
+        """
+        self.id = listener_id
+        self._wallet = weakref.ref(wallet)
+
+    def unsubscribe(self) -> bool:
+        """Remove the listener for all its event types. Returns `False` if it was already removed or the wallet is gone.
+        """
+        wallet = self._wallet()
+        if wallet is None:
+            return False
+        return wallet.remove_listener(self.id)
+
+
 class Wallet():
     """An IOTA Wallet.
 
@@ -25,8 +53,20 @@ class Wallet():
                  client_options: Optional[Union[Dict[str,
                                                      Any], ClientOptions]] = None,
                  coin_type: Optional[int] = None,
-                 secret_manager: Optional[Union[LedgerNanoSecretManager, MnemonicSecretManager, SeedSecretManager, StrongholdSecretManager]] = None):
+                 secret_manager: Optional[Union[LedgerNanoSecretManager, MnemonicSecretManager, SeedSecretManager, StrongholdSecretManager]] = None,
+                 output_maturity: Optional[int] = None,
+                 service_fee: Optional[Dict[str, Any]] = None,
+                 network_validation: Optional[str] = None,
+                 send_defaults: Optional[SendDefaults] = None,
+                 account_index: Optional[int] = None):
         """Initialize `self`.
+
+        Args:
+            output_maturity: The time in seconds after which received outputs are selected as inputs, counted from their booking milestone.
+            service_fee: A fee added as an additional output to every transaction sending funds out of an account, e.g. `{'address': 'rms1...', 'amount': {'type': 'basisPoints', 'value': 100}}`.
+            network_validation: How the wallet reacts if the network of the node doesn't match the coin type or the addresses of its accounts, one of `strict`, `warn` (default) or `disabled`.
+            send_defaults: Defaults for the return address and expiration of outputs with a storage deposit return, stored with the wallet.
+            account_index: Bind the wallet to the single account with this account index, to host wallets of multiple users off one seed.
         """
 
         # Setup the options
@@ -40,6 +80,16 @@ class Wallet():
             options['coinType'] = coin_type
         if secret_manager:
             options['secretManager'] = secret_manager
+        if output_maturity:
+            options['outputMaturity'] = output_maturity
+        if service_fee:
+            options['serviceFee'] = service_fee
+        if network_validation:
+            options['networkValidation'] = network_validation
+        if send_defaults:
+            options['sendDefaults'] = send_defaults.as_dict()
+        if account_index is not None:
+            options['accountIndex'] = account_index
 
         options_str: str = dumps(options)
 
@@ -119,6 +169,49 @@ class Wallet():
         return [Account(account_data, self.handle)
                 for account_data in accounts_data]
 
+    def get_send_defaults(self) -> SendDefaults:
+        """Get the wallet-wide defaults for the return address and expiration of outputs with a storage deposit return.
+        """
+        return from_dict(SendDefaults, self._call_method(
+            'getSendDefaults'
+        ))
+
+    def set_send_defaults(self, send_defaults: SendDefaults):
+        """Set the wallet-wide defaults for the return address and expiration of outputs with a storage deposit return,
+        used if they aren't provided per call. They are stored with the wallet.
+        """
+        return self._call_method(
+            'setSendDefaults',
+            {
+                'sendDefaults': send_defaults.as_dict()
+            }
+        )
+
+    def privacy_report(self) -> PrivacyReport:
+        """Analyze the stored transactions of all accounts for address reuse, round amounts and addresses linked by spending their outputs together.
+        The accounts should be synced first.
+        """
+        return from_dict(PrivacyReport, self._call_method(
+            'privacyReport'
+        ))
+
+    def dump_state_json(self) -> Dict[str, Any]:
+        """Returns the accounts, their default sync options, the labels and the client options of the wallet as JSON.
+        Secrets are excluded, so the state can be attached to bug reports or loaded into a wallet with another storage.
+        """
+        return self._call_method(
+            'dumpStateJson'
+        )
+
+    def load_state_json(self, state: Dict[str, Any]):
+        """Loads a state returned by `dump_state_json()` into a wallet without accounts.
+        """
+        return self._call_method(
+            'loadStateJson', {
+                'state': state
+            }
+        )
+
     def backup(self, destination: str, password: str):
         """Backup storage.
         """
@@ -231,12 +324,13 @@ class Wallet():
             }
         )
 
-    def store_mnemonic(self, mnemonic: str):
-        """Store mnemonic.
+    def store_mnemonic(self, mnemonic: str, passphrase: Optional[str] = None):
+        """Store mnemonic, optionally with a BIP-39 passphrase.
         """
         return self._call_method(
             'storeMnemonic', {
-                'mnemonic': mnemonic
+                'mnemonic': mnemonic,
+                'passphrase': passphrase
             }
 
         )
@@ -259,12 +353,37 @@ class Wallet():
             'stopBackgroundSync',
         )
 
-    def listen(self, handler, events: Optional[List[int]] = None):
+    def shutdown(self):
+        """Cancel long running operations like syncing, stop the background tasks and wait until the operations in flight stopped.
+        Operations started afterwards fail.
+        """
+        return self._call_method(
+            'shutdown',
+        )
+
+    def listen(self, handler, events: Optional[List[int]] = None) -> ListenerHandle:
         """Listen to wallet events, empty array or None will listen to all events.
-        The default value for events is None.
+        The default value for events is None. Returns a handle with which the listener can be removed again.
         """
         events_array = [] if events is None else events
-        listen_wallet(self.handle, events_array, handler)
+        listener_id = listen_wallet(self.handle, events_array, handler)
+        return ListenerHandle(listener_id, self)
+
+    def remove_listener(self, listener_id: int) -> bool:
+        """Remove a single listener for all its event types. Returns `False` if it was already removed.
+        """
+        return self._call_method(
+            'removeListener', {
+                'listenerId': listener_id
+            }
+        )
+
+    def listener_counts(self) -> Dict[str, int]:
+        """Get the number of listeners for each event type that has any, keyed by the name of the event type.
+        """
+        return self._call_method(
+            'listenerCounts'
+        )
 
     def clear_listeners(self, events: Optional[List[int]] = None):
         """Remove wallet event listeners, empty array or None will remove all listeners.
@@ -277,6 +396,24 @@ class Wallet():
             }
         )
 
+    def set_approval_handler(
+            self, handler: Callable[[int, PreparedTransactionData], bool]):
+        """Set a handler which has to approve every transaction before it gets signed, replacing a previous one.
+        The handler is called with the account index and the prepared transaction data, transactions that aren't approved fail.
+        """
+        set_approval_handler(
+            self.handle,
+            lambda account_index, prepared_transaction_data: handler(
+                account_index,
+                from_dict(
+                    PreparedTransactionData,
+                    loads(prepared_transaction_data))))
+
+    def clear_approval_handler(self):
+        """Remove the approval handler, transactions get signed without approval again.
+        """
+        clear_approval_handler(self.handle)
+
     def destroy(self):
         """Destroys the wallet instance.
         """
@@ -2,7 +2,7 @@
 // SPDX-License-Identifier: Apache-2.0
 
 use iota_sdk_bindings_core::{
-    call_client_method as rust_call_client_method,
+    call_client_method as rust_call_client_method, deserialize_method,
     iota_sdk::client::{
         mqtt::{Error as MqttError, Topic},
         Client as RustClient, ClientBuilder,
@@ -34,7 +34,7 @@ pub fn create_client(options: Option<String>) -> Result<Client> {
 
 #[pyfunction]
 pub fn call_client_method(client: &Client, method: String) -> Result<String> {
-    let method = serde_json::from_str::<ClientMethod>(&method)?;
+    let method = deserialize_method::<ClientMethod>(&method)?;
     let response = crate::block_on(async { rust_call_client_method(&client.client, method).await });
 
     Ok(serde_json::to_string(&response)?)
@@ -11,7 +11,7 @@ mod wallet;
 use std::sync::Mutex;
 
 use iota_sdk_bindings_core::{
-    call_utils_method as rust_call_utils_method, init_logger as rust_init_logger,
+    call_utils_method as rust_call_utils_method, deserialize_method, init_logger as rust_init_logger,
     iota_sdk::client::stronghold::StrongholdAdapter, UtilsMethod,
 };
 use once_cell::sync::OnceCell;
@@ -41,7 +41,7 @@ pub fn init_logger(config: String) -> Result<()> {
 
 #[pyfunction]
 pub fn call_utils_method(method: String) -> Result<String> {
-    let method = serde_json::from_str::<UtilsMethod>(&method)?;
+    let method = deserialize_method::<UtilsMethod>(&method)?;
     let response = rust_call_utils_method(method);
     Ok(serde_json::to_string(&response)?)
 }
@@ -89,6 +89,8 @@ fn iota_sdk(_py: Python<'_>, m: &PyModule) -> PyResult<()> {
     m.add_function(wrap_pyfunction!(get_secret_manager_from_wallet, m)?)
         .unwrap();
     m.add_function(wrap_pyfunction!(listen_wallet, m)?).unwrap();
+    m.add_function(wrap_pyfunction!(set_approval_handler, m)?).unwrap();
+    m.add_function(wrap_pyfunction!(clear_approval_handler, m)?).unwrap();
 
     m.add_function(wrap_pyfunction!(migrate_stronghold_snapshot_v2_to_v3, m)?)
         .unwrap();
@@ -4,7 +4,7 @@
 use std::sync::Arc;
 
 use iota_sdk_bindings_core::{
-    call_secret_manager_method as rust_call_secret_manager_method,
+    call_secret_manager_method as rust_call_secret_manager_method, deserialize_method,
     iota_sdk::client::secret::{SecretManager as RustSecretManager, SecretManagerDto},
     SecretManagerMethod,
 };
@@ -30,7 +30,7 @@ pub fn create_secret_manager(options: String) -> Result<SecretManager> {
 
 #[pyfunction]
 pub fn call_secret_manager_method(secret_manager: &SecretManager, method: String) -> Result<String> {
-    let method = serde_json::from_str::<SecretManagerMethod>(&method)?;
+    let method = deserialize_method::<SecretManagerMethod>(&method)?;
     let response =
         crate::block_on(async { rust_call_secret_manager_method(&secret_manager.secret_manager, method).await });
 
@@ -3,9 +3,13 @@
 
 use std::sync::Arc;
 
+use async_trait::async_trait;
 use iota_sdk_bindings_core::{
-    call_wallet_method as rust_call_wallet_method,
-    iota_sdk::wallet::{events::types::WalletEventType, Wallet as RustWallet},
+    call_wallet_method as rust_call_wallet_method, deserialize_method,
+    iota_sdk::{
+        client::api::{PreparedTransactionData, PreparedTransactionDataDto},
+        wallet::{core::TransactionApprover, events::types::WalletEventType, Wallet as RustWallet},
+    },
     Response, WalletMethod, WalletOptions,
 };
 use pyo3::{prelude::*, types::PyTuple};
@@ -45,7 +49,7 @@ pub fn create_wallet(options: String) -> Result<Wallet> {
 /// Call a wallet method.
 #[pyfunction]
 pub fn call_wallet_method(wallet: &Wallet, method: String) -> Result<String> {
-    let method = serde_json::from_str::<WalletMethod>(&method)?;
+    let method = deserialize_method::<WalletMethod>(&method)?;
     let response = crate::block_on(async {
         match wallet.wallet.read().await.as_ref() {
             Some(wallet) => rust_call_wallet_method(wallet, method).await,
@@ -56,9 +60,9 @@ pub fn call_wallet_method(wallet: &Wallet, method: String) -> Result<String> {
     Ok(serde_json::to_string(&response)?)
 }
 
-/// Listen to wallet events.
+/// Listen to wallet events, returns the id of the listener with which it can be removed again.
 #[pyfunction]
-pub fn listen_wallet(wallet: &Wallet, events: Vec<u8>, handler: PyObject) {
+pub fn listen_wallet(wallet: &Wallet, events: Vec<u8>, handler: PyObject) -> u64 {
     let mut rust_events = Vec::with_capacity(events.len());
 
     for event in events {
@@ -71,7 +75,7 @@ pub fn listen_wallet(wallet: &Wallet, events: Vec<u8>, handler: PyObject) {
         rust_events.push(event);
     }
 
-    crate::block_on(async {
+    let handle = crate::block_on(async {
         wallet
             .wallet
             .read()
@@ -85,6 +89,69 @@ pub fn listen_wallet(wallet: &Wallet, events: Vec<u8>, handler: PyObject) {
                     handler.call1(py, args).expect("failed to call python callback");
                 });
             })
+            .await
+    });
+    handle.id().value()
+}
+
+/// Set a handler which has to approve every transaction before it gets signed.
+/// A failing handler rejects the transaction.
+#[pyfunction]
+pub fn set_approval_handler(wallet: &Wallet, handler: PyObject) {
+    crate::block_on(async {
+        wallet
+            .wallet
+            .read()
+            .await
+            .as_ref()
+            .expect("wallet got destroyed")
+            .set_approval_handler(PythonApprover(Arc::new(handler)))
+            .await;
+    });
+}
+
+// Approves transactions with a Python handler, which is called on its own thread. The result is awaited without
+// blocking a runtime thread, a failing handler rejects the transaction.
+struct PythonApprover(Arc<PyObject>);
+
+#[async_trait]
+impl TransactionApprover for PythonApprover {
+    async fn approve_transaction(
+        &self,
+        account_index: u32,
+        prepared_transaction_data: &PreparedTransactionData,
+    ) -> bool {
+        let prepared_transaction_data =
+            serde_json::to_string(&PreparedTransactionDataDto::from(prepared_transaction_data))
+                .expect("json to string error");
+        let handler = self.0.clone();
+        let (sender, receiver) = tokio::sync::oneshot::channel();
+
+        std::thread::spawn(move || {
+            let approved = Python::with_gil(|py| {
+                handler
+                    .call1(py, (account_index, prepared_transaction_data))
+                    .and_then(|approved| approved.extract::<bool>(py))
+                    .unwrap_or(false)
+            });
+            let _ = sender.send(approved);
+        });
+
+        receiver.await.unwrap_or(false)
+    }
+}
+
+/// Remove the approval handler.
+#[pyfunction]
+pub fn clear_approval_handler(wallet: &Wallet) {
+    crate::block_on(async {
+        wallet
+            .wallet
+            .read()
+            .await
+            .as_ref()
+            .expect("wallet got destroyed")
+            .clear_approval_handler()
             .await;
     });
 }
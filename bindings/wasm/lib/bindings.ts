@@ -5,14 +5,19 @@
 // The rest of the TypeScript definitions are copied as-is to the `out` directory before being compiled.
 
 import { __UtilsMethods__ } from './utils';
+import { INTERFACE_VERSION } from './types';
 
 // Import needs to be in a single line, otherwise it breaks
 // prettier-ignore
 // @ts-ignore: path is set to match runtime transpiled js path when bundled.
-import { initLogger, createClient, destroyClient, createSecretManager, createWallet, callClientMethodAsync, callSecretManagerMethodAsync, callUtilsMethodRust, callWalletMethodAsync, destroyWallet, listenWalletAsync, getClientFromWallet, getSecretManagerFromWallet, listenMqtt, migrateStrongholdSnapshotV2ToV3, migrateDbChrysalisToStardust } from '../wasm/iota_sdk_wasm';
+import { initLogger, createClient, destroyClient, createSecretManager, createWallet, callClientMethodAsync, callSecretManagerMethodAsync, callUtilsMethodRust, callWalletMethodAsync, destroyWallet, listenWalletAsync, getClientFromWallet, getSecretManagerFromWallet, listenMqtt, migrateStrongholdSnapshotV2ToV3, migrateDbChrysalisToStardust, setApprovalHandler, clearApprovalHandler, forEachOutputIdsPage } from '../wasm/iota_sdk_wasm';
 
 const callUtilsMethod = (method: __UtilsMethods__): any => {
-    const response = JSON.parse(callUtilsMethodRust(JSON.stringify(method)));
+    const response = JSON.parse(
+        callUtilsMethodRust(
+            JSON.stringify({ ...method, version: INTERFACE_VERSION }),
+        ),
+    );
     if (response.type == 'error' || response.type == 'panic') {
         throw response;
     } else {
@@ -37,4 +42,7 @@ export {
     listenMqtt,
     migrateStrongholdSnapshotV2ToV3,
     migrateDbChrysalisToStardust,
+    setApprovalHandler,
+    clearApprovalHandler,
+    forEachOutputIdsPage,
 };
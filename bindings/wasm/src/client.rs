@@ -2,8 +2,8 @@
 // SPDX-License-Identifier: Apache-2.0
 
 use iota_sdk_bindings_core::{
-    call_client_method,
-    iota_sdk::client::{Client, ClientBuilder},
+    call_client_method, deserialize_method,
+    iota_sdk::client::{node_api::indexer::query_parameters::QueryParameter, Client, ClientBuilder, Error},
     ClientMethod, Response,
 };
 use wasm_bindgen::{prelude::wasm_bindgen, JsCast, JsValue};
@@ -52,7 +52,7 @@ pub fn call_client_method_async(method: String, methodHandler: &ClientMethodHand
     let client: Client = methodHandler.client.clone();
 
     let promise: js_sys::Promise = future_to_promise(async move {
-        let method: ClientMethod = serde_json::from_str(&method).map_err(|err| err.to_string())?;
+        let method: ClientMethod = deserialize_method(&method).map_err(|err| err.to_string())?;
 
         let response = call_client_method(&client, method).await;
         let ser = JsValue::from(serde_json::to_string(&response).map_err(|err| err.to_string())?);
@@ -66,6 +66,55 @@ pub fn call_client_method_async(method: String, methodHandler: &ClientMethodHand
     Ok(promise.unchecked_into())
 }
 
+/// Calls the callback with every page of output ids that fit the query parameters, as JSON-encoded
+/// `OutputIdsResponse`, so the output ids of all pages don't have to be kept in memory at once.
+///
+/// Stops and rejects with the error if the callback throws.
+#[wasm_bindgen(js_name = forEachOutputIdsPage)]
+#[allow(non_snake_case)]
+pub fn for_each_output_ids_page(
+    queryParameters: String,
+    callback: js_sys::Function,
+    methodHandler: &ClientMethodHandler,
+) -> Result<js_sys::Promise, JsValue> {
+    let client: Client = methodHandler.client.clone();
+    let query_parameters: Vec<QueryParameter> =
+        serde_json::from_str(&queryParameters).map_err(|err| err.to_string())?;
+
+    Ok(future_to_promise(async move {
+        client
+            .for_each_output_ids_raw(query_parameters, |page| {
+                let page = serde_json::to_string(&page).map_err(|err| err.to_string())?;
+                callback.call1(&JsValue::NULL, &JsValue::from(page))?;
+                Ok(())
+            })
+            .await
+            .map_err(|CallbackError(err)| err)?;
+        Ok(JsValue::UNDEFINED)
+    }))
+}
+
+// Errors of the client and of the JavaScript callback while iterating over pages
+struct CallbackError(JsValue);
+
+impl From<Error> for CallbackError {
+    fn from(err: Error) -> Self {
+        Self(JsValue::from(err.to_string()))
+    }
+}
+
+impl From<JsValue> for CallbackError {
+    fn from(err: JsValue) -> Self {
+        Self(err)
+    }
+}
+
+impl From<String> for CallbackError {
+    fn from(err: String) -> Self {
+        Self(JsValue::from(err))
+    }
+}
+
 /// MQTT is not supported for WebAssembly bindings.
 ///
 /// Throws an error if called, only included for compatibility
@@ -4,7 +4,7 @@
 use std::sync::Arc;
 
 use iota_sdk_bindings_core::{
-    call_secret_manager_method,
+    call_secret_manager_method, deserialize_method,
     iota_sdk::client::secret::{SecretManager, SecretManagerDto},
     Response, SecretManagerMethod,
 };
@@ -43,7 +43,7 @@ pub fn call_secret_manager_method_async(
 ) -> Result<PromiseString, JsValue> {
     let secret_manager = methodHandler.secret_manager.clone();
     let promise: js_sys::Promise = future_to_promise(async move {
-        let method: SecretManagerMethod = serde_json::from_str(&method).map_err(|err| err.to_string())?;
+        let method: SecretManagerMethod = deserialize_method(&method).map_err(|err| err.to_string())?;
 
         let response = call_secret_manager_method(&secret_manager, method).await;
         let ser = JsValue::from(serde_json::to_string(&response).map_err(|err| err.to_string())?);
@@ -4,7 +4,7 @@
 use std::sync::Arc;
 
 use iota_sdk_bindings_core::{
-    call_wallet_method,
+    call_wallet_method, deserialize_method,
     iota_sdk::wallet::{
         events::types::{Event, WalletEventType},
         Wallet,
@@ -81,7 +81,7 @@ pub async fn get_secret_manager(method_handler: &WalletMethodHandler) -> Result<
 /// Returns an error if the response itself is an error or panic.
 #[wasm_bindgen(js_name = callWalletMethodAsync)]
 pub async fn call_wallet_method_async(method: String, method_handler: &WalletMethodHandler) -> Result<String, JsValue> {
-    let method: WalletMethod = serde_json::from_str(&method).map_err(|err| err.to_string())?;
+    let method: WalletMethod = deserialize_method(&method).map_err(|err| err.to_string())?;
 
     let response = call_wallet_method(
         method_handler
@@ -102,7 +102,7 @@ pub async fn call_wallet_method_async(method: String, method_handler: &WalletMet
 }
 
 /// It takes a list of event types, registers a callback function, and then listens for events of those
-/// types
+/// types. Returns the id of the listener, with which it can be removed again.
 ///
 /// Arguments:
 ///
@@ -125,7 +125,7 @@ pub async fn listen_wallet(
     }
 
     let (tx, mut rx): (UnboundedSender<Event>, UnboundedReceiver<Event>) = unbounded_channel();
-    method_handler
+    let handle = method_handler
         .wallet
         .lock()
         .await
@@ -150,7 +150,7 @@ pub async fn listen_wallet(
         // No more links to the unbounded_channel, exit loop
     });
 
-    Ok(JsValue::UNDEFINED)
+    Ok(JsValue::from(handle.id().value() as f64))
 }
 
 /// Rocksdb chrysalis migration is not supported for WebAssembly bindings.
@@ -163,3 +163,26 @@ pub fn migrate_db_chrysalis_to_stardust(_storage_path: String, _password: Option
 
     Err(JsValue::from(js_error))
 }
+
+/// Transaction approval handlers are not supported for WebAssembly bindings, as they block until the callback
+/// returned.
+///
+/// Throws an error if called, only included for compatibility
+/// with the Node.js bindings TypeScript definitions.
+#[wasm_bindgen(js_name = setApprovalHandler)]
+pub fn set_approval_handler(_callback: js_sys::Function, _method_handler: &WalletMethodHandler) -> Result<(), JsValue> {
+    let js_error = js_sys::Error::new("Transaction approval handlers are not supported for WebAssembly");
+
+    Err(JsValue::from(js_error))
+}
+
+/// Transaction approval handlers are not supported for WebAssembly bindings.
+///
+/// Throws an error if called, only included for compatibility
+/// with the Node.js bindings TypeScript definitions.
+#[wasm_bindgen(js_name = clearApprovalHandler)]
+pub fn clear_approval_handler(_method_handler: &WalletMethodHandler) -> Result<(), JsValue> {
+    let js_error = js_sys::Error::new("Transaction approval handlers are not supported for WebAssembly");
+
+    Err(JsValue::from(js_error))
+}
@@ -5,22 +5,23 @@ use clap::Parser;
 use colored::Colorize;
 use iota_sdk::{
     client::secret::SecretManager,
-    wallet::{Account, Wallet},
+    wallet::{account::VestingSchedule, Account, Wallet},
 };
 use rustyline::{error::ReadlineError, history::MemHistory, Config, Editor};
 
 use crate::{
     command::{
         account::{
-            address_command, addresses_command, balance_command, burn_native_token_command, burn_nft_command,
-            claim_command, claimable_outputs_command, consolidate_command, create_alias_outputs_command,
-            create_native_token_command, decrease_voting_power_command, destroy_alias_command, destroy_foundry_command,
-            faucet_command, increase_voting_power_command, melt_native_token_command, mint_native_token,
+            address_command, addresses_command, airdrop_command, balance_command, burn_native_token_command,
+            burn_nft_command, claim_command, claimable_outputs_command, consolidate_command,
+            create_alias_outputs_command, create_native_token_command, decrease_voting_power_command,
+            destroy_alias_command, destroy_foundry_command, faucet_command, graph_command,
+            increase_voting_power_command, label_command, labels_command, melt_native_token_command, mint_native_token,
             mint_nft_command, new_address_command, node_info_command, output_command, outputs_command,
             participation_overview_command, send_command, send_native_token_command, send_nft_command,
             stop_participating_command, sync_command, transaction_command, transactions_command,
-            unspent_outputs_command, vote_command, voting_output_command, voting_power_command, AccountCli,
-            AccountCommand,
+            unspent_outputs_command, validate_tx_command, vesting_command, vesting_status_command, vote_command,
+            voting_output_command, voting_power_command, AccountCli, AccountCommand,
         },
         account_completion::AccountPromptHelper,
     },
@@ -120,8 +121,12 @@ pub async fn account_prompt_internal(
                         }
                     };
                     match account_cli.command {
-                        AccountCommand::Address { selector } => address_command(account, selector).await,
+                        AccountCommand::Address { selector } => address_command(wallet, account, selector).await,
                         AccountCommand::Addresses => addresses_command(account).await,
+                        AccountCommand::Airdrop { csv_file, state_file } => {
+                            ensure_password(wallet).await?;
+                            airdrop_command(account, csv_file, state_file).await
+                        }
                         AccountCommand::Balance { addresses } => balance_command(account, addresses).await,
                         AccountCommand::BurnNativeToken { token_id, amount } => {
                             ensure_password(wallet).await?;
@@ -136,9 +141,11 @@ pub async fn account_prompt_internal(
                             claim_command(account, output_id).await
                         }
                         AccountCommand::ClaimableOutputs => claimable_outputs_command(account).await,
-                        AccountCommand::Consolidate => {
-                            ensure_password(wallet).await?;
-                            consolidate_command(account).await
+                        AccountCommand::Consolidate { dry_run } => {
+                            if !dry_run {
+                                ensure_password(wallet).await?;
+                            }
+                            consolidate_command(account, dry_run).await
                         }
                         AccountCommand::CreateAliasOutput => {
                             ensure_password(wallet).await?;
@@ -159,9 +166,12 @@ pub async fn account_prompt_internal(
                             )
                             .await
                         }
-                        AccountCommand::DestroyAlias { alias_id } => {
+                        AccountCommand::DestroyAlias {
+                            alias_id,
+                            with_foundries,
+                        } => {
                             ensure_password(wallet).await?;
-                            destroy_alias_command(account, alias_id).await
+                            destroy_alias_command(account, alias_id, with_foundries).await
                         }
                         AccountCommand::DestroyFoundry { foundry_id } => {
                             ensure_password(wallet).await?;
@@ -170,7 +180,14 @@ pub async fn account_prompt_internal(
                         AccountCommand::Exit => {
                             return Ok(AccountPromptResponse::Done);
                         }
-                        AccountCommand::Faucet { address, url } => faucet_command(account, address, url).await,
+                        AccountCommand::Faucet { address, url, wait } => {
+                            faucet_command(account, address, url, wait).await
+                        }
+                        AccountCommand::Graph { dot } => graph_command(account, dot).await,
+                        AccountCommand::Label { chain_id, label } => {
+                            label_command(wallet, account, chain_id, label).await
+                        }
+                        AccountCommand::Labels => labels_command(wallet).await,
                         AccountCommand::MeltNativeToken { token_id, amount } => {
                             ensure_password(wallet).await?;
                             melt_native_token_command(account, token_id, amount).await
@@ -203,11 +220,13 @@ pub async fn account_prompt_internal(
                         }
                         AccountCommand::NewAddress => {
                             ensure_password(wallet).await?;
-                            new_address_command(account).await
+                            new_address_command(wallet, account).await
                         }
                         AccountCommand::NodeInfo => node_info_command(account).await,
-                        AccountCommand::Output { selector } => output_command(account, selector).await,
-                        AccountCommand::Outputs => outputs_command(account).await,
+                        AccountCommand::Output { selector, raw, json } => {
+                            output_command(account, selector, raw, json).await
+                        }
+                        AccountCommand::Outputs { filter } => outputs_command(wallet, account, filter).await,
                         AccountCommand::Send {
                             address,
                             amount,
@@ -248,11 +267,27 @@ pub async fn account_prompt_internal(
                             return Ok(AccountPromptResponse::Switch(wallet.get_account(account_id).await?));
                         }
                         AccountCommand::Sync => sync_command(account).await,
-                        AccountCommand::Transaction { selector } => transaction_command(account, selector).await,
+                        AccountCommand::Transaction { selector, raw, json } => {
+                            transaction_command(wallet, account, selector, raw, json).await
+                        }
                         AccountCommand::Transactions { show_details } => {
                             transactions_command(account, show_details).await
                         }
-                        AccountCommand::UnspentOutputs => unspent_outputs_command(account).await,
+                        AccountCommand::UnspentOutputs => unspent_outputs_command(wallet, account).await,
+                        AccountCommand::ValidateTx { path } => validate_tx_command(account, path).await,
+                        AccountCommand::Vesting {
+                            address,
+                            amount,
+                            cliff,
+                            period,
+                            releases,
+                        } => {
+                            ensure_password(wallet).await?;
+                            let schedule =
+                                VestingSchedule::new(cliff.as_secs() as u32, period.as_secs() as u32, releases);
+                            vesting_command(account, address, amount, schedule).await
+                        }
+                        AccountCommand::VestingStatus => vesting_status_command(account).await,
                         AccountCommand::Vote { event_id, answers } => {
                             ensure_password(wallet).await?;
                             vote_command(account, event_id, answers).await
@@ -1,34 +1,59 @@
 // Copyright 2023 IOTA Stiftung
 // SPDX-License-Identifier: Apache-2.0
 
-use std::str::FromStr;
+use std::{
+    collections::{BTreeMap, HashMap},
+    str::FromStr,
+};
 
 use clap::{CommandFactory, Parser, Subcommand};
+use dialoguer::console::Term;
 use iota_sdk::{
-    client::request_funds_from_faucet,
+    client::{
+        api::{verify_semantic, SignedTransactionDataDto},
+        secret::types::InputSigningData,
+        FaucetClient,
+    },
     types::{
         api::plugins::participation::types::ParticipationEventId,
         block::{
-            address::{Address, Bech32Address, ToBech32Ext},
+            address::{Address, Bech32Address, Hrp, ToBech32Ext},
+            input::Input,
             output::{
-                unlock_condition::AddressUnlockCondition, AliasId, AliasOutput, BasicOutputBuilder, FoundryId,
-                NativeToken, NativeTokensBuilder, NftId, NftOutput, Output, OutputId, TokenId,
+                unlock_condition::AddressUnlockCondition, AliasId, AliasOutput, BasicOutputBuilder, ChainId, Feature,
+                FoundryId, NativeToken, NativeTokensBuilder, NftId, NftOutput, Output, OutputId, TokenId, TokenScheme,
+                UnlockCondition,
             },
-            payload::transaction::TransactionId,
+            payload::{
+                transaction::{dto::TransactionPayloadDto, TransactionEssence, TransactionId, TransactionPayload},
+                Payload,
+            },
+            semantic::ConflictReason,
             ConvertTo,
         },
+        TryFromDto,
     },
     wallet::{
         account::{
-            types::{AccountIdentifier, OutputData, Transaction},
+            types::{
+                AccountIdentifier, OutputData, OutputDataDto, OutputFilter, Transaction, TransactionDto, Unlockability,
+            },
             Account, ConsolidationParams, FilterOptions, OutputsToClaim, SyncOptions, TransactionOptions,
+            VestingSchedule,
         },
-        CreateNativeTokenParams, MintNftParams, SendNativeTokensParams, SendNftParams, SendParams,
+        CreateNativeTokenParams, MintNftParams, SendNativeTokensParams, SendNftParams, SendParams, Wallet,
     },
     U256,
 };
 
-use crate::{error::Error, helper::to_utc_date_time, println_log_info};
+use crate::{
+    error::Error,
+    helper::{get_decision, print_progress, to_utc_date_time},
+    println_log_info,
+};
+
+// Rows of an airdrop are sent in chunks of this size, leaving room for remainder outputs.
+const AIRDROP_CHUNK_SIZE: usize = 100;
 
 #[derive(Debug, Parser)]
 #[command(author, version, about, long_about = None, propagate_version = true)]
@@ -55,6 +80,15 @@ pub enum AccountCommand {
     },
     /// List the account addresses.
     Addresses,
+    /// Send base coins or native tokens to the recipients of a CSV file with `address,amount[,token_id]` rows.
+    /// Sent rows are stored in a state file, so an interrupted airdrop is resumed by running it again.
+    Airdrop {
+        /// Path of the CSV file, e.g. airdrop.csv.
+        csv_file: String,
+        /// Path of the state file, defaults to the path of the CSV file with a `.state.json` suffix.
+        #[arg(long)]
+        state_file: Option<String>,
+    },
     /// Print the account balance.
     Balance {
         /// Addresses to compute the balance for.
@@ -80,7 +114,11 @@ pub enum AccountCommand {
     /// Print details about claimable outputs - if there are any.
     ClaimableOutputs,
     /// Consolidate all basic outputs into one address.
-    Consolidate,
+    Consolidate {
+        /// Only print how many transactions are needed and how the outputs and storage deposit change.
+        #[arg(long, default_value_t = false)]
+        dry_run: bool,
+    },
     /// Create a new alias output.
     CreateAliasOutput,
     /// Create a native token.
@@ -100,6 +138,10 @@ pub enum AccountCommand {
     DestroyAlias {
         /// Alias ID to be destroyed, e.g. 0xed5a90106ae5d402ebaecb9ba36f32658872df789f7a29b9f6d695b912ec6a1e.
         alias_id: String,
+        /// Destroy the foundries controlled by the alias first, after melting their native tokens held by the
+        /// account.
+        #[arg(long, default_value_t = false)]
+        with_foundries: bool,
     },
     /// Destroy a foundry.
     DestroyFoundry {
@@ -115,7 +157,27 @@ pub enum AccountCommand {
         address: Option<Bech32Address>,
         /// URL of the faucet, default to <https://faucet.testnet.shimmer.network/api/enqueue>.
         url: Option<String>,
+        /// Wait until the funds arrived and sync the account afterwards.
+        #[arg(long, default_value_t = false)]
+        wait: bool,
     },
+    /// Show which outputs depend on the aliases and NFTs of the account: foundries controlled by aliases and outputs
+    /// owned by alias or NFT addresses.
+    Graph {
+        /// Print the graph in the DOT language of Graphviz, e.g. `graph --dot` and render it with `dot -Tsvg`.
+        #[arg(long, default_value_t = false)]
+        dot: bool,
+    },
+    /// Set a local label for an alias, foundry or NFT ID, or remove it if no label is provided.
+    Label {
+        /// Alias, foundry or NFT ID to be labeled, e.g.
+        /// 0xecadf10e6545aa82da4df2dfd2a496b457c8850d2cab49b7464cb273d3dffb07.
+        chain_id: String,
+        /// Label to be shown for the ID, e.g. ticket.
+        label: Option<String>,
+    },
+    /// List the labels of alias, foundry and NFT IDs.
+    Labels,
     /// Mint additional native tokens.
     MintNativeToken {
         /// Token ID to be minted, e.g. 0x087d205988b733d97fb145ae340e27a8b19554d1ceee64574d7e5ff66c45f69e7a0100000000.
@@ -166,9 +228,20 @@ pub enum AccountCommand {
         /// Selector for output.
         /// Either by ID (e.g. 0xbce525324af12eda02bf7927e92cea3a8e8322d0f41966271443e6c3b245a4400000) or index.
         selector: OutputSelector,
+        /// Print the output with the debug representation instead of decoding it.
+        #[arg(long, default_value_t = false, conflicts_with = "json")]
+        raw: bool,
+        /// Print the output as JSON.
+        #[arg(long, default_value_t = false)]
+        json: bool,
     },
     /// List all outputs.
-    Outputs,
+    Outputs {
+        /// Only list outputs matching the filter, provided as JSON, e.g.
+        /// '{"type":"all","value":[{"type":"unspent"},{"type":"outputTypes","value":[3]}]}'.
+        #[arg(long)]
+        filter: Option<String>,
+    },
     /// Send an amount.
     Send {
         /// Address to send funds to, e.g. rms1qztwng6cty8cfm42nzvq099ev7udhrnk0rw8jt8vttf9kpqnxhpsx869vr3.
@@ -177,12 +250,13 @@ pub enum AccountCommand {
         amount: u64,
         /// Bech32 encoded return address, to which the storage deposit will be returned if one is necessary
         /// given the provided amount. If a storage deposit is needed and a return address is not provided, it will
-        /// default to the first address of the account.
+        /// default to the one set with `set-send-defaults`, or the first address of the account.
         #[arg(long)]
         return_address: Option<Bech32Address>,
         /// Expiration in seconds, after which the output will be available for the sender again, if not spent by the
         /// receiver already. The expiration will only be used if one is necessary given the provided amount. If an
-        /// expiration is needed but not provided, it will default to one day.
+        /// expiration is needed but not provided, it will default to the one set with `set-send-defaults`, or one
+        /// day.
         #[arg(long)]
         expiration: Option<humantime::Duration>,
         /// Whether to send micro amounts. This will automatically add Storage Deposit Return and Expiration unlock
@@ -223,6 +297,12 @@ pub enum AccountCommand {
         /// Selector for transaction.
         /// Either by ID (e.g. 0x84fe6b1796bddc022c9bc40206f0a692f4536b02aa8c13140264e2e01a3b7e4b) or index.
         selector: TransactionSelector,
+        /// Print the transaction with the debug representation instead of decoding it.
+        #[arg(long, default_value_t = false, conflicts_with = "json")]
+        raw: bool,
+        /// Print the transaction as JSON.
+        #[arg(long, default_value_t = false)]
+        json: bool,
     },
     /// List the account transactions.
     #[clap(visible_alias = "txs")]
@@ -231,8 +311,33 @@ pub enum AccountCommand {
         #[arg(long, default_value_t = false)]
         show_details: bool,
     },
-    /// List the account unspent outputs.
+    /// List the account unspent outputs and whether they can be unlocked.
     UnspentOutputs,
+    /// Validate a signed transaction locally and show why a node would reject it.
+    ValidateTx {
+        /// Path to a JSON file with the transaction payload, or with the transaction payload and the inputs data like
+        /// a signed transaction of the bindings. If the inputs data isn't included, the inputs are fetched from the
+        /// node.
+        path: String,
+    },
+    /// Send an amount in timelocked outputs, which are released after a cliff and then every period.
+    Vesting {
+        /// Address to send the funds to, e.g. rms1qztwng6cty8cfm42nzvq099ev7udhrnk0rw8jt8vttf9kpqnxhpsx869vr3.
+        address: Bech32Address,
+        /// Total amount to send, e.g. 12000000.
+        amount: u64,
+        /// Time until the first release, e.g. 180days.
+        #[arg(long)]
+        cliff: humantime::Duration,
+        /// Time between two releases, e.g. 30days.
+        #[arg(long)]
+        period: humantime::Duration,
+        /// Number of releases, including the one at the cliff, e.g. 12.
+        #[arg(long)]
+        releases: u32,
+    },
+    /// List the vesting outputs of the account, grouped by the transaction which created them.
+    VestingStatus,
     /// Cast votes for an event.
     Vote {
         /// Event ID for which to cast votes, e.g. 0xdc049a721dc65ec342f836c876ec15631ed915cd55213cee39e8d1c821c751f2.
@@ -327,15 +432,15 @@ impl FromStr for AddressSelector {
 }
 
 /// `address` command
-pub async fn address_command(account: &Account, selector: AddressSelector) -> Result<(), Error> {
+pub async fn address_command(wallet: &Wallet, account: &Account, selector: AddressSelector) -> Result<(), Error> {
     match selector {
         AddressSelector::Address(address) => {
-            print_address(account, &address).await?;
+            print_address(wallet, account, &address).await?;
         }
         AddressSelector::Index(index) => {
             let addresses = get_addresses_sorted(account).await?;
             if let Some(address) = addresses.get(index) {
-                print_address(account, address).await?;
+                print_address(wallet, account, address).await?;
             } else {
                 println_log_info!("No address found at index {index}");
             }
@@ -352,6 +457,232 @@ pub async fn addresses_command(account: &Account) -> Result<(), Error> {
     Ok(())
 }
 
+/// A row of an airdrop CSV file
+struct AirdropRow {
+    /// Line number in the CSV file, starting at 1
+    line: usize,
+    /// The row as written in the CSV file
+    row: String,
+    address: Bech32Address,
+    amount: U256,
+    /// Base coins are sent if there is no token ID
+    token_id: Option<TokenId>,
+}
+
+fn parse_airdrop_csv(csv: &str) -> Result<Vec<AirdropRow>, Error> {
+    let mut rows = Vec::new();
+
+    for (index, row) in csv.lines().enumerate() {
+        let line = index + 1;
+        let row = row.trim();
+        if row.is_empty() || row.starts_with('#') {
+            continue;
+        }
+        let fields = row.split(',').map(str::trim).collect::<Vec<_>>();
+        // Skip an optional header
+        if line == 1 && fields[0].eq_ignore_ascii_case("address") {
+            continue;
+        }
+        let invalid_row = |reason: String| Error::Miscellaneous(format!("invalid row in line {line}: {reason}"));
+
+        let (address, amount, token_id) = match fields.as_slice() {
+            [address, amount] => (address, amount, None),
+            [address, amount, token_id] => (
+                address,
+                amount,
+                Some(TokenId::from_str(token_id).map_err(|e| invalid_row(e.to_string()))?),
+            ),
+            _ => return Err(invalid_row("expected `address,amount[,token_id]`".to_string())),
+        };
+        let address = Bech32Address::try_from_str(address).map_err(|e| invalid_row(e.to_string()))?;
+        let amount = if token_id.is_some() {
+            U256::from_dec_str(amount).map_err(|e| invalid_row(e.to_string()))?
+        } else {
+            U256::from(amount.parse::<u64>().map_err(|e| invalid_row(e.to_string()))?)
+        };
+        if amount.is_zero() {
+            return Err(invalid_row("amount must not be zero".to_string()));
+        }
+
+        rows.push(AirdropRow {
+            line,
+            row: row.to_string(),
+            address,
+            amount,
+            token_id,
+        });
+    }
+
+    Ok(rows)
+}
+
+// Sends a chunk of airdrop rows with the same kind of asset and returns the transaction ID for each row. If the
+// transaction fails after some rows were sent already, they are returned together with the error.
+async fn send_airdrop_chunk(
+    account: &Account,
+    chunk: &[&AirdropRow],
+) -> Result<Vec<TransactionId>, (Vec<TransactionId>, Error)> {
+    if chunk[0].token_id.is_none() {
+        let params = chunk
+            .iter()
+            // Base coin amounts were parsed from an u64, so they fit
+            .map(|row| SendParams::new(row.amount.as_u64(), row.address))
+            .collect::<Result<Vec<_>, _>>()
+            .map_err(|e| (Vec::new(), e.into()))?;
+        match account.bulk_send(params, None).await {
+            Ok(report) => Ok(report.recipients),
+            Err(iota_sdk::wallet::Error::BulkSendIncomplete { report, error }) => {
+                Err((report.recipients, (*error).into()))
+            }
+            Err(e) => Err((Vec::new(), e.into())),
+        }
+    } else {
+        let params = chunk
+            .iter()
+            // Safe to unwrap, native token rows have a token ID
+            .map(|row| SendNativeTokensParams::new(row.address, [(row.token_id.unwrap(), row.amount)]))
+            .collect::<Result<Vec<_>, _>>()
+            .map_err(|e| (Vec::new(), e.into()))?;
+        let transaction = account
+            .send_native_tokens(params, None)
+            .await
+            .map_err(|e| (Vec::new(), e.into()))?;
+        Ok(vec![transaction.transaction_id; chunk.len()])
+    }
+}
+
+/// `airdrop` command
+pub async fn airdrop_command(account: &Account, csv_file: String, state_file: Option<String>) -> Result<(), Error> {
+    let rows = parse_airdrop_csv(&tokio::fs::read_to_string(&csv_file).await?)?;
+    let bech32_hrp = account.client().get_bech32_hrp().await?;
+    if let Some(row) = rows.iter().find(|row| row.address.hrp() != &bech32_hrp) {
+        return Err(Error::Miscellaneous(format!(
+            "invalid row in line {}: address doesn't match the network HRP {bech32_hrp}",
+            row.line
+        )));
+    }
+
+    // Rows sent in a previous run, by line number
+    let state_file = state_file.unwrap_or_else(|| format!("{csv_file}.state.json"));
+    let mut state = match tokio::fs::read_to_string(&state_file).await {
+        Ok(state) => serde_json::from_str::<serde_json::Map<String, serde_json::Value>>(&state)?,
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => serde_json::Map::new(),
+        Err(e) => return Err(e.into()),
+    };
+    let mut pending_rows = Vec::new();
+    for row in &rows {
+        match state.get(&row.line.to_string()) {
+            Some(sent) if sent["row"] == row.row.as_str() => {}
+            Some(sent) => {
+                return Err(Error::Miscellaneous(format!(
+                    "line {} changed since it was sent in transaction {}, remove {state_file} to start over",
+                    row.line,
+                    sent["transactionId"].as_str().unwrap_or_default()
+                )));
+            }
+            None => pending_rows.push(row),
+        }
+    }
+
+    // Preview the totals
+    let mut base_coin_total = 0u64;
+    let mut native_token_totals = BTreeMap::<TokenId, U256>::new();
+    for row in &pending_rows {
+        match row.token_id {
+            Some(token_id) => *native_token_totals.entry(token_id).or_default() += row.amount,
+            None => base_coin_total = base_coin_total.saturating_add(row.amount.as_u64()),
+        }
+    }
+    let balance = account.balance().await?;
+    println_log_info!(
+        "Airdrop to {} recipients, {} of {} rows already sent:",
+        pending_rows.len(),
+        rows.len() - pending_rows.len(),
+        rows.len()
+    );
+    println_log_info!(
+        "- {base_coin_total} base coins, {} available",
+        balance.base_coin().available()
+    );
+    for (token_id, total) in &native_token_totals {
+        let available = balance
+            .native_tokens()
+            .iter()
+            .find(|native_token| native_token.token_id() == token_id)
+            .map(|native_token| native_token.available())
+            .unwrap_or_default();
+        println_log_info!("- {total} of native token {token_id}, {available} available");
+        if *total > available {
+            return Err(Error::Miscellaneous(format!(
+                "insufficient amount of native token {token_id}"
+            )));
+        }
+    }
+    if base_coin_total > balance.base_coin().available() {
+        return Err(Error::Miscellaneous("insufficient amount of base coins".to_string()));
+    }
+    if pending_rows.is_empty() || !get_decision("Send the airdrop?")? {
+        return Ok(());
+    }
+
+    // Send base coins first, then native tokens
+    pending_rows.sort_by_key(|row| row.token_id.is_some());
+    let native_tokens_start = pending_rows.partition_point(|row| row.token_id.is_none());
+    let chunks = pending_rows[..native_tokens_start]
+        .chunks(AIRDROP_CHUNK_SIZE)
+        .chain(pending_rows[native_tokens_start..].chunks(AIRDROP_CHUNK_SIZE));
+    let term = Term::stdout();
+    let mut sent_rows = 0;
+    let mut pending_transaction_id = None;
+    print_progress(&term, sent_rows, pending_rows.len())?;
+
+    for chunk in chunks {
+        let result = loop {
+            match send_airdrop_chunk(account, chunk).await {
+                // Outputs created by the previous transaction, like its remainder, are only available once it's
+                // included and synced
+                Err((sent, Error::Wallet(iota_sdk::wallet::Error::InsufficientFunds { .. })))
+                    if sent.is_empty() && pending_transaction_id.is_some() =>
+                {
+                    let transaction_id = pending_transaction_id.take().unwrap();
+                    account
+                        .retry_transaction_until_included(&transaction_id, None, None)
+                        .await?;
+                    account.sync(None).await?;
+                }
+                result => break result,
+            }
+        };
+        let (transaction_ids, error) = match result {
+            Ok(transaction_ids) => (transaction_ids, None),
+            Err((transaction_ids, error)) => (transaction_ids, Some(error)),
+        };
+
+        for (row, transaction_id) in chunk.iter().zip(&transaction_ids) {
+            log::info!("Airdrop line {} sent in transaction {transaction_id}", row.line);
+            state.insert(
+                row.line.to_string(),
+                serde_json::json!({ "row": row.row, "transactionId": transaction_id }),
+            );
+        }
+        tokio::fs::write(&state_file, serde_json::to_string_pretty(&state)?).await?;
+        sent_rows += transaction_ids.len();
+        pending_transaction_id = transaction_ids.last().copied().or(pending_transaction_id);
+        print_progress(&term, sent_rows, pending_rows.len())?;
+
+        if let Some(error) = error {
+            term.write_line("")?;
+            println_log_info!("Airdrop stopped, run it again to resume. Sent rows are stored in {state_file}");
+            return Err(error);
+        }
+    }
+    term.write_line("")?;
+
+    println_log_info!("Airdrop sent to {sent_rows} recipients, sent rows are stored in {state_file}");
+
+    Ok(())
+}
+
 // `balance` command
 pub async fn balance_command(account: &Account, addresses: Option<Vec<Bech32Address>>) -> Result<(), Error> {
     let balance = if let Some(addresses) = addresses {
@@ -485,7 +816,15 @@ pub async fn claimable_outputs_command(account: &Account) -> Result<(), Error> {
 }
 
 // `consolidate` command
-pub async fn consolidate_command(account: &Account) -> Result<(), Error> {
+pub async fn consolidate_command(account: &Account, dry_run: bool) -> Result<(), Error> {
+    if dry_run {
+        let preview = account
+            .consolidation_preview(ConsolidationParams::new().with_force(true))
+            .await?;
+        println_log_info!("{preview:#?}");
+        return Ok(());
+    }
+
     println_log_info!("Consolidating outputs.");
 
     let transaction = account
@@ -557,9 +896,24 @@ pub async fn create_native_token_command(
 }
 
 // `destroy-alias` command
-pub async fn destroy_alias_command(account: &Account, alias_id: String) -> Result<(), Error> {
+pub async fn destroy_alias_command(account: &Account, alias_id: String, with_foundries: bool) -> Result<(), Error> {
     println_log_info!("Destroying alias {alias_id}.");
 
+    if with_foundries {
+        let plan = account.burn_plan(AliasId::from_str(&alias_id)?).await?;
+        println_log_info!("Sending {} transactions.", plan.steps.len());
+
+        for transaction in account.burn_with_plan(plan, None).await? {
+            println_log_info!(
+                "Transaction sent:\n{:?}\n{:?}",
+                transaction.transaction_id,
+                transaction.block_id
+            );
+        }
+
+        return Ok(());
+    }
+
     let transaction = account.burn(AliasId::from_str(&alias_id)?, None).await?;
 
     println_log_info!(
@@ -591,6 +945,7 @@ pub async fn faucet_command(
     account: &Account,
     address: Option<Bech32Address>,
     url: Option<String>,
+    wait: bool,
 ) -> Result<(), Error> {
     let address = if let Some(address) = address {
         address
@@ -604,7 +959,87 @@ pub async fn faucet_command(
         .as_deref()
         .unwrap_or("https://faucet.testnet.shimmer.network/api/enqueue");
 
-    println_log_info!("{}", request_funds_from_faucet(faucet_url, &address).await?);
+    let faucet = FaucetClient::new(faucet_url)?;
+
+    if wait {
+        println_log_info!("Requested funds for {address}, waiting until they arrived...");
+        let output_ids = faucet.request_funds_and_wait(account.client(), &address, None).await?;
+        println_log_info!("Received funds in {} output(s).", output_ids.len());
+        sync_command(account).await?;
+    } else {
+        let response = faucet.request_funds(&address).await?;
+        println_log_info!(
+            "Requested funds for {}, {} request(s) waiting in the faucet queue.",
+            response.address,
+            response.waiting_requests
+        );
+    }
+
+    Ok(())
+}
+
+// `graph` command
+pub async fn graph_command(account: &Account, dot: bool) -> Result<(), Error> {
+    let graph = account.chain_graph().await;
+
+    if dot {
+        // Raw stdout instead of `println_log_info!`, so the DOT output can be piped into Graphviz
+        println!("{}", graph.to_dot());
+        return Ok(());
+    }
+
+    if graph.nodes.is_empty() {
+        println_log_info!("No aliases, foundries or NFTs found");
+        return Ok(());
+    }
+
+    for node in &graph.nodes {
+        let chain_id = node
+            .chain_id
+            .map_or_else(|| node.output_id.to_string(), |chain_id| chain_id.to_string());
+        println_log_info!("{:<8}{chain_id}", format!("{:?}", node.kind));
+        for edge in graph.dependents(&node.output_id) {
+            println_log_info!("  {:?} {}", edge.relation, edge.dependent);
+        }
+    }
+
+    Ok(())
+}
+
+// `label` command
+pub async fn label_command(
+    wallet: &Wallet,
+    account: &Account,
+    chain_id: String,
+    label: Option<String>,
+) -> Result<(), Error> {
+    let chain_id = parse_chain_id(account, &chain_id).await?;
+
+    if let Some(label) = label {
+        wallet.set_label(chain_id, label.clone()).await?;
+        println_log_info!("Labeled {chain_id} as \"{label}\".");
+    } else if let Some(label) = wallet.remove_label(chain_id).await? {
+        println_log_info!("Removed label \"{label}\" of {chain_id}.");
+    } else {
+        println_log_info!("No label found for {chain_id}.");
+    }
+
+    Ok(())
+}
+
+// `labels` command
+pub async fn labels_command(wallet: &Wallet) -> Result<(), Error> {
+    let mut labels = wallet.labels().await.into_iter().collect::<Vec<_>>();
+
+    if labels.is_empty() {
+        println_log_info!("No labels found");
+    } else {
+        labels.sort_unstable_by(|(_, a), (_, b)| a.cmp(b));
+
+        for (chain_id, label) in labels {
+            println_log_info!("{:<20}{}\t{}", label, chain_id, chain_id_kind(&chain_id));
+        }
+    }
 
     Ok(())
 }
@@ -682,10 +1117,10 @@ pub async fn mint_nft_command(
 }
 
 // `new-address` command
-pub async fn new_address_command(account: &Account) -> Result<(), Error> {
+pub async fn new_address_command(wallet: &Wallet, account: &Account) -> Result<(), Error> {
     let address = account.generate_ed25519_addresses(1, None).await?;
 
-    print_address(account, address[0].address()).await?;
+    print_address(wallet, account, address[0].address()).await?;
 
     Ok(())
 }
@@ -700,7 +1135,7 @@ pub async fn node_info_command(account: &Account) -> Result<(), Error> {
 }
 
 /// `output` command
-pub async fn output_command(account: &Account, selector: OutputSelector) -> Result<(), Error> {
+pub async fn output_command(account: &Account, selector: OutputSelector, raw: bool, json: bool) -> Result<(), Error> {
     let output = match selector {
         OutputSelector::Id(id) => account.get_output(&id).await,
         OutputSelector::Index(index) => {
@@ -711,7 +1146,14 @@ pub async fn output_command(account: &Account, selector: OutputSelector) -> Resu
     };
 
     if let Some(output) = output {
-        println_log_info!("{output:#?}");
+        if raw {
+            println_log_info!("{output:#?}");
+        } else if json {
+            println_log_info!("{}", serde_json::to_string_pretty(&OutputDataDto::from(&output))?);
+        } else {
+            let hrp = account.client().get_bech32_hrp().await?;
+            println_log_info!("{}", format_output_data(&output, hrp)?);
+        }
     } else {
         println_log_info!("Output not found");
     }
@@ -720,8 +1162,16 @@ pub async fn output_command(account: &Account, selector: OutputSelector) -> Resu
 }
 
 /// `outputs` command
-pub async fn outputs_command(account: &Account) -> Result<(), Error> {
-    print_outputs(account.outputs(None).await?, "Outputs:").await
+pub async fn outputs_command(wallet: &Wallet, account: &Account, filter: Option<String>) -> Result<(), Error> {
+    let outputs = match filter {
+        Some(filter) => {
+            account
+                .query_outputs(serde_json::from_str::<OutputFilter>(&filter)?)
+                .await
+        }
+        None => account.outputs(None).await?,
+    };
+    print_outputs(outputs, "Outputs:", &wallet.labels().await, &HashMap::new()).await
 }
 
 // `send` command
@@ -834,7 +1284,13 @@ pub async fn sync_command(account: &Account) -> Result<(), Error> {
 }
 
 /// `transaction` command
-pub async fn transaction_command(account: &Account, selector: TransactionSelector) -> Result<(), Error> {
+pub async fn transaction_command(
+    wallet: &Wallet,
+    account: &Account,
+    selector: TransactionSelector,
+    raw: bool,
+    json: bool,
+) -> Result<(), Error> {
     let mut transactions = account.transactions().await;
     let transaction = match selector {
         TransactionSelector::Id(id) => transactions.into_iter().find(|tx| tx.transaction_id == id),
@@ -845,7 +1301,37 @@ pub async fn transaction_command(account: &Account, selector: TransactionSelecto
     };
 
     if let Some(tx) = transaction {
+        let labels = wallet.labels().await;
+
+        if json {
+            println_log_info!("{}", serde_json::to_string_pretty(&TransactionDto::from(&tx))?);
+            return Ok(());
+        } else if !raw {
+            let hrp = account.client().get_bech32_hrp().await?;
+            println_log_info!("{}", format_transaction(&tx, hrp, &labels)?);
+            return Ok(());
+        }
+
         println_log_info!("{:#?}", tx);
+
+        let TransactionEssence::Regular(essence) = tx.payload.essence();
+        let labeled_chain_ids = essence
+            .outputs()
+            .iter()
+            .enumerate()
+            .filter_map(|(index, output)| {
+                let output_id = OutputId::new(tx.transaction_id, index as u16).ok()?;
+                let chain_id = output.chain_id()?.or_from_output_id(&output_id);
+                labels.get(&chain_id).map(|label| (chain_id, label))
+            })
+            .collect::<Vec<_>>();
+
+        if !labeled_chain_ids.is_empty() {
+            println_log_info!("Labels:");
+            for (chain_id, label) in labeled_chain_ids {
+                println_log_info!("  {chain_id}\t{label}");
+            }
+        }
     } else {
         println_log_info!("No transaction found");
     }
@@ -877,8 +1363,134 @@ pub async fn transactions_command(account: &Account, show_details: bool) -> Resu
 }
 
 /// `unspent-outputs` command
-pub async fn unspent_outputs_command(account: &Account) -> Result<(), Error> {
-    print_outputs(account.unspent_outputs(None).await?, "Unspent outputs:").await
+pub async fn unspent_outputs_command(wallet: &Wallet, account: &Account) -> Result<(), Error> {
+    let current_time = account.client().get_time_checked().await?;
+    let protocol_parameters = account.client().get_protocol_parameters().await?;
+    let outputs = account.unspent_outputs(None).await?;
+
+    let unlockabilities = {
+        let account_details = account.details().await;
+        outputs
+            .iter()
+            .map(|output_data| {
+                output_data
+                    .unlockability(&account_details, current_time, &protocol_parameters)
+                    .map(|unlockability| (output_data.output_id, unlockability))
+            })
+            .collect::<Result<HashMap<_, _>, _>>()?
+    };
+
+    print_outputs(outputs, "Unspent outputs:", &wallet.labels().await, &unlockabilities).await
+}
+
+/// `validate-tx` command
+pub async fn validate_tx_command(account: &Account, path: String) -> Result<(), Error> {
+    let json = std::fs::read_to_string(path)?;
+    let (transaction, inputs_data) = match serde_json::from_str::<SignedTransactionDataDto>(&json) {
+        Ok(signed_transaction) => (
+            signed_transaction.transaction_payload,
+            Some(signed_transaction.inputs_data),
+        ),
+        Err(_) => (serde_json::from_str::<TransactionPayloadDto>(&json)?, None),
+    };
+    let protocol_parameters = account.client().get_protocol_parameters().await?;
+    let transaction = TransactionPayload::try_from_dto_with_params(transaction, &protocol_parameters)?;
+
+    let inputs_data = match inputs_data {
+        Some(inputs_data) => inputs_data
+            .into_iter()
+            .map(|input| Ok(InputSigningData::try_from_dto_with_params(input, &protocol_parameters)?))
+            .collect::<Result<Vec<_>, Error>>()?,
+        None => {
+            let TransactionEssence::Regular(essence) = transaction.essence();
+            let output_ids = essence
+                .inputs()
+                .iter()
+                .filter_map(|input| match input {
+                    Input::Utxo(utxo_input) => Some(*utxo_input.output_id()),
+                    Input::Treasury(_) => None,
+                })
+                .collect::<Vec<_>>();
+            let outputs = account.client().get_outputs(&output_ids).await?;
+            if let Some(spent) = outputs.iter().find(|output| output.metadata().is_spent()) {
+                println_log_info!(
+                    "Transaction {} would be rejected: {} ({})",
+                    transaction.id(),
+                    ConflictReason::InputUtxoAlreadySpent,
+                    spent.metadata().output_id()
+                );
+                return Ok(());
+            }
+            outputs
+                .into_iter()
+                .map(|output| InputSigningData {
+                    output_metadata: *output.metadata(),
+                    output: output.into_output(),
+                    chain: None,
+                })
+                .collect()
+        }
+    };
+
+    let current_time = account.client().get_time_checked().await?;
+    match verify_semantic(&inputs_data, &transaction, current_time)? {
+        ConflictReason::None => {
+            println_log_info!("Transaction {} is valid", transaction.id());
+        }
+        conflict => {
+            println_log_info!("Transaction {} would be rejected: {conflict}", transaction.id());
+        }
+    }
+
+    Ok(())
+}
+
+/// `vesting` command
+pub async fn vesting_command(
+    account: &Account,
+    address: impl ConvertTo<Bech32Address>,
+    amount: u64,
+    schedule: VestingSchedule,
+) -> Result<(), Error> {
+    let transaction = account.create_vesting(address, amount, schedule, None).await?;
+
+    println_log_info!(
+        "Vesting transaction sent:\n{:?}\n{:?}",
+        transaction.transaction_id,
+        transaction.block_id
+    );
+
+    Ok(())
+}
+
+/// `vesting-status` command
+pub async fn vesting_status_command(account: &Account) -> Result<(), Error> {
+    let vestings = account.vesting_status().await?;
+
+    if vestings.is_empty() {
+        println_log_info!("No vesting outputs found");
+        return Ok(());
+    }
+
+    for vesting in vestings {
+        println_log_info!(
+            "{}: released {}, locked {}",
+            vesting.transaction_id,
+            vesting.released_amount,
+            vesting.locked_amount
+        );
+        for release in vesting.releases {
+            let unlock_time = to_utc_date_time(release.unlock_time as u128 * 1000)?;
+            println_log_info!(
+                "  {} {}\t{}",
+                release.output_id,
+                release.amount,
+                unlock_time.format("%Y-%m-%d %H:%M:%S UTC")
+            );
+        }
+    }
+
+    Ok(())
 }
 
 pub async fn vote_command(account: &Account, event_id: ParticipationEventId, answers: Vec<u8>) -> Result<(), Error> {
@@ -956,7 +1568,7 @@ pub async fn voting_output_command(account: &Account) -> Result<(), Error> {
     Ok(())
 }
 
-async fn print_address(account: &Account, address: &Bech32Address) -> Result<(), Error> {
+async fn print_address(wallet: &Wallet, account: &Account, address: &Bech32Address) -> Result<(), Error> {
     let mut formatted_string = String::new();
 
     formatted_string.push_str(&format!("{:<11}{}\n", "Bech32:", address));
@@ -1028,11 +1640,13 @@ async fn print_address(account: &Account, address: &Bech32Address) -> Result<(),
         }
     }
 
+    let labels = wallet.labels().await;
+
     // NFT table
     if !nfts.is_empty() {
         formatted_string.push_str("NFTs:\n");
         for id in nfts.into_iter() {
-            formatted_string.push_str(&format!("  {id}\n"));
+            formatted_string.push_str(&format!("  {id}{}\n", format_label(&labels, id)));
         }
     }
 
@@ -1040,7 +1654,7 @@ async fn print_address(account: &Account, address: &Bech32Address) -> Result<(),
     if !aliases.is_empty() {
         formatted_string.push_str("Aliases:\n");
         for id in aliases.into_iter() {
-            formatted_string.push_str(&format!("  {id}\n"));
+            formatted_string.push_str(&format!("  {id}{}\n", format_label(&labels, id)));
         }
     }
 
@@ -1049,6 +1663,35 @@ async fn print_address(account: &Account, address: &Bech32Address) -> Result<(),
     Ok(())
 }
 
+// Foundry IDs are longer than alias and NFT IDs, which are told apart by the outputs of the account.
+async fn parse_chain_id(account: &Account, chain_id: &str) -> Result<ChainId, Error> {
+    if let Ok(foundry_id) = FoundryId::from_str(chain_id) {
+        return Ok(ChainId::Foundry(foundry_id));
+    }
+    let id = AliasId::from_str(chain_id)?;
+
+    account
+        .outputs(None)
+        .await?
+        .iter()
+        .find_map(|output_data| match &output_data.output {
+            Output::Alias(alias) if alias.alias_id_non_null(&output_data.output_id) == id => Some(ChainId::Alias(id)),
+            Output::Nft(nft) if nft.nft_id_non_null(&output_data.output_id) == NftId::new(*id) => {
+                Some(ChainId::Nft(NftId::new(*id)))
+            }
+            _ => None,
+        })
+        .ok_or_else(|| Error::Miscellaneous(format!("alias or NFT {chain_id} not found in the account")))
+}
+
+fn chain_id_kind(chain_id: &ChainId) -> &'static str {
+    match chain_id {
+        ChainId::Alias(_) => "Alias",
+        ChainId::Foundry(_) => "Foundry",
+        ChainId::Nft(_) => "Nft",
+    }
+}
+
 fn print_addresses(mut addresses: Vec<Bech32Address>) -> Result<(), Error> {
     if addresses.is_empty() {
         println_log_info!("No addresses found");
@@ -1063,7 +1706,12 @@ fn print_addresses(mut addresses: Vec<Bech32Address>) -> Result<(), Error> {
     Ok(())
 }
 
-async fn print_outputs(mut outputs: Vec<OutputData>, title: &str) -> Result<(), Error> {
+async fn print_outputs(
+    mut outputs: Vec<OutputData>,
+    title: &str,
+    labels: &HashMap<ChainId, String>,
+    unlockabilities: &HashMap<OutputId, Unlockability>,
+) -> Result<(), Error> {
     if outputs.is_empty() {
         println_log_info!("No outputs found");
     } else {
@@ -1074,13 +1722,28 @@ async fn print_outputs(mut outputs: Vec<OutputData>, title: &str) -> Result<(),
             let booked_time = to_utc_date_time(output_data.metadata.milestone_timestamp_booked() as u128 * 1000)?;
             let formatted_time = booked_time.format("%Y-%m-%d %H:%M:%S UTC").to_string();
 
+            let label = output_data
+                .output
+                .chain_id()
+                .map(|chain_id| format_label(labels, chain_id.or_from_output_id(&output_data.output_id)))
+                .unwrap_or_default();
+
+            let unlockability = unlockabilities
+                .get(&output_data.output_id)
+                .map(format_unlockability)
+                .transpose()?
+                .map(|unlockability| format!("\t{unlockability}"))
+                .unwrap_or_default();
+
             println_log_info!(
-                "{:<5}{}\t{}\t{}\t{}",
+                "{:<5}{}\t{}\t{}\t{}{}{}",
                 i,
                 &output_data.output_id,
                 output_data.output.kind_str(),
                 formatted_time,
                 if output_data.is_spent { "Spent" } else { "Unspent" },
+                label,
+                unlockability,
             );
         }
     }
@@ -1088,6 +1751,243 @@ async fn print_outputs(mut outputs: Vec<OutputData>, title: &str) -> Result<(),
     Ok(())
 }
 
+fn format_time(timestamp: u32) -> Result<String, Error> {
+    Ok(to_utc_date_time(timestamp as u128 * 1000)?
+        .format("%Y-%m-%d %H:%M:%S UTC")
+        .to_string())
+}
+
+// Bytes are shown as text if they are printable UTF-8, as it's the case for most metadata and tags, and as hex
+// otherwise.
+fn format_bytes(bytes: &[u8]) -> String {
+    match std::str::from_utf8(bytes) {
+        Ok(text) if text.chars().all(|c| !c.is_control() || c.is_whitespace()) => format!("{text:?}"),
+        _ => prefix_hex::encode(bytes),
+    }
+}
+
+fn format_output_data(output_data: &OutputData, hrp: Hrp) -> Result<String, Error> {
+    let mut formatted_string = String::new();
+
+    formatted_string.push_str(&format!("{:<18}{}\n", "Output ID:", output_data.output_id));
+    formatted_string.push_str(&format!(
+        "{:<18}{}\n",
+        "Booked:",
+        format_time(output_data.metadata.milestone_timestamp_booked())?
+    ));
+    if output_data.is_spent {
+        let spent = match output_data.metadata.milestone_timestamp_spent() {
+            Some(timestamp) => format_time(timestamp)?,
+            None => "yes".to_string(),
+        };
+        formatted_string.push_str(&format!("{:<18}{}\n", "Spent:", spent));
+    }
+    formatted_string.push_str(&format!("{:<18}{}\n", "Address:", output_data.address.to_bech32(hrp)));
+    if output_data.remainder {
+        formatted_string.push_str(&format!("{:<18}{}\n", "Remainder:", true));
+    }
+    for (key, value) in &output_data.annotations {
+        formatted_string.push_str(&format!("{:<18}{key}: {value}\n", "Annotation:"));
+    }
+    formatted_string.push_str(&format_output(
+        &output_data.output,
+        Some(&output_data.output_id),
+        hrp,
+        "",
+    )?);
+
+    Ok(formatted_string)
+}
+
+fn format_transaction(tx: &Transaction, hrp: Hrp, labels: &HashMap<ChainId, String>) -> Result<String, Error> {
+    let mut formatted_string = String::new();
+    let TransactionEssence::Regular(essence) = tx.payload.essence();
+
+    formatted_string.push_str(&format!("{:<18}{}\n", "Transaction ID:", tx.transaction_id));
+    if let Some(block_id) = tx.block_id {
+        formatted_string.push_str(&format!("{:<18}{}\n", "Block ID:", block_id));
+    }
+    formatted_string.push_str(&format!("{:<18}{:?}\n", "Inclusion State:", tx.inclusion_state));
+    formatted_string.push_str(&format!(
+        "{:<18}{}\n",
+        "Created:",
+        to_utc_date_time(tx.timestamp)?.format("%Y-%m-%d %H:%M:%S UTC")
+    ));
+    formatted_string.push_str(&format!(
+        "{:<18}{}\n",
+        "Direction:",
+        if tx.incoming { "Incoming" } else { "Outgoing" }
+    ));
+    if let Some(note) = &tx.note {
+        formatted_string.push_str(&format!("{:<18}{}\n", "Note:", note));
+    }
+
+    formatted_string.push_str("Inputs:\n");
+    for input in essence.inputs() {
+        let Input::Utxo(input) = input else {
+            formatted_string.push_str(&format!("  {input:?}\n"));
+            continue;
+        };
+        // The inputs might have been pruned from the node before the transaction was stored
+        let details = tx
+            .inputs
+            .iter()
+            .find(|known| known.metadata.output_id() == input.output_id())
+            .and_then(|known| Output::try_from_dto(known.output.clone()).ok())
+            .map(|output| format!("\t{}\t{}", output.kind_str(), output.amount()))
+            .unwrap_or_default();
+        formatted_string.push_str(&format!("  {}{details}\n", input.output_id()));
+    }
+
+    formatted_string.push_str("Outputs:\n");
+    for (index, output) in essence.outputs().iter().enumerate() {
+        let output_id = OutputId::new(tx.transaction_id, index as u16)?;
+        let label = output
+            .chain_id()
+            .map(|chain_id| format_label(labels, chain_id.or_from_output_id(&output_id)))
+            .unwrap_or_default();
+        formatted_string.push_str(&format!("  {output_id}{label}\n"));
+        formatted_string.push_str(&format_output(output, Some(&output_id), hrp, "    ")?);
+    }
+
+    if let Some(Payload::TaggedData(tagged_data)) = essence.payload() {
+        formatted_string.push_str("Tagged Data:\n");
+        formatted_string.push_str(&format!("  {:<16}{}\n", "Tag:", format_bytes(tagged_data.tag())));
+        formatted_string.push_str(&format!("  {:<16}{}\n", "Data:", format_bytes(tagged_data.data())));
+    }
+
+    Ok(formatted_string)
+}
+
+// Every line is prefixed with `indent`, so the output can be nested in the view of a transaction.
+fn format_output(output: &Output, output_id: Option<&OutputId>, hrp: Hrp, indent: &str) -> Result<String, Error> {
+    let mut lines = vec![
+        format!("{:<18}{}", "Type:", output.kind_str()),
+        format!("{:<18}{}", "Amount:", output.amount()),
+    ];
+
+    match output {
+        Output::Alias(alias) => {
+            let alias_id = output_id.map_or(*alias.alias_id(), |output_id| alias.alias_id_non_null(output_id));
+            lines.push(format!("{:<18}{alias_id}", "Alias ID:"));
+            lines.push(format!("{:<18}{}", "State Index:", alias.state_index()));
+            if !alias.state_metadata().is_empty() {
+                lines.push(format!(
+                    "{:<18}{}",
+                    "State Metadata:",
+                    format_bytes(alias.state_metadata())
+                ));
+            }
+            lines.push(format!("{:<18}{}", "Foundry Counter:", alias.foundry_counter()));
+        }
+        Output::Foundry(foundry) => {
+            let TokenScheme::Simple(token_scheme) = foundry.token_scheme();
+            lines.push(format!("{:<18}{}", "Foundry ID:", foundry.id()));
+            lines.push(format!("{:<18}{}", "Serial Number:", foundry.serial_number()));
+            lines.push(format!("{:<18}{}", "Minted Tokens:", token_scheme.minted_tokens()));
+            lines.push(format!("{:<18}{}", "Melted Tokens:", token_scheme.melted_tokens()));
+            lines.push(format!("{:<18}{}", "Maximum Supply:", token_scheme.maximum_supply()));
+        }
+        Output::Nft(nft) => {
+            let nft_id = output_id.map_or(*nft.nft_id(), |output_id| nft.nft_id_non_null(output_id));
+            lines.push(format!("{:<18}{nft_id}", "NFT ID:"));
+        }
+        Output::Basic(_) | Output::Treasury(_) => {}
+    }
+
+    if let Some(native_tokens) = output.native_tokens().filter(|native_tokens| !native_tokens.is_empty()) {
+        lines.push("Native Tokens:".to_string());
+        for native_token in native_tokens.iter() {
+            lines.push(format!("  {}\t{}", native_token.token_id(), native_token.amount()));
+        }
+    }
+
+    if let Some(unlock_conditions) = output.unlock_conditions() {
+        lines.push("Unlock Conditions:".to_string());
+        for unlock_condition in unlock_conditions.iter() {
+            lines.push(match unlock_condition {
+                UnlockCondition::Address(uc) => format!("  {:<26}{}", "Address:", uc.address().to_bech32(hrp)),
+                UnlockCondition::StorageDepositReturn(uc) => format!(
+                    "  {:<26}{} to {}",
+                    "Storage Deposit Return:",
+                    uc.amount(),
+                    uc.return_address().to_bech32(hrp)
+                ),
+                UnlockCondition::Timelock(uc) => {
+                    format!("  {:<26}until {}", "Timelock:", format_time(uc.timestamp())?)
+                }
+                UnlockCondition::Expiration(uc) => format!(
+                    "  {:<26}at {}, returns to {}",
+                    "Expiration:",
+                    format_time(uc.timestamp())?,
+                    uc.return_address().to_bech32(hrp)
+                ),
+                UnlockCondition::StateControllerAddress(uc) => {
+                    format!("  {:<26}{}", "State Controller Address:", uc.address().to_bech32(hrp))
+                }
+                UnlockCondition::GovernorAddress(uc) => {
+                    format!("  {:<26}{}", "Governor Address:", uc.address().to_bech32(hrp))
+                }
+                UnlockCondition::ImmutableAliasAddress(uc) => {
+                    format!("  {:<26}{}", "Immutable Alias Address:", uc.address().to_bech32(hrp))
+                }
+            });
+        }
+    }
+
+    for (title, features) in [
+        ("Features:", output.features()),
+        ("Immutable Features:", output.immutable_features()),
+    ] {
+        let Some(features) = features.filter(|features| !features.is_empty()) else {
+            continue;
+        };
+        lines.push(title.to_string());
+        for feature in features.iter() {
+            lines.push(match feature {
+                Feature::Sender(feature) => format!("  {:<26}{}", "Sender:", feature.address().to_bech32(hrp)),
+                Feature::Issuer(feature) => format!("  {:<26}{}", "Issuer:", feature.address().to_bech32(hrp)),
+                Feature::Metadata(feature) => format!("  {:<26}{}", "Metadata:", format_bytes(feature.data())),
+                Feature::Tag(feature) => format!("  {:<26}{}", "Tag:", format_bytes(feature.tag())),
+            });
+        }
+    }
+
+    Ok(lines.into_iter().map(|line| format!("{indent}{line}\n")).collect())
+}
+
+fn format_unlockability(unlockability: &Unlockability) -> Result<String, Error> {
+    Ok(match unlockability {
+        Unlockability::Spent => "spent".to_string(),
+        Unlockability::Unlockable {
+            expires_at,
+            storage_deposit_return,
+        } => {
+            let mut formatted = "unlockable".to_string();
+            if let Some(expires_at) = expires_at {
+                formatted.push_str(&format!(", expires at {}", format_time(*expires_at)?));
+            }
+            if let Some(amount) = storage_deposit_return {
+                formatted.push_str(&format!(", returns {amount}"));
+            }
+            formatted
+        }
+        Unlockability::TimelockedUntil { timestamp } => format!("timelocked until {}", format_time(*timestamp)?),
+        Unlockability::Expired {
+            timestamp,
+            return_address,
+        } => format!("expired at {}, returns to {return_address}", format_time(*timestamp)?),
+        Unlockability::RequiresUnlockBy { address } => format!("requires unlock by {address}"),
+    })
+}
+
+fn format_label(labels: &HashMap<ChainId, String>, chain_id: impl Into<ChainId>) -> String {
+    labels
+        .get(&chain_id.into())
+        .map(|label| format!("\t{label}"))
+        .unwrap_or_default()
+}
+
 fn outputs_ordering(a: &OutputData, b: &OutputData) -> std::cmp::Ordering {
     (b.metadata.milestone_timestamp_booked(), a.output_id).cmp(&(a.metadata.milestone_timestamp_booked(), b.output_id))
 }
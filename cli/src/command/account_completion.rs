@@ -15,6 +15,7 @@ const ACCOUNT_COMMANDS: &[&str] = &[
     "accounts",
     "address",
     "addresses",
+    "airdrop",
     "balance",
     "burn-native-token",
     "burn-nft",
@@ -28,6 +29,9 @@ const ACCOUNT_COMMANDS: &[&str] = &[
     "destroy-foundry",
     "exit",
     "faucet",
+    "graph",
+    "label",
+    "labels",
     "melt-native-token",
     "mint-native-token",
     "mint-nft",
@@ -45,6 +49,9 @@ const ACCOUNT_COMMANDS: &[&str] = &[
     "tx",
     "txs",
     "unspent-outputs",
+    "validate-tx",
+    "vesting",
+    "vesting-status",
     "vote",
     "stop-participating",
     "participation-overview",
@@ -11,7 +11,8 @@ use iota_sdk::{
         stronghold::StrongholdAdapter,
         utils::Password,
     },
-    wallet::{account::types::AccountIdentifier, ClientOptions, Wallet},
+    types::block::address::Bech32Address,
+    wallet::{account::types::AccountIdentifier, core::SendDefaults, ClientOptions, Wallet},
 };
 use log::LevelFilter;
 
@@ -101,6 +102,18 @@ pub enum WalletCommand {
         #[arg(short, long)]
         worker_count: Option<usize>,
     },
+    /// Set the defaults for the return address and expiration of outputs with a storage deposit return, used by
+    /// sends of micro amounts if they aren't provided. Unset options are reset.
+    SetSendDefaults {
+        /// Bech32 encoded address to which the storage deposit is returned, the first address of the sending account
+        /// if not provided.
+        #[arg(long)]
+        return_address: Option<Bech32Address>,
+        /// Expiration, after which the output is available for the sender again, e.g. "1day". One day if not
+        /// provided.
+        #[arg(long)]
+        expiration: Option<humantime::Duration>,
+    },
     /// Synchronize all accounts.
     Sync,
 }
@@ -279,6 +292,27 @@ pub async fn set_node_url_command(wallet: &Wallet, url: String) -> Result<(), Er
     Ok(())
 }
 
+pub async fn set_send_defaults_command(
+    wallet: &Wallet,
+    return_address: Option<Bech32Address>,
+    expiration: Option<humantime::Duration>,
+) -> Result<(), Error> {
+    let expiration = expiration
+        .map(|expiration| u32::try_from(expiration.as_secs()))
+        .transpose()
+        .map_err(|_| Error::Miscellaneous("expiration is too long".to_string()))?;
+    wallet
+        .set_send_defaults(SendDefaults {
+            return_address,
+            expiration,
+        })
+        .await?;
+
+    println_log_info!("Send defaults set.");
+
+    Ok(())
+}
+
 pub async fn set_pow_command(wallet: &Wallet, local_pow: bool, worker_count: Option<usize>) -> Result<(), Error> {
     // Need to get the current node, so it's not removed
     let node = wallet.client().get_node().await?;
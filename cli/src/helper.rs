@@ -50,6 +50,21 @@ pub fn get_decision(prompt: &str) -> Result<bool, Error> {
     }
 }
 
+/// Draws a progress bar over the current line of the terminal.
+pub fn print_progress(term: &Term, done: usize, total: usize) -> Result<(), Error> {
+    const WIDTH: usize = 40;
+    let filled = (done * WIDTH).checked_div(total).unwrap_or(WIDTH);
+
+    term.clear_line()?;
+    term.write_str(&format!(
+        "[{}{}] {done}/{total}",
+        "#".repeat(filled),
+        "-".repeat(WIDTH - filled)
+    ))?;
+
+    Ok(())
+}
+
 pub async fn get_account_alias(prompt: &str, wallet: &Wallet) -> Result<String, Error> {
     let account_aliases = wallet.get_account_aliases().await?;
     loop {
@@ -12,8 +12,8 @@ use crate::{
     command::wallet::{
         accounts_command, add_account, backup_command_stronghold, change_password_command, init_command,
         migrate_stronghold_snapshot_v2_to_v3_command, mnemonic_command, new_account_command, node_info_command,
-        restore_command_stronghold, set_node_url_command, set_pow_command, sync_command, InitParameters, WalletCli,
-        WalletCommand,
+        restore_command_stronghold, set_node_url_command, set_pow_command, set_send_defaults_command, sync_command,
+        InitParameters, WalletCli, WalletCommand,
     },
     error::Error,
     helper::{
@@ -167,6 +167,20 @@ pub async fn new_wallet(cli: WalletCli) -> Result<(Option<Wallet>, Option<Accoun
                     )));
                 }
             }
+            WalletCommand::SetSendDefaults {
+                return_address,
+                expiration,
+            } => {
+                if let Some((wallet, _)) = wallet_and_secret_manager {
+                    set_send_defaults_command(&wallet, return_address, expiration).await?;
+                    (Some(wallet), None)
+                } else {
+                    return Err(Error::Miscellaneous(format!(
+                        "wallet db does not exist at '{}'",
+                        storage_path.display()
+                    )));
+                }
+            }
             WalletCommand::SetPow {
                 local_pow,
                 worker_count,
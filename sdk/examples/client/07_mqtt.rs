@@ -10,7 +10,7 @@
 
 use iota_sdk::{
     client::{
-        mqtt::{BrokerOptions, MqttEvent, MqttPayload, Topic},
+        mqtt::{BrokerOptions, MqttEvent, MqttPayload, Topic, UnlockConditionTopic},
         Client, Result,
     },
     types::block::address::Bech32Address,
@@ -34,14 +34,11 @@ async fn main() -> Result<()> {
         .await?;
 
     let (tx, mut rx) = tokio::sync::mpsc::unbounded_channel();
+    let address_outputs = Topic::unlock_condition_outputs(UnlockConditionTopic::Address, &address);
 
     client
         .subscribe(
-            [
-                Topic::new("milestone-info/latest")?,
-                Topic::new("blocks")?,
-                Topic::new(format!("outputs/unlock/address/{address}"))?,
-            ],
+            [Topic::latest_milestone_info(), Topic::blocks(), address_outputs.clone()],
             move |event| {
                 println!("> Topic: {}", event.topic);
                 match &event.payload {
@@ -49,6 +46,8 @@ async fn main() -> Result<()> {
                     MqttPayload::Block(block) => println!("{block:?}"),
                     MqttPayload::MilestonePayload(ms) => println!("{ms:?}"),
                     MqttPayload::Receipt(receipt) => println!("{receipt:?}"),
+                    MqttPayload::MilestoneInfo(info) => println!("{info:?}"),
+                    MqttPayload::Output(output) => println!("{output:?}"),
                     e => println!("unknown event received: {e:?}"),
                 }
                 tx.send(()).unwrap();
@@ -62,9 +61,9 @@ async fn main() -> Result<()> {
             _ = rx.recv() => {
                 event_count += 1;
                 if event_count == num_events {
-                    client.unsubscribe([Topic::new("milestone-info/latest")?]).await?;
-                    client.unsubscribe([Topic::new("blocks")?]).await?;
-                    client.unsubscribe([Topic::new(format!("outputs/unlock/address/{address}"))?]).await?;
+                    client.unsubscribe([Topic::latest_milestone_info()]).await?;
+                    client.unsubscribe([Topic::blocks()]).await?;
+                    client.unsubscribe([address_outputs.clone()]).await?;
                     break;
                 }
             }
@@ -73,8 +73,9 @@ async fn main() -> Result<()> {
         alias_id: None,
         circulating_supply: U256::from(CIRCULATING_SUPPLY),
         maximum_supply: U256::from(MAXIMUM_SUPPLY),
-        foundry_metadata: Some(metadata.to_bytes()),
-    };
+        foundry_metadata: None,
+    }
+    .with_irc30(metadata)?;
 
     let transaction = account.create_native_token(params, None).await?;
     println!("Transaction sent: {}", transaction.transaction.transaction_id);
@@ -74,7 +74,7 @@ async fn main() -> Result<()> {
         .with_metadata(NFT1_METADATA.as_bytes().to_vec())
         .with_tag(NFT1_TAG.as_bytes().to_vec())
         .try_with_issuer(sender_address)?
-        .with_immutable_metadata(metadata.to_bytes())];
+        .with_irc27(metadata)?];
 
     let transaction = account.mint_nfts(nft_params, None).await?;
     println!("Transaction sent: {}", transaction.transaction_id);
@@ -61,6 +61,7 @@ async fn main() -> Result<()> {
         url: Url::parse(PARTICPATION_NODE_URL).map_err(iota_sdk::client::Error::Url)?,
         auth: None,
         disabled: false,
+        proxy: None,
     };
     let _ = account
         .register_participation_events(&ParticipationEventRegistrationOptions {
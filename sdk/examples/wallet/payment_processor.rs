@@ -0,0 +1,200 @@
+// Copyright 2024 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+//! In this example we will run a small payment processor: it generates addresses to receive payments on, notifies a
+//! callback URL once a payment was confirmed by enough milestones and sweeps the confirmed payments to a cold storage
+//! address. Confirmed payments are annotated, so they aren't reported again after a restart. Stop it with Ctrl+C.
+//!
+//! The callbacks are signed like the wallet webhooks, with the `X-Iota-Timestamp` and `X-Iota-Signature` headers.
+//!
+//! Rename `.env.example` to `.env` first, then run the command:
+//! ```sh
+//! cargo run --release --all-features --example payment_processor
+//! ```
+
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use iota_sdk::{
+    client::{
+        constants::SHIMMER_COIN_TYPE,
+        secret::{mnemonic::MnemonicSecretManager, SecretManager},
+    },
+    types::block::{
+        address::{Bech32Address, Hrp, ToBech32Ext},
+        output::OutputId,
+    },
+    wallet::{
+        account::{types::OutputData, Account, TransactionOptions},
+        events::{
+            types::{WalletEvent, WalletEventType},
+            webhook::{signature, SIGNATURE_HEADER, TIMESTAMP_HEADER},
+        },
+        ClientOptions, Result, Wallet,
+    },
+};
+
+// The number of addresses to generate for incoming payments
+const PAYMENT_ADDRESSES: u32 = 3;
+// The number of milestones which have to reference a payment before it's reported
+const CONFIRMATIONS: u32 = 3;
+// The interval in which the account is synced
+const SYNC_INTERVAL: Duration = Duration::from_secs(10);
+// The annotation marking payments which were already reported
+const PAYMENT_ANNOTATION: &str = "payment";
+
+#[tokio::main]
+async fn main() -> Result<()> {
+    // This example uses secrets in environment variables for simplicity which should not be done in production.
+    dotenvy::dotenv().ok();
+
+    for var in [
+        "NODE_URL",
+        "MNEMONIC",
+        "WALLET_DB_PATH",
+        "COLD_STORAGE_ADDRESS",
+        "PAYMENT_CALLBACK_URL",
+        "PAYMENT_CALLBACK_SECRET",
+    ] {
+        std::env::var(var).expect(&format!(".env variable '{var}' is undefined, see .env.example"));
+    }
+
+    let client_options = ClientOptions::new().with_node(&std::env::var("NODE_URL").unwrap())?;
+    let secret_manager = MnemonicSecretManager::try_from_mnemonic(std::env::var("MNEMONIC").unwrap())?;
+    let wallet = Wallet::builder()
+        .with_secret_manager(SecretManager::Mnemonic(secret_manager))
+        .with_storage_path(std::env::var("WALLET_DB_PATH").unwrap())
+        .with_client_options(client_options)
+        .with_coin_type(SHIMMER_COIN_TYPE)
+        .finish()
+        .await?;
+    let cold_storage_address = std::env::var("COLD_STORAGE_ADDRESS")
+        .unwrap()
+        .parse::<Bech32Address>()?;
+
+    // Get or create an account and make sure it has enough addresses to receive payments on
+    let account = wallet.get_or_create_account("PaymentProcessor").await?;
+    let address_count = account.addresses().await?.len() as u32;
+    if address_count < PAYMENT_ADDRESSES {
+        account
+            .generate_ed25519_addresses(PAYMENT_ADDRESSES - address_count, None)
+            .await?;
+    }
+    println!("Receiving payments on:");
+    for address in account.addresses().await? {
+        println!("{}", address.address());
+    }
+
+    wallet
+        .listen([WalletEventType::NewOutput], |event| {
+            if let WalletEvent::NewOutput(new_output) = &event.event {
+                println!(
+                    "Payment received: {}, waiting for {CONFIRMATIONS} confirmations",
+                    new_output.output.output_id
+                );
+            }
+        })
+        .await;
+
+    let bech32_hrp = account.client().get_bech32_hrp().await?;
+    let http_client = reqwest::Client::new();
+    let mut interval = tokio::time::interval(SYNC_INTERVAL);
+    loop {
+        interval.tick().await;
+
+        account.sync(None).await?;
+        let latest_milestone_index = account
+            .client()
+            .get_info()
+            .await?
+            .node_info
+            .status
+            .latest_milestone
+            .index;
+
+        let mut confirmed = Vec::new();
+        for output_data in pending_payments(&account).await? {
+            let confirmations = latest_milestone_index.saturating_sub(output_data.metadata.milestone_index_booked());
+            if confirmations < CONFIRMATIONS {
+                continue;
+            }
+            if let Err(err) = notify(&http_client, &output_data, bech32_hrp, confirmations).await {
+                // Retried with the next sync
+                println!("Callback for {} failed: {err}", output_data.output_id);
+                continue;
+            }
+            account
+                .set_output_annotation(&output_data.output_id, PAYMENT_ANNOTATION, "confirmed")
+                .await?;
+            println!("Payment confirmed: {}", output_data.output_id);
+            confirmed.push((output_data.output_id, output_data.output.amount()));
+        }
+
+        if !confirmed.is_empty() {
+            sweep(&account, &cold_storage_address, confirmed).await?;
+        }
+    }
+}
+
+// Returns the received outputs which weren't reported yet, remainders of own transactions aren't payments
+async fn pending_payments(account: &Account) -> Result<Vec<OutputData>> {
+    Ok(account
+        .unspent_outputs(None)
+        .await?
+        .into_iter()
+        .filter(|output_data| {
+            !output_data.remainder
+                && output_data.output.is_basic()
+                && !output_data.annotations.contains_key(PAYMENT_ANNOTATION)
+        })
+        .collect())
+}
+
+// POSTs a confirmed payment to the callback URL
+async fn notify(
+    http_client: &reqwest::Client,
+    output_data: &OutputData,
+    bech32_hrp: Hrp,
+    confirmations: u32,
+) -> core::result::Result<(), String> {
+    let body = serde_json::json!({
+        "outputId": output_data.output_id,
+        "address": output_data.address.to_bech32(bech32_hrp),
+        "amount": output_data.output.amount().to_string(),
+        "confirmations": confirmations,
+    })
+    .to_string();
+    let timestamp = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map_err(|err| err.to_string())?
+        .as_secs()
+        .to_string();
+    let secret = std::env::var("PAYMENT_CALLBACK_SECRET").unwrap();
+
+    let response = http_client
+        .post(std::env::var("PAYMENT_CALLBACK_URL").unwrap())
+        .header(reqwest::header::CONTENT_TYPE, "application/json")
+        .header(TIMESTAMP_HEADER, &timestamp)
+        .header(SIGNATURE_HEADER, signature(&secret, &timestamp, &body))
+        .body(body)
+        .send()
+        .await
+        .map_err(|err| err.to_string())?;
+    if response.status().is_success() {
+        Ok(())
+    } else {
+        Err(format!("status {}", response.status()))
+    }
+}
+
+// Sends the confirmed payments to the cold storage, using only them as inputs so unconfirmed funds stay untouched
+async fn sweep(account: &Account, cold_storage_address: &Bech32Address, payments: Vec<(OutputId, u64)>) -> Result<()> {
+    let amount = payments.iter().map(|(_, amount)| amount).sum();
+    let options = TransactionOptions {
+        custom_inputs: Some(payments.into_iter().map(|(output_id, _)| output_id).collect()),
+        ..Default::default()
+    };
+    let transaction = account.send(amount, *cold_storage_address, options).await?;
+    println!("Sweeping {amount} to cold storage: {}", transaction.transaction_id);
+
+    Ok(())
+}
@@ -99,6 +99,13 @@ impl InputSelection {
             // - the issuer feature doesn't need to be verified as the chain is not new
             // - input doesn't need to be checked for as we just transitioned it
             // - foundry alias requirement should have been met already by a prior `required_alias_nft_addresses`
+            // Native tokens are kept by the transitioned output, so they can't also be used for other outputs.
+            if output
+                .native_tokens()
+                .is_some_and(|native_tokens| !native_tokens.is_empty())
+            {
+                self.requirements.push(Requirement::NativeTokens);
+            }
             self.outputs.push(output);
         }
 
@@ -7,6 +7,9 @@ mod automatic;
 mod core;
 mod manual;
 mod sender_issuer;
+#[cfg(feature = "input_selection_test_utils")]
+#[cfg_attr(docsrs, doc(cfg(feature = "input_selection_test_utils")))]
+pub mod test_utils;
 mod utxo_chains;
 
 pub(crate) use self::core::is_alias_transition;
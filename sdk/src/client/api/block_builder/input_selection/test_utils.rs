@@ -0,0 +1,278 @@
+// Copyright 2024 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+//! Utilities to property test the input selection.
+//!
+//! [`proptest`] strategies generate arbitrary inputs and outputs and [`check_invariants()`] checks that a selection
+//! holds the invariants of a valid transaction.
+//!
+//! ```ignore
+//! proptest! {
+//!     #[test]
+//!     fn invariants(inputs in arb_inputs(addresses, token_ids, protocol_parameters.clone(), 1..20)) {
+//!         if let Ok(selected) = InputSelection::new(inputs.clone(), outputs.clone(), addresses, protocol_parameters.clone()).select() {
+//!             check_invariants(&inputs, &outputs, &selected, &protocol_parameters).map_err(TestCaseError::fail)?;
+//!         }
+//!     }
+//! }
+//! ```
+
+use std::collections::{HashMap, HashSet};
+
+use proptest::{prelude::*, sample::subsequence};
+
+use crate::{
+    client::{api::input_selection::Selected, secret::types::InputSigningData},
+    types::block::{
+        address::{Address, Ed25519Address},
+        output::{
+            unlock_condition::{
+                AddressUnlockCondition, GovernorAddressUnlockCondition, StateControllerAddressUnlockCondition,
+            },
+            AliasId, AliasOutputBuilder, BasicOutputBuilder, NativeToken, NftId, NftOutputBuilder, Output, OutputId,
+            OutputMetadata, TokenId,
+        },
+        payload::transaction::TransactionId,
+        protocol::ProtocolParameters,
+        BlockId,
+    },
+    U256,
+};
+
+/// The maximum amount of base coins generated on top of the minimum storage deposit of an output.
+pub const MAX_EXTRA_AMOUNT: u64 = 10_000_000;
+
+/// Returns `count` distinct Ed25519 addresses, so inputs and outputs can share them.
+pub fn ed25519_addresses(count: u8) -> Vec<Address> {
+    (0..count)
+        .map(|i| Address::Ed25519(Ed25519Address::new([i; Ed25519Address::LENGTH])))
+        .collect()
+}
+
+/// Returns `count` distinct token IDs, so inputs and outputs can share them.
+pub fn token_ids(count: u8) -> Vec<TokenId> {
+    (0..count).map(|i| TokenId::new([i; TokenId::LENGTH])).collect()
+}
+
+/// Generates up to 3 native tokens with distinct IDs from `token_ids`.
+pub fn arb_native_tokens(token_ids: Vec<TokenId>) -> impl Strategy<Value = Vec<NativeToken>> {
+    let max_count = token_ids.len().min(3);
+
+    subsequence(token_ids, 0..=max_count).prop_flat_map(|token_ids| {
+        token_ids
+            .into_iter()
+            .map(|token_id| (1u64..1_000_000).prop_map(move |amount| NativeToken::new(token_id, amount).unwrap()))
+            .collect::<Vec<_>>()
+    })
+}
+
+/// Generates a basic output owned by one of the `addresses`, with an amount covering its storage deposit.
+pub fn arb_basic_output(
+    addresses: Vec<Address>,
+    token_ids: Vec<TokenId>,
+    protocol_parameters: ProtocolParameters,
+) -> impl Strategy<Value = Output> {
+    (
+        proptest::sample::select(addresses),
+        arb_native_tokens(token_ids),
+        0..MAX_EXTRA_AMOUNT,
+    )
+        .prop_map(move |(address, native_tokens, extra_amount)| {
+            let builder = BasicOutputBuilder::new_with_minimum_storage_deposit(*protocol_parameters.rent_structure())
+                .add_unlock_condition(AddressUnlockCondition::new(address))
+                .with_native_tokens(native_tokens);
+            // PANIC: the generated outputs are known to be valid.
+            let amount = builder.clone().finish().unwrap().amount() + extra_amount;
+            builder
+                .with_amount(amount)
+                .finish_output(protocol_parameters.token_supply())
+                .unwrap()
+        })
+}
+
+/// Generates an alias output controlled by one of the `addresses`, with an amount covering its storage deposit.
+pub fn arb_alias_output(
+    addresses: Vec<Address>,
+    token_ids: Vec<TokenId>,
+    protocol_parameters: ProtocolParameters,
+) -> impl Strategy<Value = Output> {
+    (
+        // Null alias IDs are only valid for new aliases
+        any::<[u8; AliasId::LENGTH]>().prop_filter("null alias ID", |id| id != &[0; AliasId::LENGTH]),
+        proptest::sample::select(addresses),
+        arb_native_tokens(token_ids),
+        0..MAX_EXTRA_AMOUNT,
+    )
+        .prop_map(move |(alias_id, address, native_tokens, extra_amount)| {
+            let builder = AliasOutputBuilder::new_with_minimum_storage_deposit(
+                *protocol_parameters.rent_structure(),
+                AliasId::new(alias_id),
+            )
+            .add_unlock_condition(StateControllerAddressUnlockCondition::new(address))
+            .add_unlock_condition(GovernorAddressUnlockCondition::new(address))
+            .with_state_index(1)
+            .with_native_tokens(native_tokens);
+            // PANIC: the generated outputs are known to be valid.
+            let amount = builder.clone().finish().unwrap().amount() + extra_amount;
+            builder
+                .with_amount(amount)
+                .finish_output(protocol_parameters.token_supply())
+                .unwrap()
+        })
+}
+
+/// Generates an NFT output owned by one of the `addresses`, with an amount covering its storage deposit.
+pub fn arb_nft_output(
+    addresses: Vec<Address>,
+    token_ids: Vec<TokenId>,
+    protocol_parameters: ProtocolParameters,
+) -> impl Strategy<Value = Output> {
+    (
+        any::<[u8; NftId::LENGTH]>(),
+        proptest::sample::select(addresses),
+        arb_native_tokens(token_ids),
+        0..MAX_EXTRA_AMOUNT,
+    )
+        .prop_map(move |(nft_id, address, native_tokens, extra_amount)| {
+            let builder = NftOutputBuilder::new_with_minimum_storage_deposit(
+                *protocol_parameters.rent_structure(),
+                NftId::new(nft_id),
+            )
+            .add_unlock_condition(AddressUnlockCondition::new(address))
+            .with_native_tokens(native_tokens);
+            // PANIC: the generated outputs are known to be valid.
+            let amount = builder.clone().finish().unwrap().amount() + extra_amount;
+            builder
+                .with_amount(amount)
+                .finish_output(protocol_parameters.token_supply())
+                .unwrap()
+        })
+}
+
+/// Generates a basic, alias or NFT output, basic outputs being the most likely.
+pub fn arb_output(
+    addresses: Vec<Address>,
+    token_ids: Vec<TokenId>,
+    protocol_parameters: ProtocolParameters,
+) -> impl Strategy<Value = Output> {
+    prop_oneof![
+        4 => arb_basic_output(addresses.clone(), token_ids.clone(), protocol_parameters.clone()),
+        1 => arb_alias_output(addresses.clone(), token_ids.clone(), protocol_parameters.clone()),
+        1 => arb_nft_output(addresses, token_ids, protocol_parameters),
+    ]
+}
+
+/// Generates an unspent input for the input selection from an output strategy.
+pub fn arb_input(output: impl Strategy<Value = Output>) -> impl Strategy<Value = InputSigningData> {
+    (
+        output,
+        any::<[u8; TransactionId::LENGTH]>(),
+        any::<[u8; BlockId::LENGTH]>(),
+    )
+        .prop_map(|(output, transaction_id, block_id)| InputSigningData {
+            output,
+            output_metadata: OutputMetadata::new(
+                BlockId::new(block_id),
+                // PANIC: 0 is a valid output index.
+                OutputId::new(TransactionId::new(transaction_id), 0).unwrap(),
+                false,
+                None,
+                None,
+                None,
+                0,
+                0,
+                0,
+            ),
+            chain: None,
+        })
+}
+
+/// Generates a list of unspent inputs with basic, alias and NFT outputs.
+pub fn arb_inputs(
+    addresses: Vec<Address>,
+    token_ids: Vec<TokenId>,
+    protocol_parameters: ProtocolParameters,
+    size: impl Into<proptest::collection::SizeRange>,
+) -> impl Strategy<Value = Vec<InputSigningData>> {
+    proptest::collection::vec(arb_input(arb_output(addresses, token_ids, protocol_parameters)), size)
+}
+
+/// Checks the invariants of a selection without burn.
+///
+/// The selected inputs are distinct available inputs, the requested outputs are kept, base coins and native tokens
+/// are balanced, every output covers its storage deposit and every selected alias and NFT input is transitioned.
+pub fn check_invariants(
+    available_inputs: &[InputSigningData],
+    requested_outputs: &[Output],
+    selected: &Selected,
+    protocol_parameters: &ProtocolParameters,
+) -> Result<(), String> {
+    let available_input_ids = available_inputs
+        .iter()
+        .map(InputSigningData::output_id)
+        .collect::<HashSet<_>>();
+    let mut selected_input_ids = HashSet::new();
+    for input in &selected.inputs {
+        if !available_input_ids.contains(input.output_id()) {
+            return Err(format!("selected input {} wasn't available", input.output_id()));
+        }
+        if !selected_input_ids.insert(input.output_id()) {
+            return Err(format!("input {} is selected more than once", input.output_id()));
+        }
+    }
+
+    for output in requested_outputs {
+        if !selected.outputs.contains(output) {
+            return Err(format!("requested output {output:?} is missing"));
+        }
+    }
+
+    let input_amount = selected.inputs.iter().map(|input| input.output.amount()).sum::<u64>();
+    let output_amount = selected.outputs.iter().map(Output::amount).sum::<u64>();
+    if input_amount != output_amount {
+        return Err(format!(
+            "input amount {input_amount} doesn't match output amount {output_amount}"
+        ));
+    }
+
+    let input_native_tokens = sum_native_tokens(selected.inputs.iter().map(|input| &input.output));
+    let output_native_tokens = sum_native_tokens(selected.outputs.iter());
+    if input_native_tokens != output_native_tokens {
+        return Err(format!(
+            "input native tokens {input_native_tokens:?} don't match output native tokens {output_native_tokens:?}"
+        ));
+    }
+
+    for output in &selected.outputs {
+        output
+            .verify_storage_deposit(
+                *protocol_parameters.rent_structure(),
+                protocol_parameters.token_supply(),
+            )
+            .map_err(|e| format!("output {output:?} doesn't cover its storage deposit: {e}"))?;
+    }
+
+    for input in &selected.inputs {
+        let Some(chain_id) = input.output.chain_id() else {
+            continue;
+        };
+        let chain_id = chain_id.or_from_output_id(input.output_id());
+        if !selected
+            .outputs
+            .iter()
+            .any(|output| output.chain_id() == Some(chain_id))
+        {
+            return Err(format!("chain input {} isn't transitioned", input.output_id()));
+        }
+    }
+
+    Ok(())
+}
+
+fn sum_native_tokens<'a>(outputs: impl Iterator<Item = &'a Output>) -> HashMap<TokenId, U256> {
+    let mut native_tokens = HashMap::new();
+    for native_token in outputs.filter_map(Output::native_tokens).flat_map(|n| n.iter()) {
+        *native_tokens.entry(*native_token.token_id()).or_insert_with(U256::zero) += native_token.amount();
+    }
+    native_tokens
+}
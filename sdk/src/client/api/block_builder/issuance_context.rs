@@ -0,0 +1,74 @@
+// Copyright 2024 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+//! Issuance context of blocks, to re-validate issued blocks later.
+
+use packable::PackableExt;
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    client::{Client, ClientInner, Error, Result},
+    pow::score::PowScorer,
+    types::block::{parent::Parents, payload::Payload, protocol::ProtocolParameters, Block},
+    utils::unix_timestamp_now,
+};
+
+/// The network state a block was built with, stored alongside the block to re-validate it later with
+/// [`Client::revalidate_block()`].
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BlockIssuanceContext {
+    /// The protocol parameters the block was built with.
+    pub protocol_parameters: ProtocolParameters,
+    /// Whether the proof of work of the block was done locally, else the node did it.
+    pub local_pow: bool,
+    /// The latest milestone timestamp known to the client when the block was built.
+    pub latest_milestone_timestamp: Option<u32>,
+    /// The local unix timestamp in seconds when the block was built.
+    pub issuing_time: u32,
+}
+
+impl ClientInner {
+    /// Returns the current issuance context of the client.
+    pub async fn block_issuance_context(&self) -> Result<BlockIssuanceContext> {
+        let network_info = self.get_network_info().await?;
+        Ok(BlockIssuanceContext {
+            protocol_parameters: network_info.protocol_parameters,
+            local_pow: network_info.local_pow,
+            latest_milestone_timestamp: network_info.latest_milestone_timestamp,
+            issuing_time: unix_timestamp_now().as_secs() as u32,
+        })
+    }
+
+    /// Finishes the block like [`ClientInner::finish_block_builder()`] and returns it together with the context it was
+    /// built with.
+    pub async fn finish_block_builder_with_context(
+        &self,
+        parents: Option<Parents>,
+        payload: Option<Payload>,
+    ) -> Result<(Block, BlockIssuanceContext)> {
+        let context = self.block_issuance_context().await?;
+        let block = self.finish_block_builder(parents, payload).await?;
+        Ok((block, context))
+    }
+}
+
+impl Client {
+    /// Re-validates a stored block against the context it was built with: the block has to be syntactically valid for
+    /// the protocol parameters of the context and, if the proof of work was done locally, reach their min PoW score.
+    pub fn revalidate_block(block: &Block, context: &BlockIssuanceContext) -> Result<()> {
+        let block_bytes = block.pack_to_vec();
+
+        Block::unpack_verified(block_bytes.as_slice(), &context.protocol_parameters)?;
+
+        let min_pow_score = context.protocol_parameters.min_pow_score();
+        if context.local_pow && min_pow_score > 0 {
+            let score = PowScorer::new().score(&block_bytes);
+            if score < min_pow_score as f64 {
+                return Err(Error::InsufficientPowScore { score, min_pow_score });
+            }
+        }
+
+        Ok(())
+    }
+}
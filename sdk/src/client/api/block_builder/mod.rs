@@ -2,6 +2,7 @@
 // SPDX-License-Identifier: Apache-2.0
 
 pub mod input_selection;
+pub mod issuance_context;
 pub mod pow;
 pub mod transaction;
 
@@ -11,7 +12,7 @@ use packable::bounded::TryIntoBoundedU16Error;
 use serde::{Deserialize, Serialize};
 
 use self::input_selection::BurnDto;
-pub use self::transaction::verify_semantic;
+pub use self::{issuance_context::BlockIssuanceContext, transaction::verify_semantic};
 use crate::{
     client::{
         api::block_builder::input_selection::Burn, constants::SHIMMER_COIN_TYPE, secret::SecretManager, Client, Error,
@@ -30,6 +30,8 @@ const MAX_TX_LENGTH_FOR_BLOCK_WITH_8_PARENTS: usize = Block::LENGTH_MAX - Block:
 const SINGLE_UNLOCK_LENGTH: usize = 1 + 1 + Ed25519Signature::PUBLIC_KEY_LENGTH + Ed25519Signature::SIGNATURE_LENGTH;
 // Type + reference index
 const REFERENCE_ALIAS_NFT_UNLOCK_LENGTH: usize = 1 + 2;
+// Unlock type + signature type + public key + signature
+const SIGNATURE_UNLOCK_LENGTH: usize = 1 + 1 + Ed25519Signature::PUBLIC_KEY_LENGTH + Ed25519Signature::SIGNATURE_LENGTH;
 
 impl<'a> ClientBlockBuilder<'a> {
     /// Prepare a transaction
@@ -115,6 +117,9 @@ impl<'a> ClientBlockBuilder<'a> {
 }
 
 /// Verifies the semantic of a prepared transaction.
+///
+/// The inputs are matched to the inputs of the essence by their output ID, so they can be in any order, and
+/// [`ConflictReason::InputUtxoNotFound`] is returned if one is missing.
 pub fn verify_semantic(
     input_signing_data: &[InputSigningData],
     transaction: &TransactionPayload,
@@ -122,10 +127,19 @@ pub fn verify_semantic(
 ) -> crate::client::Result<ConflictReason> {
     let transaction_id = transaction.id();
     let TransactionEssence::Regular(essence) = transaction.essence();
-    let inputs = input_signing_data
-        .iter()
-        .map(|input| (input.output_id(), &input.output))
-        .collect::<Vec<(&OutputId, &Output)>>();
+    let mut inputs = Vec::<(&OutputId, &Output)>::with_capacity(essence.inputs().len());
+    for input in essence.inputs() {
+        let Input::Utxo(utxo_input) = input else {
+            return Ok(ConflictReason::SemanticValidationFailed);
+        };
+        match input_signing_data
+            .iter()
+            .find(|input| input.output_id() == utxo_input.output_id())
+        {
+            Some(input) => inputs.push((input.output_id(), &input.output)),
+            None => return Ok(ConflictReason::InputUtxoNotFound),
+        }
+    }
 
     let context = ValidationContext::new(
         &transaction_id,
@@ -150,6 +164,39 @@ pub fn validate_transaction_payload_length(transaction_payload: &TransactionPayl
     Ok(())
 }
 
+/// Verifies that the prepared transaction doesn't exceed the block size limit with 8 parents, before it gets signed.
+///
+/// One signature unlock is assumed for every distinct chain of the inputs and reference/alias/nft unlocks otherwise, so
+/// the length is estimated more precisely than by `validate_regular_transaction_essence_length()`.
+pub fn validate_prepared_transaction_length(prepared_transaction_data: &PreparedTransactionData) -> Result<()> {
+    let mut chains = Vec::new();
+    for input in &prepared_transaction_data.inputs_data {
+        if let Some(chain) = input.chain {
+            if !chains.contains(&chain) {
+                chains.push(chain);
+            }
+        }
+    }
+    let signature_unlocks_amount = chains.len().max(1);
+    let reference_alias_nft_unlocks_amount = prepared_transaction_data
+        .inputs_data
+        .len()
+        .saturating_sub(signature_unlocks_amount);
+
+    let length = prepared_transaction_data.essence.packed_len()
+        + core::mem::size_of::<u16>()
+        + signature_unlocks_amount * SIGNATURE_UNLOCK_LENGTH
+        + reference_alias_nft_unlocks_amount * REFERENCE_ALIAS_NFT_UNLOCK_LENGTH;
+
+    if length > MAX_TX_LENGTH_FOR_BLOCK_WITH_8_PARENTS {
+        return Err(Error::InvalidTransactionPayloadLength {
+            length,
+            max_length: MAX_TX_LENGTH_FOR_BLOCK_WITH_8_PARENTS,
+        });
+    }
+    Ok(())
+}
+
 /// Verifies that the transaction essence doesn't exceed the block size limit with 8 parents.
 /// Assuming one signature unlock and otherwise reference/alias/nft unlocks. `validate_transaction_payload_length()`
 /// should later be used to check the length again with the correct unlocks.
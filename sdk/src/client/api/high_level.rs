@@ -1,9 +1,13 @@
 // Copyright 2022 IOTA Stiftung
 // SPDX-License-Identifier: Apache-2.0
 
-use std::collections::HashSet;
+use std::{
+    collections::HashSet,
+    ops::{Bound, RangeBounds},
+};
 
 use futures::{StreamExt, TryStreamExt};
+use serde::{Deserialize, Serialize};
 
 use crate::{
     client::{
@@ -24,7 +28,7 @@ use crate::{
             parent::Parents,
             payload::{
                 transaction::{TransactionEssence, TransactionId},
-                Payload,
+                Payload, TaggedDataPayload, TransactionPayload,
             },
             Block, BlockId,
         },
@@ -32,6 +36,20 @@ use crate::{
     utils::unix_timestamp_now,
 };
 
+/// A page of the blocks referenced by a milestone, from the explorer API of INX-Chronicle.
+#[derive(Debug, Serialize, Deserialize)]
+struct ExplorerMilestoneBlocksResponse {
+    blocks: Vec<ExplorerBlock>,
+    cursor: Option<String>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct ExplorerBlock {
+    block_id: BlockId,
+    payload_kind: Option<u32>,
+}
+
 impl Client {
     /// Get the inputs of a transaction for the given transaction id.
     pub async fn inputs_from_transaction_id(&self, transaction_id: &TransactionId) -> Result<Vec<OutputWithMetadata>> {
@@ -69,6 +87,99 @@ impl Client {
         futures::future::try_join_all(block_ids.iter().map(|block_id| self.get_block(block_id))).await
     }
 
+    /// Finds the blocks with a tagged data payload with the tag, also as payload of a transaction, which were
+    /// referenced by the milestones in the range of milestone indexes. An unbounded range starts after the pruning
+    /// index of the node and ends with its latest milestone.
+    ///
+    /// Nodes don't index tagged data, so the blocks are looked up with the explorer API of a permanode running
+    /// INX-Chronicle, see [`ClientBuilder::with_permanode()`](crate::client::ClientBuilder::with_permanode).
+    pub async fn find_blocks_by_tag(&self, tag: &[u8], range: impl RangeBounds<u32> + Send) -> Result<Vec<Block>> {
+        let (start_bound, end_bound) = (range.start_bound().cloned(), range.end_bound().cloned());
+        let status = self.get_info().await?.node_info.status;
+        let start = match start_bound {
+            Bound::Included(start) => start,
+            Bound::Excluded(start) => start.saturating_add(1),
+            Bound::Unbounded => status.pruning_index + 1,
+        };
+        let end = match end_bound {
+            Bound::Included(end) => end.saturating_add(1),
+            Bound::Excluded(end) => end,
+            Bound::Unbounded => status.latest_milestone.index + 1,
+        };
+
+        let mut blocks = Vec::new();
+        for milestone_index in start..end {
+            let block_ids = self.milestone_payload_block_ids(milestone_index).await?;
+            let milestone_blocks =
+                futures::future::try_join_all(block_ids.iter().map(|block_id| self.get_block(block_id))).await?;
+            blocks.extend(
+                milestone_blocks
+                    .into_iter()
+                    .filter(|block| tagged_data(block).is_some_and(|tagged_data| tagged_data.tag() == tag)),
+            );
+        }
+
+        Ok(blocks)
+    }
+
+    /// Builds a block with a tagged data payload and posts it, then promotes or reattaches it until it's referenced by
+    /// a milestone. Returns the referenced block, which is a reattachment if the posted block couldn't be referenced
+    /// anymore.
+    pub async fn build_tagged_data_block(
+        &self,
+        tag: impl Into<Vec<u8>> + Send,
+        data: impl Into<Vec<u8>> + Send,
+    ) -> Result<Block> {
+        let block = self
+            .build_block()
+            .with_tag(tag.into())
+            .with_data(data.into())
+            .finish_tagged_data()
+            .await?;
+
+        if self
+            .get_block_metadata(&block.id())
+            .await?
+            .referenced_by_milestone_index
+            .is_some()
+        {
+            return Ok(block);
+        }
+        // The referenced block is at the first position
+        let mut blocks = self.retry_until_included(&block.id(), None, None).await?;
+        Ok(blocks.swap_remove(0).1)
+    }
+
+    // Returns the ids of the blocks with a tagged data or a transaction payload which were referenced by the milestone.
+    async fn milestone_payload_block_ids(&self, milestone_index: u32) -> Result<Vec<BlockId>> {
+        let path = format!("api/explorer/v2/milestones/by-index/{milestone_index}/blocks");
+        let mut block_ids = Vec::new();
+        let mut cursor = None;
+
+        loop {
+            let query = cursor.map(|cursor| format!("cursor={cursor}"));
+            let response: ExplorerMilestoneBlocksResponse =
+                self.get_request(&path, query.as_deref(), false, true).await?;
+            block_ids.extend(
+                response
+                    .blocks
+                    .into_iter()
+                    .filter(|block| {
+                        matches!(
+                            block.payload_kind,
+                            Some(TaggedDataPayload::KIND | TransactionPayload::KIND)
+                        )
+                    })
+                    .map(|block| block.block_id),
+            );
+
+            match response.cursor {
+                Some(next_cursor) => cursor = Some(next_cursor),
+                None => return Ok(block_ids),
+            }
+        }
+    }
+
     /// Retries (promotes or reattaches) a block for provided block id. Block should only be
     /// retried only if they are valid and haven't been confirmed for a while.
     pub async fn retry(&self, block_id: &BlockId) -> Result<(BlockId, Block)> {
@@ -306,3 +417,17 @@ impl Client {
         Ok(current_time)
     }
 }
+
+// Returns the tagged data payload of a block, or of its transaction.
+fn tagged_data(block: &Block) -> Option<&TaggedDataPayload> {
+    match block.payload()? {
+        Payload::TaggedData(tagged_data) => Some(tagged_data),
+        Payload::Transaction(transaction) => match transaction.essence() {
+            TransactionEssence::Regular(essence) => match essence.payload()? {
+                Payload::TaggedData(tagged_data) => Some(tagged_data),
+                _ => None,
+            },
+        },
+        _ => None,
+    }
+}
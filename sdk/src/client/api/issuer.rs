@@ -0,0 +1,94 @@
+// Copyright 2024 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+//! Resolution and verification of the issuers of NFTs, e.g. to check that an NFT belongs to a collection.
+//!
+//! An issuer feature can only be added to an output if its address is unlocked in the same transaction, so the
+//! immutable issuer feature of an NFT proves that it was minted by the alias, NFT or Ed25519 address. NFTs of a
+//! collection are issued by the collection NFT, which holds the IRC-27 metadata of the collection.
+
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    client::{Client, Error, Result},
+    types::block::{
+        address::{Address, Bech32Address, ToBech32Ext},
+        output::{NftId, Output},
+    },
+};
+
+/// An alias or NFT which issued outputs, with its immutable features. Only immutable data is included, so it can be
+/// cached.
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct IssuerInfo {
+    /// The address of the issuer.
+    pub address: Bech32Address,
+    /// The issuer of the issuer itself, e.g. the alias which minted a collection NFT.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub issuer: Option<Bech32Address>,
+    /// The immutable metadata, e.g. the IRC-27 metadata of a collection NFT.
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        with = "crate::utils::serde::option_prefix_hex_bytes"
+    )]
+    pub immutable_metadata: Option<Vec<u8>>,
+}
+
+impl Client {
+    /// Resolves an alias or NFT issuer from its current output and returns its immutable features. Results are
+    /// cached if the client has [`CacheOptions`](crate::client::CacheOptions), also after the issuer was destroyed.
+    /// Ed25519 addresses have no output which could be resolved.
+    pub async fn resolve_issuer(&self, issuer: impl Into<Address> + Send) -> Result<IssuerInfo> {
+        let issuer = issuer.into();
+        let cache_key = format!("issuer-{}-{issuer}", issuer.kind());
+        let cache = self.cache.read().await.clone();
+        if let Some(info) = cache
+            .as_ref()
+            .and_then(|cache| cache.get(&cache_key))
+            .and_then(|bytes| serde_json::from_slice(&bytes).ok())
+        {
+            return Ok(info);
+        }
+
+        let output_id = match issuer {
+            Address::Alias(alias_address) => self.alias_output_id(*alias_address.alias_id()).await?,
+            Address::Nft(nft_address) => self.nft_output_id(*nft_address.nft_id()).await?,
+            Address::Ed25519(_) => return Err(Error::UnresolvableIssuer(issuer.to_string())),
+        };
+        let output = self.get_output(&output_id).await?.into_output();
+
+        let hrp = self.get_bech32_hrp().await?;
+        let immutable_features = output.immutable_features();
+        let info = IssuerInfo {
+            address: issuer.to_bech32(hrp),
+            issuer: immutable_features
+                .and_then(|features| features.issuer())
+                .map(|issuer| issuer.address().to_bech32(hrp)),
+            immutable_metadata: immutable_features
+                .and_then(|features| features.metadata())
+                .map(|metadata| metadata.data().to_vec()),
+        };
+
+        if let Some(cache) = cache {
+            cache.insert(cache_key, serde_json::to_vec(&info)?);
+        }
+
+        Ok(info)
+    }
+
+    /// Returns whether the NFT was issued by `issuer`, e.g. the collection NFT. Fails if the NFT doesn't exist
+    /// anymore.
+    pub async fn verify_issuer(&self, nft_id: NftId, issuer: impl Into<Address> + Send) -> Result<bool> {
+        let issuer = issuer.into();
+        let output_id = self.nft_output_id(nft_id).await?;
+        let Output::Nft(nft) = self.get_output(&output_id).await?.into_output() else {
+            return Ok(false);
+        };
+        Ok(nft
+            .immutable_features()
+            .issuer()
+            .is_some_and(|feature| feature.address() == &issuer))
+    }
+}
@@ -7,8 +7,10 @@ mod address;
 mod block_builder;
 mod consolidation;
 mod high_level;
+mod issuer;
+mod nft_history;
 mod types;
 
-pub use self::{address::*, block_builder::*, types::*};
+pub use self::{address::*, block_builder::*, issuer::*, nft_history::*, types::*};
 
 const ADDRESS_GAP_RANGE: u32 = 20;
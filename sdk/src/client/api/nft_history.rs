@@ -0,0 +1,125 @@
+// Copyright 2024 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+//! Resolution of the ownership history of NFTs, e.g. to display their provenance.
+//!
+//! The outputs of an NFT form a chain: every transaction spends the previous output of the NFT and creates the next
+//! one. The history is resolved backwards from the current output, by looking up the inputs of the transaction which
+//! created each output, until the output which minted the NFT is reached. Spent outputs and old transactions are
+//! pruned by nodes, so a permanode should be configured for NFTs with a longer history.
+
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    client::{node_api::error::Error as NodeApiError, Client, Error, Result},
+    types::block::{
+        address::{Bech32Address, ToBech32Ext},
+        output::{NftId, Output, OutputId, OutputWithMetadata},
+        payload::transaction::TransactionId,
+    },
+};
+
+/// The ownership history of an NFT, see [`Client::nft_history()`].
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct NftHistory {
+    /// The ID of the NFT.
+    pub nft_id: NftId,
+    /// The outputs of the NFT, starting with the oldest known one and ending with the current one.
+    pub outputs: Vec<NftHistoryEntry>,
+    /// Whether the history starts with the output which minted the NFT. If not, older outputs or transactions were
+    /// pruned by the nodes.
+    pub complete: bool,
+}
+
+impl NftHistory {
+    /// Returns the owners of the NFT in chronological order, without repetitions from outputs that didn't change the
+    /// owner.
+    pub fn owners(&self) -> Vec<&Bech32Address> {
+        let mut owners: Vec<&Bech32Address> = Vec::new();
+        for entry in &self.outputs {
+            if owners.last() != Some(&&entry.owner) {
+                owners.push(&entry.owner);
+            }
+        }
+        owners
+    }
+}
+
+/// An output of an NFT with the owner at that time.
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct NftHistoryEntry {
+    /// The ID of the output.
+    pub output_id: OutputId,
+    /// The transaction which created the output, transferring the NFT to the owner or minting it.
+    pub transaction_id: TransactionId,
+    /// The address of the address unlock condition of the output.
+    pub owner: Bech32Address,
+    /// The milestone index at which the output was booked.
+    pub milestone_index_booked: u32,
+    /// The milestone timestamp at which the output was booked.
+    pub milestone_timestamp_booked: u32,
+}
+
+impl Client {
+    /// Resolves the ownership history of an NFT by walking its output chain backwards from the current output. Fails
+    /// if the NFT doesn't exist anymore. Outputs and transactions are requested from a permanode if one is
+    /// configured.
+    pub async fn nft_history(&self, nft_id: NftId) -> Result<NftHistory> {
+        let hrp = self.get_bech32_hrp().await?;
+        let output_id = self.nft_output_id(nft_id).await?;
+        let mut output = self.get_output(&output_id).await?;
+
+        let mut outputs = Vec::new();
+        let complete = loop {
+            let Output::Nft(nft) = output.output() else {
+                let output_id = output.metadata().output_id();
+                return Err(Error::InvalidNftHistory(format!(
+                    "output {output_id} isn't an NFT output"
+                )));
+            };
+            outputs.push(NftHistoryEntry {
+                output_id: *output.metadata().output_id(),
+                transaction_id: *output.metadata().transaction_id(),
+                owner: nft.address().to_bech32(hrp),
+                milestone_index_booked: output.metadata().milestone_index_booked(),
+                milestone_timestamp_booked: output.metadata().milestone_timestamp_booked(),
+            });
+            // Only the output which minted the NFT has a null ID
+            if nft.nft_id().is_null() {
+                break true;
+            }
+
+            let inputs = match self
+                .inputs_from_transaction_id(output.metadata().transaction_id())
+                .await
+            {
+                Ok(inputs) => inputs,
+                Err(Error::Node(NodeApiError::NotFound(_))) => break false,
+                Err(e) => return Err(e),
+            };
+            output = previous_nft_output(inputs, nft_id).ok_or_else(|| {
+                let transaction_id = output.metadata().transaction_id();
+                Error::InvalidNftHistory(format!(
+                    "transaction {transaction_id} didn't spend a previous output of NFT {nft_id}"
+                ))
+            })?;
+        };
+        outputs.reverse();
+
+        Ok(NftHistory {
+            nft_id,
+            outputs,
+            complete,
+        })
+    }
+}
+
+// Returns the input which is the previous output of the NFT
+fn previous_nft_output(inputs: Vec<OutputWithMetadata>, nft_id: NftId) -> Option<OutputWithMetadata> {
+    inputs.into_iter().find(|input| match input.output() {
+        Output::Nft(nft) => nft.nft_id_non_null(input.metadata().output_id()) == nft_id,
+        _ => false,
+    })
+}
@@ -7,19 +7,24 @@ use std::{collections::HashMap, sync::Arc, time::Duration};
 use serde::{Deserialize, Serialize};
 
 use super::{node_manager::builder::NodeManagerBuilder, ClientInner};
+#[cfg(feature = "testing")]
+use crate::client::mock::MockClient;
 #[cfg(feature = "mqtt")]
 use crate::client::node_api::mqtt::{BrokerOptions, MqttEvent};
 use crate::{
     client::{
+        cache::{Cache, CacheOptions},
         constants::{DEFAULT_API_TIMEOUT, DEFAULT_REMOTE_POW_API_TIMEOUT, DEFAULT_TIPS_INTERVAL},
         error::Result,
         node_manager::{
             builder::validate_url,
             node::{Node, NodeAuth},
+            tls_pin::TlsPin,
+            transport::Transport,
         },
         Client,
     },
-    types::block::protocol::ProtocolParameters,
+    types::block::{address::Hrp, output::RentStructure, protocol::ProtocolParameters},
 };
 
 /// Builder to construct client instance with sensible default values
@@ -48,10 +53,18 @@ pub struct ClientBuilder {
     #[cfg(not(target_family = "wasm"))]
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub pow_worker_count: Option<usize>,
-    /// The maximum parallel API requests
+    /// The maximum parallel API requests, also the batch size when many outputs are requested at once
     #[cfg(not(target_family = "wasm"))]
     #[serde(default = "default_max_parallel_api_requests")]
     pub max_parallel_api_requests: usize,
+    /// Options to cache node responses which can't change anymore, disabled if not set
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub cache: Option<CacheOptions>,
+    /// A mocked node which answers all requests instead of the nodes
+    #[cfg(feature = "testing")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "testing")))]
+    #[serde(skip)]
+    pub mock_client: Option<MockClient>,
 }
 
 fn default_api_timeout() -> Duration {
@@ -76,6 +89,7 @@ impl Default for NetworkInfo {
             fallback_to_local_pow: true,
             tips_interval: DEFAULT_TIPS_INTERVAL,
             latest_milestone_timestamp: None,
+            protocol_parameters_overrides: None,
         }
     }
 }
@@ -93,6 +107,9 @@ impl Default for ClientBuilder {
             pow_worker_count: None,
             #[cfg(not(target_family = "wasm"))]
             max_parallel_api_requests: super::constants::MAX_PARALLEL_API_REQUESTS,
+            cache: None,
+            #[cfg(feature = "testing")]
+            mock_client: None,
         }
     }
 }
@@ -116,6 +133,10 @@ impl ClientBuilder {
             let node: Node = node_dto.into();
             validate_url(node.url)?;
         }
+        if let Some(node_dto) = &self.node_manager_builder.issuer_node {
+            let node: Node = node_dto.into();
+            validate_url(node.url)?;
+        }
         for node_dto in &self.node_manager_builder.nodes {
             let node: Node = node_dto.into();
             validate_url(node.url)?;
@@ -146,6 +167,16 @@ impl ClientBuilder {
         Ok(self)
     }
 
+    /// Sets a node by its URL to which blocks are submitted while it's healthy, with optional jwt and or basic
+    /// authentication. Blocks are submitted with the other nodes if it's unhealthy or fails, which is reported to the
+    /// listeners of
+    /// [`ClientInner::listen_issuer_node_fallback()`](crate::client::ClientInner::listen_issuer_node_fallback). It's
+    /// not used for other requests.
+    pub fn with_issuer_node(mut self, url: &str, auth: Option<NodeAuth>) -> Result<Self> {
+        self.node_manager_builder = self.node_manager_builder.with_issuer_node(url, auth)?;
+        Ok(self)
+    }
+
     /// Adds a permanode by its URL, with optional jwt and or basic authentication
     pub fn with_permanode(mut self, url: &str, auth: Option<NodeAuth>) -> Result<Self> {
         self.node_manager_builder = self.node_manager_builder.with_permanode(url, auth)?;
@@ -229,6 +260,16 @@ impl ClientBuilder {
         self
     }
 
+    /// Sets protocol parameters which replace the ones fetched from the node, to target networks with custom
+    /// parameters.
+    pub fn with_protocol_parameters_overrides(
+        mut self,
+        overrides: impl Into<Option<ProtocolParametersOverrides>>,
+    ) -> Self {
+        self.network_info.protocol_parameters_overrides = overrides.into();
+        self
+    }
+
     /// Sets the default request timeout.
     pub fn with_api_timeout(mut self, timeout: Duration) -> Self {
         self.api_timeout = timeout;
@@ -248,6 +289,37 @@ impl ClientBuilder {
         self
     }
 
+    /// Set a proxy for the requests to the nodes, e.g. `http://proxy.local:3128` or, with the `socks` feature,
+    /// `socks5h://127.0.0.1:9050` to connect through TOR. With `socks5h` the hostnames of the nodes are resolved by the
+    /// proxy. MQTT connections don't use the proxy.
+    pub fn with_proxy(mut self, proxy: &str) -> Result<Self> {
+        self.node_manager_builder = self.node_manager_builder.with_proxy(proxy)?;
+        Ok(self)
+    }
+
+    /// Adds a node with optional authentication which is connected through its own proxy instead of the proxy of the
+    /// client.
+    pub fn with_node_proxy(mut self, url: &str, auth: impl Into<Option<NodeAuth>>, proxy: &str) -> Result<Self> {
+        self.node_manager_builder = self.node_manager_builder.with_node_proxy(url, auth, proxy)?;
+        Ok(self)
+    }
+
+    /// Pins a TLS certificate or public key for the host of an https node URL. Connections to the host are rejected if
+    /// its certificate doesn't match any of its pins, even if it's issued by a trusted CA. Custom transports and MQTT
+    /// connections don't use the pins.
+    pub fn with_tls_pin(mut self, url: &str, pin: TlsPin) -> Result<Self> {
+        self.node_manager_builder = self.node_manager_builder.with_tls_pin(url, pin)?;
+        Ok(self)
+    }
+
+    /// Set a transport which sends the requests to the nodes instead of TCP connections, e.g. a
+    /// [`UnixSocketTransport`](crate::client::node_manager::transport::UnixSocketTransport) or an in-process test
+    /// server. It isn't serialized with the client options.
+    pub fn with_transport(mut self, transport: impl Into<Transport>) -> Self {
+        self.node_manager_builder = self.node_manager_builder.with_transport(transport.into());
+        self
+    }
+
     /// Set maximum parallel API requests.
     #[cfg(not(target_family = "wasm"))]
     pub fn with_max_parallel_api_requests(mut self, max_parallel_api_requests: usize) -> Self {
@@ -255,6 +327,23 @@ impl ClientBuilder {
         self
     }
 
+    /// Set the options to cache node responses which can't change anymore, like blocks and milestones.
+    pub fn with_cache(mut self, cache: impl Into<Option<CacheOptions>>) -> Self {
+        self.cache = cache.into();
+        self
+    }
+
+    /// Sets a mocked node which answers all requests from in-memory state, the protocol parameters and the latest
+    /// milestone timestamp are taken from it.
+    #[cfg(feature = "testing")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "testing")))]
+    pub fn with_mock_client(mut self, mock_client: MockClient) -> Self {
+        self.network_info.protocol_parameters = mock_client.protocol_parameters();
+        self.network_info.latest_milestone_timestamp = Some(mock_client.milestone_timestamp());
+        self.mock_client = Some(mock_client);
+        self
+    }
+
     /// Build the Client instance.
     #[cfg(not(target_family = "wasm"))]
     pub async fn finish(self) -> Result<Client> {
@@ -273,9 +362,15 @@ impl ClientBuilder {
         #[cfg(feature = "mqtt")]
         let (mqtt_event_tx, mqtt_event_rx) = tokio::sync::watch::channel(MqttEvent::Connected);
 
+        let mut network_info = self.network_info;
+        // Apply the overrides right away, so they are also used if no node can be reached
+        if let Some(overrides) = &network_info.protocol_parameters_overrides {
+            network_info.protocol_parameters = overrides.apply(&network_info.protocol_parameters)?;
+        }
+
         let client_inner = Arc::new(ClientInner {
             node_manager: RwLock::new(self.node_manager_builder.build(HashMap::new())),
-            network_info: RwLock::new(self.network_info),
+            network_info: RwLock::new(network_info),
             api_timeout: RwLock::new(self.api_timeout),
             remote_pow_timeout: RwLock::new(self.remote_pow_timeout),
             pow_worker_count: RwLock::new(self.pow_worker_count),
@@ -288,6 +383,10 @@ impl ClientBuilder {
                 receiver: RwLock::new(mqtt_event_rx),
             },
             request_pool: crate::client::request_pool::RequestPool::new(self.max_parallel_api_requests),
+            cache: RwLock::new(self.cache.map(|options| Arc::new(Cache::new(options)))),
+            issuer_node_fallback_listeners: Default::default(),
+            #[cfg(feature = "testing")]
+            mock_client: self.mock_client,
         });
 
         client_inner.sync_nodes(&nodes, ignore_node_health).await?;
@@ -330,6 +429,10 @@ impl ClientBuilder {
                     receiver: RwLock::new(mqtt_event_rx),
                 },
                 last_sync: tokio::sync::Mutex::new(None),
+                cache: RwLock::new(self.cache.map(|options| Arc::new(Cache::new(options)))),
+                issuer_node_fallback_listeners: Default::default(),
+                #[cfg(feature = "testing")]
+                mock_client: self.mock_client,
             }),
         };
 
@@ -348,6 +451,9 @@ impl ClientBuilder {
             pow_worker_count: *client.pow_worker_count.read().await,
             #[cfg(not(target_family = "wasm"))]
             max_parallel_api_requests: client.request_pool.size().await,
+            cache: client.cache.read().await.as_ref().map(|cache| cache.options().clone()),
+            #[cfg(feature = "testing")]
+            mock_client: client.mock_client.clone(),
         }
     }
 }
@@ -371,6 +477,9 @@ pub struct NetworkInfo {
     /// The latest cached milestone timestamp.
     #[serde(skip)]
     pub latest_milestone_timestamp: Option<u32>,
+    /// Protocol parameters which replace the ones fetched from the node.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub protocol_parameters_overrides: Option<ProtocolParametersOverrides>,
 }
 
 impl NetworkInfo {
@@ -398,6 +507,164 @@ impl NetworkInfo {
         self.latest_milestone_timestamp = latest_milestone_timestamp.into();
         self
     }
+
+    pub fn with_protocol_parameters_overrides(
+        mut self,
+        protocol_parameters_overrides: impl Into<Option<ProtocolParametersOverrides>>,
+    ) -> Self {
+        self.protocol_parameters_overrides = protocol_parameters_overrides.into();
+        self
+    }
+
+    /// Sets the protocol parameters fetched from a node, with the overrides applied on top of them.
+    pub(crate) fn update_protocol_parameters(
+        &mut self,
+        protocol_parameters: ProtocolParameters,
+    ) -> core::result::Result<(), crate::types::block::Error> {
+        self.protocol_parameters = match &self.protocol_parameters_overrides {
+            Some(overrides) => {
+                overrides.warn_on_mismatch(&protocol_parameters);
+                overrides.apply(&protocol_parameters)?
+            }
+            None => protocol_parameters,
+        };
+        Ok(())
+    }
+}
+
+/// Protocol parameters which replace the ones fetched from the node, fields that aren't set are taken from the node.
+///
+/// This allows to target private tangles and test networks with custom parameters, a warning is logged for every
+/// parameter that differs from the node.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ProtocolParametersOverrides {
+    /// The version of the protocol.
+    #[serde(default, rename = "version", skip_serializing_if = "Option::is_none")]
+    pub protocol_version: Option<u8>,
+    /// The human friendly name of the network.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub network_name: Option<String>,
+    /// The HRP prefix used for Bech32 addresses in the network.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub bech32_hrp: Option<Hrp>,
+    /// The minimum PoW score of the network.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub min_pow_score: Option<u32>,
+    /// The below max depth parameter of the network.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub below_max_depth: Option<u8>,
+    /// The rent structure of the network.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub rent_structure: Option<RentStructure>,
+    /// The token supply of the network.
+    #[serde(
+        default,
+        with = "crate::utils::serde::option_string",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub token_supply: Option<u64>,
+}
+
+impl ProtocolParametersOverrides {
+    pub fn with_protocol_version(mut self, protocol_version: impl Into<Option<u8>>) -> Self {
+        self.protocol_version = protocol_version.into();
+        self
+    }
+
+    pub fn with_network_name(mut self, network_name: impl Into<Option<String>>) -> Self {
+        self.network_name = network_name.into();
+        self
+    }
+
+    pub fn with_bech32_hrp(mut self, bech32_hrp: impl Into<Option<Hrp>>) -> Self {
+        self.bech32_hrp = bech32_hrp.into();
+        self
+    }
+
+    pub fn with_min_pow_score(mut self, min_pow_score: impl Into<Option<u32>>) -> Self {
+        self.min_pow_score = min_pow_score.into();
+        self
+    }
+
+    pub fn with_below_max_depth(mut self, below_max_depth: impl Into<Option<u8>>) -> Self {
+        self.below_max_depth = below_max_depth.into();
+        self
+    }
+
+    pub fn with_rent_structure(mut self, rent_structure: impl Into<Option<RentStructure>>) -> Self {
+        self.rent_structure = rent_structure.into();
+        self
+    }
+
+    pub fn with_token_supply(mut self, token_supply: impl Into<Option<u64>>) -> Self {
+        self.token_supply = token_supply.into();
+        self
+    }
+
+    /// Returns the protocol parameters with the overrides applied on top of them.
+    pub fn apply(
+        &self,
+        protocol_parameters: &ProtocolParameters,
+    ) -> core::result::Result<ProtocolParameters, crate::types::block::Error> {
+        ProtocolParameters::new(
+            self.protocol_version
+                .unwrap_or_else(|| protocol_parameters.protocol_version()),
+            self.network_name
+                .clone()
+                .unwrap_or_else(|| protocol_parameters.network_name().to_owned()),
+            self.bech32_hrp.unwrap_or_else(|| *protocol_parameters.bech32_hrp()),
+            self.min_pow_score
+                .unwrap_or_else(|| protocol_parameters.min_pow_score()),
+            self.below_max_depth
+                .unwrap_or_else(|| protocol_parameters.below_max_depth()),
+            self.rent_structure
+                .unwrap_or_else(|| *protocol_parameters.rent_structure()),
+            self.token_supply.unwrap_or_else(|| protocol_parameters.token_supply()),
+        )
+    }
+
+    fn warn_on_mismatch(&self, protocol_parameters: &ProtocolParameters) {
+        fn warn<T: PartialEq + core::fmt::Debug + ?Sized>(name: &str, overridden: Option<&T>, node: &T) {
+            if let Some(overridden) = overridden {
+                if overridden != node {
+                    log::warn!("Protocol parameter {name} is overridden with {overridden:?}, the node uses {node:?}");
+                }
+            }
+        }
+
+        warn(
+            "version",
+            self.protocol_version.as_ref(),
+            &protocol_parameters.protocol_version(),
+        );
+        warn(
+            "network name",
+            self.network_name.as_deref(),
+            protocol_parameters.network_name(),
+        );
+        warn("bech32 HRP", self.bech32_hrp.as_ref(), protocol_parameters.bech32_hrp());
+        warn(
+            "min PoW score",
+            self.min_pow_score.as_ref(),
+            &protocol_parameters.min_pow_score(),
+        );
+        warn(
+            "below max depth",
+            self.below_max_depth.as_ref(),
+            &protocol_parameters.below_max_depth(),
+        );
+        warn(
+            "rent structure",
+            self.rent_structure.as_ref(),
+            protocol_parameters.rent_structure(),
+        );
+        warn(
+            "token supply",
+            self.token_supply.as_ref(),
+            &protocol_parameters.token_supply(),
+        );
+    }
 }
 
 fn default_local_pow() -> bool {
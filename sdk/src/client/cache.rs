@@ -0,0 +1,197 @@
+// Copyright 2024 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+//! A cache for node responses which can't change anymore, like blocks and milestones.
+
+#[cfg(not(target_family = "wasm"))]
+use std::path::PathBuf;
+use std::{
+    collections::{BTreeMap, HashMap},
+    sync::Mutex,
+};
+
+use serde::{Deserialize, Serialize};
+
+use crate::client::{constants::DEFAULT_CACHE_CAPACITY, ClientInner};
+
+/// Options to cache node responses which can't change anymore: blocks, the blocks which included a transaction,
+/// milestones and their UTXO changes.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CacheOptions {
+    /// The maximum amount of responses kept in memory, the least recently used ones are dropped first.
+    #[serde(default = "default_capacity")]
+    pub capacity: usize,
+    /// A directory in which the responses are also stored, so they are still available after a restart.
+    #[cfg(not(target_family = "wasm"))]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub storage_path: Option<PathBuf>,
+}
+
+fn default_capacity() -> usize {
+    DEFAULT_CACHE_CAPACITY
+}
+
+impl Default for CacheOptions {
+    fn default() -> Self {
+        Self {
+            capacity: DEFAULT_CACHE_CAPACITY,
+            #[cfg(not(target_family = "wasm"))]
+            storage_path: None,
+        }
+    }
+}
+
+impl CacheOptions {
+    /// Creates new [`CacheOptions`] which keep up to `capacity` responses in memory.
+    pub fn new(capacity: usize) -> Self {
+        Self {
+            capacity,
+            ..Default::default()
+        }
+    }
+
+    /// Sets a directory in which the responses are also stored.
+    #[cfg(not(target_family = "wasm"))]
+    pub fn with_storage_path(mut self, storage_path: impl Into<PathBuf>) -> Self {
+        self.storage_path = Some(storage_path.into());
+        self
+    }
+}
+
+#[derive(Debug)]
+pub(crate) struct Cache {
+    options: CacheOptions,
+    entries: Mutex<LruEntries>,
+}
+
+#[derive(Debug, Default)]
+struct LruEntries {
+    // Incremented on every access, the entry with the lowest tick is the least recently used one
+    tick: u64,
+    entries: HashMap<String, (u64, Vec<u8>)>,
+    recency: BTreeMap<u64, String>,
+}
+
+impl LruEntries {
+    fn get(&mut self, key: &str) -> Option<Vec<u8>> {
+        self.tick += 1;
+        let tick = self.tick;
+        let (entry_tick, value) = self.entries.get_mut(key)?;
+        let key = self.recency.remove(entry_tick).expect("every entry has a tick");
+        *entry_tick = tick;
+        self.recency.insert(tick, key);
+        Some(value.clone())
+    }
+
+    fn insert(&mut self, key: String, value: Vec<u8>, capacity: usize) {
+        self.tick += 1;
+        if let Some((old_tick, _)) = self.entries.insert(key.clone(), (self.tick, value)) {
+            self.recency.remove(&old_tick);
+        }
+        self.recency.insert(self.tick, key);
+
+        while self.entries.len() > capacity {
+            let Some((_, key)) = self.recency.pop_first() else {
+                break;
+            };
+            self.entries.remove(&key);
+        }
+    }
+}
+
+impl Cache {
+    pub(crate) fn new(options: CacheOptions) -> Self {
+        Self {
+            options,
+            entries: Default::default(),
+        }
+    }
+
+    pub(crate) fn options(&self) -> &CacheOptions {
+        &self.options
+    }
+
+    /// Returns the cache key of a GET request, if its response can't change anymore.
+    pub(crate) fn key(path: &str, query: Option<&str>, raw: bool) -> Option<String> {
+        if query.is_some() {
+            return None;
+        }
+        let route = path.strip_prefix("api/core/v2/")?;
+        let segments = route.split('/').collect::<Vec<_>>();
+        match segments.as_slice() {
+            ["blocks", _]
+            | ["transactions", _, "included-block"]
+            | ["milestones", _]
+            | ["milestones", _, "utxo-changes"]
+            | ["milestones", "by-index", _]
+            | ["milestones", "by-index", _, "utxo-changes"] => {
+                // The keys are also used as file names
+                let key = segments.join("-");
+                Some(if raw { format!("raw-{key}") } else { key })
+            }
+            _ => None,
+        }
+    }
+
+    fn lock(&self) -> std::sync::MutexGuard<'_, LruEntries> {
+        // The entries are never left inconsistent by a panic, so a poisoned lock can still be used.
+        self.entries.lock().unwrap_or_else(|e| e.into_inner())
+    }
+
+    /// Returns a cached response, from memory or from the storage.
+    pub(crate) fn get(&self, key: &str) -> Option<Vec<u8>> {
+        if let Some(value) = self.lock().get(key) {
+            return Some(value);
+        }
+
+        #[cfg(not(target_family = "wasm"))]
+        if let Some(storage_path) = &self.options.storage_path {
+            if let Ok(value) = std::fs::read(storage_path.join(key)) {
+                self.lock()
+                    .insert(key.to_string(), value.clone(), self.options.capacity);
+                return Some(value);
+            }
+        }
+
+        None
+    }
+
+    /// Caches a response in memory and in the storage.
+    pub(crate) fn insert(&self, key: String, value: Vec<u8>) {
+        #[cfg(not(target_family = "wasm"))]
+        if let Some(storage_path) = &self.options.storage_path {
+            // The response can also be requested from the node again, so failing to store it isn't an error
+            if let Err(e) =
+                std::fs::create_dir_all(storage_path).and_then(|_| std::fs::write(storage_path.join(&key), &value))
+            {
+                log::warn!("[CACHE] couldn't store {key}: {e}");
+            }
+        }
+
+        self.lock().insert(key, value, self.options.capacity);
+    }
+
+    /// Removes all responses from memory and from the storage.
+    pub(crate) fn clear(&self) {
+        *self.lock() = LruEntries::default();
+
+        #[cfg(not(target_family = "wasm"))]
+        if let Some(storage_path) = &self.options.storage_path {
+            if let Err(e) = std::fs::remove_dir_all(storage_path) {
+                if e.kind() != std::io::ErrorKind::NotFound {
+                    log::warn!("[CACHE] couldn't remove {}: {e}", storage_path.display());
+                }
+            }
+        }
+    }
+}
+
+impl ClientInner {
+    /// Removes all cached node responses, also from the storage.
+    pub async fn clear_cache(&self) {
+        if let Some(cache) = &*self.cache.read().await {
+            cache.clear();
+        }
+    }
+}
@@ -20,8 +20,9 @@ use crate::client::constants::CACHE_NETWORK_INFO_TIMEOUT_IN_SECONDS;
 use crate::{
     client::{
         builder::{ClientBuilder, NetworkInfo},
+        cache::Cache,
         error::Result,
-        node_manager::NodeManager,
+        node_manager::{issuer_node::IssuerNodeFallbackListener, NodeManager},
         Error,
     },
     types::block::{address::Hrp, output::RentStructure, protocol::ProtocolParameters},
@@ -60,6 +61,11 @@ pub struct ClientInner {
     pub(crate) last_sync: tokio::sync::Mutex<Option<u32>>,
     #[cfg(not(target_family = "wasm"))]
     pub(crate) request_pool: RequestPool,
+    /// Cache for node responses which can't change anymore.
+    pub(crate) cache: RwLock<Option<Arc<Cache>>>,
+    pub(crate) issuer_node_fallback_listeners: RwLock<Vec<IssuerNodeFallbackListener>>,
+    #[cfg(feature = "testing")]
+    pub(crate) mock_client: Option<crate::client::mock::MockClient>,
 }
 
 #[derive(Default)]
@@ -93,6 +99,9 @@ impl std::fmt::Debug for Client {
         d.field("network_info", &self.network_info);
         #[cfg(not(target_family = "wasm"))]
         d.field("request_pool", &self.request_pool);
+        d.field("cache", &self.cache);
+        #[cfg(feature = "testing")]
+        d.field("mock_client", &self.mock_client);
         d.finish()
     }
 }
@@ -121,7 +130,7 @@ impl ClientInner {
             }
             let info = self.get_info().await?.node_info;
             let mut client_network_info = self.network_info.write().await;
-            client_network_info.protocol_parameters = info.protocol.clone();
+            client_network_info.update_protocol_parameters(info.protocol.clone())?;
             *self.last_sync.lock().await = Some(current_time + CACHE_NETWORK_INFO_TIMEOUT_IN_SECONDS);
         }
 
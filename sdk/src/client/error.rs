@@ -45,6 +45,21 @@ pub enum Error {
     /// Crypto.rs error
     #[error("{0}")]
     Crypto(#[from] crypto::Error),
+    /// A seed source failed to provide entropy
+    #[error("seed source error: {0}")]
+    SeedSource(String),
+    /// The faucet rejected a request
+    #[error("faucet request failed with status code {code}: {message}")]
+    Faucet { code: u16, message: String },
+    /// The faucet rejected a request because too many were sent
+    #[error(
+        "faucet rate limit reached{}",
+        retry_after.map(|seconds| format!(", retry after {seconds} seconds")).unwrap_or_default()
+    )]
+    FaucetRateLimited { retry_after: Option<u64> },
+    /// The funds requested from a faucet didn't arrive in time
+    #[error("funds requested from the faucet didn't arrive within {0} seconds")]
+    FaucetTimeout(u64),
     /// Address not found
     #[error("address: {address} not found in range: {range}")]
     InputAddressNotFound {
@@ -53,12 +68,26 @@ pub enum Error {
         /// The range in which the address was not found.
         range: String,
     },
+    /// The proof of work score of a block is lower than required
+    #[error("the proof of work score {score} of the block is lower than the min PoW score {min_pow_score}")]
+    InsufficientPowScore {
+        /// The score of the block.
+        score: f64,
+        /// The min PoW score of the protocol parameters.
+        min_pow_score: u32,
+    },
     /// Invalid amount in API response
     #[error("invalid amount in API response: {0}")]
     InvalidAmount(String),
+    /// Only alias and NFT issuers have outputs which can be resolved
+    #[error("issuer {0} can't be resolved, only alias and NFT addresses have outputs")]
+    UnresolvableIssuer(String),
     /// Invalid mnemonic error
     #[error("invalid mnemonic {0}")]
     InvalidMnemonic(String),
+    /// The output chain of an NFT is inconsistent
+    #[error("invalid NFT history: {0}")]
+    InvalidNftHistory(String),
     /// The transaction essence is too large
     #[error("the transaction essence is too large. Its length is {length}, max length is {max_length}")]
     InvalidRegularTransactionEssenceLength {
@@ -67,6 +96,9 @@ pub enum Error {
         /// The max supported length.
         max_length: usize,
     },
+    /// A transaction proof doesn't match the transaction or its consumed outputs
+    #[error("invalid transaction proof: {0}")]
+    InvalidTransactionProof(String),
     /// The transaction payload is too large
     #[error("the transaction payload is too large. Its length is {length}, max length is {max_length}")]
     InvalidTransactionPayloadLength {
@@ -189,6 +221,12 @@ pub enum Error {
     #[cfg_attr(docsrs, doc(cfg(feature = "stronghold")))]
     #[error("{0}")]
     Stronghold(#[from] crate::client::stronghold::Error),
+
+    /// Error starting or stopping a test network
+    #[cfg(all(feature = "testing", not(target_family = "wasm")))]
+    #[cfg_attr(docsrs, doc(cfg(feature = "testing")))]
+    #[error("node harness error: {0}")]
+    NodeHarness(String),
 }
 
 // Serialize type with Display error
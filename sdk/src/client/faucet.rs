@@ -0,0 +1,195 @@
+// Copyright 2024 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+//! A client for the faucet of a test network, which enqueues requests for funds and waits until they arrived.
+//!
+//! ```ignore
+//! let faucet = FaucetClient::new("https://faucet.testnet.shimmer.network/api/enqueue")?;
+//! let output_ids = faucet.request_funds_and_wait(&client, &address, None).await?;
+//! ```
+
+use std::{collections::HashSet, time::Duration};
+
+use reqwest::{header::RETRY_AFTER, RequestBuilder, Response, StatusCode};
+use serde::{de::DeserializeOwned, Deserialize, Serialize};
+use url::Url;
+
+use crate::{
+    client::{
+        constants::{DEFAULT_FAUCET_POLL_INTERVAL, DEFAULT_FAUCET_TIMEOUT},
+        node_api::indexer::query_parameters::QueryParameter,
+        node_manager::node::NodeAuth,
+        Client, Error, Result,
+    },
+    types::block::{
+        address::{Bech32Address, Hrp},
+        output::OutputId,
+    },
+};
+
+/// The response of the faucet to an enqueued request.
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct FaucetEnqueueResponse {
+    /// The address the funds are sent to.
+    pub address: Bech32Address,
+    /// The number of requests in the queue, including this one.
+    pub waiting_requests: usize,
+}
+
+/// Information about a faucet.
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct FaucetInfo {
+    /// The address the faucet sends funds from.
+    pub address: Bech32Address,
+    /// The remaining balance of the faucet.
+    pub balance: u64,
+    /// The name of the token of the network.
+    pub token_name: String,
+    /// The bech32 HRP of the network.
+    pub bech32_hrp: Hrp,
+}
+
+#[derive(Deserialize)]
+struct FaucetErrorResponse {
+    error: FaucetErrorMessage,
+}
+
+#[derive(Deserialize)]
+struct FaucetErrorMessage {
+    message: String,
+}
+
+/// A client for a faucet, see the [module documentation](self).
+#[derive(Clone, Debug)]
+pub struct FaucetClient {
+    url: Url,
+    auth: Option<NodeAuth>,
+    http_client: reqwest::Client,
+}
+
+impl FaucetClient {
+    /// Creates a client for the faucet with the enqueue endpoint at `url`, e.g.
+    /// `https://faucet.testnet.shimmer.network/api/enqueue`.
+    pub fn new(url: &str) -> Result<Self> {
+        Ok(Self {
+            url: Url::parse(url)?,
+            auth: None,
+            http_client: reqwest::Client::new(),
+        })
+    }
+
+    /// Sends the jwt, basic auth and custom headers of the auth with every request to the faucet.
+    pub fn with_auth(mut self, auth: impl Into<Option<NodeAuth>>) -> Self {
+        self.auth = auth.into();
+        self
+    }
+
+    /// Returns the URL of the enqueue endpoint.
+    pub fn url(&self) -> &Url {
+        &self.url
+    }
+
+    /// Requests information about the faucet, from the `info` endpoint next to the enqueue endpoint.
+    pub async fn info(&self) -> Result<FaucetInfo> {
+        let response = self
+            .authenticate(self.http_client.get(self.url.join("info")?))
+            .send()
+            .await
+            .map_err(|err| Error::Node(err.into()))?;
+        parse_response(response).await
+    }
+
+    /// Enqueues a request for funds to the address. The funds arrive once the request is processed, see
+    /// [`FaucetClient::request_funds_and_wait()`].
+    ///
+    /// Fails with [`Error::FaucetRateLimited`] if too many requests were sent.
+    pub async fn request_funds(&self, address: &Bech32Address) -> Result<FaucetEnqueueResponse> {
+        log::debug!("[FaucetClient] request_funds for {address}");
+        let response = self
+            .authenticate(self.http_client.post(self.url.clone()))
+            .json(&serde_json::json!({ "address": address }))
+            .send()
+            .await
+            .map_err(|err| Error::Node(err.into()))?;
+        parse_response(response).await
+    }
+
+    /// Enqueues a request for funds to the address and polls the outputs of the address until new ones arrived, or
+    /// fails with [`Error::FaucetTimeout`] after `timeout` (two minutes by default). Returns the ids of the new basic
+    /// outputs.
+    pub async fn request_funds_and_wait(
+        &self,
+        client: &Client,
+        address: &Bech32Address,
+        timeout: impl Into<Option<Duration>> + Send,
+    ) -> Result<Vec<OutputId>> {
+        let timeout = timeout.into().unwrap_or(DEFAULT_FAUCET_TIMEOUT);
+        let known_output_ids = address_output_ids(client, address).await?;
+
+        let enqueued = self.request_funds(address).await?;
+        log::debug!(
+            "[FaucetClient] request enqueued, {} requests waiting",
+            enqueued.waiting_requests
+        );
+
+        let attempts = (timeout.as_millis() / DEFAULT_FAUCET_POLL_INTERVAL.as_millis()).max(1);
+        for _ in 0..attempts {
+            #[cfg(target_family = "wasm")]
+            gloo_timers::future::TimeoutFuture::new(DEFAULT_FAUCET_POLL_INTERVAL.as_millis() as u32).await;
+            #[cfg(not(target_family = "wasm"))]
+            tokio::time::sleep(DEFAULT_FAUCET_POLL_INTERVAL).await;
+
+            let new_output_ids = address_output_ids(client, address)
+                .await?
+                .difference(&known_output_ids)
+                .copied()
+                .collect::<Vec<_>>();
+            if !new_output_ids.is_empty() {
+                return Ok(new_output_ids);
+            }
+        }
+
+        Err(Error::FaucetTimeout(timeout.as_secs()))
+    }
+
+    fn authenticate(&self, request_builder: RequestBuilder) -> RequestBuilder {
+        match &self.auth {
+            Some(auth) => auth.apply(request_builder),
+            None => request_builder,
+        }
+    }
+}
+
+async fn address_output_ids(client: &Client, address: &Bech32Address) -> Result<HashSet<OutputId>> {
+    Ok(client
+        .basic_output_ids([QueryParameter::Address(*address)])
+        .await?
+        .items
+        .into_iter()
+        .collect())
+}
+
+async fn parse_response<T: DeserializeOwned>(response: Response) -> Result<T> {
+    let status = response.status();
+    if status == StatusCode::TOO_MANY_REQUESTS {
+        let retry_after = response
+            .headers()
+            .get(RETRY_AFTER)
+            .and_then(|value| value.to_str().ok())
+            .and_then(|value| value.parse().ok());
+        return Err(Error::FaucetRateLimited { retry_after });
+    }
+
+    let text = response.text().await.map_err(|err| Error::Node(err.into()))?;
+    if !status.is_success() {
+        let message = serde_json::from_str::<FaucetErrorResponse>(&text).map_or(text, |e| e.error.message);
+        return Err(Error::Faucet {
+            code: status.as_u16(),
+            message,
+        });
+    }
+
+    Ok(serde_json::from_str(&text)?)
+}
@@ -0,0 +1,312 @@
+// Copyright 2024 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+//! Builders for requests to IOTA Smart Contract (ISC) chains.
+//!
+//! An on-ledger request is an output sent to the alias address of a chain, which has a sender feature and the request
+//! encoded in its metadata feature. The allowance of a request is the part of the sent assets that the called contract
+//! may take, the rest is deposited to the L2 account of the sender.
+//!
+//! ```ignore
+//! // Deposit 1 Smr to an EVM account, leaving the rest of the amount to pay the gas fee
+//! let request = IscRequest::transfer_allowance_to(
+//!     AgentId::Ethereum(evm_address),
+//!     Allowance::new().with_base_tokens(1_000_000),
+//! );
+//! let output = BasicOutputBuilder::new_with_amount(1_100_000)
+//!     .add_unlock_condition(AddressUnlockCondition::new(chain_address))
+//!     .add_feature(SenderFeature::new(sender_address))
+//!     .add_feature(request.to_metadata_feature()?)
+//!     .finish_output(token_supply)?;
+//! ```
+
+use std::collections::BTreeMap;
+
+use crypto::hashes::{blake2b::Blake2b256, Digest};
+use packable::PackableExt;
+use primitive_types::U256;
+
+use crate::types::block::{
+    address::Address,
+    output::{feature::MetadataFeature, NativeToken, NftId},
+    Error,
+};
+
+/// The name of the core contract managing the L2 accounts of a chain.
+pub const ACCOUNTS_CONTRACT: &str = "accounts";
+/// The entry point of the accounts contract crediting the sent assets to the L2 account of the sender.
+pub const DEPOSIT_ENTRY_POINT: &str = "deposit";
+/// The entry point of the accounts contract moving the allowance to the L2 account of another agent.
+pub const TRANSFER_ALLOWANCE_TO_ENTRY_POINT: &str = "transferAllowanceTo";
+/// The parameter of `transferAllowanceTo` holding the receiving agent.
+pub const AGENT_ID_PARAM: &str = "a";
+
+const AGENT_ID_KIND_ADDRESS: u8 = 1;
+const AGENT_ID_KIND_ETHEREUM_ADDRESS: u8 = 3;
+const ALLOWANCE_HAS_BASE_TOKENS: u8 = 0x80;
+const ALLOWANCE_HAS_NATIVE_TOKENS: u8 = 0x40;
+const ALLOWANCE_HAS_NFTS: u8 = 0x20;
+
+/// The hashed name of a contract or an entry point, identifying it on a chain.
+#[derive(Copy, Clone, Debug, Default, Eq, PartialEq, Hash, Ord, PartialOrd)]
+pub struct Hname(u32);
+
+impl Hname {
+    /// Hashes a contract or entry point name into its [`Hname`].
+    pub fn new(name: &str) -> Self {
+        let hash = Blake2b256::digest(name.as_bytes());
+        // 0 and u32::MAX are reserved, so the next 4 bytes of the hash are used instead
+        for window in hash.chunks_exact(4) {
+            let hname = u32::from_le_bytes(window.try_into().unwrap());
+            if hname != 0 && hname != u32::MAX {
+                return Self(hname);
+            }
+        }
+        unreachable!("a hash with only reserved windows")
+    }
+
+    /// Returns the [`Hname`] as `u32`.
+    pub fn as_u32(&self) -> u32 {
+        self.0
+    }
+}
+
+impl From<u32> for Hname {
+    fn from(value: u32) -> Self {
+        Self(value)
+    }
+}
+
+impl core::fmt::Display for Hname {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "{:08x}", self.0)
+    }
+}
+
+/// An agent owning an L2 account on a chain.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum AgentId {
+    /// An L1 address.
+    Address(Address),
+    /// An EVM account.
+    Ethereum([u8; 20]),
+}
+
+impl AgentId {
+    /// Encodes the agent id like a chain does.
+    pub fn pack_to_vec(&self) -> Vec<u8> {
+        let mut bytes = Vec::new();
+        match self {
+            Self::Address(address) => {
+                bytes.push(AGENT_ID_KIND_ADDRESS);
+                bytes.extend(address.pack_to_vec());
+            }
+            Self::Ethereum(address) => {
+                bytes.push(AGENT_ID_KIND_ETHEREUM_ADDRESS);
+                bytes.extend(address);
+            }
+        }
+        bytes
+    }
+}
+
+impl From<Address> for AgentId {
+    fn from(value: Address) -> Self {
+        Self::Address(value)
+    }
+}
+
+/// The assets of a request that the called contract may take.
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct Allowance {
+    /// The base coin amount.
+    pub base_tokens: u64,
+    /// The native tokens.
+    pub native_tokens: Vec<NativeToken>,
+    /// The NFTs, which have to be sent with the request.
+    pub nfts: Vec<NftId>,
+}
+
+impl Allowance {
+    /// Creates an empty [`Allowance`].
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the base coin amount.
+    pub fn with_base_tokens(mut self, base_tokens: u64) -> Self {
+        self.base_tokens = base_tokens;
+        self
+    }
+
+    /// Adds a native token.
+    pub fn add_native_token(mut self, native_token: NativeToken) -> Self {
+        self.native_tokens.push(native_token);
+        self
+    }
+
+    /// Adds an NFT.
+    pub fn add_nft(mut self, nft_id: NftId) -> Self {
+        self.nfts.push(nft_id);
+        self
+    }
+
+    /// Returns whether no assets are allowed.
+    pub fn is_empty(&self) -> bool {
+        self.base_tokens == 0 && self.native_tokens.is_empty() && self.nfts.is_empty()
+    }
+
+    fn pack(&self, bytes: &mut Vec<u8>) {
+        if self.is_empty() {
+            bytes.push(0);
+            return;
+        }
+
+        let mut flags = 0;
+        if self.base_tokens != 0 {
+            flags |= ALLOWANCE_HAS_BASE_TOKENS;
+        }
+        if !self.native_tokens.is_empty() {
+            flags |= ALLOWANCE_HAS_NATIVE_TOKENS;
+        }
+        if !self.nfts.is_empty() {
+            flags |= ALLOWANCE_HAS_NFTS;
+        }
+        bytes.push(flags);
+
+        if self.base_tokens != 0 {
+            write_varint(bytes, self.base_tokens);
+        }
+        if !self.native_tokens.is_empty() {
+            write_varint(bytes, self.native_tokens.len() as u64);
+            for native_token in &self.native_tokens {
+                bytes.extend(native_token.token_id().pack_to_vec());
+                write_u256(bytes, native_token.amount());
+            }
+        }
+        if !self.nfts.is_empty() {
+            write_varint(bytes, self.nfts.len() as u64);
+            for nft_id in &self.nfts {
+                bytes.extend(nft_id.as_slice());
+            }
+        }
+    }
+}
+
+/// A request to a contract of an ISC chain, encoded into the metadata feature of the output sent to the chain.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct IscRequest {
+    /// The called contract.
+    pub target_contract: Hname,
+    /// The called entry point of the contract.
+    pub entry_point: Hname,
+    /// The parameters of the call.
+    pub params: BTreeMap<Vec<u8>, Vec<u8>>,
+    /// The assets the contract may take.
+    pub allowance: Allowance,
+    /// The max gas the request may use, `u64::MAX` lets the chain apply its own limit.
+    pub gas_budget: u64,
+}
+
+impl IscRequest {
+    /// Creates a request calling `entry_point` of `target_contract`.
+    pub fn new(target_contract: Hname, entry_point: Hname) -> Self {
+        Self {
+            target_contract,
+            entry_point,
+            params: BTreeMap::new(),
+            allowance: Allowance::default(),
+            gas_budget: u64::MAX,
+        }
+    }
+
+    /// Creates a request depositing the sent assets, minus the gas fee, to the L2 account of the sender.
+    pub fn deposit() -> Self {
+        Self::new(Hname::new(ACCOUNTS_CONTRACT), Hname::new(DEPOSIT_ENTRY_POINT))
+    }
+
+    /// Creates a request moving the allowance to the L2 account of `agent_id`, e.g. an EVM account. The rest of the
+    /// sent assets, minus the gas fee, stay in the L2 account of the sender.
+    pub fn transfer_allowance_to(agent_id: impl Into<AgentId>, allowance: Allowance) -> Self {
+        Self::new(
+            Hname::new(ACCOUNTS_CONTRACT),
+            Hname::new(TRANSFER_ALLOWANCE_TO_ENTRY_POINT),
+        )
+        .with_param(AGENT_ID_PARAM, agent_id.into().pack_to_vec())
+        .with_allowance(allowance)
+    }
+
+    /// Creates a request depositing an NFT to the L2 account of `agent_id`. The request has to be sent with the NFT
+    /// output.
+    pub fn deposit_nft(agent_id: impl Into<AgentId>, nft_id: NftId) -> Self {
+        Self::transfer_allowance_to(agent_id, Allowance::new().add_nft(nft_id))
+    }
+
+    /// Sets a parameter of the call.
+    pub fn with_param(mut self, key: impl Into<Vec<u8>>, value: impl Into<Vec<u8>>) -> Self {
+        self.params.insert(key.into(), value.into());
+        self
+    }
+
+    /// Sets the assets the contract may take.
+    pub fn with_allowance(mut self, allowance: Allowance) -> Self {
+        self.allowance = allowance;
+        self
+    }
+
+    /// Sets the max gas the request may use.
+    pub fn with_gas_budget(mut self, gas_budget: u64) -> Self {
+        self.gas_budget = gas_budget;
+        self
+    }
+
+    /// Encodes the request like a chain expects it in the metadata feature.
+    pub fn pack_to_vec(&self) -> Vec<u8> {
+        let mut bytes = Vec::new();
+        // Requests sent from L1 have no sender contract
+        bytes.extend(0u32.to_le_bytes());
+        bytes.extend(self.target_contract.as_u32().to_le_bytes());
+        bytes.extend(self.entry_point.as_u32().to_le_bytes());
+        // The gas budget is incremented by one, so 0 can mean no limit
+        write_varint(&mut bytes, self.gas_budget.checked_add(1).unwrap_or(0));
+        write_varint(&mut bytes, self.params.len() as u64);
+        for (key, value) in &self.params {
+            write_bytes(&mut bytes, key);
+            write_bytes(&mut bytes, value);
+        }
+        self.allowance.pack(&mut bytes);
+        bytes
+    }
+
+    /// Returns the metadata feature to add to the output sent to the chain.
+    pub fn to_metadata_feature(&self) -> Result<MetadataFeature, Error> {
+        MetadataFeature::new(self.pack_to_vec())
+    }
+}
+
+// Writes an unsigned LEB128 integer.
+fn write_varint(bytes: &mut Vec<u8>, mut value: u64) {
+    loop {
+        let byte = (value & 0x7f) as u8;
+        value >>= 7;
+        if value == 0 {
+            bytes.push(byte);
+            return;
+        }
+        bytes.push(byte | 0x80);
+    }
+}
+
+// Writes bytes prefixed with their length.
+fn write_bytes(bytes: &mut Vec<u8>, value: &[u8]) {
+    write_varint(bytes, value.len() as u64);
+    bytes.extend(value);
+}
+
+// Writes the minimal big-endian bytes of an amount, prefixed with their length.
+fn write_u256(bytes: &mut Vec<u8>, value: U256) {
+    let mut be_bytes = [0; 32];
+    value.to_big_endian(&mut be_bytes);
+    let start = be_bytes.iter().position(|byte| *byte != 0).unwrap_or(be_bytes.len());
+    write_bytes(bytes, &be_bytes[start..]);
+}
@@ -0,0 +1,713 @@
+// Copyright 2024 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+//! An in-memory mock of the node API, to test client and wallet flows deterministically without a node.
+//!
+//! ```ignore
+//! let mock_client = MockClient::default();
+//! mock_client.add_output(
+//!     BasicOutputBuilder::new_with_amount(1_000_000)
+//!         .add_unlock_condition(AddressUnlockCondition::new(address))
+//!         .finish_output(mock_client.protocol_parameters().token_supply())?,
+//! );
+//! let client = Client::builder().with_mock_client(mock_client.clone()).finish().await?;
+//! ```
+
+use std::{
+    collections::{BTreeMap, HashMap},
+    str::FromStr,
+    sync::{Arc, Mutex, MutexGuard},
+};
+
+use packable::PackableExt;
+use serde_json::Value;
+
+use crate::{
+    client::{node_api::error::Error as NodeApiError, Error, NodeInfoWrapper, Result},
+    types::{
+        api::{
+            core::response::{
+                BaseTokenResponse, BlockMetadataResponse, ConfirmedMilestoneResponse, InfoResponse,
+                LatestMilestoneResponse, LedgerInclusionState, MetricsResponse, OutputWithMetadataResponse,
+                StatusResponse, SubmitBlockResponse, TipsResponse, UtxoChangesResponse,
+            },
+            plugins::indexer::OutputIdsResponse,
+        },
+        block::{
+            address::{Address, Bech32Address},
+            input::Input,
+            output::{Output, OutputId, OutputMetadata, OutputWithMetadata, RentStructure},
+            payload::{
+                transaction::{TransactionEssence, TransactionId},
+                Payload,
+            },
+            protocol::ProtocolParameters,
+            semantic::{semantic_validation, ConflictReason, ValidationContext},
+            Block, BlockDto, BlockId,
+        },
+        TryFromDto,
+    },
+    utils::unix_timestamp_now,
+};
+
+/// A mocked node, which answers the requests of a client from in-memory state instead of sending them to a node.
+///
+/// Every posted block is referenced by a new milestone right away, its transaction is semantically validated and
+/// applied to the ledger. Clones share the same state, so the ledger can be inspected and modified while a client
+/// uses it.
+#[derive(Clone, Debug, Default)]
+pub struct MockClient {
+    state: Arc<Mutex<MockState>>,
+}
+
+// Filter for the indexer query parameters
+type OutputFilter = Box<dyn Fn(&OutputId, &Output) -> bool>;
+
+#[derive(Debug)]
+struct MockState {
+    protocol_parameters: ProtocolParameters,
+    milestone_index: u32,
+    milestone_timestamp: u32,
+    outputs: BTreeMap<OutputId, OutputWithMetadata>,
+    blocks: HashMap<BlockId, (Block, BlockMetadataResponse)>,
+    included_blocks: HashMap<TransactionId, BlockId>,
+    tips: Vec<BlockId>,
+    // Used to create unique transaction ids for outputs that are added directly
+    added_outputs: u32,
+}
+
+impl Default for MockState {
+    fn default() -> Self {
+        // PANIC: these values are known to be correct, a min PoW score of 0 keeps the local PoW instant.
+        let protocol_parameters = ProtocolParameters::new(
+            crate::types::block::PROTOCOL_VERSION,
+            String::from("mock"),
+            "smr",
+            0,
+            15,
+            RentStructure::default(),
+            1_813_620_509_061_365,
+        )
+        .unwrap();
+        Self::new(protocol_parameters)
+    }
+}
+
+impl MockState {
+    fn new(protocol_parameters: ProtocolParameters) -> Self {
+        Self {
+            protocol_parameters,
+            milestone_index: 1,
+            milestone_timestamp: unix_timestamp_now().as_secs() as u32,
+            outputs: BTreeMap::new(),
+            blocks: HashMap::new(),
+            included_blocks: HashMap::new(),
+            tips: vec![BlockId::null()],
+            added_outputs: 0,
+        }
+    }
+
+    // Returns the output with the ledger index set to the current milestone
+    fn output_with_metadata(&self, output_id: &OutputId) -> Result<OutputWithMetadata> {
+        let output = self.outputs.get(output_id).ok_or_else(|| not_found(output_id))?;
+        let metadata = output.metadata();
+
+        Ok(OutputWithMetadata::new(
+            output.output().clone(),
+            OutputMetadata::new(
+                *metadata.block_id(),
+                *metadata.output_id(),
+                metadata.is_spent(),
+                metadata.milestone_index_spent(),
+                metadata.milestone_timestamp_spent(),
+                metadata.transaction_id_spent().copied(),
+                metadata.milestone_index_booked(),
+                metadata.milestone_timestamp_booked(),
+                self.milestone_index,
+            ),
+        ))
+    }
+
+    fn block(&self, block_id: &BlockId) -> Result<&(Block, BlockMetadataResponse)> {
+        self.blocks.get(block_id).ok_or_else(|| not_found(block_id))
+    }
+
+    fn included_block(&self, transaction_id: &TransactionId) -> Result<&(Block, BlockMetadataResponse)> {
+        self.block(
+            self.included_blocks
+                .get(transaction_id)
+                .ok_or_else(|| not_found(transaction_id))?,
+        )
+    }
+
+    fn info(&self) -> InfoResponse {
+        InfoResponse {
+            name: String::from("mock"),
+            version: env!("CARGO_PKG_VERSION").to_string(),
+            status: StatusResponse {
+                is_healthy: true,
+                latest_milestone: LatestMilestoneResponse {
+                    index: self.milestone_index,
+                    timestamp: Some(self.milestone_timestamp),
+                    milestone_id: None,
+                },
+                confirmed_milestone: ConfirmedMilestoneResponse {
+                    index: self.milestone_index,
+                    timestamp: Some(self.milestone_timestamp),
+                    milestone_id: None,
+                },
+                pruning_index: 0,
+            },
+            supported_protocol_versions: vec![self.protocol_parameters.protocol_version()],
+            protocol: self.protocol_parameters.clone(),
+            pending_protocol_parameters: Vec::new(),
+            base_token: BaseTokenResponse {
+                name: String::from("Mock"),
+                ticker_symbol: String::from("MOCK"),
+                unit: String::from("MOCK"),
+                subunit: None,
+                decimals: 6,
+                use_metric_prefix: false,
+            },
+            metrics: MetricsResponse {
+                blocks_per_second: 0.0,
+                referenced_blocks_per_second: 0.0,
+                referenced_rate: 0.0,
+            },
+            features: Vec::new(),
+        }
+    }
+
+    // Returns the ids of the unspent outputs matching the indexer route and query
+    fn output_ids(&self, route: &str, query: Option<&str>) -> Result<OutputIdsResponse> {
+        let mut segments = route.split('/');
+        let output_kind = segments.next().filter(|kind| !kind.is_empty());
+        let id = segments.next();
+
+        let mut filters = Vec::new();
+        let mut created_after = None;
+        // The cursor of the mock is the index of the first output id of a page
+        let mut cursor = 0;
+        let mut page_size = None;
+        for (key, value) in query.unwrap_or_default().split('&').filter_map(|p| p.split_once('=')) {
+            let bool_value = || value == "true";
+            let address = || {
+                Bech32Address::from_str(value)
+                    .map(|address| *address.inner())
+                    .map_err(Error::from)
+            };
+            let filter: OutputFilter = match key {
+                "address" => {
+                    let address = address()?;
+                    Box::new(move |_, output| {
+                        output
+                            .unlock_conditions()
+                            .and_then(|u| u.address())
+                            .is_some_and(|u| u.address() == &address)
+                    })
+                }
+                "aliasAddress" => {
+                    let address = address()?;
+                    Box::new(move |_, output| {
+                        output
+                            .unlock_conditions()
+                            .and_then(|u| u.immutable_alias_address())
+                            .is_some_and(|u| Address::Alias(*u.alias_address()) == address)
+                    })
+                }
+                "stateController" => {
+                    let address = address()?;
+                    Box::new(move |_, output| {
+                        output
+                            .unlock_conditions()
+                            .and_then(|u| u.state_controller_address())
+                            .is_some_and(|u| u.address() == &address)
+                    })
+                }
+                "governor" => {
+                    let address = address()?;
+                    Box::new(move |_, output| {
+                        output
+                            .unlock_conditions()
+                            .and_then(|u| u.governor_address())
+                            .is_some_and(|u| u.address() == &address)
+                    })
+                }
+                "unlockableByAddress" => {
+                    let address = address()?;
+                    Box::new(move |_, output| is_unlockable_by(output, &address))
+                }
+                "hasExpiration" => {
+                    let value = bool_value();
+                    Box::new(move |_, output| {
+                        output.unlock_conditions().and_then(|u| u.expiration()).is_some() == value
+                    })
+                }
+                "hasTimelock" => {
+                    let value = bool_value();
+                    Box::new(move |_, output| output.unlock_conditions().and_then(|u| u.timelock()).is_some() == value)
+                }
+                "hasStorageDepositReturn" => {
+                    let value = bool_value();
+                    Box::new(move |_, output| {
+                        output
+                            .unlock_conditions()
+                            .and_then(|u| u.storage_deposit_return())
+                            .is_some()
+                            == value
+                    })
+                }
+                "hasNativeTokens" => {
+                    let value = bool_value();
+                    Box::new(move |_, output| output.native_tokens().is_some_and(|n| !n.is_empty()) == value)
+                }
+                "createdAfter" => {
+                    created_after = Some(value.parse::<u32>().map_err(|_| {
+                        Error::Node(NodeApiError::ResponseError {
+                            code: 400,
+                            text: format!("invalid query parameter {key}"),
+                            url: route.to_string(),
+                        })
+                    })?);
+                    continue;
+                }
+                "cursor" | "pageSize" => {
+                    let value = value.parse::<usize>().map_err(|_| {
+                        Error::Node(NodeApiError::ResponseError {
+                            code: 400,
+                            text: format!("invalid query parameter {key}"),
+                            url: route.to_string(),
+                        })
+                    })?;
+                    if key == "cursor" {
+                        cursor = value;
+                    } else {
+                        page_size = Some(value.max(1));
+                    }
+                    continue;
+                }
+                _ => {
+                    return Err(Error::Node(NodeApiError::ResponseError {
+                        code: 400,
+                        text: format!("unsupported query parameter {key}"),
+                        url: route.to_string(),
+                    }));
+                }
+            };
+            filters.push(filter);
+        }
+
+        let mut items = self
+            .outputs
+            .iter()
+            .filter(|(_, output)| !output.metadata().is_spent())
+            .filter(|(_, output)| {
+                created_after.is_none_or(|after| output.metadata().milestone_timestamp_booked() > after)
+            })
+            .filter(|(output_id, output)| match (output_kind, output.output()) {
+                (None, _) => true,
+                (Some("basic"), Output::Basic(_)) => true,
+                (Some("alias"), Output::Alias(alias)) => {
+                    id.is_none_or(|id| alias.alias_id_non_null(output_id).to_string() == id)
+                }
+                (Some("foundry"), Output::Foundry(foundry)) => id.is_none_or(|id| foundry.id().to_string() == id),
+                (Some("nft"), Output::Nft(nft)) => id.is_none_or(|id| nft.nft_id_non_null(output_id).to_string() == id),
+                _ => false,
+            })
+            .filter(|(output_id, output)| filters.iter().all(|filter| filter(output_id, output.output())))
+            .map(|(output_id, _)| *output_id)
+            .collect::<Vec<_>>();
+
+        // A single chain output is requested by its id
+        if id.is_some() && items.is_empty() {
+            return Err(not_found(route));
+        }
+
+        let mut items = items.split_off(cursor.min(items.len()));
+        let next_cursor = page_size.filter(|page_size| items.len() > *page_size).map(|page_size| {
+            items.truncate(page_size);
+            (cursor + page_size).to_string()
+        });
+
+        Ok(OutputIdsResponse {
+            ledger_index: self.milestone_index,
+            cursor: next_cursor,
+            items,
+        })
+    }
+
+    // Returns the outputs created and consumed in the milestone with the given index
+    fn utxo_changes(&self, index: u32) -> UtxoChangesResponse {
+        let outputs = self.outputs.values().map(|output| output.metadata());
+        UtxoChangesResponse {
+            index,
+            created_outputs: outputs
+                .clone()
+                .filter(|metadata| metadata.milestone_index_booked() == index)
+                .map(|metadata| *metadata.output_id())
+                .collect(),
+            consumed_outputs: outputs
+                .filter(|metadata| metadata.milestone_index_spent() == Some(index))
+                .map(|metadata| *metadata.output_id())
+                .collect(),
+        }
+    }
+
+    // Returns the blocks referenced by the milestone with the given index, like the explorer API of INX-Chronicle
+    fn milestone_blocks(&self, index: u32) -> Value {
+        let blocks = self
+            .blocks
+            .iter()
+            .filter(|(_, (_, metadata))| metadata.referenced_by_milestone_index == Some(index))
+            .map(|(block_id, (block, _))| {
+                serde_json::json!({
+                    "blockId": block_id,
+                    "payloadKind": block.payload().map(Payload::kind),
+                })
+            })
+            .collect::<Vec<_>>();
+
+        serde_json::json!({ "blocks": blocks, "cursor": null })
+    }
+
+    // References the block in a new milestone and applies its transaction
+    fn post_block(&mut self, block: &Block) -> Result<BlockId> {
+        let block_id = block.id();
+        self.milestone_index += 1;
+
+        let mut metadata = BlockMetadataResponse {
+            block_id,
+            parents: block.parents().to_vec(),
+            is_solid: true,
+            referenced_by_milestone_index: Some(self.milestone_index),
+            milestone_index: None,
+            ledger_inclusion_state: Some(LedgerInclusionState::NoTransaction),
+            conflict_reason: None,
+            white_flag_index: None,
+            should_promote: None,
+            should_reattach: None,
+        };
+
+        if let Some(Payload::Transaction(transaction)) = block.payload() {
+            let transaction_id = transaction.id();
+            let conflict_reason = self.apply_transaction(&block_id, transaction)?;
+
+            if conflict_reason == ConflictReason::None {
+                metadata.ledger_inclusion_state = Some(LedgerInclusionState::Included);
+                self.included_blocks.insert(transaction_id, block_id);
+            } else {
+                log::debug!("[MOCK] transaction {transaction_id} is conflicting: {conflict_reason:?}");
+                metadata.ledger_inclusion_state = Some(LedgerInclusionState::Conflicting);
+                metadata.conflict_reason = Some(conflict_reason as u8);
+            }
+        }
+
+        self.blocks.insert(block_id, (block.clone(), metadata));
+        self.tips = vec![block_id];
+
+        Ok(block_id)
+    }
+
+    fn apply_transaction(
+        &mut self,
+        block_id: &BlockId,
+        transaction: &crate::types::block::payload::TransactionPayload,
+    ) -> Result<ConflictReason> {
+        let transaction_id = transaction.id();
+        let TransactionEssence::Regular(essence) = transaction.essence();
+
+        if essence.network_id() != self.protocol_parameters.network_id() {
+            return Ok(ConflictReason::SemanticValidationFailed);
+        }
+
+        let mut inputs = Vec::new();
+        for input in essence.inputs() {
+            let Input::Utxo(input) = input else {
+                return Ok(ConflictReason::SemanticValidationFailed);
+            };
+            match self.outputs.get(input.output_id()) {
+                Some(output) if output.metadata().is_spent() => return Ok(ConflictReason::InputUtxoAlreadySpent),
+                Some(output) => inputs.push((input.output_id(), output.output())),
+                None => return Ok(ConflictReason::InputUtxoNotFound),
+            }
+        }
+
+        let context = ValidationContext::new(
+            &transaction_id,
+            essence,
+            inputs.iter().map(|(id, input)| (*id, *input)),
+            transaction.unlocks(),
+            self.milestone_timestamp,
+        );
+        let conflict_reason = semantic_validation(context, &inputs, transaction.unlocks())?;
+        if conflict_reason != ConflictReason::None {
+            return Ok(conflict_reason);
+        }
+
+        let input_ids = inputs.iter().map(|(id, _)| **id).collect::<Vec<_>>();
+        for output_id in input_ids {
+            let output = self.outputs.get_mut(&output_id).expect("input was checked to exist");
+            let metadata = output.metadata();
+            output.metadata = OutputMetadata::new(
+                *metadata.block_id(),
+                output_id,
+                true,
+                Some(self.milestone_index),
+                Some(self.milestone_timestamp),
+                Some(transaction_id),
+                metadata.milestone_index_booked(),
+                metadata.milestone_timestamp_booked(),
+                self.milestone_index,
+            );
+        }
+
+        for (index, output) in essence.outputs().iter().enumerate() {
+            let output_id = OutputId::new(transaction_id, index as u16)?;
+            self.outputs.insert(
+                output_id,
+                OutputWithMetadata::new(
+                    output.clone(),
+                    OutputMetadata::new(
+                        *block_id,
+                        output_id,
+                        false,
+                        None,
+                        None,
+                        None,
+                        self.milestone_index,
+                        self.milestone_timestamp,
+                        self.milestone_index,
+                    ),
+                ),
+            );
+        }
+
+        Ok(ConflictReason::None)
+    }
+}
+
+impl MockClient {
+    /// Creates a new [`MockClient`] with the given protocol parameters and an empty ledger.
+    pub fn new(protocol_parameters: ProtocolParameters) -> Self {
+        Self {
+            state: Arc::new(Mutex::new(MockState::new(protocol_parameters))),
+        }
+    }
+
+    fn state(&self) -> MutexGuard<'_, MockState> {
+        // The state is never left inconsistent by a panic, so a poisoned lock can still be used.
+        self.state.lock().unwrap_or_else(|e| e.into_inner())
+    }
+
+    /// Returns the protocol parameters of the mocked node.
+    pub fn protocol_parameters(&self) -> ProtocolParameters {
+        self.state().protocol_parameters.clone()
+    }
+
+    /// Returns the index of the latest milestone.
+    pub fn milestone_index(&self) -> u32 {
+        self.state().milestone_index
+    }
+
+    /// Returns the timestamp of the latest milestone.
+    pub fn milestone_timestamp(&self) -> u32 {
+        self.state().milestone_timestamp
+    }
+
+    /// Sets the latest milestone, the timestamp is used for the semantic validation of the following transactions.
+    pub fn set_milestone(&self, index: u32, timestamp: u32) {
+        let mut state = self.state();
+        state.milestone_index = index;
+        state.milestone_timestamp = timestamp;
+    }
+
+    /// Adds an unspent output to the ledger, as if it was created by a transaction in the latest milestone.
+    pub fn add_output(&self, output: Output) -> OutputId {
+        let mut state = self.state();
+        state.added_outputs += 1;
+        let mut transaction_id = [0u8; TransactionId::LENGTH];
+        transaction_id[..4].copy_from_slice(&state.added_outputs.to_le_bytes());
+        // PANIC: 0 is a valid output index.
+        let output_id = OutputId::new(TransactionId::new(transaction_id), 0).unwrap();
+        let metadata = OutputMetadata::new(
+            BlockId::null(),
+            output_id,
+            false,
+            None,
+            None,
+            None,
+            state.milestone_index,
+            state.milestone_timestamp,
+            state.milestone_index,
+        );
+        state
+            .outputs
+            .insert(output_id, OutputWithMetadata::new(output, metadata));
+        output_id
+    }
+
+    /// Returns an output of the ledger.
+    pub fn output(&self, output_id: &OutputId) -> Option<OutputWithMetadata> {
+        self.state().output_with_metadata(output_id).ok()
+    }
+
+    /// Returns all unspent outputs of the ledger.
+    pub fn unspent_outputs(&self) -> Vec<OutputWithMetadata> {
+        let state = self.state();
+        state
+            .outputs
+            .iter()
+            .filter(|(_, output)| !output.metadata().is_spent())
+            .filter_map(|(output_id, _)| state.output_with_metadata(output_id).ok())
+            .collect()
+    }
+
+    /// Returns a posted block with its metadata.
+    pub fn block(&self, block_id: &BlockId) -> Option<(Block, BlockMetadataResponse)> {
+        self.state().block(block_id).ok().cloned()
+    }
+
+    pub(crate) fn get_request(&self, path: &str, query: Option<&str>) -> Result<Value> {
+        let state = self.state();
+
+        let value = if path == super::node_api::core::routes::INFO_PATH {
+            serde_json::to_value(NodeInfoWrapper {
+                node_info: state.info(),
+                url: String::from("mock"),
+            })?
+        } else if path == "api/core/v2/tips" {
+            serde_json::to_value(TipsResponse {
+                tips: state.tips.clone(),
+            })?
+        } else if let Some(route) = path.strip_prefix("api/core/v2/blocks/") {
+            match route.split_once('/') {
+                Some((block_id, "metadata")) => serde_json::to_value(&state.block(&parse_id(block_id)?)?.1)?,
+                None => serde_json::to_value(BlockDto::from(&state.block(&parse_id(route)?)?.0))?,
+                _ => return Err(not_found(path)),
+            }
+        } else if let Some(route) = path.strip_prefix("api/core/v2/outputs/") {
+            match route.split_once('/') {
+                Some((output_id, "metadata")) => {
+                    serde_json::to_value(state.output_with_metadata(&parse_id(output_id)?)?.metadata())?
+                }
+                None => serde_json::to_value(OutputWithMetadataResponse::from(
+                    state.output_with_metadata(&parse_id(route)?)?,
+                ))?,
+                _ => return Err(not_found(path)),
+            }
+        } else if let Some(route) = path.strip_prefix("api/core/v2/transactions/") {
+            match route.split_once('/') {
+                Some((transaction_id, "included-block")) => {
+                    serde_json::to_value(BlockDto::from(&state.included_block(&parse_id(transaction_id)?)?.0))?
+                }
+                Some((transaction_id, "included-block/metadata")) => {
+                    serde_json::to_value(&state.included_block(&parse_id(transaction_id)?)?.1)?
+                }
+                _ => return Err(not_found(path)),
+            }
+        } else if let Some(route) = path.strip_prefix("api/core/v2/milestones/by-index/") {
+            match route.split_once('/') {
+                Some((index, "utxo-changes")) => {
+                    let index = index.parse::<u32>().map_err(|_| not_found(path))?;
+                    if index > state.milestone_index {
+                        return Err(not_found(path));
+                    }
+                    serde_json::to_value(state.utxo_changes(index))?
+                }
+                _ => return Err(not_found(path)),
+            }
+        } else if let Some(route) = path.strip_prefix("api/explorer/v2/milestones/by-index/") {
+            match route.split_once('/') {
+                Some((index, "blocks")) => {
+                    let index = index.parse::<u32>().map_err(|_| not_found(path))?;
+                    if index > state.milestone_index {
+                        return Err(not_found(path));
+                    }
+                    serde_json::to_value(state.milestone_blocks(index))?
+                }
+                _ => return Err(not_found(path)),
+            }
+        } else if path == "api/indexer/v1/outputs" {
+            serde_json::to_value(state.output_ids("", query)?)?
+        } else if let Some(route) = path.strip_prefix("api/indexer/v1/outputs/") {
+            serde_json::to_value(state.output_ids(route, query)?)?
+        } else {
+            return Err(not_found(path));
+        };
+
+        Ok(value)
+    }
+
+    pub(crate) fn get_request_bytes(&self, path: &str) -> Result<Vec<u8>> {
+        let state = self.state();
+
+        if let Some(block_id) = path.strip_prefix("api/core/v2/blocks/") {
+            Ok(state.block(&parse_id(block_id)?)?.0.pack_to_vec())
+        } else if let Some(output_id) = path.strip_prefix("api/core/v2/outputs/") {
+            Ok(state
+                .output_with_metadata(&parse_id(output_id)?)?
+                .output()
+                .pack_to_vec())
+        } else if let Some(transaction_id) = path
+            .strip_prefix("api/core/v2/transactions/")
+            .and_then(|route| route.strip_suffix("/included-block"))
+        {
+            Ok(state.included_block(&parse_id(transaction_id)?)?.0.pack_to_vec())
+        } else {
+            Err(not_found(path))
+        }
+    }
+
+    pub(crate) fn post_request_json(&self, path: &str, json: Value) -> Result<Value> {
+        if path != "api/core/v2/blocks" {
+            return Err(not_found(path));
+        }
+        let protocol_parameters = self.protocol_parameters();
+        let block = Block::try_from_dto_with_params(serde_json::from_value::<BlockDto>(json)?, protocol_parameters)?;
+
+        Ok(serde_json::to_value(SubmitBlockResponse {
+            block_id: self.post_block(&block)?,
+        })?)
+    }
+
+    pub(crate) fn post_block(&self, block: &Block) -> Result<BlockId> {
+        self.state().post_block(block)
+    }
+}
+
+// Only used to compare client options, clones of a mock client are equal.
+impl PartialEq for MockClient {
+    fn eq(&self, other: &Self) -> bool {
+        Arc::ptr_eq(&self.state, &other.state)
+    }
+}
+
+impl Eq for MockClient {}
+
+fn is_unlockable_by(output: &Output, address: &Address) -> bool {
+    let Some(unlock_conditions) = output.unlock_conditions() else {
+        return false;
+    };
+
+    unlock_conditions.address().is_some_and(|u| u.address() == address)
+        || unlock_conditions
+            .expiration()
+            .is_some_and(|u| u.return_address() == address)
+        || unlock_conditions
+            .state_controller_address()
+            .is_some_and(|u| u.address() == address)
+        || unlock_conditions
+            .governor_address()
+            .is_some_and(|u| u.address() == address)
+        || unlock_conditions
+            .immutable_alias_address()
+            .is_some_and(|u| &Address::Alias(*u.alias_address()) == address)
+}
+
+fn parse_id<T: FromStr>(id: &str) -> Result<T> {
+    id.parse().map_err(|_| not_found(id))
+}
+
+fn not_found(id: impl ToString) -> Error {
+    Error::Node(NodeApiError::NotFound(id.to_string()))
+}
@@ -37,9 +37,17 @@ macro_rules! lazy_static {
 
 pub mod api;
 pub mod builder;
+pub mod cache;
 pub mod constants;
 pub mod core;
 pub mod error;
+pub mod faucet;
+#[cfg(feature = "isc")]
+#[cfg_attr(docsrs, doc(cfg(feature = "isc")))]
+pub mod isc;
+#[cfg(feature = "testing")]
+#[cfg_attr(docsrs, doc(cfg(feature = "testing")))]
+pub mod mock;
 pub mod node_api;
 pub mod node_manager;
 #[cfg(not(target_family = "wasm"))]
@@ -49,15 +57,21 @@ pub mod storage;
 #[cfg(feature = "stronghold")]
 #[cfg_attr(docsrs, doc(cfg(feature = "stronghold")))]
 pub mod stronghold;
+#[cfg(all(feature = "testing", not(target_family = "wasm")))]
+#[cfg_attr(docsrs, doc(cfg(feature = "testing")))]
+pub mod testing;
 pub mod utils;
 
 #[cfg(feature = "mqtt")]
 pub use self::node_api::mqtt;
 pub use self::{
-    builder::{ClientBuilder, NetworkInfo},
+    builder::{ClientBuilder, NetworkInfo, ProtocolParametersOverrides},
+    cache::CacheOptions,
     core::*,
     error::*,
+    faucet::FaucetClient,
     node_api::core::routes::NodeInfoWrapper,
+    node_manager::request_options::RequestOptions,
     utils::*,
 };
 
@@ -11,31 +11,53 @@ use crate::{
 };
 
 impl Client {
-    /// Request outputs by their output ID in parallel
+    /// Request outputs by their output ID in parallel, in batches of at most
+    /// [`max_parallel_api_requests`](crate::client::ClientBuilder::with_max_parallel_api_requests). The outputs are
+    /// returned in the order of the IDs.
     pub async fn get_outputs(&self, output_ids: &[OutputId]) -> Result<Vec<OutputWithMetadata>> {
-        futures::future::try_join_all(output_ids.iter().map(|id| self.get_output(id))).await
+        let mut outputs = Vec::with_capacity(output_ids.len());
+        for batch in output_ids.chunks(self.output_batch_size(output_ids).await) {
+            outputs.extend(futures::future::try_join_all(batch.iter().map(|id| self.get_output(id))).await?);
+        }
+        Ok(outputs)
     }
 
     /// Request outputs by their output ID in parallel, ignoring failed requests
     /// Useful to get data about spent outputs, that might not be pruned yet
     pub async fn get_outputs_ignore_errors(&self, output_ids: &[OutputId]) -> Result<Vec<OutputWithMetadata>> {
-        Ok(
-            futures::future::join_all(output_ids.iter().map(|id| self.get_output(id)))
-                .await
-                .into_iter()
-                .filter_map(Result::ok)
-                .collect(),
-        )
+        let mut outputs = Vec::with_capacity(output_ids.len());
+        for batch in output_ids.chunks(self.output_batch_size(output_ids).await) {
+            outputs.extend(
+                futures::future::join_all(batch.iter().map(|id| self.get_output(id)))
+                    .await
+                    .into_iter()
+                    .filter_map(Result::ok),
+            );
+        }
+        Ok(outputs)
     }
 
     /// Requests metadata for outputs by their output ID in parallel, ignoring failed requests
     pub async fn get_outputs_metadata_ignore_errors(&self, output_ids: &[OutputId]) -> Result<Vec<OutputMetadata>> {
-        Ok(
-            futures::future::join_all(output_ids.iter().map(|id| self.get_output_metadata(id)))
-                .await
-                .into_iter()
-                .filter_map(Result::ok)
-                .collect(),
-        )
+        let mut outputs_metadata = Vec::with_capacity(output_ids.len());
+        for batch in output_ids.chunks(self.output_batch_size(output_ids).await) {
+            outputs_metadata.extend(
+                futures::future::join_all(batch.iter().map(|id| self.get_output_metadata(id)))
+                    .await
+                    .into_iter()
+                    .filter_map(Result::ok),
+            );
+        }
+        Ok(outputs_metadata)
+    }
+
+    // Large requests are split into batches, so they don't queue up thousands of requests at once.
+    async fn output_batch_size(&self, output_ids: &[OutputId]) -> usize {
+        #[cfg(not(target_family = "wasm"))]
+        let batch_size = self.request_pool.size().await;
+        #[cfg(target_family = "wasm")]
+        let batch_size = output_ids.len();
+        // Chunks can't be empty
+        batch_size.min(output_ids.len()).max(1)
     }
 }
@@ -10,7 +10,11 @@ use url::Url;
 use crate::{
     client::{
         constants::{DEFAULT_API_TIMEOUT, DEFAULT_USER_AGENT},
-        node_manager::node::{Node, NodeAuth},
+        node_manager::{
+            http_client::HttpClient,
+            node::{Node, NodeAuth},
+            request_options::RequestOptions,
+        },
         Client, ClientInner, Error, Result,
     },
     types::{
@@ -53,12 +57,14 @@ impl ClientInner {
 
         let mut url = Url::parse(url)?;
         url.set_path(path);
-        let status = crate::client::node_manager::http_client::HttpClient::new(DEFAULT_USER_AGENT.to_string())
+        let http_client = self.node_manager.read().await.http_client.clone();
+        let status = http_client
             .get(
                 Node {
                     url,
                     auth: None,
                     disabled: false,
+                    proxy: None,
                 },
                 DEFAULT_API_TIMEOUT,
             )
@@ -102,6 +108,22 @@ impl ClientInner {
     /// Returns the BlockId of the submitted block.
     /// POST JSON to /api/core/v2/blocks
     pub async fn post_block(&self, block: &Block) -> Result<BlockId> {
+        self.post_block_with_options(block, &RequestOptions::default()).await
+    }
+
+    /// Returns the BlockId of the submitted block, which is sent to the node of the options instead of the node pool
+    /// if one is set.
+    /// POST JSON to /api/core/v2/blocks
+    pub async fn post_block_with_options(&self, block: &Block, options: &RequestOptions) -> Result<BlockId> {
+        self.post_with_issuer_node(options, |options| self.post_block_to(block, options))
+            .await
+    }
+
+    async fn post_block_to(&self, block: &Block, options: RequestOptions) -> Result<BlockId> {
+        #[cfg(feature = "testing")]
+        if let Some(mock_client) = &self.mock_client {
+            return mock_client.post_block(block);
+        }
         let path = "api/core/v2/blocks";
         let local_pow = self.get_local_pow().await;
         let timeout = if local_pow {
@@ -116,7 +138,13 @@ impl ClientInner {
             .node_manager
             .read()
             .await
-            .post_request_json::<SubmitBlockResponse>(path, timeout, serde_json::to_value(block_dto)?, local_pow)
+            .post_request_json::<SubmitBlockResponse>(
+                path,
+                timeout,
+                serde_json::to_value(block_dto)?,
+                local_pow,
+                &options,
+            )
             .await
         {
             Ok(res) => res,
@@ -146,7 +174,7 @@ impl ClientInner {
                 self.node_manager
                     .read()
                     .await
-                    .post_request_json(path, timeout, serde_json::to_value(block_dto)?, true)
+                    .post_request_json(path, timeout, serde_json::to_value(block_dto)?, true, &options)
                     .await?
             }
             Err(e) => return Err(e),
@@ -158,6 +186,22 @@ impl ClientInner {
     /// Returns the BlockId of the submitted block.
     /// POST /api/core/v2/blocks
     pub async fn post_block_raw(&self, block: &Block) -> Result<BlockId> {
+        self.post_block_raw_with_options(block, &RequestOptions::default()).await
+    }
+
+    /// Returns the BlockId of the submitted block, which is sent to the node of the options instead of the node pool
+    /// if one is set.
+    /// POST /api/core/v2/blocks
+    pub async fn post_block_raw_with_options(&self, block: &Block, options: &RequestOptions) -> Result<BlockId> {
+        self.post_with_issuer_node(options, |options| self.post_block_raw_to(block, options))
+            .await
+    }
+
+    async fn post_block_raw_to(&self, block: &Block, options: RequestOptions) -> Result<BlockId> {
+        #[cfg(feature = "testing")]
+        if let Some(mock_client) = &self.mock_client {
+            return mock_client.post_block(block);
+        }
         let path = "api/core/v2/blocks";
         let local_pow = self.get_local_pow().await;
         let timeout = if local_pow {
@@ -171,7 +215,7 @@ impl ClientInner {
             .node_manager
             .read()
             .await
-            .post_request_bytes::<SubmitBlockResponse>(path, timeout, &block.pack_to_vec(), local_pow)
+            .post_request_bytes::<SubmitBlockResponse>(path, timeout, &block.pack_to_vec(), local_pow, &options)
             .await
         {
             Ok(res) => res,
@@ -198,7 +242,7 @@ impl ClientInner {
                 self.node_manager
                     .read()
                     .await
-                    .post_request_bytes(path, timeout, &block_with_local_pow.pack_to_vec(), true)
+                    .post_request_bytes(path, timeout, &block_with_local_pow.pack_to_vec(), true, &options)
                     .await?
             }
             Err(e) => return Err(e),
@@ -420,36 +464,39 @@ impl ClientInner {
 impl Client {
     /// GET /api/core/v2/info endpoint
     pub async fn get_node_info(url: &str, auth: Option<NodeAuth>) -> Result<InfoResponse> {
-        let mut url = crate::client::node_manager::builder::validate_url(Url::parse(url)?)?;
-        if let Some(auth) = &auth {
-            if let Some((name, password)) = &auth.basic_auth_name_pwd {
-                url.set_username(name)
-                    .map_err(|_| crate::client::Error::UrlAuth("username"))?;
-                url.set_password(Some(password))
-                    .map_err(|_| crate::client::Error::UrlAuth("password"))?;
-            }
-        }
-        let path = "api/core/v2/info";
-        if url.path().ends_with('/') {
-            url.set_path(&format!("{}{}", url.path(), path));
-        } else {
-            url.set_path(&format!("{}/{}", url.path(), path));
-        }
+        let node = Node {
+            url: Url::parse(url)?,
+            auth,
+            disabled: false,
+            proxy: None,
+        };
+        get_node_info_with(&HttpClient::new(DEFAULT_USER_AGENT.to_string()), node).await
+    }
+}
 
-        let resp: InfoResponse =
-            crate::client::node_manager::http_client::HttpClient::new(DEFAULT_USER_AGENT.to_string())
-                .get(
-                    Node {
-                        url,
-                        auth,
-                        disabled: false,
-                    },
-                    DEFAULT_API_TIMEOUT,
-                )
-                .await?
-                .into_json()
-                .await?;
+impl ClientInner {
+    // Requests the node info with the http client of the node manager, so its transport and proxies are used
+    pub(crate) async fn get_node_info_of(&self, node: &Node) -> Result<InfoResponse> {
+        let http_client = self.node_manager.read().await.http_client.clone();
+        get_node_info_with(&http_client, node.clone()).await
+    }
+}
 
-        Ok(resp)
+async fn get_node_info_with(http_client: &HttpClient, mut node: Node) -> Result<InfoResponse> {
+    let mut url = crate::client::node_manager::builder::validate_url(node.url)?;
+    let path = "api/core/v2/info";
+    if url.path().ends_with('/') {
+        url.set_path(&format!("{}{}", url.path(), path));
+    } else {
+        url.set_path(&format!("{}/{}", url.path(), path));
     }
+    node.url = url;
+
+    let resp: InfoResponse = http_client
+        .get(node, DEFAULT_API_TIMEOUT)
+        .await?
+        .into_json()
+        .await?;
+
+    Ok(resp)
 }
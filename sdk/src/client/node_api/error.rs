@@ -26,6 +26,9 @@ pub enum Error {
     /// None of our nodes have remote Pow enabled
     #[error("No node available for remote Pow")]
     UnavailablePow,
+    /// A custom transport couldn't send a request or receive its response
+    #[error("Transport error: {0}")]
+    Transport(String),
     /// We made a call to the node but the protocol was unsupported
     #[error("Call to {0} is not supported on this node")]
     NotSupported(String),
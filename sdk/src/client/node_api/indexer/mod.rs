@@ -8,8 +8,8 @@ pub mod routes;
 
 pub(crate) use self::query_parameters::{QueryParameter, QueryParameters};
 use crate::{
-    client::{ClientInner, Result},
-    types::api::plugins::indexer::OutputIdsResponse,
+    client::{ClientInner, Error, RequestOptions, Result},
+    types::api::plugins::indexer::{OutputIdsResponse, RawOutputIdsResponse},
 };
 
 impl ClientInner {
@@ -18,42 +18,118 @@ impl ClientInner {
     pub async fn get_output_ids(
         &self,
         route: &str,
-        mut query_parameters: QueryParameters,
+        query_parameters: QueryParameters,
+        need_quorum: bool,
+        prefer_permanode: bool,
+    ) -> Result<OutputIdsResponse> {
+        self.get_output_ids_with_options(
+            route,
+            query_parameters,
+            need_quorum,
+            prefer_permanode,
+            &RequestOptions::default(),
+        )
+        .await
+    }
+
+    /// Get all output ids for a provided URL route and query parameters, like [`Self::get_output_ids()`], but sends the
+    /// requests to the node of the options instead of the node pool if one is set.
+    pub async fn get_output_ids_with_options(
+        &self,
+        route: &str,
+        query_parameters: QueryParameters,
         need_quorum: bool,
         prefer_permanode: bool,
+        options: &RequestOptions,
     ) -> Result<OutputIdsResponse> {
+        // Return early with only a single page if a `QueryParameter::Cursor(_)` is provided.
+        if query_parameters.contains(QueryParameter::Cursor(String::new()).kind()) {
+            let output_ids_response = self
+                .get_request_with_options::<RawOutputIdsResponse>(
+                    route,
+                    query_parameters.to_query_string().as_deref(),
+                    need_quorum,
+                    prefer_permanode,
+                    options,
+                )
+                .await?;
+            return Ok(output_ids_response.try_into()?);
+        }
+
         let mut merged_output_ids_response = OutputIdsResponse {
             ledger_index: 0,
             cursor: None,
             items: Vec::new(),
         };
 
-        // Return early with only a single page if a `QueryParameter::Cursor(_)` is provided.
-        let return_early = query_parameters.contains(QueryParameter::Cursor(String::new()).kind());
+        // The output ids are parsed into the merged response right away, so the pages aren't kept in memory
+        self.for_each_output_ids_page_with_options(
+            route,
+            query_parameters,
+            need_quorum,
+            prefer_permanode,
+            options,
+            |output_ids_response| {
+                merged_output_ids_response.ledger_index = output_ids_response.ledger_index;
+                output_ids_response
+                    .for_each_output_id(|output_id| merged_output_ids_response.items.push(output_id))
+                    .map_err(Error::from)
+            },
+        )
+        .await?;
 
-        while let Some(cursor) = {
+        Ok(merged_output_ids_response)
+    }
+
+    /// Calls `f` with every page of output ids for a provided URL route and query parameters, without parsing the
+    /// output ids, so only a single page is kept in memory at a time. Starts at the page of a provided
+    /// `QueryParameter::Cursor(_)`. Stops at the first error returned by `f`.
+    pub async fn for_each_output_ids_page<E: From<Error>>(
+        &self,
+        route: &str,
+        query_parameters: QueryParameters,
+        need_quorum: bool,
+        prefer_permanode: bool,
+        f: impl FnMut(RawOutputIdsResponse) -> core::result::Result<(), E>,
+    ) -> core::result::Result<(), E> {
+        self.for_each_output_ids_page_with_options(
+            route,
+            query_parameters,
+            need_quorum,
+            prefer_permanode,
+            &RequestOptions::default(),
+            f,
+        )
+        .await
+    }
+
+    async fn for_each_output_ids_page_with_options<E: From<Error>>(
+        &self,
+        route: &str,
+        mut query_parameters: QueryParameters,
+        need_quorum: bool,
+        prefer_permanode: bool,
+        options: &RequestOptions,
+        mut f: impl FnMut(RawOutputIdsResponse) -> core::result::Result<(), E>,
+    ) -> core::result::Result<(), E> {
+        loop {
             let output_ids_response = self
-                .get_request::<OutputIdsResponse>(
+                .get_request_with_options::<RawOutputIdsResponse>(
                     route,
                     query_parameters.to_query_string().as_deref(),
                     need_quorum,
                     prefer_permanode,
+                    options,
                 )
                 .await?;
+            let cursor = output_ids_response.cursor.clone();
 
-            if return_early {
-                return Ok(output_ids_response);
-            }
+            f(output_ids_response)?;
 
-            merged_output_ids_response.ledger_index = output_ids_response.ledger_index;
-            merged_output_ids_response.cursor = output_ids_response.cursor;
-            merged_output_ids_response.items.extend(output_ids_response.items);
-
-            &merged_output_ids_response.cursor
-        } {
-            query_parameters.replace(QueryParameter::Cursor(cursor.to_string()));
+            match cursor {
+                Some(cursor) => query_parameters.replace(QueryParameter::Cursor(cursor)),
+                None => return Ok(()),
+            }
         }
-
-        Ok(merged_output_ids_response)
     }
 }
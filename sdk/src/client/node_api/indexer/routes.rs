@@ -13,10 +13,10 @@ use crate::{
             },
             QueryParameters,
         },
-        ClientInner, Error, Result,
+        ClientInner, Error, RequestOptions, Result,
     },
     types::{
-        api::plugins::indexer::OutputIdsResponse,
+        api::plugins::indexer::{OutputIdsResponse, RawOutputIdsResponse},
         block::output::{AliasId, FoundryId, NftId, OutputId},
     },
 };
@@ -41,6 +41,55 @@ impl ClientInner {
         self.get_output_ids(route, query_parameters, true, false).await
     }
 
+    /// Get basic, alias, nft and foundry outputs filtered by the given parameters, like [`Self::output_ids()`], but
+    /// sends the requests to the node of the options instead of the node pool if one is set.
+    /// api/indexer/v1/outputs
+    pub async fn output_ids_with_options(
+        &self,
+        query_parameters: impl Into<Vec<QueryParameter>> + Send,
+        options: &RequestOptions,
+    ) -> Result<OutputIdsResponse> {
+        let route = "api/indexer/v1/outputs";
+
+        let query_parameters = verify_query_parameters_outputs(query_parameters.into())?;
+
+        self.get_output_ids_with_options(route, query_parameters, true, false, options)
+            .await
+    }
+
+    /// Get a single page of basic, alias, nft and foundry outputs filtered by the given parameters, like
+    /// [`Self::output_ids()`], but without parsing the output ids. The next page can be requested with the cursor of
+    /// the response in a `QueryParameter::Cursor(_)`.
+    /// api/indexer/v1/outputs
+    pub async fn output_ids_raw(
+        &self,
+        query_parameters: impl Into<Vec<QueryParameter>> + Send,
+    ) -> Result<RawOutputIdsResponse> {
+        let route = "api/indexer/v1/outputs";
+
+        let query_parameters = verify_query_parameters_outputs(query_parameters.into())?;
+
+        self.get_request(route, query_parameters.to_query_string().as_deref(), true, false)
+            .await
+    }
+
+    /// Calls `f` with every page of basic, alias, nft and foundry outputs filtered by the given parameters, without
+    /// parsing the output ids, so only a single page is kept in memory at a time. Stops at the first error returned by
+    /// `f`.
+    /// api/indexer/v1/outputs
+    pub async fn for_each_output_ids_raw<E: From<Error>>(
+        &self,
+        query_parameters: impl Into<Vec<QueryParameter>> + Send,
+        f: impl FnMut(RawOutputIdsResponse) -> core::result::Result<(), E>,
+    ) -> core::result::Result<(), E> {
+        let route = "api/indexer/v1/outputs";
+
+        let query_parameters = verify_query_parameters_outputs(query_parameters.into())?;
+
+        self.for_each_output_ids_page(route, query_parameters, true, false, f)
+            .await
+    }
+
     /// Get basic outputs filtered by the given parameters.
     /// GET with query parameter returns all outputIDs that fit these filter criteria.
     /// Query parameters: "address", "hasStorageDepositReturn", "storageDepositReturnAddress",
@@ -59,6 +108,22 @@ impl ClientInner {
         self.get_output_ids(route, query_parameters, true, false).await
     }
 
+    /// Get basic outputs filtered by the given parameters, like [`Self::basic_output_ids()`], but sends the requests to
+    /// the node of the options instead of the node pool if one is set.
+    /// api/indexer/v1/outputs/basic
+    pub async fn basic_output_ids_with_options(
+        &self,
+        query_parameters: impl Into<Vec<QueryParameter>> + Send,
+        options: &RequestOptions,
+    ) -> Result<OutputIdsResponse> {
+        let route = "api/indexer/v1/outputs/basic";
+
+        let query_parameters = verify_query_parameters_basic_outputs(query_parameters.into())?;
+
+        self.get_output_ids_with_options(route, query_parameters, true, false, options)
+            .await
+    }
+
     /// Get alias outputs filtered by the given parameters.
     /// GET with query parameter returns all outputIDs that fit these filter criteria.
     /// Query parameters: "stateController", "governor", "issuer", "sender", "createdBefore", "createdAfter"
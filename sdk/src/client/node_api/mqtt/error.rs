@@ -16,4 +16,7 @@ pub enum Error {
     /// Invalid topic.
     #[error("invalid topic {0}")]
     InvalidTopic(String),
+    /// Invalid payload.
+    #[error("invalid payload of topic {topic}: {error}")]
+    InvalidPayload { topic: String, error: String },
 }
@@ -10,18 +10,11 @@ use std::{sync::Arc, time::Instant};
 
 use crypto::utils;
 use log::warn;
-use packable::PackableExt;
 use rumqttc::{AsyncClient, Event, EventLoop, Incoming, MqttOptions, NetworkOptions, QoS, SubscribeFilter, Transport};
 use tokio::sync::watch::Receiver as WatchReceiver;
 
 pub use self::{error::Error, types::*};
-use crate::{
-    client::{Client, ClientInner},
-    types::block::{
-        payload::{milestone::ReceiptMilestoneOption, Payload},
-        Block,
-    },
-};
+use crate::client::{Client, ClientInner};
 
 impl Client {
     /// Returns a handle to the MQTT topics manager.
@@ -193,74 +186,19 @@ fn poll_mqtt(client: &Client, mut event_loop: EventLoop) {
                             let mqtt_topic_handlers = client.mqtt.topic_handlers.read().await;
 
                             if let Some(handlers) = mqtt_topic_handlers.get(&Topic::new_unchecked(&p.topic)) {
-                                let event = {
-                                    if p.topic.contains("blocks") || p.topic.contains("included-block") {
-                                        let payload = &*p.payload;
-                                        let protocol_parameters = &client.network_info.read().await.protocol_parameters;
-
-                                        match Block::unpack_verified(payload, protocol_parameters) {
-                                            Ok(block) => Ok(TopicEvent {
-                                                topic: p.topic.clone(),
-                                                payload: MqttPayload::Block((&block).into()),
-                                            }),
-                                            Err(e) => {
-                                                warn!("Block unpacking failed: {:?}", e);
-                                                Err(())
-                                            }
+                                let event = TopicEvent::decode(
+                                    p.topic.clone(),
+                                    &p.payload,
+                                    &client.network_info.read().await.protocol_parameters,
+                                );
+                                match event {
+                                    Ok(event) => {
+                                        for handler in handlers {
+                                            handler(&event);
                                         }
-                                    } else if p.topic.contains("milestones") {
-                                        let payload = &*p.payload;
-                                        let protocol_parameters = &client.network_info.read().await.protocol_parameters;
-
-                                        match Payload::unpack_verified(payload, protocol_parameters) {
-                                            Ok(Payload::Milestone(milestone)) => Ok(TopicEvent {
-                                                topic: p.topic.clone(),
-                                                payload: MqttPayload::MilestonePayload(milestone.as_ref().into()),
-                                            }),
-                                            Ok(p) => {
-                                                warn!(
-                                                    "'milestone' topic returned non-milestone payload, kind: {:?}",
-                                                    p.kind()
-                                                );
-                                                Err(())
-                                            }
-                                            Err(e) => {
-                                                warn!("MilestonePayload unpacking failed: {:?}", e);
-                                                Err(())
-                                            }
-                                        }
-                                    } else if p.topic.contains("receipts") {
-                                        let payload = &*p.payload;
-                                        let protocol_parameters = &client.network_info.read().await.protocol_parameters;
-
-                                        match ReceiptMilestoneOption::unpack_verified(payload, protocol_parameters) {
-                                            Ok(receipt) => Ok(TopicEvent {
-                                                topic: p.topic.clone(),
-                                                payload: MqttPayload::Receipt((&receipt).into()),
-                                            }),
-                                            Err(e) => {
-                                                warn!("Receipt unpacking failed: {:?}", e);
-                                                Err(())
-                                            }
-                                        }
-                                    } else {
-                                        match serde_json::from_slice(&p.payload) {
-                                            Ok(value) => Ok(TopicEvent {
-                                                topic: p.topic.clone(),
-                                                payload: MqttPayload::Json(value),
-                                            }),
-                                            Err(e) => {
-                                                warn!("Cannot parse JSON: {:?}", e);
-                                                Err(())
-                                            }
-                                        }
-                                    }
-                                };
-                                if let Ok(event) = event {
-                                    for handler in handlers {
-                                        handler(&event);
                                     }
-                                };
+                                    Err(e) => warn!("{e}"),
+                                }
                             }
                         });
                     }
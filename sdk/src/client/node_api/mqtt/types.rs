@@ -5,14 +5,26 @@
 
 use std::{collections::HashMap, sync::Arc, time::Duration};
 
+use packable::PackableExt;
 use regex::RegexSet;
 use serde::{de::Error as _, Deserialize, Deserializer, Serialize};
 use serde_json::Value;
 
 use super::Error;
-use crate::types::block::{
-    payload::{dto::MilestonePayloadDto, milestone::option::dto::ReceiptMilestoneOptionDto},
-    BlockDto,
+use crate::types::{
+    api::core::response::{BlockMetadataResponse, LatestMilestoneResponse, OutputWithMetadataResponse},
+    block::{
+        address::Bech32Address,
+        output::{AliasId, FoundryId, NftId, OutputId},
+        payload::{
+            dto::MilestonePayloadDto,
+            milestone::{option::dto::ReceiptMilestoneOptionDto, ReceiptMilestoneOption},
+            transaction::TransactionId,
+            Payload,
+        },
+        protocol::ProtocolParameters,
+        Block, BlockDto, BlockId,
+    },
 };
 
 type TopicHandler = Box<dyn Fn(&TopicEvent) + Send + Sync>;
@@ -28,6 +40,46 @@ pub struct TopicEvent {
     pub payload: MqttPayload,
 }
 
+impl TopicEvent {
+    /// Decodes the payload of a message received on the given topic. Blocks, milestones and receipts are unpacked and
+    /// verified, JSON payloads of milestone info, block metadata and output topics are decoded into their response
+    /// types and passed as JSON if that fails.
+    pub fn decode(
+        topic: impl Into<String>,
+        payload: &[u8],
+        protocol_parameters: &ProtocolParameters,
+    ) -> Result<Self, Error> {
+        let topic = topic.into();
+        let invalid_payload = |error: String| Error::InvalidPayload {
+            topic: topic.clone(),
+            error,
+        };
+
+        let payload = if topic.starts_with("blocks") || topic.ends_with("included-block") {
+            let block = Block::unpack_verified(payload, protocol_parameters)
+                .map_err(|e| invalid_payload(format!("block unpacking failed: {e:?}")))?;
+            MqttPayload::Block((&block).into())
+        } else if topic == "milestones" {
+            match Payload::unpack_verified(payload, protocol_parameters)
+                .map_err(|e| invalid_payload(format!("milestone payload unpacking failed: {e:?}")))?
+            {
+                Payload::Milestone(milestone) => MqttPayload::MilestonePayload(milestone.as_ref().into()),
+                p => return Err(invalid_payload(format!("non-milestone payload, kind: {}", p.kind()))),
+            }
+        } else if topic == "receipts" {
+            let receipt = ReceiptMilestoneOption::unpack_verified(payload, protocol_parameters)
+                .map_err(|e| invalid_payload(format!("receipt unpacking failed: {e:?}")))?;
+            MqttPayload::Receipt((&receipt).into())
+        } else {
+            let value = serde_json::from_slice::<Value>(payload)
+                .map_err(|e| invalid_payload(format!("cannot parse JSON: {e}")))?;
+            MqttPayload::from_json(&topic, value)
+        };
+
+        Ok(Self { topic, payload })
+    }
+}
+
 /// The payload of an `TopicEvent`.
 #[derive(Debug, Clone, Eq, PartialEq, Serialize, Deserialize)]
 #[non_exhaustive]
@@ -40,6 +92,32 @@ pub enum MqttPayload {
     MilestonePayload(MilestonePayloadDto),
     /// In case it contains a `Receipt` object.
     Receipt(ReceiptMilestoneOptionDto),
+    /// In case it contains the info of the latest or confirmed milestone.
+    MilestoneInfo(LatestMilestoneResponse),
+    /// In case it contains block metadata.
+    BlockMetadata(Box<BlockMetadataResponse>),
+    /// In case it contains an output with its metadata.
+    Output(Box<OutputWithMetadataResponse>),
+}
+
+impl MqttPayload {
+    // Decodes the JSON payload of a topic into its response type, keeps it as JSON if that's not possible
+    fn from_json(topic: &str, value: Value) -> Self {
+        let decoded = if topic.starts_with("milestone-info/") {
+            serde_json::from_value(value.clone()).map(Self::MilestoneInfo)
+        } else if topic.starts_with("block-metadata/") {
+            serde_json::from_value(value.clone()).map(|metadata| Self::BlockMetadata(Box::new(metadata)))
+        } else if topic.starts_with("outputs/") {
+            serde_json::from_value(value.clone()).map(|output| Self::Output(Box::new(output)))
+        } else {
+            return Self::Json(value);
+        };
+
+        decoded.unwrap_or_else(|e| {
+            log::warn!("Cannot decode payload of topic {topic}: {e}");
+            Self::Json(value)
+        })
+    }
 }
 
 /// Mqtt events.
@@ -187,7 +265,7 @@ impl Topic {
                 // Transaction topics.
                 r"^transactions/0x([a-f0-9]{64})/included-block$",
                 // Output topics.
-                r"^outputs/0x([a-f0-9]{64})(\d{4})$",
+                r"^outputs/0x([a-f0-9]{64})([a-f0-9]{4})$",
                 r"^outputs/alias/0x([a-f0-9]{64})$",
                 r"^outputs/nft/0x([a-f0-9]{64})$",
                 r"^outputs/foundry/0x([a-f0-9]{76})$",
@@ -202,4 +280,130 @@ impl Topic {
     pub fn as_str(&self) -> &str {
         &self.0
     }
+
+    /// The topic of the latest milestone info.
+    pub fn latest_milestone_info() -> Self {
+        Self::new_unchecked("milestone-info/latest")
+    }
+
+    /// The topic of the confirmed milestone info.
+    pub fn confirmed_milestone_info() -> Self {
+        Self::new_unchecked("milestone-info/confirmed")
+    }
+
+    /// The topic of all milestone payloads.
+    pub fn milestones() -> Self {
+        Self::new_unchecked("milestones")
+    }
+
+    /// The topic of all blocks.
+    pub fn blocks() -> Self {
+        Self::new_unchecked("blocks")
+    }
+
+    /// The topic of all blocks containing a transaction.
+    pub fn transaction_blocks() -> Self {
+        Self::new_unchecked("blocks/transaction")
+    }
+
+    /// The topic of blocks containing a transaction with a tagged data payload, optionally only with the given tag.
+    pub fn tagged_data_transaction_blocks(tag: Option<&[u8]>) -> Result<Self, Error> {
+        Self::with_tag("blocks/transaction/tagged-data", tag)
+    }
+
+    /// The topic of blocks containing a tagged data payload, optionally only with the given tag.
+    pub fn tagged_data_blocks(tag: Option<&[u8]>) -> Result<Self, Error> {
+        Self::with_tag("blocks/tagged-data", tag)
+    }
+
+    /// The topic of the metadata of a block.
+    pub fn block_metadata(block_id: &BlockId) -> Self {
+        Self::new_unchecked(format!("block-metadata/{block_id}"))
+    }
+
+    /// The topic of the metadata of all blocks referenced by a milestone.
+    pub fn referenced_block_metadata() -> Self {
+        Self::new_unchecked("block-metadata/referenced")
+    }
+
+    /// The topic of the block which included a transaction.
+    pub fn transaction_included_block(transaction_id: &TransactionId) -> Self {
+        Self::new_unchecked(format!("transactions/{transaction_id}/included-block"))
+    }
+
+    /// The topic of an output, which is published when it's created and spent.
+    pub fn output(output_id: &OutputId) -> Self {
+        Self::new_unchecked(format!("outputs/{output_id}"))
+    }
+
+    /// The topic of the outputs of an alias.
+    pub fn alias_outputs(alias_id: &AliasId) -> Self {
+        Self::new_unchecked(format!("outputs/alias/{alias_id}"))
+    }
+
+    /// The topic of the outputs of an NFT.
+    pub fn nft_outputs(nft_id: &NftId) -> Self {
+        Self::new_unchecked(format!("outputs/nft/{nft_id}"))
+    }
+
+    /// The topic of the outputs of a foundry.
+    pub fn foundry_outputs(foundry_id: &FoundryId) -> Self {
+        Self::new_unchecked(format!("outputs/foundry/{foundry_id}"))
+    }
+
+    /// The topic of created outputs with an unlock condition for the given address.
+    pub fn unlock_condition_outputs(unlock_condition: UnlockConditionTopic, address: &Bech32Address) -> Self {
+        Self::new_unchecked(format!("outputs/unlock/{}/{address}", unlock_condition.as_str()))
+    }
+
+    /// The topic of spent outputs with an unlock condition for the given address.
+    pub fn spent_unlock_condition_outputs(unlock_condition: UnlockConditionTopic, address: &Bech32Address) -> Self {
+        Self::new_unchecked(format!("outputs/unlock/{}/{address}/spent", unlock_condition.as_str()))
+    }
+
+    /// The topic of all receipts.
+    pub fn receipts() -> Self {
+        Self::new_unchecked("receipts")
+    }
+
+    fn with_tag(topic: &str, tag: Option<&[u8]>) -> Result<Self, Error> {
+        tag.map_or_else(
+            || Ok(Self::new_unchecked(topic)),
+            |tag| Self::new(format!("{topic}/{}", prefix_hex::encode(tag))),
+        )
+    }
+}
+
+/// The unlock condition by which the outputs of an address are selected in [`Topic::unlock_condition_outputs()`].
+#[derive(Debug, Clone, Copy, Hash, Eq, PartialEq)]
+pub enum UnlockConditionTopic {
+    /// Any unlock condition.
+    Any,
+    /// The address unlock condition.
+    Address,
+    /// The return address of the storage deposit return unlock condition.
+    StorageReturn,
+    /// The return address of the expiration unlock condition.
+    Expiration,
+    /// The state controller address unlock condition.
+    StateController,
+    /// The governor address unlock condition.
+    Governor,
+    /// The immutable alias address unlock condition.
+    ImmutableAlias,
+}
+
+impl UnlockConditionTopic {
+    /// Returns the topic level of the unlock condition.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Any => "+",
+            Self::Address => "address",
+            Self::StorageReturn => "storage-return",
+            Self::Expiration => "expiration",
+            Self::StateController => "state-controller",
+            Self::Governor => "governor",
+            Self::ImmutableAlias => "immutable-alias",
+        }
+    }
 }
@@ -0,0 +1,69 @@
+// Copyright 2024 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+//! Raw access to the REST API of the nodes, for routes the client doesn't cover
+
+use std::fmt::Debug;
+
+use serde::{de::DeserializeOwned, Serialize};
+
+use crate::client::{ClientInner, RequestOptions, Result};
+
+/// Sends requests to any REST route of the nodes, using the node selection, authentication, rate limiting and error
+/// mapping of the client. Created with [`ClientInner::rest()`].
+///
+/// Paths are relative to the node URL, e.g. `api/core/v2/info` or `api/my-plugin/v1/status`.
+#[derive(Clone, Copy)]
+pub struct RestClient<'a> {
+    client: &'a ClientInner,
+}
+
+impl Debug for RestClient<'_> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("RestClient").finish_non_exhaustive()
+    }
+}
+
+impl ClientInner {
+    /// Returns a [`RestClient`] to call node routes, e.g. of plugins, which don't have a method in the client yet.
+    pub fn rest(&self) -> RestClient<'_> {
+        RestClient { client: self }
+    }
+}
+
+impl RestClient<'_> {
+    /// Sends a GET request and deserializes the JSON response.
+    pub async fn get<T: DeserializeOwned + Debug + Serialize>(&self, path: &str, query: Option<&str>) -> Result<T> {
+        self.get_with_options(path, query, &RequestOptions::default()).await
+    }
+
+    /// Sends a GET request to the node of the options instead of the node pool if one is set, and deserializes the
+    /// JSON response.
+    pub async fn get_with_options<T: DeserializeOwned + Debug + Serialize>(
+        &self,
+        path: &str,
+        query: Option<&str>,
+        options: &RequestOptions,
+    ) -> Result<T> {
+        self.client
+            .get_request_with_options(trim_path(path), query, false, false, options)
+            .await
+    }
+
+    /// Sends a GET request which accepts the binary serialization of the response and returns its bytes.
+    pub async fn get_bytes(&self, path: &str, query: Option<&str>) -> Result<Vec<u8>> {
+        self.client.get_request_bytes(trim_path(path), query).await
+    }
+
+    /// Sends a POST request with a JSON body and deserializes the JSON response. Remote PoW nodes aren't preferred.
+    pub async fn post<T: DeserializeOwned + Send>(&self, path: &str, body: &(impl Serialize + Sync)) -> Result<T> {
+        self.client
+            .post_request_json(trim_path(path), serde_json::to_value(body)?, true)
+            .await
+    }
+}
+
+// Routes are appended to the path of the node URL
+fn trim_path(path: &str) -> &str {
+    path.trim_start_matches('/')
+}
@@ -5,7 +5,7 @@
 
 use std::{
     collections::{HashMap, HashSet},
-    sync::RwLock,
+    sync::{atomic::AtomicBool, RwLock},
     time::Duration,
 };
 
@@ -19,6 +19,8 @@ use crate::{
         node_manager::{
             http_client::HttpClient,
             node::{Node, NodeAuth, NodeDto},
+            tls_pin::{TlsPin, TlsPins},
+            transport::Transport,
             NodeManager,
         },
     },
@@ -35,6 +37,9 @@ pub struct NodeManagerBuilder {
     /// Node which will be tried first when using remote PoW, even before the primary_node
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub primary_pow_node: Option<NodeDto>,
+    /// Node to which blocks are submitted while it's healthy, it's not used for other requests
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub issuer_node: Option<NodeDto>,
     /// Nodes
     #[serde(default, skip_serializing_if = "HashSet::is_empty")]
     pub nodes: HashSet<NodeDto>,
@@ -61,6 +66,15 @@ pub struct NodeManagerBuilder {
     /// The User-Agent header for requests
     #[serde(default = "default_user_agent")]
     pub user_agent: String,
+    /// The proxy for the requests to nodes without their own proxy
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub proxy: Option<Url>,
+    /// The pinned TLS certificates or public keys, keyed by the host of the node URL
+    #[serde(default, skip_serializing_if = "TlsPins::is_empty")]
+    pub tls_pins: TlsPins,
+    /// The transport which sends the requests instead of TCP connections
+    #[serde(skip)]
+    pub transport: Option<Transport>,
 }
 
 fn default_user_agent() -> String {
@@ -90,61 +104,52 @@ impl NodeManagerBuilder {
             url,
             auth: None,
             disabled: false,
+            proxy: None,
         }));
         Ok(self)
     }
 
     pub(crate) fn with_primary_node(mut self, url: &str, auth: Option<NodeAuth>) -> Result<Self> {
-        let mut url = validate_url(Url::parse(url)?)?;
-        if let Some(auth) = &auth {
-            if let Some((name, password)) = &auth.basic_auth_name_pwd {
-                url.set_username(name)
-                    .map_err(|_| crate::client::Error::UrlAuth("username"))?;
-                url.set_password(Some(password))
-                    .map_err(|_| crate::client::Error::UrlAuth("password"))?;
-            }
-        }
+        let url = validate_url(Url::parse(url)?)?;
         self.primary_node.replace(NodeDto::Node(Node {
             url,
             auth,
             disabled: false,
+            proxy: None,
         }));
         Ok(self)
     }
 
     pub(crate) fn with_primary_pow_node(mut self, url: &str, auth: Option<NodeAuth>) -> Result<Self> {
-        let mut url = validate_url(Url::parse(url)?)?;
-        if let Some(auth) = &auth {
-            if let Some((name, password)) = &auth.basic_auth_name_pwd {
-                url.set_username(name)
-                    .map_err(|_| crate::client::Error::UrlAuth("username"))?;
-                url.set_password(Some(password))
-                    .map_err(|_| crate::client::Error::UrlAuth("password"))?;
-            }
-        }
+        let url = validate_url(Url::parse(url)?)?;
         self.primary_pow_node.replace(NodeDto::Node(Node {
             url,
             auth,
             disabled: false,
+            proxy: None,
+        }));
+        Ok(self)
+    }
+
+    pub(crate) fn with_issuer_node(mut self, url: &str, auth: Option<NodeAuth>) -> Result<Self> {
+        let url = validate_url(Url::parse(url)?)?;
+        self.issuer_node.replace(NodeDto::Node(Node {
+            url,
+            auth,
+            disabled: false,
+            proxy: None,
         }));
         Ok(self)
     }
 
     pub(crate) fn with_permanode(mut self, url: &str, auth: impl Into<Option<NodeAuth>>) -> Result<Self> {
-        let mut url = validate_url(Url::parse(url)?)?;
+        let url = validate_url(Url::parse(url)?)?;
         let auth = auth.into();
-        if let Some(auth) = &auth {
-            if let Some((name, password)) = &auth.basic_auth_name_pwd {
-                url.set_username(name)
-                    .map_err(|_| crate::client::Error::UrlAuth("username"))?;
-                url.set_password(Some(password))
-                    .map_err(|_| crate::client::Error::UrlAuth("password"))?;
-            }
-        }
         self.permanodes.insert(NodeDto::Node(Node {
             url,
             auth,
             disabled: false,
+            proxy: None,
         }));
 
         Ok(self)
@@ -156,20 +161,13 @@ impl NodeManagerBuilder {
     }
 
     pub(crate) fn with_node_auth(mut self, url: &str, auth: impl Into<Option<NodeAuth>>) -> Result<Self> {
-        let mut url = validate_url(Url::parse(url)?)?;
+        let url = validate_url(Url::parse(url)?)?;
         let auth = auth.into();
-        if let Some(auth) = &auth {
-            if let Some((name, password)) = &auth.basic_auth_name_pwd {
-                url.set_username(name)
-                    .map_err(|_| crate::client::Error::UrlAuth("username"))?;
-                url.set_password(Some(password))
-                    .map_err(|_| crate::client::Error::UrlAuth("password"))?;
-            }
-        }
         self.nodes.insert(NodeDto::Node(Node {
             url,
             auth,
             disabled: false,
+            proxy: None,
         }));
         Ok(self)
     }
@@ -181,6 +179,7 @@ impl NodeManagerBuilder {
                 url,
                 auth: None,
                 disabled: false,
+                proxy: None,
             }));
         }
         Ok(self)
@@ -211,10 +210,51 @@ impl NodeManagerBuilder {
         self
     }
 
+    pub(crate) fn with_proxy(mut self, proxy: &str) -> Result<Self> {
+        self.proxy.replace(validate_proxy_url(Url::parse(proxy)?)?);
+        Ok(self)
+    }
+
+    pub(crate) fn with_node_proxy(mut self, url: &str, auth: impl Into<Option<NodeAuth>>, proxy: &str) -> Result<Self> {
+        let url = validate_url(Url::parse(url)?)?;
+        self.nodes.insert(NodeDto::Node(Node {
+            url,
+            auth: auth.into(),
+            disabled: false,
+            proxy: Some(validate_proxy_url(Url::parse(proxy)?)?),
+        }));
+        Ok(self)
+    }
+
+    pub(crate) fn with_tls_pin(mut self, url: &str, pin: TlsPin) -> Result<Self> {
+        let url = validate_url(Url::parse(url)?)?;
+        if url.scheme() != "https" {
+            return Err(Error::UrlValidation(format!("TLS pins require an https url: {url}")));
+        }
+        // Same format as the server name of the TLS connection, so without brackets for IPv6 addresses
+        let host = match url.host() {
+            Some(url::Host::Ipv6(ip)) => ip.to_string(),
+            Some(host) => host.to_string(),
+            None => return Err(Error::UrlValidation(format!("missing host: {url}"))),
+        };
+        let pins = self.tls_pins.entry(host).or_default();
+        if !pins.contains(&pin) {
+            pins.push(pin);
+        }
+        Ok(self)
+    }
+
+    pub(crate) fn with_transport(mut self, transport: impl Into<Option<Transport>>) -> Self {
+        self.transport = transport.into();
+        self
+    }
+
     pub(crate) fn build(self, healthy_nodes: HashMap<Node, InfoResponse>) -> NodeManager {
         NodeManager {
             primary_node: self.primary_node.map(Into::into),
             primary_pow_node: self.primary_pow_node.map(Into::into),
+            issuer_node: self.issuer_node.map(Into::into),
+            issuer_node_healthy: AtomicBool::new(false),
             nodes: self.nodes.into_iter().map(Into::into).collect(),
             permanodes: self.permanodes.into_iter().map(Into::into).collect(),
             ignore_node_health: self.ignore_node_health,
@@ -223,7 +263,10 @@ impl NodeManagerBuilder {
             quorum: self.quorum,
             min_quorum_size: self.min_quorum_size,
             quorum_threshold: self.quorum_threshold,
-            http_client: HttpClient::new(self.user_agent),
+            http_client: HttpClient::new(self.user_agent)
+                .with_proxy(self.proxy)
+                .with_tls_pins(self.tls_pins)
+                .with_transport(self.transport),
         }
     }
 }
@@ -233,6 +276,7 @@ impl Default for NodeManagerBuilder {
         Self {
             primary_node: None,
             primary_pow_node: None,
+            issuer_node: None,
             nodes: HashSet::new(),
             permanodes: HashSet::new(),
             ignore_node_health: false,
@@ -241,6 +285,9 @@ impl Default for NodeManagerBuilder {
             min_quorum_size: DEFAULT_MIN_QUORUM_SIZE,
             quorum_threshold: DEFAULT_QUORUM_THRESHOLD,
             user_agent: DEFAULT_USER_AGENT.to_string(),
+            proxy: None,
+            tls_pins: TlsPins::new(),
+            transport: None,
         }
     }
 }
@@ -253,11 +300,22 @@ pub fn validate_url(url: Url) -> Result<Url> {
     Ok(url)
 }
 
+/// Validates if the proxy url starts with http or https, or with socks5 or socks5h if the `socks` feature is enabled
+pub fn validate_proxy_url(url: Url) -> Result<Url> {
+    match url.scheme() {
+        "http" | "https" => Ok(url),
+        #[cfg(feature = "socks")]
+        "socks5" | "socks5h" => Ok(url),
+        scheme => Err(Error::UrlValidation(format!("unsupported proxy scheme: {scheme}"))),
+    }
+}
+
 impl From<&NodeManager> for NodeManagerBuilder {
     fn from(value: &NodeManager) -> Self {
         Self {
             primary_node: value.primary_node.clone().map(NodeDto::Node),
             primary_pow_node: value.primary_pow_node.clone().map(NodeDto::Node),
+            issuer_node: value.issuer_node.clone().map(NodeDto::Node),
             nodes: value.nodes.iter().cloned().map(NodeDto::Node).collect(),
             permanodes: value.permanodes.iter().cloned().map(NodeDto::Node).collect(),
             ignore_node_health: value.ignore_node_health,
@@ -266,6 +324,9 @@ impl From<&NodeManager> for NodeManagerBuilder {
             min_quorum_size: value.min_quorum_size,
             quorum_threshold: value.quorum_threshold,
             user_agent: value.http_client.user_agent.clone(),
+            proxy: value.http_client.proxy.clone(),
+            tls_pins: value.http_client.tls_pins.as_ref().clone(),
+            transport: value.http_client.transport.clone(),
         }
     }
 }
@@ -3,34 +3,59 @@
 
 //! The node manager that takes care of sending requests with healthy nodes and quorum if enabled
 
-use std::time::Duration;
+use std::{
+    collections::HashMap,
+    sync::{Arc, Mutex},
+    time::Duration,
+};
 
 use reqwest::RequestBuilder;
 use serde::de::DeserializeOwned;
 use serde_json::Value;
+use url::Url;
 
 use crate::client::{
     node_api::error::{Error, Result},
-    node_manager::node::Node,
+    node_manager::{
+        node::Node,
+        tls_pin::TlsPins,
+        transport::{Transport, TransportRequest, TransportResponse},
+    },
 };
-pub(crate) struct Response(reqwest::Response);
+
+pub(crate) enum Response {
+    Reqwest(reqwest::Response),
+    Transport(TransportResponse),
+}
 
 impl Response {
     pub(crate) fn status(&self) -> u16 {
-        self.0.status().as_u16()
+        match self {
+            Self::Reqwest(response) => response.status().as_u16(),
+            Self::Transport(response) => response.status,
+        }
     }
 
     pub(crate) async fn into_json<T: DeserializeOwned>(self) -> Result<T> {
-        self.0.json().await.map_err(Into::into)
+        match self {
+            Self::Reqwest(response) => response.json().await.map_err(Into::into),
+            Self::Transport(response) => serde_json::from_slice(&response.body)
+                .map_err(|e| Error::Transport(format!("invalid JSON response: {e}"))),
+        }
     }
 
-    #[cfg(not(target_family = "wasm"))]
     pub(crate) async fn into_text(self) -> Result<String> {
-        self.0.text().await.map_err(Into::into)
+        match self {
+            Self::Reqwest(response) => response.text().await.map_err(Into::into),
+            Self::Transport(response) => Ok(String::from_utf8_lossy(&response.body).into_owned()),
+        }
     }
 
     pub(crate) async fn into_bytes(self) -> Result<Vec<u8>> {
-        self.0.bytes().await.map(|b| b.to_vec()).map_err(Into::into)
+        match self {
+            Self::Reqwest(response) => response.bytes().await.map(|b| b.to_vec()).map_err(Into::into),
+            Self::Transport(response) => Ok(response.body),
+        }
     }
 }
 
@@ -38,6 +63,11 @@ impl Response {
 pub(crate) struct HttpClient {
     client: reqwest::Client,
     pub(crate) user_agent: String,
+    pub(crate) proxy: Option<Url>,
+    pub(crate) tls_pins: Arc<TlsPins>,
+    // Clients are created per proxy, as the proxy can't be set per request
+    clients: Arc<Mutex<HashMap<Option<Url>, reqwest::Client>>>,
+    pub(crate) transport: Option<Transport>,
 }
 
 impl HttpClient {
@@ -45,15 +75,86 @@ impl HttpClient {
         Self {
             client: reqwest::Client::new(),
             user_agent,
+            proxy: None,
+            tls_pins: Default::default(),
+            clients: Default::default(),
+            transport: None,
+        }
+    }
+
+    pub(crate) fn with_proxy(mut self, proxy: impl Into<Option<Url>>) -> Self {
+        self.proxy = proxy.into();
+        self
+    }
+
+    pub(crate) fn with_tls_pins(mut self, tls_pins: TlsPins) -> Self {
+        self.tls_pins = Arc::new(tls_pins);
+        self
+    }
+
+    // Returns the client connecting through the proxy of the node or the default proxy, and verifying the TLS pins.
+    // Fails instead of connecting directly or without the pins if they can't be used.
+    fn client_for(&self, node: &Node) -> Result<reqwest::Client> {
+        let proxy = node.proxy.as_ref().or(self.proxy.as_ref());
+        if proxy.is_none() && self.tls_pins.is_empty() {
+            return Ok(self.client.clone());
+        }
+
+        #[cfg(target_family = "wasm")]
+        {
+            Err(Error::NotSupported(
+                if proxy.is_some() { "proxy" } else { "TLS pinning" }.to_string(),
+            ))
+        }
+        #[cfg(not(target_family = "wasm"))]
+        {
+            // Clients are never left inconsistent by a panic, so a poisoned lock can still be used.
+            let mut clients = self.clients.lock().unwrap_or_else(|e| e.into_inner());
+            if let Some(client) = clients.get(&proxy.cloned()) {
+                return Ok(client.clone());
+            }
+            let mut client_builder = reqwest::Client::builder();
+            if let Some(proxy) = proxy {
+                client_builder = client_builder.proxy(reqwest::Proxy::all(proxy.as_str())?);
+            }
+            if !self.tls_pins.is_empty() {
+                #[cfg(feature = "tls")]
+                {
+                    client_builder = client_builder.use_preconfigured_tls(
+                        crate::client::node_manager::tls_pin::verifier::tls_config(self.tls_pins.clone()),
+                    );
+                }
+                #[cfg(not(feature = "tls"))]
+                return Err(Error::NotSupported("TLS pinning without the `tls` feature".to_string()));
+            }
+            let client = client_builder.build()?;
+            clients.insert(proxy.cloned(), client.clone());
+            Ok(client)
         }
     }
 
-    async fn parse_response(response: reqwest::Response, url: &url::Url) -> Result<Response> {
+    pub(crate) fn with_transport(mut self, transport: impl Into<Option<Transport>>) -> Self {
+        self.transport = transport.into();
+        self
+    }
+
+    // Sends the request with the custom transport if one is set
+    async fn send(&self, request_builder: RequestBuilder) -> Result<Response> {
+        match &self.transport {
+            Some(transport) => {
+                let request = TransportRequest::from_reqwest(request_builder.build()?);
+                Ok(Response::Transport(transport.0.send(request).await?))
+            }
+            None => Ok(Response::Reqwest(request_builder.send().await?)),
+        }
+    }
+
+    async fn parse_response(response: Response, url: &Url) -> Result<Response> {
         let status = response.status();
-        if status.is_success() {
-            Ok(Response(response))
+        if (200..300).contains(&status) {
+            Ok(response)
         } else {
-            let text = response.text().await?;
+            let text = response.into_text().await?;
             // Different urls, nodes and versions give different replies
             if text == *"no available nodes with remote Pow"
                 || text.contains("proof of work is not available on this node")
@@ -61,11 +162,11 @@ impl HttpClient {
                 || text.contains("`Pow` not enabled")
             {
                 Err(Error::UnavailablePow)
-            } else if status.as_u16() == 404 {
+            } else if status == 404 {
                 Err(Error::NotFound(url.to_string()))
             } else {
                 Err(Error::ResponseError {
-                    code: status.as_u16(),
+                    code: status,
                     text,
                     url: url.to_string(),
                 })
@@ -77,9 +178,7 @@ impl HttpClient {
         let mut request_builder = request_builder.header(reqwest::header::USER_AGENT, &self.user_agent);
 
         if let Some(node_auth) = &node.auth {
-            if let Some(jwt) = &node_auth.jwt {
-                request_builder = request_builder.bearer_auth(jwt);
-            }
+            request_builder = node_auth.apply(request_builder);
         }
         #[cfg(not(target_family = "wasm"))]
         {
@@ -89,10 +188,10 @@ impl HttpClient {
     }
 
     pub(crate) async fn get(&self, node: Node, timeout: Duration) -> Result<Response> {
-        let mut request_builder = self.client.get(node.url.clone());
+        let mut request_builder = self.client_for(&node)?.get(node.url.clone());
         request_builder = self.build_request(request_builder, &node, timeout);
         let start_time = instant::Instant::now();
-        let resp = request_builder.send().await?;
+        let resp = self.send(request_builder).await?;
         log::debug!(
             "GET: {:?} ms for {} {}",
             start_time.elapsed().as_millis(),
@@ -104,23 +203,23 @@ impl HttpClient {
 
     // Get with header: "accept", "application/vnd.iota.serializer-v1"
     pub(crate) async fn get_bytes(&self, node: Node, timeout: Duration) -> Result<Response> {
-        let mut request_builder = self.client.get(node.url.clone());
+        let mut request_builder = self.client_for(&node)?.get(node.url.clone());
         request_builder = self.build_request(request_builder, &node, timeout);
         request_builder = request_builder.header("accept", "application/vnd.iota.serializer-v1");
-        let resp = request_builder.send().await?;
+        let resp = self.send(request_builder).await?;
         Self::parse_response(resp, &node.url).await
     }
 
     pub(crate) async fn post_json(&self, node: Node, timeout: Duration, json: Value) -> Result<Response> {
-        let mut request_builder = self.client.post(node.url.clone());
+        let mut request_builder = self.client_for(&node)?.post(node.url.clone());
         request_builder = self.build_request(request_builder, &node, timeout);
-        Self::parse_response(request_builder.json(&json).send().await?, &node.url).await
+        Self::parse_response(self.send(request_builder.json(&json)).await?, &node.url).await
     }
 
     pub(crate) async fn post_bytes(&self, node: Node, timeout: Duration, body: &[u8]) -> Result<Response> {
-        let mut request_builder = self.client.post(node.url.clone());
+        let mut request_builder = self.client_for(&node)?.post(node.url.clone());
         request_builder = self.build_request(request_builder, &node, timeout);
         request_builder = request_builder.header("Content-Type", "application/vnd.iota.serializer-v1");
-        Self::parse_response(request_builder.body(body.to_vec()).send().await?, &node.url).await
+        Self::parse_response(self.send(request_builder.body(body.to_vec())).await?, &node.url).await
     }
 }
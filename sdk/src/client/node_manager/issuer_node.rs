@@ -0,0 +1,119 @@
+// Copyright 2024 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+//! Block submission with a dedicated issuer node, falling back to the other nodes.
+
+use std::{future::Future, sync::atomic::Ordering};
+
+use serde::{Deserialize, Serialize};
+use url::Url;
+
+use super::{node::NodeDto, request_options::RequestOptions, NodeManager};
+use crate::client::{ClientInner, Result};
+
+pub(crate) type IssuerNodeFallbackListener = Box<dyn Fn(&IssuerNodeFallbackEvent) + Send + Sync + 'static>;
+
+/// A block is submitted with the other nodes, because the issuer node couldn't be used.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct IssuerNodeFallbackEvent {
+    /// The url of the issuer node.
+    pub issuer_node: Url,
+    /// The error of the issuer node, `None` if it wasn't used because it's unhealthy.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+}
+
+impl NodeManager {
+    // Nodes are only health checked by the sync process, which doesn't exist in wasm
+    pub(crate) fn is_issuer_node_healthy(&self) -> bool {
+        cfg!(target_family = "wasm") || self.ignore_node_health || self.issuer_node_healthy.load(Ordering::Relaxed)
+    }
+}
+
+impl ClientInner {
+    /// Calls the handler whenever a block is submitted with the other nodes, because the issuer node is unhealthy or
+    /// failed.
+    pub async fn listen_issuer_node_fallback(
+        &self,
+        handler: impl Fn(&IssuerNodeFallbackEvent) + Send + Sync + 'static,
+    ) {
+        self.issuer_node_fallback_listeners
+            .write()
+            .await
+            .push(Box::new(handler));
+    }
+
+    /// Removes all issuer node fallback handlers.
+    pub async fn clear_issuer_node_fallback_listeners(&self) {
+        self.issuer_node_fallback_listeners.write().await.clear();
+    }
+
+    /// Sends a block submission request to the issuer node if it's set and healthy. The other nodes are used if that's
+    /// not possible or a node is set in the options.
+    pub(crate) async fn post_with_issuer_node<T, F, Fut>(&self, options: &RequestOptions, post: F) -> Result<T>
+    where
+        F: Fn(RequestOptions) -> Fut,
+        Fut: Future<Output = Result<T>>,
+    {
+        if options.node.is_none() {
+            let issuer_node = {
+                let node_manager = self.node_manager.read().await;
+                node_manager
+                    .issuer_node
+                    .clone()
+                    .filter(|node| !node.disabled)
+                    .map(|node| (node, node_manager.is_issuer_node_healthy()))
+            };
+
+            if let Some((issuer_node, healthy)) = issuer_node {
+                let error = if healthy {
+                    let issuer_options = RequestOptions {
+                        node: Some(NodeDto::Node(issuer_node.clone())),
+                    };
+                    match post(issuer_options).await {
+                        Ok(response) => return Ok(response),
+                        Err(err) => Some(err.to_string()),
+                    }
+                } else {
+                    None
+                };
+                log::warn!(
+                    "[post_with_issuer_node] falling back to the other nodes: {}",
+                    error.as_deref().unwrap_or("issuer node is unhealthy")
+                );
+
+                let event = IssuerNodeFallbackEvent {
+                    issuer_node: issuer_node.url,
+                    error,
+                };
+                for listener in self.issuer_node_fallback_listeners.read().await.iter() {
+                    listener(&event);
+                }
+            }
+        }
+
+        post(options.clone()).await
+    }
+
+    /// Checks the health of the issuer node, if set.
+    #[cfg(not(target_family = "wasm"))]
+    pub(crate) async fn sync_issuer_node(&self, ignore_node_health: bool) {
+        let Some(issuer_node) = self.node_manager.read().await.issuer_node.clone() else {
+            return;
+        };
+
+        let healthy = match self.get_node_info_of(&issuer_node).await {
+            Ok(info) => info.status.is_healthy || ignore_node_health,
+            Err(err) => {
+                log::warn!("Couldn't get issuer node info: {err}");
+                false
+            }
+        };
+        self.node_manager
+            .read()
+            .await
+            .issuer_node_healthy
+            .store(healthy, Ordering::Relaxed);
+    }
+}
@@ -5,26 +5,31 @@
 
 pub mod builder;
 pub(crate) mod http_client;
+pub mod issuer_node;
 /// Structs for nodes
 pub mod node;
+pub mod request_options;
 pub(crate) mod syncing;
+pub mod tls_pin;
+pub mod transport;
 
 use std::{
     collections::{HashMap, HashSet},
     fmt::Debug,
-    sync::RwLock,
+    sync::{atomic::AtomicBool, RwLock},
     time::Duration,
 };
 
 use serde::{de::DeserializeOwned, Serialize};
 use serde_json::Value;
 
-use self::{http_client::HttpClient, node::Node};
+use self::{http_client::HttpClient, node::Node, request_options::RequestOptions};
 use super::ClientInner;
 #[cfg(not(target_family = "wasm"))]
 use crate::client::request_pool::RateLimitExt;
 use crate::{
     client::{
+        cache::Cache,
         error::{Error, Result},
         node_manager::builder::NodeManagerBuilder,
     },
@@ -36,6 +41,8 @@ use crate::{
 pub struct NodeManager {
     pub(crate) primary_node: Option<Node>,
     primary_pow_node: Option<Node>,
+    pub(crate) issuer_node: Option<Node>,
+    pub(crate) issuer_node_healthy: AtomicBool,
     pub(crate) nodes: HashSet<Node>,
     permanodes: HashSet<Node>,
     pub(crate) ignore_node_health: bool,
@@ -52,6 +59,8 @@ impl Debug for NodeManager {
         let mut d = f.debug_struct("NodeManager");
         d.field("primary_node", &self.primary_node);
         d.field("primary_pow_node", &self.primary_pow_node);
+        d.field("issuer_node", &self.issuer_node);
+        d.field("issuer_node_healthy", &self.issuer_node_healthy);
         d.field("nodes", &self.nodes);
         d.field("permanodes", &self.permanodes);
         d.field("ignore_node_health", &self.ignore_node_health);
@@ -71,16 +80,86 @@ impl ClientInner {
         need_quorum: bool,
         prefer_permanode: bool,
     ) -> Result<T> {
+        self.get_request_with_options(path, query, need_quorum, prefer_permanode, &RequestOptions::default())
+            .await
+    }
+
+    pub(crate) async fn get_request_with_options<T: DeserializeOwned + Debug + Serialize>(
+        &self,
+        path: &str,
+        query: Option<&str>,
+        need_quorum: bool,
+        prefer_permanode: bool,
+        options: &RequestOptions,
+    ) -> Result<T> {
+        // Responses which can't change anymore are cached, if enabled
+        let cache = self.cache.read().await.clone().zip(Cache::key(path, query, false));
+        if let Some((cache, key)) = &cache {
+            if let Some(response) = cache.get(key).and_then(|bytes| serde_json::from_slice(&bytes).ok()) {
+                return Ok(response);
+            }
+        }
+
+        let response: T = self
+            .get_request_uncached(path, query, need_quorum, prefer_permanode, options)
+            .await?;
+
+        if let Some((cache, key)) = cache {
+            cache.insert(key, serde_json::to_vec(&response)?);
+        }
+        Ok(response)
+    }
+
+    async fn get_request_uncached<T: DeserializeOwned + Debug + Serialize>(
+        &self,
+        path: &str,
+        query: Option<&str>,
+        need_quorum: bool,
+        prefer_permanode: bool,
+        options: &RequestOptions,
+    ) -> Result<T> {
+        #[cfg(feature = "testing")]
+        if let Some(mock_client) = &self.mock_client {
+            return Ok(serde_json::from_value(mock_client.get_request(path, query)?)?);
+        }
         let node_manager = self.node_manager.read().await;
-        let request = node_manager.get_request(path, query, self.get_timeout().await, need_quorum, prefer_permanode);
+        let request = node_manager.get_request(
+            path,
+            query,
+            self.get_timeout().await,
+            need_quorum,
+            prefer_permanode,
+            options,
+        );
         #[cfg(not(target_family = "wasm"))]
         let request = request.rate_limit(&self.request_pool);
         request.await
     }
 
     pub(crate) async fn get_request_bytes(&self, path: &str, query: Option<&str>) -> Result<Vec<u8>> {
+        let cache = self.cache.read().await.clone().zip(Cache::key(path, query, true));
+        if let Some((cache, key)) = &cache {
+            if let Some(response) = cache.get(key) {
+                return Ok(response);
+            }
+        }
+
+        let response = self.get_request_bytes_uncached(path, query).await?;
+
+        if let Some((cache, key)) = cache {
+            cache.insert(key, response.clone());
+        }
+        Ok(response)
+    }
+
+    async fn get_request_bytes_uncached(&self, path: &str, query: Option<&str>) -> Result<Vec<u8>> {
+        #[cfg(feature = "testing")]
+        if let Some(mock_client) = &self.mock_client {
+            return mock_client.get_request_bytes(path);
+        }
         let node_manager = self.node_manager.read().await;
-        let request = node_manager.get_request_bytes(path, query, self.get_timeout().await);
+        let options = RequestOptions::default();
+        let request = node_manager.get_request_bytes(path, query, self.get_timeout().await, &options);
         #[cfg(not(target_family = "wasm"))]
         let request = request.rate_limit(&self.request_pool);
         request.await
@@ -92,8 +171,13 @@ impl ClientInner {
         json: Value,
         local_pow: bool,
     ) -> Result<T> {
+        #[cfg(feature = "testing")]
+        if let Some(mock_client) = &self.mock_client {
+            return Ok(serde_json::from_value(mock_client.post_request_json(path, json)?)?);
+        }
         let node_manager = self.node_manager.read().await;
-        let request = node_manager.post_request_json(path, self.get_timeout().await, json, local_pow);
+        let options = RequestOptions::default();
+        let request = node_manager.post_request_json(path, self.get_timeout().await, json, local_pow, &options);
         #[cfg(not(target_family = "wasm"))]
         let request = request.rate_limit(&self.request_pool);
         request.await
@@ -111,6 +195,34 @@ impl NodeManager {
         query: Option<&str>,
         use_pow_nodes: bool,
         prefer_permanode: bool,
+        options: &RequestOptions,
+    ) -> Result<Vec<Node>> {
+        let mut nodes_with_modified_url = match &options.node {
+            // An explicit node bypasses the node pool and its health
+            Some(node) => vec![Node::from(node)],
+            None => self.get_pool_nodes(path, query, use_pow_nodes, prefer_permanode)?,
+        };
+
+        // Set path and query parameters
+        for node in &mut nodes_with_modified_url {
+            if node.url.path().ends_with('/') {
+                node.url.set_path(&format!("{}{}", node.url.path(), path));
+            } else {
+                node.url.set_path(&format!("{}/{}", node.url.path(), path));
+            }
+            node.url.set_query(query);
+        }
+
+        Ok(nodes_with_modified_url)
+    }
+
+    // Returns the nodes of the node pool in the order they should be used for a request
+    fn get_pool_nodes(
+        &self,
+        path: &str,
+        query: Option<&str>,
+        use_pow_nodes: bool,
+        prefer_permanode: bool,
     ) -> Result<Vec<Node>> {
         let mut nodes_with_modified_url: Vec<Node> = Vec::new();
 
@@ -187,26 +299,6 @@ impl NodeManager {
             return Err(crate::client::Error::HealthyNodePoolEmpty);
         }
 
-        // Set path and query parameters
-        for node in &mut nodes_with_modified_url {
-            if node.url.path().ends_with('/') {
-                node.url.set_path(&format!("{}{}", node.url.path(), path));
-            } else {
-                node.url.set_path(&format!("{}/{}", node.url.path(), path));
-            }
-            node.url.set_query(query);
-            if let Some(auth) = &node.auth {
-                if let Some((name, password)) = &auth.basic_auth_name_pwd {
-                    node.url
-                        .set_username(name)
-                        .map_err(|_| crate::client::Error::UrlAuth("username"))?;
-                    node.url
-                        .set_password(Some(password))
-                        .map_err(|_| crate::client::Error::UrlAuth("password"))?;
-                }
-            }
-        }
-
         Ok(nodes_with_modified_url)
     }
 
@@ -217,11 +309,14 @@ impl NodeManager {
         timeout: Duration,
         need_quorum: bool,
         prefer_permanode: bool,
+        options: &RequestOptions,
     ) -> Result<T> {
         let mut result: HashMap<String, usize> = HashMap::new();
+        // There is no quorum with a single explicit node
+        let need_quorum = need_quorum && options.node.is_none();
         // primary_pow_node should only be used for post request with remote PoW
         // Get node urls and set path
-        let nodes = self.get_nodes(path, query, false, prefer_permanode)?;
+        let nodes = self.get_nodes(path, query, false, prefer_permanode, options)?;
         if self.quorum && need_quorum && nodes.len() < self.min_quorum_size {
             return Err(Error::QuorumPoolSizeError {
                 available_nodes: nodes.len(),
@@ -334,10 +429,11 @@ impl NodeManager {
         path: &str,
         query: Option<&str>,
         timeout: Duration,
+        options: &RequestOptions,
     ) -> Result<Vec<u8>> {
         // primary_pow_node should only be used for post request with remote Pow
         // Get node urls and set path
-        let nodes = self.get_nodes(path, query, false, false)?;
+        let nodes = self.get_nodes(path, query, false, false, options)?;
         let mut error = None;
         // Send requests
         for node in nodes {
@@ -364,9 +460,10 @@ impl NodeManager {
         timeout: Duration,
         body: &[u8],
         local_pow: bool,
+        options: &RequestOptions,
     ) -> Result<T> {
         // primary_pow_node should only be used for post request with remote PoW
-        let nodes = self.get_nodes(path, None, !local_pow, false)?;
+        let nodes = self.get_nodes(path, None, !local_pow, false, options)?;
         let mut error = None;
         // Send requests
         for node in nodes {
@@ -393,9 +490,10 @@ impl NodeManager {
         timeout: Duration,
         json: Value,
         local_pow: bool,
+        options: &RequestOptions,
     ) -> Result<T> {
         // primary_pow_node should only be used for post request with remote PoW
-        let nodes = self.get_nodes(path, None, !local_pow, false)?;
+        let nodes = self.get_nodes(path, None, !local_pow, false, options)?;
         let mut error = None;
         // Send requests
         for node in nodes {
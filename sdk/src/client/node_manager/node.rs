@@ -1,20 +1,65 @@
 // Copyright 2021 IOTA Stiftung
 // SPDX-License-Identifier: Apache-2.0
 
-use std::hash::Hash;
+use std::{collections::BTreeMap, hash::Hash};
 
+use reqwest::RequestBuilder;
 use serde::{Deserialize, Serialize};
 
 use crate::Url;
 
 /// Node authentication object.
-#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, Hash)]
+#[derive(Serialize, Deserialize, Clone, Default, PartialEq, Eq, Hash)]
 #[serde(rename_all = "camelCase")]
 pub struct NodeAuth {
     /// JWT.
     pub jwt: Option<String>,
     /// Username and password.
     pub basic_auth_name_pwd: Option<(String, String)>,
+    /// Custom headers sent with every request, e.g. the API key of a node provider.
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub headers: BTreeMap<String, String>,
+}
+
+impl NodeAuth {
+    /// Adds a custom header which is sent with every request to the node.
+    pub fn with_header(mut self, name: impl Into<String>, value: impl Into<String>) -> Self {
+        self.headers.insert(name.into(), value.into());
+        self
+    }
+
+    // Adds the authentication to a request, basic auth is sent as header instead of being part of the URL, so it
+    // doesn't end up in logs and errors. Only one Authorization header can be sent, the jwt takes precedence.
+    pub(crate) fn apply(&self, mut request_builder: RequestBuilder) -> RequestBuilder {
+        if let Some(jwt) = &self.jwt {
+            request_builder = request_builder.bearer_auth(jwt);
+        } else if let Some((name, password)) = &self.basic_auth_name_pwd {
+            request_builder = request_builder.basic_auth(name, Some(password));
+        }
+        for (name, value) in &self.headers {
+            request_builder = request_builder.header(name, value);
+        }
+        request_builder
+    }
+}
+
+// The secrets are redacted, only the names of the headers are shown
+impl core::fmt::Debug for NodeAuth {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        let headers = self
+            .headers
+            .keys()
+            .map(|name| (name, "<omitted>"))
+            .collect::<BTreeMap<_, _>>();
+        f.debug_struct("NodeAuth")
+            .field("jwt", &self.jwt.as_ref().map(|_| "<omitted>"))
+            .field(
+                "basic_auth_name_pwd",
+                &self.basic_auth_name_pwd.as_ref().map(|(name, _)| (name, "<omitted>")),
+            )
+            .field("headers", &headers)
+            .finish()
+    }
 }
 
 /// Node definition.
@@ -28,6 +73,10 @@ pub struct Node {
     /// Whether the node is disabled or not.
     #[serde(default)]
     pub disabled: bool,
+    /// The proxy to connect to the node with instead of the proxy of the client, e.g. `socks5h://127.0.0.1:9050` to
+    /// connect through TOR.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub proxy: Option<Url>,
 }
 
 impl From<Url> for Node {
@@ -36,6 +85,7 @@ impl From<Url> for Node {
             url,
             auth: None,
             disabled: false,
+            proxy: None,
         }
     }
 }
@@ -0,0 +1,45 @@
+// Copyright 2024 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use serde::{Deserialize, Serialize};
+use url::Url;
+
+use super::{
+    builder::validate_url,
+    node::{Node, NodeAuth, NodeDto},
+};
+use crate::client::Result;
+
+/// Options for a single request.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RequestOptions {
+    /// The node to send the request to, bypassing the node pool. Its health isn't checked and quorum isn't used.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub node: Option<NodeDto>,
+}
+
+impl RequestOptions {
+    /// Creates request options which use the node pool.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sends the request to the node with the given URL instead of the node pool.
+    pub fn with_node(self, url: &str) -> Result<Self> {
+        self.with_node_auth(url, None)
+    }
+
+    /// Sends the request to the node with the given URL and optional jwt and or basic authentication instead of the
+    /// node pool.
+    pub fn with_node_auth(mut self, url: &str, auth: impl Into<Option<NodeAuth>>) -> Result<Self> {
+        let url = validate_url(Url::parse(url)?)?;
+        self.node.replace(NodeDto::Node(Node {
+            url,
+            auth: auth.into(),
+            disabled: false,
+            proxy: None,
+        }));
+        Ok(self)
+    }
+}
@@ -70,7 +70,7 @@ impl ClientInner {
 
         for node in nodes {
             // Put the healthy node url into the network_nodes
-            match crate::client::Client::get_node_info(node.url.as_ref(), node.auth.clone()).await {
+            match self.get_node_info_of(node).await {
                 Ok(info) => {
                     if info.status.is_healthy || ignore_node_health {
                         match network_nodes.get_mut(info.protocol.network_name()) {
@@ -106,7 +106,7 @@ impl ClientInner {
                 let mut network_info = self.network_info.write().await;
 
                 network_info.latest_milestone_timestamp = info.status.latest_milestone.timestamp;
-                network_info.protocol_parameters = info.protocol.clone();
+                network_info.update_protocol_parameters(info.protocol.clone())?;
             }
 
             for (info, node_url) in nodes {
@@ -123,6 +123,8 @@ impl ClientInner {
             .write()
             .map_err(|_| crate::client::Error::PoisonError)? = healthy_nodes;
 
+        self.sync_issuer_node(ignore_node_health).await;
+
         Ok(())
     }
 }
@@ -0,0 +1,183 @@
+// Copyright 2024 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+//! Pinning of the TLS certificates or public keys of nodes, so a connection is rejected even if a compromised CA or
+//! DNS hands out a valid certificate for the host of the node.
+//!
+//! ```ignore
+//! let client = Client::builder()
+//!     .with_node("https://node.example.com")?
+//!     .with_tls_pin("https://node.example.com", TlsPin::PublicKey(spki_sha256))?
+//!     .finish()
+//!     .await?;
+//! ```
+
+use std::collections::BTreeMap;
+
+use crypto::hashes::sha::{Sha256, SHA256_LEN};
+use serde::{Deserialize, Serialize};
+
+use crate::utils::serde::prefix_hex_bytes;
+
+/// The pins of the nodes, keyed by the host of the node URL.
+pub type TlsPins = BTreeMap<String, Vec<TlsPin>>;
+
+/// A pin of the TLS certificate of a node. The certificate still has to be valid and issued by a trusted CA, but the
+/// connection is only accepted if it matches one of the pins of the host.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize)]
+#[serde(tag = "type", content = "sha256", rename_all = "camelCase")]
+pub enum TlsPin {
+    /// The SHA-256 hash of the DER encoded certificate, it has to be updated whenever the certificate is renewed.
+    Certificate(#[serde(with = "prefix_hex_bytes")] [u8; SHA256_LEN]),
+    /// The SHA-256 hash of the DER encoded subject public key info of the certificate, which stays the same when the
+    /// certificate is renewed with the same key. It's the same hash as the `pin-sha256` of HPKP.
+    PublicKey(#[serde(with = "prefix_hex_bytes")] [u8; SHA256_LEN]),
+}
+
+impl TlsPin {
+    /// Creates a pin of a DER encoded certificate.
+    pub fn certificate(certificate: &[u8]) -> Self {
+        Self::Certificate(sha256(certificate))
+    }
+
+    /// Creates a pin of the public key of a DER encoded certificate, returns `None` if the certificate can't be parsed.
+    pub fn public_key(certificate: &[u8]) -> Option<Self> {
+        subject_public_key_info(certificate).map(|spki| Self::PublicKey(sha256(spki)))
+    }
+
+    /// Returns whether a DER encoded certificate matches the pin.
+    pub fn matches(&self, certificate: &[u8]) -> bool {
+        match self {
+            Self::Certificate(hash) => sha256(certificate) == *hash,
+            Self::PublicKey(hash) => subject_public_key_info(certificate).is_some_and(|spki| sha256(spki) == *hash),
+        }
+    }
+}
+
+fn sha256(bytes: &[u8]) -> [u8; SHA256_LEN] {
+    use crypto::hashes::Digest;
+
+    Sha256::digest(bytes).into()
+}
+
+// The tag, the contents and the whole encoding of a DER element, followed by the input remaining after it
+type DerElement<'a> = (u8, &'a [u8], &'a [u8], &'a [u8]);
+
+// Splits the first DER element of the input into its tag, its contents, the whole element and the remaining input
+fn der_element(input: &[u8]) -> Option<DerElement<'_>> {
+    let (&tag, rest) = input.split_first()?;
+    let (&first_length_byte, rest) = rest.split_first()?;
+    let (length, rest) = if first_length_byte < 0x80 {
+        (first_length_byte as usize, rest)
+    } else {
+        let length_bytes = (first_length_byte & 0x7f) as usize;
+        if length_bytes == 0 || length_bytes > 4 || rest.len() < length_bytes {
+            return None;
+        }
+        let (length, rest) = rest.split_at(length_bytes);
+        (
+            length
+                .iter()
+                .fold(0usize, |length, byte| (length << 8) | *byte as usize),
+            rest,
+        )
+    };
+    if rest.len() < length {
+        return None;
+    }
+    let (contents, rest) = rest.split_at(length);
+    let element = &input[..input.len() - rest.len()];
+
+    Some((tag, contents, element, rest))
+}
+
+// Returns the DER encoded subject public key info of a certificate, which is the 7th field of the TBS certificate, or
+// the 6th one without the optional version
+fn subject_public_key_info(certificate: &[u8]) -> Option<&[u8]> {
+    const SEQUENCE: u8 = 0x30;
+    const VERSION: u8 = 0xa0;
+
+    let (SEQUENCE, certificate, _, _) = der_element(certificate)? else {
+        return None;
+    };
+    let (SEQUENCE, mut tbs_certificate, _, _) = der_element(certificate)? else {
+        return None;
+    };
+    if tbs_certificate.first() == Some(&VERSION) {
+        tbs_certificate = der_element(tbs_certificate)?.3;
+    }
+    // Serial number, signature algorithm, issuer, validity and subject
+    for _ in 0..5 {
+        tbs_certificate = der_element(tbs_certificate)?.3;
+    }
+    match der_element(tbs_certificate)? {
+        (SEQUENCE, _, spki, _) => Some(spki),
+        _ => None,
+    }
+}
+
+#[cfg(all(feature = "tls", not(target_family = "wasm")))]
+pub(crate) mod verifier {
+    use std::{sync::Arc, time::SystemTime};
+
+    use rustls::{
+        client::{ServerCertVerified, ServerCertVerifier, WebPkiVerifier},
+        Certificate, ClientConfig, OwnedTrustAnchor, RootCertStore, ServerName,
+    };
+
+    use super::TlsPins;
+
+    // Verifies the certificate like the default verifier of reqwest, and then checks it against the pins of the host
+    struct PinnedCertVerifier {
+        verifier: WebPkiVerifier,
+        pins: Arc<TlsPins>,
+    }
+
+    impl ServerCertVerifier for PinnedCertVerifier {
+        fn verify_server_cert(
+            &self,
+            end_entity: &Certificate,
+            intermediates: &[Certificate],
+            server_name: &ServerName,
+            scts: &mut dyn Iterator<Item = &[u8]>,
+            ocsp_response: &[u8],
+            now: SystemTime,
+        ) -> Result<ServerCertVerified, rustls::Error> {
+            let verified =
+                self.verifier
+                    .verify_server_cert(end_entity, intermediates, server_name, scts, ocsp_response, now)?;
+
+            let host = match server_name {
+                ServerName::DnsName(name) => name.as_ref().to_string(),
+                ServerName::IpAddress(ip) => ip.to_string(),
+                _ => return Err(rustls::Error::General("unsupported server name".to_string())),
+            };
+            if let Some(pins) = self.pins.get(&host) {
+                if !pins.iter().any(|pin| pin.matches(&end_entity.0)) {
+                    log::warn!("[TLS] certificate of {host} doesn't match its pins");
+                    return Err(rustls::Error::General(format!(
+                        "certificate of {host} doesn't match its pins"
+                    )));
+                }
+            }
+
+            Ok(verified)
+        }
+    }
+
+    // Creates the TLS config for reqwest, with the webpki roots reqwest uses by default
+    pub(crate) fn tls_config(pins: Arc<TlsPins>) -> ClientConfig {
+        let mut roots = RootCertStore::empty();
+        roots.add_trust_anchors(webpki_roots::TLS_SERVER_ROOTS.iter().map(|anchor| {
+            OwnedTrustAnchor::from_subject_spki_name_constraints(anchor.subject, anchor.spki, anchor.name_constraints)
+        }));
+
+        ClientConfig::builder()
+            .with_safe_defaults()
+            .with_custom_certificate_verifier(Arc::new(PinnedCertVerifier {
+                verifier: WebPkiVerifier::new(roots, None),
+                pins,
+            }))
+            .with_no_client_auth()
+    }
+}
@@ -0,0 +1,170 @@
+// Copyright 2024 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+//! Custom transports to send the HTTP requests of the client, e.g. over a unix domain socket or to an in-process test
+//! server instead of TCP.
+//!
+//! ```ignore
+//! let client = Client::builder()
+//!     .with_node("http://localhost")?
+//!     .with_transport(UnixSocketTransport::new("/run/hornet/api.sock"))
+//!     .finish()
+//!     .await?;
+//! ```
+
+use std::{fmt::Debug, sync::Arc, time::Duration};
+
+use async_trait::async_trait;
+use reqwest::{header::HeaderMap, Method};
+use url::Url;
+
+use crate::client::node_api::error::Result;
+
+/// An HTTP request of the client to a node, with the authentication and headers already applied.
+#[derive(Clone, Debug)]
+pub struct TransportRequest {
+    pub method: Method,
+    /// The URL of the node with the path and query of the route.
+    pub url: Url,
+    pub headers: HeaderMap,
+    pub body: Option<Vec<u8>>,
+    /// The timeout of the request, not set on wasm.
+    pub timeout: Option<Duration>,
+}
+
+impl TransportRequest {
+    pub(crate) fn from_reqwest(request: reqwest::Request) -> Self {
+        #[cfg(not(target_family = "wasm"))]
+        let timeout = request.timeout().copied();
+        #[cfg(target_family = "wasm")]
+        let timeout = None;
+
+        Self {
+            method: request.method().clone(),
+            url: request.url().clone(),
+            headers: request.headers().clone(),
+            body: request.body().and_then(|body| body.as_bytes()).map(<[u8]>::to_vec),
+            timeout,
+        }
+    }
+}
+
+/// The response of a node to a [`TransportRequest`].
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct TransportResponse {
+    pub status: u16,
+    pub body: Vec<u8>,
+}
+
+/// Sends the HTTP requests of the client instead of its default TCP connections, set with
+/// [`ClientBuilder::with_transport()`](crate::client::ClientBuilder::with_transport).
+///
+/// Responses with an unsuccessful status are turned into errors by the client, so a transport only fails if no
+/// response was received, with [`Error::Transport`](crate::client::node_api::error::Error::Transport).
+#[cfg_attr(target_family = "wasm", async_trait(?Send))]
+#[cfg_attr(not(target_family = "wasm"), async_trait)]
+pub trait HttpTransport: Debug + Send + Sync {
+    async fn send(&self, request: TransportRequest) -> Result<TransportResponse>;
+}
+
+/// A shared [`HttpTransport`], clones are equal.
+#[derive(Clone, Debug)]
+pub struct Transport(pub(crate) Arc<dyn HttpTransport>);
+
+impl Transport {
+    pub fn new(transport: impl HttpTransport + 'static) -> Self {
+        Self(Arc::new(transport))
+    }
+}
+
+impl<T: HttpTransport + 'static> From<T> for Transport {
+    fn from(transport: T) -> Self {
+        Self::new(transport)
+    }
+}
+
+// Only used to compare client options
+impl PartialEq for Transport {
+    fn eq(&self, other: &Self) -> bool {
+        Arc::ptr_eq(&self.0, &other.0)
+    }
+}
+
+impl Eq for Transport {}
+
+/// Sends the requests over a unix domain socket, e.g. to a node running on the same machine. The host of the node
+/// URLs is only used for the `Host` header.
+#[cfg(all(unix, feature = "unix_socket"))]
+#[cfg_attr(docsrs, doc(cfg(all(unix, feature = "unix_socket"))))]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct UnixSocketTransport {
+    path: std::path::PathBuf,
+}
+
+#[cfg(all(unix, feature = "unix_socket"))]
+impl UnixSocketTransport {
+    /// Creates a transport connecting to the unix domain socket at `path`.
+    pub fn new(path: impl Into<std::path::PathBuf>) -> Self {
+        Self { path: path.into() }
+    }
+
+    /// Returns the path of the socket.
+    pub fn path(&self) -> &std::path::Path {
+        &self.path
+    }
+
+    async fn send_request(&self, request: TransportRequest) -> Result<TransportResponse> {
+        use crate::client::node_api::error::Error;
+
+        let stream = tokio::net::UnixStream::connect(&self.path)
+            .await
+            .map_err(|e| Error::Transport(format!("couldn't connect to {}: {e}", self.path.display())))?;
+        let (mut sender, connection) = hyper::client::conn::handshake(stream)
+            .await
+            .map_err(|e| Error::Transport(e.to_string()))?;
+        tokio::spawn(async move {
+            if let Err(e) = connection.await {
+                log::debug!("[UnixSocketTransport] connection error: {e}");
+            }
+        });
+
+        let path_and_query = match request.url.query() {
+            Some(query) => format!("{}?{query}", request.url.path()),
+            None => request.url.path().to_string(),
+        };
+        let mut hyper_request = hyper::Request::builder()
+            .method(request.method)
+            .uri(path_and_query)
+            .header("host", request.url.host_str().unwrap_or("localhost"))
+            .body(hyper::Body::from(request.body.unwrap_or_default()))
+            .map_err(|e| Error::Transport(e.to_string()))?;
+        hyper_request.headers_mut().extend(request.headers);
+
+        let response = sender
+            .send_request(hyper_request)
+            .await
+            .map_err(|e| Error::Transport(e.to_string()))?;
+        let status = response.status().as_u16();
+        let body = hyper::body::to_bytes(response.into_body())
+            .await
+            .map_err(|e| Error::Transport(e.to_string()))?;
+
+        Ok(TransportResponse {
+            status,
+            body: body.to_vec(),
+        })
+    }
+}
+
+#[cfg(all(unix, feature = "unix_socket"))]
+#[async_trait]
+impl HttpTransport for UnixSocketTransport {
+    async fn send(&self, request: TransportRequest) -> Result<TransportResponse> {
+        match request.timeout {
+            Some(timeout) => tokio::time::timeout(timeout, self.send_request(request))
+                .await
+                .map_err(|_| crate::client::node_api::error::Error::Transport("request timed out".to_string()))?,
+            None => self.send_request(request).await,
+        }
+    }
+}
@@ -0,0 +1,93 @@
+// Copyright 2024 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+//! Deterministic child mnemonics derived from a root seed, as specified in
+//! <https://github.com/bitcoin/bips/blob/master/bip-0085.mediawiki>.
+
+use crypto::{
+    keys::bip39::{wordlist, Mnemonic},
+    macs::hmac::HMAC_SHA512,
+    signatures::secp256k1_ecdsa,
+};
+use zeroize::Zeroizing;
+
+use crate::client::{Error, MnemonicStrength, Result};
+
+const BIP85_PURPOSE: u32 = 83696968;
+const BIP39_APPLICATION: u32 = 39;
+const ENGLISH_LANGUAGE: u32 = 0;
+const HARDENED: u32 = 1 << 31;
+const ENTROPY_HMAC_KEY: &[u8] = b"bip-entropy-from-k";
+
+/// Returns the hardened BIP-32 chain of the child mnemonic with the given strength and index.
+pub(crate) fn child_mnemonic_chain(strength: MnemonicStrength, index: u32) -> Result<[u32; 5]> {
+    if index >= HARDENED {
+        return Err(Error::InvalidMnemonic(format!(
+            "child mnemonic index {index} is out of range, expected less than {HARDENED}"
+        )));
+    }
+
+    Ok([
+        BIP85_PURPOSE,
+        BIP39_APPLICATION,
+        ENGLISH_LANGUAGE,
+        strength.word_count() as u32,
+        index,
+    ]
+    .map(|segment| segment | HARDENED))
+}
+
+/// Encodes the entropy derived from the private key at the child mnemonic chain as a mnemonic.
+pub(crate) fn child_mnemonic_from_key(
+    private_key: &secp256k1_ecdsa::SecretKey,
+    strength: MnemonicStrength,
+) -> Result<Mnemonic> {
+    let mut entropy = Zeroizing::new([0u8; 64]);
+    HMAC_SHA512(private_key.to_bytes().as_ref(), ENTROPY_HMAC_KEY, &mut entropy);
+
+    wordlist::encode(&entropy[..strength.entropy_len()], &wordlist::ENGLISH)
+        .map_err(|e| Error::InvalidMnemonic(format!("{e:?}")))
+}
+
+#[cfg(test)]
+mod tests {
+    use crypto::keys::slip10::Slip10;
+
+    use super::*;
+
+    #[test]
+    fn bip85_test_vectors() {
+        // Master key of xprv9s21ZrQH143K2LBWUUQRFXhucrQqBpKdRRxNVq2zBqsx8HVqFk2uYo8kmbaLLHRdqtQpUm98uKfu3vca1LqdGhUtyoFnCNkfmXRyPXLjbKb
+        let mut extended_bytes = [0u8; 65];
+        extended_bytes[1..33].copy_from_slice(
+            &prefix_hex::decode::<[u8; 32]>("0x3f15e5d852dc2e9ba5e9fe189a8dd2e1547badef5b563bbe6579fc6807d80ed9")
+                .unwrap(),
+        );
+        extended_bytes[33..].copy_from_slice(
+            &prefix_hex::decode::<[u8; 32]>("0x1b67969d1ec69bdfeeae43213da8460ba34b92d0788c8f7bfcfa44906e8a589c")
+                .unwrap(),
+        );
+        let master = Slip10::<secp256k1_ecdsa::SecretKey>::try_from_extended_bytes(&extended_bytes).unwrap();
+
+        for (strength, expected) in [
+            (
+                MnemonicStrength::Words12,
+                "girl mad pet galaxy egg matter matrix prison refuse sense ordinary nose",
+            ),
+            (
+                MnemonicStrength::Words18,
+                "near account window bike charge season chef number sketch tomorrow excuse sniff circle vital hockey outdoor supply token",
+            ),
+            (
+                MnemonicStrength::Words24,
+                "puppy ocean match cereal symbol another shed magic wrap hammer bulb intact gadget divorce twin tonight reason outdoor destroy simple truth cigar social volcano",
+            ),
+        ] {
+            let child = master.derive(child_mnemonic_chain(strength, 0).unwrap().into_iter());
+            let mnemonic = child_mnemonic_from_key(&child.secret_key(), strength).unwrap();
+            assert_eq!(&**mnemonic, expected);
+        }
+
+        assert!(child_mnemonic_chain(MnemonicStrength::Words24, HARDENED).is_err());
+    }
+}
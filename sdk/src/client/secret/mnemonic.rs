@@ -8,7 +8,11 @@ use std::ops::Range;
 use async_trait::async_trait;
 use crypto::{
     hashes::{blake2b::Blake2b256, Digest},
-    keys::{bip39::Mnemonic, bip44::Bip44, slip10::Seed},
+    keys::{
+        bip39::{Mnemonic, Passphrase},
+        bip44::Bip44,
+        slip10::Seed,
+    },
     signatures::{
         ed25519,
         secp256k1_ecdsa::{self, EvmAddress},
@@ -18,7 +22,7 @@ use zeroize::Zeroizing;
 
 use super::{GenerateAddressOptions, SecretManage};
 use crate::{
-    client::{api::PreparedTransactionData, Client, Error},
+    client::{api::PreparedTransactionData, Client, Error, MnemonicStrength},
     types::block::{
         address::Ed25519Address, payload::transaction::TransactionPayload, signature::Ed25519Signature, unlock::Unlocks,
     },
@@ -143,6 +147,17 @@ impl MnemonicSecretManager {
         Ok(Self(Client::mnemonic_to_seed(mnemonic.into())?.into()))
     }
 
+    /// Create a new [`MnemonicSecretManager`] from a BIP-39 mnemonic in English and a BIP-39 passphrase, also known as
+    /// "25th word".
+    pub fn try_from_mnemonic_with_passphrase(
+        mnemonic: impl Into<Mnemonic>,
+        passphrase: impl Into<Passphrase>,
+    ) -> Result<Self, Error> {
+        Ok(Self(
+            Client::mnemonic_to_seed_with_passphrase(mnemonic.into(), passphrase.into())?.into(),
+        ))
+    }
+
     /// Create a new [`MnemonicSecretManager`] from a hex-encoded raw seed string.
     pub fn try_from_hex_seed(hex: impl Into<Zeroizing<String>>) -> Result<Self, Error> {
         let hex = hex.into();
@@ -150,6 +165,16 @@ impl MnemonicSecretManager {
         let seed = Seed::from_bytes(bytes.as_ref());
         Ok(Self(seed))
     }
+
+    /// Derives the child mnemonic with the given strength and index from the seed, as specified in BIP-85.
+    ///
+    /// For more information, see <https://github.com/bitcoin/bips/blob/master/bip-0085.mediawiki>.
+    pub fn derive_child_mnemonic(&self, index: u32, strength: MnemonicStrength) -> Result<Mnemonic, Error> {
+        let chain = super::bip85::child_mnemonic_chain(strength, index)?;
+        let child_key = self.0.derive::<secp256k1_ecdsa::SecretKey, _>(chain.into_iter());
+
+        super::bip85::child_mnemonic_from_key(&child_key.secret_key(), strength)
+    }
 }
 
 #[cfg(test)]
@@ -3,6 +3,7 @@
 
 //! Secret manager module enabling address generation and transaction essence signing.
 
+pub(crate) mod bip85;
 /// Module for ledger nano based secret management.
 #[cfg(feature = "ledger_nano")]
 #[cfg_attr(docsrs, doc(cfg(feature = "ledger_nano")))]
@@ -26,7 +27,10 @@ use std::{collections::HashMap, fmt, ops::Range, str::FromStr};
 
 use async_trait::async_trait;
 use crypto::{
-    keys::{bip39::Mnemonic, bip44::Bip44},
+    keys::{
+        bip39::{Mnemonic, Passphrase},
+        bip44::Bip44,
+    },
     signatures::secp256k1_ecdsa::{self, EvmAddress},
 };
 use serde::{de::DeserializeOwned, Deserialize, Serialize};
@@ -49,7 +53,7 @@ use crate::{
             transaction::validate_transaction_payload_length,
             verify_semantic, PreparedTransactionData,
         },
-        Error,
+        Error, MnemonicStrength,
     },
     types::block::{
         address::{Address, Ed25519Address},
@@ -241,6 +245,12 @@ pub enum SecretManagerDto {
     /// Mnemonic
     #[serde(alias = "mnemonic")]
     Mnemonic(Zeroizing<String>),
+    /// Mnemonic with a BIP-39 passphrase
+    #[serde(alias = "mnemonicWithPassphrase")]
+    MnemonicWithPassphrase {
+        mnemonic: Zeroizing<String>,
+        passphrase: Zeroizing<String>,
+    },
     /// Private Key
     #[cfg(feature = "private_key_secret_manager")]
     #[cfg_attr(docsrs, doc(cfg(feature = "private_key_secret_manager")))]
@@ -281,6 +291,10 @@ impl TryFrom<SecretManagerDto> for SecretManager {
                 Self::Mnemonic(MnemonicSecretManager::try_from_mnemonic(mnemonic.as_str().to_owned())?)
             }
 
+            SecretManagerDto::MnemonicWithPassphrase { mnemonic, passphrase } => Self::Mnemonic(
+                MnemonicSecretManager::try_from_mnemonic_with_passphrase(mnemonic.as_str().to_owned(), passphrase)?,
+            ),
+
             #[cfg(feature = "private_key_secret_manager")]
             SecretManagerDto::PrivateKey(private_key) => {
                 Self::PrivateKey(Box::new(PrivateKeySecretManager::try_from_hex(private_key)?))
@@ -507,6 +521,12 @@ impl SecretManagerConfig for SecretManager {
             SecretManagerDto::Mnemonic(mnemonic) => {
                 Self::Mnemonic(MnemonicSecretManager::try_from_mnemonic(mnemonic.as_str().to_owned())?)
             }
+            SecretManagerDto::MnemonicWithPassphrase { mnemonic, passphrase } => {
+                Self::Mnemonic(MnemonicSecretManager::try_from_mnemonic_with_passphrase(
+                    mnemonic.as_str().to_owned(),
+                    passphrase.clone(),
+                )?)
+            }
             #[cfg(feature = "private_key_secret_manager")]
             SecretManagerDto::PrivateKey(private_key) => {
                 Self::PrivateKey(Box::new(PrivateKeySecretManager::try_from_hex(private_key.to_owned())?))
@@ -522,10 +542,77 @@ impl SecretManager {
         Ok(Self::Mnemonic(MnemonicSecretManager::try_from_mnemonic(mnemonic)?))
     }
 
+    /// Tries to create a [`SecretManager`] from a mnemonic string and a BIP-39 passphrase.
+    pub fn try_from_mnemonic_with_passphrase(
+        mnemonic: impl Into<Mnemonic>,
+        passphrase: impl Into<Passphrase>,
+    ) -> crate::client::Result<Self> {
+        Ok(Self::Mnemonic(
+            MnemonicSecretManager::try_from_mnemonic_with_passphrase(mnemonic, passphrase)?,
+        ))
+    }
+
     /// Tries to create a [`SecretManager`] from a seed hex string.
     pub fn try_from_hex_seed(seed: impl Into<Zeroizing<String>>) -> crate::client::Result<Self> {
         Ok(Self::Mnemonic(MnemonicSecretManager::try_from_hex_seed(seed)?))
     }
+
+    /// Checks if a re-entered mnemonic and BIP-39 passphrase belong to the seed of this secret manager, so users can
+    /// confirm their backup without the stored mnemonic ever leaving the secret manager.
+    ///
+    /// Only the seed is stored, which can't be split into words, so the whole mnemonic must be re-entered. To confirm
+    /// single words right after generating a mnemonic, use [`crate::client::verify_mnemonic_words()`] instead.
+    pub async fn verify_backup(
+        &self,
+        mnemonic: impl Into<Mnemonic> + Send,
+        passphrase: impl Into<Passphrase> + Send,
+    ) -> crate::client::Result<bool> {
+        if matches!(self, Self::Placeholder) {
+            return Err(Error::PlaceholderSecretManager);
+        }
+        #[cfg(feature = "private_key_secret_manager")]
+        if matches!(self, Self::PrivateKey(_)) {
+            return Err(Error::SecretManagerMismatch);
+        }
+
+        let backup = MnemonicSecretManager::try_from_mnemonic_with_passphrase(mnemonic, passphrase)?;
+        // Seeds can't be read from all secret managers, so the first address of both is compared instead
+        let chain = Bip44::new(crate::client::constants::IOTA_COIN_TYPE);
+        let address_indexes = chain.address_index..chain.address_index + 1;
+        let expected = backup
+            .generate_ed25519_addresses(chain.coin_type, chain.account, address_indexes.clone(), None)
+            .await?;
+        let stored =
+            SecretManage::generate_ed25519_addresses(self, chain.coin_type, chain.account, address_indexes, None)
+                .await?;
+
+        Ok(expected == stored)
+    }
+
+    /// Derives the 24 words child mnemonic with the given index from the seed, as specified in BIP-85, so an app can
+    /// use its own mnemonic that's recoverable from the backup of this one.
+    pub async fn derive_child_mnemonic(&self, index: u32) -> crate::client::Result<Mnemonic> {
+        self.derive_child_mnemonic_with_strength(index, MnemonicStrength::default())
+            .await
+    }
+
+    /// Derives the child mnemonic with the given strength and index from the seed, as specified in BIP-85.
+    pub async fn derive_child_mnemonic_with_strength(
+        &self,
+        index: u32,
+        strength: MnemonicStrength,
+    ) -> crate::client::Result<Mnemonic> {
+        match self {
+            #[cfg(feature = "stronghold")]
+            Self::Stronghold(secret_manager) => secret_manager.derive_child_mnemonic(index, strength).await,
+            #[cfg(feature = "ledger_nano")]
+            Self::LedgerNano(_) => Err(Error::SecretManagerMismatch),
+            Self::Mnemonic(secret_manager) => secret_manager.derive_child_mnemonic(index, strength),
+            #[cfg(feature = "private_key_secret_manager")]
+            Self::PrivateKey(_) => Err(Error::SecretManagerMismatch),
+            Self::Placeholder => Err(Error::PlaceholderSecretManager),
+        }
+    }
 }
 
 pub(crate) async fn default_sign_transaction_essence<M: SecretManage>(
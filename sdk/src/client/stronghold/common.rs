@@ -22,6 +22,12 @@ pub(super) const SEED_RECORD_PATH: &[u8] = b"iota-wallet-seed";
 /// The value has been hard-coded historically.
 pub(super) const DERIVE_OUTPUT_RECORD_PATH: &[u8] = b"iota-wallet-derived";
 
+/// Stronghold record path prefix to SLIP-10 private keys cached during a derivation session.
+pub(super) const DERIVE_CACHE_RECORD_PATH: &[u8] = b"iota-wallet-derived-cache";
+
+/// Stronghold record path to the one-time key that wraps a derived BIP-85 private key to export it.
+pub(super) const CHILD_MNEMONIC_WRAP_KEY_RECORD_PATH: &[u8] = b"iota-wallet-child-mnemonic-wrap-key";
+
 /// The client path for the seed.
 ///
 /// The value has been hard-coded historically.
@@ -27,6 +27,9 @@ pub enum Error {
     /// No mnemonic has been stored into the Stronghold vault
     #[error("no mnemonic has been stored into the Stronghold vault")]
     MnemonicMissing,
+    /// No entry has been stored with this key into the Stronghold store
+    #[error("no entry has been stored with the key `{0}` into the Stronghold store")]
+    StoreEntryMissing(String),
     /// Procedure execution error from Stronghold
     #[error("stronghold reported a procedure error: {0}")]
     Procedure(#[from] iota_stronghold::procedures::ProcedureError),
@@ -0,0 +1,99 @@
+// Copyright 2024 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+//! Inspecting and pruning the content of a Stronghold snapshot.
+
+use iota_stronghold::Location;
+use serde::{Deserialize, Serialize};
+
+use super::{
+    common::{PRIVATE_DATA_CLIENT_PATH, SECRET_VAULT_PATH, SEED_RECORD_PATH},
+    Error, StrongholdAdapter,
+};
+
+/// The content of a Stronghold snapshot, without any secret.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct StrongholdInventory {
+    /// Whether a mnemonic has been stored in the secret vault.
+    pub mnemonic: bool,
+    /// The keys of the encrypted key-value store, sorted by namespace and key.
+    pub store_keys: Vec<StrongholdStoreKey>,
+}
+
+/// A key of the encrypted key-value store of a Stronghold snapshot.
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
+pub struct StrongholdStoreKey {
+    /// The Stronghold client the store belongs to.
+    pub namespace: String,
+    /// The key of the entry.
+    pub key: String,
+}
+
+impl StrongholdAdapter {
+    /// Lists what is stored in Stronghold: if a mnemonic is present and the keys of the key-value store.
+    pub async fn inventory(&self) -> Result<StrongholdInventory, Error> {
+        // The key needs to be supplied first.
+        if !self.is_key_available().await {
+            return Err(Error::KeyCleared);
+        }
+
+        let stronghold_client = self.stronghold.lock().await.get_client(PRIVATE_DATA_CLIENT_PATH)?;
+        let namespace = String::from_utf8_lossy(PRIVATE_DATA_CLIENT_PATH).into_owned();
+
+        let mnemonic = stronghold_client.record_exists(&Location::generic(SECRET_VAULT_PATH, SEED_RECORD_PATH))?;
+        let mut store_keys = stronghold_client
+            .store()
+            .keys()?
+            .into_iter()
+            .map(|key| StrongholdStoreKey {
+                namespace: namespace.clone(),
+                key: String::from_utf8_lossy(&key).into_owned(),
+            })
+            .collect::<Vec<_>>();
+        store_keys.sort();
+
+        Ok(StrongholdInventory { mnemonic, store_keys })
+    }
+
+    /// Deletes the stored mnemonic and persists Stronghold to the disk.
+    ///
+    /// Addresses can't be generated and transactions can't be signed anymore, unless a mnemonic is stored again.
+    pub async fn delete_mnemonic(&self) -> Result<(), Error> {
+        // The key needs to be supplied first.
+        if !self.is_key_available().await {
+            return Err(Error::KeyCleared);
+        }
+
+        let stronghold_client = self.stronghold.lock().await.get_client(PRIVATE_DATA_CLIENT_PATH)?;
+        if !stronghold_client.record_exists(&Location::generic(SECRET_VAULT_PATH, SEED_RECORD_PATH))? {
+            return Err(Error::MnemonicMissing);
+        }
+        stronghold_client
+            .vault(SECRET_VAULT_PATH)
+            .delete_secret(SEED_RECORD_PATH)?;
+
+        self.write_stronghold_snapshot(None).await
+    }
+
+    /// Deletes an entry of the key-value store and persists Stronghold to the disk.
+    pub async fn delete_store_entry(&self, key: &str) -> Result<(), Error> {
+        // The key needs to be supplied first.
+        if !self.is_key_available().await {
+            return Err(Error::KeyCleared);
+        }
+
+        let deleted = self
+            .stronghold
+            .lock()
+            .await
+            .get_client(PRIVATE_DATA_CLIENT_PATH)?
+            .store()
+            .delete(key.as_bytes())?;
+        if deleted.is_none() {
+            return Err(Error::StoreEntryMissing(key.to_owned()));
+        }
+
+        self.write_stronghold_snapshot(None).await
+    }
+}
@@ -48,12 +48,14 @@
 
 mod common;
 mod error;
+mod inventory;
 mod migration;
 mod secret;
 mod storage;
 
 use alloc::sync::Weak;
 use std::{
+    collections::HashSet,
     path::{Path, PathBuf},
     sync::Arc,
     time::Duration,
@@ -68,7 +70,10 @@ use tokio::{
 use zeroize::Zeroizing;
 
 pub(crate) use self::common::PRIVATE_DATA_CLIENT_PATH;
-pub use self::error::Error;
+pub use self::{
+    error::Error,
+    inventory::{StrongholdInventory, StrongholdStoreKey},
+};
 use super::{storage::StorageAdapter, utils::Password};
 
 /// A wrapper on [Stronghold].
@@ -99,6 +104,11 @@ pub struct StrongholdAdapter {
     /// A handle to the timeout task.
     timeout_task: Arc<Mutex<Option<TaskHandle>>>,
 
+    /// The record paths of the keys cached in the vault if a derivation session has been started.
+    ///
+    /// See [`start_derivation_session()`](Self::start_derivation_session()).
+    derivation_cache: Arc<Mutex<Option<HashSet<Vec<u8>>>>>,
+
     /// The path to the corresponding Stronghold snapshot file.
     pub(crate) snapshot_path: PathBuf,
 }
@@ -224,6 +234,7 @@ impl StrongholdAdapterBuilder {
             key_provider,
             timeout: self.timeout,
             timeout_task,
+            derivation_cache: Arc::new(Mutex::new(None)),
             snapshot_path: snapshot_path.as_ref().to_path_buf(),
         })
     }
@@ -495,7 +506,8 @@ impl StrongholdAdapter {
     /// Persist Stronghold to a snapshot at a provided `snapshot_path` or at the Stronghold's own `snapshot_path` if
     /// None.
     ///
-    /// It doesn't unload the snapshot; see also [`unload_stronghold_snapshot()`].
+    /// It doesn't unload the snapshot; see also [`unload_stronghold_snapshot()`]. Keys cached by a derivation session
+    /// are deleted beforehand, the session itself stays active.
     ///
     /// [`unload_stronghold_snapshot()`]: Self::unload_stronghold_snapshot()
     pub async fn write_stronghold_snapshot(&self, snapshot_path: Option<&Path>) -> Result<(), Error> {
@@ -517,6 +529,9 @@ impl StrongholdAdapter {
             return Err(Error::KeyCleared);
         };
 
+        // Keys cached by a derivation session must never be persisted.
+        self.purge_derivation_cache().await?;
+
         self.stronghold.lock().await.commit_with_keyprovider(
             &SnapshotPath::from_path(snapshot_path.unwrap_or(&self.snapshot_path)),
             key_provider,
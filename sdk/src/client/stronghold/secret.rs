@@ -4,10 +4,11 @@
 //! The [SecretManage] implementation for [StrongholdAdapter].
 
 use core::borrow::Borrow;
-use std::ops::Range;
+use std::{collections::HashSet, ops::Range};
 
 use async_trait::async_trait;
 use crypto::{
+    ciphers::aes_kw::Aes256Kw,
     hashes::{blake2b::Blake2b256, Digest},
     keys::{
         bip39::{Mnemonic, MnemonicRef, Passphrase},
@@ -24,16 +25,21 @@ use iota_stronghold::{
     procedures::{self, Curve, KeyType, Slip10DeriveInput},
     Location,
 };
+use zeroize::Zeroizing;
 
 use super::{
-    common::{DERIVE_OUTPUT_RECORD_PATH, PRIVATE_DATA_CLIENT_PATH, SECRET_VAULT_PATH, SEED_RECORD_PATH},
+    common::{
+        CHILD_MNEMONIC_WRAP_KEY_RECORD_PATH, DERIVE_CACHE_RECORD_PATH, DERIVE_OUTPUT_RECORD_PATH,
+        PRIVATE_DATA_CLIENT_PATH, SECRET_VAULT_PATH, SEED_RECORD_PATH,
+    },
     StrongholdAdapter,
 };
 use crate::{
     client::{
         api::PreparedTransactionData,
-        secret::{types::StrongholdDto, GenerateAddressOptions, SecretManage, SecretManagerConfig},
+        secret::{bip85, types::StrongholdDto, GenerateAddressOptions, SecretManage, SecretManagerConfig},
         stronghold::Error,
+        MnemonicStrength,
     },
     types::block::{
         address::Ed25519Address, payload::transaction::TransactionPayload, signature::Ed25519Signature, unlock::Unlocks,
@@ -67,41 +73,102 @@ impl SecretManage for StrongholdAdapter {
         let mut addresses = Vec::new();
         let internal = options.into().map(|o| o.internal).unwrap_or_default();
 
+        // During a derivation session, the key of the change level is derived from the seed once and cached, so only
+        // the last, address index, segment has to be derived per address. The derived keys are then written to the
+        // same cached record instead of being deleted one by one, which is costly.
+        let mut derivation_cache = self.derivation_cache.lock().await;
+        let (derive_input, skipped_segments, session_location) =
+            if let Some(derivation_cache) = derivation_cache.as_mut() {
+                let chain = Bip44::new(coin_type)
+                    .with_account(account_index)
+                    .with_change(internal as _)
+                    .to_chain::<ed25519::SecretKey>();
+                let parent_chain = &chain[..chain.len() - 1];
+                let parent_location = Location::generic(
+                    SECRET_VAULT_PATH,
+                    [
+                        DERIVE_CACHE_RECORD_PATH,
+                        &parent_chain.iter().flat_map(|seg| seg.ser32()).collect::<Vec<u8>>(),
+                    ]
+                    .concat(),
+                );
+
+                // The cached key may be gone if Stronghold has been purged or reloaded in the meantime.
+                if !self
+                    .stronghold
+                    .lock()
+                    .await
+                    .get_client(PRIVATE_DATA_CLIENT_PATH)
+                    .map_err(Error::from)?
+                    .record_exists(&parent_location)
+                    .map_err(Error::from)?
+                {
+                    self.slip10_derive_chain(
+                        Curve::Ed25519,
+                        parent_chain.iter().copied().map(Into::into).collect(),
+                        seed_location,
+                        parent_location.clone(),
+                    )
+                    .await?;
+                    derivation_cache.insert(parent_location.record_path().to_vec());
+                }
+
+                let session_location = Location::generic(
+                    SECRET_VAULT_PATH,
+                    [DERIVE_CACHE_RECORD_PATH, DERIVE_OUTPUT_RECORD_PATH].concat(),
+                );
+                derivation_cache.insert(session_location.record_path().to_vec());
+
+                (
+                    Slip10DeriveInput::Key(parent_location),
+                    parent_chain.len(),
+                    Some(session_location),
+                )
+            } else {
+                (seed_location, 0, None)
+            };
+
         for address_index in address_indexes {
             let chain = Bip44::new(coin_type)
                 .with_account(account_index)
                 .with_change(internal as _)
-                .with_address_index(address_index);
-
-            let derive_location = Location::generic(
-                SECRET_VAULT_PATH,
-                [
-                    DERIVE_OUTPUT_RECORD_PATH,
-                    &chain
-                        .to_chain::<ed25519::SecretKey>()
-                        .into_iter()
-                        .flat_map(|seg| seg.ser32())
-                        .collect::<Vec<u8>>(),
-                ]
-                .concat(),
-            );
+                .with_address_index(address_index)
+                .to_chain::<ed25519::SecretKey>();
+
+            let derive_location = session_location.clone().unwrap_or_else(|| {
+                Location::generic(
+                    SECRET_VAULT_PATH,
+                    [
+                        DERIVE_OUTPUT_RECORD_PATH,
+                        &chain.iter().flat_map(|seg| seg.ser32()).collect::<Vec<u8>>(),
+                    ]
+                    .concat(),
+                )
+            });
 
             // Derive a SLIP-10 private key in the vault.
-            self.slip10_derive(Curve::Ed25519, chain, seed_location.clone(), derive_location.clone())
-                .await?;
+            self.slip10_derive_chain(
+                Curve::Ed25519,
+                chain[skipped_segments..].iter().copied().map(Into::into).collect(),
+                derive_input.clone(),
+                derive_location.clone(),
+            )
+            .await?;
 
             // Get the Ed25519 public key from the derived SLIP-10 private key in the vault.
             let public_key = self.ed25519_public_key(derive_location.clone()).await?;
 
-            // Cleanup location afterwards
-            self.stronghold
-                .lock()
-                .await
-                .get_client(PRIVATE_DATA_CLIENT_PATH)
-                .map_err(Error::from)?
-                .vault(SECRET_VAULT_PATH)
-                .delete_secret(derive_location.record_path())
-                .map_err(Error::from)?;
+            // Cleanup location afterwards, unless it's cached by a derivation session
+            if session_location.is_none() {
+                self.stronghold
+                    .lock()
+                    .await
+                    .get_client(PRIVATE_DATA_CLIENT_PATH)
+                    .map_err(Error::from)?
+                    .vault(SECRET_VAULT_PATH)
+                    .delete_secret(derive_location.record_path())
+                    .map_err(Error::from)?;
+            }
 
             // Hash the public key to get the address.
             let hash = Blake2b256::digest(public_key);
@@ -324,6 +391,27 @@ impl SecretManagerConfig for StrongholdAdapter {
 
 /// Private methods for the secret manager implementation.
 impl StrongholdAdapter {
+    /// Delete the keys cached by a derivation session from the vault.
+    pub(super) async fn purge_derivation_cache(&self) -> Result<(), Error> {
+        let mut derivation_cache = self.derivation_cache.lock().await;
+        let Some(record_paths) = derivation_cache.as_mut() else {
+            return Ok(());
+        };
+        if record_paths.is_empty() {
+            return Ok(());
+        }
+
+        let stronghold_client = self.stronghold.lock().await.get_client(PRIVATE_DATA_CLIENT_PATH)?;
+        for record_path in record_paths.drain() {
+            // The record may already be gone if Stronghold has been purged or reloaded in the meantime.
+            if stronghold_client.record_exists(&Location::generic(SECRET_VAULT_PATH, record_path.clone()))? {
+                stronghold_client.vault(SECRET_VAULT_PATH).delete_secret(record_path)?;
+            }
+        }
+
+        Ok(())
+    }
+
     /// Execute [BIP39Recover](procedures::BIP39Recover) procedure in Stronghold to put a mnemonic into the Stronghold
     /// vault.
     async fn bip39_recover(&self, mnemonic: Mnemonic, passphrase: Passphrase, output: Location) -> Result<(), Error> {
@@ -357,6 +445,18 @@ impl StrongholdAdapter {
                 .collect(),
             Curve::Secp256k1 => chain.to_chain::<secp256k1_ecdsa::SecretKey>().to_vec(),
         };
+        self.slip10_derive_chain(curve, chain, input, output).await
+    }
+
+    /// Execute [Slip10Derive](procedures::Slip10Derive) procedure in Stronghold to derive a SLIP-10 private key along
+    /// raw `chain` segments in the Stronghold vault.
+    async fn slip10_derive_chain(
+        &self,
+        curve: Curve,
+        chain: Vec<u32>,
+        input: Slip10DeriveInput,
+        output: Location,
+    ) -> Result<(), Error> {
         if let Err(err) = self
             .stronghold
             .lock()
@@ -456,8 +556,41 @@ impl StrongholdAdapter {
         Ok(secp256k1_ecdsa::PublicKey::try_from_slice(&bytes)?)
     }
 
+    /// Start a derivation session to speed up the generation of many Ed25519 addresses, e.g. when restoring accounts.
+    ///
+    /// Until the session is ended, the private key of each account and change level is derived from the seed once
+    /// and cached in the vault, instead of deriving the whole chain for every address, and the private keys of the
+    /// addresses are overwritten instead of being deleted one by one. Cached keys are deleted before a snapshot is
+    /// written, so they're never persisted.
+    pub async fn start_derivation_session(&self) {
+        self.derivation_cache.lock().await.get_or_insert_with(HashSet::new);
+    }
+
+    /// End a derivation session, deleting the cached keys from the vault.
+    pub async fn end_derivation_session(&self) -> Result<(), Error> {
+        self.purge_derivation_cache().await?;
+        self.derivation_cache.lock().await.take();
+
+        Ok(())
+    }
+
+    /// Check if a derivation session has been started.
+    pub async fn is_derivation_session_active(&self) -> bool {
+        self.derivation_cache.lock().await.is_some()
+    }
+
     /// Store a mnemonic into the Stronghold vault.
     pub async fn store_mnemonic(&self, mnemonic: impl Borrow<MnemonicRef> + Send) -> Result<(), Error> {
+        self.store_mnemonic_with_passphrase(mnemonic, Passphrase::default())
+            .await
+    }
+
+    /// Store a mnemonic with a BIP-39 passphrase, also known as "25th word", into the Stronghold vault.
+    pub async fn store_mnemonic_with_passphrase(
+        &self,
+        mnemonic: impl Borrow<MnemonicRef> + Send,
+        passphrase: impl Into<Passphrase> + Send,
+    ) -> Result<(), Error> {
         // The key needs to be supplied first.
         if self.key_provider.lock().await.is_none() {
             return Err(Error::KeyCleared);
@@ -485,14 +618,82 @@ impl StrongholdAdapter {
         }
 
         // Execute the BIP-39 recovery procedure to put it into the vault (in memory).
-        self.bip39_recover(trimmed_mnemonic, Passphrase::default(), output)
-            .await?;
+        self.bip39_recover(trimmed_mnemonic, passphrase.into(), output).await?;
 
         // Persist Stronghold to the disk
         self.write_stronghold_snapshot(None).await?;
 
         Ok(())
     }
+
+    /// Derives the child mnemonic with the given strength and index from the stored seed, as specified in BIP-85.
+    ///
+    /// For more information, see <https://github.com/bitcoin/bips/blob/master/bip-0085.mediawiki>.
+    pub async fn derive_child_mnemonic(
+        &self,
+        index: u32,
+        strength: MnemonicStrength,
+    ) -> Result<Mnemonic, crate::client::Error> {
+        if !self.is_key_available().await {
+            return Err(Error::KeyCleared.into());
+        }
+
+        // Stronghold arguments.
+        let seed_location = Slip10DeriveInput::Seed(Location::generic(SECRET_VAULT_PATH, SEED_RECORD_PATH));
+        let derive_location = Location::generic(SECRET_VAULT_PATH, DERIVE_OUTPUT_RECORD_PATH);
+        let wrap_key_location = Location::generic(SECRET_VAULT_PATH, CHILD_MNEMONIC_WRAP_KEY_RECORD_PATH);
+
+        // Derive the BIP-85 private key in the vault.
+        let chain = bip85::child_mnemonic_chain(strength, index)?;
+        self.slip10_derive_chain(Curve::Secp256k1, chain.to_vec(), seed_location, derive_location.clone())
+            .await?;
+
+        // Stronghold never returns secrets, but the entropy of the child mnemonic is derived outside of the vault from
+        // the private key. So the private key is exported by wrapping it with a one-time key that is known here.
+        let mut wrap_key = Zeroizing::new(vec![0u8; 32]);
+        crypto::utils::rand::fill(wrap_key.as_mut()).map_err(Error::from)?;
+        let wrapped_key = {
+            let stronghold_client = self
+                .stronghold
+                .lock()
+                .await
+                .get_client(PRIVATE_DATA_CLIENT_PATH)
+                .map_err(Error::from)?;
+            let vault = stronghold_client.vault(SECRET_VAULT_PATH);
+            vault
+                .write_secret(wrap_key_location.clone(), wrap_key.clone())
+                .map_err(Error::from)?;
+            let wrapped_key = stronghold_client.execute_procedure(procedures::AesKeyWrapEncrypt {
+                cipher: procedures::AesKeyWrapCipher::Aes256,
+                encryption_key: wrap_key_location.clone(),
+                wrap_key: derive_location.clone(),
+            });
+
+            // Cleanup locations afterwards
+            vault
+                .delete_secret(wrap_key_location.record_path())
+                .map_err(Error::from)?;
+            vault
+                .delete_secret(derive_location.record_path())
+                .map_err(Error::from)?;
+
+            wrapped_key.map_err(Error::from)?
+        };
+
+        // The extended key is stored without the leading zero byte, so the private key comes first.
+        let mut extended_key = Zeroizing::new(vec![0u8; wrapped_key.len() - Aes256Kw::BLOCK]);
+        Aes256Kw::new(&wrap_key)
+            .unwrap_key(&wrapped_key, &mut extended_key)
+            .map_err(Error::from)?;
+        let private_key = secp256k1_ecdsa::SecretKey::try_from_bytes(
+            extended_key[..secp256k1_ecdsa::SecretKey::LENGTH]
+                .try_into()
+                .expect("the extended key is longer than a private key"),
+        )
+        .map_err(Error::from)?;
+
+        bip85::child_mnemonic_from_key(&private_key, strength)
+    }
 }
 
 #[cfg(test)]
@@ -539,6 +740,60 @@ mod tests {
         std::fs::remove_file(stronghold_path).ok();
     }
 
+    #[tokio::test]
+    async fn test_derivation_session() {
+        let stronghold_path = "test_derivation_session.stronghold";
+        // Remove potential old stronghold file
+        std::fs::remove_file(stronghold_path).ok();
+        let mnemonic = Mnemonic::from(
+            "giant dynamic museum toddler six deny defense ostrich bomb access mercy blood explain muscle shoot shallow glad autumn author calm heavy hawk abuse rally".to_owned(),
+        );
+        let stronghold_adapter = StrongholdAdapter::builder()
+            .password("drowssap".to_owned())
+            .build(stronghold_path)
+            .unwrap();
+        stronghold_adapter.store_mnemonic(mnemonic).await.unwrap();
+
+        let options = GenerateAddressOptions::internal();
+        let addresses = stronghold_adapter
+            .generate_ed25519_addresses(IOTA_COIN_TYPE, 0, 0..10, options)
+            .await
+            .unwrap();
+
+        stronghold_adapter.start_derivation_session().await;
+        assert!(stronghold_adapter.is_derivation_session_active().await);
+        // The same addresses are generated with the cached key
+        for range in [0..10, 5..10, 0..10] {
+            assert_eq!(
+                stronghold_adapter
+                    .generate_ed25519_addresses(IOTA_COIN_TYPE, 0, range.clone(), options)
+                    .await
+                    .unwrap(),
+                addresses[range.start as usize..range.end as usize]
+            );
+        }
+        // The key of the change level and the one of the last address
+        let cached_keys = |adapter: &StrongholdAdapter| adapter.derivation_cache.try_lock().unwrap().clone().unwrap();
+        assert_eq!(cached_keys(&stronghold_adapter).len(), 2);
+
+        // The cached key isn't persisted, but is derived again when needed
+        stronghold_adapter.write_stronghold_snapshot(None).await.unwrap();
+        assert!(cached_keys(&stronghold_adapter).is_empty());
+        assert_eq!(
+            stronghold_adapter
+                .generate_ed25519_addresses(IOTA_COIN_TYPE, 0, 0..10, options)
+                .await
+                .unwrap(),
+            addresses
+        );
+
+        stronghold_adapter.end_derivation_session().await.unwrap();
+        assert!(!stronghold_adapter.is_derivation_session_active().await);
+
+        // Remove garbage after test, but don't care about the result
+        std::fs::remove_file(stronghold_path).ok();
+    }
+
     #[tokio::test]
     async fn test_evm_address_generation() {
         let stronghold_path = "test_evm_address_generation.stronghold";
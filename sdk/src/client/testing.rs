@@ -0,0 +1,302 @@
+// Copyright 2024 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+//! Helpers to run integration tests against a local private tangle.
+//!
+//! A [`NodeHarness`] starts the network, waits until the node and the faucet are healthy, creates clients connected to
+//! it and funds addresses. The network is torn down when the harness is stopped or dropped.
+//!
+//! ```ignore
+//! // Uses the private tangle of the hornet repository if `IOTA_PRIVATE_TANGLE_DIR` is set, otherwise an already
+//! // running network
+//! let harness = NodeHarness::start(NodeHarnessOptions::from_env()).await?;
+//! let client = harness.client().await?;
+//! harness.fund(&address).await?;
+//! ```
+
+use std::{
+    path::PathBuf,
+    process::Command,
+    time::{Duration, Instant},
+};
+
+use crate::{
+    client::{Client, Error, FaucetClient, Result},
+    types::block::{address::Bech32Address, output::OutputId},
+};
+
+/// The default URL of the node of a private tangle.
+pub const PRIVATE_TANGLE_NODE_URL: &str = "http://localhost:14265";
+/// The default URL of the faucet enqueue endpoint of a private tangle.
+pub const PRIVATE_TANGLE_FAUCET_URL: &str = "http://localhost:8091/api/enqueue";
+/// The environment variable with the path of the `private_tangle` directory of the hornet repository.
+pub const PRIVATE_TANGLE_DIR_ENV: &str = "IOTA_PRIVATE_TANGLE_DIR";
+
+const HEALTH_POLL_INTERVAL: Duration = Duration::from_secs(1);
+
+/// How the network of a [`NodeHarness`] is started and stopped.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum NodeLauncher {
+    /// The `private_tangle` directory of the hornet repository, started with docker by its scripts.
+    Docker { private_tangle_dir: PathBuf },
+    /// Commands run in a directory, e.g. to start prebuilt node and faucet binaries. Each start and stop command is
+    /// split into the program and its arguments at whitespaces.
+    Commands {
+        working_dir: PathBuf,
+        start: Vec<String>,
+        stop: Vec<String>,
+    },
+    /// A network which is already running and isn't started or stopped by the harness.
+    External,
+}
+
+/// Options for a [`NodeHarness`].
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct NodeHarnessOptions {
+    /// How the network is started and stopped.
+    pub launcher: NodeLauncher,
+    /// The URL of the node.
+    pub node_url: String,
+    /// The URL of the faucet enqueue endpoint.
+    pub faucet_url: String,
+    /// The time to wait until the node and the faucet are healthy.
+    pub startup_timeout: Duration,
+}
+
+impl Default for NodeHarnessOptions {
+    fn default() -> Self {
+        Self {
+            launcher: NodeLauncher::External,
+            node_url: PRIVATE_TANGLE_NODE_URL.to_string(),
+            faucet_url: PRIVATE_TANGLE_FAUCET_URL.to_string(),
+            startup_timeout: Duration::from_secs(120),
+        }
+    }
+}
+
+impl NodeHarnessOptions {
+    /// Uses the private tangle in the directory of the `IOTA_PRIVATE_TANGLE_DIR` environment variable, or an already
+    /// running network if it isn't set.
+    pub fn from_env() -> Self {
+        let launcher =
+            std::env::var_os(PRIVATE_TANGLE_DIR_ENV).map_or(NodeLauncher::External, |dir| NodeLauncher::Docker {
+                private_tangle_dir: dir.into(),
+            });
+        Self {
+            launcher,
+            ..Default::default()
+        }
+    }
+
+    /// Sets how the network is started and stopped.
+    pub fn with_launcher(mut self, launcher: NodeLauncher) -> Self {
+        self.launcher = launcher;
+        self
+    }
+
+    /// Sets the URL of the node.
+    pub fn with_node_url(mut self, node_url: impl Into<String>) -> Self {
+        self.node_url = node_url.into();
+        self
+    }
+
+    /// Sets the URL of the faucet enqueue endpoint.
+    pub fn with_faucet_url(mut self, faucet_url: impl Into<String>) -> Self {
+        self.faucet_url = faucet_url.into();
+        self
+    }
+
+    /// Sets the time to wait until the node and the faucet are healthy.
+    pub fn with_startup_timeout(mut self, startup_timeout: Duration) -> Self {
+        self.startup_timeout = startup_timeout;
+        self
+    }
+}
+
+/// A local test network, see the [module documentation](self).
+#[derive(Debug)]
+pub struct NodeHarness {
+    options: NodeHarnessOptions,
+    faucet: FaucetClient,
+    running: bool,
+}
+
+impl NodeHarness {
+    /// Starts the network and waits until the node and the faucet are healthy.
+    pub async fn start(options: NodeHarnessOptions) -> Result<Self> {
+        let faucet = FaucetClient::new(&options.faucet_url)?;
+        let mut harness = Self {
+            options,
+            faucet,
+            running: false,
+        };
+
+        for command in harness.start_commands() {
+            run_command(command).await?;
+            harness.running = true;
+        }
+        harness.wait_until_healthy().await?;
+
+        Ok(harness)
+    }
+
+    /// Returns the options of the harness.
+    pub fn options(&self) -> &NodeHarnessOptions {
+        &self.options
+    }
+
+    /// Creates a client connected to the node.
+    pub async fn client(&self) -> Result<Client> {
+        Client::builder().with_node(&self.options.node_url)?.finish().await
+    }
+
+    /// Returns a client for the faucet.
+    pub fn faucet(&self) -> &FaucetClient {
+        &self.faucet
+    }
+
+    /// Requests funds from the faucet and waits until they arrived, returns the ids of the new outputs.
+    pub async fn fund(&self, address: &Bech32Address) -> Result<Vec<OutputId>> {
+        let client = self.client().await?;
+        self.faucet.request_funds_and_wait(&client, address, None).await
+    }
+
+    /// Stops the network, if it was started by the harness.
+    pub async fn stop(mut self) -> Result<()> {
+        let commands = self.stop_commands();
+        self.running = false;
+        for command in commands {
+            run_command(command).await?;
+        }
+        Ok(())
+    }
+
+    fn start_commands(&self) -> Vec<(PathBuf, Vec<String>)> {
+        match &self.options.launcher {
+            NodeLauncher::Docker { private_tangle_dir } => ["./cleanup.sh", "./bootstrap.sh", "./run.sh -d"]
+                .into_iter()
+                .map(|command| (private_tangle_dir.clone(), split_command(command)))
+                .collect(),
+            NodeLauncher::Commands { working_dir, start, .. } => start
+                .iter()
+                .map(|command| (working_dir.clone(), split_command(command)))
+                .collect(),
+            NodeLauncher::External => Vec::new(),
+        }
+    }
+
+    fn stop_commands(&self) -> Vec<(PathBuf, Vec<String>)> {
+        if !self.running {
+            return Vec::new();
+        }
+        match &self.options.launcher {
+            NodeLauncher::Docker { private_tangle_dir } => {
+                vec![(private_tangle_dir.clone(), split_command("./cleanup.sh"))]
+            }
+            NodeLauncher::Commands { working_dir, stop, .. } => stop
+                .iter()
+                .map(|command| (working_dir.clone(), split_command(command)))
+                .collect(),
+            NodeLauncher::External => Vec::new(),
+        }
+    }
+
+    async fn wait_until_healthy(&self) -> Result<()> {
+        let http_client = reqwest::Client::new();
+        let node_health = format!("{}/health", self.options.node_url.trim_end_matches('/'));
+        let faucet_info = self.faucet.url().join("info")?;
+        let deadline = Instant::now() + self.options.startup_timeout;
+
+        loop {
+            let node_healthy = is_success(&http_client, node_health.as_str()).await;
+            if node_healthy && is_success(&http_client, faucet_info.as_str()).await {
+                log::debug!("[NodeHarness] node and faucet are healthy");
+                return Ok(());
+            }
+            if Instant::now() >= deadline {
+                return Err(Error::NodeHarness(format!(
+                    "node or faucet not healthy after {} seconds",
+                    self.options.startup_timeout.as_secs()
+                )));
+            }
+            tokio::time::sleep(HEALTH_POLL_INTERVAL).await;
+        }
+    }
+}
+
+impl Drop for NodeHarness {
+    fn drop(&mut self) {
+        for (working_dir, command) in self.stop_commands() {
+            if let Err(e) = run_command_blocking(&working_dir, &command) {
+                log::warn!("[NodeHarness] failed to stop the network: {e}");
+            }
+        }
+    }
+}
+
+fn split_command(command: &str) -> Vec<String> {
+    command.split_whitespace().map(ToString::to_string).collect()
+}
+
+async fn is_success(http_client: &reqwest::Client, url: &str) -> bool {
+    http_client
+        .get(url)
+        .send()
+        .await
+        .is_ok_and(|response| response.status().is_success())
+}
+
+async fn run_command((working_dir, command): (PathBuf, Vec<String>)) -> Result<()> {
+    tokio::task::spawn_blocking(move || run_command_blocking(&working_dir, &command)).await?
+}
+
+fn run_command_blocking(working_dir: &std::path::Path, command: &[String]) -> Result<()> {
+    let (program, args) = command
+        .split_first()
+        .ok_or_else(|| Error::NodeHarness("empty command".to_string()))?;
+    log::debug!("[NodeHarness] running {command:?} in {}", working_dir.display());
+
+    let status = Command::new(program)
+        .args(args)
+        .current_dir(working_dir)
+        .status()
+        .map_err(|e| Error::NodeHarness(format!("failed to run {program}: {e}")))?;
+    if !status.success() {
+        return Err(Error::NodeHarness(format!("{program} exited with {status}")));
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+
+    #[test]
+    fn commands_are_split() {
+        let mut harness = NodeHarness {
+            options: NodeHarnessOptions::default().with_launcher(NodeLauncher::Commands {
+                working_dir: PathBuf::from("network"),
+                start: vec!["echo start node".to_string()],
+                stop: vec!["echo stop node".to_string(), "echo stop faucet".to_string()],
+            }),
+            faucet: FaucetClient::new(PRIVATE_TANGLE_FAUCET_URL).unwrap(),
+            running: true,
+        };
+
+        let command = |program: &str, args: &[&str]| {
+            let mut command = vec![program.to_string()];
+            command.extend(args.iter().map(ToString::to_string));
+            (PathBuf::from("network"), command)
+        };
+        assert_eq!(harness.start_commands(), vec![command("echo", &["start", "node"])]);
+        assert_eq!(
+            harness.stop_commands(),
+            vec![command("echo", &["stop", "node"]), command("echo", &["stop", "faucet"])]
+        );
+
+        harness.running = false;
+        assert!(harness.stop_commands().is_empty());
+    }
+}
@@ -8,7 +8,7 @@ use std::collections::HashMap;
 
 use crypto::{
     hashes::{blake2b::Blake2b256, Digest},
-    keys::bip39::{wordlist, Mnemonic, MnemonicRef, Passphrase, Seed},
+    keys::bip39::{wordlist, Mnemonic, MnemonicRef, Passphrase, PassphraseRef, Seed},
     utils,
 };
 use serde::{Deserialize, Serialize};
@@ -17,11 +17,20 @@ use zeroize::{Zeroize, ZeroizeOnDrop};
 use super::{Client, ClientInner};
 use crate::{
     client::{Error, Result},
-    types::block::{
-        address::{Address, Bech32Address, Ed25519Address, Hrp, ToBech32Ext},
-        output::{AliasId, NftId},
-        payload::TaggedDataPayload,
-        ConvertTo,
+    types::{
+        api::core::response::OutputWithMetadataResponse,
+        block::{
+            address::{Address, Bech32Address, Ed25519Address, Hrp, ToBech32Ext},
+            input::Input,
+            output::{AliasId, NftId, Output},
+            payload::{
+                transaction::{dto::TransactionPayloadDto, TransactionEssence, TransactionId, TransactionPayload},
+                TaggedDataPayload,
+            },
+            semantic::{semantic_validation, ConflictReason, ValidationContext},
+            BlockId, ConvertTo,
+        },
+        TryFromDto,
     },
 };
 
@@ -48,14 +57,95 @@ pub fn hex_public_key_to_bech32_address(hex: &str, bech32_hrp: impl ConvertTo<Hr
     Ok(Address::Ed25519(address).try_to_bech32(bech32_hrp)?)
 }
 
-/// Generates a new mnemonic.
+/// A source of entropy for new mnemonics, e.g. a hardware RNG, or a deterministic one in tests.
+pub trait SeedSource {
+    /// Fills `entropy` with random bytes.
+    fn fill_entropy(&mut self, entropy: &mut [u8]) -> Result<()>;
+}
+
+/// The random number generator of the operating system, used by [`generate_mnemonic()`].
+#[derive(Clone, Copy, Debug, Default)]
+pub struct OsSeedSource;
+
+impl SeedSource for OsSeedSource {
+    fn fill_entropy(&mut self, entropy: &mut [u8]) -> Result<()> {
+        Ok(utils::rand::fill(entropy)?)
+    }
+}
+
+#[cfg(feature = "rand")]
+#[cfg_attr(docsrs, doc(cfg(feature = "rand")))]
+impl<R: rand::RngCore + rand::CryptoRng> SeedSource for R {
+    fn fill_entropy(&mut self, entropy: &mut [u8]) -> Result<()> {
+        self.try_fill_bytes(entropy)
+            .map_err(|e| Error::SeedSource(e.to_string()))
+    }
+}
+
+/// The number of words of a mnemonic, defaults to 24.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq, Hash, Serialize, Deserialize)]
+#[serde(try_from = "u8", into = "u8")]
+pub enum MnemonicStrength {
+    /// 12 words, from 128 bits of entropy.
+    Words12,
+    /// 18 words, from 192 bits of entropy.
+    Words18,
+    /// 24 words, from 256 bits of entropy.
+    #[default]
+    Words24,
+}
+
+impl MnemonicStrength {
+    /// Returns the number of words.
+    pub fn word_count(&self) -> u8 {
+        match self {
+            Self::Words12 => 12,
+            Self::Words18 => 18,
+            Self::Words24 => 24,
+        }
+    }
+
+    /// Returns the number of entropy bytes the mnemonic encodes.
+    pub fn entropy_len(&self) -> usize {
+        self.word_count() as usize * 4 / 3
+    }
+}
+
+impl TryFrom<u8> for MnemonicStrength {
+    type Error = Error;
+
+    fn try_from(word_count: u8) -> Result<Self> {
+        match word_count {
+            12 => Ok(Self::Words12),
+            18 => Ok(Self::Words18),
+            24 => Ok(Self::Words24),
+            _ => Err(Error::InvalidMnemonic(format!(
+                "unsupported word count {word_count}, expected 12, 18 or 24"
+            ))),
+        }
+    }
+}
+
+impl From<MnemonicStrength> for u8 {
+    fn from(strength: MnemonicStrength) -> Self {
+        strength.word_count()
+    }
+}
+
+/// Generates a new mnemonic with 24 words from the random number generator of the operating system.
 pub fn generate_mnemonic() -> Result<Mnemonic> {
+    generate_mnemonic_with_rng(&mut OsSeedSource, MnemonicStrength::default())
+}
+
+/// Generates a new mnemonic with the given number of words from the entropy of `rng`.
+pub fn generate_mnemonic_with_rng(rng: &mut impl SeedSource, strength: MnemonicStrength) -> Result<Mnemonic> {
     let mut entropy = [0u8; 32];
-    utils::rand::fill(&mut entropy)?;
-    let mnemonic = wordlist::encode(&entropy, &crypto::keys::bip39::wordlist::ENGLISH)
-        .map_err(|e| crate::client::Error::InvalidMnemonic(format!("{e:?}")))?;
+    let entropy = &mut entropy[..strength.entropy_len()];
+    rng.fill_entropy(entropy)?;
+    let mnemonic = wordlist::encode(entropy, &crypto::keys::bip39::wordlist::ENGLISH)
+        .map_err(|e| crate::client::Error::InvalidMnemonic(format!("{e:?}")));
     entropy.zeroize();
-    Ok(mnemonic)
+    mnemonic
 }
 
 /// Returns a hex encoded seed for a mnemonic.
@@ -65,11 +155,19 @@ pub fn mnemonic_to_hex_seed(mnemonic: impl Borrow<MnemonicRef>) -> Result<String
 
 /// Returns a seed for a mnemonic.
 pub fn mnemonic_to_seed(mnemonic: impl Borrow<MnemonicRef>) -> Result<Seed> {
+    mnemonic_to_seed_with_passphrase(mnemonic, Passphrase::default())
+}
+
+/// Returns a seed for a mnemonic and a BIP-39 passphrase, also known as "25th word".
+pub fn mnemonic_to_seed_with_passphrase(
+    mnemonic: impl Borrow<MnemonicRef>,
+    passphrase: impl Borrow<PassphraseRef>,
+) -> Result<Seed> {
     // first we check if the mnemonic is valid to give meaningful errors
     verify_mnemonic(mnemonic.borrow())?;
     Ok(crypto::keys::bip39::mnemonic_to_seed(
         mnemonic.borrow(),
-        &Passphrase::default(),
+        passphrase.borrow(),
     ))
 }
 
@@ -80,6 +178,23 @@ pub fn verify_mnemonic(mnemonic: impl Borrow<MnemonicRef>) -> Result<()> {
     Ok(())
 }
 
+/// Checks if the words entered by a user match the words of a mnemonic at the given zero-based positions, to confirm
+/// a backup before the mnemonic is stored.
+pub fn verify_mnemonic_words<W: AsRef<str>>(
+    mnemonic: impl Borrow<MnemonicRef>,
+    words_with_positions: &[(usize, W)],
+) -> Result<bool> {
+    verify_mnemonic(mnemonic.borrow())?;
+    if words_with_positions.is_empty() {
+        return Err(Error::InvalidMnemonic("no words to verify".to_string()));
+    }
+
+    let words = mnemonic.borrow().split_whitespace().collect::<Vec<_>>();
+    Ok(words_with_positions
+        .iter()
+        .all(|(position, word)| words.get(*position) == Some(&word.as_ref().trim())))
+}
+
 /// Requests funds from a faucet
 pub async fn request_funds_from_faucet(url: &str, bech32_address: &Bech32Address) -> Result<String> {
     let mut map = HashMap::new();
@@ -98,6 +213,88 @@ pub async fn request_funds_from_faucet(url: &str, bech32_address: &Bech32Address
     Ok(faucet_response)
 }
 
+/// A self-contained proof that a transaction was confirmed, which third parties can check with
+/// [`verify_transaction_proof()`] without access to the wallet which sent it, e.g. to resolve disputes.
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TransactionProof {
+    /// The signed transaction.
+    pub transaction: TransactionPayloadDto,
+    /// The outputs consumed by the transaction in the order of its inputs, with their metadata.
+    pub inputs: Vec<OutputWithMetadataResponse>,
+    /// The block which included the transaction.
+    pub block_id: BlockId,
+    /// The index of the milestone which confirmed the transaction.
+    pub milestone_index: u32,
+    /// The timestamp of the milestone which confirmed the transaction.
+    pub milestone_timestamp: u32,
+}
+
+/// Verifies a [`TransactionProof`] and returns the ID of the proven transaction.
+///
+/// The consumed outputs have to match the inputs and the inputs commitment of the transaction, their metadata has to
+/// mark them as spent by it in the referenced milestone and the transaction has to be semantically valid at the
+/// milestone timestamp, which includes the signatures of its unlocks.
+///
+/// A proof can't show that its milestone exists, so the verifier has to compare the milestone index and timestamp and
+/// the block ID with a node or explorer it trusts.
+pub fn verify_transaction_proof(proof: &TransactionProof) -> Result<TransactionId> {
+    let transaction = TransactionPayload::try_from_dto(proof.transaction.clone())?;
+    let transaction_id = transaction.id();
+    let TransactionEssence::Regular(essence) = transaction.essence();
+
+    if essence.inputs().len() != proof.inputs.len() {
+        return Err(Error::InvalidTransactionProof(format!(
+            "{} consumed outputs for {} inputs",
+            proof.inputs.len(),
+            essence.inputs().len()
+        )));
+    }
+
+    let mut inputs = Vec::with_capacity(proof.inputs.len());
+    for (input, consumed) in essence.inputs().iter().zip(&proof.inputs) {
+        let Input::Utxo(input) = input else {
+            return Err(Error::InvalidTransactionProof(
+                "treasury inputs can't be proven".to_string(),
+            ));
+        };
+        let metadata = &consumed.metadata;
+        if metadata.output_id() != input.output_id() {
+            return Err(Error::InvalidTransactionProof(format!(
+                "consumed output {} doesn't match input {}",
+                metadata.output_id(),
+                input.output_id()
+            )));
+        }
+        if metadata.transaction_id_spent() != Some(&transaction_id)
+            || metadata.milestone_index_spent() != Some(proof.milestone_index)
+            || metadata.milestone_timestamp_spent() != Some(proof.milestone_timestamp)
+        {
+            return Err(Error::InvalidTransactionProof(format!(
+                "output {} wasn't spent by transaction {transaction_id} in milestone {}",
+                metadata.output_id(),
+                proof.milestone_index
+            )));
+        }
+        inputs.push((input.output_id(), Output::try_from_dto(consumed.output.clone())?));
+    }
+    let inputs = inputs.iter().map(|(id, output)| (*id, output)).collect::<Vec<_>>();
+
+    let context = ValidationContext::new(
+        &transaction_id,
+        essence,
+        inputs.iter().map(|(id, output)| (*id, *output)),
+        transaction.unlocks(),
+        proof.milestone_timestamp,
+    );
+    let conflict = semantic_validation(context, &inputs, transaction.unlocks())?;
+    if conflict != ConflictReason::None {
+        return Err(Error::TransactionSemantic(conflict));
+    }
+
+    Ok(transaction_id)
+}
+
 impl ClientInner {
     /// Transforms a hex encoded address to a bech32 encoded address
     pub async fn hex_to_bech32(
@@ -159,16 +356,37 @@ impl Client {
         generate_mnemonic()
     }
 
+    /// Generates a new mnemonic with the given number of words from the entropy of `rng`.
+    pub fn generate_mnemonic_with_rng(rng: &mut impl SeedSource, strength: MnemonicStrength) -> Result<Mnemonic> {
+        generate_mnemonic_with_rng(rng, strength)
+    }
+
     /// Returns a seed for a mnemonic.
     pub fn mnemonic_to_seed(mnemonic: impl Borrow<MnemonicRef>) -> Result<Seed> {
         mnemonic_to_seed(mnemonic)
     }
 
+    /// Returns a seed for a mnemonic and a BIP-39 passphrase.
+    pub fn mnemonic_to_seed_with_passphrase(
+        mnemonic: impl Borrow<MnemonicRef>,
+        passphrase: impl Borrow<PassphraseRef>,
+    ) -> Result<Seed> {
+        mnemonic_to_seed_with_passphrase(mnemonic, passphrase)
+    }
+
     /// Returns a hex encoded seed for a mnemonic.
     pub fn mnemonic_to_hex_seed(mnemonic: impl Borrow<MnemonicRef>) -> Result<String> {
         mnemonic_to_hex_seed(mnemonic)
     }
 
+    /// Checks if the words entered by a user match the words of a mnemonic at the given zero-based positions.
+    pub fn verify_mnemonic_words<W: AsRef<str>>(
+        mnemonic: impl Borrow<MnemonicRef>,
+        words_with_positions: &[(usize, W)],
+    ) -> Result<bool> {
+        verify_mnemonic_words(mnemonic, words_with_positions)
+    }
+
     /// UTF-8 encodes the `tag` of a given TaggedDataPayload.
     pub fn tag_to_utf8(payload: &TaggedDataPayload) -> Result<String> {
         String::from_utf8(payload.tag().to_vec()).map_err(|_| Error::TaggedData("found invalid UTF-8".to_string()))
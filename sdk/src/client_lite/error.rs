@@ -0,0 +1,47 @@
+// Copyright 2024 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use alloc::string::String;
+use core::fmt;
+
+/// Errors of the [`LiteClient`](super::LiteClient), generic over the error of its transport.
+#[derive(Debug)]
+pub enum Error<E> {
+    /// The transport failed to send the request or to receive the response.
+    Transport(E),
+    /// The requested resource wasn't found.
+    NotFound,
+    /// The node answered with an unsuccessful status.
+    Response { status: u16, text: String },
+    /// The response couldn't be deserialized.
+    Json(serde_json::Error),
+    /// The response contained an invalid block or output.
+    Block(crate::types::block::Error),
+}
+
+#[cfg(feature = "std")]
+impl<E: fmt::Debug + fmt::Display> std::error::Error for Error<E> {}
+
+impl<E: fmt::Display> fmt::Display for Error<E> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Transport(e) => write!(f, "transport error: {e}"),
+            Self::NotFound => write!(f, "not found"),
+            Self::Response { status, text } => write!(f, "response error with status code {status}: {text}"),
+            Self::Json(e) => write!(f, "invalid JSON response: {e}"),
+            Self::Block(e) => write!(f, "{e}"),
+        }
+    }
+}
+
+impl<E> From<serde_json::Error> for Error<E> {
+    fn from(error: serde_json::Error) -> Self {
+        Self::Json(error)
+    }
+}
+
+impl<E> From<crate::types::block::Error> for Error<E> {
+    fn from(error: crate::types::block::Error) -> Self {
+        Self::Block(error)
+    }
+}
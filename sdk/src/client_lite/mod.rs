@@ -0,0 +1,158 @@
+// Copyright 2024 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+//! A minimal blocking client for constrained devices, which submits pre-signed blocks and queries single outputs.
+//!
+//! It works without `std`, an async runtime or an HTTP client, the requests are sent by a [`LiteTransport`] of the
+//! platform. There is no node pool, indexer, MQTT or PoW, which the full client of the `client` feature provides.
+//!
+//! ```ignore
+//! let mut client = LiteClient::new(EspTransport::new("https://node.example.com"));
+//! let block_id = client.post_block_raw(&signed_block_bytes)?;
+//! let output = client.get_output(&output_id)?;
+//! ```
+
+mod error;
+mod transport;
+
+use alloc::{format, string::String};
+
+use packable::PackableExt;
+use serde::de::DeserializeOwned;
+
+pub use self::{
+    error::Error,
+    transport::{LiteMethod, LiteRequest, LiteResponse, LiteTransport, BINARY_CONTENT_TYPE, JSON_CONTENT_TYPE},
+};
+use crate::types::{
+    api::core::response::{BlockMetadataResponse, InfoResponse, OutputWithMetadataResponse, SubmitBlockResponse},
+    block::{
+        output::{Output, OutputId, OutputMetadata, OutputWithMetadata},
+        protocol::ProtocolParameters,
+        Block, BlockId,
+    },
+    TryFromDto,
+};
+
+/// The result of the [`LiteClient`].
+pub type Result<T, E> = core::result::Result<T, Error<E>>;
+
+/// A minimal client which sends its requests to a single node with a [`LiteTransport`].
+#[derive(Debug)]
+pub struct LiteClient<T> {
+    transport: T,
+    protocol_parameters: Option<ProtocolParameters>,
+}
+
+impl<T: LiteTransport> LiteClient<T> {
+    /// Creates a client which sends its requests with the transport.
+    pub fn new(transport: T) -> Self {
+        Self {
+            transport,
+            protocol_parameters: None,
+        }
+    }
+
+    /// Sets the protocol parameters of the network, so they don't have to be requested from the node.
+    pub fn with_protocol_parameters(mut self, protocol_parameters: ProtocolParameters) -> Self {
+        self.protocol_parameters.replace(protocol_parameters);
+        self
+    }
+
+    /// Returns the transport of the client.
+    pub fn transport(&self) -> &T {
+        &self.transport
+    }
+
+    /// Consumes the client and returns its transport.
+    pub fn into_transport(self) -> T {
+        self.transport
+    }
+
+    /// Returns the protocol parameters, requesting them from the node if they aren't known yet.
+    pub fn protocol_parameters(&mut self) -> Result<&ProtocolParameters, T::Error> {
+        let protocol_parameters = match self.protocol_parameters.take() {
+            Some(protocol_parameters) => protocol_parameters,
+            None => self.get_info()?.protocol,
+        };
+        Ok(self.protocol_parameters.insert(protocol_parameters))
+    }
+
+    /// Returns general information about the node and keeps its protocol parameters.
+    /// GET /api/core/v2/info
+    pub fn get_info(&mut self) -> Result<InfoResponse, T::Error> {
+        let info: InfoResponse = self.get_json("/api/core/v2/info")?;
+        self.protocol_parameters.replace(info.protocol.clone());
+
+        Ok(info)
+    }
+
+    /// Submits a block in its binary serialization and returns its BlockId. The block has to be signed and its PoW
+    /// done already, or the node has to do the PoW.
+    /// POST /api/core/v2/blocks
+    pub fn post_block_raw(&mut self, block_bytes: &[u8]) -> Result<BlockId, T::Error> {
+        let response = self.send(LiteRequest {
+            method: LiteMethod::Post,
+            path: "/api/core/v2/blocks",
+            accept: JSON_CONTENT_TYPE,
+            content_type: BINARY_CONTENT_TYPE,
+            body: block_bytes,
+        })?;
+
+        Ok(serde_json::from_slice::<SubmitBlockResponse>(&response.body)?.block_id)
+    }
+
+    /// Submits a block and returns its BlockId.
+    /// POST /api/core/v2/blocks
+    pub fn post_block(&mut self, block: &Block) -> Result<BlockId, T::Error> {
+        self.post_block_raw(&block.pack_to_vec())
+    }
+
+    /// Returns the metadata of a block, e.g. to check if it was included.
+    /// GET /api/core/v2/blocks/{blockId}/metadata
+    pub fn get_block_metadata(&mut self, block_id: &BlockId) -> Result<BlockMetadataResponse, T::Error> {
+        self.get_json(&format!("/api/core/v2/blocks/{block_id}/metadata"))
+    }
+
+    /// Finds an output by its OutputId (TransactionId + output_index).
+    /// GET /api/core/v2/outputs/{outputId}
+    pub fn get_output(&mut self, output_id: &OutputId) -> Result<OutputWithMetadata, T::Error> {
+        let response: OutputWithMetadataResponse = self.get_json(&format!("/api/core/v2/outputs/{output_id}"))?;
+        let token_supply = self.protocol_parameters()?.token_supply();
+        let output = Output::try_from_dto_with_params(response.output, token_supply)?;
+
+        Ok(OutputWithMetadata::new(output, response.metadata))
+    }
+
+    /// Returns the metadata of an output, e.g. to check if it's spent.
+    /// GET /api/core/v2/outputs/{outputId}/metadata
+    pub fn get_output_metadata(&mut self, output_id: &OutputId) -> Result<OutputMetadata, T::Error> {
+        self.get_json(&format!("/api/core/v2/outputs/{output_id}/metadata"))
+    }
+
+    fn get_json<R: DeserializeOwned>(&mut self, path: &str) -> Result<R, T::Error> {
+        let response = self.send(LiteRequest {
+            method: LiteMethod::Get,
+            path,
+            accept: JSON_CONTENT_TYPE,
+            content_type: "",
+            body: &[],
+        })?;
+
+        Ok(serde_json::from_slice(&response.body)?)
+    }
+
+    // Sends a request and turns unsuccessful responses into errors
+    fn send(&mut self, request: LiteRequest<'_>) -> Result<LiteResponse, T::Error> {
+        let response = self.transport.send(request).map_err(Error::Transport)?;
+
+        match response.status {
+            200..=299 => Ok(response),
+            404 => Err(Error::NotFound),
+            status => Err(Error::Response {
+                status,
+                text: String::from_utf8_lossy(&response.body).into(),
+            }),
+        }
+    }
+}
@@ -0,0 +1,56 @@
+// Copyright 2024 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use alloc::vec::Vec;
+
+/// The content type of the binary serialization of blocks.
+pub const BINARY_CONTENT_TYPE: &str = "application/vnd.iota.serializer-v1";
+/// The content type of JSON requests and responses.
+pub const JSON_CONTENT_TYPE: &str = "application/json";
+
+/// The HTTP method of a [`LiteRequest`].
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum LiteMethod {
+    Get,
+    Post,
+}
+
+/// A request of the [`LiteClient`](super::LiteClient) to its node.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct LiteRequest<'a> {
+    pub method: LiteMethod,
+    /// The path and query of the route, e.g. `/api/core/v2/info`.
+    pub path: &'a str,
+    /// The value of the `Accept` header.
+    pub accept: &'a str,
+    /// The value of the `Content-Type` header of a POST request, empty for GET requests.
+    pub content_type: &'a str,
+    /// The body of a POST request, empty for GET requests.
+    pub body: &'a [u8],
+}
+
+/// The response of the node to a [`LiteRequest`].
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct LiteResponse {
+    pub status: u16,
+    pub body: Vec<u8>,
+}
+
+/// Sends the requests of the [`LiteClient`](super::LiteClient).
+///
+/// It can use the HTTP client of the platform like the one of ESP-IDF, or a serial link to a gateway. The transport is
+/// responsible for the connection, TLS and authentication of the node.
+pub trait LiteTransport {
+    type Error;
+
+    /// Sends a request and returns the response, whatever its status is.
+    fn send(&mut self, request: LiteRequest<'_>) -> Result<LiteResponse, Self::Error>;
+}
+
+impl<T: LiteTransport + ?Sized> LiteTransport for &mut T {
+    type Error = T::Error;
+
+    fn send(&mut self, request: LiteRequest<'_>) -> Result<LiteResponse, Self::Error> {
+        (**self).send(request)
+    }
+}
@@ -19,8 +19,11 @@ extern crate alloc;
 #[cfg(feature = "client")]
 #[cfg_attr(docsrs, doc(cfg(feature = "client")))]
 pub mod client;
-#[cfg(feature = "pow")]
-#[cfg_attr(docsrs, doc(cfg(feature = "pow")))]
+#[cfg(feature = "client_lite")]
+#[cfg_attr(docsrs, doc(cfg(feature = "client_lite")))]
+pub mod client_lite;
+#[cfg(feature = "pow_score")]
+#[cfg_attr(docsrs, doc(cfg(feature = "pow_score")))]
 pub mod pow;
 pub mod types;
 #[cfg(feature = "wallet")]
@@ -4,11 +4,14 @@
 //! Provides proof of work implementations and scoring for the IOTA protocol.
 //! TIP <https://github.com/iotaledger/tips/blob/main/tips/TIP-0012/tip-0012.md>.
 
+#[cfg(feature = "pow")]
+#[cfg_attr(docsrs, doc(cfg(feature = "pow")))]
 pub mod miner;
 pub mod score;
-#[cfg(target_family = "wasm")]
+#[cfg(all(feature = "pow", target_family = "wasm"))]
 pub mod wasm_miner;
 
 // Precomputed natural logarithm of 3 for performance reasons.
 // See https://oeis.org/A002391.
+#[cfg(feature = "pow")]
 const LN_3: f64 = 1.098_612_288_668_109;
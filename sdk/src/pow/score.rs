@@ -38,10 +38,10 @@ impl PowScorer {
     /// Returns the Proof of Work hash of given bytes.
     /// Panic: expects at least 8 bytes.
     pub fn hash(&mut self, bytes: &[u8]) -> TritBuf<T1B1Buf> {
-        debug_assert!(bytes.len() >= std::mem::size_of::<u8>());
+        debug_assert!(bytes.len() >= core::mem::size_of::<u8>());
 
         // Compute Blake2b-256 hash of the block, excluding the nonce.
-        let length = bytes.len() - std::mem::size_of::<u64>();
+        let length = bytes.len() - core::mem::size_of::<u64>();
         let (head, tail) = bytes.split_at(length);
         self.blake2b.update(head);
         let pow_digest = self.blake2b.finalize_reset();
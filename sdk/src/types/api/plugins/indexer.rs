@@ -3,6 +3,8 @@
 
 //! Node indexer responses.
 
+#[cfg(feature = "serde")]
+use alloc::boxed::Box;
 use alloc::{string::String, vec::Vec};
 use core::ops::Deref;
 
@@ -32,3 +34,64 @@ impl Deref for OutputIdsResponse {
         &self.items
     }
 }
+
+/// Response of GET /api/indexer/v1/* with the output ids left unparsed.
+///
+/// Large responses aren't kept in memory twice, the output ids can be parsed one by one with
+/// [`RawOutputIdsResponse::for_each_output_id()`].
+#[cfg(feature = "serde")]
+#[derive(Clone, Debug, serde::Serialize, serde::Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RawOutputIdsResponse {
+    /// The ledger index at which the outputs were collected
+    pub ledger_index: u32,
+    /// Cursor confirmationMS+outputId.pageSize
+    pub cursor: Option<String>,
+    /// The unparsed JSON array of output ids
+    pub items: Box<serde_json::value::RawValue>,
+}
+
+#[cfg(feature = "serde")]
+impl RawOutputIdsResponse {
+    /// Parses the output ids one by one and calls `f` with each of them, without collecting them.
+    pub fn for_each_output_id(&self, f: impl FnMut(OutputId)) -> Result<(), serde_json::Error> {
+        use serde::Deserializer as _;
+
+        struct OutputIdsVisitor<F>(F);
+
+        impl<'de, F: FnMut(OutputId)> serde::de::Visitor<'de> for OutputIdsVisitor<F> {
+            type Value = ();
+
+            fn expecting(&self, formatter: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+                formatter.write_str("an array of output ids")
+            }
+
+            fn visit_seq<A: serde::de::SeqAccess<'de>>(mut self, mut seq: A) -> Result<Self::Value, A::Error> {
+                while let Some(output_id) = seq.next_element::<OutputId>()? {
+                    (self.0)(output_id);
+                }
+                Ok(())
+            }
+        }
+
+        let mut deserializer = serde_json::Deserializer::from_str(self.items.get());
+        (&mut deserializer).deserialize_seq(OutputIdsVisitor(f))?;
+        deserializer.end()
+    }
+}
+
+#[cfg(feature = "serde")]
+impl TryFrom<RawOutputIdsResponse> for OutputIdsResponse {
+    type Error = serde_json::Error;
+
+    fn try_from(response: RawOutputIdsResponse) -> Result<Self, Self::Error> {
+        let mut items = Vec::new();
+        response.for_each_output_id(|output_id| items.push(output_id))?;
+
+        Ok(Self {
+            ledger_index: response.ledger_index,
+            cursor: response.cursor,
+            items,
+        })
+    }
+}
@@ -177,6 +177,13 @@ impl Block {
         Ok(block)
     }
 
+    /// Computes the Proof of Work score of the block, which has to reach the minimum PoW score of the network.
+    #[cfg(feature = "pow_score")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "pow_score")))]
+    pub fn pow_score(&self) -> f64 {
+        crate::pow::score::PowScorer::new().score(&self.pack_to_vec())
+    }
+
     /// Returns a Blake2b256 hash of block bytes without nonce for PoW.
     pub fn hash_without_nonce(&self) -> [u8; 32] {
         let block_bytes = self.pack_to_vec();
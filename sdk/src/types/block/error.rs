@@ -12,7 +12,7 @@ use crate::types::block::{
     input::UtxoInput,
     output::{
         feature::FeatureCount, unlock_condition::UnlockConditionCount, AliasId, ChainId, MetadataFeatureLength,
-        NativeTokenCount, NftId, OutputIndex, StateMetadataLength, TagFeatureLength,
+        NativeTokenCount, NftId, OutputIndex, StateMetadataLength, TagFeatureLength, TokenId,
     },
     parent::ParentCount,
     payload::{
@@ -102,6 +102,8 @@ pub enum Error {
     NativeTokensNotUniqueSorted,
     NativeTokensNullAmount,
     NativeTokensOverflow,
+    NativeTokensUnderflow,
+    NativeTokensDivisionByZero,
     NetworkIdMismatch { expected: u64, actual: u64 },
     NonZeroStateIndexOrFoundryCounter,
     ParentsNotUniqueSorted,
@@ -109,12 +111,14 @@ pub enum Error {
     NonceNotFound,
     ReceiptFundsNotUniqueSorted,
     RemainingBytesAfterBlock,
+    RemainingBytesAfterOutput,
     SelfControlledAliasOutput(AliasId),
     SelfDepositNft(NftId),
     SignaturePublicKeyMismatch { expected: String, actual: String },
     StorageDepositReturnOverflow,
     TailTransactionHashNotUnique { previous: usize, current: usize },
     TimelockUnlockConditionZero,
+    TokenIdMismatch { expected: TokenId, actual: TokenId },
     UnallowedFeature { index: usize, kind: u8 },
     UnallowedUnlockCondition { index: usize, kind: u8 },
     UnlockConditionsNotUniqueSorted,
@@ -274,6 +278,8 @@ impl fmt::Display for Error {
             Self::NativeTokensNotUniqueSorted => write!(f, "native tokens are not unique and/or sorted"),
             Self::NativeTokensNullAmount => write!(f, "native tokens null amount"),
             Self::NativeTokensOverflow => write!(f, "native tokens overflow"),
+            Self::NativeTokensUnderflow => write!(f, "native tokens underflow"),
+            Self::NativeTokensDivisionByZero => write!(f, "native tokens division by zero"),
             Self::NetworkIdMismatch { expected, actual } => {
                 write!(f, "network ID mismatch: expected {expected} but got {actual}")
             }
@@ -295,6 +301,9 @@ impl fmt::Display for Error {
             Self::RemainingBytesAfterBlock => {
                 write!(f, "remaining bytes after block")
             }
+            Self::RemainingBytesAfterOutput => {
+                write!(f, "remaining bytes after output")
+            }
             Self::SelfControlledAliasOutput(alias_id) => {
                 write!(f, "self controlled alias output, alias ID {alias_id}")
             }
@@ -319,6 +328,9 @@ impl fmt::Display for Error {
                     "timelock unlock condition with milestone index and timestamp set to 0",
                 )
             }
+            Self::TokenIdMismatch { expected, actual } => {
+                write!(f, "token ID mismatch: expected {expected} but got {actual}")
+            }
             Self::UnallowedFeature { index, kind } => {
                 write!(f, "unallowed feature at index {index} with kind {kind}")
             }
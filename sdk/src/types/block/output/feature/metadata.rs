@@ -151,9 +151,14 @@ pub(crate) mod irc_27 {
     }
 
     impl Irc27Metadata {
+        /// The version of the IRC27 standard.
+        pub const VERSION: &'static str = "v1.0";
+        /// Valid range of a single royalty percentage and of the sum of all royalty percentages.
+        pub const ROYALTY_RANGE: RangeInclusive<f64> = 0.0..=1.0;
+
         pub fn new(media_type: impl Into<String>, uri: Url, name: impl Into<String>) -> Self {
             Self {
-                version: "v1.0".to_owned(),
+                version: Self::VERSION.to_owned(),
                 media_type: media_type.into(),
                 uri,
                 name: name.into(),
@@ -204,6 +209,55 @@ pub(crate) mod irc_27 {
             // Unwrap: Safe because this struct is known to be valid
             serde_json::to_string(self).unwrap().into_bytes()
         }
+
+        /// Checks that the metadata follows the IRC27 standard: the version is supported, the media type and name
+        /// aren't empty and the royalty percentages, as well as their sum, are within [`Self::ROYALTY_RANGE`].
+        pub fn validate(&self) -> Result<(), Error> {
+            if self.version != Self::VERSION {
+                return Err(Error::InvalidField("version"));
+            }
+            if self.media_type.is_empty() {
+                return Err(Error::InvalidField("type"));
+            }
+            if self.name.is_empty() {
+                return Err(Error::InvalidField("name"));
+            }
+            if self
+                .royalties
+                .values()
+                .any(|percentage| !Self::ROYALTY_RANGE.contains(percentage))
+                || !Self::ROYALTY_RANGE.contains(&self.royalties_percentage())
+            {
+                return Err(Error::InvalidField("royalties"));
+            }
+
+            Ok(())
+        }
+
+        /// Returns the sum of all royalty percentages.
+        pub fn royalties_percentage(&self) -> f64 {
+            self.royalties.values().sum()
+        }
+
+        /// Computes the royalty payments for a sale of the NFT with the given amount. The payments are rounded down,
+        /// so the returned amounts never exceed the sale amount.
+        pub fn royalty_amounts(&self, sale_amount: u64) -> BTreeMap<Bech32Address, u64> {
+            self.royalties
+                .iter()
+                .map(|(address, percentage)| (*address, (sale_amount as f64 * percentage) as u64))
+                .filter(|(_, amount)| *amount > 0)
+                .collect()
+        }
+    }
+
+    impl TryFrom<&MetadataFeature> for Irc27Metadata {
+        type Error = Error;
+
+        fn try_from(value: &MetadataFeature) -> Result<Self, Error> {
+            let metadata = serde_json::from_slice::<Self>(value.data()).map_err(|_| Error::InvalidField("metadata"))?;
+            metadata.validate()?;
+            Ok(metadata)
+        }
     }
 
     impl TryFrom<Irc27Metadata> for MetadataFeature {
@@ -301,6 +355,61 @@ pub(crate) mod irc_27 {
             assert_eq!(metadata, metadata_deser);
             assert_eq!(json, serde_json::to_value(metadata).unwrap())
         }
+
+        #[test]
+        fn validation() {
+            let metadata = Irc27Metadata::new(
+                "image/jpeg",
+                "https://mywebsite.com/my-nft-files-1.jpeg".parse().unwrap(),
+                "My NFT #0001",
+            );
+            assert_eq!(metadata.validate(), Ok(()));
+
+            let feature = MetadataFeature::try_from(metadata.clone()).unwrap();
+            assert_eq!(Irc27Metadata::try_from(&feature), Ok(metadata.clone()));
+            assert_eq!(
+                Irc27Metadata::try_from(&MetadataFeature::new(b"not json".to_vec()).unwrap()),
+                Err(Error::InvalidField("metadata"))
+            );
+
+            assert_eq!(
+                Irc27Metadata::new("image/jpeg", metadata.uri().clone(), "").validate(),
+                Err(Error::InvalidField("name"))
+            );
+            assert_eq!(
+                metadata
+                    .clone()
+                    .add_royalty(rand_address().to_bech32_unchecked("iota1"), 1.5)
+                    .validate(),
+                Err(Error::InvalidField("royalties"))
+            );
+            assert_eq!(
+                metadata
+                    .add_royalty(rand_address().to_bech32_unchecked("iota1"), 0.6)
+                    .add_royalty(rand_address().to_bech32_unchecked("iota1"), 0.6)
+                    .validate(),
+                Err(Error::InvalidField("royalties"))
+            );
+        }
+
+        #[test]
+        fn royalty_amounts() {
+            let first = rand_address().to_bech32_unchecked("iota1");
+            let second = rand_address().to_bech32_unchecked("iota1");
+            let metadata = Irc27Metadata::new(
+                "image/jpeg",
+                "https://mywebsite.com/my-nft-files-1.jpeg".parse().unwrap(),
+                "My NFT #0001",
+            )
+            .add_royalty(first, 0.025)
+            .add_royalty(second, 0.1);
+
+            let amounts = metadata.royalty_amounts(1_000_000);
+            assert_eq!(amounts.len(), 2);
+            assert_eq!(amounts[&first], 25_000);
+            assert_eq!(amounts[&second], 100_000);
+            assert!(metadata.royalty_amounts(1).is_empty());
+        }
     }
 }
 
@@ -342,6 +451,9 @@ pub(crate) mod irc_30 {
     }
 
     impl Irc30Metadata {
+        /// The maximum number of decimals, more can't be represented with the maximum supply of a native token.
+        pub const MAX_DECIMALS: u32 = 77;
+
         pub fn new(name: impl Into<String>, symbol: impl Into<String>, decimals: u32) -> Self {
             Self {
                 name: name.into(),
@@ -378,6 +490,32 @@ pub(crate) mod irc_30 {
             // Unwrap: Safe because this struct is known to be valid
             serde_json::to_string(self).unwrap().into_bytes()
         }
+
+        /// Checks that the metadata follows the IRC30 standard: the name isn't empty, the symbol isn't empty and
+        /// contains no whitespace and the decimals don't exceed [`Self::MAX_DECIMALS`].
+        pub fn validate(&self) -> Result<(), Error> {
+            if self.name.is_empty() {
+                return Err(Error::InvalidField("name"));
+            }
+            if self.symbol.is_empty() || self.symbol.chars().any(char::is_whitespace) {
+                return Err(Error::InvalidField("symbol"));
+            }
+            if self.decimals > Self::MAX_DECIMALS {
+                return Err(Error::InvalidField("decimals"));
+            }
+
+            Ok(())
+        }
+    }
+
+    impl TryFrom<&MetadataFeature> for Irc30Metadata {
+        type Error = Error;
+
+        fn try_from(value: &MetadataFeature) -> Result<Self, Error> {
+            let metadata = serde_json::from_slice::<Self>(value.data()).map_err(|_| Error::InvalidField("metadata"))?;
+            metadata.validate()?;
+            Ok(metadata)
+        }
     }
 
     impl TryFrom<Irc30Metadata> for MetadataFeature {
@@ -425,6 +563,36 @@ pub(crate) mod irc_30 {
             assert_eq!(metadata, metadata_deser);
             assert_eq!(json, serde_json::to_value(metadata).unwrap())
         }
+
+        #[test]
+        fn validation() {
+            let metadata = Irc30Metadata::new("FooCoin", "FOO", 3);
+            assert_eq!(metadata.validate(), Ok(()));
+
+            let feature = MetadataFeature::try_from(metadata.clone()).unwrap();
+            assert_eq!(Irc30Metadata::try_from(&feature), Ok(metadata));
+            assert_eq!(
+                Irc30Metadata::try_from(&MetadataFeature::new(b"not json".to_vec()).unwrap()),
+                Err(Error::InvalidField("metadata"))
+            );
+
+            assert_eq!(
+                Irc30Metadata::new("", "FOO", 3).validate(),
+                Err(Error::InvalidField("name"))
+            );
+            assert_eq!(
+                Irc30Metadata::new("FooCoin", "", 3).validate(),
+                Err(Error::InvalidField("symbol"))
+            );
+            assert_eq!(
+                Irc30Metadata::new("FooCoin", "F O O", 3).validate(),
+                Err(Error::InvalidField("symbol"))
+            );
+            assert_eq!(
+                Irc30Metadata::new("FooCoin", "FOO", Irc30Metadata::MAX_DECIMALS + 1).validate(),
+                Err(Error::InvalidField("decimals"))
+            );
+        }
     }
 }
 
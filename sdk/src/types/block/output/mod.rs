@@ -32,9 +32,9 @@ use core::ops::RangeInclusive;
 
 use derive_more::From;
 use packable::{
-    error::{UnpackError, UnpackErrorExt},
+    error::{UnexpectedEOF, UnpackError, UnpackErrorExt},
     packer::Packer,
-    unpacker::Unpacker,
+    unpacker::{SliceUnpacker, Unpacker},
     Packable, PackableExt,
 };
 
@@ -55,7 +55,7 @@ pub use self::{
     foundry_id::FoundryId,
     inputs_commitment::InputsCommitment,
     metadata::OutputMetadata,
-    native_token::{NativeToken, NativeTokens, NativeTokensBuilder},
+    native_token::{NativeToken, NativeTokens, NativeTokensBuilder, TokenAmount},
     nft::{NftOutput, NftOutputBuilder},
     nft_id::NftId,
     output_id::OutputId,
@@ -424,6 +424,22 @@ impl Output {
 
         Ok(())
     }
+
+    /// Unpacks an [`Output`] from a sequence of bytes doing syntactical checks and verifying that there are no
+    /// trailing bytes in the sequence.
+    pub fn unpack_strict<T: AsRef<[u8]>>(
+        bytes: T,
+        visitor: &<Self as Packable>::UnpackVisitor,
+    ) -> Result<Self, UnpackError<<Self as Packable>::UnpackError, UnexpectedEOF>> {
+        let mut unpacker = SliceUnpacker::new(bytes.as_ref());
+        let output = Self::unpack::<_, true>(&mut unpacker, visitor)?;
+
+        if u8::unpack::<_, true>(&mut unpacker, &()).is_ok() {
+            return Err(UnpackError::Packable(Error::RemainingBytesAfterOutput));
+        }
+
+        Ok(output)
+    }
 }
 
 impl Packable for Output {
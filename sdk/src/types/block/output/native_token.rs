@@ -10,7 +10,7 @@ use alloc::{
 use derive_more::{Deref, DerefMut, From};
 use iterator_sorted::is_unique_sorted;
 use packable::{bounded::BoundedU8, prefix::BoxedSlicePrefix, Packable};
-use primitive_types::U256;
+use primitive_types::{U256, U512};
 
 use crate::types::block::{output::TokenId, Error};
 
@@ -62,6 +62,14 @@ impl Ord for NativeToken {
     }
 }
 
+impl TryFrom<TokenAmount> for NativeToken {
+    type Error = Error;
+
+    fn try_from(token_amount: TokenAmount) -> Result<Self, Self::Error> {
+        Self::new(token_amount.token_id, token_amount.amount)
+    }
+}
+
 #[inline]
 fn verify_amount<const VERIFY: bool>(amount: &U256, _: &()) -> Result<(), Error> {
     if VERIFY && amount.is_zero() {
@@ -71,6 +79,147 @@ fn verify_amount<const VERIFY: bool>(amount: &U256, _: &()) -> Result<(), Error>
     }
 }
 
+/// An amount of a native token which, unlike a [`NativeToken`], can be zero. The arithmetic operations never overflow
+/// silently and fail if the amounts belong to different tokens.
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct TokenAmount {
+    // Identifier of the native token.
+    #[cfg_attr(feature = "serde", serde(rename = "id"))]
+    token_id: TokenId,
+    // Amount of native tokens.
+    amount: U256,
+}
+
+impl TokenAmount {
+    /// Creates a new [`TokenAmount`].
+    pub fn new(token_id: TokenId, amount: impl Into<U256>) -> Self {
+        Self {
+            token_id,
+            amount: amount.into(),
+        }
+    }
+
+    /// Creates a new [`TokenAmount`] without any tokens.
+    pub fn zero(token_id: TokenId) -> Self {
+        Self::new(token_id, U256::zero())
+    }
+
+    /// Returns the token ID of the [`TokenAmount`].
+    #[inline(always)]
+    pub fn token_id(&self) -> &TokenId {
+        &self.token_id
+    }
+
+    /// Returns the amount of the [`TokenAmount`].
+    #[inline(always)]
+    pub fn amount(&self) -> U256 {
+        self.amount
+    }
+
+    /// Returns whether the amount is zero.
+    pub fn is_zero(&self) -> bool {
+        self.amount.is_zero()
+    }
+
+    fn verify_token_id(&self, other: &Self) -> Result<(), Error> {
+        if self.token_id != other.token_id {
+            return Err(Error::TokenIdMismatch {
+                expected: self.token_id,
+                actual: other.token_id,
+            });
+        }
+
+        Ok(())
+    }
+
+    /// Adds two amounts of the same token, fails on overflow.
+    pub fn checked_add(self, other: Self) -> Result<Self, Error> {
+        self.verify_token_id(&other)?;
+        let amount = self
+            .amount
+            .checked_add(other.amount)
+            .ok_or(Error::NativeTokensOverflow)?;
+
+        Ok(Self::new(self.token_id, amount))
+    }
+
+    /// Subtracts an amount of the same token, fails if it's larger.
+    pub fn checked_sub(self, other: Self) -> Result<Self, Error> {
+        self.verify_token_id(&other)?;
+        let amount = self
+            .amount
+            .checked_sub(other.amount)
+            .ok_or(Error::NativeTokensUnderflow)?;
+
+        Ok(Self::new(self.token_id, amount))
+    }
+
+    /// Adds two amounts of the same token, saturating at the maximum amount.
+    pub fn saturating_add(self, other: Self) -> Result<Self, Error> {
+        self.verify_token_id(&other)?;
+
+        Ok(Self::new(self.token_id, self.amount.saturating_add(other.amount)))
+    }
+
+    /// Subtracts an amount of the same token, saturating at zero.
+    pub fn saturating_sub(self, other: Self) -> Result<Self, Error> {
+        self.verify_token_id(&other)?;
+
+        Ok(Self::new(self.token_id, self.amount.saturating_sub(other.amount)))
+    }
+
+    /// Returns `amount * numerator / denominator` rounded down, the multiplication can't overflow. Fails if the result
+    /// doesn't fit into a [`U256`] or the denominator is zero.
+    pub fn checked_mul_ratio(self, numerator: impl Into<U256>, denominator: impl Into<U256>) -> Result<Self, Error> {
+        let denominator = denominator.into();
+        if denominator.is_zero() {
+            return Err(Error::NativeTokensDivisionByZero);
+        }
+        let amount = self.amount.full_mul(numerator.into()) / U512::from(denominator);
+
+        Ok(Self::new(
+            self.token_id,
+            U256::try_from(amount).map_err(|_| Error::NativeTokensOverflow)?,
+        ))
+    }
+
+    /// Returns `percent` percent of the amount, rounded down.
+    pub fn checked_percentage(self, percent: u8) -> Result<Self, Error> {
+        self.checked_mul_ratio(percent, 100u8)
+    }
+
+    /// Returns the share of this amount in a total amount of the same token, in basis points: 100 are 1%. Fails if the
+    /// total is zero.
+    pub fn basis_points_of(&self, total: &Self) -> Result<U256, Error> {
+        self.verify_token_id(total)?;
+        if total.amount.is_zero() {
+            return Err(Error::NativeTokensDivisionByZero);
+        }
+
+        U256::try_from(self.amount.full_mul(U256::from(10_000u16)) / U512::from(total.amount))
+            .map_err(|_| Error::NativeTokensOverflow)
+    }
+}
+
+impl From<NativeToken> for TokenAmount {
+    fn from(native_token: NativeToken) -> Self {
+        Self::new(native_token.token_id, native_token.amount)
+    }
+}
+
+impl From<(TokenId, U256)> for TokenAmount {
+    fn from((token_id, amount): (TokenId, U256)) -> Self {
+        Self::new(token_id, amount)
+    }
+}
+
+impl From<TokenAmount> for (TokenId, U256) {
+    fn from(token_amount: TokenAmount) -> Self {
+        (token_amount.token_id, token_amount.amount)
+    }
+}
+
 /// A builder for [`NativeTokens`].
 #[derive(Clone, Default, Debug, Deref, DerefMut, From)]
 #[must_use]
@@ -234,6 +383,12 @@ impl NativeTokens {
             .binary_search_by_key(token_id, |native_token| native_token.token_id)
             .map_or(None, |index| Some(&self.0[index]))
     }
+
+    /// Returns the amount of the provided token ID, zero if it isn't contained.
+    pub fn token_amount(&self, token_id: &TokenId) -> TokenAmount {
+        self.get(token_id)
+            .map_or_else(|| TokenAmount::zero(*token_id), |native_token| (*native_token).into())
+    }
 }
 
 #[inline]
@@ -2,7 +2,7 @@
 // SPDX-License-Identifier: Apache-2.0
 
 pub mod string {
-    use alloc::string::String;
+    use alloc::{format, string::String};
     use core::{fmt::Display, str::FromStr};
 
     use serde::{de, Deserialize, Deserializer, Serializer};
@@ -21,12 +21,15 @@ pub mod string {
         T::Err: Display,
         D: Deserializer<'de>,
     {
-        String::deserialize(deserializer)?.parse().map_err(de::Error::custom)
+        let string = String::deserialize(deserializer)?;
+        string
+            .parse()
+            .map_err(|e| de::Error::custom(format!("can't parse `{string}`: {e}")))
     }
 }
 
 pub mod option_string {
-    use alloc::string::String;
+    use alloc::{format, string::String};
     use core::{fmt::Display, str::FromStr};
 
     use serde::{de, Deserialize, Deserializer, Serializer};
@@ -49,7 +52,11 @@ pub mod option_string {
         D: Deserializer<'de>,
     {
         Option::<String>::deserialize(deserializer)?
-            .map(|string| string.parse().map_err(de::Error::custom))
+            .map(|string| {
+                string
+                    .parse()
+                    .map_err(|e| de::Error::custom(format!("can't parse `{string}`: {e}")))
+            })
             .transpose()
     }
 }
@@ -7,7 +7,10 @@ use tokio::sync::RwLock;
 
 use crate::{
     client::secret::{SecretManage, SecretManager},
-    types::block::address::{Address, Bech32Address, Ed25519Address, Hrp},
+    types::block::{
+        address::{Address, Bech32Address, Ed25519Address, Hrp},
+        output::AliasId,
+    },
     wallet::{
         account::{types::AccountAddress, Account, AccountDetails},
         Error, Wallet,
@@ -19,6 +22,7 @@ pub struct AccountBuilder<S: SecretManage = SecretManager> {
     addresses: Option<Vec<AccountAddress>>,
     alias: Option<String>,
     bech32_hrp: Option<Hrp>,
+    identity_alias: Option<AliasId>,
     wallet: Wallet<S>,
 }
 
@@ -32,6 +36,7 @@ where
             addresses: None,
             alias: None,
             bech32_hrp: None,
+            identity_alias: None,
             wallet,
         }
     }
@@ -55,12 +60,25 @@ where
         self
     }
 
+    /// Set the alias output used as identity of the account, see [`Account::set_identity_alias()`]
+    pub fn with_identity_alias(mut self, alias_id: impl Into<Option<AliasId>>) -> Self {
+        self.identity_alias = alias_id.into();
+        self
+    }
+
     /// Build the Account and add it to the accounts from Wallet
     /// Also generates the first address of the account and if it's not the first account, the address for the first
     /// account will also be generated and compared, so no accounts get generated with different seeds
     pub async fn finish(&mut self) -> crate::wallet::Result<Account<S>> {
         let mut accounts = self.wallet.accounts.write().await;
-        let account_index = accounts.len() as u32;
+        let account_index = match self.wallet.account_index {
+            // A wallet bound to an account index only holds that account
+            Some(account_index) if !accounts.is_empty() => {
+                return Err(Error::AccountIndexAlreadyExists(account_index));
+            }
+            Some(account_index) => account_index,
+            None => accounts.len() as u32,
+        };
         // If no alias is provided, the account index will be set as alias
         let account_alias = self.alias.clone().unwrap_or_else(|| account_index.to_string());
         log::debug!(
@@ -118,7 +136,11 @@ where
                 let bech32_hrp = {
                     match bech32_hrp {
                         Some(bech32_hrp) => bech32_hrp,
-                        None => self.wallet.client().get_bech32_hrp().await?,
+                        None => {
+                            let bech32_hrp = self.wallet.client().get_bech32_hrp().await?;
+                            self.wallet.validate_network(bech32_hrp, coin_type, None)?;
+                            bech32_hrp
+                        }
                     }
                 };
 
@@ -151,6 +173,10 @@ where
             incoming_transactions: HashMap::new(),
             inaccessible_incoming_transactions: HashSet::new(),
             native_token_foundries: HashMap::new(),
+            identity_alias: self.identity_alias,
+            idempotency_keys: HashMap::new(),
+            remainder_indexes: HashMap::new(),
+            quarantined_outputs: HashSet::new(),
         };
 
         let account = Account::new(account, self.wallet.inner.clone()).await?;
@@ -8,12 +8,19 @@ pub(crate) const DEFAULT_OUTPUT_CONSOLIDATION_THRESHOLD: usize = 100;
 #[cfg(feature = "ledger_nano")]
 pub(crate) const DEFAULT_LEDGER_OUTPUT_CONSOLIDATION_THRESHOLD: usize = 15;
 
-/// Amount of API request that can be sent in parallel during syncing
+/// Default amount of addresses, transactions and foundries that are synced in parallel
 pub(crate) const PARALLEL_REQUESTS_AMOUNT: usize = 500;
 
 /// ms before an account actually syncs with the network, before it just returns the previous syncing result
 /// this is done to prevent unnecessary simultaneous synchronizations
 pub(crate) const MIN_SYNC_INTERVAL: u128 = 5;
 
+/// Amount of milestones since the previous sync of an address up to which it's synced incrementally, the utxo changes
+/// of each of these milestones have to be requested
+pub(crate) const MAX_INCREMENTAL_SYNC_MILESTONES: u32 = 100;
+
 // Default expiration time for [ExpirationUnlockCondition] when sending native tokens, one day in seconds
 pub(crate) const DEFAULT_EXPIRATION_TIME: u32 = 86400;
+
+/// ms after which the idempotency key of a confirmed or conflicting transaction is removed, seven days
+pub(crate) const IDEMPOTENCY_KEY_RETENTION: u128 = 7 * 24 * 60 * 60 * 1000;
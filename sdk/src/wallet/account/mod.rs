@@ -24,20 +24,26 @@ use tokio::sync::{Mutex, RwLock};
 
 #[cfg(feature = "participation")]
 pub use self::operations::participation::{AccountParticipationOverview, ParticipationEventWithNodes};
-use self::types::{
-    address::{AccountAddress, AddressWithUnspentOutputs},
-    Balance, OutputData, Transaction, TransactionDto,
+use self::{
+    operations::syncing::incremental::SyncCursors,
+    types::{
+        address::{AccountAddress, AddressWithUnspentOutputs},
+        Balance, OutputData, OutputFilter, Transaction, TransactionDto, Unlockability,
+    },
 };
 pub use self::{
     operations::{
+        chain_graph::{ChainEdge, ChainGraph, ChainNode, ChainNodeKind, ChainRelation},
         output_claiming::OutputsToClaim,
-        output_consolidation::ConsolidationParams,
+        output_consolidation::{ConsolidationParams, ConsolidationPreview},
         syncing::{
-            options::{AccountSyncOptions, AliasSyncOptions, NftSyncOptions},
+            options::{AccountSyncOptions, AliasSyncOptions, NativeTokenFilter, NftSyncOptions},
             SyncOptions,
         },
         transaction::{
             high_level::{
+                burning_melting::burn_plan::{BurnPlan, BurnPlanStep},
+                claim_link::{ClaimLink, ClaimLinkTransaction, ClaimLinkTransactionDto, CLAIM_LINK_SCHEME},
                 create_alias::CreateAliasParams,
                 minting::{
                     create_native_token::{
@@ -46,14 +52,23 @@ pub use self::{
                     },
                     mint_nfts::MintNftParams,
                 },
+                send::BulkSendReport,
+                send_native_tokens::{NativeTokensTransaction, NativeTokensTransactionDto, StorageDepositChoice},
+                swap::{SwapAcceptance, SwapAsset, SwapOffer},
+                vesting::{VestingRelease, VestingSchedule, VestingStatus, VESTING_TAG},
             },
             prepare_output::{Assets, Features, OutputParams, ReturnStrategy, StorageDeposit, Unlocks},
-            RemainderValueStrategy, TransactionOptions, TransactionOptionsDto,
+            CoinSelectionStrategy, RemainderValueStrategy, ServiceFee, ServiceFeeAmount, TransactionOptions,
+            TransactionOptionsDto,
         },
     },
     types::OutputDataDto,
 };
 use super::core::WalletInner;
+#[cfg(feature = "irc_27")]
+use crate::types::block::output::feature::Irc27Metadata;
+#[cfg(feature = "irc_30")]
+use crate::types::block::output::feature::Irc30Metadata;
 use crate::{
     client::{
         secret::{SecretManage, SecretManager},
@@ -62,6 +77,7 @@ use crate::{
     types::{
         api::core::response::OutputWithMetadataResponse,
         block::{
+            address::{Address, AliasAddress, Bech32Address},
             output::{dto::FoundryOutputDto, AliasId, FoundryId, FoundryOutput, NftId, Output, OutputId, TokenId},
             payload::{
                 transaction::{TransactionEssence, TransactionId},
@@ -73,7 +89,7 @@ use crate::{
     wallet::{account::types::InclusionState, Result},
 };
 
-/// Options to filter outputs
+/// Options to filter outputs, see [`OutputFilter`](types::OutputFilter) for more fine grained queries
 #[derive(Debug, Default, Clone, Serialize, Deserialize, Eq, PartialEq)]
 #[serde(rename_all = "camelCase")]
 pub struct FilterOptions {
@@ -135,6 +151,15 @@ pub struct AccountDetails {
     inaccessible_incoming_transactions: HashSet<TransactionId>,
     /// Foundries for native tokens in outputs
     native_token_foundries: HashMap<FoundryId, FoundryOutput>,
+    /// The alias output used as identity of the account, remainders are sent to its address and outputs owned by it
+    /// are synced
+    pub(crate) identity_alias: Option<AliasId>,
+    /// Transactions sent with an idempotency key, see [`TransactionOptions::idempotency_key`]
+    idempotency_keys: HashMap<String, TransactionId>,
+    /// The index of the remainder output of sent transactions, see [`Account::rebuild_conflicting_transaction`]
+    pub(crate) remainder_indexes: HashMap<TransactionId, u16>,
+    /// Unspent outputs which look like spam, see [`SyncOptions::quarantine_spam_outputs`]
+    quarantined_outputs: HashSet<OutputId>,
 }
 
 /// A thread guard over an account, so we can lock the account during operations.
@@ -167,6 +192,14 @@ pub struct AccountInner {
     // again, because sending transactions can change that
     pub(crate) last_synced: Mutex<u128>,
     pub(crate) default_sync_options: Mutex<SyncOptions>,
+    // milestones at which the addresses were synced, to only request the changes since then with incremental syncing
+    pub(crate) sync_cursors: Mutex<SyncCursors>,
+    // idempotency keys of transactions which are currently submitted, waiters are notified when one is released
+    pub(crate) pending_idempotency_keys: std::sync::Mutex<HashSet<String>>,
+    pub(crate) idempotency_key_released: tokio::sync::Notify,
+    // IRC27 metadata of the unspent nft outputs, parsed during syncing
+    #[cfg(feature = "irc_27")]
+    pub(crate) irc27_metadata_cache: RwLock<HashMap<NftId, Irc27Metadata>>,
 }
 
 // impl Deref so we can use `account.details()` instead of `account.details.read()`
@@ -201,6 +234,11 @@ where
                 details: RwLock::new(details),
                 last_synced: Default::default(),
                 default_sync_options: Mutex::new(default_sync_options),
+                sync_cursors: Default::default(),
+                pending_idempotency_keys: Default::default(),
+                idempotency_key_released: Default::default(),
+                #[cfg(feature = "irc_27")]
+                irc27_metadata_cache: Default::default(),
             }),
         })
     }
@@ -210,6 +248,20 @@ where
         &self.wallet.client
     }
 
+    /// Explains whether an output of the account can currently be unlocked, and if not, why not. Returns `None` if
+    /// the output isn't stored in the account.
+    pub async fn output_unlockability(&self, output_id: &OutputId) -> Result<Option<Unlockability>> {
+        let current_time = self.client().get_time_checked().await?;
+        let protocol_parameters = self.client().get_protocol_parameters().await?;
+        let account_details = self.details().await;
+
+        account_details
+            .outputs()
+            .get(output_id)
+            .map(|output_data| output_data.unlockability(&account_details, current_time, &protocol_parameters))
+            .transpose()
+    }
+
     /// Get the [`Output`] that minted a native token by the token ID. First try to get it
     /// from the account, if it isn't in the account try to get it from the node
     pub async fn get_foundry_output(&self, native_token_id: TokenId) -> Result<Output> {
@@ -230,6 +282,40 @@ where
         Ok(output_response.output().to_owned())
     }
 
+    /// Returns the address of the alias output used as identity of the account, if one is set
+    pub async fn identity_address(&self) -> Result<Option<Bech32Address>> {
+        let identity_alias = *self.details().await.identity_alias();
+        Ok(match identity_alias {
+            Some(alias_id) => Some(Bech32Address::new(
+                self.client().get_bech32_hrp().await?,
+                AliasAddress::new(alias_id),
+            )),
+            None => None,
+        })
+    }
+
+    /// Returns the IRC30 metadata of a native token from the immutable metadata feature of its foundry, the foundry
+    /// is requested from the node if it isn't known to the account.
+    #[cfg(feature = "irc_30")]
+    pub async fn token_metadata(&self, token_id: TokenId) -> Result<Option<Irc30Metadata>> {
+        let cached_foundry = self
+            .details()
+            .await
+            .native_token_foundries()
+            .get(&FoundryId::from(token_id))
+            .cloned();
+        let foundry = match cached_foundry {
+            Some(foundry) => Output::Foundry(foundry),
+            None => self.get_foundry_output(token_id).await?,
+        };
+
+        Ok(foundry
+            .immutable_features()
+            .and_then(|features| features.metadata())
+            .map(Irc30Metadata::try_from)
+            .transpose()?)
+    }
+
     /// Save the account to the database, accepts the updated_account as option so we don't need to drop it before
     /// saving
     #[cfg(feature = "storage")]
@@ -309,83 +395,42 @@ impl AccountInner {
 
     fn filter_outputs<'a>(
         &self,
+        account_details: &AccountDetails,
         outputs: impl Iterator<Item = &'a OutputData>,
         filter: impl Into<Option<FilterOptions>>,
     ) -> Result<Vec<OutputData>> {
-        let filter = filter.into();
-
-        if let Some(filter) = filter {
-            let mut filtered_outputs = Vec::new();
-
-            for output in outputs {
-                match &output.output {
-                    Output::Alias(alias) => {
-                        if let Some(alias_ids) = &filter.alias_ids {
-                            let alias_id = alias.alias_id_non_null(&output.output_id);
-                            if alias_ids.contains(&alias_id) {
-                                filtered_outputs.push(output.clone());
-                                continue;
-                            }
-                        }
-                    }
-                    Output::Foundry(foundry) => {
-                        if let Some(foundry_ids) = &filter.foundry_ids {
-                            let foundry_id = foundry.id();
-                            if foundry_ids.contains(&foundry_id) {
-                                filtered_outputs.push(output.clone());
-                                continue;
-                            }
-                        }
-                    }
-                    Output::Nft(nft) => {
-                        if let Some(nft_ids) = &filter.nft_ids {
-                            let nft_id = nft.nft_id_non_null(&output.output_id);
-                            if nft_ids.contains(&nft_id) {
-                                filtered_outputs.push(output.clone());
-                                continue;
-                            }
-                        }
-                    }
-                    _ => {}
-                }
-
-                if let Some(lower_bound_booked_timestamp) = filter.lower_bound_booked_timestamp {
-                    if output.metadata.milestone_timestamp_booked() < lower_bound_booked_timestamp {
-                        continue;
-                    }
-                }
-                if let Some(upper_bound_booked_timestamp) = filter.upper_bound_booked_timestamp {
-                    if output.metadata.milestone_timestamp_booked() > upper_bound_booked_timestamp {
-                        continue;
-                    }
-                }
-
-                if let Some(output_types) = &filter.output_types {
-                    if !output_types.contains(&output.output.kind()) {
-                        continue;
-                    }
-                }
-
-                // If ids are provided, only return them and no other outputs.
-                if filter.alias_ids.is_none() && filter.foundry_ids.is_none() && filter.nft_ids.is_none() {
-                    filtered_outputs.push(output.clone());
-                }
+        match filter.into() {
+            Some(filter) => {
+                let filter = OutputFilter::from(filter);
+                Ok(outputs
+                    .filter(|output_data| filter.matches(output_data, account_details))
+                    .cloned()
+                    .collect())
             }
-
-            Ok(filtered_outputs)
-        } else {
-            Ok(outputs.cloned().collect())
+            None => Ok(outputs.cloned().collect()),
         }
     }
 
     /// Returns outputs of the account
     pub async fn outputs(&self, filter: impl Into<Option<FilterOptions>> + Send) -> Result<Vec<OutputData>> {
-        self.filter_outputs(self.details().await.outputs.values(), filter)
+        let account_details = self.details().await;
+        self.filter_outputs(&account_details, account_details.outputs.values(), filter)
     }
 
     /// Returns unspent outputs of the account
     pub async fn unspent_outputs(&self, filter: impl Into<Option<FilterOptions>> + Send) -> Result<Vec<OutputData>> {
-        self.filter_outputs(self.details().await.unspent_outputs.values(), filter)
+        let account_details = self.details().await;
+        self.filter_outputs(&account_details, account_details.unspent_outputs.values(), filter)
+    }
+
+    /// Returns the outputs of the account matching the filter, use [`OutputFilter::Unspent`] to only get unspent ones
+    pub async fn query_outputs(&self, filter: OutputFilter) -> Vec<OutputData> {
+        self.details()
+            .await
+            .query_outputs(&filter)
+            .into_iter()
+            .cloned()
+            .collect()
     }
 
     /// Gets the unspent alias output matching the given ID.
@@ -418,6 +463,20 @@ impl AccountInner {
         .map(|res| res.first().cloned())
     }
 
+    /// Returns the IRC27 metadata of an unspent nft of the account, if it has valid metadata. Only available after
+    /// syncing.
+    #[cfg(feature = "irc_27")]
+    pub async fn nft_irc27_metadata(&self, nft_id: &NftId) -> Option<Irc27Metadata> {
+        self.irc27_metadata_cache.read().await.get(nft_id).cloned()
+    }
+
+    /// Returns the IRC27 metadata of all unspent nfts of the account with valid metadata. Only available after
+    /// syncing.
+    #[cfg(feature = "irc_27")]
+    pub async fn nfts_irc27_metadata(&self) -> HashMap<NftId, Irc27Metadata> {
+        self.irc27_metadata_cache.read().await.clone()
+    }
+
     /// Returns all incoming transactions of the account
     pub async fn incoming_transactions(&self) -> Vec<Transaction> {
         self.details().await.incoming_transactions.values().cloned().collect()
@@ -428,6 +487,14 @@ impl AccountInner {
         self.details().await.transactions.values().cloned().collect()
     }
 
+    /// Returns the transaction sent with the idempotency key, see [`TransactionOptions::idempotency_key`]
+    pub async fn get_transaction_by_idempotency_key(&self, idempotency_key: &str) -> Option<Transaction> {
+        self.details()
+            .await
+            .transaction_by_idempotency_key(idempotency_key)
+            .cloned()
+    }
+
     /// Returns all pending transactions of the account
     pub async fn pending_transactions(&self) -> Vec<Transaction> {
         let mut transactions = Vec::new();
@@ -450,6 +517,48 @@ impl AccountDetails {
         all_addresses.extend(self.internal_addresses().clone());
         all_addresses.to_vec()
     }
+
+    /// Returns the unspent output of an alias whose state controller is an address of the account
+    pub(crate) fn controlled_alias_output(&self, alias_id: &AliasId) -> Option<&OutputData> {
+        self.unspent_outputs
+            .values()
+            .find(|output_data| match &output_data.output {
+                Output::Alias(alias) => {
+                    &alias.alias_id_non_null(&output_data.output_id) == alias_id
+                        && self
+                            .addresses()
+                            .iter()
+                            .any(|address| address.address.inner() == alias.state_controller_address())
+                }
+                _ => false,
+            })
+    }
+
+    /// Returns the transaction sent with the idempotency key
+    pub(crate) fn transaction_by_idempotency_key(&self, idempotency_key: &str) -> Option<&Transaction> {
+        self.idempotency_keys
+            .get(idempotency_key)
+            .and_then(|transaction_id| self.transactions.get(transaction_id))
+    }
+
+    /// Returns the address of the identity alias, if one is set, erroring if the account doesn't control it
+    pub(crate) fn identity_alias_address(&self) -> Result<Option<Address>> {
+        self.identity_alias
+            .map(|alias_id| {
+                self.controlled_alias_output(&alias_id)
+                    .map(|_| Address::Alias(AliasAddress::new(alias_id)))
+                    .ok_or(crate::wallet::Error::AliasNotFoundInUnspentOutputs(alias_id))
+            })
+            .transpose()
+    }
+
+    /// Returns the outputs of the account matching the filter
+    pub fn query_outputs(&self, filter: &OutputFilter) -> Vec<&OutputData> {
+        self.outputs
+            .values()
+            .filter(|output_data| filter.matches(output_data, self))
+            .collect()
+    }
 }
 
 pub(crate) fn build_transaction_from_payload_and_inputs(
@@ -505,6 +614,18 @@ pub struct AccountDetailsDto {
     /// Foundries for native tokens in outputs
     #[serde(default)]
     pub native_token_foundries: HashMap<FoundryId, FoundryOutputDto>,
+    /// The alias output used as identity of the account
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub identity_alias: Option<AliasId>,
+    /// Transactions sent with an idempotency key
+    #[serde(default, skip_serializing_if = "HashMap::is_empty")]
+    pub idempotency_keys: HashMap<String, TransactionId>,
+    /// The index of the remainder output of sent transactions
+    #[serde(default, skip_serializing_if = "HashMap::is_empty")]
+    pub remainder_indexes: HashMap<TransactionId, u16>,
+    /// Unspent outputs which look like spam
+    #[serde(default, skip_serializing_if = "HashSet::is_empty")]
+    pub quarantined_outputs: HashSet<OutputId>,
 }
 
 impl TryFromDto for AccountDetails {
@@ -550,6 +671,10 @@ impl TryFromDto for AccountDetails {
                 .into_iter()
                 .map(|(id, o)| Ok((id, FoundryOutput::try_from_dto_with_params(o, &params)?)))
                 .collect::<crate::wallet::Result<_>>()?,
+            identity_alias: dto.identity_alias,
+            idempotency_keys: dto.idempotency_keys,
+            remainder_indexes: dto.remainder_indexes,
+            quarantined_outputs: dto.quarantined_outputs,
         })
     }
 }
@@ -590,6 +715,10 @@ impl From<&AccountDetails> for AccountDetailsDto {
                 .iter()
                 .map(|(id, foundry)| (*id, FoundryOutputDto::from(foundry)))
                 .collect(),
+            identity_alias: *value.identity_alias(),
+            idempotency_keys: value.idempotency_keys().clone(),
+            remainder_indexes: value.remainder_indexes().clone(),
+            quarantined_outputs: value.quarantined_outputs().clone(),
         }
     }
 }
@@ -694,6 +823,19 @@ mod test {
             incoming_transactions,
             inaccessible_incoming_transactions: HashSet::new(),
             native_token_foundries: HashMap::new(),
+            identity_alias: None,
+            idempotency_keys: HashMap::from([(
+                "payout-1".to_string(),
+                TransactionId::from_str("0x131fc4cb8f315ae36ae3bf6a4e4b3486d5f17581288f1217410da3e0700d195a").unwrap(),
+            )]),
+            remainder_indexes: HashMap::from([(
+                TransactionId::from_str("0x131fc4cb8f315ae36ae3bf6a4e4b3486d5f17581288f1217410da3e0700d195a").unwrap(),
+                1,
+            )]),
+            quarantined_outputs: HashSet::from([OutputId::from_str(
+                "0x131fc4cb8f315ae36ae3bf6a4e4b3486d5f17581288f1217410da3e0700d195a0000",
+            )
+            .unwrap()]),
         };
 
         let deser_account = AccountDetails::try_from_dto(
@@ -737,6 +879,10 @@ mod test {
                 incoming_transactions: HashMap::new(),
                 inaccessible_incoming_transactions: HashSet::new(),
                 native_token_foundries: HashMap::new(),
+                identity_alias: None,
+                idempotency_keys: HashMap::new(),
+                remainder_indexes: HashMap::new(),
+                quarantined_outputs: HashSet::new(),
             }
         }
     }
@@ -2,7 +2,10 @@
 // SPDX-License-Identifier: Apache-2.0
 
 #[cfg(feature = "ledger_nano")]
-use crate::client::secret::{ledger_nano::LedgerSecretManager, DowncastSecretManager};
+use crate::{
+    client::secret::{ledger_nano::LedgerSecretManager, DowncastSecretManager},
+    types::block::address::Address,
+};
 use crate::{
     client::secret::{GenerateAddressOptions, SecretManage},
     types::block::address::Bech32Address,
@@ -193,4 +196,83 @@ where
 
         Ok(result)
     }
+
+    /// Display an address of the account on the Ledger Nano device, so the user can check it against the address
+    /// shown by the wallet and confirm it. Returns `false` if the user rejected the address on the device.
+    ///
+    /// The address has to be generated and stored in the account already, else
+    /// [`Error::AddressNotFoundInAccount`](crate::wallet::Error::AddressNotFoundInAccount) is returned.
+    /// ```ignore
+    /// if !account.verify_address_on_device(0, false).await? {
+    ///     println!("address rejected on the device");
+    /// }
+    /// ```
+    #[cfg(feature = "ledger_nano")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "ledger_nano")))]
+    pub async fn verify_address_on_device(&self, address_index: u32, internal: bool) -> crate::wallet::Result<bool> {
+        log::debug!("[ADDRESS GENERATION] verifying address {address_index} on the device, internal: {internal}");
+        let account_details = self.details().await;
+        let (coin_type, account_index) = (account_details.coin_type, account_details.index);
+        let stored_address = if internal {
+            account_details.internal_addresses.get(address_index as usize)
+        } else {
+            account_details.public_addresses.get(address_index as usize)
+        }
+        .map(|address| address.address);
+        drop(account_details);
+
+        use crate::wallet::account::SecretManager;
+        let secret_manager = self.wallet.secret_manager.read().await;
+        let ledger_nano = secret_manager
+            .downcast::<LedgerSecretManager>()
+            .or_else(|| {
+                secret_manager.downcast::<SecretManager>().and_then(|s| {
+                    if let SecretManager::LedgerNano(n) = s {
+                        Some(n)
+                    } else {
+                        None
+                    }
+                })
+            })
+            .ok_or(crate::client::Error::SecretManagerMismatch)?;
+
+        // Generate without prompt first, to make sure that the device derives the address stored in the account
+        let mut options = GenerateAddressOptions {
+            internal,
+            ledger_nano_prompt: false,
+        };
+        let device_address = Address::Ed25519(
+            ledger_nano
+                .generate_ed25519_addresses(coin_type, account_index, address_index..address_index + 1, options)
+                .await?[0],
+        );
+        #[cfg_attr(not(feature = "events"), allow(unused_variables))]
+        let address = match stored_address {
+            Some(address) if address.inner() == &device_address => address,
+            _ => {
+                return Err(crate::wallet::Error::AddressNotFoundInAccount(Bech32Address::new(
+                    self.client().get_bech32_hrp().await?,
+                    device_address,
+                )));
+            }
+        };
+
+        #[cfg(feature = "events")]
+        self.emit(
+            account_index,
+            WalletEvent::LedgerAddressGeneration(AddressData { address }),
+        )
+        .await;
+
+        // Generate with prompt so the user can verify
+        options.ledger_nano_prompt = true;
+        match ledger_nano
+            .generate_ed25519_addresses(coin_type, account_index, address_index..address_index + 1, options)
+            .await
+        {
+            Ok(_) => Ok(true),
+            Err(crate::client::Error::Ledger(crate::client::secret::ledger_nano::Error::DeniedByUser)) => Ok(false),
+            Err(e) => Err(e.into()),
+        }
+    }
 }
@@ -1,13 +1,11 @@
 // Copyright 2022 IOTA Stiftung
 // SPDX-License-Identifier: Apache-2.0
 
-use primitive_types::U256;
-
 use crate::{
     client::secret::SecretManage,
     types::block::{
-        address::Bech32Address,
-        output::{unlock_condition::UnlockCondition, FoundryId, NativeTokensBuilder, Output, Rent},
+        address::{Address, Bech32Address},
+        output::{unlock_condition::UnlockCondition, FoundryId, NativeTokensBuilder, Output, Rent, TokenAmount},
         ConvertTo,
     },
     wallet::{
@@ -75,6 +73,27 @@ where
 
         let claimable_outputs = account_details.claimable_outputs(OutputsToClaim::All, local_time)?;
 
+        let is_account_address = |address: &Address| account_addresses.iter().any(|a| a.address.inner == *address);
+        // If output has a StorageDepositReturnUnlockCondition, the amount of it should be subtracted, because this part
+        // needs to be sent back
+        let amount_without_return = |output: &Output| {
+            output
+                .unlock_conditions()
+                .and_then(|u| u.storage_deposit_return())
+                .map_or_else(
+                    || output.amount(),
+                    |sdr| {
+                        if is_account_address(sdr.return_address()) {
+                            // sending to ourself, we get the full amount
+                            output.amount()
+                        } else {
+                            // Sending to someone else
+                            output.amount() - sdr.amount()
+                        }
+                    },
+                )
+        };
+
         for address_with_unspent_outputs in addresses_with_unspent_outputs {
             #[cfg(feature = "participation")]
             {
@@ -86,6 +105,10 @@ where
             }
 
             for output_id in &address_with_unspent_outputs.output_ids {
+                // Quarantined spam outputs aren't part of the balance
+                if account_details.quarantined_outputs.contains(output_id) {
+                    continue;
+                }
                 if let Some(data) = account_details.unspent_outputs.get(output_id) {
                     // Check if output is from the network we're currently connected to
                     if data.network_id != network_id {
@@ -188,27 +211,7 @@ where
                                         );
 
                                     if output_can_be_unlocked_now_and_in_future {
-                                        // If output has a StorageDepositReturnUnlockCondition, the amount of it should
-                                        // be subtracted, because this part
-                                        // needs to be sent back
-                                        let amount = output
-                                            .unlock_conditions()
-                                            .and_then(|u| u.storage_deposit_return())
-                                            .map_or_else(
-                                                || output.amount(),
-                                                |sdr| {
-                                                    if account_addresses
-                                                        .iter()
-                                                        .any(|a| a.address.inner == *sdr.return_address())
-                                                    {
-                                                        // sending to ourself, we get the full amount
-                                                        output.amount()
-                                                    } else {
-                                                        // Sending to someone else
-                                                        output.amount() - sdr.amount()
-                                                    }
-                                                },
-                                            );
+                                        let amount = amount_without_return(output);
 
                                         // add nft_id for nft outputs
                                         if let Output::Nft(output) = &output {
@@ -247,21 +250,44 @@ where
                                     } else {
                                         // only add outputs that can't be locked now and at any point in the future
                                         balance.potentially_locked_outputs.insert(*output_id, true);
+                                        // Claimable now, but returns to the sender when it expires
+                                        balance.breakdown.expiring_away += amount_without_return(output);
                                     }
                                 } else {
-                                    // Don't add expired outputs that can't ever be unlocked by us
-                                    if let Some(expiration) = output
+                                    let unlock_conditions = output
                                         .unlock_conditions()
-                                        .expect("output needs to have unlock conditions")
-                                        .expiration()
-                                    {
+                                        .expect("output needs to have unlock conditions");
+                                    let timelock = unlock_conditions
+                                        .timelock()
+                                        .map(|timelock| timelock.timestamp())
+                                        .filter(|timestamp| local_time < *timestamp);
+                                    let is_receiver = unlock_conditions
+                                        .address()
+                                        .is_some_and(|address| is_account_address(address.address()));
+
+                                    // Don't add expired outputs that can't ever be unlocked by us
+                                    if let Some(expiration) = unlock_conditions.expiration() {
                                         // Not expired, could get unlockable when it's expired, so we insert it
                                         if local_time < expiration.timestamp() {
                                             balance.potentially_locked_outputs.insert(*output_id, false);
+                                            if !is_receiver {
+                                                // Sent by us and not claimed yet
+                                                balance.breakdown.expiring_to_us += output.amount();
+                                            }
                                         }
                                     } else {
                                         balance.potentially_locked_outputs.insert(*output_id, false);
                                     }
+
+                                    if let Some(timestamp) = timelock.filter(|_| is_receiver) {
+                                        balance.breakdown.timelocked += amount_without_return(output);
+                                        balance.breakdown.next_unlock = Some(
+                                            balance
+                                                .breakdown
+                                                .next_unlock
+                                                .map_or(timestamp, |next_unlock| next_unlock.min(timestamp)),
+                                        );
+                                    }
                                 }
                             }
                         }
@@ -294,8 +320,10 @@ where
         let mut locked_native_tokens = NativeTokensBuilder::default();
 
         for locked_output in &account_details.locked_outputs {
-            // Skip potentially_locked_outputs, as their amounts aren't added to the balance
-            if balance.potentially_locked_outputs.contains_key(locked_output) {
+            // Skip potentially_locked_outputs and quarantined outputs, as their amounts aren't added to the balance
+            if balance.potentially_locked_outputs.contains_key(locked_output)
+                || account_details.quarantined_outputs.contains(locked_output)
+            {
                 continue;
             }
             if let Some(output_data) = account_details.unspent_outputs.get(locked_output) {
@@ -316,17 +344,20 @@ where
             total_rent_amount,
         );
 
+        balance.breakdown.pending_reserved = locked_amount;
+        balance.breakdown.storage_deposit_locked = total_rent_amount;
         locked_amount += total_rent_amount;
 
         for native_token in total_native_tokens.finish_set()? {
+            let total = TokenAmount::from(native_token);
             // Check if some amount is currently locked
-            let locked_native_token_amount = locked_native_tokens.iter().find_map(|(id, amount)| {
-                if id == native_token.token_id() {
-                    Some(amount)
-                } else {
-                    None
-                }
-            });
+            let locked = TokenAmount::new(
+                *native_token.token_id(),
+                locked_native_tokens
+                    .get(native_token.token_id())
+                    .copied()
+                    .unwrap_or_default(),
+            );
 
             let metadata = account_details
                 .native_token_foundries
@@ -336,8 +367,8 @@ where
 
             balance.native_tokens.push(NativeTokensBalance {
                 token_id: *native_token.token_id(),
-                total: native_token.amount(),
-                available: native_token.amount() - *locked_native_token_amount.unwrap_or(&U256::from(0u8)),
+                total: total.amount(),
+                available: total.saturating_sub(locked)?.amount(),
                 metadata,
             })
         }
@@ -354,6 +385,7 @@ where
                 .saturating_sub(locked_amount)
                 .saturating_sub(balance.base_coin.voting_power);
         }
+        balance.breakdown.spendable = balance.base_coin.available;
 
         Ok(balance)
     }
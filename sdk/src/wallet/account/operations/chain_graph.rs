@@ -0,0 +1,179 @@
+// Copyright 2024 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use std::{collections::HashMap, fmt::Write};
+
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    client::secret::SecretManage,
+    types::block::{
+        address::{Address, AliasAddress, NftAddress},
+        output::{ChainId, Output, OutputId},
+    },
+    wallet::account::Account,
+};
+
+/// The kind of an output in a [`ChainGraph`].
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum ChainNodeKind {
+    Alias,
+    Foundry,
+    Nft,
+    /// A basic output, only part of the graph if it's owned by an alias or NFT.
+    Basic,
+}
+
+/// An unspent output of the account in a [`ChainGraph`].
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ChainNode {
+    pub output_id: OutputId,
+    pub kind: ChainNodeKind,
+    /// The alias, foundry or NFT ID, `None` for basic outputs.
+    pub chain_id: Option<ChainId>,
+    #[serde(with = "crate::utils::serde::string")]
+    pub amount: u64,
+}
+
+/// How the `owner` output of a [`ChainEdge`] is related to the `dependent` output.
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum ChainRelation {
+    /// The alias controls the foundry, which has to be destroyed before the alias can be.
+    ControlsFoundry,
+    /// The alias or NFT address is the address unlock condition of the output.
+    Owns,
+    /// The alias or NFT address is the state controller of the alias.
+    StateController,
+    /// The alias or NFT address is the governor of the alias.
+    Governor,
+}
+
+/// A dependency between two outputs of a [`ChainGraph`], the `dependent` output can only be unlocked with the `owner`
+/// output.
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ChainEdge {
+    pub owner: OutputId,
+    pub dependent: OutputId,
+    pub relation: ChainRelation,
+}
+
+/// The dependencies between the unspent alias, foundry and NFT outputs of an account and the outputs they own.
+#[derive(Clone, Debug, Default, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ChainGraph {
+    pub nodes: Vec<ChainNode>,
+    pub edges: Vec<ChainEdge>,
+}
+
+impl ChainGraph {
+    /// Returns the edges of the outputs which depend on the given output.
+    pub fn dependents<'a>(&'a self, output_id: &'a OutputId) -> impl Iterator<Item = &'a ChainEdge> + 'a {
+        self.edges.iter().filter(move |edge| &edge.owner == output_id)
+    }
+
+    /// Renders the graph in the DOT language of Graphviz.
+    pub fn to_dot(&self) -> String {
+        let mut dot = String::from("digraph chains {\n");
+
+        for node in &self.nodes {
+            let label = node.chain_id.as_ref().map_or_else(
+                || format!("{:?} {}", node.kind, node.output_id),
+                |chain_id| format!("{:?} {chain_id}", node.kind),
+            );
+            writeln!(dot, "    \"{}\" [label=\"{label}\\n{}\"];", node.output_id, node.amount)
+                .expect("writing to a string can't fail");
+        }
+        for edge in &self.edges {
+            writeln!(
+                dot,
+                "    \"{}\" -> \"{}\" [label=\"{:?}\"];",
+                edge.owner, edge.dependent, edge.relation
+            )
+            .expect("writing to a string can't fail");
+        }
+        dot.push('}');
+
+        dot
+    }
+}
+
+impl<S: 'static + SecretManage> Account<S> {
+    /// Returns the [`ChainGraph`] of the unspent outputs of the account, which shows the foundries that have to be
+    /// destroyed and the outputs that have to be moved before an alias or NFT can be destroyed or burned.
+    pub async fn chain_graph(&self) -> ChainGraph {
+        let account_details = self.details().await;
+        let unspent_outputs = account_details.unspent_outputs();
+
+        // Addresses of the chains of the account, to find the outputs they own.
+        let chain_addresses = unspent_outputs
+            .iter()
+            .filter_map(|(output_id, output_data)| {
+                let address = match &output_data.output {
+                    Output::Alias(alias) => Address::from(AliasAddress::new(alias.alias_id_non_null(output_id))),
+                    Output::Nft(nft) => Address::from(NftAddress::new(nft.nft_id_non_null(output_id))),
+                    _ => return None,
+                };
+                Some((address, *output_id))
+            })
+            .collect::<HashMap<_, _>>();
+
+        let mut graph = ChainGraph::default();
+
+        for (output_id, output_data) in unspent_outputs {
+            let output = &output_data.output;
+            let mut edges = Vec::new();
+
+            if let Some(unlock_conditions) = output.unlock_conditions() {
+                if let Some(immutable_alias_address) = unlock_conditions.immutable_alias_address() {
+                    edges.push((immutable_alias_address.address(), ChainRelation::ControlsFoundry));
+                }
+                if let Some(address) = unlock_conditions.address() {
+                    edges.push((address.address(), ChainRelation::Owns));
+                }
+                if let Some(state_controller) = unlock_conditions.state_controller_address() {
+                    edges.push((state_controller.address(), ChainRelation::StateController));
+                }
+                if let Some(governor) = unlock_conditions.governor_address() {
+                    edges.push((governor.address(), ChainRelation::Governor));
+                }
+            }
+
+            let edges = edges
+                .into_iter()
+                .filter_map(|(address, relation)| {
+                    chain_addresses.get(address).map(|owner| ChainEdge {
+                        owner: *owner,
+                        dependent: *output_id,
+                        relation,
+                    })
+                })
+                .collect::<Vec<_>>();
+
+            let kind = match output {
+                Output::Alias(_) => ChainNodeKind::Alias,
+                Output::Foundry(_) => ChainNodeKind::Foundry,
+                Output::Nft(_) => ChainNodeKind::Nft,
+                // Basic outputs owned by an Ed25519 address don't depend on anything.
+                Output::Basic(_) if !edges.is_empty() => ChainNodeKind::Basic,
+                _ => continue,
+            };
+
+            graph.nodes.push(ChainNode {
+                output_id: *output_id,
+                kind,
+                chain_id: output.chain_id().map(|chain_id| chain_id.or_from_output_id(output_id)),
+                amount: output.amount(),
+            });
+            graph.edges.extend(edges);
+        }
+
+        graph.nodes.sort_by_key(|node| node.output_id);
+        graph.edges.sort_by_key(|edge| (edge.owner, edge.dependent));
+
+        graph
+    }
+}
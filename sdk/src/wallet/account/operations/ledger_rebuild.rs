@@ -0,0 +1,171 @@
+// Copyright 2024 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use std::collections::{BTreeMap, HashMap, HashSet};
+
+#[cfg(feature = "events")]
+use crate::wallet::events::types::{LedgerRebuildEvent, WalletEvent};
+use crate::{
+    client::secret::SecretManage,
+    types::{
+        block::{
+            address::Address,
+            output::{Output, OutputId},
+        },
+        TryFromDto,
+    },
+    wallet::account::{operations::syncing::SyncOptions, types::Balance, Account, AccountDetails},
+};
+
+impl AccountDetails {
+    // Discards the outputs and transactions, returns the annotations of the outputs so they can be restored.
+    // Pending transactions and locked outputs are kept, so transactions in flight are synced again instead of their
+    // inputs being selected by another transaction.
+    fn clear_ledger(&mut self) -> HashMap<OutputId, BTreeMap<String, String>> {
+        let annotations = self
+            .outputs
+            .drain()
+            .filter(|(_, output_data)| !output_data.annotations.is_empty())
+            .map(|(output_id, output_data)| (output_id, output_data.annotations))
+            .collect();
+
+        self.unspent_outputs.clear();
+        self.addresses_with_unspent_outputs.clear();
+        let pending_transactions = &self.pending_transactions;
+        self.transactions
+            .retain(|transaction_id, _| pending_transactions.contains(transaction_id));
+        self.incoming_transactions.clear();
+        self.inaccessible_incoming_transactions.clear();
+        self.native_token_foundries.clear();
+        self.quarantined_outputs.clear();
+
+        annotations
+    }
+
+    // Drops the locks of outputs which aren't unspent anymore and the idempotency keys and remainder indexes of
+    // transactions which weren't found again
+    fn prune_rebuilt_ledger(&mut self) {
+        let unspent_outputs = &self.unspent_outputs;
+        self.locked_outputs
+            .retain(|output_id| unspent_outputs.contains_key(output_id));
+        let transactions = &self.transactions;
+        self.idempotency_keys
+            .retain(|_, transaction_id| transactions.contains_key(transaction_id));
+        self.remainder_indexes
+            .retain(|transaction_id, _| transactions.contains_key(transaction_id));
+    }
+
+    // Incoming transactions which consumed outputs of the account were sent by it, e.g. the ones which created its
+    // remainders
+    fn restore_sent_transactions(&mut self) {
+        let addresses = self
+            .addresses()
+            .into_iter()
+            .map(|address| *address.address.inner())
+            .collect::<HashSet<_>>();
+
+        let sent_transaction_ids = self
+            .incoming_transactions
+            .iter()
+            .filter(|(_, transaction)| {
+                transaction.inputs.iter().any(|input| {
+                    Output::try_from_dto(input.output.clone())
+                        .ok()
+                        .and_then(|output| owner_address(&output))
+                        .is_some_and(|address| addresses.contains(&address))
+                })
+            })
+            .map(|(transaction_id, _)| *transaction_id)
+            .collect::<Vec<_>>();
+
+        for transaction_id in sent_transaction_ids {
+            if let Some(mut transaction) = self.incoming_transactions.remove(&transaction_id) {
+                transaction.incoming = false;
+                self.transactions.insert(transaction_id, transaction);
+            }
+        }
+    }
+}
+
+// The address which has to unlock an output, ignoring expirations
+fn owner_address(output: &Output) -> Option<Address> {
+    let unlock_conditions = output.unlock_conditions()?;
+
+    unlock_conditions
+        .address()
+        .map(|unlock_condition| *unlock_condition.address())
+        .or_else(|| {
+            unlock_conditions
+                .state_controller_address()
+                .map(|unlock_condition| *unlock_condition.address())
+        })
+}
+
+impl<S: 'static + SecretManage> Account<S>
+where
+    crate::wallet::Error: From<S::Error>,
+{
+    /// Discards the outputs and transactions of the account and syncs them from the node again, to recover from a
+    /// corrupted local state. Unlike a regular sync, nothing known locally is kept, except the addresses, the
+    /// annotations of outputs which are found again and the pending transactions with their locked inputs, which are
+    /// synced again.
+    ///
+    /// The outputs are found with the indexer, the transactions which created them are requested from the node and
+    /// the permanodes of the client. Transactions which consumed outputs of the account are restored as sent
+    /// transactions, other sent transactions can't be found anymore once all their outputs are spent.
+    pub async fn rebuild_ledger_from_history(&self, options: Option<SyncOptions>) -> crate::wallet::Result<Balance> {
+        log::debug!("[rebuild_ledger_from_history]");
+        let mut options = match options {
+            Some(options) => options,
+            None => self.default_sync_options().await,
+        };
+        options.force_syncing = true;
+        options.incremental = false;
+        options.sync_incoming_transactions = true;
+        options.sync_pending_transactions = true;
+
+        let annotations = self.details_mut().await.clear_ledger();
+        *self.sync_cursors.lock().await = Default::default();
+        #[cfg(feature = "irc_27")]
+        self.irc27_metadata_cache.write().await.clear();
+        #[cfg(feature = "events")]
+        self.emit(
+            self.details().await.index,
+            WalletEvent::LedgerRebuild(LedgerRebuildEvent::Cleared),
+        )
+        .await;
+
+        self.sync(Some(options)).await?;
+
+        let mut account_details = self.details_mut().await;
+        for (output_id, annotations) in annotations {
+            if let Some(output_data) = account_details.unspent_outputs.get_mut(&output_id) {
+                output_data.annotations = annotations.clone();
+            }
+            if let Some(output_data) = account_details.outputs.get_mut(&output_id) {
+                output_data.annotations = annotations;
+            }
+        }
+        account_details.restore_sent_transactions();
+        account_details.prune_rebuilt_ledger();
+
+        #[cfg(feature = "storage")]
+        self.save(Some(&account_details)).await?;
+
+        #[cfg(feature = "events")]
+        {
+            let event = LedgerRebuildEvent::Finished {
+                unspent_outputs: account_details.unspent_outputs.len(),
+                transactions: account_details.transactions.len(),
+                incoming_transactions: account_details.incoming_transactions.len(),
+            };
+            let account_index = account_details.index;
+            drop(account_details);
+            self.emit(account_index, WalletEvent::LedgerRebuild(event)).await;
+        }
+        #[cfg(not(feature = "events"))]
+        drop(account_details);
+
+        self.balance().await
+    }
+}
@@ -5,6 +5,8 @@
 pub(crate) mod address_generation;
 /// The module to get the accounts balance
 pub(crate) mod balance;
+/// The module for the dependency graph of the chain outputs
+pub(crate) mod chain_graph;
 /// Helper functions
 pub(crate) mod helpers;
 /// The module for claiming of outputs with
@@ -13,14 +15,22 @@ pub(crate) mod helpers;
 pub(crate) mod output_claiming;
 /// The module for the output consolidation
 pub(crate) mod output_consolidation;
+/// The module to rebuild the ledger of an account from the node
+pub(crate) mod ledger_rebuild;
 /// The module to find additional addresses with unspent outputs
 pub(crate) mod output_finder;
+/// The module for local annotations of outputs
+pub(crate) mod output_annotations;
 /// The module for participation
 #[cfg(feature = "participation")]
 pub(crate) mod participation;
+/// The module for the quarantine of spam outputs
+pub(crate) mod quarantine;
 /// The module for retrying blocks or transactions
 pub(crate) mod retry;
 /// The module for synchronization of an account
 pub(crate) mod syncing;
 /// The module for transactions
 pub(crate) mod transaction;
+/// The module for proofs of confirmed transactions
+pub(crate) mod transaction_proof;
@@ -0,0 +1,82 @@
+// Copyright 2024 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use std::collections::BTreeMap;
+
+use crate::{
+    client::secret::SecretManage,
+    types::block::output::OutputId,
+    wallet::account::{Account, AccountDetails},
+};
+
+impl AccountDetails {
+    // Outputs are stored twice if unspent, so both have to be updated
+    fn update_output_annotations<T>(
+        &mut self,
+        output_id: &OutputId,
+        mut f: impl FnMut(&mut BTreeMap<String, String>) -> T,
+    ) -> crate::wallet::Result<T> {
+        if let Some(output_data) = self.unspent_outputs.get_mut(output_id) {
+            f(&mut output_data.annotations);
+        }
+        let output_data = self
+            .outputs
+            .get_mut(output_id)
+            .ok_or(crate::wallet::Error::OutputNotFoundInAccount(*output_id))?;
+
+        Ok(f(&mut output_data.annotations))
+    }
+}
+
+impl<S: 'static + SecretManage> Account<S>
+where
+    crate::wallet::Error: From<S::Error>,
+{
+    /// Sets a local annotation of an output of the account, replacing a previous value of the key. Annotations are
+    /// only known to the account, stored with it and returned in
+    /// [`OutputData::annotations`](crate::wallet::account::types::OutputData::annotations).
+    /// ```ignore
+    /// account.set_output_annotation(&output_id, "escrow", "42").await?;
+    /// let escrow_outputs = account
+    ///     .query_outputs(OutputFilter::Annotation {
+    ///         key: "escrow".to_string(),
+    ///         value: None,
+    ///     })
+    ///     .await;
+    /// ```
+    pub async fn set_output_annotation(
+        &self,
+        output_id: &OutputId,
+        key: impl Into<String> + Send,
+        value: impl Into<String> + Send,
+    ) -> crate::wallet::Result<()> {
+        let (key, value) = (key.into(), value.into());
+        log::debug!("[set_output_annotation] {output_id} {key}");
+        let mut account_details = self.details_mut().await;
+        account_details
+            .update_output_annotations(output_id, |annotations| annotations.insert(key.clone(), value.clone()))?;
+
+        #[cfg(feature = "storage")]
+        self.save(Some(&account_details)).await?;
+
+        Ok(())
+    }
+
+    /// Removes a local annotation of an output of the account and returns its value, if there was one.
+    pub async fn remove_output_annotation(
+        &self,
+        output_id: &OutputId,
+        key: &str,
+    ) -> crate::wallet::Result<Option<String>> {
+        log::debug!("[remove_output_annotation] {output_id} {key}");
+        let mut account_details = self.details_mut().await;
+        let value = account_details.update_output_annotations(output_id, |annotations| annotations.remove(key))?;
+
+        #[cfg(feature = "storage")]
+        if value.is_some() {
+            self.save(Some(&account_details)).await?;
+        }
+
+        Ok(value)
+    }
+}
@@ -53,8 +53,11 @@ impl AccountDetails {
             .iter()
             .filter(|(_, o)| o.output.is_basic() || o.output.is_nft())
         {
-            // Don't use outputs that are locked for other transactions
-            if !self.locked_outputs.contains(output_id) && self.outputs.contains_key(output_id) {
+            // Don't use outputs that are locked for other transactions or quarantined as spam
+            if !self.locked_outputs.contains(output_id)
+                && !self.quarantined_outputs.contains(output_id)
+                && self.outputs.contains_key(output_id)
+            {
                 if let Some(unlock_conditions) = output_data.output.unlock_conditions() {
                     // If there is a single [UnlockCondition], then it's an
                     // [AddressUnlockCondition] and we own it already without
@@ -121,6 +124,50 @@ impl AccountDetails {
         );
         Ok(output_ids_to_claim.into_iter().collect())
     }
+
+    /// Get basic outputs that were sent by the account with a [`StorageDepositReturnUnlockCondition`] and whose
+    /// [`ExpirationUnlockCondition`](crate::types::block::output::unlock_condition::ExpirationUnlockCondition)
+    /// lapsed, so they can be unlocked by the account again
+    pub(crate) fn reclaimable_outputs(&self, time: u32) -> Vec<OutputData> {
+        log::debug!("[AccountDetails] reclaimable_outputs");
+
+        let is_account_address = |address: &Address| {
+            self.addresses_with_unspent_outputs
+                .iter()
+                .any(|a| a.address.inner == *address)
+        };
+
+        let reclaimable_outputs = self
+            .unspent_outputs
+            .values()
+            .filter(|output_data| {
+                // Don't use outputs that are locked for other transactions
+                if self.locked_outputs.contains(&output_data.output_id) {
+                    return false;
+                }
+                let Output::Basic(basic_output) = &output_data.output else {
+                    return false;
+                };
+                let unlock_conditions = basic_output.unlock_conditions();
+
+                !unlock_conditions.is_time_locked(time)
+                    && unlock_conditions
+                        .storage_deposit_return()
+                        .is_some_and(|sdr| is_account_address(sdr.return_address()))
+                    && unlock_conditions
+                        .expiration()
+                        .and_then(|expiration| expiration.return_address_expired(time))
+                        .is_some_and(is_account_address)
+            })
+            .cloned()
+            .collect::<Vec<_>>();
+
+        log::debug!(
+            "[OUTPUT_CLAIMING] available outputs to reclaim: {}",
+            reclaimable_outputs.len()
+        );
+        reclaimable_outputs
+    }
 }
 
 impl<S: 'static + SecretManage> Account<S>
@@ -141,6 +188,16 @@ where
         account_details.claimable_outputs(outputs_to_claim, local_time)
     }
 
+    /// Get basic outputs that were sent by the account with a [`StorageDepositReturnUnlockCondition`] and returned to
+    /// it, because the receiver didn't claim them before their
+    /// [`ExpirationUnlockCondition`](crate::types::block::output::unlock_condition::ExpirationUnlockCondition)
+    /// lapsed. They're found during syncing and can be reclaimed with [`Account::reclaim_expired_outputs()`].
+    pub async fn reclaimable_outputs(&self) -> crate::wallet::Result<Vec<OutputData>> {
+        let local_time = self.client().get_time_checked().await?;
+
+        Ok(self.details().await.reclaimable_outputs(local_time))
+    }
+
     /// Get basic outputs that have only one unlock condition which is [AddressUnlockCondition], so they can be used as
     /// additional inputs
     pub(crate) async fn get_basic_outputs_for_additional_inputs(&self) -> crate::wallet::Result<Vec<OutputData>> {
@@ -431,6 +488,37 @@ where
     }
 }
 
+impl<S: 'static + SecretManage> Account<S>
+where
+    crate::wallet::Error: From<S::Error>,
+{
+    /// Send the amount and native tokens of all outputs from [`Account::reclaimable_outputs()`] to the account.
+    pub async fn reclaim_expired_outputs(&self) -> crate::wallet::Result<Transaction> {
+        log::debug!("[OUTPUT_CLAIMING] reclaim_expired_outputs");
+        let prepared_transaction = self.prepare_reclaim_expired_outputs().await?;
+
+        self.sign_and_submit_transaction(prepared_transaction, None).await
+    }
+
+    /// Prepare to send the amount and native tokens of all outputs from [`Account::reclaimable_outputs()`] to the
+    /// account.
+    pub async fn prepare_reclaim_expired_outputs(&self) -> crate::wallet::Result<PreparedTransactionData> {
+        log::debug!("[OUTPUT_CLAIMING] prepare_reclaim_expired_outputs");
+        let output_ids = self
+            .reclaimable_outputs()
+            .await?
+            .into_iter()
+            .map(|output_data| output_data.output_id)
+            .collect::<Vec<_>>();
+
+        if output_ids.is_empty() {
+            return Err(crate::wallet::Error::NoOutputsToReclaim);
+        }
+
+        self.prepare_claim_outputs(output_ids).await
+    }
+}
+
 /// Get the `StorageDepositReturnUnlockCondition`, if not expired
 pub(crate) fn sdr_not_expired(output: &Output, current_time: u32) -> Option<&StorageDepositReturnUnlockCondition> {
     output.unlock_conditions().and_then(|unlock_conditions| {
@@ -12,6 +12,7 @@ use crate::{
         input::INPUT_COUNT_MAX,
         output::{
             unlock_condition::AddressUnlockCondition, BasicOutputBuilder, NativeTokens, NativeTokensBuilder, Output,
+            OutputId, Rent,
         },
     },
 };
@@ -69,6 +70,33 @@ impl ConsolidationParams {
     }
 }
 
+/// What [`Account::consolidate_outputs()`] would do with the same params, without creating a transaction.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ConsolidationPreview {
+    /// The number of outputs which can be consolidated now.
+    pub consolidatable_outputs: usize,
+    /// The output count from which outputs are consolidated, unless forced.
+    pub output_threshold: usize,
+    /// Whether outputs would be consolidated with the params.
+    pub would_consolidate: bool,
+    /// The number of outputs consumed by the next consolidation transaction.
+    pub next_transaction_inputs: usize,
+    /// The number of transactions needed to consolidate all consolidatable outputs.
+    pub transactions_needed: usize,
+    /// The number of unspent outputs of the account after all consolidation transactions.
+    pub resulting_output_count: usize,
+    /// The base coin amount of the consolidatable outputs.
+    #[serde(with = "crate::utils::serde::string")]
+    pub amount: u64,
+    /// The storage deposit of the consolidatable outputs.
+    #[serde(with = "crate::utils::serde::string")]
+    pub storage_deposit_before: u64,
+    /// The storage deposit of the outputs created by the consolidation transactions.
+    #[serde(with = "crate::utils::serde::string")]
+    pub storage_deposit_after: u64,
+}
+
 impl<S: 'static + SecretManage> Account<S>
 where
     crate::wallet::Error: From<S::Error>,
@@ -107,6 +135,7 @@ where
     /// is set to `true`, the threshold is ignored. Only consolidates the amount of outputs that fit into a single
     /// transaction.
     pub async fn consolidate_outputs(&self, params: ConsolidationParams) -> Result<Transaction> {
+        let _in_flight = self.wallet.start_operation()?;
         let prepared_transaction = self.prepare_consolidate_outputs(params).await?;
         let consolidation_tx = self.sign_and_submit_transaction(prepared_transaction, None).await?;
 
@@ -123,10 +152,94 @@ where
     /// [Account::consolidate_outputs()](crate::wallet::Account::consolidate_outputs).
     pub async fn prepare_consolidate_outputs(&self, params: ConsolidationParams) -> Result<PreparedTransactionData> {
         log::debug!("[OUTPUT_CONSOLIDATION] prepare consolidating outputs if needed");
+        let token_supply = self.client().get_token_supply().await?;
+        let outputs_to_consolidate = self.outputs_to_consolidate().await?;
+        let output_threshold = self.consolidation_output_threshold(&params).await;
+
+        // only consolidate if the unlocked outputs are >= output_threshold
+        if outputs_to_consolidate.is_empty() || (!params.force && outputs_to_consolidate.len() < output_threshold) {
+            log::debug!(
+                "[OUTPUT_CONSOLIDATION] no consolidation needed, available_outputs: {}, output_threshold: {}",
+                outputs_to_consolidate.len(),
+                output_threshold
+            );
+            return Err(crate::wallet::Error::NoOutputsToConsolidate {
+                available_outputs: outputs_to_consolidate.len(),
+                consolidation_threshold: output_threshold,
+            });
+        }
+
+        let max_inputs = self.max_consolidation_inputs().await;
+        let (custom_inputs, consolidation_output) = consolidation_batch(
+            &outputs_to_consolidate,
+            max_inputs,
+            params.target_address.as_ref(),
+            token_supply,
+        )?;
+
+        let options = Some(TransactionOptions {
+            custom_inputs: Some(custom_inputs),
+            ..Default::default()
+        });
+
+        self.prepare_transaction([consolidation_output], options).await
+    }
+
+    /// Reports how many transactions [Account::consolidate_outputs()](crate::wallet::Account::consolidate_outputs)
+    /// needs to consolidate all outputs, how many outputs remain and how the storage deposit changes, without creating
+    /// a transaction.
+    pub async fn consolidation_preview(&self, params: ConsolidationParams) -> Result<ConsolidationPreview> {
+        log::debug!("[OUTPUT_CONSOLIDATION] consolidation preview");
+        let token_supply = self.client().get_token_supply().await?;
+        let rent_structure = self.client().get_rent_structure().await?;
+        let outputs_to_consolidate = self.outputs_to_consolidate().await?;
+        let output_threshold = self.consolidation_output_threshold(&params).await;
+        let max_inputs = self.max_consolidation_inputs().await;
+        let unspent_output_count = self.details().await.unspent_outputs().len();
+
+        let mut preview = ConsolidationPreview {
+            consolidatable_outputs: outputs_to_consolidate.len(),
+            output_threshold,
+            would_consolidate: !outputs_to_consolidate.is_empty()
+                && (params.force || outputs_to_consolidate.len() >= output_threshold),
+            next_transaction_inputs: 0,
+            transactions_needed: 0,
+            resulting_output_count: unspent_output_count - outputs_to_consolidate.len(),
+            amount: 0,
+            storage_deposit_before: 0,
+            storage_deposit_after: 0,
+        };
+        for output_data in &outputs_to_consolidate {
+            preview.amount += output_data.output.amount();
+            preview.storage_deposit_before += output_data.output.rent_cost(&rent_structure);
+        }
+
+        // Every transaction consolidates the outputs that fit into it, until none are left
+        let mut remaining_outputs = outputs_to_consolidate;
+        while !remaining_outputs.is_empty() {
+            let (inputs, consolidation_output) = consolidation_batch(
+                &remaining_outputs,
+                max_inputs,
+                params.target_address.as_ref(),
+                token_supply,
+            )?;
+            if preview.transactions_needed == 0 {
+                preview.next_transaction_inputs = inputs.len();
+            }
+            preview.transactions_needed += 1;
+            preview.resulting_output_count += 1;
+            preview.storage_deposit_after += consolidation_output.rent_cost(&rent_structure);
+            remaining_outputs.retain(|output_data| !inputs.contains(&output_data.output_id));
+        }
+
+        Ok(preview)
+    }
+
+    // Returns the outputs which can be consolidated now
+    async fn outputs_to_consolidate(&self) -> Result<Vec<OutputData>> {
         #[cfg(feature = "participation")]
         let voting_output = self.get_voting_output().await?;
         let current_time = self.client().get_time_checked().await?;
-        let token_supply = self.client().get_token_supply().await?;
         let mut outputs_to_consolidate = Vec::new();
         let account_details = self.details().await;
         let account_addresses = &account_details.addresses_with_unspent_outputs[..];
@@ -139,7 +252,8 @@ where
                     continue;
                 }
             }
-            let is_locked_output = account_details.locked_outputs.contains(output_id);
+            let is_locked_output = account_details.locked_outputs.contains(output_id)
+                || account_details.quarantined_outputs.contains(output_id);
             let should_consolidate_output =
                 self.should_consolidate_output(output_data, current_time, account_addresses)?;
             if !is_locked_output && should_consolidate_output {
@@ -147,10 +261,13 @@ where
             }
         }
 
-        drop(account_details);
+        Ok(outputs_to_consolidate)
+    }
 
+    // Returns the output threshold of the params or the default one for the secret manager
+    async fn consolidation_output_threshold(&self, params: &ConsolidationParams) -> usize {
         #[allow(clippy::option_if_let_else)]
-        let output_threshold = match params.output_threshold {
+        match params.output_threshold {
             Some(t) => t,
             None => {
                 #[cfg(feature = "ledger_nano")]
@@ -178,23 +295,13 @@ where
                 #[cfg(not(feature = "ledger_nano"))]
                 DEFAULT_OUTPUT_CONSOLIDATION_THRESHOLD
             }
-        };
-
-        // only consolidate if the unlocked outputs are >= output_threshold
-        if outputs_to_consolidate.is_empty() || (!params.force && outputs_to_consolidate.len() < output_threshold) {
-            log::debug!(
-                "[OUTPUT_CONSOLIDATION] no consolidation needed, available_outputs: {}, output_threshold: {}",
-                outputs_to_consolidate.len(),
-                output_threshold
-            );
-            return Err(crate::wallet::Error::NoOutputsToConsolidate {
-                available_outputs: outputs_to_consolidate.len(),
-                consolidation_threshold: output_threshold,
-            });
         }
+    }
 
+    // Returns how many inputs a consolidation transaction can have, which is limited by the protocol or the ledger nano
+    async fn max_consolidation_inputs(&self) -> u16 {
         #[cfg(feature = "ledger_nano")]
-        let max_inputs = {
+        {
             use crate::wallet::account::SecretManager;
             let secret_manager = self.wallet.secret_manager.read().await;
             if let Some(ledger) = secret_manager.downcast::<LedgerSecretManager>().or_else(|| {
@@ -225,43 +332,46 @@ where
             } else {
                 INPUT_COUNT_MAX
             }
-        };
+        }
         #[cfg(not(feature = "ledger_nano"))]
-        let max_inputs = INPUT_COUNT_MAX;
-
-        let mut total_amount = 0;
-        let mut custom_inputs = Vec::with_capacity(max_inputs.into());
-        let mut total_native_tokens = NativeTokensBuilder::new();
+        INPUT_COUNT_MAX
+    }
+}
 
-        for output_data in outputs_to_consolidate.iter().take(max_inputs.into()) {
-            if let Some(native_tokens) = output_data.output.native_tokens() {
-                // Skip output if the max native tokens count would be exceeded
-                if get_new_native_token_count(&total_native_tokens, native_tokens)? > NativeTokens::COUNT_MAX.into() {
-                    log::debug!("[OUTPUT_CONSOLIDATION] skipping output to not exceed the max native tokens count");
-                    continue;
-                }
-                total_native_tokens.add_native_tokens(native_tokens.clone())?;
-            };
-            total_amount += output_data.output.amount();
+// Selects the outputs that are consolidated with a single transaction and returns their ids with the consolidation
+// output
+fn consolidation_batch(
+    outputs_to_consolidate: &[OutputData],
+    max_inputs: u16,
+    target_address: Option<&Bech32Address>,
+    token_supply: u64,
+) -> Result<(Vec<OutputId>, Output)> {
+    let mut total_amount = 0;
+    let mut custom_inputs = Vec::with_capacity(max_inputs.into());
+    let mut total_native_tokens = NativeTokensBuilder::new();
 
-            custom_inputs.push(output_data.output_id);
-        }
+    for output_data in outputs_to_consolidate.iter().take(max_inputs.into()) {
+        if let Some(native_tokens) = output_data.output.native_tokens() {
+            // Skip output if the max native tokens count would be exceeded
+            if get_new_native_token_count(&total_native_tokens, native_tokens)? > NativeTokens::COUNT_MAX.into() {
+                log::debug!("[OUTPUT_CONSOLIDATION] skipping output to not exceed the max native tokens count");
+                continue;
+            }
+            total_native_tokens.add_native_tokens(native_tokens.clone())?;
+        };
+        total_amount += output_data.output.amount();
 
-        let consolidation_output = [BasicOutputBuilder::new_with_amount(total_amount)
-            .add_unlock_condition(AddressUnlockCondition::new(
-                params
-                    .target_address
-                    .map(|bech32| bech32.into_inner())
-                    .unwrap_or(outputs_to_consolidate[0].address),
-            ))
-            .with_native_tokens(total_native_tokens.finish()?)
-            .finish_output(token_supply)?];
+        custom_inputs.push(output_data.output_id);
+    }
 
-        let options = Some(TransactionOptions {
-            custom_inputs: Some(custom_inputs),
-            ..Default::default()
-        });
+    let consolidation_output = BasicOutputBuilder::new_with_amount(total_amount)
+        .add_unlock_condition(AddressUnlockCondition::new(
+            target_address
+                .map(|bech32| *bech32.inner())
+                .unwrap_or(outputs_to_consolidate[0].address),
+        ))
+        .with_native_tokens(total_native_tokens.finish()?)
+        .finish_output(token_supply)?;
 
-        self.prepare_transaction(consolidation_output, options).await
-    }
+    Ok((custom_inputs, consolidation_output))
 }
@@ -0,0 +1,199 @@
+// Copyright 2024 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use std::collections::HashSet;
+
+use crate::{
+    client::{api::PreparedTransactionData, secret::SecretManage},
+    types::block::{
+        address::{Address, AliasAddress},
+        output::{Output, OutputId, Rent, RentStructure},
+        payload::transaction::TransactionEssence,
+    },
+    wallet::{
+        account::{
+            types::{OutputData, Transaction},
+            Account, AccountDetails, TransactionOptions,
+        },
+        Error,
+    },
+};
+
+// Parts of links which spam outputs use to lure receivers to phishing sites
+const LINK_PATTERNS: [&str; 3] = ["http://", "https://", "www."];
+
+impl AccountDetails {
+    // Returns whether an output looks like spam: it has a dust amount, isn't sent by a known address and has links in
+    // its metadata or tag
+    pub(crate) fn is_spam_output(&self, output: &Output, rent_structure: &RentStructure) -> bool {
+        if !output.is_basic() && !output.is_nft() {
+            return false;
+        }
+        let account_addresses = self
+            .addresses()
+            .into_iter()
+            .map(|address| *address.address.inner())
+            .collect::<HashSet<_>>();
+
+        // Only the amount which doesn't have to be returned is received
+        let amount = output.amount();
+        let received_amount = output
+            .unlock_conditions()
+            .and_then(|unlock_conditions| unlock_conditions.storage_deposit_return())
+            .filter(|sdr| !account_addresses.contains(sdr.return_address()))
+            .map_or(amount, |sdr| amount.saturating_sub(sdr.amount()));
+        if received_amount > output.rent_cost(rent_structure) {
+            return false;
+        }
+
+        let has_known_sender = output
+            .features()
+            .and_then(|features| features.sender())
+            .is_some_and(|sender| self.is_known_address(sender.address(), &account_addresses));
+        if has_known_sender {
+            return false;
+        }
+
+        output
+            .features()
+            .into_iter()
+            .chain(output.immutable_features())
+            .flat_map(|features| {
+                [
+                    features.metadata().map(|metadata| metadata.data()),
+                    features.tag().map(|tag| tag.tag()),
+                ]
+            })
+            .flatten()
+            .any(contains_link)
+    }
+
+    // The addresses of the account and the addresses it sent outputs to are known
+    fn is_known_address(&self, address: &Address, account_addresses: &HashSet<Address>) -> bool {
+        account_addresses.contains(address)
+            || self
+                .identity_alias
+                .is_some_and(|alias_id| address == &Address::Alias(AliasAddress::new(alias_id)))
+            || self.transactions.values().any(|transaction| {
+                let TransactionEssence::Regular(essence) = transaction.payload.essence();
+                essence.outputs().iter().any(|output| {
+                    output
+                        .unlock_conditions()
+                        .and_then(|unlock_conditions| unlock_conditions.address())
+                        .is_some_and(|unlock_condition| unlock_condition.address() == address)
+                })
+            })
+    }
+}
+
+fn contains_link(data: &[u8]) -> bool {
+    let text = String::from_utf8_lossy(data).to_lowercase();
+    LINK_PATTERNS.iter().any(|pattern| text.contains(pattern))
+}
+
+impl<S: 'static + SecretManage> Account<S>
+where
+    crate::wallet::Error: From<S::Error>,
+{
+    /// Returns the unspent outputs which were quarantined as spam while syncing with
+    /// [`SyncOptions::quarantine_spam_outputs`](crate::wallet::account::SyncOptions::quarantine_spam_outputs).
+    ///
+    /// Quarantined outputs aren't part of the balance, aren't claimed and aren't used as inputs, until they're
+    /// released with [`Account::release_quarantined_outputs()`]. With [`Account::burn_quarantined_outputs()`] they
+    /// can be cleaned up.
+    pub async fn quarantined_outputs(&self) -> Vec<OutputData> {
+        let account_details = self.details().await;
+
+        account_details
+            .quarantined_outputs
+            .iter()
+            .filter_map(|output_id| account_details.unspent_outputs.get(output_id))
+            .cloned()
+            .collect()
+    }
+
+    /// Releases quarantined outputs which aren't spam, so they're part of the balance again. Released outputs aren't
+    /// quarantined again by later syncs.
+    pub async fn release_quarantined_outputs(&self, output_ids: &[OutputId]) -> crate::wallet::Result<()> {
+        log::debug!("[release_quarantined_outputs] {output_ids:?}");
+        let mut account_details = self.details_mut().await;
+        if let Some(output_id) = output_ids
+            .iter()
+            .find(|output_id| !account_details.quarantined_outputs.contains(output_id))
+        {
+            return Err(Error::OutputNotQuarantined(*output_id));
+        }
+        for output_id in output_ids {
+            account_details.quarantined_outputs.remove(output_id);
+        }
+
+        #[cfg(feature = "storage")]
+        self.save(Some(&account_details)).await?;
+
+        Ok(())
+    }
+
+    /// Sends a transaction which consumes quarantined outputs and burns their native tokens and NFTs. The base coins
+    /// of the outputs are kept in a remainder, except the ones which have to be returned to the sender.
+    /// ```ignore
+    /// let spam_output_ids = account
+    ///     .quarantined_outputs()
+    ///     .await
+    ///     .into_iter()
+    ///     .map(|output_data| output_data.output_id)
+    ///     .collect();
+    /// let transaction = account.burn_quarantined_outputs(spam_output_ids, None).await?;
+    /// ```
+    pub async fn burn_quarantined_outputs(
+        &self,
+        output_ids: Vec<OutputId>,
+        options: impl Into<Option<TransactionOptions>> + Send,
+    ) -> crate::wallet::Result<Transaction> {
+        let options = options.into();
+        if let Some(transaction) = self.idempotent_transaction(options.as_ref()).await {
+            return Ok(transaction);
+        }
+        let prepared_transaction = self
+            .prepare_burn_quarantined_outputs(output_ids, options.clone())
+            .await?;
+
+        self.sign_and_submit_transaction(prepared_transaction, options).await
+    }
+
+    /// Prepares the transaction for [`Account::burn_quarantined_outputs()`].
+    pub async fn prepare_burn_quarantined_outputs(
+        &self,
+        output_ids: Vec<OutputId>,
+        options: impl Into<Option<TransactionOptions>> + Send,
+    ) -> crate::wallet::Result<PreparedTransactionData> {
+        log::debug!("[prepare_burn_quarantined_outputs] {output_ids:?}");
+        let mut options: TransactionOptions = options.into().unwrap_or_default();
+        let mut burn = options.burn.take().unwrap_or_default();
+        {
+            let account_details = self.details().await;
+            for output_id in &output_ids {
+                if !account_details.quarantined_outputs.contains(output_id) {
+                    return Err(Error::OutputNotQuarantined(*output_id));
+                }
+                let output = &account_details
+                    .unspent_outputs
+                    .get(output_id)
+                    .ok_or(Error::OutputNotFoundInAccount(*output_id))?
+                    .output;
+                if let Output::Nft(nft_output) = output {
+                    burn.nfts.insert(nft_output.nft_id_non_null(output_id));
+                }
+                for native_token in output.native_tokens().into_iter().flat_map(|n| n.iter()) {
+                    *burn.native_tokens.entry(*native_token.token_id()).or_default() += native_token.amount();
+                }
+            }
+        }
+
+        let mut mandatory_inputs = options.mandatory_inputs.take().unwrap_or_default();
+        mandatory_inputs.extend(output_ids);
+        options.mandatory_inputs = Some(mandatory_inputs);
+        options.burn = Some(burn);
+
+        self.prepare_transaction([], Some(options)).await
+    }
+}
@@ -11,37 +11,38 @@ impl<S: 'static + SecretManage> Account<S>
 where
     crate::wallet::Error: From<S::Error>,
 {
-    /// Returns output ids of basic outputs that have only the address unlock condition
+    /// Returns output ids of basic outputs that have only the address unlock condition, optionally only the ones
+    /// created after the given milestone timestamp
     pub(crate) async fn get_basic_output_ids_with_address_unlock_condition_only(
         &self,
         bech32_address: impl ConvertTo<Bech32Address>,
+        created_after: Option<u32>,
     ) -> crate::client::Result<Vec<OutputId>> {
         let bech32_address = bech32_address.convert()?;
         // Only request basic outputs with `AddressUnlockCondition` only
-        Ok(self
-            .client()
-            .basic_output_ids([
-                QueryParameter::Address(bech32_address),
-                QueryParameter::HasExpiration(false),
-                QueryParameter::HasTimelock(false),
-                QueryParameter::HasStorageDepositReturn(false),
-            ])
-            .await?
-            .items)
+        let mut query_parameters = vec![
+            QueryParameter::Address(bech32_address),
+            QueryParameter::HasExpiration(false),
+            QueryParameter::HasTimelock(false),
+            QueryParameter::HasStorageDepositReturn(false),
+        ];
+        query_parameters.extend(created_after.map(QueryParameter::CreatedAfter));
+
+        Ok(self.client().basic_output_ids(query_parameters).await?.items)
     }
 
     /// Returns output ids of basic outputs that have the address in the `AddressUnlockCondition`,
-    /// `ExpirationUnlockCondition` or `StorageDepositReturnUnlockCondition`
+    /// `ExpirationUnlockCondition` or `StorageDepositReturnUnlockCondition`, optionally only the ones created after the
+    /// given milestone timestamp
     pub(crate) async fn get_basic_output_ids_with_any_unlock_condition(
         &self,
         bech32_address: impl ConvertTo<Bech32Address>,
+        created_after: Option<u32>,
     ) -> crate::wallet::Result<Vec<OutputId>> {
         let bech32_address = bech32_address.convert()?;
+        let mut query_parameters = vec![QueryParameter::UnlockableByAddress(bech32_address)];
+        query_parameters.extend(created_after.map(QueryParameter::CreatedAfter));
 
-        Ok(self
-            .client()
-            .basic_output_ids([QueryParameter::UnlockableByAddress(bech32_address)])
-            .await?
-            .items)
+        Ok(self.client().basic_output_ids(query_parameters).await?.items)
     }
 }
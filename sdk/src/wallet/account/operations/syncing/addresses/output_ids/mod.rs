@@ -9,7 +9,6 @@ use std::collections::HashSet;
 
 #[cfg(not(target_family = "wasm"))]
 use futures::FutureExt;
-use instant::Instant;
 
 use crate::{
     client::{node_api::indexer::QueryParameter, secret::SecretManage},
@@ -18,8 +17,9 @@ use crate::{
         output::OutputId,
     },
     wallet::account::{
-        constants::PARALLEL_REQUESTS_AMOUNT, operations::syncing::SyncOptions,
-        types::address::AddressWithUnspentOutputs, Account,
+        operations::syncing::{incremental::IncrementalSync, SyncOptions},
+        types::address::AddressWithUnspentOutputs,
+        Account,
     },
 };
 
@@ -29,17 +29,18 @@ where
 {
     /// Returns output ids for outputs that are directly (Ed25519 address in AddressUnlockCondition) or indirectly
     /// (alias/nft address in AddressUnlockCondition and the alias/nft output is controlled with the Ed25519 address)
-    /// connected to
+    /// connected to, if `created_after` is provided, only the ones created after this milestone timestamp
     pub(crate) async fn get_output_ids_for_address(
         &self,
         address: Address,
         sync_options: &SyncOptions,
+        created_after: Option<u32>,
     ) -> crate::wallet::Result<Vec<OutputId>> {
         let bech32_address = Bech32Address::new(self.client().get_bech32_hrp().await?, address);
 
         if sync_options.sync_only_most_basic_outputs {
             let output_ids = self
-                .get_basic_output_ids_with_address_unlock_condition_only(bech32_address)
+                .get_basic_output_ids_with_address_unlock_condition_only(bech32_address, created_after)
                 .await?;
             return Ok(output_ids);
         }
@@ -49,11 +50,9 @@ where
             || (address.is_nft() && sync_options.nft.all_outputs())
             || (address.is_alias() && sync_options.alias.all_outputs())
         {
-            return Ok(self
-                .client()
-                .output_ids([QueryParameter::UnlockableByAddress(bech32_address)])
-                .await?
-                .items);
+            let mut query_parameters = vec![QueryParameter::UnlockableByAddress(bech32_address)];
+            query_parameters.extend(created_after.map(QueryParameter::CreatedAfter));
+            return Ok(self.client().output_ids(query_parameters).await?.items);
         }
 
         #[cfg(target_family = "wasm")]
@@ -70,7 +69,7 @@ where
             #[cfg(target_family = "wasm")]
             {
                 results.push(
-                    self.get_basic_output_ids_with_any_unlock_condition(bech32_address)
+                    self.get_basic_output_ids_with_any_unlock_condition(bech32_address, created_after)
                         .await,
                 )
             }
@@ -82,7 +81,7 @@ where
                         let account = self.clone();
                         tokio::spawn(async move {
                             account
-                                .get_basic_output_ids_with_any_unlock_condition(bech32_address)
+                                .get_basic_output_ids_with_any_unlock_condition(bech32_address, created_after)
                                 .await
                         })
                         .await
@@ -99,7 +98,10 @@ where
             // nfts
             #[cfg(target_family = "wasm")]
             {
-                results.push(self.get_nft_output_ids_with_any_unlock_condition(bech32_address).await)
+                results.push(
+                    self.get_nft_output_ids_with_any_unlock_condition(bech32_address, created_after)
+                        .await,
+                )
             }
 
             #[cfg(not(target_family = "wasm"))]
@@ -109,7 +111,7 @@ where
                         let account = self.clone();
                         tokio::spawn(async move {
                             account
-                                .get_nft_output_ids_with_any_unlock_condition(bech32_address)
+                                .get_nft_output_ids_with_any_unlock_condition(bech32_address, created_after)
                                 .await
                         })
                         .await
@@ -127,7 +129,7 @@ where
             #[cfg(target_family = "wasm")]
             {
                 results.push(
-                    self.get_alias_and_foundry_output_ids(bech32_address, sync_options)
+                    self.get_alias_and_foundry_output_ids(bech32_address, sync_options, created_after)
                         .await,
                 )
             }
@@ -140,7 +142,7 @@ where
                         let account = self.clone();
                         tokio::spawn(async move {
                             account
-                                .get_alias_and_foundry_output_ids(bech32_address, &sync_options)
+                                .get_alias_and_foundry_output_ids(bech32_address, &sync_options, created_after)
                                 .await
                         })
                         .await
@@ -150,13 +152,12 @@ where
             }
         } else if address.is_alias() && sync_options.alias.foundry_outputs {
             // foundries
+            let mut query_parameters = vec![QueryParameter::AliasAddress(bech32_address)];
+            query_parameters.extend(created_after.map(QueryParameter::CreatedAfter));
+
             #[cfg(target_family = "wasm")]
             {
-                results.push(Ok(self
-                    .client()
-                    .foundry_output_ids([QueryParameter::AliasAddress(bech32_address)])
-                    .await?
-                    .items))
+                results.push(Ok(self.client().foundry_output_ids(query_parameters).await?.items))
             }
 
             #[cfg(not(target_family = "wasm"))]
@@ -164,13 +165,7 @@ where
                 tasks.push(
                     async move {
                         let client = self.client().clone();
-                        tokio::spawn(async move {
-                            Ok(client
-                                .foundry_output_ids([QueryParameter::AliasAddress(bech32_address)])
-                                .await?
-                                .items)
-                        })
-                        .await
+                        tokio::spawn(async move { Ok(client.foundry_output_ids(query_parameters).await?.items) }).await
                     }
                     .boxed(),
                 );
@@ -190,84 +185,32 @@ where
         Ok(output_ids.into_iter().collect())
     }
 
-    /// Get the current output ids for provided addresses and only returns addresses that have unspent outputs and
-    /// return spent outputs separated
-    pub(crate) async fn get_output_ids_for_addresses(
+    /// Updates the output ids of an address with its current unspent outputs and returns the output ids it had before,
+    /// which got spent or are alias/nft/foundries that don't get synced anymore because of other sync options. If
+    /// the address can be synced incrementally, only its new outputs are requested.
+    pub(crate) async fn update_output_ids_for_address(
         &self,
+        mut address: AddressWithUnspentOutputs,
         options: &SyncOptions,
-        addresses_with_unspent_outputs: Vec<AddressWithUnspentOutputs>,
-    ) -> crate::wallet::Result<(Vec<AddressWithUnspentOutputs>, Vec<OutputId>)> {
-        log::debug!("[SYNC] start get_output_ids_for_addresses");
-        let address_output_ids_start_time = Instant::now();
-
-        let mut addresses_with_outputs = Vec::new();
-        // spent outputs or alias/nft/foundries that don't get synced anymore, because of other sync options
-        let mut spent_or_not_anymore_synced_outputs = Vec::new();
-        // We split the addresses into chunks so we don't get timeouts if we have thousands
-        for addresses_chunk in &mut addresses_with_unspent_outputs
-            .chunks(PARALLEL_REQUESTS_AMOUNT)
-            .map(|x: &[AddressWithUnspentOutputs]| x.to_vec())
-        {
-            let results;
-            #[cfg(target_family = "wasm")]
-            {
-                let mut tasks = Vec::new();
-                for address in addresses_chunk {
-                    let output_ids = self.get_output_ids_for_address(address.address.inner, &options).await?;
-                    tasks.push(crate::wallet::Result::Ok((address, output_ids)));
-                }
-                results = tasks;
-            }
-
-            #[cfg(not(target_family = "wasm"))]
-            {
-                let mut tasks = Vec::new();
-                for address in addresses_chunk {
-                    let account = self.clone();
-                    let sync_options = options.clone();
-                    tasks.push(async move {
-                        tokio::spawn(async move {
-                            let output_ids = account
-                                .get_output_ids_for_address(address.address.inner, &sync_options)
-                                .await?;
-                            crate::wallet::Result::Ok((address, output_ids))
-                        })
-                        .await
-                    });
-                }
-
-                results = futures::future::try_join_all(tasks).await?;
-            }
-
-            for res in results {
-                let (mut address, output_ids): (AddressWithUnspentOutputs, Vec<OutputId>) = res?;
-                // only return addresses with outputs
-                if !output_ids.is_empty() {
-                    // outputs we had before, but now not anymore, got spent or are alias/nft/foundries that don't get
-                    // synced anymore because of other sync options
-                    for output_id in address.output_ids {
-                        if !output_ids.contains(&output_id) {
-                            spent_or_not_anymore_synced_outputs.push(output_id);
-                        }
-                    }
-                    address.output_ids = output_ids;
-                    addresses_with_outputs.push(address);
-                } else {
-                    // outputs we had before, but now not anymore, got spent or are alias/nft/foundries that don't get
-                    // synced anymore because of other sync options
-                    spent_or_not_anymore_synced_outputs.extend(address.output_ids);
-                }
-            }
+        incremental_sync: Option<&IncrementalSync>,
+    ) -> crate::wallet::Result<(AddressWithUnspentOutputs, Vec<OutputId>)> {
+        let created_after = incremental_sync.and_then(|i| i.created_after(&address.address.inner));
+        let mut output_ids = self
+            .get_output_ids_for_address(address.address.inner, options, created_after)
+            .await?;
+        if let Some(incremental_sync) = incremental_sync.filter(|_| created_after.is_some()) {
+            // Only the new outputs were requested, the known ones are kept if they weren't consumed
+            output_ids = incremental_sync.merge_output_ids(&address.output_ids, output_ids);
         }
 
-        log::debug!(
-            "[SYNC] spent or not anymore synced alias/nft/foundries outputs: {:?}",
-            spent_or_not_anymore_synced_outputs
-        );
-        log::debug!(
-            "[SYNC] finished get_output_ids_for_addresses in {:.2?}",
-            address_output_ids_start_time.elapsed()
-        );
-        Ok((addresses_with_outputs, spent_or_not_anymore_synced_outputs))
+        let spent_or_not_anymore_synced_outputs = address
+            .output_ids
+            .iter()
+            .filter(|output_id| !output_ids.contains(output_id))
+            .copied()
+            .collect();
+        address.output_ids = output_ids;
+
+        Ok((address, spent_or_not_anymore_synced_outputs))
     }
 }
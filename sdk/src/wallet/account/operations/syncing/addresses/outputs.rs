@@ -1,13 +1,19 @@
 // Copyright 2022 IOTA Stiftung
 // SPDX-License-Identifier: Apache-2.0
 
+use std::sync::Arc;
+
+use futures::{StreamExt, TryStreamExt};
 use instant::Instant;
 
 use crate::{
     client::secret::SecretManage,
+    types::block::output::OutputId,
     wallet::{
         account::{
-            constants::PARALLEL_REQUESTS_AMOUNT, types::address::AddressWithUnspentOutputs, Account, OutputData,
+            operations::syncing::{incremental::IncrementalSync, SyncOptions},
+            types::address::AddressWithUnspentOutputs,
+            Account, OutputData,
         },
         task,
     },
@@ -17,48 +23,74 @@ impl<S: 'static + SecretManage> Account<S>
 where
     crate::wallet::Error: From<S::Error>,
 {
-    /// Get outputs from addresses
-    pub(crate) async fn get_outputs_from_address_output_ids(
+    /// Get the current output ids and outputs of the addresses. Every address is processed as a pipeline, its outputs
+    /// are requested as soon as its output ids are known, while up to `options.parallel_requests` addresses are
+    /// processed at the same time. Only returns addresses that have unspent outputs and returns the output ids which
+    /// got spent or don't get synced anymore separated.
+    pub(crate) async fn get_outputs_for_addresses(
         &self,
+        options: &SyncOptions,
         addresses_with_unspent_outputs: Vec<AddressWithUnspentOutputs>,
-    ) -> crate::wallet::Result<(Vec<AddressWithUnspentOutputs>, Vec<OutputData>)> {
-        log::debug!("[SYNC] start get_outputs_from_address_output_ids");
+        incremental_sync: Option<Arc<IncrementalSync>>,
+    ) -> crate::wallet::Result<(Vec<AddressWithUnspentOutputs>, Vec<OutputId>, Vec<OutputData>)> {
+        log::debug!("[SYNC] start get_outputs_for_addresses");
         let address_outputs_start_time = Instant::now();
 
-        let mut addresses_with_outputs = Vec::new();
-        let mut outputs_data = Vec::new();
-
-        // We split the addresses into chunks so we don't get timeouts if we have thousands
-        for addresses_chunk in &mut addresses_with_unspent_outputs
-            .chunks(PARALLEL_REQUESTS_AMOUNT)
-            .map(|x: &[AddressWithUnspentOutputs]| x.to_vec())
-        {
-            let mut tasks = Vec::new();
-            for address in addresses_chunk {
+        let tasks = addresses_with_unspent_outputs
+            .into_iter()
+            .map(|address| {
                 let account = self.clone();
-                tasks.push(async move {
+                let options = options.clone();
+                let incremental_sync = incremental_sync.clone();
+                async move {
                     task::spawn(async move {
-                        let output_responses = account.get_outputs(address.output_ids.clone()).await?;
-
-                        let outputs = account
-                            .output_response_to_output_data(output_responses, &address)
+                        let (address, spent_or_not_anymore_synced_outputs) = account
+                            .update_output_ids_for_address(address, &options, incremental_sync.as_deref())
                             .await?;
-                        crate::wallet::Result::Ok((address, outputs))
+                        let outputs = if address.output_ids.is_empty() {
+                            Vec::new()
+                        } else {
+                            let output_responses = account.get_outputs(address.output_ids.clone()).await?;
+                            account
+                                .output_response_to_output_data(output_responses, &address)
+                                .await?
+                        };
+                        crate::wallet::Result::Ok((address, spent_or_not_anymore_synced_outputs, outputs))
                     })
-                    .await
-                });
-            }
-            let results = futures::future::try_join_all(tasks).await?;
-            for res in results {
-                let (address, outputs): (AddressWithUnspentOutputs, Vec<OutputData>) = res?;
+                    .await?
+                }
+            })
+            .collect::<Vec<_>>();
+        let results = futures::stream::iter(tasks)
+            .buffered(options.parallel_requests.max(1))
+            .try_collect::<Vec<_>>()
+            .await?;
+
+        let mut addresses_with_outputs = Vec::new();
+        // spent outputs or alias/nft/foundries that don't get synced anymore, because of other sync options
+        let mut spent_or_not_anymore_synced_outputs = Vec::new();
+        let mut outputs_data = Vec::new();
+        for (address, spent_or_not_anymore_synced, outputs) in results {
+            spent_or_not_anymore_synced_outputs.extend(spent_or_not_anymore_synced);
+            // only return addresses with outputs
+            if !address.output_ids.is_empty() {
                 addresses_with_outputs.push(address);
                 outputs_data.extend(outputs);
             }
         }
+
+        log::debug!(
+            "[SYNC] spent or not anymore synced alias/nft/foundries outputs: {:?}",
+            spent_or_not_anymore_synced_outputs
+        );
         log::debug!(
-            "[SYNC] finished get_outputs_from_address_output_ids in {:.2?}",
+            "[SYNC] finished get_outputs_for_addresses in {:.2?}",
             address_outputs_start_time.elapsed()
         );
-        Ok((addresses_with_outputs, outputs_data))
+        Ok((
+            addresses_with_outputs,
+            spent_or_not_anymore_synced_outputs,
+            outputs_data,
+        ))
     }
 }
@@ -0,0 +1,136 @@
+// Copyright 2024 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use std::collections::{HashMap, HashSet};
+
+use crate::{
+    client::secret::SecretManage,
+    types::block::{
+        address::{Address, Bech32Address},
+        output::{Output, OutputId},
+    },
+    wallet::account::{operations::syncing::SyncOptions, types::address::AddressWithUnspentOutputs, Account},
+};
+
+impl<S: 'static + SecretManage> Account<S>
+where
+    crate::wallet::Error: From<S::Error>,
+{
+    /// Loads the outputs of the wallet bootstrap set that belong to this account on its first sync, if the account has
+    /// no outputs yet. Returns the addresses owning bootstrapped outputs and the bootstrapped addresses of the
+    /// account, so the first sync can be limited to them.
+    pub(crate) async fn bootstrap_outputs(&self) -> crate::wallet::Result<Vec<Bech32Address>> {
+        if self.sync_cursors.lock().await.bootstrapped {
+            return Ok(Vec::new());
+        }
+        let bootstrapped_addresses = self.load_bootstrap_outputs().await?;
+        // Later syncs aren't bootstrapped again, even if the account still has no outputs
+        self.sync_cursors.lock().await.bootstrapped = true;
+
+        Ok(bootstrapped_addresses)
+    }
+
+    async fn load_bootstrap_outputs(&self) -> crate::wallet::Result<Vec<Bech32Address>> {
+        if !self.details().await.outputs().is_empty() {
+            return Ok(Vec::new());
+        }
+        let account_addresses = self.addresses().await?;
+
+        // Other accounts can't own the same addresses, so they're claimed right away
+        let mut bootstrapped_addresses = {
+            let mut bootstrap_addresses = self.wallet.bootstrap_addresses.write().await;
+            account_addresses
+                .iter()
+                .map(|a| a.address)
+                .filter(|address| bootstrap_addresses.remove(address))
+                .collect::<Vec<_>>()
+        };
+
+        let bootstrap_output_ids = self
+            .wallet
+            .bootstrap_outputs
+            .read()
+            .await
+            .iter()
+            .copied()
+            .collect::<Vec<_>>();
+        if bootstrap_output_ids.is_empty() {
+            return Ok(bootstrapped_addresses);
+        }
+        log::debug!("[SYNC] bootstrapping from {} known outputs", bootstrap_output_ids.len());
+
+        let mut outputs_per_address = HashMap::new();
+
+        for output_with_metadata in self.client().get_outputs_ignore_errors(&bootstrap_output_ids).await? {
+            if output_with_metadata.metadata().is_spent() {
+                continue;
+            }
+            let Some(owner) = owner_address(output_with_metadata.output()) else {
+                continue;
+            };
+            if let Some(account_address) = account_addresses.iter().find(|a| &a.address.inner == owner) {
+                outputs_per_address
+                    .entry(account_address.clone())
+                    .or_insert_with(Vec::new)
+                    .push(output_with_metadata);
+            }
+        }
+
+        let mut addresses_with_unspent_outputs = Vec::new();
+        let mut outputs_data = Vec::new();
+        let mut bootstrapped_output_ids = HashSet::<OutputId>::new();
+
+        for (account_address, outputs) in outputs_per_address {
+            let address_with_unspent_outputs = AddressWithUnspentOutputs {
+                address: account_address.address,
+                key_index: account_address.key_index,
+                internal: account_address.internal,
+                output_ids: outputs.iter().map(|o| *o.metadata().output_id()).collect(),
+            };
+            bootstrapped_output_ids.extend(address_with_unspent_outputs.output_ids.iter().copied());
+            outputs_data.extend(
+                self.output_response_to_output_data(outputs, &address_with_unspent_outputs)
+                    .await?,
+            );
+            addresses_with_unspent_outputs.push(address_with_unspent_outputs);
+        }
+
+        if addresses_with_unspent_outputs.is_empty() {
+            return Ok(bootstrapped_addresses);
+        }
+
+        for address in addresses_with_unspent_outputs.iter().map(|a| a.address) {
+            if !bootstrapped_addresses.contains(&address) {
+                bootstrapped_addresses.push(address);
+            }
+        }
+
+        self.update_account(
+            addresses_with_unspent_outputs,
+            outputs_data,
+            HashMap::new(),
+            &SyncOptions::default(),
+        )
+        .await?;
+
+        // Other accounts don't need to look at outputs which were already claimed by this one
+        self.wallet
+            .bootstrap_outputs
+            .write()
+            .await
+            .retain(|output_id| !bootstrapped_output_ids.contains(output_id));
+
+        Ok(bootstrapped_addresses)
+    }
+}
+
+// The address which owns an output for syncing purposes, alias outputs are owned by their state controller.
+fn owner_address(output: &Output) -> Option<&Address> {
+    match output {
+        Output::Alias(alias) => Some(alias.state_controller_address()),
+        _ => output
+            .unlock_conditions()
+            .and_then(|unlock_conditions| unlock_conditions.address())
+            .map(|unlock_condition| unlock_condition.address()),
+    }
+}
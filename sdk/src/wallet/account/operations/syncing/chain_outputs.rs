@@ -0,0 +1,137 @@
+// Copyright 2024 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::{
+    client::secret::SecretManage,
+    types::block::{
+        address::Address,
+        output::{AliasId, FoundryId, OutputId, OutputWithMetadata},
+    },
+    wallet::account::{
+        types::{AddressWithUnspentOutputs, OutputData},
+        Account,
+    },
+};
+
+// Returns `None` if the indexer has no output for a chain id
+fn found_output_id(result: crate::client::Result<OutputId>) -> crate::wallet::Result<Option<OutputId>> {
+    match result {
+        Ok(output_id) => Ok(Some(output_id)),
+        Err(crate::client::Error::NoOutput(_))
+        | Err(crate::client::Error::Node(crate::client::node_api::error::Error::NotFound(_))) => Ok(None),
+        Err(e) => Err(e.into()),
+    }
+}
+
+impl<S: 'static + SecretManage> Account<S>
+where
+    crate::wallet::Error: From<S::Error>,
+{
+    /// Returns the unspent output of an alias. If it isn't known to the account, e.g. because it wasn't synced with
+    /// the sync options, it's requested from the node and stored, if its state controller is an address of the
+    /// account.
+    pub(crate) async fn get_or_sync_alias_output(
+        &self,
+        alias_id: AliasId,
+    ) -> crate::wallet::Result<Option<OutputData>> {
+        if let Some(output_data) = self.unspent_alias_output(&alias_id).await? {
+            return Ok(Some(output_data));
+        }
+
+        let Some(output_id) = found_output_id(self.client().alias_output_id(alias_id).await)? else {
+            return Ok(None);
+        };
+        let output_with_metadata = self.client().get_output(&output_id).await?;
+        let state_controller = output_with_metadata
+            .output()
+            .unlock_conditions()
+            .and_then(|unlock_conditions| unlock_conditions.state_controller_address())
+            .map(|unlock_condition| *unlock_condition.address());
+        let Some(state_controller) = state_controller else {
+            return Ok(None);
+        };
+
+        self.store_requested_output(output_with_metadata, &state_controller)
+            .await
+    }
+
+    /// Returns the unspent output of a foundry. If it isn't known to the account, e.g. because it wasn't synced with
+    /// the sync options, it's requested from the node and stored with the address which controls the output of its
+    /// alias.
+    pub(crate) async fn get_or_sync_foundry_output(
+        &self,
+        foundry_id: FoundryId,
+        alias_output_data: &OutputData,
+    ) -> crate::wallet::Result<Option<OutputData>> {
+        if let Some(output_data) = self.unspent_foundry_output(&foundry_id).await? {
+            return Ok(Some(output_data));
+        }
+
+        let Some(output_id) = found_output_id(self.client().foundry_output_id(foundry_id).await)? else {
+            return Ok(None);
+        };
+        let output_with_metadata = self.client().get_output(&output_id).await?;
+
+        self.store_requested_output(output_with_metadata, &alias_output_data.address)
+            .await
+    }
+
+    // Stores an unspent output which was requested outside of a sync, returns `None` if the address isn't one of the
+    // account
+    async fn store_requested_output(
+        &self,
+        output_with_metadata: OutputWithMetadata,
+        address: &Address,
+    ) -> crate::wallet::Result<Option<OutputData>> {
+        let account_address = self
+            .details()
+            .await
+            .addresses()
+            .into_iter()
+            .find(|account_address| account_address.address.inner() == address);
+        let Some(account_address) = account_address else {
+            return Ok(None);
+        };
+        let associated_address = AddressWithUnspentOutputs {
+            address: account_address.address,
+            key_index: account_address.key_index,
+            internal: account_address.internal,
+            output_ids: Vec::new(),
+        };
+        let Some(output_data) = self
+            .output_response_to_output_data(vec![output_with_metadata], &associated_address)
+            .await?
+            .pop()
+        else {
+            return Ok(None);
+        };
+        log::debug!("[SYNC] stored requested output {}", output_data.output_id);
+
+        let mut account_details = self.details_mut().await;
+        let output_id = output_data.output_id;
+        account_details.outputs.insert(output_id, output_data.clone());
+        account_details.unspent_outputs.insert(output_id, output_data.clone());
+        match account_details
+            .addresses_with_unspent_outputs
+            .iter_mut()
+            .find(|address| address.address == associated_address.address)
+        {
+            Some(address) => {
+                if !address.output_ids.contains(&output_id) {
+                    address.output_ids.push(output_id);
+                }
+            }
+            None => account_details
+                .addresses_with_unspent_outputs
+                .push(AddressWithUnspentOutputs {
+                    output_ids: vec![output_id],
+                    ..associated_address
+                }),
+        }
+
+        #[cfg(feature = "storage")]
+        self.save(Some(&account_details)).await?;
+
+        Ok(Some(output_data))
+    }
+}
@@ -3,6 +3,8 @@
 
 use std::collections::HashSet;
 
+use futures::{StreamExt, TryStreamExt};
+
 use crate::{
     client::secret::SecretManage,
     types::block::output::{FoundryId, Output},
@@ -16,26 +18,31 @@ where
     pub(crate) async fn request_and_store_foundry_outputs(
         &self,
         foundry_ids: HashSet<FoundryId>,
+        parallel_requests: usize,
     ) -> crate::wallet::Result<()> {
         log::debug!("[SYNC] request_and_store_foundry_outputs");
 
         let mut foundries = self.details().await.native_token_foundries().clone();
-        let results =
-            futures::future::try_join_all(foundry_ids.into_iter().filter(|f| !foundries.contains_key(f)).map(
-                |foundry_id| {
-                    let client = self.client().clone();
-                    async move {
-                        task::spawn(async move {
-                            match client.foundry_output_id(foundry_id).await {
-                                Ok(output_id) => Ok(Some(client.get_output(&output_id).await?)),
-                                Err(crate::client::Error::NoOutput(_)) => Ok(None),
-                                Err(e) => Err(crate::wallet::Error::Client(e.into())),
-                            }
-                        })
-                        .await?
-                    }
-                },
-            ))
+        let tasks = foundry_ids
+            .into_iter()
+            .filter(|f| !foundries.contains_key(f))
+            .map(|foundry_id| {
+                let client = self.client().clone();
+                async move {
+                    task::spawn(async move {
+                        match client.foundry_output_id(foundry_id).await {
+                            Ok(output_id) => Ok(Some(client.get_output(&output_id).await?)),
+                            Err(crate::client::Error::NoOutput(_)) => Ok(None),
+                            Err(e) => Err(crate::wallet::Error::Client(e.into())),
+                        }
+                    })
+                    .await?
+                }
+            })
+            .collect::<Vec<_>>();
+        let results = futures::stream::iter(tasks)
+            .buffered(parallel_requests.max(1))
+            .try_collect::<Vec<_>>()
             .await?;
 
         // Update account with new foundries.
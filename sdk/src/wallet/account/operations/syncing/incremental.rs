@@ -0,0 +1,191 @@
+// Copyright 2024 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use std::collections::{HashMap, HashSet};
+
+use crate::{
+    client::secret::SecretManage,
+    types::block::{
+        address::Address,
+        output::{Output, OutputId},
+    },
+    wallet::account::{
+        constants::MAX_INCREMENTAL_SYNC_MILESTONES, operations::syncing::SyncOptions,
+        types::address::AddressWithUnspentOutputs, Account,
+    },
+};
+
+/// The confirmed milestone at the start of the previous sync of an address.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) struct SyncCursor {
+    pub(crate) milestone_index: u32,
+    pub(crate) milestone_timestamp: u32,
+}
+
+/// The cursors of the addresses synced with incremental sync enabled, they're only valid for the options they were
+/// synced with.
+#[derive(Debug, Default)]
+pub(crate) struct SyncCursors {
+    options: Option<SyncOptions>,
+    addresses: HashMap<Address, SyncCursor>,
+    /// Whether the first sync already loaded the outputs of the wallet bootstrap set.
+    pub(crate) bootstrapped: bool,
+}
+
+/// The changes since the cursors of the addresses which can be synced incrementally.
+#[derive(Debug)]
+pub(crate) struct IncrementalSync {
+    cursors: HashMap<Address, SyncCursor>,
+    consumed_output_ids: HashSet<OutputId>,
+}
+
+impl IncrementalSync {
+    /// Returns the milestone timestamp after which new outputs of the address have to be requested, `None` if the
+    /// address needs a full sync.
+    pub(crate) fn created_after(&self, address: &Address) -> Option<u32> {
+        // Outputs of the cursor milestone are requested again, in case the indexer didn't process it yet
+        self.cursors
+            .get(address)
+            .map(|cursor| cursor.milestone_timestamp.saturating_sub(1))
+    }
+
+    /// Merges the previously known output ids of an address with the new ones, without the consumed ones.
+    pub(crate) fn merge_output_ids(&self, old_output_ids: &[OutputId], new_output_ids: Vec<OutputId>) -> Vec<OutputId> {
+        let mut output_ids = old_output_ids
+            .iter()
+            .filter(|output_id| !self.consumed_output_ids.contains(output_id))
+            .copied()
+            .collect::<Vec<_>>();
+        for output_id in new_output_ids {
+            if !output_ids.contains(&output_id) {
+                output_ids.push(output_id);
+            }
+        }
+        output_ids
+    }
+}
+
+// Only the options selecting the outputs of an address matter for its cursor
+fn syncs_same_outputs(a: &SyncOptions, b: &SyncOptions) -> bool {
+    a.account == b.account
+        && a.alias == b.alias
+        && a.nft == b.nft
+        && a.sync_only_most_basic_outputs == b.sync_only_most_basic_outputs
+        && a.native_token_filter == b.native_token_filter
+}
+
+impl<S: 'static + SecretManage> Account<S>
+where
+    crate::wallet::Error: From<S::Error>,
+{
+    /// Returns the confirmed milestone, which is used as cursor for the addresses synced after it.
+    pub(crate) async fn sync_cursor(&self) -> crate::wallet::Result<SyncCursor> {
+        let confirmed_milestone = self.client().get_info().await?.node_info.status.confirmed_milestone;
+
+        Ok(SyncCursor {
+            milestone_index: confirmed_milestone.index,
+            milestone_timestamp: confirmed_milestone.timestamp.unwrap_or_default(),
+        })
+    }
+
+    /// Requests the outputs consumed since the cursors of the addresses to sync, to only sync the changes of these
+    /// addresses. Returns `None` if all addresses need a full sync.
+    pub(crate) async fn prepare_incremental_sync(
+        &self,
+        options: &SyncOptions,
+        addresses_to_sync: &[AddressWithUnspentOutputs],
+        current_cursor: SyncCursor,
+    ) -> crate::wallet::Result<Option<IncrementalSync>> {
+        let mut cursors = {
+            let sync_cursors = self.sync_cursors.lock().await;
+            match &sync_cursors.options {
+                Some(cursor_options) if syncs_same_outputs(cursor_options, options) => addresses_to_sync
+                    .iter()
+                    .filter_map(|address| {
+                        sync_cursors
+                            .addresses
+                            .get(&address.address.inner)
+                            .filter(|cursor| {
+                                current_cursor.milestone_index.saturating_sub(cursor.milestone_index)
+                                    <= MAX_INCREMENTAL_SYNC_MILESTONES
+                            })
+                            .map(|cursor| (address.address.inner, *cursor))
+                    })
+                    .collect::<HashMap<_, _>>(),
+                _ => return Ok(None),
+            }
+        };
+
+        let Some(start_index) = cursors.values().map(|cursor| cursor.milestone_index).min() else {
+            return Ok(None);
+        };
+
+        let utxo_changes = match futures::future::try_join_all(
+            (start_index..=current_cursor.milestone_index).map(|index| self.client().get_utxo_changes_by_index(index)),
+        )
+        .await
+        {
+            Ok(utxo_changes) => utxo_changes,
+            Err(error) => {
+                // The milestones could be pruned already
+                log::debug!("[SYNC] couldn't get the utxo changes since milestone {start_index}: {error}");
+                return Ok(None);
+            }
+        };
+        let consumed_output_ids = utxo_changes
+            .into_iter()
+            .flat_map(|utxo_changes| utxo_changes.consumed_outputs)
+            .collect::<HashSet<_>>();
+
+        // Alias and nft outputs own other outputs, which aren't consumed when they are transferred, so addresses with
+        // a consumed alias or nft output are fully synced
+        let account_details = self.details().await;
+        let unspent_outputs = account_details.unspent_outputs();
+        for address in addresses_to_sync {
+            let consumed_alias_or_nft = address.output_ids.iter().any(|output_id| {
+                consumed_output_ids.contains(output_id)
+                    && unspent_outputs
+                        .get(output_id)
+                        .is_none_or(|output_data| matches!(output_data.output, Output::Alias(_) | Output::Nft(_)))
+            });
+            if consumed_alias_or_nft {
+                cursors.remove(&address.address.inner);
+            }
+        }
+
+        log::debug!(
+            "[SYNC] syncing {} of {} addresses incrementally",
+            cursors.len(),
+            addresses_to_sync.len()
+        );
+        if cursors.is_empty() {
+            return Ok(None);
+        }
+
+        Ok(Some(IncrementalSync {
+            cursors,
+            consumed_output_ids,
+        }))
+    }
+
+    /// Sets the cursor of the synced addresses, cursors of other options are dropped.
+    pub(crate) async fn update_sync_cursors(
+        &self,
+        options: &SyncOptions,
+        synced_addresses: &[Address],
+        cursor: SyncCursor,
+    ) {
+        let mut sync_cursors = self.sync_cursors.lock().await;
+        if !sync_cursors
+            .options
+            .as_ref()
+            .is_some_and(|cursor_options| syncs_same_outputs(cursor_options, options))
+        {
+            sync_cursors.options = Some(options.clone());
+            sync_cursors.addresses.clear();
+        }
+        sync_cursors
+            .addresses
+            .extend(synced_addresses.iter().map(|address| (*address, cursor)));
+    }
+}
@@ -2,24 +2,42 @@
 // SPDX-License-Identifier: Apache-2.0
 
 pub(crate) mod addresses;
+pub(crate) mod bootstrap;
+pub(crate) mod chain_outputs;
 pub(crate) mod foundries;
+pub(crate) mod incremental;
+#[cfg(feature = "irc_27")]
+pub(crate) mod nft_metadata;
 pub(crate) mod options;
 pub(crate) mod outputs;
 pub(crate) mod transactions;
 
-use std::collections::{HashMap, HashSet};
+use std::{
+    collections::{HashMap, HashSet},
+    sync::Arc,
+};
+
+use futures::{StreamExt, TryStreamExt};
 
 pub use self::options::SyncOptions;
+use self::{
+    incremental::IncrementalSync,
+    options::{AliasSyncOptions, NativeTokenFilter},
+};
 use crate::{
     client::secret::SecretManage,
     types::block::{
-        address::{Address, ToBech32Ext},
+        address::{Address, AliasAddress, ToBech32Ext},
         output::{FoundryId, Output, OutputId, OutputMetadata},
     },
-    wallet::account::{
-        constants::MIN_SYNC_INTERVAL,
-        types::{AddressWithUnspentOutputs, OutputData},
-        Account, Balance,
+    wallet::{
+        account::{
+            constants::MIN_SYNC_INTERVAL,
+            types::{AddressWithUnspentOutputs, OutputData},
+            Account, Balance,
+        },
+        core::CancellationToken,
+        task,
     },
 };
 
@@ -49,7 +67,27 @@ where
     /// Sync the account by fetching new information from the nodes. Will also retry pending transactions
     /// if necessary. A custom default can be set using set_default_sync_options.
     pub async fn sync(&self, options: Option<SyncOptions>) -> crate::wallet::Result<Balance> {
-        let options = match options {
+        self.sync_cancellable(options, None).await
+    }
+
+    /// Syncs the account like [`Account::sync()`], but stops with
+    /// [`Error::Cancelled`](crate::wallet::Error::Cancelled) once the token is cancelled. The account is only updated
+    /// with complete sync results, so a cancelled sync doesn't leave it partially updated.
+    pub async fn sync_with_cancellation(
+        &self,
+        options: Option<SyncOptions>,
+        cancellation: &CancellationToken,
+    ) -> crate::wallet::Result<Balance> {
+        self.sync_cancellable(options, Some(cancellation)).await
+    }
+
+    async fn sync_cancellable(
+        &self,
+        options: Option<SyncOptions>,
+        cancellation: Option<&CancellationToken>,
+    ) -> crate::wallet::Result<Balance> {
+        let _in_flight = self.wallet.start_operation()?;
+        let mut options = match options {
             Some(opt) => opt,
             None => self.default_sync_options().await,
         };
@@ -71,16 +109,24 @@ where
             return self.balance().await;
         }
 
-        self.sync_internal(&options).await?;
+        // On the first sync, known outputs from the wallet bootstrap set are loaded directly, so only their addresses
+        // need to be synced
+        let bootstrapped_addresses = self.bootstrap_outputs().await?;
+        if !bootstrapped_addresses.is_empty() && options.addresses.is_empty() {
+            options.addresses = bootstrapped_addresses;
+        }
+
+        self.sync_internal(&options, cancellation).await?;
 
         // Sync transactions after updating account with outputs, so we can use them to check the transaction
         // status
         if options.sync_pending_transactions {
+            self.wallet.check_cancelled(cancellation)?;
             let confirmed_tx_with_unknown_output = self.sync_pending_transactions().await?;
             // Sync again if we don't know the output yet, to prevent having no unspent outputs after syncing
             if confirmed_tx_with_unknown_output {
                 log::debug!("[SYNC] a transaction for which no output is known got confirmed, syncing outputs again");
-                self.sync_internal(&options).await?;
+                self.sync_internal(&options, cancellation).await?;
             }
         };
 
@@ -92,17 +138,51 @@ where
         Ok(balance)
     }
 
-    async fn sync_internal(&self, options: &SyncOptions) -> crate::wallet::Result<()> {
+    async fn sync_internal(
+        &self,
+        options: &SyncOptions,
+        cancellation: Option<&CancellationToken>,
+    ) -> crate::wallet::Result<()> {
         log::debug!("[SYNC] sync_internal");
+        self.wallet.check_cancelled(cancellation)?;
 
         let addresses_to_sync = self.get_addresses_to_sync(options).await?;
         log::debug!("[SYNC] addresses_to_sync {}", addresses_to_sync.len());
 
-        let (spent_or_not_synced_output_ids, addresses_with_unspent_outputs, outputs_data): (
+        // The cursor is taken before requesting the outputs, so changes during the sync are also requested next time
+        let sync_cursor = if options.incremental {
+            Some(self.sync_cursor().await?)
+        } else {
+            None
+        };
+        let incremental_sync = match sync_cursor {
+            Some(sync_cursor) => {
+                self.prepare_incremental_sync(options, &addresses_to_sync, sync_cursor)
+                    .await?
+            }
+            None => None,
+        };
+        let synced_addresses = addresses_to_sync
+            .iter()
+            .map(|address| address.address.inner)
+            .collect::<Vec<_>>();
+
+        let (spent_or_not_synced_output_ids, mut addresses_with_unspent_outputs, mut outputs_data): (
             Vec<OutputId>,
             Vec<AddressWithUnspentOutputs>,
             Vec<OutputData>,
-        ) = self.request_outputs_recursively(addresses_to_sync, options).await?;
+        ) = self
+            .request_outputs_recursively(addresses_to_sync, options, incremental_sync)
+            .await?;
+
+        if let Some(native_token_filter) = &options.native_token_filter {
+            self.apply_native_token_filter(
+                native_token_filter,
+                &mut addresses_with_unspent_outputs,
+                &mut outputs_data,
+            )
+            .await;
+        }
 
         // Request possible spent outputs
         log::debug!("[SYNC] spent_or_not_synced_outputs: {spent_or_not_synced_output_ids:?}");
@@ -126,7 +206,8 @@ where
                 .map(|output| *output.output_id.transaction_id())
                 .collect();
             // Request and store transaction payload for newly received unspent outputs
-            self.request_incoming_transaction_data(transaction_ids).await?;
+            self.request_incoming_transaction_data(transaction_ids, options.parallel_requests)
+                .await?;
         }
 
         if options.sync_native_token_foundries {
@@ -141,9 +222,13 @@ where
                 .collect::<HashSet<_>>();
 
             // Request and store foundry outputs
-            self.request_and_store_foundry_outputs(native_token_foundry_ids).await?;
+            self.request_and_store_foundry_outputs(native_token_foundry_ids, options.parallel_requests)
+                .await?;
         }
 
+        // Stop before updating the account, so it doesn't get updated partially
+        self.wallet.check_cancelled(cancellation)?;
+
         // Updates account with balances, output ids, outputs
         self.update_account(
             addresses_with_unspent_outputs,
@@ -151,7 +236,53 @@ where
             spent_or_unsynced_output_metadata_map,
             options,
         )
-        .await
+        .await?;
+
+        if let Some(sync_cursor) = sync_cursor {
+            self.update_sync_cursors(options, &synced_addresses, sync_cursor).await;
+        }
+
+        #[cfg(feature = "irc_27")]
+        self.update_irc27_metadata_cache().await;
+
+        Ok(())
+    }
+
+    // Drops the outputs ignored by the filter from the sync results and removes them from the account, if they were
+    // tracked before
+    async fn apply_native_token_filter(
+        &self,
+        native_token_filter: &NativeTokenFilter,
+        addresses_with_unspent_outputs: &mut Vec<AddressWithUnspentOutputs>,
+        outputs_data: &mut Vec<OutputData>,
+    ) {
+        let ignored_output_ids = outputs_data
+            .iter()
+            .filter(|output_data| native_token_filter.ignores(&output_data.output))
+            .map(|output_data| output_data.output_id)
+            .collect::<HashSet<_>>();
+        if ignored_output_ids.is_empty() {
+            return;
+        }
+        log::debug!("[SYNC] ignored outputs with filtered native tokens: {ignored_output_ids:?}");
+
+        outputs_data.retain(|output_data| !ignored_output_ids.contains(&output_data.output_id));
+        for address in addresses_with_unspent_outputs.iter_mut() {
+            address
+                .output_ids
+                .retain(|output_id| !ignored_output_ids.contains(output_id));
+        }
+        addresses_with_unspent_outputs.retain(|address| !address.output_ids.is_empty());
+
+        // Outputs used by pending transactions are kept, the account is saved when it's updated with the sync results
+        let mut account_details = self.details_mut().await;
+        for output_id in &ignored_output_ids {
+            if !account_details.locked_outputs.contains(output_id) {
+                account_details.unspent_outputs.remove(output_id);
+                account_details.outputs.remove(output_id);
+                account_details.quarantined_outputs.remove(output_id);
+            }
+        }
     }
 
     // First request all outputs directly related to the ed25519 addresses, then for each nft and alias output we got,
@@ -161,14 +292,11 @@ where
         &self,
         addresses_to_sync: Vec<AddressWithUnspentOutputs>,
         options: &SyncOptions,
+        incremental_sync: Option<IncrementalSync>,
     ) -> crate::wallet::Result<(Vec<OutputId>, Vec<AddressWithUnspentOutputs>, Vec<OutputData>)> {
         // Get outputs for addresses and add them also to the `addresses_with_unspent_outputs`
-        let (addresses_with_output_ids, mut spent_or_not_synced_output_ids) = self
-            .get_output_ids_for_addresses(options, addresses_to_sync.clone())
-            .await?;
-
-        let (mut addresses_with_unspent_outputs, mut outputs_data) = self
-            .get_outputs_from_address_output_ids(addresses_with_output_ids)
+        let (mut addresses_with_unspent_outputs, mut spent_or_not_synced_output_ids, mut outputs_data) = self
+            .get_outputs_for_addresses(options, addresses_to_sync, incremental_sync.map(Arc::new))
             .await?;
 
         // Cache the alias and nft address with the related ed2559 address, so we can update the account address with
@@ -177,6 +305,22 @@ where
 
         let bech32_hrp = self.client().get_bech32_hrp().await?;
 
+        // The identity alias is used like an address of the account, so all outputs owned by it are synced
+        let identity_alias_address = self
+            .details()
+            .await
+            .identity_alias()
+            .map(|alias_id| Address::Alias(AliasAddress::new(alias_id)));
+        let identity_alias_options = SyncOptions {
+            alias: AliasSyncOptions {
+                basic_outputs: true,
+                nft_outputs: true,
+                alias_outputs: true,
+                foundry_outputs: true,
+            },
+            ..options.clone()
+        };
+
         let mut new_outputs_data = outputs_data.clone();
 
         loop {
@@ -201,9 +345,34 @@ where
                 break;
             }
 
-            for (alias_or_nft_address, ed25519_address) in new_alias_and_nft_addresses.drain() {
-                let output_ids = self.get_output_ids_for_address(alias_or_nft_address, options).await?;
+            // The outputs of all new alias and nft addresses are requested at the same time
+            let tasks = new_alias_and_nft_addresses
+                .drain()
+                .map(|(alias_or_nft_address, ed25519_address)| {
+                    let account = self.clone();
+                    let address_options = if Some(alias_or_nft_address) == identity_alias_address {
+                        identity_alias_options.clone()
+                    } else {
+                        options.clone()
+                    };
+                    async move {
+                        task::spawn(async move {
+                            let output_ids = account
+                                .get_output_ids_for_address(alias_or_nft_address, &address_options, None)
+                                .await?;
+                            let outputs = account.get_outputs(output_ids.clone()).await?;
+                            crate::wallet::Result::Ok((ed25519_address, output_ids, outputs))
+                        })
+                        .await?
+                    }
+                })
+                .collect::<Vec<_>>();
+            let results = futures::stream::iter(tasks)
+                .buffered(options.parallel_requests.max(1))
+                .try_collect::<Vec<_>>()
+                .await?;
 
+            for (ed25519_address, output_ids, new_outputs_data_inner) in results {
                 // Update address with unspent outputs
                 let address_with_unspent_outputs = addresses_with_unspent_outputs
                     .iter_mut()
@@ -211,9 +380,13 @@ where
                     .ok_or_else(|| {
                         crate::wallet::Error::AddressNotFoundInAccount(ed25519_address.to_bech32(bech32_hrp))
                     })?;
-                address_with_unspent_outputs.output_ids.extend(output_ids.clone());
+                // With incremental syncing, the ids of already known outputs are still included
+                for output_id in &output_ids {
+                    if !address_with_unspent_outputs.output_ids.contains(output_id) {
+                        address_with_unspent_outputs.output_ids.push(*output_id);
+                    }
+                }
 
-                let new_outputs_data_inner = self.get_outputs(output_ids).await?;
                 let outputs_data_inner = self
                     .output_response_to_output_data(new_outputs_data_inner, address_with_unspent_outputs)
                     .await?;
@@ -223,7 +396,7 @@ where
             }
         }
 
-        // get_output_ids_for_addresses() will return recursively owned outputs not anymore, sine they will only get
+        // get_outputs_for_addresses() will return recursively owned outputs not anymore, sine they will only get
         // synced afterwards, so we filter these unspent outputs here. Maybe the spent_or_not_synced_output_ids can be
         // calculated more efficient in the future, by comparing the new and old outputs only at this point. Then this
         // retain isn't needed anymore.
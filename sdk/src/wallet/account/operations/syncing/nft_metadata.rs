@@ -0,0 +1,49 @@
+// Copyright 2024 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use std::collections::HashMap;
+
+use crate::{
+    client::secret::SecretManage,
+    types::block::output::{feature::Irc27Metadata, Output},
+    wallet::Account,
+};
+
+impl<S: 'static + SecretManage> Account<S>
+where
+    crate::wallet::Error: From<S::Error>,
+{
+    /// Parses the IRC27 metadata of new unspent nft outputs and removes the metadata of nfts that aren't owned
+    /// anymore.
+    pub(crate) async fn update_irc27_metadata_cache(&self) {
+        log::debug!("[SYNC] update_irc27_metadata_cache");
+
+        let account_details = self.details().await;
+        let unspent_nfts = account_details
+            .unspent_outputs()
+            .values()
+            .filter_map(|output_data| match &output_data.output {
+                Output::Nft(nft) => Some((nft.nft_id_non_null(&output_data.output_id), nft)),
+                _ => None,
+            })
+            .collect::<HashMap<_, _>>();
+
+        let mut cache = self.irc27_metadata_cache.write().await;
+        cache.retain(|nft_id, _| unspent_nfts.contains_key(nft_id));
+
+        for (nft_id, nft) in unspent_nfts {
+            if cache.contains_key(&nft_id) {
+                continue;
+            }
+            // The immutable metadata can't change, so it only needs to be parsed once
+            if let Some(metadata) = nft.immutable_features().metadata() {
+                match Irc27Metadata::try_from(metadata) {
+                    Ok(irc27_metadata) => {
+                        cache.insert(nft_id, irc27_metadata);
+                    }
+                    Err(err) => log::debug!("[SYNC] nft {nft_id} has no valid IRC27 metadata: {err}"),
+                }
+            }
+        }
+    }
+}
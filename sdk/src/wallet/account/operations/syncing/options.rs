@@ -1,9 +1,17 @@
 // Copyright 2021 IOTA Stiftung
 // SPDX-License-Identifier: Apache-2.0
 
+use std::collections::BTreeSet;
+
 use serde::{Deserialize, Serialize};
 
-use crate::types::block::address::Bech32Address;
+use crate::{
+    types::block::{
+        address::Bech32Address,
+        output::{Output, TokenId},
+    },
+    wallet::account::constants::PARALLEL_REQUESTS_AMOUNT,
+};
 
 const DEFAULT_ADDRESS_START_INDEX: u32 = 0;
 const DEFAULT_FORCE_SYNCING: bool = false;
@@ -11,6 +19,8 @@ const DEFAULT_SYNC_INCOMING_TRANSACTIONS: bool = false;
 const DEFAULT_SYNC_ONLY_MOST_BASIC_OUTPUTS: bool = false;
 const DEFAULT_SYNC_PENDING_TRANSACTIONS: bool = true;
 const DEFAULT_SYNC_NATIVE_TOKEN_FOUNDRIES: bool = false;
+const DEFAULT_INCREMENTAL: bool = false;
+const DEFAULT_PARALLEL_REQUESTS: usize = PARALLEL_REQUESTS_AMOUNT;
 
 /// The synchronization options
 #[derive(Debug, Clone, Eq, PartialEq, Hash, Serialize, Deserialize)]
@@ -56,6 +66,26 @@ pub struct SyncOptions {
     /// Sync native token foundries, so their metadata can be returned in the balance.
     #[serde(default = "default_sync_native_token_foundries")]
     pub sync_native_token_foundries: bool,
+    /// Only request the outputs which changed since the previous sync of an address, based on the milestone at which
+    /// it was synced. Falls back to a full sync for addresses which weren't synced with the same options before, or if
+    /// too many milestones passed in between.
+    #[serde(default = "default_incremental")]
+    pub incremental: bool,
+    /// Maximum amount of addresses, transactions and foundries which are synced at the same time. The requests of an
+    /// address are sent as soon as the ones of a previous address finished, the amount of API requests sent at the
+    /// same time is also limited by the `max_parallel_api_requests` of the client.
+    #[serde(default = "default_parallel_requests")]
+    pub parallel_requests: usize,
+    /// Ignores basic outputs with native tokens which aren't accepted by the filter, e.g. airdropped spam tokens. They
+    /// aren't tracked and not part of the balance, tracked outputs which are ignored after changing the filter are
+    /// removed from the account.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub native_token_filter: Option<NativeTokenFilter>,
+    /// Quarantines new outputs which look like spam: basic and NFT outputs with a dust amount, without a known sender
+    /// and with links in their metadata or tag. Quarantined outputs aren't part of the balance and aren't used as
+    /// inputs, see [`Account::quarantined_outputs()`](crate::wallet::Account::quarantined_outputs).
+    #[serde(default)]
+    pub quarantine_spam_outputs: bool,
 }
 
 fn default_address_start_index() -> u32 {
@@ -82,6 +112,14 @@ fn default_sync_native_token_foundries() -> bool {
     DEFAULT_SYNC_NATIVE_TOKEN_FOUNDRIES
 }
 
+fn default_incremental() -> bool {
+    DEFAULT_INCREMENTAL
+}
+
+fn default_parallel_requests() -> usize {
+    DEFAULT_PARALLEL_REQUESTS
+}
+
 impl Default for SyncOptions {
     fn default() -> Self {
         Self {
@@ -96,6 +134,39 @@ impl Default for SyncOptions {
             sync_only_most_basic_outputs: default_sync_only_most_basic_outputs(),
             sync_native_token_foundries: default_sync_native_token_foundries(),
             force_syncing: default_force_syncing(),
+            incremental: default_incremental(),
+            parallel_requests: default_parallel_requests(),
+            native_token_filter: None,
+            quarantine_spam_outputs: false,
+        }
+    }
+}
+
+/// Filter for the native tokens of the outputs received by an account.
+#[derive(Debug, Clone, Eq, PartialEq, Hash, Serialize, Deserialize)]
+#[serde(tag = "type", content = "tokenIds", rename_all = "camelCase")]
+pub enum NativeTokenFilter {
+    /// Only outputs whose native tokens are all in the list are tracked.
+    Allowlist(BTreeSet<TokenId>),
+    /// Outputs with a native token in the list are ignored.
+    Denylist(BTreeSet<TokenId>),
+}
+
+impl NativeTokenFilter {
+    /// Returns whether an output is ignored because of its native tokens. Only basic outputs are ignored, so no alias,
+    /// foundry or NFT gets lost.
+    pub fn ignores(&self, output: &Output) -> bool {
+        let Output::Basic(basic_output) = output else {
+            return false;
+        };
+        let mut token_ids = basic_output
+            .native_tokens()
+            .iter()
+            .map(|native_token| native_token.token_id());
+
+        match self {
+            Self::Allowlist(token_ids_to_track) => token_ids.any(|token_id| !token_ids_to_track.contains(token_id)),
+            Self::Denylist(token_ids_to_ignore) => token_ids.any(|token_id| token_ids_to_ignore.contains(token_id)),
         }
     }
 }
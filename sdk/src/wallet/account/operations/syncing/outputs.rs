@@ -2,6 +2,7 @@
 // SPDX-License-Identifier: Apache-2.0
 
 use crypto::keys::bip44::Bip44;
+use futures::{StreamExt, TryStreamExt};
 use instant::Instant;
 
 use crate::{
@@ -63,6 +64,7 @@ where
                     network_id,
                     remainder,
                     chain: Some(chain),
+                    annotations: Default::default(),
                 }
             })
             .collect())
@@ -121,6 +123,7 @@ where
     pub(crate) async fn request_incoming_transaction_data(
         &self,
         mut transaction_ids: Vec<TransactionId>,
+        parallel_requests: usize,
     ) -> crate::wallet::Result<()> {
         log::debug!("[SYNC] request_incoming_transaction_data");
 
@@ -134,51 +137,52 @@ where
         });
         drop(account_details);
 
-        // Limit parallel requests to 100, to avoid timeouts
-        let results =
-            futures::future::try_join_all(transaction_ids.chunks(100).map(|x| x.to_vec()).map(|transaction_ids| {
+        let tasks = transaction_ids
+            .into_iter()
+            .map(|transaction_id| {
                 let client = self.client().clone();
                 async move {
                     task::spawn(async move {
-                        futures::future::try_join_all(transaction_ids.iter().map(|transaction_id| async {
-                            let transaction_id = *transaction_id;
-                            match client.get_included_block(&transaction_id).await {
-                                Ok(block) => {
-                                    if let Some(Payload::Transaction(transaction_payload)) = block.payload() {
-                                        let inputs_with_meta =
-                                            get_inputs_for_transaction_payload(&client, transaction_payload).await?;
-                                        let inputs_response: Vec<OutputWithMetadataResponse> = inputs_with_meta
-                                            .into_iter()
-                                            .map(OutputWithMetadataResponse::from)
-                                            .collect();
-
-                                        let transaction = build_transaction_from_payload_and_inputs(
-                                            transaction_id,
-                                            *transaction_payload.clone(),
-                                            inputs_response,
-                                        )?;
-
-                                        Ok((transaction_id, Some(transaction)))
-                                    } else {
-                                        Ok((transaction_id, None))
-                                    }
-                                }
-                                Err(crate::client::Error::Node(crate::client::node_api::error::Error::NotFound(_))) => {
+                        match client.get_included_block(&transaction_id).await {
+                            Ok(block) => {
+                                if let Some(Payload::Transaction(transaction_payload)) = block.payload() {
+                                    let inputs_with_meta =
+                                        get_inputs_for_transaction_payload(&client, transaction_payload).await?;
+                                    let inputs_response: Vec<OutputWithMetadataResponse> = inputs_with_meta
+                                        .into_iter()
+                                        .map(OutputWithMetadataResponse::from)
+                                        .collect();
+
+                                    let transaction = build_transaction_from_payload_and_inputs(
+                                        transaction_id,
+                                        *transaction_payload.clone(),
+                                        inputs_response,
+                                    )?;
+
+                                    Ok((transaction_id, Some(transaction)))
+                                } else {
                                     Ok((transaction_id, None))
                                 }
-                                Err(e) => Err(crate::wallet::Error::Client(e.into())),
                             }
-                        }))
-                        .await
+                            Err(crate::client::Error::Node(crate::client::node_api::error::Error::NotFound(_))) => {
+                                Ok((transaction_id, None))
+                            }
+                            Err(e) => Err(crate::wallet::Error::Client(e.into())),
+                        }
                     })
                     .await?
                 }
-            }))
+            })
+            .collect::<Vec<_>>();
+        // Limit the parallel requests, to avoid timeouts
+        let results = futures::stream::iter(tasks)
+            .buffered(parallel_requests.max(1))
+            .try_collect::<Vec<_>>()
             .await?;
 
         // Update account with new transactions
         let mut account_details = self.details_mut().await;
-        for (transaction_id, txn) in results.into_iter().flatten() {
+        for (transaction_id, txn) in results {
             if let Some(transaction) = txn {
                 account_details
                     .incoming_transactions
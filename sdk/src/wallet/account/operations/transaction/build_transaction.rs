@@ -6,7 +6,7 @@ use instant::Instant;
 use crate::{
     client::{
         api::{
-            input_selection::Selected, transaction::validate_regular_transaction_essence_length,
+            input_selection::Selected, transaction::validate_prepared_transaction_length,
             PreparedTransactionData,
         },
         secret::{types::InputSigningData, SecretManage},
@@ -60,11 +60,7 @@ where
             essence_builder = essence_builder.with_payload(options.tagged_data_payload);
         }
 
-        let essence = essence_builder.finish_with_params(protocol_parameters)?;
-
-        validate_regular_transaction_essence_length(&essence)?;
-
-        let essence = TransactionEssence::Regular(essence);
+        let essence = TransactionEssence::Regular(essence_builder.finish_with_params(protocol_parameters)?);
 
         let prepared_transaction_data = PreparedTransactionData {
             essence,
@@ -72,6 +68,8 @@ where
             remainder: selected_transaction_data.remainder,
         };
 
+        validate_prepared_transaction_length(&prepared_transaction_data)?;
+
         log::debug!(
             "[TRANSACTION] finished build_transaction in {:.2?}",
             build_transaction_essence_start_time.elapsed()
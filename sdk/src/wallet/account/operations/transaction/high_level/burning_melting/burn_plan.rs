@@ -0,0 +1,210 @@
+// Copyright 2024 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use std::collections::{BTreeMap, HashSet};
+
+use primitive_types::U256;
+
+use crate::{
+    client::api::input_selection::Burn,
+    types::block::{
+        output::{FoundryId, Output, TokenId, TokenScheme},
+        payload::transaction::TransactionId,
+    },
+    wallet::{
+        account::{
+            operations::helpers::time::can_output_be_unlocked_forever_from_now_on, types::Transaction,
+            TransactionOptions,
+        },
+        Account, Error,
+    },
+};
+
+/// A step of a [`BurnPlan`], every step is sent in its own transaction.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub enum BurnPlanStep {
+    /// Melt the circulating supply of a native token, so the foundry which minted it can be destroyed.
+    MeltNativeToken { token_id: TokenId, amount: U256 },
+    /// Burn aliases, foundries, NFTs and native tokens.
+    Burn(Burn),
+}
+
+/// The transactions needed to burn aliases together with the foundries they control, created with
+/// [`Account::burn_plan()`] and sent with [`Account::burn_with_plan()`].
+#[derive(Debug, Clone, Default, Eq, PartialEq)]
+pub struct BurnPlan {
+    /// The steps in the order they're sent, each one spends outputs created by the previous one.
+    pub steps: Vec<BurnPlanStep>,
+}
+
+impl BurnPlan {
+    /// Returns the foundries destroyed by the plan.
+    pub fn foundries(&self) -> HashSet<FoundryId> {
+        self.steps
+            .iter()
+            .filter_map(|step| match step {
+                BurnPlanStep::Burn(burn) => Some(burn.foundries().iter().copied()),
+                BurnPlanStep::MeltNativeToken { .. } => None,
+            })
+            .flatten()
+            .collect()
+    }
+}
+
+impl Account {
+    /// Plans the transactions to burn aliases, foundries, NFTs and native tokens, which [`Account::burn()`] can't do
+    /// in a single transaction.
+    ///
+    /// The foundries controlled by the burned aliases are destroyed first, after melting their circulating supply,
+    /// which has to be held by the account. Native tokens of destroyed foundries are melted instead of burned. Outputs
+    /// owned by the alias addresses aren't moved, see [`Account::chain_graph()`] to find them.
+    pub async fn burn_plan(&self, burn: impl Into<Burn> + Send) -> crate::wallet::Result<BurnPlan> {
+        let mut burn = burn.into();
+        let unspent_outputs = self.unspent_outputs(None).await?;
+        let addresses_with_unspent_outputs = self.addresses_with_unspent_outputs().await?;
+        let local_time = self.client().get_time_checked().await?;
+
+        let mut foundries = burn.foundries.clone();
+        let mut held_native_tokens = BTreeMap::<TokenId, U256>::new();
+        for output_data in &unspent_outputs {
+            match &output_data.output {
+                Output::Foundry(foundry) => {
+                    if burn.aliases.contains(foundry.alias_address().alias_id()) {
+                        foundries.insert(foundry.id());
+                    }
+                }
+                // Native tokens held by foundries aren't added, they can't be melted by their own foundry
+                output => {
+                    // Outputs with an expiration have to be claimed before their tokens can be melted
+                    if !can_output_be_unlocked_forever_from_now_on(&addresses_with_unspent_outputs, output, local_time)
+                    {
+                        continue;
+                    }
+                    for native_token in output.native_tokens().into_iter().flat_map(|n| n.iter()) {
+                        *held_native_tokens.entry(*native_token.token_id()).or_default() += native_token.amount();
+                    }
+                }
+            }
+        }
+
+        let mut foundry_ids = foundries.iter().copied().collect::<Vec<_>>();
+        foundry_ids.sort_unstable();
+
+        let mut steps = Vec::new();
+        for foundry_id in foundry_ids {
+            let foundry = unspent_outputs
+                .iter()
+                .find_map(|output_data| match &output_data.output {
+                    Output::Foundry(foundry) if foundry.id() == foundry_id => Some(foundry),
+                    _ => None,
+                })
+                .ok_or_else(|| {
+                    Error::BurningOrMeltingFailed(format!("foundry {foundry_id} not found in unspent outputs"))
+                })?;
+            let TokenScheme::Simple(token_scheme) = foundry.token_scheme();
+            let circulating_supply = token_scheme.circulating_supply();
+            if circulating_supply.is_zero() {
+                continue;
+            }
+
+            let token_id = TokenId::from(foundry_id);
+            let held = held_native_tokens.get(&token_id).copied().unwrap_or_default();
+            if held < circulating_supply {
+                return Err(Error::FoundryTokensNotHeld {
+                    foundry_id,
+                    circulating_supply,
+                    held,
+                });
+            }
+            // Melting the whole circulating supply also gets rid of the tokens which should be burned
+            burn.native_tokens.remove(&token_id);
+            steps.push(BurnPlanStep::MeltNativeToken {
+                token_id,
+                amount: circulating_supply,
+            });
+        }
+
+        // An alias can only be burned once the foundries it controls are destroyed
+        if !burn.aliases.is_empty() && foundries.len() > burn.foundries.len() {
+            steps.push(BurnPlanStep::Burn(Burn::new().set_foundries(foundries)));
+            burn.foundries = HashSet::new();
+        } else {
+            burn.foundries = foundries;
+        }
+        if !(burn.aliases.is_empty()
+            && burn.nfts.is_empty()
+            && burn.foundries.is_empty()
+            && burn.native_tokens.is_empty())
+        {
+            steps.push(BurnPlanStep::Burn(burn));
+        }
+
+        Ok(BurnPlan { steps })
+    }
+
+    /// Sends the transactions of a [`BurnPlan`], waiting until each one is included and syncing the account before
+    /// sending the next one.
+    ///
+    /// If a step fails after transactions were sent already, [`Error::BurnPlanIncomplete`] lists them, and a new plan
+    /// for the remaining steps can be created after syncing.
+    pub async fn burn_with_plan(
+        &self,
+        plan: BurnPlan,
+        options: impl Into<Option<TransactionOptions>> + Send,
+    ) -> crate::wallet::Result<Vec<Transaction>> {
+        let options = options.into();
+        if options
+            .as_ref()
+            .is_some_and(|options| options.idempotency_key.is_some())
+        {
+            return Err(Error::CustomInput(
+                "burn plans can't use an idempotency key, their transactions depend on the state of the account"
+                    .to_string(),
+            ));
+        }
+
+        let mut transactions = Vec::<Transaction>::new();
+        for step in plan.steps {
+            let previous_transaction_id = transactions.last().map(|transaction| transaction.transaction_id);
+            match self.send_burn_plan_step(step, previous_transaction_id, &options).await {
+                Ok(transaction) => transactions.push(transaction),
+                Err(e) if transactions.is_empty() => return Err(e),
+                Err(e) => {
+                    return Err(Error::BurnPlanIncomplete {
+                        transaction_ids: transactions
+                            .iter()
+                            .map(|transaction| transaction.transaction_id)
+                            .collect(),
+                        error: Box::new(e),
+                    });
+                }
+            }
+        }
+
+        Ok(transactions)
+    }
+
+    async fn send_burn_plan_step(
+        &self,
+        step: BurnPlanStep,
+        previous_transaction_id: Option<TransactionId>,
+        options: &Option<TransactionOptions>,
+    ) -> crate::wallet::Result<Transaction> {
+        if let Some(transaction_id) = previous_transaction_id {
+            // The step spends the alias or foundry outputs created by the previous transaction
+            self.retry_transaction_until_included(&transaction_id, None, None)
+                .await?;
+            self.sync(None).await?;
+        }
+
+        let prepared_transaction = match step {
+            BurnPlanStep::MeltNativeToken { token_id, amount } => {
+                self.prepare_melt_native_token(token_id, amount, options.clone())
+                    .await?
+            }
+            BurnPlanStep::Burn(burn) => self.prepare_burn(burn, options.clone()).await?,
+        };
+        self.sign_and_submit_transaction(prepared_transaction, options.clone())
+            .await
+    }
+}
@@ -30,6 +30,9 @@ where
         options: impl Into<Option<TransactionOptions>> + Send,
     ) -> crate::wallet::Result<Transaction> {
         let options = options.into();
+        if let Some(transaction) = self.idempotent_transaction(options.as_ref()).await {
+            return Ok(transaction);
+        }
         let prepared_transaction = self
             .prepare_melt_native_token(token_id, melt_amount, options.clone())
             .await?;
@@ -84,40 +87,21 @@ where
         }
     }
 
-    /// Find and return unspent `OutputData` for given `alias_id` and `foundry_id`
+    /// Find and return unspent `OutputData` for given `alias_id` and `foundry_id`, outputs which weren't synced yet
+    /// are requested from the node
     async fn find_alias_and_foundry_output_data(
         &self,
         alias_id: AliasId,
         foundry_id: FoundryId,
     ) -> crate::wallet::Result<(OutputData, OutputData)> {
-        let mut existing_alias_output_data = None;
-        let mut existing_foundry_output = None;
-
-        for (output_id, output_data) in self.details().await.unspent_outputs().iter() {
-            match &output_data.output {
-                Output::Alias(output) => {
-                    if output.alias_id_non_null(output_id) == alias_id {
-                        existing_alias_output_data = Some(output_data.clone());
-                    }
-                }
-                Output::Foundry(output) => {
-                    if output.id() == foundry_id {
-                        existing_foundry_output = Some(output_data.clone());
-                    }
-                }
-                // Not interested in these outputs here
-                Output::Treasury(_) | Output::Basic(_) | Output::Nft(_) => {}
-            }
-
-            if existing_alias_output_data.is_some() && existing_foundry_output.is_some() {
-                break;
-            }
-        }
-
-        let existing_alias_output_data = existing_alias_output_data
+        let existing_alias_output_data = self
+            .get_or_sync_alias_output(alias_id)
+            .await?
             .ok_or_else(|| Error::BurningOrMeltingFailed("required alias output for foundry not found".to_string()))?;
 
-        let existing_foundry_output_data = existing_foundry_output
+        let existing_foundry_output_data = self
+            .get_or_sync_foundry_output(foundry_id, &existing_alias_output_data)
+            .await?
             .ok_or_else(|| Error::BurningOrMeltingFailed("required foundry output not found".to_string()))?;
 
         Ok((existing_alias_output_data, existing_foundry_output_data))
@@ -9,6 +9,7 @@ use crate::{
     },
 };
 
+pub(crate) mod burn_plan;
 pub(crate) mod melt_native_token;
 
 impl Account {
@@ -23,6 +24,9 @@ impl Account {
         options: impl Into<Option<TransactionOptions>> + Send,
     ) -> crate::wallet::Result<Transaction> {
         let options = options.into();
+        if let Some(transaction) = self.idempotent_transaction(options.as_ref()).await {
+            return Ok(transaction);
+        }
         let prepared = self.prepare_burn(burn, options.clone()).await?;
 
         self.sign_and_submit_transaction(prepared, options).await
@@ -0,0 +1,288 @@
+// Copyright 2024 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use core::{fmt, str::FromStr};
+
+use crypto::{
+    hashes::{blake2b::Blake2b256, Digest},
+    signatures::ed25519::SecretKey,
+};
+
+use serde::Serialize;
+
+use crate::{
+    client::{
+        api::SignedTransactionData,
+        secret::{types::InputSigningData, SecretManage},
+    },
+    types::block::{
+        address::{Address, Ed25519Address},
+        input::{Input, UtxoInput},
+        output::{
+            unlock_condition::{AddressUnlockCondition, ExpirationUnlockCondition},
+            BasicOutputBuilder, InputsCommitment, Output, OutputId,
+        },
+        payload::{
+            transaction::{RegularTransactionEssence, TransactionEssence},
+            TransactionPayload,
+        },
+        signature::{Ed25519Signature, Signature},
+        unlock::{SignatureUnlock, Unlock, Unlocks},
+    },
+    wallet::{
+        account::{
+            constants::DEFAULT_EXPIRATION_TIME, operations::transaction::Transaction, types::TransactionDto, Account,
+            TransactionOptions,
+        },
+        Error,
+    },
+};
+
+/// The scheme with which encoded claim links start.
+pub const CLAIM_LINK_SCHEME: &str = "iota-claim";
+
+/// A link to claim the funds of an output which is owned by a one-time key, see
+/// [`Account::create_claim_link()`](crate::wallet::Account::create_claim_link).
+///
+/// The link is encoded as `iota-claim:<output id>:<hex encoded secret key>`. Anyone knowing it can claim the funds,
+/// so it should only be shared with the intended recipient.
+#[derive(Clone)]
+pub struct ClaimLink {
+    output_id: OutputId,
+    secret_key: SecretKey,
+}
+
+impl ClaimLink {
+    /// Creates a new [`ClaimLink`] for an output owned by the address of the secret key.
+    pub fn new(output_id: OutputId, secret_key: SecretKey) -> Self {
+        Self { output_id, secret_key }
+    }
+
+    /// Returns the id of the output which can be claimed.
+    pub fn output_id(&self) -> &OutputId {
+        &self.output_id
+    }
+
+    /// Returns the address owning the output which can be claimed.
+    pub fn address(&self) -> Ed25519Address {
+        claim_address(&self.secret_key)
+    }
+
+    /// Returns the link as URL, with the encoded link in the fragment so it isn't sent to the server.
+    pub fn to_url(&self, base_url: &str) -> String {
+        format!("{}#{self}", base_url.trim_end_matches('#'))
+    }
+}
+
+impl fmt::Display for ClaimLink {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{CLAIM_LINK_SCHEME}:{}:{}",
+            self.output_id,
+            prefix_hex::encode(self.secret_key.as_slice())
+        )
+    }
+}
+
+impl fmt::Debug for ClaimLink {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        // Don't leak the secret key in logs
+        f.debug_struct("ClaimLink")
+            .field("output_id", &self.output_id)
+            .field("address", &self.address())
+            .finish_non_exhaustive()
+    }
+}
+
+impl FromStr for ClaimLink {
+    type Err = Error;
+
+    /// Parses an encoded claim link, also if it's the fragment of a URL.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let link = s.rsplit_once('#').map_or(s, |(_, fragment)| fragment);
+        let invalid = |reason: &str| Error::InvalidClaimLink(reason.to_string());
+
+        let mut parts = link.trim().split(':');
+        if parts.next() != Some(CLAIM_LINK_SCHEME) {
+            return Err(invalid("unknown scheme"));
+        }
+        let output_id = parts
+            .next()
+            .ok_or_else(|| invalid("missing output id"))?
+            .parse::<OutputId>()
+            .map_err(|e| invalid(&e.to_string()))?;
+        let secret_key = parts
+            .next()
+            .ok_or_else(|| invalid("missing secret key"))
+            .and_then(|secret_key| {
+                prefix_hex::decode::<[u8; SecretKey::LENGTH]>(secret_key).map_err(|_| invalid("invalid secret key"))
+            })?;
+        if parts.next().is_some() {
+            return Err(invalid("unexpected trailing data"));
+        }
+
+        Ok(Self::new(output_id, SecretKey::from_bytes(&secret_key)))
+    }
+}
+
+fn claim_address(secret_key: &SecretKey) -> Ed25519Address {
+    Ed25519Address::new(Blake2b256::digest(secret_key.public_key().to_bytes()).into())
+}
+
+/// The result of [`Account::create_claim_link()`](crate::wallet::Account::create_claim_link)
+#[derive(Debug)]
+pub struct ClaimLinkTransaction {
+    pub claim_link: ClaimLink,
+    pub transaction: Transaction,
+}
+
+/// Dto for [`ClaimLinkTransaction`], with the encoded claim link
+#[derive(Debug, Eq, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ClaimLinkTransactionDto {
+    pub claim_link: String,
+    pub transaction: TransactionDto,
+}
+
+impl From<&ClaimLinkTransaction> for ClaimLinkTransactionDto {
+    fn from(value: &ClaimLinkTransaction) -> Self {
+        Self {
+            claim_link: value.claim_link.to_string(),
+            transaction: TransactionDto::from(&value.transaction),
+        }
+    }
+}
+
+impl<S: 'static + SecretManage> Account<S>
+where
+    crate::wallet::Error: From<S::Error>,
+{
+    /// Sends base coins to a freshly generated one-time key and returns a link with which the recipient can claim them.
+    ///
+    /// The recipient doesn't need to be known in advance, anyone with the link can claim the funds with
+    /// [`Account::redeem_claim_link()`](crate::wallet::Account::redeem_claim_link). If they aren't claimed within
+    /// `expiration` seconds (one day by default), they are available for the first address of this account again.
+    /// The amount has to cover the storage deposit of the output.
+    /// ```ignore
+    /// let ClaimLinkTransaction { claim_link, transaction } = account.create_claim_link(1_000_000, None, None).await?;
+    /// println!("Share {} with the recipient", claim_link.to_url("https://example.com/claim"));
+    /// ```
+    pub async fn create_claim_link(
+        &self,
+        amount: u64,
+        expiration: impl Into<Option<u32>> + Send,
+        options: impl Into<Option<TransactionOptions>> + Send,
+    ) -> crate::wallet::Result<ClaimLinkTransaction> {
+        log::debug!("[TRANSACTION] create_claim_link");
+        let token_supply = self.client().get_token_supply().await?;
+        let return_address = self
+            .addresses()
+            .await?
+            .first()
+            .ok_or(Error::FailedToGetRemainder)?
+            .address;
+        let local_time = self.client().get_time_checked().await?;
+        let expiration_time = local_time + expiration.into().unwrap_or(DEFAULT_EXPIRATION_TIME);
+
+        let secret_key = SecretKey::generate()?;
+        let output = BasicOutputBuilder::new_with_amount(amount)
+            .add_unlock_condition(AddressUnlockCondition::new(claim_address(&secret_key)))
+            .add_unlock_condition(ExpirationUnlockCondition::new(return_address, expiration_time)?)
+            .finish_output(token_supply)?;
+
+        let transaction = self.send_outputs([output.clone()], options).await?;
+
+        let TransactionEssence::Regular(essence) = transaction.payload.essence();
+        let output_index = essence
+            .outputs()
+            .iter()
+            .position(|o| o == &output)
+            .expect("the output is part of the transaction");
+
+        Ok(ClaimLinkTransaction {
+            claim_link: ClaimLink::new(
+                OutputId::new(transaction.transaction_id, output_index as u16)?,
+                secret_key,
+            ),
+            transaction,
+        })
+    }
+
+    /// Claims the funds of a [`ClaimLink`] by sending them to the first address of this account.
+    ///
+    /// Fails if the funds were already claimed or if the link expired and they are only available for the sender
+    /// again.
+    /// ```ignore
+    /// let transaction = account.redeem_claim_link(&"iota-claim:0x...:0x...".parse()?).await?;
+    /// ```
+    pub async fn redeem_claim_link(&self, claim_link: &ClaimLink) -> crate::wallet::Result<Transaction> {
+        log::debug!("[TRANSACTION] redeem_claim_link {}", claim_link.output_id());
+        let protocol_parameters = self.client().get_protocol_parameters().await?;
+        let address = self
+            .addresses()
+            .await?
+            .first()
+            .ok_or(Error::FailedToGetRemainder)?
+            .address;
+        let local_time = self.client().get_time_checked().await?;
+
+        let not_redeemable = |reason| Error::ClaimLinkNotRedeemable {
+            output_id: *claim_link.output_id(),
+            reason,
+        };
+        let output_with_metadata = self.client().get_output(claim_link.output_id()).await?;
+        if output_with_metadata.metadata().is_spent() {
+            return Err(not_redeemable("the output was already spent"));
+        }
+        let Output::Basic(input) = output_with_metadata.output() else {
+            return Err(not_redeemable("the output isn't a basic output"));
+        };
+        let unlock_conditions = input.unlock_conditions();
+        if unlock_conditions.storage_deposit_return().is_some() || unlock_conditions.is_time_locked(local_time) {
+            return Err(not_redeemable("the output has additional unlock conditions"));
+        }
+        let claim_address = Address::from(claim_link.address());
+        if input.address() != &claim_address {
+            return Err(not_redeemable("the output isn't owned by the claim link"));
+        }
+        if unlock_conditions.is_expired(local_time) {
+            return Err(not_redeemable("the claim link expired"));
+        }
+
+        let output = BasicOutputBuilder::new_with_amount(input.amount())
+            .with_native_tokens(input.native_tokens().clone())
+            .add_unlock_condition(AddressUnlockCondition::new(address))
+            .finish_output(protocol_parameters.token_supply())?;
+
+        let inputs_commitment = InputsCommitment::new([output_with_metadata.output()].into_iter());
+        let essence = TransactionEssence::Regular(
+            RegularTransactionEssence::builder(protocol_parameters.network_id(), inputs_commitment)
+                .with_inputs([Input::Utxo(UtxoInput::from(*claim_link.output_id()))])
+                .with_outputs([output])
+                .finish_with_params(&protocol_parameters)?,
+        );
+        let inputs_data = vec![InputSigningData {
+            output: output_with_metadata.output().clone(),
+            output_metadata: output_with_metadata.into_metadata(),
+            chain: None,
+        }];
+
+        // The input isn't owned by the secret manager, so it's signed with the key of the claim link directly
+        let signature = claim_link.secret_key.sign(&essence.hash());
+        let unlock = Unlock::Signature(SignatureUnlock::new(Signature::from(Ed25519Signature::new(
+            claim_link.secret_key.public_key(),
+            signature,
+        ))));
+        let transaction_payload = TransactionPayload::new(essence, Unlocks::new([unlock])?)?;
+
+        self.submit_and_store_transaction(
+            SignedTransactionData {
+                transaction_payload,
+                inputs_data,
+            },
+            None,
+        )
+        .await
+    }
+}
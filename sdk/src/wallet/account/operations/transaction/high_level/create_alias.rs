@@ -60,6 +60,9 @@ where
         options: impl Into<Option<TransactionOptions>> + Send,
     ) -> crate::wallet::Result<Transaction> {
         let options = options.into();
+        if let Some(transaction) = self.idempotent_transaction(options.as_ref()).await {
+            return Ok(transaction);
+        }
         let prepared_transaction = self.prepare_create_alias_output(params, options.clone()).await?;
 
         self.sign_and_submit_transaction(prepared_transaction, options).await
@@ -4,6 +4,8 @@
 use primitive_types::U256;
 use serde::{Deserialize, Serialize};
 
+#[cfg(feature = "irc_30")]
+use crate::types::block::output::feature::Irc30Metadata;
 use crate::{
     client::{
         api::{PreparedTransactionData, PreparedTransactionDataDto},
@@ -37,6 +39,16 @@ pub struct CreateNativeTokenParams {
     pub foundry_metadata: Option<Vec<u8>>,
 }
 
+impl CreateNativeTokenParams {
+    /// Validate the IRC30 metadata and set it as foundry metadata
+    #[cfg(feature = "irc_30")]
+    pub fn with_irc30(mut self, metadata: Irc30Metadata) -> crate::wallet::Result<Self> {
+        metadata.validate()?;
+        self.foundry_metadata = Some(metadata.to_bytes());
+        Ok(self)
+    }
+}
+
 /// The result of a transaction to create a native token
 #[derive(Debug)]
 pub struct CreateNativeTokenTransaction {
@@ -5,7 +5,9 @@ use primitive_types::U256;
 
 use crate::{
     client::{api::PreparedTransactionData, secret::SecretManage},
-    types::block::output::{AliasOutputBuilder, FoundryOutputBuilder, Output, SimpleTokenScheme, TokenId, TokenScheme},
+    types::block::output::{
+        AliasOutputBuilder, FoundryId, FoundryOutputBuilder, Output, SimpleTokenScheme, TokenId, TokenScheme,
+    },
     wallet::{
         account::{types::Transaction, Account, TransactionOptions},
         Error,
@@ -38,6 +40,9 @@ where
         options: impl Into<Option<TransactionOptions>> + Send,
     ) -> crate::wallet::Result<Transaction> {
         let options = options.into();
+        if let Some(transaction) = self.idempotent_transaction(options.as_ref()).await {
+            return Ok(transaction);
+        }
         let prepared = self
             .prepare_mint_native_token(token_id, mint_amount, options.clone())
             .await?;
@@ -57,21 +62,20 @@ where
         log::debug!("[TRANSACTION] mint_native_token");
 
         let mint_amount = mint_amount.into();
-        let account_details = self.details().await;
         let token_supply = self.client().get_token_supply().await?;
-        let existing_foundry_output = account_details.unspent_outputs().values().find(|output_data| {
-            if let Output::Foundry(output) = &output_data.output {
-                TokenId::new(*output.id()) == token_id
-            } else {
-                false
-            }
-        });
-
-        let existing_foundry_output = existing_foundry_output
-            .ok_or_else(|| Error::MintingFailed(format!("foundry output {token_id} is not available")))?
-            .clone();
-
-        let existing_alias_output = if let Output::Foundry(foundry_output) = &existing_foundry_output.output {
+        let foundry_id = FoundryId::from(token_id);
+
+        // The foundry and the alias output that controls it are requested from the node if they weren't synced yet
+        let existing_alias_output = self
+            .get_or_sync_alias_output(*foundry_id.alias_address().alias_id())
+            .await?
+            .ok_or_else(|| Error::MintingFailed("alias output is not available".to_string()))?;
+        let existing_foundry_output = self
+            .get_or_sync_foundry_output(foundry_id, &existing_alias_output)
+            .await?
+            .ok_or_else(|| Error::MintingFailed(format!("foundry output {token_id} is not available")))?;
+
+        if let Output::Foundry(foundry_output) = &existing_foundry_output.output {
             let TokenScheme::Simple(token_scheme) = foundry_output.token_scheme();
             // Check if we can mint the provided amount without exceeding the maximum_supply
             if token_scheme.maximum_supply() - token_scheme.circulating_supply() < mint_amount {
@@ -80,23 +84,7 @@ where
                     token_scheme.maximum_supply()
                 )));
             }
-
-            // Get the alias output that controls the foundry output
-            let existing_alias_output = account_details.unspent_outputs().values().find(|output_data| {
-                if let Output::Alias(output) = &output_data.output {
-                    output.alias_id_non_null(&output_data.output_id) == **foundry_output.alias_address()
-                } else {
-                    false
-                }
-            });
-            existing_alias_output
-                .ok_or_else(|| Error::MintingFailed("alias output is not available".to_string()))?
-                .clone()
-        } else {
-            return Err(Error::MintingFailed("alias output is not available".to_string()));
-        };
-
-        drop(account_details);
+        }
 
         let alias_output = if let Output::Alias(alias_output) = existing_alias_output.output {
             alias_output
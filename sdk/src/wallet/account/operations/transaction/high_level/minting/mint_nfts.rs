@@ -4,6 +4,8 @@
 use getset::Getters;
 use serde::{Deserialize, Serialize};
 
+#[cfg(feature = "irc_27")]
+use crate::types::block::output::feature::Irc27Metadata;
 use crate::{
     client::{api::PreparedTransactionData, secret::SecretManage},
     types::block::{
@@ -107,6 +109,14 @@ impl MintNftParams {
         self.immutable_metadata = immutable_metadata.into();
         self
     }
+
+    /// Validate the IRC27 metadata and set it as immutable metadata
+    #[cfg(feature = "irc_27")]
+    pub fn with_irc27(mut self, metadata: Irc27Metadata) -> crate::wallet::Result<Self> {
+        metadata.validate()?;
+        self.immutable_metadata = Some(metadata.to_bytes());
+        Ok(self)
+    }
 }
 
 impl<S: 'static + SecretManage> Account<S>
@@ -144,6 +154,9 @@ where
         I::IntoIter: Send,
     {
         let options = options.into();
+        if let Some(transaction) = self.idempotent_transaction(options.as_ref()).await {
+            return Ok(transaction);
+        }
         let prepared_transaction = self.prepare_mint_nfts(params, options.clone()).await?;
 
         self.sign_and_submit_transaction(prepared_transaction, options).await
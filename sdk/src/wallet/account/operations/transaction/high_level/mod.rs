@@ -2,8 +2,11 @@
 // SPDX-License-Identifier: Apache-2.0
 
 pub(crate) mod burning_melting;
+pub(crate) mod claim_link;
 pub(crate) mod create_alias;
 pub(crate) mod minting;
 pub(crate) mod send;
 pub(crate) mod send_native_tokens;
 pub(crate) mod send_nft;
+pub(crate) mod swap;
+pub(crate) mod vesting;
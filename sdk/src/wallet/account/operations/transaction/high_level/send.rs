@@ -1,6 +1,8 @@
 // Copyright 2022 IOTA Stiftung
 // SPDX-License-Identifier: Apache-2.0
 
+use std::{future::Future, ops::Range};
+
 use getset::Getters;
 use serde::{Deserialize, Serialize};
 
@@ -12,18 +14,23 @@ use crate::{
             unlock_condition::{
                 AddressUnlockCondition, ExpirationUnlockCondition, StorageDepositReturnUnlockCondition,
             },
-            BasicOutputBuilder, MinimumStorageDepositBasicOutput,
+            BasicOutputBuilder, MinimumStorageDepositBasicOutput, OUTPUT_COUNT_MAX,
         },
+        payload::transaction::TransactionId,
         ConvertTo,
     },
     wallet::{
         account::{
             constants::DEFAULT_EXPIRATION_TIME, operations::transaction::Transaction, Account, TransactionOptions,
         },
+        core::CancellationToken,
         Error,
     },
 };
 
+// One output of a transaction is kept for the remainder
+const BULK_SEND_CHUNK_SIZE_MAX: usize = OUTPUT_COUNT_MAX as usize - 1;
+
 /// Parameters for `send()`
 #[derive(Debug, Clone, Serialize, Deserialize, Getters)]
 pub struct SendParams {
@@ -36,12 +43,14 @@ pub struct SendParams {
     address: Bech32Address,
     /// Bech32 encoded return address, to which the storage deposit will be returned if one is necessary
     /// given the provided amount. If a storage deposit is needed and a return address is not provided, it will
-    /// default to the first address of the account.
+    /// default to the one of the wallet's [`SendDefaults`](crate::wallet::core::SendDefaults), or the first address
+    /// of the account.
     #[getset(get = "pub")]
     return_address: Option<Bech32Address>,
     /// Expiration in seconds, after which the output will be available for the sender again, if not spent by the
     /// receiver already. The expiration will only be used if one is necessary given the provided amount. If an
-    /// expiration is needed but not provided, it will default to one day.
+    /// expiration is needed but not provided, it will default to the one of the wallet's
+    /// [`SendDefaults`](crate::wallet::core::SendDefaults), or one day.
     #[getset(get = "pub")]
     expiration: Option<u32>,
 }
@@ -75,6 +84,17 @@ impl SendParams {
     }
 }
 
+/// The result of [`Account::bulk_send()`](crate::wallet::Account::bulk_send)
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BulkSendReport {
+    /// The ids of the sent transactions, in the order they were sent
+    pub transaction_ids: Vec<TransactionId>,
+    /// The id of the transaction sending to each recipient, in the order of the provided params. Shorter than the
+    /// params if not all recipients were sent to.
+    pub recipients: Vec<TransactionId>,
+}
+
 impl<S: 'static + SecretManage> Account<S>
 where
     crate::wallet::Error: From<S::Error>,
@@ -99,6 +119,8 @@ where
     /// Calls [Account::send_outputs()](crate::wallet::Account::send_outputs) internally.
     /// The options may define the remainder value strategy or custom inputs.
     /// Addresses provided with [`SendParams`] need to be bech32-encoded.
+    /// All recipients are sent to in a single transaction, use
+    /// [Account::bulk_send()](crate::wallet::Account::bulk_send) for more recipients than fit into one.
     /// ```ignore
     /// let params = [SendParams::new(
     ///     "rms1qpszqzadsym6wpppd6z037dvlejmjuke7s24hm95s9fg9vpua7vluaw60xu",
@@ -120,11 +142,166 @@ where
         I::IntoIter: Send,
     {
         let options = options.into();
+        if let Some(transaction) = self.idempotent_transaction(options.as_ref()).await {
+            return Ok(transaction);
+        }
         let prepared_transaction = self.prepare_send(params, options.clone()).await?;
 
         self.sign_and_submit_transaction(prepared_transaction, options).await
     }
 
+    /// Sends base coins to any number of recipients, splitting them into as many transactions as needed.
+    ///
+    /// Every transaction sends to at most 127 recipients, less if more inputs than allowed would be needed. If the
+    /// funds of the account are locked in a previously sent transaction, its inclusion is awaited and the account
+    /// synced before the next transaction is sent. If a transaction fails after others were sent already,
+    /// [`Error::BulkSendIncomplete`] is returned with the report of the sent ones, so the remaining recipients can be
+    /// sent to later.
+    /// ```ignore
+    /// let report = account.bulk_send(airdrop_params, None).await?;
+    /// for (params, transaction_id) in airdrop_params.iter().zip(report.recipients) {
+    ///     println!("{} sent in {transaction_id}", params.address());
+    /// }
+    /// ```
+    pub async fn bulk_send(
+        &self,
+        params: Vec<SendParams>,
+        options: impl Into<Option<TransactionOptions>> + Send,
+    ) -> crate::wallet::Result<BulkSendReport> {
+        self.bulk_send_cancellable(params, options, None).await
+    }
+
+    /// Sends base coins to any number of recipients like [`Account::bulk_send()`], but stops before the next
+    /// transaction once the token is cancelled. If transactions were sent already, [`Error::BulkSendIncomplete`] is
+    /// returned with [`Error::Cancelled`], so the remaining recipients can be sent to later.
+    pub async fn bulk_send_with_cancellation(
+        &self,
+        params: Vec<SendParams>,
+        options: impl Into<Option<TransactionOptions>> + Send,
+        cancellation: &CancellationToken,
+    ) -> crate::wallet::Result<BulkSendReport> {
+        self.bulk_send_cancellable(params, options, Some(cancellation)).await
+    }
+
+    async fn bulk_send_cancellable(
+        &self,
+        params: Vec<SendParams>,
+        options: impl Into<Option<TransactionOptions>> + Send,
+        cancellation: Option<&CancellationToken>,
+    ) -> crate::wallet::Result<BulkSendReport> {
+        log::debug!("[TRANSACTION] bulk_send {} recipients", params.len());
+        let params = &params;
+        self.send_in_chunks(params.len(), options, cancellation, move |recipients, options| {
+            self.prepare_send(params[recipients].to_vec(), options)
+        })
+        .await
+    }
+
+    /// Sends to `count` recipients in as many transactions as needed, preparing each transaction for a range of
+    /// recipients with `prepare`. See [`Account::bulk_send()`] for how the transactions are split.
+    pub(crate) async fn send_in_chunks<F, Fut>(
+        &self,
+        count: usize,
+        options: impl Into<Option<TransactionOptions>> + Send,
+        cancellation: Option<&CancellationToken>,
+        prepare: F,
+    ) -> crate::wallet::Result<BulkSendReport>
+    where
+        F: Fn(Range<usize>, Option<TransactionOptions>) -> Fut + Send + Sync,
+        Fut: Future<Output = crate::wallet::Result<PreparedTransactionData>> + Send,
+    {
+        let _in_flight = self.wallet.start_operation()?;
+        let options = options.into();
+        if options
+            .as_ref()
+            .is_some_and(|options| options.idempotency_key.is_some())
+        {
+            return Err(Error::CustomInput(
+                "bulk sends can't use an idempotency key, their transactions depend on the state of the account"
+                    .to_string(),
+            ));
+        }
+        let mut report = BulkSendReport::default();
+        let mut chunk_size = BULK_SEND_CHUNK_SIZE_MAX;
+        let mut pending_transaction_id = None;
+
+        while report.recipients.len() < count {
+            if let Err(e) = self.wallet.check_cancelled(cancellation) {
+                if report.transaction_ids.is_empty() {
+                    return Err(e);
+                }
+                return Err(Error::BulkSendIncomplete {
+                    report: Box::new(report),
+                    error: Box::new(e),
+                });
+            }
+            let chunk = report.recipients.len()..count.min(report.recipients.len() + chunk_size);
+
+            let result = match prepare(chunk.clone(), options.clone()).await {
+                Ok(prepared_transaction) => {
+                    self.sign_and_submit_transaction(prepared_transaction, options.clone())
+                        .await
+                }
+                Err(e) => Err(e),
+            };
+
+            match result {
+                Ok(transaction) => {
+                    log::debug!(
+                        "[TRANSACTION] sent to {} recipients in {}",
+                        chunk.len(),
+                        transaction.transaction_id
+                    );
+                    report.transaction_ids.push(transaction.transaction_id);
+                    report.recipients.extend(chunk.map(|_| transaction.transaction_id));
+                    pending_transaction_id = Some(transaction.transaction_id);
+                }
+                Err(Error::Client(e))
+                    if chunk.len() > 1
+                        && matches!(
+                            *e,
+                            crate::client::Error::InputSelection(
+                                crate::client::api::input_selection::Error::InvalidInputCount(_)
+                            )
+                        ) =>
+                {
+                    chunk_size = chunk.len() / 2;
+                }
+                Err(Error::TransactionTooLarge { outputs_to_move, .. })
+                    if chunk.len() > 1 && !outputs_to_move.is_empty() =>
+                {
+                    chunk_size = outputs_to_move[0].clamp(1, chunk.len() - 1);
+                }
+                Err(Error::InsufficientFunds { .. }) if pending_transaction_id.is_some() => {
+                    // Outputs created by the previous transaction, like its remainder, are only available once it's
+                    // included and synced
+                    let transaction_id = pending_transaction_id.take().expect("checked above");
+                    let result = async {
+                        self.retry_transaction_until_included(&transaction_id, None, None)
+                            .await?;
+                        self.sync(None).await
+                    }
+                    .await;
+                    if let Err(e) = result {
+                        return Err(Error::BulkSendIncomplete {
+                            report: Box::new(report),
+                            error: Box::new(e),
+                        });
+                    }
+                }
+                Err(e) if report.transaction_ids.is_empty() => return Err(e),
+                Err(e) => {
+                    return Err(Error::BulkSendIncomplete {
+                        report: Box::new(report),
+                        error: Box::new(e),
+                    });
+                }
+            }
+        }
+
+        Ok(report)
+    }
+
     /// Prepares the transaction for
     /// [Account::send()](crate::wallet::Account::send).
     pub async fn prepare_send<I: IntoIterator<Item = SendParams> + Send>(
@@ -141,7 +318,11 @@ where
         let token_supply = self.client().get_token_supply().await?;
 
         let account_addresses = self.addresses().await?;
-        let default_return_address = account_addresses.first().ok_or(Error::FailedToGetRemainder)?;
+        let send_defaults = self.wallet.send_defaults.read().await.clone();
+        let default_return_address = match send_defaults.return_address {
+            Some(return_address) => return_address,
+            None => account_addresses.first().ok_or(Error::FailedToGetRemainder)?.address,
+        };
 
         let local_time = self.client().get_time_checked().await?;
 
@@ -165,7 +346,7 @@ where
                     Ok::<_, Error>(return_address)
                 })
                 .transpose()?
-                .unwrap_or(default_return_address.address);
+                .unwrap_or(default_return_address);
 
             // Get the minimum required amount for an output assuming it does not need a storage deposit.
             let output = BasicOutputBuilder::new_with_minimum_storage_deposit(rent_structure)
@@ -179,9 +360,10 @@ where
                         .finish_output(token_supply)?,
                 )
             } else {
-                let expiration_time = expiration.map_or(local_time + DEFAULT_EXPIRATION_TIME, |expiration_time| {
-                    local_time + expiration_time
-                });
+                let expiration = expiration
+                    .or(send_defaults.expiration)
+                    .unwrap_or(DEFAULT_EXPIRATION_TIME);
+                let expiration_time = local_time + expiration;
 
                 // Since it does need a storage deposit, calculate how much that should be
                 let storage_deposit_amount = MinimumStorageDepositBasicOutput::new(rent_structure, token_supply)
@@ -13,13 +13,17 @@ use crate::{
             unlock_condition::{
                 AddressUnlockCondition, ExpirationUnlockCondition, StorageDepositReturnUnlockCondition,
             },
-            BasicOutputBuilder, MinimumStorageDepositBasicOutput, NativeToken, NativeTokens, TokenId,
+            BasicOutputBuilder, MinimumStorageDepositBasicOutput, NativeToken, NativeTokens, Output, TokenAmount,
+            TokenId,
         },
         ConvertTo,
     },
     wallet::{
         account::{
-            constants::DEFAULT_EXPIRATION_TIME, operations::transaction::Transaction, Account, TransactionOptions,
+            constants::DEFAULT_EXPIRATION_TIME,
+            operations::transaction::Transaction,
+            types::TransactionDto,
+            Account, TransactionOptions,
         },
         Error, Result,
     },
@@ -36,29 +40,45 @@ pub struct SendNativeTokensParams {
     #[getset(get = "pub")]
     native_tokens: Vec<(TokenId, U256)>,
     /// Bech32 encoded address return address, to which the storage deposit will be returned. Default will use the
-    /// first address of the account
+    /// one of the wallet's [`SendDefaults`](crate::wallet::core::SendDefaults), or the first address of the account
     #[getset(get = "pub")]
     return_address: Option<Bech32Address>,
     /// Expiration in seconds, after which the output will be available for the sender again, if not spent by the
-    /// receiver before. Default is 1 day
+    /// receiver before. Default is the one of the wallet's [`SendDefaults`](crate::wallet::core::SendDefaults), or 1
+    /// day
     #[getset(get = "pub")]
     expiration: Option<u32>,
+    /// The maximum storage deposit which is returned to the sender, the rest of it is gifted to the recipient. If it's
+    /// too low for a storage deposit return, the whole storage deposit is gifted and the output has no expiration.
+    /// Default is to return the whole storage deposit.
+    #[getset(get = "pub")]
+    #[serde(default, with = "crate::utils::serde::option_string")]
+    max_storage_deposit_return: Option<u64>,
 }
 
 impl SendNativeTokensParams {
     /// Creates a new instance of [`SendNativeTokensParams`]
     pub fn new(
         address: impl ConvertTo<Bech32Address>,
-        native_tokens: impl IntoIterator<Item = (TokenId, U256)>,
+        native_tokens: impl IntoIterator<Item = impl Into<TokenAmount>>,
     ) -> Result<Self> {
         Ok(Self {
             address: address.convert()?,
-            native_tokens: native_tokens.into_iter().collect(),
+            native_tokens: native_tokens
+                .into_iter()
+                .map(|token_amount| token_amount.into().into())
+                .collect(),
             return_address: None,
             expiration: None,
+            max_storage_deposit_return: None,
         })
     }
 
+    /// Returns the native tokens to send with their token ids.
+    pub fn token_amounts(&self) -> impl Iterator<Item = TokenAmount> + '_ {
+        self.native_tokens.iter().map(|native_token| TokenAmount::from(*native_token))
+    }
+
     /// Set the return address and try convert to [`Bech32Address`]
     pub fn try_with_return_address(mut self, return_address: impl ConvertTo<Bech32Address>) -> Result<Self> {
         self.return_address = Some(return_address.convert()?);
@@ -76,6 +96,56 @@ impl SendNativeTokensParams {
         self.expiration = expiration_secs;
         self
     }
+
+    /// Set the maximum storage deposit which is returned to the sender, `Some(0)` to always gift it
+    pub fn with_max_storage_deposit_return(mut self, max_storage_deposit_return: impl Into<Option<u64>>) -> Self {
+        self.max_storage_deposit_return = max_storage_deposit_return.into();
+        self
+    }
+}
+
+/// How the storage deposit of an output sending native tokens was funded.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "camelCase")]
+pub enum StorageDepositChoice {
+    /// The storage deposit is returned to the sender with a [`StorageDepositReturnUnlockCondition`], except the
+    /// gifted part above the maximum return.
+    Return {
+        #[serde(with = "crate::utils::serde::string")]
+        returned: u64,
+        #[serde(with = "crate::utils::serde::string")]
+        gifted: u64,
+    },
+    /// The storage deposit is gifted to the recipient, so the output has no return and expiration unlock conditions.
+    Gift {
+        #[serde(with = "crate::utils::serde::string")]
+        gifted: u64,
+    },
+}
+
+/// The result of [`Account::send_native_tokens_with_storage_deposits()`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct NativeTokensTransaction {
+    pub transaction: Transaction,
+    /// How the storage deposit of each output was funded, in the order of the params.
+    pub storage_deposits: Vec<StorageDepositChoice>,
+}
+
+/// Dto for [`NativeTokensTransaction`]
+#[derive(Debug, Eq, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct NativeTokensTransactionDto {
+    pub transaction: TransactionDto,
+    pub storage_deposits: Vec<StorageDepositChoice>,
+}
+
+impl From<&NativeTokensTransaction> for NativeTokensTransactionDto {
+    fn from(value: &NativeTokensTransaction) -> Self {
+        Self {
+            transaction: TransactionDto::from(&value.transaction),
+            storage_deposits: value.storage_deposits.clone(),
+        }
+    }
 }
 
 impl<S: 'static + SecretManage> Account<S>
@@ -84,7 +154,9 @@ where
 {
     /// Sends native tokens in basic outputs with a [`StorageDepositReturnUnlockCondition`] and an
     /// [`ExpirationUnlockCondition`], so that the storage deposit is returned to the sender and the sender gets access
-    /// to the output again after a predefined time (default 1 day).
+    /// to the output again after a predefined time (default 1 day). With
+    /// [`SendNativeTokensParams::with_max_storage_deposit_return()`] only part of the storage deposit is returned, or
+    /// all of it is gifted if the maximum is too low for a storage deposit return.
     /// Calls [Account::send_outputs()](crate::wallet::Account::send_outputs) internally. The options may define the
     /// remainder value strategy or custom inputs. Note that the address needs to be bech32-encoded.
     /// ```ignore
@@ -112,11 +184,51 @@ where
         I::IntoIter: Send,
     {
         let options = options.into();
+        if let Some(transaction) = self.idempotent_transaction(options.as_ref()).await {
+            return Ok(transaction);
+        }
         let prepared_transaction = self.prepare_send_native_tokens(params, options.clone()).await?;
 
         self.sign_and_submit_transaction(prepared_transaction, options).await
     }
 
+    /// Like [Account::send_native_tokens()](crate::wallet::Account::send_native_tokens), but also returns how the
+    /// storage deposit of each output was funded, which depends on
+    /// [`SendNativeTokensParams::with_max_storage_deposit_return()`].
+    /// ```ignore
+    /// let params = SendNativeTokensParams::new(address, [(token_id, U256::from(50))])?
+    ///     .with_max_storage_deposit_return(100_000);
+    /// let NativeTokensTransaction { transaction, storage_deposits } = account
+    ///     .send_native_tokens_with_storage_deposits([params], None)
+    ///     .await?;
+    /// if let StorageDepositChoice::Gift { gifted } = storage_deposits[0] {
+    ///     println!("Gifted {gifted} base coins for the storage deposit");
+    /// }
+    /// ```
+    pub async fn send_native_tokens_with_storage_deposits<I: IntoIterator<Item = SendNativeTokensParams> + Send>(
+        &self,
+        params: I,
+        options: impl Into<Option<TransactionOptions>> + Send,
+    ) -> crate::wallet::Result<NativeTokensTransaction>
+    where
+        I::IntoIter: Send,
+    {
+        let options = options.into();
+        let (outputs, storage_deposits) = self.native_tokens_outputs(params).await?;
+        let transaction = match self.idempotent_transaction(options.as_ref()).await {
+            Some(transaction) => transaction,
+            None => {
+                let prepared_transaction = self.prepare_transaction(outputs, options.clone()).await?;
+                self.sign_and_submit_transaction(prepared_transaction, options).await?
+            }
+        };
+
+        Ok(NativeTokensTransaction {
+            transaction,
+            storage_deposits,
+        })
+    }
+
     /// Prepares the transaction for
     /// [Account::send_native_tokens()](crate::wallet::Account::send_native_tokens).
     pub async fn prepare_send_native_tokens<I: IntoIterator<Item = SendNativeTokensParams> + Send>(
@@ -128,20 +240,43 @@ where
         I::IntoIter: Send,
     {
         log::debug!("[TRANSACTION] prepare_send_native_tokens");
+        let (outputs, _) = self.native_tokens_outputs(params).await?;
+
+        self.prepare_transaction(outputs, options).await
+    }
+
+    // Builds the outputs for the params and decides how their storage deposits are funded
+    async fn native_tokens_outputs<I: IntoIterator<Item = SendNativeTokensParams> + Send>(
+        &self,
+        params: I,
+    ) -> crate::wallet::Result<(Vec<Output>, Vec<StorageDepositChoice>)>
+    where
+        I::IntoIter: Send,
+    {
         let rent_structure = self.client().get_rent_structure().await?;
         let token_supply = self.client().get_token_supply().await?;
 
         let account_addresses = self.addresses().await?;
-        let default_return_address = account_addresses.first().ok_or(Error::FailedToGetRemainder)?;
+        let send_defaults = self.wallet.send_defaults.read().await.clone();
+        let default_return_address = match send_defaults.return_address {
+            Some(return_address) => return_address,
+            None => account_addresses.first().ok_or(Error::FailedToGetRemainder)?.address,
+        };
 
         let local_time = self.client().get_time_checked().await?;
 
+        // A storage deposit return has to cover the storage deposit of an output to the return address
+        let min_storage_deposit_return =
+            MinimumStorageDepositBasicOutput::new(rent_structure, token_supply).finish()?;
+
         let mut outputs = Vec::new();
+        let mut storage_deposits = Vec::new();
         for SendNativeTokensParams {
             address,
             native_tokens,
             return_address,
             expiration,
+            max_storage_deposit_return,
         } in params
         {
             self.client().bech32_hrp_matches(address.hrp()).await?;
@@ -156,17 +291,32 @@ where
                     Ok::<_, Error>(addr)
                 })
                 .transpose()?
-                .unwrap_or(default_return_address.address);
+                .unwrap_or(default_return_address);
 
             let native_tokens = NativeTokens::from_vec(
                 native_tokens
                     .into_iter()
-                    .map(|(id, amount)| {
-                        NativeToken::new(id, amount).map_err(|e| crate::wallet::Error::Client(Box::new(e.into())))
+                    .map(|native_token| {
+                        NativeToken::try_from(TokenAmount::from(native_token))
+                            .map_err(|e| crate::wallet::Error::Client(Box::new(e.into())))
                     })
                     .collect::<Result<Vec<NativeToken>>>()?,
             )?;
 
+            if max_storage_deposit_return.is_some_and(|max| max < min_storage_deposit_return) {
+                let gifted = MinimumStorageDepositBasicOutput::new(rent_structure, token_supply)
+                    .with_native_tokens(native_tokens.clone())
+                    .finish()?;
+                outputs.push(
+                    BasicOutputBuilder::new_with_amount(gifted)
+                        .with_native_tokens(native_tokens)
+                        .add_unlock_condition(AddressUnlockCondition::new(address))
+                        .finish_output(token_supply)?,
+                );
+                storage_deposits.push(StorageDepositChoice::Gift { gifted });
+                continue;
+            }
+
             // get minimum required amount for such an output, so we don't lock more than required
             // We have to check it for every output individually, because different address types and amount of
             // different native tokens require a different storage deposit
@@ -176,24 +326,34 @@ where
                 .with_expiration()?
                 .finish()?;
 
-            let expiration_time = expiration.map_or(local_time + DEFAULT_EXPIRATION_TIME, |expiration_time| {
-                local_time + expiration_time
-            });
+            let expiration = expiration
+                .or(send_defaults.expiration)
+                .unwrap_or(DEFAULT_EXPIRATION_TIME);
+            let expiration_time = local_time + expiration;
+
+            // Without a maximum we send the full storage_deposit_amount back to the sender, so only the native
+            // tokens are sent
+            let returned =
+                max_storage_deposit_return.map_or(storage_deposit_amount, |max| max.min(storage_deposit_amount));
 
             outputs.push(
                 BasicOutputBuilder::new_with_amount(storage_deposit_amount)
                     .with_native_tokens(native_tokens)
                     .add_unlock_condition(AddressUnlockCondition::new(address))
-                    .add_unlock_condition(
-                        // We send the full storage_deposit_amount back to the sender, so only the native tokens are
-                        // sent
-                        StorageDepositReturnUnlockCondition::new(return_address, storage_deposit_amount, token_supply)?,
-                    )
+                    .add_unlock_condition(StorageDepositReturnUnlockCondition::new(
+                        return_address,
+                        returned,
+                        token_supply,
+                    )?)
                     .add_unlock_condition(ExpirationUnlockCondition::new(return_address, expiration_time)?)
                     .finish_output(token_supply)?,
-            )
+            );
+            storage_deposits.push(StorageDepositChoice::Return {
+                returned,
+                gifted: storage_deposit_amount - returned,
+            });
         }
 
-        self.prepare_transaction(outputs, options).await
+        Ok((outputs, storage_deposits))
     }
 }
@@ -1,6 +1,8 @@
 // Copyright 2022 IOTA Stiftung
 // SPDX-License-Identifier: Apache-2.0
 
+use std::collections::HashSet;
+
 use getset::Getters;
 use serde::{Deserialize, Serialize};
 
@@ -11,7 +13,10 @@ use crate::{
         output::{unlock_condition::AddressUnlockCondition, NftId, NftOutputBuilder, Output},
         ConvertTo,
     },
-    wallet::account::{operations::transaction::Transaction, Account, TransactionOptions},
+    wallet::{
+        account::{operations::transaction::Transaction, Account, BulkSendReport, TransactionOptions},
+        Error,
+    },
 };
 
 /// Params for `send_nft()`
@@ -70,11 +75,62 @@ where
         I::IntoIter: Send,
     {
         let options = options.into();
+        if let Some(transaction) = self.idempotent_transaction(options.as_ref()).await {
+            return Ok(transaction);
+        }
         let prepared_transaction = self.prepare_send_nft(params, options.clone()).await?;
 
         self.sign_and_submit_transaction(prepared_transaction, options).await
     }
 
+    /// Sends any number of NFTs, splitting them into as many transactions as needed like
+    /// [Account::bulk_send()](crate::wallet::Account::bulk_send).
+    ///
+    /// All NFTs are checked to be unspent outputs of the account before the first transaction is sent. The NFTs are
+    /// transferred with all their features, so the immutable issuer feature proving their collection is kept, and
+    /// sender features are unlocked by the account. The report lists the transaction sending each NFT, in the order
+    /// of the params.
+    /// ```ignore
+    /// let params = nft_ids
+    ///     .into_iter()
+    ///     .zip(addresses)
+    ///     .map(|(nft_id, address)| SendNftParams::new(address, nft_id))
+    ///     .collect::<Result<Vec<_>>>()?;
+    /// let report = account.distribute_nfts(params, None).await?;
+    /// ```
+    pub async fn distribute_nfts(
+        &self,
+        params: Vec<SendNftParams>,
+        options: impl Into<Option<TransactionOptions>> + Send,
+    ) -> crate::wallet::Result<BulkSendReport> {
+        log::debug!("[TRANSACTION] distribute_nfts {} NFTs", params.len());
+        let owned_nft_ids = self
+            .unspent_outputs(None)
+            .await?
+            .iter()
+            .filter_map(|output_data| match &output_data.output {
+                Output::Nft(nft_output) => Some(nft_output.nft_id_non_null(&output_data.output_id)),
+                _ => None,
+            })
+            .collect::<HashSet<_>>();
+        let mut nft_ids = HashSet::new();
+        for SendNftParams { address, nft_id } in &params {
+            self.client().bech32_hrp_matches(address.hrp()).await?;
+            if !owned_nft_ids.contains(nft_id) {
+                return Err(Error::NftNotFoundInUnspentOutputs);
+            }
+            if !nft_ids.insert(nft_id) {
+                return Err(Error::CustomInput(format!("NFT {nft_id} is distributed more than once")));
+            }
+        }
+
+        let params = &params;
+        self.send_in_chunks(params.len(), options, None, move |nfts, options| {
+            self.prepare_send_nft(params[nfts].to_vec(), options)
+        })
+        .await
+    }
+
     /// Prepares the transaction for
     /// [Account::send_nft()](crate::wallet::Account::send_nft).
     pub async fn prepare_send_nft<I: IntoIterator<Item = SendNftParams> + Send>(
@@ -0,0 +1,778 @@
+// Copyright 2024 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+//! Atomic swaps of base coins, native tokens and NFTs between two accounts.
+//!
+//! A swap is a single transaction which consumes outputs of both parties, so either both assets are exchanged or
+//! none. The maker creates a [`SwapOffer`] with [`Account::create_swap_offer()`], which locks the outputs it consumes,
+//! and sends it to the taker out-of-band, e.g. as JSON. The taker builds the transaction with
+//! [`Account::accept_swap_offer()`], signs its own inputs and sends the [`SwapAcceptance`] back. The maker checks that
+//! it receives the asked asset, signs its inputs and submits the transaction with [`Account::complete_swap()`].
+//!
+//! Until the maker completes the swap, it can withdraw the offer with [`Account::cancel_swap()`]. The expiration of an
+//! offer is enforced by the maker's account, as the ledger has no unlock condition which could make the maker's
+//! outputs conditional on the payment.
+
+use core::{cmp::Reverse, fmt, str::FromStr};
+use std::collections::HashMap;
+
+use primitive_types::U256;
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    client::{
+        api::{PreparedTransactionData, SignedTransactionData},
+        secret::{types::InputSigningData, SecretManage},
+    },
+    types::{
+        api::core::response::OutputWithMetadataResponse,
+        block::{
+            address::{Address, Bech32Address},
+            input::{Input, UtxoInput},
+            output::{
+                dto::OutputDto, unlock_condition::AddressUnlockCondition, BasicOutputBuilder, InputsCommitment,
+                NativeTokensBuilder, NftId, NftOutput, NftOutputBuilder, Output, OutputId, TokenId,
+            },
+            payload::{
+                transaction::{dto::TransactionEssenceDto, RegularTransactionEssence, TransactionEssence},
+                TransactionPayload,
+            },
+            protocol::ProtocolParameters,
+            unlock::{dto::UnlockDto, ReferenceUnlock, Unlock, Unlocks},
+        },
+        TryFromDto,
+    },
+    wallet::{
+        account::{
+            constants::DEFAULT_EXPIRATION_TIME, operations::transaction::Transaction, Account, AccountDetails,
+            OutputData,
+        },
+        Error,
+    },
+};
+
+/// An asset which is exchanged in a swap.
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "camelCase")]
+pub enum SwapAsset {
+    /// An amount of base coins.
+    #[serde(rename_all = "camelCase")]
+    BaseCoin {
+        #[serde(with = "crate::utils::serde::string")]
+        amount: u64,
+    },
+    /// An amount of a native token.
+    #[serde(rename_all = "camelCase")]
+    NativeToken { token_id: TokenId, amount: U256 },
+    /// An NFT, which is transferred with its storage deposit.
+    #[serde(rename_all = "camelCase")]
+    Nft { nft_id: NftId },
+}
+
+/// An offer to swap assets, see the [module documentation](self).
+///
+/// Offers are exchanged out-of-band as JSON, which is also their [`Display`](fmt::Display) and [`FromStr`] format.
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SwapOffer {
+    /// The asset the maker gives.
+    pub offered: SwapAsset,
+    /// The asset the maker asks for in return.
+    pub asked: SwapAsset,
+    /// The address of the maker, which receives the asked asset and the rest of its inputs.
+    pub address: Bech32Address,
+    /// The outputs of the maker which are consumed by the swap.
+    pub inputs: Vec<OutputWithMetadataResponse>,
+    /// Unix timestamp in seconds after which the maker doesn't complete the swap anymore.
+    pub expiration: u32,
+}
+
+impl SwapOffer {
+    /// Returns the ids of the outputs of the maker which are consumed by the swap.
+    pub fn input_ids(&self) -> Vec<OutputId> {
+        self.inputs.iter().map(|input| *input.metadata.output_id()).collect()
+    }
+}
+
+impl fmt::Display for SwapOffer {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", serde_json::to_string(self).map_err(|_| fmt::Error)?)
+    }
+}
+
+impl FromStr for SwapOffer {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        serde_json::from_str(s).map_err(|e| Error::InvalidSwap(format!("can't parse offer: {e}")))
+    }
+}
+
+/// The transaction of an accepted [`SwapOffer`], with the unlocks of the taker, which the maker completes with
+/// [`Account::complete_swap()`].
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SwapAcceptance {
+    /// The accepted offer.
+    pub offer: SwapOffer,
+    /// The transaction, which consumes the inputs of the offer followed by the inputs of the taker.
+    pub essence: TransactionEssenceDto,
+    /// The outputs of the taker which are consumed by the swap.
+    pub inputs: Vec<OutputWithMetadataResponse>,
+    /// The unlocks of the inputs of the taker.
+    pub unlocks: Vec<UnlockDto>,
+}
+
+/// The base coins, native tokens and NFTs of one party of a swap.
+#[derive(Default)]
+struct SwapBalance {
+    amount: u64,
+    native_tokens: NativeTokensBuilder,
+    nfts: Vec<(NftId, NftOutput)>,
+}
+
+impl SwapBalance {
+    /// Sums up outputs which are consumed by a swap.
+    fn of_inputs<'a>(inputs: impl IntoIterator<Item = (&'a OutputId, &'a Output)>) -> crate::wallet::Result<Self> {
+        let mut balance = Self::default();
+        for (output_id, output) in inputs {
+            match output {
+                Output::Basic(basic) => {
+                    balance.amount += basic.amount();
+                    balance.native_tokens.add_native_tokens(basic.native_tokens().clone())?;
+                }
+                Output::Nft(nft) => balance.nfts.push((nft.nft_id_non_null(output_id), nft.clone())),
+                _ => {
+                    return Err(Error::InvalidSwap(format!(
+                        "input {output_id} isn't a basic or NFT output"
+                    )));
+                }
+            }
+        }
+        Ok(balance)
+    }
+
+    /// Sums up the outputs of a swap which are only unlockable by `address`.
+    fn of_outputs<'a>(outputs: impl IntoIterator<Item = &'a Output>, address: &Address) -> crate::wallet::Result<Self> {
+        let mut balance = Self::default();
+        for output in outputs {
+            if !is_owned_by(output, address) {
+                continue;
+            }
+            match output {
+                Output::Basic(basic) => {
+                    balance.amount += basic.amount();
+                    balance.native_tokens.add_native_tokens(basic.native_tokens().clone())?;
+                }
+                Output::Nft(nft) => balance.nfts.push((*nft.nft_id(), nft.clone())),
+                _ => {}
+            }
+        }
+        Ok(balance)
+    }
+
+    /// Adds an asset which was taken from another balance.
+    fn add(&mut self, asset: Asset) -> crate::wallet::Result<()> {
+        match asset {
+            Asset::BaseCoin(amount) => self.amount += amount,
+            Asset::NativeToken(token_id, amount) => {
+                let entry = self.native_tokens.entry(token_id).or_default();
+                *entry = entry
+                    .checked_add(amount)
+                    .ok_or(crate::types::block::Error::NativeTokensOverflow)?;
+            }
+            Asset::Nft(nft_id, nft) => self.nfts.push((nft_id, nft)),
+        }
+        Ok(())
+    }
+
+    /// Takes an asset, returns `None` if the balance doesn't contain it.
+    fn take(&mut self, asset: &SwapAsset) -> Option<Asset> {
+        match asset {
+            SwapAsset::BaseCoin { amount } => {
+                self.amount = self.amount.checked_sub(*amount)?;
+                Some(Asset::BaseCoin(*amount))
+            }
+            SwapAsset::NativeToken { token_id, amount } => {
+                let available = self.native_tokens.get_mut(token_id)?;
+                *available = available.checked_sub(*amount)?;
+                if available.is_zero() {
+                    self.native_tokens.remove(token_id);
+                }
+                Some(Asset::NativeToken(*token_id, *amount))
+            }
+            SwapAsset::Nft { nft_id } => {
+                let index = self.nfts.iter().position(|(id, _)| id == nft_id)?;
+                let (nft_id, nft) = self.nfts.remove(index);
+                Some(Asset::Nft(nft_id, nft))
+            }
+        }
+    }
+
+    /// Returns whether this balance contains at least the base coins, native tokens and NFTs of `other`.
+    fn covers(&self, other: &Self) -> bool {
+        self.amount >= other.amount
+            && other
+                .native_tokens
+                .iter()
+                .all(|(token_id, amount)| self.native_tokens.get(token_id).is_some_and(|a| a >= amount))
+            && other
+                .nfts
+                .iter()
+                .all(|(nft_id, _)| self.nfts.iter().any(|(id, _)| id == nft_id))
+    }
+
+    /// Returns the minimum amount of the basic output holding the base coins and native tokens of this balance.
+    fn minimum_amount(&self, address: Address, protocol_parameters: &ProtocolParameters) -> crate::wallet::Result<u64> {
+        Ok(
+            BasicOutputBuilder::new_with_minimum_storage_deposit(*protocol_parameters.rent_structure())
+                .with_native_tokens(self.native_tokens.clone().finish()?)
+                .add_unlock_condition(AddressUnlockCondition::new(address))
+                .finish_output(protocol_parameters.token_supply())?
+                .amount(),
+        )
+    }
+
+    /// Returns whether the base coins and native tokens of this balance need a basic output.
+    fn needs_basic_output(&self) -> bool {
+        self.amount > 0 || !self.native_tokens.is_empty()
+    }
+
+    /// Returns the outputs holding this balance.
+    fn into_outputs(self, address: Address, token_supply: u64) -> crate::wallet::Result<Vec<Output>> {
+        let mut outputs = Vec::new();
+        if self.needs_basic_output() {
+            outputs.push(
+                BasicOutputBuilder::new_with_amount(self.amount)
+                    .with_native_tokens(self.native_tokens.finish()?)
+                    .add_unlock_condition(AddressUnlockCondition::new(address))
+                    .finish_output(token_supply)?,
+            );
+        }
+        for (nft_id, nft) in self.nfts {
+            outputs.push(
+                NftOutputBuilder::from(&nft)
+                    .with_nft_id(nft_id)
+                    .with_unlock_conditions([AddressUnlockCondition::new(address)])
+                    .finish_output(token_supply)?,
+            );
+        }
+        Ok(outputs)
+    }
+}
+
+/// An asset which was taken from a [`SwapBalance`].
+enum Asset {
+    BaseCoin(u64),
+    NativeToken(TokenId, U256),
+    Nft(NftId, NftOutput),
+}
+
+/// Returns whether the output can only be unlocked by `address`, so it can be used in a swap.
+fn is_owned_by(output: &Output, address: &Address) -> bool {
+    match output {
+        Output::Basic(basic) => basic.unlock_conditions().len() == 1 && basic.address() == address,
+        Output::Nft(nft) => nft.unlock_conditions().len() == 1 && nft.address() == address,
+        _ => false,
+    }
+}
+
+/// Returns the unlocked outputs of the account which can be consumed by a swap.
+fn swappable_outputs(account_details: &AccountDetails) -> Vec<OutputData> {
+    account_details
+        .unspent_outputs()
+        .values()
+        .filter(|output_data| {
+            !account_details.locked_outputs.contains(&output_data.output_id)
+                && !account_details.quarantined_outputs.contains(&output_data.output_id)
+                && output_data.chain.is_some()
+                && is_owned_by(&output_data.output, &output_data.address)
+        })
+        .cloned()
+        .collect()
+}
+
+/// Converts outputs of the other party of a swap, which the account can't sign.
+fn foreign_inputs(
+    inputs: &[OutputWithMetadataResponse],
+    protocol_parameters: &ProtocolParameters,
+) -> crate::wallet::Result<Vec<InputSigningData>> {
+    inputs
+        .iter()
+        .map(|input| {
+            Ok(InputSigningData {
+                output: Output::try_from_dto_with_params(input.output.clone(), protocol_parameters)?,
+                output_metadata: input.metadata,
+                chain: None,
+            })
+        })
+        .collect()
+}
+
+/// Converts outputs of the account which are consumed by a swap.
+fn own_inputs(inputs: &[OutputData]) -> Vec<InputSigningData> {
+    inputs
+        .iter()
+        .map(|output_data| InputSigningData {
+            output: output_data.output.clone(),
+            output_metadata: output_data.metadata,
+            chain: output_data.chain,
+        })
+        .collect()
+}
+
+fn output_with_metadata_response(output_data: &OutputData) -> OutputWithMetadataResponse {
+    OutputWithMetadataResponse {
+        metadata: output_data.metadata,
+        output: OutputDto::from(&output_data.output),
+    }
+}
+
+/// Returns the outputs of a swap, or `None` if the inputs of the taker don't cover the asked asset and the storage
+/// deposits.
+fn swap_outputs<'a>(
+    offer: &SwapOffer,
+    maker_inputs: &[InputSigningData],
+    taker_inputs: impl IntoIterator<Item = (&'a OutputId, &'a Output)>,
+    taker_address: Address,
+    protocol_parameters: &ProtocolParameters,
+) -> crate::wallet::Result<Option<Vec<Output>>> {
+    let maker_address = *offer.address.inner();
+    let mut maker = SwapBalance::of_inputs(maker_inputs.iter().map(|input| (input.output_id(), &input.output)))?;
+    let mut taker = SwapBalance::of_inputs(taker_inputs)?;
+
+    let offered = maker
+        .take(&offer.offered)
+        .ok_or_else(|| Error::InvalidSwap("the inputs of the offer don't contain the offered asset".to_string()))?;
+    taker.add(offered)?;
+    let Some(asked) = taker.take(&offer.asked) else {
+        return Ok(None);
+    };
+    maker.add(asked)?;
+
+    // The taker pays the storage deposit the remainder of the maker lacks
+    if maker.needs_basic_output() {
+        let minimum_amount = maker.minimum_amount(maker_address, protocol_parameters)?;
+        if maker.amount < minimum_amount {
+            let Some(amount) = taker.amount.checked_sub(minimum_amount - maker.amount) else {
+                return Ok(None);
+            };
+            taker.amount = amount;
+            maker.amount = minimum_amount;
+        }
+    }
+    if taker.needs_basic_output() && taker.amount < taker.minimum_amount(taker_address, protocol_parameters)? {
+        return Ok(None);
+    }
+
+    let token_supply = protocol_parameters.token_supply();
+    let mut outputs = maker.into_outputs(maker_address, token_supply)?;
+    outputs.extend(taker.into_outputs(taker_address, token_supply)?);
+    Ok(Some(outputs))
+}
+
+impl<S: 'static + SecretManage> Account<S>
+where
+    crate::wallet::Error: From<S::Error>,
+{
+    /// Creates an offer to swap an asset of this account for an asset of another account, see the
+    /// [module documentation](self).
+    ///
+    /// The outputs holding the offered asset are locked until the swap is completed or cancelled. The maker doesn't
+    /// complete the swap after `expiration` seconds (one day by default).
+    /// ```ignore
+    /// let offer = account
+    ///     .create_swap_offer(
+    ///         SwapAsset::NativeToken { token_id, amount: U256::from(100) },
+    ///         SwapAsset::BaseCoin { amount: 1_000_000 },
+    ///         None,
+    ///     )
+    ///     .await?;
+    /// println!("Send {offer} to the taker");
+    /// ```
+    pub async fn create_swap_offer(
+        &self,
+        offered: SwapAsset,
+        asked: SwapAsset,
+        expiration: impl Into<Option<u32>> + Send,
+    ) -> crate::wallet::Result<SwapOffer> {
+        log::debug!("[TRANSACTION] create_swap_offer");
+        let address = self
+            .addresses()
+            .await?
+            .first()
+            .ok_or(Error::FailedToGetRemainder)?
+            .address;
+        let local_time = self.client().get_time_checked().await?;
+
+        let mut account_details = self.details_mut().await;
+        let mut candidates = swappable_outputs(&account_details);
+        let inputs = match &offered {
+            SwapAsset::BaseCoin { amount } => {
+                candidates.retain(|output_data| output_data.output.is_basic());
+                // Prefer outputs without native tokens, which would only be moved
+                candidates.sort_by_key(|output_data| {
+                    (
+                        output_data.output.native_tokens().is_some_and(|t| !t.is_empty()),
+                        Reverse(output_data.output.amount()),
+                    )
+                });
+                let mut selected_amount = 0;
+                let inputs = candidates
+                    .into_iter()
+                    .take_while(|output_data| {
+                        let missing = selected_amount < *amount;
+                        selected_amount += output_data.output.amount();
+                        missing
+                    })
+                    .collect::<Vec<_>>();
+                let available = inputs.iter().map(|output_data| output_data.output.amount()).sum();
+                if available < *amount {
+                    return Err(Error::InsufficientFunds {
+                        available,
+                        required: *amount,
+                    });
+                }
+                inputs
+            }
+            SwapAsset::NativeToken { token_id, amount } => {
+                let token_amount = |output_data: &OutputData| {
+                    output_data
+                        .output
+                        .native_tokens()
+                        .and_then(|native_tokens| native_tokens.iter().find(|t| t.token_id() == token_id))
+                        .map_or(U256::zero(), |native_token| native_token.amount())
+                };
+                candidates.retain(|output_data| output_data.output.is_basic() && !token_amount(output_data).is_zero());
+                candidates.sort_by_key(|output_data| Reverse(token_amount(output_data)));
+                let mut selected_amount = U256::zero();
+                let inputs = candidates
+                    .into_iter()
+                    .take_while(|output_data| {
+                        let missing = selected_amount < *amount;
+                        selected_amount = selected_amount.saturating_add(token_amount(output_data));
+                        missing
+                    })
+                    .collect::<Vec<_>>();
+                if inputs.iter().map(token_amount).fold(U256::zero(), U256::saturating_add) < *amount {
+                    return Err(Error::InvalidSwap(format!(
+                        "the account doesn't have {amount} unlocked native tokens {token_id}"
+                    )));
+                }
+                inputs
+            }
+            SwapAsset::Nft { nft_id } => vec![candidates
+                .into_iter()
+                .find(|output_data| match &output_data.output {
+                    Output::Nft(nft) => nft.nft_id_non_null(&output_data.output_id) == *nft_id,
+                    _ => false,
+                })
+                .ok_or(Error::NftNotFoundInUnspentOutputs)?],
+        };
+
+        for output_data in &inputs {
+            account_details.locked_outputs.insert(output_data.output_id);
+        }
+        #[cfg(feature = "storage")]
+        self.save(Some(&account_details)).await?;
+
+        Ok(SwapOffer {
+            offered,
+            asked,
+            address,
+            inputs: inputs.iter().map(output_with_metadata_response).collect(),
+            expiration: local_time + expiration.into().unwrap_or(DEFAULT_EXPIRATION_TIME),
+        })
+    }
+
+    /// Accepts a [`SwapOffer`] of another account by building the swap transaction and signing the inputs of this
+    /// account. The returned [`SwapAcceptance`] has to be sent back to the maker, who completes the swap with
+    /// [`Account::complete_swap()`].
+    ///
+    /// The inputs of this account aren't locked, spending them before the maker completed the swap makes the
+    /// acceptance invalid.
+    /// ```ignore
+    /// let acceptance = account.accept_swap_offer(&serialized_offer.parse()?).await?;
+    /// ```
+    pub async fn accept_swap_offer(&self, offer: &SwapOffer) -> crate::wallet::Result<SwapAcceptance> {
+        log::debug!("[TRANSACTION] accept_swap_offer");
+        let protocol_parameters = self.client().get_protocol_parameters().await?;
+        let taker_address = self
+            .addresses()
+            .await?
+            .first()
+            .ok_or(Error::FailedToGetRemainder)?
+            .address
+            .into_inner();
+        let local_time = self.client().get_time_checked().await?;
+        if offer.expiration <= local_time {
+            return Err(Error::InvalidSwap("the offer expired".to_string()));
+        }
+
+        let maker_inputs = foreign_inputs(&offer.inputs, &protocol_parameters)?;
+        if let Some(input) = maker_inputs
+            .iter()
+            .find(|input| !is_owned_by(&input.output, offer.address.inner()))
+        {
+            return Err(Error::InvalidSwap(format!(
+                "input {} isn't only unlockable by the maker",
+                input.output_id()
+            )));
+        }
+        let offer_input_ids = offer.input_ids();
+        let current_inputs = self.client().get_outputs(&offer_input_ids).await?;
+        if current_inputs.len() != maker_inputs.len()
+            || current_inputs
+                .iter()
+                .zip(&maker_inputs)
+                .any(|(current, input)| current.metadata().is_spent() || current.output() != &input.output)
+        {
+            return Err(Error::InvalidSwap(
+                "an input of the offer was already spent".to_string(),
+            ));
+        }
+
+        // Wait for previously queued operations, so their selected inputs are already locked
+        let queue_guard = self.wallet.transaction_queue.lock().await;
+        let mut candidates = swappable_outputs(&*self.details().await);
+        let provides_asked = |output_data: &OutputData| match (&output_data.output, &offer.asked) {
+            (Output::Nft(nft), SwapAsset::Nft { nft_id }) => nft.nft_id_non_null(&output_data.output_id) == *nft_id,
+            (Output::Basic(basic), SwapAsset::NativeToken { token_id, .. }) => {
+                basic.native_tokens().iter().any(|t| t.token_id() == token_id)
+            }
+            _ => false,
+        };
+        candidates.retain(|output_data| {
+            !offer_input_ids.contains(&output_data.output_id)
+                && (output_data.output.is_basic() || provides_asked(output_data))
+        });
+        // Use the outputs with the asked asset first, then the largest ones
+        candidates.sort_by_key(|output_data| {
+            (
+                Reverse(provides_asked(output_data)),
+                Reverse(output_data.output.amount()),
+            )
+        });
+
+        let mut candidates = candidates.into_iter();
+        let mut taker_inputs = Vec::new();
+        let outputs = loop {
+            if let Some(outputs) = swap_outputs(
+                offer,
+                &maker_inputs,
+                taker_inputs
+                    .iter()
+                    .map(|output_data: &OutputData| (&output_data.output_id, &output_data.output)),
+                taker_address,
+                &protocol_parameters,
+            )? {
+                break outputs;
+            }
+            taker_inputs.push(candidates.next().ok_or_else(|| {
+                Error::InvalidSwap("the account doesn't have enough unlocked funds to accept the offer".to_string())
+            })?);
+        };
+
+        let inputs_commitment = InputsCommitment::new(
+            maker_inputs
+                .iter()
+                .map(|input| &input.output)
+                .chain(taker_inputs.iter().map(|output_data| &output_data.output)),
+        );
+        let essence = TransactionEssence::Regular(
+            RegularTransactionEssence::builder(protocol_parameters.network_id(), inputs_commitment)
+                .with_inputs(
+                    offer_input_ids
+                        .iter()
+                        .chain(taker_inputs.iter().map(|output_data| &output_data.output_id))
+                        .map(|output_id| Input::Utxo(UtxoInput::from(*output_id)))
+                        .collect::<Vec<_>>(),
+                )
+                .with_outputs(outputs)
+                .finish_with_params(&protocol_parameters)?,
+        );
+        drop(queue_guard);
+
+        let first_index = maker_inputs.len();
+        let mut inputs_data = maker_inputs;
+        inputs_data.extend(own_inputs(&taker_inputs));
+        let prepared_transaction_data = PreparedTransactionData {
+            essence,
+            inputs_data,
+            remainder: None,
+        };
+        let unlocks = self
+            .sign_swap_inputs(&prepared_transaction_data, first_index, &taker_inputs)
+            .await?
+            .iter()
+            .map(UnlockDto::from)
+            .collect();
+
+        Ok(SwapAcceptance {
+            offer: offer.clone(),
+            essence: TransactionEssenceDto::from(&prepared_transaction_data.essence),
+            inputs: taker_inputs.iter().map(output_with_metadata_response).collect(),
+            unlocks,
+        })
+    }
+
+    /// Completes a swap which the taker accepted: checks that this account receives the asked asset and the rest of
+    /// its inputs, signs them and submits the transaction.
+    ///
+    /// `offer` has to be the offer created by this account, as the one in the acceptance could have been altered.
+    /// Fails if the offer expired or was cancelled.
+    pub async fn complete_swap(
+        &self,
+        offer: &SwapOffer,
+        acceptance: &SwapAcceptance,
+    ) -> crate::wallet::Result<Transaction> {
+        log::debug!("[TRANSACTION] complete_swap");
+        if &acceptance.offer != offer {
+            return Err(Error::InvalidSwap(
+                "the acceptance is for a different offer".to_string(),
+            ));
+        }
+        let protocol_parameters = self.client().get_protocol_parameters().await?;
+        let local_time = self.client().get_time_checked().await?;
+        if offer.expiration <= local_time {
+            return Err(Error::InvalidSwap("the offer expired".to_string()));
+        }
+
+        let offer_input_ids = offer.input_ids();
+        let maker_inputs = {
+            let account_details = self.details().await;
+            // Cancelled offers could have their inputs locked again by another transaction
+            let used_by_transaction = account_details
+                .pending_transactions()
+                .iter()
+                .filter_map(|transaction_id| account_details.transactions().get(transaction_id))
+                .flat_map(|transaction| &transaction.inputs)
+                .any(|input| offer_input_ids.contains(input.metadata.output_id()));
+            offer_input_ids
+                .iter()
+                .map(|output_id| {
+                    account_details
+                        .locked_outputs
+                        .contains(output_id)
+                        .then(|| account_details.unspent_outputs().get(output_id).cloned())
+                        .flatten()
+                })
+                .collect::<Option<Vec<_>>>()
+                .filter(|_| !used_by_transaction)
+                .ok_or_else(|| Error::InvalidSwap("the offer was cancelled or its inputs were spent".to_string()))?
+        };
+        let taker_inputs = foreign_inputs(&acceptance.inputs, &protocol_parameters)?;
+
+        let essence = TransactionEssence::try_from_dto_with_params(acceptance.essence.clone(), &protocol_parameters)?;
+        let TransactionEssence::Regular(regular_essence) = &essence;
+        let expected_inputs = offer_input_ids
+            .iter()
+            .chain(taker_inputs.iter().map(InputSigningData::output_id))
+            .map(|output_id| Input::Utxo(UtxoInput::from(*output_id)))
+            .collect::<Vec<_>>();
+        if regular_essence.inputs() != expected_inputs.as_slice() || acceptance.unlocks.len() != taker_inputs.len() {
+            return Err(Error::InvalidSwap(
+                "the inputs of the transaction don't match the offer".to_string(),
+            ));
+        }
+
+        let mut expected = SwapBalance::of_inputs(
+            maker_inputs
+                .iter()
+                .map(|output_data| (&output_data.output_id, &output_data.output)),
+        )?;
+        expected
+            .take(&offer.offered)
+            .ok_or_else(|| Error::InvalidSwap("the inputs of the offer don't contain the offered asset".to_string()))?;
+        let received = SwapBalance::of_outputs(regular_essence.outputs(), offer.address.inner())?;
+        let receives_asked = match &offer.asked {
+            SwapAsset::BaseCoin { amount } => expected.add(Asset::BaseCoin(*amount)).is_ok(),
+            SwapAsset::NativeToken { token_id, amount } => expected.add(Asset::NativeToken(*token_id, *amount)).is_ok(),
+            SwapAsset::Nft { nft_id } => received.nfts.iter().any(|(id, _)| id == nft_id),
+        };
+        if !receives_asked || !received.covers(&expected) {
+            return Err(Error::InvalidSwap(
+                "the transaction doesn't pay the asked asset".to_string(),
+            ));
+        }
+
+        let mut inputs_data = own_inputs(&maker_inputs);
+        inputs_data.extend(taker_inputs);
+        let prepared_transaction_data = PreparedTransactionData {
+            essence,
+            inputs_data,
+            remainder: None,
+        };
+        let unlocks = self
+            .sign_swap_inputs(&prepared_transaction_data, 0, &maker_inputs)
+            .await?
+            .into_iter()
+            .map(Ok)
+            .chain(acceptance.unlocks.iter().cloned().map(Unlock::try_from))
+            .collect::<Result<Vec<_>, _>>()?;
+        let transaction_payload = TransactionPayload::new(prepared_transaction_data.essence, Unlocks::new(unlocks)?)?;
+
+        self.submit_and_store_transaction(
+            SignedTransactionData {
+                transaction_payload,
+                inputs_data: prepared_transaction_data.inputs_data,
+            },
+            None,
+        )
+        .await
+    }
+
+    /// Cancels a [`SwapOffer`] of this account by unlocking its inputs, so the swap can't be completed anymore.
+    pub async fn cancel_swap(&self, offer: &SwapOffer) -> crate::wallet::Result<()> {
+        log::debug!("[TRANSACTION] cancel_swap");
+        let mut account_details = self.details_mut().await;
+        for output_id in offer.input_ids() {
+            account_details.locked_outputs.remove(&output_id);
+        }
+        #[cfg(feature = "storage")]
+        self.save(Some(&account_details)).await?;
+        Ok(())
+    }
+
+    /// Asks the approval handler of the wallet for the swap transaction, then signs the inputs of this account, which
+    /// start at `first_index` in the transaction.
+    ///
+    /// The inputs are signed one by one, as the secret manager can't sign the whole transaction without the chains of
+    /// the other party, so swaps aren't supported by Ledger Nano devices.
+    async fn sign_swap_inputs(
+        &self,
+        prepared_transaction_data: &PreparedTransactionData,
+        first_index: usize,
+        inputs: &[OutputData],
+    ) -> crate::wallet::Result<Vec<Unlock>> {
+        // Ask for approval before queueing, as it may take a while
+        let transaction_approver = self.wallet.transaction_approver.read().await.clone();
+        if let Some(transaction_approver) = transaction_approver {
+            if !transaction_approver
+                .approve_transaction(self.details().await.index, prepared_transaction_data)
+                .await
+            {
+                log::debug!("[TRANSACTION] swap rejected by the approval handler");
+                return Err(Error::TransactionRejected);
+            }
+        }
+
+        let _queue_guard = self.wallet.transaction_queue.lock().await;
+        let essence_hash = prepared_transaction_data.essence.hash();
+        let secret_manager = self.wallet.secret_manager.read().await;
+        let mut signature_indexes = HashMap::new();
+        let mut unlocks = Vec::with_capacity(inputs.len());
+        for (index, input) in inputs.iter().enumerate() {
+            let unlock = match signature_indexes.get(&input.address) {
+                Some(signature_index) => Unlock::Reference(ReferenceUnlock::new(*signature_index)?),
+                None => {
+                    let chain = input.chain.ok_or(Error::MissingParameter("chain"))?;
+                    signature_indexes.insert(input.address, (first_index + index) as u16);
+                    secret_manager.signature_unlock(&essence_hash, chain).await?
+                }
+            };
+            unlocks.push(unlock);
+        }
+        Ok(unlocks)
+    }
+}
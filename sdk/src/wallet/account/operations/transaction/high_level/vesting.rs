@@ -0,0 +1,243 @@
+// Copyright 2024 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use std::collections::BTreeMap;
+
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    client::{api::PreparedTransactionData, secret::SecretManage},
+    types::block::{
+        address::Bech32Address,
+        output::{
+            feature::TagFeature,
+            unlock_condition::{AddressUnlockCondition, TimelockUnlockCondition},
+            BasicOutputBuilder, Output, OutputId,
+        },
+        payload::transaction::TransactionId,
+        ConvertTo,
+    },
+    wallet::{
+        account::{operations::transaction::Transaction, Account, TransactionOptions},
+        Error,
+    },
+};
+
+/// The tag feature of vesting outputs, with which the recipient finds them.
+pub const VESTING_TAG: &[u8] = b"vesting";
+
+/// The schedule after which funds are released by [`Account::create_vesting()`]: nothing until the cliff, then an
+/// equal part every period.
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct VestingSchedule {
+    /// Unix timestamp in seconds at which the vesting starts, the current time if not set.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub start: Option<u32>,
+    /// Seconds from the start until the first release.
+    pub cliff: u32,
+    /// Seconds between two releases.
+    pub period: u32,
+    /// The number of releases, including the one at the cliff.
+    pub releases: u32,
+}
+
+impl VestingSchedule {
+    /// Creates a schedule which starts now and releases funds `releases` times, first after `cliff` and then every
+    /// `period` seconds.
+    pub fn new(cliff: u32, period: u32, releases: u32) -> Self {
+        Self {
+            start: None,
+            cliff,
+            period,
+            releases,
+        }
+    }
+
+    /// Sets the unix timestamp in seconds at which the vesting starts.
+    pub fn with_start(mut self, start: u32) -> Self {
+        self.start = Some(start);
+        self
+    }
+
+    /// Returns the unlock times and amounts of the releases of `total_amount`, if the vesting starts at `start`. The
+    /// remainder of the division is added to the last release.
+    pub fn releases(&self, start: u32, total_amount: u64) -> crate::wallet::Result<Vec<(u32, u64)>> {
+        let invalid = |reason: &str| Error::InvalidVestingSchedule(reason.to_string());
+        if self.releases == 0 {
+            return Err(invalid("at least one release is required"));
+        }
+        if self.releases > 1 && self.period == 0 {
+            return Err(invalid("releases need a period between them"));
+        }
+        let amount = total_amount / self.releases as u64;
+        if amount == 0 {
+            return Err(invalid("the amount is too small for the number of releases"));
+        }
+
+        (0..self.releases)
+            .map(|release| {
+                let unlock_time = self
+                    .period
+                    .checked_mul(release)
+                    .and_then(|offset| start.checked_add(self.cliff)?.checked_add(offset))
+                    .ok_or_else(|| invalid("the last release is too far in the future"))?;
+                let amount = if release == self.releases - 1 {
+                    amount + total_amount % self.releases as u64
+                } else {
+                    amount
+                };
+                Ok((unlock_time, amount))
+            })
+            .collect()
+    }
+}
+
+/// A release of vested funds, see [`VestingStatus`].
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct VestingRelease {
+    /// The output holding the funds.
+    pub output_id: OutputId,
+    /// The released amount.
+    #[serde(with = "crate::utils::serde::string")]
+    pub amount: u64,
+    /// Unix timestamp in seconds at which the funds are released.
+    pub unlock_time: u32,
+}
+
+/// The unspent vesting outputs of the account which were created in the same transaction, see
+/// [`Account::vesting_status()`].
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct VestingStatus {
+    /// The transaction which created the vesting outputs.
+    pub transaction_id: TransactionId,
+    /// The releases, ordered by their unlock time.
+    pub releases: Vec<VestingRelease>,
+    /// The amount which is released, but not spent yet.
+    #[serde(with = "crate::utils::serde::string")]
+    pub released_amount: u64,
+    /// The amount which is still locked.
+    #[serde(with = "crate::utils::serde::string")]
+    pub locked_amount: u64,
+    /// Unix timestamp in seconds of the next release, if any funds are still locked.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub next_release: Option<u32>,
+}
+
+impl<S: 'static + SecretManage> Account<S>
+where
+    crate::wallet::Error: From<S::Error>,
+{
+    /// Sends `total_amount` to `address`, split into timelocked outputs which are released according to the
+    /// schedule. Every release has to cover the storage deposit of its output.
+    ///
+    /// The outputs are tagged with [`VESTING_TAG`], so the recipient can follow them with
+    /// [`Account::vesting_status()`].
+    /// ```ignore
+    /// // Release 12 Mi monthly over a year, starting after six months
+    /// let month = 30 * 24 * 60 * 60;
+    /// let schedule = VestingSchedule::new(6 * month, month, 12);
+    /// let transaction = account
+    ///     .create_vesting(
+    ///         "rms1qpszqzadsym6wpppd6z037dvlejmjuke7s24hm95s9fg9vpua7vluaw60xu",
+    ///         12_000_000,
+    ///         schedule,
+    ///         None,
+    ///     )
+    ///     .await?;
+    /// ```
+    pub async fn create_vesting(
+        &self,
+        address: impl ConvertTo<Bech32Address>,
+        total_amount: u64,
+        schedule: VestingSchedule,
+        options: impl Into<Option<TransactionOptions>> + Send,
+    ) -> crate::wallet::Result<Transaction> {
+        let options = options.into();
+        if let Some(transaction) = self.idempotent_transaction(options.as_ref()).await {
+            return Ok(transaction);
+        }
+        let prepared_transaction = self
+            .prepare_create_vesting(address, total_amount, schedule, options.clone())
+            .await?;
+
+        self.sign_and_submit_transaction(prepared_transaction, options).await
+    }
+
+    /// Prepares the transaction for [Account::create_vesting()](crate::wallet::Account::create_vesting).
+    pub async fn prepare_create_vesting(
+        &self,
+        address: impl ConvertTo<Bech32Address>,
+        total_amount: u64,
+        schedule: VestingSchedule,
+        options: impl Into<Option<TransactionOptions>> + Send,
+    ) -> crate::wallet::Result<PreparedTransactionData> {
+        log::debug!("[TRANSACTION] prepare_create_vesting");
+        let address = address.convert()?;
+        self.client().bech32_hrp_matches(address.hrp()).await?;
+        let token_supply = self.client().get_token_supply().await?;
+        let start = match schedule.start {
+            Some(start) => start,
+            None => self.client().get_time_checked().await?,
+        };
+
+        let outputs = schedule
+            .releases(start, total_amount)?
+            .into_iter()
+            .map(|(unlock_time, amount)| {
+                Ok(BasicOutputBuilder::new_with_amount(amount)
+                    .add_unlock_condition(AddressUnlockCondition::new(address))
+                    .add_unlock_condition(TimelockUnlockCondition::new(unlock_time)?)
+                    .add_feature(TagFeature::new(VESTING_TAG)?)
+                    .finish_output(token_supply)?)
+            })
+            .collect::<crate::wallet::Result<Vec<_>>>()?;
+
+        self.prepare_transaction(outputs, options).await
+    }
+
+    /// Returns the unspent vesting outputs of the account, grouped by the transaction which created them. Released
+    /// funds stay in the status until they're spent.
+    pub async fn vesting_status(&self) -> crate::wallet::Result<Vec<VestingStatus>> {
+        let local_time = self.client().get_time_checked().await?;
+        let account_details = self.details().await;
+
+        let mut vestings = BTreeMap::<TransactionId, Vec<VestingRelease>>::new();
+        for output_data in account_details.unspent_outputs().values() {
+            let Output::Basic(basic) = &output_data.output else {
+                continue;
+            };
+            let (Some(tag), Some(timelock)) = (basic.features().tag(), basic.unlock_conditions().timelock()) else {
+                continue;
+            };
+            if tag.tag() == VESTING_TAG {
+                vestings
+                    .entry(*output_data.output_id.transaction_id())
+                    .or_default()
+                    .push(VestingRelease {
+                        output_id: output_data.output_id,
+                        amount: basic.amount(),
+                        unlock_time: timelock.timestamp(),
+                    });
+            }
+        }
+
+        Ok(vestings
+            .into_iter()
+            .map(|(transaction_id, mut releases)| {
+                releases.sort_by_key(|release| (release.unlock_time, release.output_id));
+                let (released, locked): (Vec<_>, Vec<_>) =
+                    releases.iter().partition(|release| release.unlock_time <= local_time);
+                VestingStatus {
+                    transaction_id,
+                    released_amount: released.iter().map(|release| release.amount).sum(),
+                    locked_amount: locked.iter().map(|release| release.amount).sum(),
+                    next_release: locked.first().map(|release| release.unlock_time),
+                    releases,
+                }
+            })
+            .collect())
+    }
+}
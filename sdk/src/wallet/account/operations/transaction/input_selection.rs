@@ -1,7 +1,10 @@
 // Copyright 2021 IOTA Stiftung
 // SPDX-License-Identifier: Apache-2.0
 
-use std::collections::{hash_map::Values, HashSet};
+use std::{
+    collections::{hash_map::Values, BTreeSet, HashMap, HashSet},
+    sync::atomic::Ordering,
+};
 
 #[cfg(feature = "events")]
 use crate::wallet::events::types::{TransactionProgressEvent, WalletEvent};
@@ -11,11 +14,12 @@ use crate::{
         secret::{types::InputSigningData, SecretManage},
     },
     types::block::{
-        address::Address,
+        address::{Address, Bech32Address, ToBech32Ext},
         output::{Output, OutputId},
     },
     wallet::account::{
-        operations::helpers::time::can_output_be_unlocked_forever_from_now_on, Account, AccountDetails, OutputData,
+        operations::{helpers::time::can_output_be_unlocked_forever_from_now_on, transaction::CoinSelectionStrategy},
+        Account, AccountDetails, OutputData,
     },
 };
 
@@ -31,6 +35,7 @@ where
         mandatory_inputs: Option<HashSet<OutputId>>,
         remainder_address: Option<Address>,
         burn: Option<&Burn>,
+        coin_selection_strategy: CoinSelectionStrategy,
     ) -> crate::wallet::Result<Selected> {
         log::debug!("[TRANSACTION] select_inputs");
         // Voting output needs to be requested before to prevent a deadlock
@@ -48,8 +53,17 @@ where
         .await;
 
         let current_time = self.client().get_time_checked().await?;
-        #[allow(unused_mut)]
+        let output_maturity = self.wallet.output_maturity.load(Ordering::Relaxed);
         let mut forbidden_inputs = account_details.locked_outputs.clone();
+        // Quarantined spam outputs are only used if they're explicitly provided
+        forbidden_inputs.extend(account_details.quarantined_outputs.iter().filter(|output_id| {
+            !custom_inputs
+                .as_ref()
+                .is_some_and(|custom_inputs| custom_inputs.contains(output_id))
+                && !mandatory_inputs
+                    .as_ref()
+                    .is_some_and(|mandatory_inputs| mandatory_inputs.contains(output_id))
+        }));
 
         let addresses = account_details
             .public_addresses()
@@ -75,6 +89,7 @@ where
             &account_details,
             account_details.unspent_outputs.values(),
             current_time,
+            output_maturity,
             &outputs,
             burn,
             custom_inputs.as_ref(),
@@ -147,6 +162,13 @@ where
 
             let selected_transaction_data = input_selection.select()?;
 
+            if coin_selection_strategy == CoinSelectionStrategy::SingleAddressOnly {
+                let input_addresses = input_addresses(&account_details, &selected_transaction_data.inputs);
+                if input_addresses.len() > 1 {
+                    return Err(self.address_linkage_error(input_addresses).await?);
+                }
+            }
+
             // lock outputs so they don't get used by another transaction
             for output in &selected_transaction_data.inputs {
                 account_details.locked_outputs.insert(*output.output_id());
@@ -160,23 +182,58 @@ where
             return Ok(selected_transaction_data);
         }
 
-        let mut input_selection = InputSelection::new(
-            available_outputs_signing_data,
-            outputs,
-            addresses,
-            protocol_parameters.clone(),
-        )
-        .forbidden_inputs(forbidden_inputs);
+        let new_input_selection = |available_outputs_signing_data: Vec<InputSigningData>| {
+            let mut input_selection = InputSelection::new(
+                available_outputs_signing_data,
+                outputs.clone(),
+                addresses.clone(),
+                protocol_parameters.clone(),
+            )
+            .forbidden_inputs(forbidden_inputs.clone());
 
-        if let Some(address) = remainder_address {
-            input_selection = input_selection.remainder_address(address);
-        }
+            if let Some(address) = remainder_address {
+                input_selection = input_selection.remainder_address(address);
+            }
+
+            if let Some(burn) = burn {
+                input_selection = input_selection.burn(burn.clone());
+            }
+
+            input_selection
+        };
+
+        if coin_selection_strategy == CoinSelectionStrategy::SingleAddressOnly {
+            // Try the addresses with the most funds first, the first one which can cover the transaction on its own is
+            // used
+            let mut outputs_per_address = HashMap::<Address, (u64, Vec<InputSigningData>)>::new();
+            for input in &available_outputs_signing_data {
+                if let Some(output_data) = account_details.unspent_outputs.get(input.output_id()) {
+                    let (amount, inputs) = outputs_per_address.entry(output_data.address).or_default();
+                    *amount += input.output.amount();
+                    inputs.push(input.clone());
+                }
+            }
+            let mut outputs_per_address = outputs_per_address.into_values().collect::<Vec<_>>();
+            outputs_per_address.sort_by(|(a, _), (b, _)| b.cmp(a));
+
+            for (_, address_outputs_signing_data) in outputs_per_address {
+                if let Ok(selected_transaction_data) = new_input_selection(address_outputs_signing_data).select() {
+                    for output in &selected_transaction_data.inputs {
+                        log::debug!("[TRANSACTION] locking: {}", output.output_id());
+                        account_details.locked_outputs.insert(*output.output_id());
+                    }
+                    return Ok(selected_transaction_data);
+                }
+            }
 
-        if let Some(burn) = burn {
-            input_selection = input_selection.burn(burn.clone());
+            // No single address can cover the transaction, report the addresses that would get linked
+            let selected_transaction_data = new_input_selection(available_outputs_signing_data).select()?;
+            return Err(self
+                .address_linkage_error(input_addresses(&account_details, &selected_transaction_data.inputs))
+                .await?);
         }
 
-        let selected_transaction_data = match input_selection.select() {
+        let selected_transaction_data = match new_input_selection(available_outputs_signing_data).select() {
             Ok(r) => r,
             // TODO this error doesn't exist with the new ISA
             // Err(crate::client::Error::ConsolidationRequired(output_count)) => {
@@ -201,6 +258,26 @@ where
 
         Ok(selected_transaction_data)
     }
+
+    // Returns the error for a transaction that would spend inputs of all these addresses together.
+    async fn address_linkage_error(&self, addresses: BTreeSet<Address>) -> crate::wallet::Result<crate::wallet::Error> {
+        let bech32_hrp = self.client().get_bech32_hrp().await?;
+        Ok(crate::wallet::Error::AddressLinkage(
+            addresses
+                .into_iter()
+                .map(|address| address.to_bech32(bech32_hrp))
+                .collect::<Vec<Bech32Address>>(),
+        ))
+    }
+}
+
+// Returns the distinct addresses of the account which own the inputs.
+fn input_addresses(account: &AccountDetails, inputs: &[InputSigningData]) -> BTreeSet<Address> {
+    inputs
+        .iter()
+        .filter_map(|input| account.unspent_outputs.get(input.output_id()))
+        .map(|output_data| output_data.address)
+        .collect()
 }
 
 /// Filter available outputs to only include outputs that don't have unlock conditions, that could create
@@ -221,11 +298,14 @@ where
 /// | [Address, not expired Expiration, ...]              | no                |
 /// | [Address, StorageDepositReturn, ...]                | no                |
 /// | [Address, StorageDepositReturn, expired Expiration] | yes               |
+///
+/// Outputs booked less than `output_maturity` seconds ago are not included either.
 #[allow(clippy::too_many_arguments)]
 fn filter_inputs(
     account: &AccountDetails,
     available_outputs: Values<'_, OutputId, OutputData>,
     current_time: u32,
+    output_maturity: u32,
     outputs: &[Output],
     burn: Option<&Burn>,
     custom_inputs: Option<&HashSet<OutputId>>,
@@ -253,6 +333,11 @@ fn filter_inputs(
             if !output_can_be_unlocked_now_and_in_future {
                 continue;
             }
+
+            // Outputs that are not mature yet will not be included
+            if current_time < output_data.metadata.milestone_timestamp_booked().saturating_add(output_maturity) {
+                continue;
+            }
         }
 
         // Defaults to state transition if it is not explicitly a governance transition or a burn.
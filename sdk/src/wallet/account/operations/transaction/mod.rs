@@ -7,10 +7,15 @@ mod input_selection;
 mod options;
 pub(crate) mod prepare_output;
 mod prepare_transaction;
+mod rebuild_conflicting_transaction;
+mod service_fee;
 mod sign_transaction;
 pub(crate) mod submit_transaction;
 
-pub use self::options::{RemainderValueStrategy, TransactionOptions, TransactionOptionsDto};
+pub use self::{
+    options::{CoinSelectionStrategy, RemainderValueStrategy, TransactionOptions, TransactionOptionsDto},
+    service_fee::{ServiceFee, ServiceFeeAmount},
+};
 use crate::{
     client::{
         api::{verify_semantic, PreparedTransactionData, SignedTransactionData},
@@ -21,13 +26,13 @@ use crate::{
         api::core::response::OutputWithMetadataResponse,
         block::{
             output::{dto::OutputDto, Output},
-            payload::transaction::TransactionPayload,
+            payload::transaction::{TransactionEssence, TransactionPayload},
             semantic::ConflictReason,
         },
     },
     wallet::account::{
         types::{InclusionState, Transaction},
-        Account,
+        Account, AccountInner,
     },
 };
 
@@ -92,19 +97,31 @@ where
         log::debug!("[TRANSACTION] finish_transaction");
         let options = options.into();
 
+        if let Some(transaction) = self.idempotent_transaction(options.as_ref()).await {
+            return Ok(transaction);
+        }
+
         let prepared_transaction_data = self.prepare_transaction(outputs, options.clone()).await?;
 
         self.sign_and_submit_transaction(prepared_transaction_data, options)
             .await
     }
 
-    /// Signs a transaction, submit it to a node and store it in the account
+    /// Signs a transaction, submit it to a node and store it in the account. If a transaction was already sent with the
+    /// idempotency key of the options, it's returned instead without signing.
     pub async fn sign_and_submit_transaction(
         &self,
         prepared_transaction_data: PreparedTransactionData,
         options: impl Into<Option<TransactionOptions>> + Send,
     ) -> crate::wallet::Result<Transaction> {
         log::debug!("[TRANSACTION] sign_and_submit_transaction");
+        let options = options.into();
+
+        if let Some(transaction) = self.idempotent_transaction(options.as_ref()).await {
+            // unlock outputs so they are available for a new transaction
+            self.unlock_inputs(&prepared_transaction_data.inputs_data).await?;
+            return Ok(transaction);
+        }
 
         let signed_transaction_data = match self.sign_transaction_essence(&prepared_transaction_data).await {
             Ok(res) => res,
@@ -115,15 +132,29 @@ where
             }
         };
 
-        self.submit_and_store_transaction(signed_transaction_data, options)
+        let remainder = prepared_transaction_data.remainder.map(|remainder| remainder.output);
+        self.submit_and_store_transaction_with_remainder(signed_transaction_data, options, remainder.as_ref())
             .await
     }
 
-    /// Validates the transaction, submit it to a node and store it in the account
+    /// Validates the transaction, submit it to a node and store it in the account. If a transaction was already sent
+    /// with the idempotency key of the options, it's returned instead and the inputs are unlocked again.
     pub async fn submit_and_store_transaction(
         &self,
         signed_transaction_data: SignedTransactionData,
         options: impl Into<Option<TransactionOptions>> + Send,
+    ) -> crate::wallet::Result<Transaction> {
+        self.submit_and_store_transaction_with_remainder(signed_transaction_data, options, None)
+            .await
+    }
+
+    // Submits and stores the transaction together with the index of its remainder output, if it has one, so only the
+    // remainder is left out when the transaction is rebuilt
+    async fn submit_and_store_transaction_with_remainder(
+        &self,
+        signed_transaction_data: SignedTransactionData,
+        options: impl Into<Option<TransactionOptions>> + Send,
+        remainder: Option<&Output>,
     ) -> crate::wallet::Result<Transaction> {
         log::debug!(
             "[TRANSACTION] submit_and_store_transaction {}",
@@ -131,6 +162,16 @@ where
         );
         let options = options.into();
 
+        // Reserve the idempotency key, so a transaction with the same key can't be submitted at the same time
+        let _idempotency_key_reservation = match options.as_ref().and_then(|options| options.idempotency_key.as_ref()) {
+            Some(idempotency_key) => Some(self.reserve_idempotency_key(idempotency_key).await),
+            None => None,
+        };
+        if let Some(transaction) = self.idempotent_transaction(options.as_ref()).await {
+            self.unlock_inputs(&signed_transaction_data.inputs_data).await?;
+            return Ok(transaction);
+        }
+
         // Validate transaction before sending and storing it
         let local_time = self.client().get_time_checked().await?;
 
@@ -163,6 +204,15 @@ where
         };
 
         let transaction_id = signed_transaction_data.transaction_payload.id();
+        // The remainder is added after the outputs of the transaction
+        let remainder_index = remainder.and_then(|remainder| {
+            let TransactionEssence::Regular(essence) = signed_transaction_data.transaction_payload.essence();
+            essence
+                .outputs()
+                .iter()
+                .rposition(|output| output == remainder)
+                .map(|index| index as u16)
+        });
 
         // store transaction payload to account (with db feature also store the account to the db)
         let network_id = self.client().get_network_id().await?;
@@ -176,6 +226,7 @@ where
             })
             .collect();
 
+        let (note, idempotency_key) = options.map_or((None, None), |o| (o.note, o.idempotency_key));
         let transaction = Transaction {
             transaction_id,
             payload: signed_transaction_data.transaction_payload,
@@ -184,12 +235,20 @@ where
             timestamp: crate::utils::unix_timestamp_now().as_millis(),
             inclusion_state: InclusionState::Pending,
             incoming: false,
-            note: options.and_then(|o| o.note),
+            note,
             inputs,
         };
 
         let mut account_details = self.details_mut().await;
 
+        if let Some(idempotency_key) = idempotency_key {
+            account_details.idempotency_keys.insert(idempotency_key, transaction_id);
+        }
+        if let Some(remainder_index) = remainder_index {
+            account_details
+                .remainder_indexes
+                .insert(transaction_id, remainder_index);
+        }
         account_details.transactions.insert(transaction_id, transaction.clone());
         account_details.pending_transactions.insert(transaction_id);
         #[cfg(feature = "storage")]
@@ -201,6 +260,43 @@ where
         Ok(transaction)
     }
 
+    // Waits until no other transaction with the idempotency key is submitted and reserves the key until the returned
+    // reservation is dropped
+    async fn reserve_idempotency_key(&self, idempotency_key: &str) -> IdempotencyKeyReservation<'_> {
+        loop {
+            // Created before checking the key, so a release in between isn't missed
+            let released = self.inner.idempotency_key_released.notified();
+            if self
+                .inner
+                .pending_idempotency_keys
+                .lock()
+                .unwrap_or_else(|e| e.into_inner())
+                .insert(idempotency_key.to_owned())
+            {
+                return IdempotencyKeyReservation {
+                    account: &self.inner,
+                    idempotency_key: idempotency_key.to_owned(),
+                };
+            }
+            released.await;
+        }
+    }
+
+    /// Returns the transaction which was already sent with the idempotency key of the options.
+    pub(crate) async fn idempotent_transaction(&self, options: Option<&TransactionOptions>) -> Option<Transaction> {
+        let idempotency_key = options?.idempotency_key.as_ref()?;
+        let transaction = self
+            .details()
+            .await
+            .transaction_by_idempotency_key(idempotency_key)
+            .cloned()?;
+        log::debug!(
+            "[TRANSACTION] transaction {} was already sent with idempotency key {idempotency_key}",
+            transaction.transaction_id
+        );
+        Some(transaction)
+    }
+
     // unlock outputs
     async fn unlock_inputs(&self, inputs: &[InputSigningData]) -> crate::wallet::Result<()> {
         let mut account_details = self.details_mut().await;
@@ -215,3 +311,20 @@ where
         Ok(())
     }
 }
+
+// An idempotency key reserved by a transaction which is submitted, released when dropped
+struct IdempotencyKeyReservation<'a> {
+    account: &'a AccountInner,
+    idempotency_key: String,
+}
+
+impl Drop for IdempotencyKeyReservation<'_> {
+    fn drop(&mut self) {
+        self.account
+            .pending_idempotency_keys
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .remove(&self.idempotency_key);
+        self.account.idempotency_key_released.notify_waiters();
+    }
+}
@@ -25,6 +25,12 @@ pub struct TransactionOptions {
     pub burn: Option<Burn>,
     pub note: Option<String>,
     pub allow_micro_amount: bool,
+    pub coin_selection_strategy: CoinSelectionStrategy,
+    /// A key to send the transaction only once: if a transaction was already sent with the same key, it's returned
+    /// instead of sending a new one. The keys are stored with the account, so they also work across restarts. A key
+    /// is removed when syncing seven days after its transaction was sent, if the transaction is confirmed or
+    /// conflicting by then, and can be used for a new transaction afterwards.
+    pub idempotency_key: Option<String>,
 }
 
 impl TransactionOptions {
@@ -38,6 +44,8 @@ impl TransactionOptions {
             burn: value.burn.map(Burn::try_from).transpose()?,
             note: value.note,
             allow_micro_amount: value.allow_micro_amount,
+            coin_selection_strategy: value.coin_selection_strategy,
+            idempotency_key: value.idempotency_key,
         })
     }
 }
@@ -60,6 +68,10 @@ pub struct TransactionOptionsDto {
     pub note: Option<String>,
     #[serde(default)]
     pub allow_micro_amount: bool,
+    #[serde(default)]
+    pub coin_selection_strategy: CoinSelectionStrategy,
+    #[serde(default)]
+    pub idempotency_key: Option<String>,
 }
 
 #[allow(clippy::enum_variant_names)]
@@ -80,3 +92,14 @@ impl Default for RemainderValueStrategy {
         Self::ReuseAddress
     }
 }
+
+/// The strategy to use for the automatic selection of inputs.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub enum CoinSelectionStrategy {
+    /// Select inputs of any addresses of the account.
+    #[default]
+    AnyAddress,
+    /// Only select inputs of a single address, so a transaction doesn't link multiple addresses of the account.
+    /// Explicitly provided custom inputs are allowed to belong to distinct addresses.
+    SingleAddressOnly,
+}
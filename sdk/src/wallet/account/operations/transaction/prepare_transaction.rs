@@ -4,10 +4,13 @@
 use std::collections::HashSet;
 
 use instant::Instant;
-use packable::bounded::TryIntoBoundedU16Error;
+use packable::{bounded::TryIntoBoundedU16Error, PackableExt};
 
 #[cfg(feature = "events")]
-use crate::wallet::events::types::{AddressData, TransactionProgressEvent, WalletEvent};
+use crate::{
+    types::block::output::dto::OutputDto,
+    wallet::events::types::{AddressData, TransactionProgressEvent, WalletEvent},
+};
 use crate::{
     client::{api::PreparedTransactionData, secret::SecretManage},
     types::block::{
@@ -31,8 +34,18 @@ where
         options: impl Into<Option<TransactionOptions>> + Send,
     ) -> crate::wallet::Result<PreparedTransactionData> {
         log::debug!("[TRANSACTION] prepare_transaction");
+        // Wait for previously queued operations, so their selected inputs are already locked
+        let _queue_guard = self.wallet.transaction_queue.lock().await;
         let options = options.into();
-        let outputs = outputs.into();
+        if let Some(idempotency_key) = options.as_ref().and_then(|options| options.idempotency_key.as_ref()) {
+            if let Some(transaction) = self.details().await.transaction_by_idempotency_key(idempotency_key) {
+                return Err(crate::wallet::Error::IdempotencyKeyUsed {
+                    key: idempotency_key.clone(),
+                    transaction_id: transaction.transaction_id,
+                });
+            }
+        }
+        let mut outputs = outputs.into();
         let prepare_transaction_start_time = Instant::now();
         let rent_structure = self.client().get_rent_structure().await?;
         let token_supply = self.client().get_token_supply().await?;
@@ -42,6 +55,22 @@ where
             output.verify_storage_deposit(rent_structure, token_supply)?;
         }
 
+        if let Some(service_fee_output) = self.service_fee_output(&outputs).await? {
+            log::debug!("[TRANSACTION] adding service fee output {service_fee_output:?}");
+            #[cfg(feature = "events")]
+            {
+                let account_index = self.details().await.index;
+                self.emit(
+                    account_index,
+                    WalletEvent::TransactionProgress(TransactionProgressEvent::AddingServiceFee(Box::new(
+                        OutputDto::from(&service_fee_output),
+                    ))),
+                )
+                .await;
+            }
+            outputs.push(service_fee_output);
+        }
+
         let is_burn_present = options.as_ref().map(|options| options.burn.is_some()).unwrap_or(false);
 
         // Validate the number of outputs. The validation shouldn't be performed if [`Burn`] is present.
@@ -75,7 +104,7 @@ where
                 match &options.remainder_value_strategy {
                     RemainderValueStrategy::ReuseAddress => {
                         // select_inputs will select an address from the inputs if it's none
-                        None
+                        self.details().await.identity_alias_address()?
                     }
                     RemainderValueStrategy::ChangeAddress => {
                         let remainder_address = self.generate_remainder_address().await?;
@@ -97,9 +126,12 @@ where
                     RemainderValueStrategy::CustomAddress(address) => Some(address.address().inner),
                 }
             }
-            None => None,
+            None => self.details().await.identity_alias_address()?,
         };
 
+        // Needed to suggest which outputs to move into another transaction if the transaction gets too large
+        let output_lengths = outputs.iter().map(|output| output.packed_len()).collect::<Vec<_>>();
+
         let selected_transaction_data = self
             .select_inputs(
                 outputs,
@@ -113,6 +145,10 @@ where
                     .map(|inputs| HashSet::from_iter(inputs.clone())),
                 remainder_address,
                 options.as_ref().and_then(|options| options.burn.as_ref()),
+                options
+                    .as_ref()
+                    .map(|options| options.coin_selection_strategy)
+                    .unwrap_or_default(),
             )
             .await?;
 
@@ -124,6 +160,15 @@ where
             Err(err) => {
                 // unlock outputs so they are available for a new transaction
                 self.unlock_inputs(&selected_transaction_data.inputs).await?;
+                if let crate::wallet::Error::Client(error) = &err {
+                    if let crate::client::Error::InvalidTransactionPayloadLength { length, max_length } = **error {
+                        return Err(crate::wallet::Error::TransactionTooLarge {
+                            length,
+                            max_length,
+                            outputs_to_move: outputs_to_move(&output_lengths, length - max_length),
+                        });
+                    }
+                }
                 return Err(err);
             }
         };
@@ -135,3 +180,17 @@ where
         Ok(prepared_transaction_data)
     }
 }
+
+// Returns the indexes of the last outputs which together are at least `excess_length` bytes long, so sending them in
+// another transaction makes the transaction small enough. The first output is always kept, if that isn't enough no
+// outputs are returned.
+fn outputs_to_move(output_lengths: &[usize], excess_length: usize) -> Vec<usize> {
+    let mut moved_length = 0;
+    for index in (1..output_lengths.len()).rev() {
+        moved_length += output_lengths[index];
+        if moved_length >= excess_length {
+            return (index..output_lengths.len()).collect();
+        }
+    }
+    Vec::new()
+}
@@ -0,0 +1,98 @@
+// Copyright 2024 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::{
+    client::{api::PreparedTransactionData, secret::SecretManage},
+    types::block::{
+        input::Input,
+        output::OutputId,
+        payload::{
+            transaction::{TransactionEssence, TransactionId},
+            Payload,
+        },
+    },
+    wallet::{
+        account::{operations::transaction::TransactionOptions, types::InclusionState, Account},
+        Error,
+    },
+};
+
+impl<S: 'static + SecretManage> Account<S>
+where
+    crate::wallet::Error: From<S::Error>,
+{
+    /// Rebuilds a transaction that was rejected because some of its inputs got spent by another transaction.
+    /// The inputs that are still unspent are reused, replacements for the spent ones are selected and the outputs of
+    /// the original transaction are kept, except for the remainder which is created again if needed. The remainder is
+    /// only known if the transaction was signed by the account, not if it was only submitted with
+    /// [`Account::submit_and_store_transaction()`].
+    /// The account should be synced before, so the transaction is marked as conflicting and its inputs are unlocked.
+    pub async fn rebuild_conflicting_transaction(
+        &self,
+        transaction_id: &TransactionId,
+    ) -> crate::wallet::Result<PreparedTransactionData> {
+        log::debug!("[TRANSACTION] rebuild_conflicting_transaction {transaction_id}");
+        let (transaction, remainder_index) = {
+            let account_details = self.details().await;
+            let transaction = account_details
+                .transactions()
+                .get(transaction_id)
+                .cloned()
+                .ok_or(Error::TransactionNotFound(*transaction_id))?;
+            let remainder_index = account_details.remainder_indexes().get(transaction_id).copied();
+            (transaction, remainder_index)
+        };
+
+        if transaction.inclusion_state != InclusionState::Conflicting {
+            return Err(Error::TransactionNotConflicting(*transaction_id));
+        }
+
+        let TransactionEssence::Regular(essence) = transaction.payload.essence();
+        let input_ids = essence
+            .inputs()
+            .iter()
+            .filter_map(|input| match input {
+                Input::Utxo(input) => Some(*input.output_id()),
+                Input::Treasury(_) => None,
+            })
+            .collect::<Vec<_>>();
+
+        // Inputs that the node doesn't know anymore got spent and pruned
+        let unspent_input_ids = self
+            .client()
+            .get_outputs_metadata_ignore_errors(&input_ids)
+            .await?
+            .into_iter()
+            .filter(|metadata| !metadata.is_spent())
+            .map(|metadata| *metadata.output_id())
+            .collect::<Vec<OutputId>>();
+
+        for input_id in input_ids.iter().filter(|id| !unspent_input_ids.contains(id)) {
+            log::debug!("[TRANSACTION] input {input_id} of {transaction_id} got spent in another transaction");
+        }
+
+        // The remainder is selected again, so it's left out, other outputs to the account were sent on purpose
+        let mut outputs = essence
+            .outputs()
+            .iter()
+            .enumerate()
+            .filter(|(index, _)| remainder_index != Some(*index as u16))
+            .map(|(_, output)| output.clone())
+            .collect::<Vec<_>>();
+        if outputs.is_empty() {
+            outputs = essence.outputs().to_vec();
+        }
+
+        let options = TransactionOptions {
+            tagged_data_payload: match essence.payload() {
+                Some(Payload::TaggedData(tagged_data_payload)) => Some(*tagged_data_payload.clone()),
+                _ => None,
+            },
+            mandatory_inputs: (!unspent_input_ids.is_empty()).then_some(unspent_input_ids),
+            note: transaction.note,
+            ..Default::default()
+        };
+
+        self.prepare_transaction(outputs, options).await
+    }
+}
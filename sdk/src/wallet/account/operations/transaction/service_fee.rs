@@ -0,0 +1,144 @@
+// Copyright 2024 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use std::collections::HashSet;
+
+use primitive_types::U256;
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    client::secret::SecretManage,
+    types::block::{
+        address::{Address, Bech32Address},
+        output::{unlock_condition::AddressUnlockCondition, BasicOutputBuilder, NativeToken, Output, TokenId},
+    },
+    wallet::account::Account,
+};
+
+/// A fee paid with every transaction of a wallet, e.g. to a wallet-as-a-service provider. It's added as an
+/// additional output to the fee address when a transaction is prepared.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ServiceFee {
+    /// The address receiving the fee.
+    pub address: Bech32Address,
+    /// The amount of the fee.
+    pub amount: ServiceFeeAmount,
+    /// The native token in which the fee is paid, base coins if `None`.
+    pub token_id: Option<TokenId>,
+}
+
+/// The amount of a [`ServiceFee`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "type", content = "value", rename_all = "camelCase")]
+pub enum ServiceFeeAmount {
+    /// A fixed amount per transaction.
+    Fixed(U256),
+    /// A share of the amount sent with the transaction, in basis points: 100 are 1%.
+    BasisPoints(u16),
+}
+
+impl ServiceFee {
+    /// Creates a new [`ServiceFee`] paid in base coins.
+    pub fn new(address: Bech32Address, amount: ServiceFeeAmount) -> Self {
+        Self {
+            address,
+            amount,
+            token_id: None,
+        }
+    }
+
+    /// Sets the native token in which the fee is paid.
+    pub fn with_token_id(mut self, token_id: impl Into<Option<TokenId>>) -> Self {
+        self.token_id = token_id.into();
+        self
+    }
+}
+
+impl<S: 'static + SecretManage> Account<S>
+where
+    crate::wallet::Error: From<S::Error>,
+{
+    // Returns the output paying the service fee of the wallet for a transaction with these outputs. Only outputs to
+    // addresses outside of the account are charged, so no fee is due if nothing leaves the account.
+    pub(crate) async fn service_fee_output(&self, outputs: &[Output]) -> crate::wallet::Result<Option<Output>> {
+        let Some(service_fee) = &self.wallet.service_fee else {
+            return Ok(None);
+        };
+
+        let mut account_addresses = self
+            .addresses()
+            .await?
+            .into_iter()
+            .map(|address| *address.address().inner())
+            .collect::<HashSet<Address>>();
+        account_addresses.extend(self.details().await.identity_alias_address()?);
+
+        let mut sent_amount = U256::zero();
+        let mut sends_outputs = false;
+        for output in outputs {
+            let Some(unlock_conditions) = output.unlock_conditions() else {
+                continue;
+            };
+            match unlock_conditions.address() {
+                Some(address) if !account_addresses.contains(address.address()) => sends_outputs = true,
+                _ => continue,
+            }
+            match service_fee.token_id {
+                Some(token_id) => {
+                    if let Some(native_token) = output.native_tokens().and_then(|tokens| tokens.get(&token_id)) {
+                        sent_amount = sent_amount.saturating_add(native_token.amount());
+                    }
+                }
+                None => {
+                    // A storage deposit returned to the account isn't sent
+                    let returned_amount = unlock_conditions
+                        .storage_deposit_return()
+                        .filter(|sdr| account_addresses.contains(sdr.return_address()))
+                        .map_or(0, |sdr| sdr.amount());
+                    sent_amount = sent_amount.saturating_add(U256::from(output.amount() - returned_amount));
+                }
+            }
+        }
+        if !sends_outputs {
+            return Ok(None);
+        }
+
+        let fee = match service_fee.amount {
+            ServiceFeeAmount::Fixed(amount) => amount,
+            ServiceFeeAmount::BasisPoints(basis_points) => {
+                sent_amount.saturating_mul(U256::from(basis_points)) / U256::from(10_000)
+            }
+        };
+        if fee.is_zero() {
+            return Ok(None);
+        }
+
+        let rent_structure = self.client().get_rent_structure().await?;
+        let token_supply = self.client().get_token_supply().await?;
+        let builder = BasicOutputBuilder::new_with_minimum_storage_deposit(rent_structure)
+            .add_unlock_condition(AddressUnlockCondition::new(service_fee.address));
+
+        let output = match service_fee.token_id {
+            Some(token_id) => builder
+                .add_native_token(NativeToken::new(token_id, fee)?)
+                .finish_output(token_supply)?,
+            None => {
+                let fee = u64::try_from(fee).map_err(|_| {
+                    crate::wallet::Error::CustomInput(format!("service fee {fee} exceeds the token supply"))
+                })?;
+                let output = builder.finish_output(token_supply)?;
+                // Fees below the minimum storage deposit are increased to it
+                if fee > output.amount() {
+                    BasicOutputBuilder::from(output.as_basic())
+                        .with_amount(fee)
+                        .finish_output(token_supply)?
+                } else {
+                    output
+                }
+            }
+        };
+
+        Ok(Some(output))
+    }
+}
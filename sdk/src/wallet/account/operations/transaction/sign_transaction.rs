@@ -31,6 +31,22 @@ where
     ) -> crate::wallet::Result<SignedTransactionData> {
         log::debug!("[TRANSACTION] sign_transaction_essence");
         log::debug!("[TRANSACTION] prepared_transaction_data {prepared_transaction_data:?}");
+
+        // Ask for approval before queueing, as it may take a while
+        let transaction_approver = self.wallet.transaction_approver.read().await.clone();
+        if let Some(transaction_approver) = transaction_approver {
+            if !transaction_approver
+                .approve_transaction(self.details().await.index, prepared_transaction_data)
+                .await
+            {
+                log::debug!("[TRANSACTION] transaction rejected by the approval handler");
+                // unlock outputs so they are available for a new transaction
+                self.unlock_inputs(&prepared_transaction_data.inputs_data).await?;
+                return Err(crate::wallet::Error::TransactionRejected);
+            }
+        }
+
+        let _queue_guard = self.wallet.transaction_queue.lock().await;
         #[cfg(feature = "events")]
         self.emit(
             self.details().await.index,
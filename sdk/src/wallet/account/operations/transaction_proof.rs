@@ -0,0 +1,66 @@
+// Copyright 2024 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::{
+    client::{secret::SecretManage, utils::TransactionProof},
+    types::{
+        api::core::response::OutputWithMetadataResponse,
+        block::{
+            input::Input,
+            payload::transaction::{dto::TransactionPayloadDto, TransactionEssence, TransactionId},
+        },
+    },
+    wallet::{account::Account, Error},
+};
+
+impl<S: 'static + SecretManage> Account<S>
+where
+    Error: From<S::Error>,
+{
+    /// Exports a [`TransactionProof`] of a confirmed transaction of the account, which third parties can check with
+    /// [`verify_transaction_proof()`](crate::client::utils::verify_transaction_proof) without access to the wallet.
+    /// The consumed outputs are requested from the node, so this fails once the node pruned them.
+    pub async fn export_transaction_proof(
+        &self,
+        transaction_id: &TransactionId,
+    ) -> crate::wallet::Result<TransactionProof> {
+        let transaction = match self.get_transaction(transaction_id).await {
+            Some(transaction) => transaction,
+            None => self
+                .get_incoming_transaction(transaction_id)
+                .await
+                .ok_or(Error::TransactionNotFound(*transaction_id))?,
+        };
+        let TransactionEssence::Regular(essence) = transaction.payload.essence();
+        let input_ids = essence
+            .inputs()
+            .iter()
+            .filter_map(|input| match input {
+                Input::Utxo(input) => Some(*input.output_id()),
+                Input::Treasury(_) => None,
+            })
+            .collect::<Vec<_>>();
+
+        let inputs = self.client().get_outputs(&input_ids).await?;
+        let (milestone_index, milestone_timestamp) = inputs
+            .first()
+            .and_then(|input| {
+                let metadata = input.metadata();
+                if metadata.transaction_id_spent() == Some(transaction_id) {
+                    metadata.milestone_index_spent().zip(metadata.milestone_timestamp_spent())
+                } else {
+                    None
+                }
+            })
+            .ok_or(Error::TransactionNotConfirmed(*transaction_id))?;
+        let block_metadata = self.client().get_included_block_metadata(transaction_id).await?;
+
+        Ok(TransactionProof {
+            transaction: TransactionPayloadDto::from(&transaction.payload),
+            inputs: inputs.iter().map(OutputWithMetadataResponse::from).collect(),
+            block_id: block_metadata.block_id,
+            milestone_index,
+            milestone_timestamp,
+        })
+    }
+}
@@ -7,7 +7,9 @@ use getset::{CopyGetters, Getters};
 use primitive_types::U256;
 use serde::{Deserialize, Serialize};
 
-use crate::types::block::output::{feature::MetadataFeature, AliasId, FoundryId, NftId, OutputId, TokenId};
+use crate::types::block::output::{
+    feature::MetadataFeature, AliasId, FoundryId, NftId, OutputId, TokenAmount, TokenId,
+};
 
 /// The balance of an account, returned from [`crate::wallet::account::Account::sync()`] and
 /// [`crate::wallet::account::Account::balance()`].
@@ -32,12 +34,16 @@ pub struct Balance {
     /// [`ExpirationUnlockCondition`](crate::types::block::output::unlock_condition::ExpirationUnlockCondition) this
     /// can change at any time
     pub(crate) potentially_locked_outputs: HashMap<OutputId, bool>,
+    /// Base coin amounts split by why they can or can't be spent right now
+    #[serde(default)]
+    pub(crate) breakdown: BalanceBreakdown,
 }
 
 impl std::ops::AddAssign for Balance {
     fn add_assign(&mut self, rhs: Self) {
         self.base_coin += rhs.base_coin;
         self.required_storage_deposit += rhs.required_storage_deposit;
+        self.breakdown += rhs.breakdown;
 
         for rhs_native_token_balance in rhs.native_tokens.into_iter() {
             if let Some(total_native_token_balance) = self
@@ -85,6 +91,51 @@ impl std::ops::AddAssign for BaseCoinBalance {
     }
 }
 
+/// Base coin amounts of a [`Balance`] split by their spendability. Only `spendable` can be used by new transactions
+/// right now, the other amounts explain why the rest of the funds can't.
+#[derive(Clone, Debug, Default, Eq, PartialEq, Serialize, Deserialize, CopyGetters)]
+#[serde(rename_all = "camelCase")]
+#[getset(get_copy = "pub")]
+pub struct BalanceBreakdown {
+    /// Amount which can be spent right now, equal to [`BaseCoinBalance::available()`]
+    #[serde(with = "crate::utils::serde::string")]
+    pub(crate) spendable: u64,
+    /// Amount of outputs owned by the account, which are still timelocked
+    #[serde(with = "crate::utils::serde::string")]
+    pub(crate) timelocked: u64,
+    /// Unix timestamp in seconds at which the next timelock expires
+    pub(crate) next_unlock: Option<u32>,
+    /// Amount of unexpired outputs sent by the account which the recipient didn't claim yet, they return to the
+    /// account when they expire
+    #[serde(with = "crate::utils::serde::string")]
+    pub(crate) expiring_to_us: u64,
+    /// Amount of outputs received with an expiration, which have to be claimed before they expire and return to the
+    /// sender
+    #[serde(with = "crate::utils::serde::string")]
+    pub(crate) expiring_away: u64,
+    /// Amount locked as storage deposit of outputs with native tokens, NFTs, aliases and foundries
+    #[serde(with = "crate::utils::serde::string")]
+    pub(crate) storage_deposit_locked: u64,
+    /// Amount of outputs used as inputs by pending transactions
+    #[serde(with = "crate::utils::serde::string")]
+    pub(crate) pending_reserved: u64,
+}
+
+impl std::ops::AddAssign for BalanceBreakdown {
+    fn add_assign(&mut self, rhs: Self) {
+        self.spendable += rhs.spendable;
+        self.timelocked += rhs.timelocked;
+        self.next_unlock = match (self.next_unlock, rhs.next_unlock) {
+            (Some(lhs), Some(rhs)) => Some(lhs.min(rhs)),
+            (lhs, rhs) => lhs.or(rhs),
+        };
+        self.expiring_to_us += rhs.expiring_to_us;
+        self.expiring_away += rhs.expiring_away;
+        self.storage_deposit_locked += rhs.storage_deposit_locked;
+        self.pending_reserved += rhs.pending_reserved;
+    }
+}
+
 #[derive(Clone, Debug, Default, Eq, PartialEq, Serialize, Deserialize, CopyGetters)]
 #[getset(get_copy = "pub")]
 pub struct RequiredStorageDeposit {
@@ -126,6 +177,18 @@ pub struct NativeTokensBalance {
     pub(crate) metadata: Option<MetadataFeature>,
 }
 
+impl NativeTokensBalance {
+    /// Returns the total amount with its token id.
+    pub fn total_amount(&self) -> TokenAmount {
+        TokenAmount::new(self.token_id, self.total)
+    }
+
+    /// Returns the available amount with its token id.
+    pub fn available_amount(&self) -> TokenAmount {
+        TokenAmount::new(self.token_id, self.available)
+    }
+}
+
 impl Default for NativeTokensBalance {
     fn default() -> Self {
         Self {
@@ -139,8 +202,9 @@ impl Default for NativeTokensBalance {
 
 impl std::ops::AddAssign for NativeTokensBalance {
     fn add_assign(&mut self, rhs: Self) {
-        self.total += rhs.total;
-        self.available += rhs.available;
+        debug_assert_eq!(self.token_id, rhs.token_id, "added balances of different native tokens");
+        self.total = self.total.saturating_add(rhs.total);
+        self.available = self.available.saturating_add(rhs.available);
         if self.metadata.is_none() {
             self.metadata = rhs.metadata;
         }
@@ -4,17 +4,21 @@
 /// Address types used in the account
 pub(crate) mod address;
 pub(crate) mod balance;
+pub(crate) mod output_filter;
 #[cfg(feature = "participation")]
 pub mod participation;
+pub(crate) mod unlockability;
 
-use std::str::FromStr;
+use std::{collections::BTreeMap, str::FromStr};
 
 use crypto::keys::bip44::Bip44;
 use serde::{Deserialize, Deserializer, Serialize};
 
 pub use self::{
     address::{AccountAddress, AddressWithUnspentOutputs},
-    balance::{Balance, BaseCoinBalance, NativeTokensBalance, RequiredStorageDeposit},
+    balance::{Balance, BalanceBreakdown, BaseCoinBalance, NativeTokensBalance, RequiredStorageDeposit},
+    output_filter::OutputFilter,
+    unlockability::Unlockability,
 };
 use crate::{
     client::secret::types::InputSigningData,
@@ -49,6 +53,8 @@ pub struct OutputData {
     pub remainder: bool,
     // bip44 path
     pub chain: Option<Bip44>,
+    /// Local annotations set by the user, they aren't part of the output on the ledger.
+    pub annotations: BTreeMap<String, String>,
 }
 
 impl OutputData {
@@ -113,6 +119,9 @@ pub struct OutputDataDto {
     /// Bip32 path
     #[serde(with = "option_bip44", default)]
     pub chain: Option<Bip44>,
+    /// Local annotations
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub annotations: BTreeMap<String, String>,
 }
 
 impl From<&OutputData> for OutputDataDto {
@@ -126,6 +135,7 @@ impl From<&OutputData> for OutputDataDto {
             network_id: value.network_id.to_string(),
             remainder: value.remainder,
             chain: value.chain,
+            annotations: value.annotations.clone(),
         }
     }
 }
@@ -150,6 +160,7 @@ impl TryFromDto for OutputData {
                 .map_err(|_| BlockError::InvalidField("network id"))?,
             remainder: dto.remainder,
             chain: dto.chain,
+            annotations: dto.annotations,
         })
     }
 }
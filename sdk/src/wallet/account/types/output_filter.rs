@@ -0,0 +1,243 @@
+// Copyright 2024 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use std::collections::HashSet;
+
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    types::block::{
+        address::Address,
+        output::{AliasId, FoundryId, NftId, Output, TokenId},
+    },
+    wallet::account::{
+        operations::helpers::time::can_output_be_unlocked_now, types::OutputData, AccountDetails, FilterOptions,
+    },
+};
+
+/// A composable filter to query the outputs of an account.
+///
+/// ```ignore
+/// // Unspent basic outputs with at least 1 Mi that hold a certain native token
+/// let filter = OutputFilter::Unspent
+///     .and(OutputFilter::OutputTypes(vec![BasicOutput::KIND]))
+///     .and(OutputFilter::MinAmount(1_000_000))
+///     .and(OutputFilter::NativeToken(token_id));
+/// let outputs = account.query_outputs(filter).await;
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "type", content = "value", rename_all = "camelCase")]
+pub enum OutputFilter {
+    /// Matches if all of the filters match, an empty list matches every output.
+    All(Vec<Self>),
+    /// Matches if any of the filters matches, an empty list matches no output.
+    Any(Vec<Self>),
+    /// Matches if the filter doesn't match.
+    Not(Box<Self>),
+    /// Matches outputs of the provided types (Basic = 3, Alias = 4, Foundry = 5, NFT = 6).
+    OutputTypes(Vec<u8>),
+    /// Matches outputs with at least this amount.
+    MinAmount(#[serde(with = "crate::utils::serde::string")] u64),
+    /// Matches outputs with at most this amount.
+    MaxAmount(#[serde(with = "crate::utils::serde::string")] u64),
+    /// Matches outputs holding the native token.
+    NativeToken(TokenId),
+    /// Matches alias outputs with one of these IDs.
+    AliasIds(HashSet<AliasId>),
+    /// Matches foundry outputs with one of these IDs.
+    FoundryIds(HashSet<FoundryId>),
+    /// Matches nft outputs with one of these IDs.
+    NftIds(HashSet<NftId>),
+    /// Matches outputs booked in a milestone with a timestamp within the bounds.
+    #[serde(rename_all = "camelCase")]
+    BookedTimestamp {
+        lower_bound: Option<u32>,
+        upper_bound: Option<u32>,
+    },
+    /// Matches unspent outputs.
+    Unspent,
+    /// Matches outputs that can be unlocked by the account at the provided milestone timestamp.
+    UnlockableAt(u32),
+    /// Matches outputs with a metadata feature containing a JSON object with this key, for example IRC27/IRC30
+    /// metadata.
+    MetadataKey(String),
+    /// Matches outputs with an expiration unlock condition expiring within the bounds.
+    #[serde(rename_all = "camelCase")]
+    Expiration {
+        lower_bound: Option<u32>,
+        upper_bound: Option<u32>,
+    },
+    /// Matches outputs with a local annotation with this key and, if provided, this value.
+    Annotation { key: String, value: Option<String> },
+}
+
+impl OutputFilter {
+    /// Combines two filters, so both have to match.
+    pub fn and(self, other: Self) -> Self {
+        match self {
+            Self::All(mut filters) => {
+                filters.push(other);
+                Self::All(filters)
+            }
+            _ => Self::All(vec![self, other]),
+        }
+    }
+
+    /// Combines two filters, so at least one has to match.
+    pub fn or(self, other: Self) -> Self {
+        match self {
+            Self::Any(mut filters) => {
+                filters.push(other);
+                Self::Any(filters)
+            }
+            _ => Self::Any(vec![self, other]),
+        }
+    }
+
+    /// Checks if an output of the account matches the filter.
+    pub(crate) fn matches(&self, output_data: &OutputData, account: &AccountDetails) -> bool {
+        let output = &output_data.output;
+
+        match self {
+            Self::All(filters) => filters.iter().all(|filter| filter.matches(output_data, account)),
+            Self::Any(filters) => filters.iter().any(|filter| filter.matches(output_data, account)),
+            Self::Not(filter) => !filter.matches(output_data, account),
+            Self::OutputTypes(output_types) => output_types.contains(&output.kind()),
+            Self::MinAmount(amount) => output.amount() >= *amount,
+            Self::MaxAmount(amount) => output.amount() <= *amount,
+            Self::NativeToken(token_id) => output
+                .native_tokens()
+                .is_some_and(|native_tokens| native_tokens.iter().any(|t| t.token_id() == token_id)),
+            Self::AliasIds(alias_ids) => match output {
+                Output::Alias(alias) => alias_ids.contains(&alias.alias_id_non_null(&output_data.output_id)),
+                _ => false,
+            },
+            Self::FoundryIds(foundry_ids) => match output {
+                Output::Foundry(foundry) => foundry_ids.contains(&foundry.id()),
+                _ => false,
+            },
+            Self::NftIds(nft_ids) => match output {
+                Output::Nft(nft) => nft_ids.contains(&nft.nft_id_non_null(&output_data.output_id)),
+                _ => false,
+            },
+            Self::BookedTimestamp {
+                lower_bound,
+                upper_bound,
+            } => is_within_bounds(
+                output_data.metadata.milestone_timestamp_booked(),
+                *lower_bound,
+                *upper_bound,
+            ),
+            Self::Unspent => !output_data.is_spent,
+            Self::UnlockableAt(timestamp) => {
+                // Outputs can also be owned by alias and nft outputs of the account
+                let alias_and_nft_addresses = account
+                    .unspent_outputs()
+                    .values()
+                    .filter_map(|output_data| match &output_data.output {
+                        Output::Alias(alias) => Some(Address::Alias(alias.alias_address(&output_data.output_id))),
+                        Output::Nft(nft) => Some(Address::Nft(nft.nft_address(&output_data.output_id))),
+                        _ => None,
+                    })
+                    .collect::<Vec<_>>();
+                can_output_be_unlocked_now(
+                    account.addresses_with_unspent_outputs(),
+                    &alias_and_nft_addresses,
+                    output_data,
+                    *timestamp,
+                    None,
+                )
+                .unwrap_or(false)
+            }
+            Self::MetadataKey(key) => output
+                .features()
+                .and_then(|features| features.metadata())
+                .and_then(|metadata| {
+                    serde_json::from_slice::<serde_json::Map<String, serde_json::Value>>(metadata.data()).ok()
+                })
+                .is_some_and(|metadata| metadata.contains_key(key)),
+            Self::Expiration {
+                lower_bound,
+                upper_bound,
+            } => output
+                .unlock_conditions()
+                .and_then(|unlock_conditions| unlock_conditions.expiration())
+                .is_some_and(|expiration| is_within_bounds(expiration.timestamp(), *lower_bound, *upper_bound)),
+            Self::Annotation { key, value } => output_data
+                .annotations
+                .get(key)
+                .is_some_and(|annotation| value.is_none() || value.as_ref() == Some(annotation)),
+        }
+    }
+}
+
+fn is_within_bounds(value: u32, lower_bound: Option<u32>, upper_bound: Option<u32>) -> bool {
+    lower_bound.is_none_or(|lower_bound| value >= lower_bound)
+        && upper_bound.is_none_or(|upper_bound| value <= upper_bound)
+}
+
+impl From<FilterOptions> for OutputFilter {
+    fn from(options: FilterOptions) -> Self {
+        let mut id_filters = Vec::new();
+        if let Some(alias_ids) = options.alias_ids {
+            id_filters.push(Self::AliasIds(alias_ids));
+        }
+        if let Some(foundry_ids) = options.foundry_ids {
+            id_filters.push(Self::FoundryIds(foundry_ids));
+        }
+        if let Some(nft_ids) = options.nft_ids {
+            id_filters.push(Self::NftIds(nft_ids));
+        }
+        // If ids are provided, only outputs with these ids are returned, without applying the other options
+        if !id_filters.is_empty() {
+            return Self::Any(id_filters);
+        }
+
+        let mut filters = Vec::new();
+        if options.lower_bound_booked_timestamp.is_some() || options.upper_bound_booked_timestamp.is_some() {
+            filters.push(Self::BookedTimestamp {
+                lower_bound: options.lower_bound_booked_timestamp,
+                upper_bound: options.upper_bound_booked_timestamp,
+            });
+        }
+        if let Some(output_types) = options.output_types {
+            filters.push(Self::OutputTypes(output_types));
+        }
+        Self::All(filters)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+
+    #[test]
+    fn serde() {
+        let filter =
+            OutputFilter::Unspent
+                .and(OutputFilter::OutputTypes(vec![3]))
+                .and(OutputFilter::MinAmount(1_000_000).or(OutputFilter::BookedTimestamp {
+                    lower_bound: Some(1),
+                    upper_bound: None,
+                }));
+        let json = serde_json::json!({
+            "type": "all",
+            "value": [
+                { "type": "unspent" },
+                { "type": "outputTypes", "value": [3] },
+                {
+                    "type": "any",
+                    "value": [
+                        { "type": "minAmount", "value": "1000000" },
+                        { "type": "bookedTimestamp", "value": { "lowerBound": 1, "upperBound": null } }
+                    ]
+                }
+            ]
+        });
+
+        assert_eq!(serde_json::to_value(&filter).unwrap(), json);
+        assert_eq!(serde_json::from_value::<OutputFilter>(json).unwrap(), filter);
+    }
+}
@@ -0,0 +1,130 @@
+// Copyright 2024 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use std::collections::HashSet;
+
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    types::block::{
+        address::{Address, Bech32Address},
+        output::Output,
+        protocol::ProtocolParameters,
+    },
+    wallet::account::{types::OutputData, AccountDetails},
+};
+
+/// Whether an output of an account can currently be unlocked, and if not, why not.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "camelCase")]
+pub enum Unlockability {
+    /// The output is already spent.
+    Spent,
+    /// The output can be unlocked by the account.
+    #[serde(rename_all = "camelCase")]
+    Unlockable {
+        /// The timestamp from which on the output can only be unlocked by its return address, if it isn't owned by
+        /// the account anymore then.
+        expires_at: Option<u32>,
+        /// The amount which has to be returned with a storage deposit return output when the output is unlocked.
+        #[serde(default, with = "crate::utils::serde::option_string")]
+        storage_deposit_return: Option<u64>,
+    },
+    /// The output is timelocked.
+    #[serde(rename_all = "camelCase")]
+    TimelockedUntil {
+        /// The timestamp from which on the output can be unlocked.
+        timestamp: u32,
+    },
+    /// The output expired and can now only be unlocked by its return address, which isn't owned by the account.
+    #[serde(rename_all = "camelCase")]
+    Expired {
+        /// The timestamp at which the output expired.
+        timestamp: u32,
+        /// The return address the output belongs to since then.
+        return_address: Bech32Address,
+    },
+    /// The output has to be unlocked by an address which isn't owned by the account, e.g. an alias controlled by
+    /// someone else.
+    #[serde(rename_all = "camelCase")]
+    RequiresUnlockBy {
+        /// The address which has to unlock the output.
+        address: Bech32Address,
+    },
+}
+
+impl Unlockability {
+    /// Returns whether the output can currently be unlocked by the account.
+    pub fn is_unlockable(&self) -> bool {
+        matches!(self, Self::Unlockable { .. })
+    }
+}
+
+impl OutputData {
+    /// Explains whether the output can be unlocked by the account at `current_time`, and if not, why not.
+    pub fn unlockability(
+        &self,
+        account: &AccountDetails,
+        current_time: u32,
+        protocol_parameters: &ProtocolParameters,
+    ) -> crate::wallet::Result<Unlockability> {
+        if self.is_spent {
+            return Ok(Unlockability::Spent);
+        }
+
+        let bech32_hrp = *protocol_parameters.bech32_hrp();
+        let unlock_conditions = self.output.unlock_conditions();
+
+        if let Some(timelock) = unlock_conditions.and_then(|unlock_conditions| unlock_conditions.timelock()) {
+            if current_time < timelock.timestamp() {
+                return Ok(Unlockability::TimelockedUntil {
+                    timestamp: timelock.timestamp(),
+                });
+            }
+        }
+
+        // Outputs can also be owned by alias and nft outputs of the account
+        let mut account_addresses = account
+            .addresses()
+            .into_iter()
+            .map(|address| *address.address().inner())
+            .collect::<HashSet<Address>>();
+        account_addresses.extend(account.unspent_outputs().values().filter_map(
+            |output_data| match &output_data.output {
+                Output::Alias(alias) => Some(Address::Alias(alias.alias_address(&output_data.output_id))),
+                Output::Nft(nft) => Some(Address::Nft(nft.nft_address(&output_data.output_id))),
+                _ => None,
+            },
+        ));
+
+        let (required_address, _) = self
+            .output
+            .required_and_unlocked_address(current_time, &self.output_id, None)?;
+
+        let expiration = unlock_conditions.and_then(|unlock_conditions| unlock_conditions.expiration());
+
+        if !account_addresses.contains(&required_address) {
+            return Ok(match expiration {
+                Some(expiration) if current_time >= expiration.timestamp() => Unlockability::Expired {
+                    timestamp: expiration.timestamp(),
+                    return_address: Bech32Address::new(bech32_hrp, required_address),
+                },
+                _ => Unlockability::RequiresUnlockBy {
+                    address: Bech32Address::new(bech32_hrp, required_address),
+                },
+            });
+        }
+
+        Ok(Unlockability::Unlockable {
+            expires_at: expiration
+                .filter(|expiration| {
+                    current_time < expiration.timestamp() && !account_addresses.contains(expiration.return_address())
+                })
+                .map(|expiration| expiration.timestamp()),
+            storage_deposit_return: unlock_conditions
+                .and_then(|unlock_conditions| unlock_conditions.storage_deposit_return())
+                .filter(|sdr| !account_addresses.contains(sdr.return_address()))
+                .map(|sdr| sdr.amount()),
+        })
+    }
+}
@@ -5,11 +5,17 @@ use std::collections::HashMap;
 
 use crate::{
     client::secret::SecretManage,
-    types::block::output::{OutputId, OutputMetadata},
+    types::block::{
+        output::{AliasId, OutputId, OutputMetadata},
+        payload::transaction::TransactionId,
+        BlockId,
+    },
+    utils::unix_timestamp_now,
     wallet::account::{
+        constants::IDEMPOTENCY_KEY_RETENTION,
         operations::syncing::options::SyncOptions,
         types::{address::AddressWithUnspentOutputs, InclusionState, OutputData, Transaction},
-        Account, AccountAddress,
+        Account, AccountAddress, AccountDetails,
     },
 };
 #[cfg(feature = "events")]
@@ -34,6 +40,26 @@ where
         Ok(())
     }
 
+    /// Set the alias output used as identity of the account, `None` removes it.
+    ///
+    /// Outputs of all kinds owned by the alias address are synced and the remainders of transactions with
+    /// [`RemainderValueStrategy::ReuseAddress`](crate::wallet::account::RemainderValueStrategy::ReuseAddress) are
+    /// sent to it. Inputs owned by the alias address are unlocked by transitioning the alias output. The alias output
+    /// must be synced and its state controller must be an address of the account.
+    pub async fn set_identity_alias(&self, alias_id: impl Into<Option<AliasId>>) -> crate::wallet::Result<()> {
+        let alias_id = alias_id.into();
+        let mut account_details = self.details_mut().await;
+        if let Some(alias_id) = alias_id {
+            if account_details.controlled_alias_output(&alias_id).is_none() {
+                return Err(crate::wallet::Error::AliasNotFoundInUnspentOutputs(alias_id));
+            }
+        }
+        account_details.identity_alias = alias_id;
+        #[cfg(feature = "storage")]
+        self.save(Some(&account_details)).await?;
+        Ok(())
+    }
+
     /// Update account with newly synced data and emit events for outputs
     pub(crate) async fn update_account(
         &self,
@@ -45,6 +71,7 @@ where
         log::debug!("[SYNC] Update account with new synced transactions");
 
         let network_id = self.client().get_network_id().await?;
+        let rent_structure = self.client().get_rent_structure().await?;
         let mut account_details = self.details_mut().await;
         #[cfg(feature = "events")]
         let account_index = account_details.index;
@@ -118,6 +145,7 @@ where
                     log::debug!("[SYNC] Spent output {}", output_id);
                     account_details.locked_outputs.remove(&output_id);
                     account_details.unspent_outputs.remove(&output_id);
+                    account_details.quarantined_outputs.remove(&output_id);
                     // Update spent data fields
                     if let Some(output_data) = account_details.outputs.get_mut(&output_id) {
                         output_data.metadata.set_spent(true);
@@ -138,13 +166,24 @@ where
         }
 
         // Add new synced outputs
-        for output_data in unspent_outputs {
+        for mut output_data in unspent_outputs {
+            // Keep the annotations of already known outputs
+            if let Some(known_output_data) = account_details.outputs.get(&output_data.output_id) {
+                output_data.annotations = known_output_data.annotations.clone();
+            }
             // Insert output, if it's unknown emit the NewOutputEvent
             if account_details
                 .outputs
                 .insert(output_data.output_id, output_data.clone())
                 .is_none()
             {
+                if options.quarantine_spam_outputs
+                    && !output_data.is_spent
+                    && account_details.is_spam_output(&output_data.output, &rent_structure)
+                {
+                    log::debug!("[SYNC] Quarantined spam output {}", output_data.output_id);
+                    account_details.quarantined_outputs.insert(output_data.output_id);
+                }
                 #[cfg(feature = "events")]
                 {
                     let transaction = account_details
@@ -174,6 +213,22 @@ where
             }
         }
 
+        // Forget the idempotency keys of transactions which can't change anymore after the retention window
+        let time_now = unix_timestamp_now().as_millis();
+        let AccountDetails {
+            idempotency_keys,
+            transactions,
+            ..
+        } = &mut *account_details;
+        idempotency_keys.retain(|_, transaction_id| {
+            !transactions.get(transaction_id).is_some_and(|transaction| {
+                matches!(
+                    transaction.inclusion_state,
+                    InclusionState::Confirmed | InclusionState::Conflicting
+                ) && time_now.saturating_sub(transaction.timestamp) >= IDEMPOTENCY_KEY_RETENTION
+            })
+        });
+
         #[cfg(feature = "storage")]
         {
             log::debug!(
@@ -230,6 +285,7 @@ where
             }
             account_details.locked_outputs.remove(output_to_unlock);
             account_details.unspent_outputs.remove(output_to_unlock);
+            account_details.quarantined_outputs.remove(output_to_unlock);
             log::debug!("[SYNC] Unlocked spent output {}", output_to_unlock);
         }
 
@@ -252,6 +308,27 @@ where
         Ok(())
     }
 
+    /// Replaces the block id of a transaction which was reissued, returns `false` if the transaction isn't pending
+    /// anymore, e.g. because a sync updated it in the meantime.
+    pub(crate) async fn update_pending_transaction_block_id(
+        &self,
+        transaction_id: &TransactionId,
+        block_id: BlockId,
+    ) -> crate::wallet::Result<bool> {
+        let mut account_details = self.details_mut().await;
+        if !account_details.pending_transactions.contains(transaction_id) {
+            return Ok(false);
+        }
+        let Some(transaction) = account_details.transactions.get_mut(transaction_id) else {
+            return Ok(false);
+        };
+        transaction.block_id.replace(block_id);
+
+        #[cfg(feature = "storage")]
+        self.save(Some(&account_details)).await?;
+        Ok(true)
+    }
+
     /// Update account with newly generated addresses
     pub(crate) async fn update_account_addresses(
         &self,
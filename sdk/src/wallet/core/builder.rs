@@ -1,30 +1,43 @@
 // Copyright 2021 IOTA Stiftung
 // SPDX-License-Identifier: Apache-2.0
 
-use std::sync::{
-    atomic::{AtomicU32, AtomicUsize},
-    Arc,
-};
 #[cfg(feature = "storage")]
-use std::{collections::HashSet, sync::atomic::Ordering};
+use std::sync::atomic::Ordering;
+use std::{
+    collections::HashSet,
+    sync::{
+        atomic::{AtomicU32, AtomicUsize},
+        Arc,
+    },
+};
 
+use crypto::keys::bip44::Bip44;
 use futures::{future::try_join_all, FutureExt};
 use serde::Serialize;
 use tokio::sync::RwLock;
 
 use super::operations::storage::SaveLoadWallet;
+#[cfg(feature = "webhook")]
+use crate::wallet::events::webhook::{Webhook, WebhookConfig};
 #[cfg(feature = "events")]
 use crate::wallet::events::EventEmitter;
-#[cfg(all(feature = "storage", not(feature = "rocksdb")))]
-use crate::wallet::storage::adapter::memory::Memory;
 #[cfg(feature = "storage")]
 use crate::wallet::{
     account::AccountDetails,
-    storage::{StorageManager, StorageOptions},
+    migration::{storage_version, StorageVersion},
+    storage::{
+        adapter::{memory::Memory, DynStorageAdapter},
+        Storage, StorageManager, StorageOptions,
+    },
 };
 use crate::{
     client::secret::{SecretManage, SecretManager},
-    wallet::{core::WalletInner, Account, ClientOptions, Wallet},
+    types::block::{address::Bech32Address, output::OutputId},
+    wallet::{
+        account::ServiceFee,
+        core::{NetworkValidation, SendDefaults, WalletInner},
+        Account, ClientOptions, Wallet,
+    },
 };
 
 /// Builder for the wallet.
@@ -33,10 +46,27 @@ use crate::{
 pub struct WalletBuilder<S: SecretManage = SecretManager> {
     pub(crate) client_options: Option<ClientOptions>,
     pub(crate) coin_type: Option<u32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) account_index: Option<u32>,
     #[cfg(feature = "storage")]
     pub(crate) storage_options: Option<StorageOptions>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) send_defaults: Option<SendDefaults>,
     #[serde(skip)]
     pub(crate) secret_manager: Option<Arc<RwLock<S>>>,
+    #[serde(skip)]
+    pub(crate) bootstrap_outputs: Option<Vec<OutputId>>,
+    #[serde(skip)]
+    pub(crate) bootstrap_addresses: Option<Vec<Bech32Address>>,
+    #[serde(skip)]
+    pub(crate) output_maturity: Option<u32>,
+    #[serde(skip)]
+    pub(crate) service_fee: Option<ServiceFee>,
+    #[serde(skip)]
+    pub(crate) network_validation: Option<NetworkValidation>,
+    #[cfg(feature = "webhook")]
+    #[serde(skip)]
+    pub(crate) webhooks: Vec<WebhookConfig>,
 }
 
 impl<S: SecretManage> Default for WalletBuilder<S> {
@@ -44,9 +74,18 @@ impl<S: SecretManage> Default for WalletBuilder<S> {
         Self {
             client_options: Default::default(),
             coin_type: Default::default(),
+            account_index: Default::default(),
             #[cfg(feature = "storage")]
             storage_options: Default::default(),
+            send_defaults: Default::default(),
             secret_manager: Default::default(),
+            bootstrap_outputs: Default::default(),
+            bootstrap_addresses: Default::default(),
+            output_maturity: Default::default(),
+            service_fee: Default::default(),
+            network_validation: Default::default(),
+            #[cfg(feature = "webhook")]
+            webhooks: Default::default(),
         }
     }
 }
@@ -75,6 +114,24 @@ where
         self
     }
 
+    /// Set the coin type and the account index for the wallet from a BIP-44 path, the change and address index are
+    /// ignored. See [`WalletBuilder::for_account_index()`].
+    pub fn with_bip_path(mut self, bip_path: impl Into<Option<Bip44>>) -> Self {
+        let bip_path = bip_path.into();
+        self.coin_type = bip_path.map(|bip_path| bip_path.coin_type);
+        self.account_index = bip_path.map(|bip_path| bip_path.account);
+        self
+    }
+
+    /// Bind the wallet to a single account with the given account index, instead of numbering its accounts from 0.
+    /// Wallets with their own storage can so share a seed without sharing addresses, e.g. one wallet per user of a
+    /// service. The account index is stored with the wallet and building the wallet fails if it doesn't match the
+    /// stored one, so the storage of another wallet doesn't get used by accident.
+    pub fn for_account_index(mut self, account_index: u32) -> Self {
+        self.account_index = Some(account_index);
+        self
+    }
+
     /// Set the storage options to be used.
     #[cfg(feature = "storage")]
     #[cfg_attr(docsrs, doc(cfg(feature = "storage")))]
@@ -106,6 +163,79 @@ where
         });
         self
     }
+
+    /// Set known output ids, for example exported from a previous installation, from which accounts get bootstrapped
+    /// on their first sync. Only the addresses owning these outputs are then synced, instead of all account addresses.
+    /// Output ids that are spent or unknown to the node are ignored.
+    pub fn with_bootstrap_outputs(mut self, output_ids: impl Into<Option<Vec<OutputId>>>) -> Self {
+        self.bootstrap_outputs = output_ids.into();
+        self
+    }
+
+    /// Set known addresses, for example exported from a previous installation, which are synced on the first sync of
+    /// accounts instead of all account addresses. Addresses which aren't generated for an account are ignored.
+    pub fn with_bootstrap_addresses(mut self, addresses: impl Into<Option<Vec<Bech32Address>>>) -> Self {
+        self.bootstrap_addresses = addresses.into();
+        self
+    }
+
+    /// Set the time in seconds for which received outputs are not selected as inputs, counted from the milestone
+    /// that booked them. Avoids building on outputs that could still get orphaned. Outputs provided as custom or
+    /// mandatory inputs are not affected. The setting isn't stored with the wallet.
+    pub fn with_output_maturity(mut self, seconds: impl Into<Option<u32>>) -> Self {
+        self.output_maturity = seconds.into();
+        self
+    }
+
+    /// Set a fee which is added as an additional output to every transaction sending funds out of an account. The
+    /// setting isn't stored with the wallet.
+    pub fn with_service_fee(mut self, service_fee: impl Into<Option<ServiceFee>>) -> Self {
+        self.service_fee = service_fee.into();
+        self
+    }
+
+    /// Set how the wallet reacts if the network of the node doesn't match the coin type or the addresses of its
+    /// accounts, when it's built, the client options are changed or an account is created. Defaults to
+    /// [`NetworkValidation::Warn`]. The setting isn't stored with the wallet.
+    pub fn with_network_validation(mut self, network_validation: impl Into<Option<NetworkValidation>>) -> Self {
+        self.network_validation = network_validation.into();
+        self
+    }
+
+    /// Set wallet-wide defaults for the return address and expiration of outputs with a storage deposit return, used
+    /// if they aren't provided per call. The setting is stored with the wallet.
+    pub fn with_send_defaults(mut self, send_defaults: impl Into<Option<SendDefaults>>) -> Self {
+        self.send_defaults = send_defaults.into();
+        self
+    }
+
+    /// Add a webhook to which the events of all accounts are POSTed. Can be called multiple times to add several
+    /// webhooks. The setting isn't stored with the wallet.
+    #[cfg(feature = "webhook")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "webhook")))]
+    pub fn with_webhook(mut self, config: WebhookConfig) -> Self {
+        self.webhooks.push(config);
+        self
+    }
+
+    /// Returns the migration state of the storage without modifying it, so an application can for example create a
+    /// backup before [`WalletBuilder::finish()`] applies the pending migrations. Has to be called before the wallet
+    /// is built, as the storage can't be opened twice.
+    ///
+    /// Fails with [`Error::UnsupportedMigrationVersion`](crate::wallet::Error::UnsupportedMigrationVersion) if the
+    /// storage was migrated by a newer SDK version, in which case building the wallet fails too.
+    #[cfg(feature = "storage")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "storage")))]
+    pub async fn check_storage_version(&self) -> crate::wallet::Result<StorageVersion> {
+        let storage_options = self.storage_options.clone().unwrap_or_default();
+        // Don't create the database if it doesn't exist yet
+        let inner: Box<dyn DynStorageAdapter> = if storage_options.path.is_dir() {
+            Box::new(storage_adapter(&storage_options)?)
+        } else {
+            Box::new(Memory::default())
+        };
+        storage_version(&Storage::new(inner, storage_options.encryption_key)).await
+    }
 }
 
 impl<S: 'static + SecretManage> WalletBuilder<S>
@@ -134,14 +264,12 @@ where
             }
         }
 
-        #[cfg(all(feature = "rocksdb", feature = "storage"))]
-        let storage =
-            crate::wallet::storage::adapter::rocksdb::RocksdbStorageAdapter::new(storage_options.path.clone())?;
-        #[cfg(all(not(feature = "rocksdb"), feature = "storage"))]
-        let storage = Memory::default();
-
         #[cfg(feature = "storage")]
-        let mut storage_manager = StorageManager::new(storage, storage_options.encryption_key.clone()).await?;
+        let mut storage_manager = StorageManager::new(
+            storage_adapter(&storage_options)?,
+            storage_options.encryption_key.clone(),
+        )
+        .await?;
 
         #[cfg(feature = "storage")]
         let read_manager_builder = Self::load(&storage_manager).await?;
@@ -172,6 +300,12 @@ where
             self.secret_manager.replace(secret_manager);
         }
 
+        if self.send_defaults.is_none() {
+            self.send_defaults = read_manager_builder
+                .as_ref()
+                .and_then(|builder| builder.send_defaults.clone());
+        }
+        let stored_account_index = read_manager_builder.as_ref().and_then(|builder| builder.account_index);
         if self.coin_type.is_none() {
             self.coin_type = read_manager_builder.and_then(|builder| builder.coin_type);
         }
@@ -193,21 +327,44 @@ where
             }
         }
 
-        // Store wallet data in storage
-        #[cfg(feature = "storage")]
-        self.save(&storage_manager).await?;
+        // Check against the account index of the stored wallet or of potential accounts of an unbound wallet
+        if self.account_index.is_none() {
+            self.account_index = stored_account_index;
+        }
+        if let Some(new_account_index) = self.account_index {
+            #[cfg(feature = "storage")]
+            let existing_account_index = stored_account_index.or_else(|| {
+                accounts
+                    .iter()
+                    .map(|account| *account.index())
+                    .find(|index| *index != new_account_index)
+            });
+            #[cfg(not(feature = "storage"))]
+            let existing_account_index = stored_account_index;
+            if let Some(existing_account_index) = existing_account_index.filter(|index| *index != new_account_index) {
+                return Err(crate::wallet::Error::AccountIndexMismatch {
+                    new_account_index,
+                    existing_account_index,
+                });
+            }
+        }
 
         #[cfg(feature = "events")]
-        let event_emitter = tokio::sync::RwLock::new(EventEmitter::new());
+        let event_emitter = Arc::new(tokio::sync::RwLock::new(EventEmitter::new()));
 
         // It happened that inputs got locked, the transaction failed, but they weren't unlocked again, so we do this
         // here
         #[cfg(feature = "storage")]
         unlock_unused_inputs(&mut accounts)?;
+        #[cfg(feature = "storage")]
+        let chain_labels = storage_manager.get_chain_labels().await?;
+        #[cfg(not(feature = "storage"))]
+        let chain_labels = Default::default();
         #[cfg(not(feature = "storage"))]
         let accounts = Vec::new();
         let wallet_inner = Arc::new(WalletInner {
             background_syncing_status: AtomicUsize::new(0),
+            inclusion_monitor_status: AtomicUsize::new(0),
             client: self
                 .client_options
                 .clone()
@@ -215,15 +372,45 @@ where
                 .finish()
                 .await?,
             coin_type: AtomicU32::new(coin_type),
+            account_index: self.account_index,
             secret_manager: self
                 .secret_manager
+                .clone()
                 .ok_or(crate::wallet::Error::MissingParameter("secret_manager"))?,
             #[cfg(feature = "events")]
             event_emitter,
+            #[cfg(feature = "webhook")]
+            webhooks: core::mem::take(&mut self.webhooks)
+                .into_iter()
+                .map(Webhook::spawn)
+                .collect::<crate::wallet::Result<_>>()?,
             #[cfg(feature = "storage")]
             storage_options,
             #[cfg(feature = "storage")]
             storage_manager: tokio::sync::RwLock::new(storage_manager),
+            transaction_queue: tokio::sync::Mutex::new(()),
+            bootstrap_outputs: RwLock::new(
+                self.bootstrap_outputs
+                    .take()
+                    .unwrap_or_default()
+                    .into_iter()
+                    .collect::<HashSet<_>>(),
+            ),
+            bootstrap_addresses: RwLock::new(
+                self.bootstrap_addresses
+                    .take()
+                    .unwrap_or_default()
+                    .into_iter()
+                    .collect::<HashSet<_>>(),
+            ),
+            transaction_approver: RwLock::new(None),
+            output_maturity: AtomicU32::new(self.output_maturity.unwrap_or_default()),
+            chain_labels: RwLock::new(chain_labels),
+            service_fee: self.service_fee.clone(),
+            network_validation: self.network_validation.unwrap_or_default(),
+            send_defaults: RwLock::new(self.send_defaults.clone().unwrap_or_default()),
+            shutdown_token: Default::default(),
+            operations_in_flight: AtomicUsize::new(0),
         });
 
         let mut accounts: Vec<Account<S>> = try_join_all(
@@ -233,6 +420,14 @@ where
         )
         .await?;
 
+        // Check the network before storing the client options, so a mismatching network isn't used again on the next
+        // start
+        wallet_inner.validate_accounts_network(&accounts).await?;
+
+        // Store wallet data in storage
+        #[cfg(feature = "storage")]
+        self.save(&*wallet_inner.storage_manager.read().await).await?;
+
         // If the wallet builder is not set, it means the user provided it and we need to update the addresses.
         // In the other case it was loaded from the database and addresses are up to date.
         if new_provided_client_options {
@@ -253,12 +448,32 @@ where
         Self {
             client_options: Some(wallet.client_options().await),
             coin_type: Some(wallet.coin_type.load(Ordering::Relaxed)),
+            account_index: wallet.account_index,
             storage_options: Some(wallet.storage_options.clone()),
+            send_defaults: Some(wallet.send_defaults.read().await.clone()),
             secret_manager: Some(wallet.secret_manager.clone()),
+            bootstrap_outputs: None,
+            bootstrap_addresses: None,
+            output_maturity: Some(wallet.output_maturity.load(Ordering::Relaxed)),
+            service_fee: wallet.service_fee.clone(),
+            network_validation: Some(wallet.network_validation),
+            #[cfg(feature = "webhook")]
+            webhooks: Vec::new(),
         }
     }
 }
 
+#[cfg(feature = "storage")]
+#[cfg_attr(not(feature = "rocksdb"), allow(unused_variables))]
+fn storage_adapter(storage_options: &StorageOptions) -> crate::wallet::Result<impl DynStorageAdapter + 'static> {
+    #[cfg(feature = "rocksdb")]
+    return Ok(crate::wallet::storage::adapter::rocksdb::RocksdbStorageAdapter::new(
+        storage_options.path.clone(),
+    )?);
+    #[cfg(not(feature = "rocksdb"))]
+    Ok(Memory::default())
+}
+
 // Check if any of the locked inputs is not used in a transaction and unlock them, so they get available for new
 // transactions
 #[cfg(feature = "storage")]
@@ -299,9 +514,13 @@ pub(crate) mod dto {
         pub(crate) client_options: Option<ClientOptions>,
         #[serde(default, skip_serializing_if = "Option::is_none")]
         pub(crate) coin_type: Option<u32>,
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        pub(crate) account_index: Option<u32>,
         #[cfg(feature = "storage")]
         #[serde(default, skip_serializing_if = "Option::is_none")]
         pub(crate) storage_options: Option<StorageOptions>,
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        pub(crate) send_defaults: Option<SendDefaults>,
     }
 
     impl<S: SecretManage> From<WalletBuilderDto> for WalletBuilder<S> {
@@ -309,9 +528,18 @@ pub(crate) mod dto {
             Self {
                 client_options: value.client_options,
                 coin_type: value.coin_type,
+                account_index: value.account_index,
                 #[cfg(feature = "storage")]
                 storage_options: value.storage_options,
+                send_defaults: value.send_defaults,
                 secret_manager: None,
+                bootstrap_outputs: None,
+                bootstrap_addresses: None,
+                output_maturity: None,
+                service_fee: None,
+                network_validation: None,
+                #[cfg(feature = "webhook")]
+                webhooks: Vec::new(),
             }
         }
     }
@@ -4,19 +4,35 @@
 pub(crate) mod builder;
 pub(crate) mod operations;
 
-use std::sync::{
-    atomic::{AtomicU32, AtomicUsize},
-    Arc,
+use std::{
+    collections::{HashMap, HashSet},
+    sync::{
+        atomic::{AtomicU32, AtomicUsize},
+        Arc,
+    },
 };
 
 use crypto::keys::bip39::{Mnemonic, MnemonicRef};
-use tokio::sync::RwLock;
-
-pub use self::builder::WalletBuilder;
+use tokio::sync::{Mutex, RwLock};
+
+pub use self::{
+    builder::WalletBuilder,
+    operations::{
+        inclusion_monitor::InclusionMonitorOptions,
+        network_validation::NetworkValidation,
+        privacy_report::{PrivacyReport, PrivacyWarning},
+        roles::{ProposalBuilder, Signer, WalletReader},
+        send_defaults::SendDefaults,
+        shutdown::CancellationToken,
+        state_json::{AccountState, WalletState, WALLET_STATE_VERSION},
+        transaction_approval::TransactionApprover,
+    },
+};
 #[cfg(feature = "events")]
 use crate::wallet::events::{
+    listener_handle,
     types::{Event, WalletEventType},
-    EventEmitter,
+    EventEmitter, ListenerHandle, ListenerId,
 };
 #[cfg(feature = "storage")]
 use crate::wallet::storage::{StorageManager, StorageOptions};
@@ -25,7 +41,11 @@ use crate::{
         secret::{SecretManage, SecretManager},
         verify_mnemonic, Client,
     },
-    wallet::account::{builder::AccountBuilder, operations::syncing::SyncOptions, types::Balance, Account},
+    types::block::{
+        address::Bech32Address,
+        output::{ChainId, OutputId},
+    },
+    wallet::account::{builder::AccountBuilder, operations::syncing::SyncOptions, types::Balance, Account, ServiceFee},
 };
 
 /// The wallet, used to create and get accounts. One wallet can hold many accounts, but they should
@@ -74,15 +94,40 @@ where
 pub struct WalletInner<S: SecretManage = SecretManager> {
     // 0 = not running, 1 = running, 2 = stopping
     pub(crate) background_syncing_status: AtomicUsize,
+    // 0 = not running, 1 = running, 2 = stopping
+    pub(crate) inclusion_monitor_status: AtomicUsize,
     pub(crate) client: Client,
     pub(crate) coin_type: AtomicU32,
+    // The index of the only account, if the wallet is bound to one
+    pub(crate) account_index: Option<u32>,
     pub(crate) secret_manager: Arc<RwLock<S>>,
     #[cfg(feature = "events")]
-    pub(crate) event_emitter: tokio::sync::RwLock<EventEmitter>,
+    pub(crate) event_emitter: Arc<tokio::sync::RwLock<EventEmitter>>,
+    #[cfg(feature = "webhook")]
+    pub(crate) webhooks: Vec<crate::wallet::events::webhook::Webhook>,
     #[cfg(feature = "storage")]
     pub(crate) storage_options: StorageOptions,
     #[cfg(feature = "storage")]
     pub(crate) storage_manager: tokio::sync::RwLock<StorageManager>,
+    // Queue which serializes preparing and signing transactions across accounts, so concurrent operations see the
+    // outputs locked by previous ones and a secret manager never signs multiple transactions at once
+    pub(crate) transaction_queue: Mutex<()>,
+    // Known output ids which weren't yet claimed by an account during its first sync
+    pub(crate) bootstrap_outputs: RwLock<HashSet<OutputId>>,
+    // Known addresses which weren't yet synced by an account during its first sync
+    pub(crate) bootstrap_addresses: RwLock<HashSet<Bech32Address>>,
+    pub(crate) transaction_approver: RwLock<Option<Arc<dyn TransactionApprover>>>,
+    // Seconds after their booking milestone until outputs are selected as inputs
+    pub(crate) output_maturity: AtomicU32,
+    // Local labels of alias, foundry and NFT ids
+    pub(crate) chain_labels: RwLock<HashMap<ChainId, String>>,
+    pub(crate) service_fee: Option<ServiceFee>,
+    pub(crate) network_validation: NetworkValidation,
+    pub(crate) send_defaults: RwLock<SendDefaults>,
+    // Cancelled by `Wallet::shutdown()`
+    pub(crate) shutdown_token: CancellationToken,
+    // Long running operations which `Wallet::shutdown()` waits for
+    pub(crate) operations_in_flight: AtomicUsize,
 }
 
 impl<S: 'static + SecretManage> Wallet<S>
@@ -172,16 +217,40 @@ impl<S: SecretManage> WalletInner<S> {
         &self.secret_manager
     }
 
-    /// Listen to wallet events, empty vec will listen to all events
+    /// Get the account index the wallet is bound to, see [`WalletBuilder::for_account_index()`].
+    pub fn account_index(&self) -> Option<u32> {
+        self.account_index
+    }
+
+    /// Listen to wallet events, empty vec will listen to all events. The returned handle can remove the listener again.
     #[cfg(feature = "events")]
     #[cfg_attr(docsrs, doc(cfg(feature = "events")))]
-    pub async fn listen<F, I: IntoIterator<Item = WalletEventType> + Send>(&self, events: I, handler: F)
+    pub async fn listen<F, I: IntoIterator<Item = WalletEventType> + Send>(
+        &self,
+        events: I,
+        handler: F,
+    ) -> ListenerHandle
     where
         I::IntoIter: Send,
         F: Fn(&Event) + 'static + Send + Sync,
     {
         let mut emitter = self.event_emitter.write().await;
-        emitter.on(events, handler);
+        let id = emitter.on(events, handler);
+        listener_handle(&self.event_emitter, id)
+    }
+
+    /// Remove a single wallet event listener, returns `false` if it isn't registered.
+    #[cfg(feature = "events")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "events")))]
+    pub async fn remove_listener(&self, id: ListenerId) -> bool {
+        self.event_emitter.write().await.remove(id)
+    }
+
+    /// Get the number of wallet event listeners for each event type that has any.
+    #[cfg(feature = "events")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "events")))]
+    pub async fn listener_counts(&self) -> HashMap<WalletEventType, usize> {
+        self.event_emitter.read().await.listener_counts()
     }
 
     /// Remove wallet event listeners, empty vec will remove all listeners
@@ -208,6 +277,10 @@ impl<S: SecretManage> WalletInner<S> {
 
     #[cfg(feature = "events")]
     pub(crate) async fn emit(&self, account_index: u32, event: crate::wallet::events::types::WalletEvent) {
+        #[cfg(feature = "webhook")]
+        for webhook in &self.webhooks {
+            webhook.send(account_index, &event);
+        }
         self.event_emitter.read().await.emit(account_index, event);
     }
 
@@ -24,6 +24,7 @@ where
         interval: Option<Duration>,
     ) -> crate::wallet::Result<()> {
         log::debug!("[start_background_syncing]");
+        self.check_cancelled(None)?;
         // stop existing process if running
         if self.background_syncing_status.load(Ordering::Relaxed) == 1 {
             self.background_syncing_status.store(2, Ordering::Relaxed);
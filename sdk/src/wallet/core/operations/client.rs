@@ -1,11 +1,15 @@
 // Copyright 2023 IOTA Stiftung
 // SPDX-License-Identifier: Apache-2.0
 
-use std::collections::{HashMap, HashSet};
+use std::{
+    collections::{HashMap, HashSet},
+    sync::Arc,
+};
 
 use super::storage::SaveLoadWallet;
 use crate::{
     client::{
+        cache::Cache,
         node_manager::{
             builder::NodeManagerBuilder,
             node::{Node, NodeAuth, NodeDto},
@@ -44,10 +48,17 @@ where
             pow_worker_count,
             #[cfg(not(target_family = "wasm"))]
             max_parallel_api_requests,
+            cache,
+            // A mocked node can only be set when the client is built
+            #[cfg(feature = "testing")]
+                mock_client: _,
         } = client_options;
 
-        // Only check bech32 if something in the node_manager_builder changed
-        let change_in_node_manager = self.client_options().await.node_manager_builder != node_manager_builder;
+        let current_client_options = self.client_options().await;
+        // Only check bech32 if something in the node_manager_builder or the protocol parameter overrides changed
+        let network_changed = current_client_options.node_manager_builder != node_manager_builder
+            || current_client_options.network_info.protocol_parameters_overrides
+                != network_info.protocol_parameters_overrides;
 
         self.client
             .update_node_manager(node_manager_builder.build(HashMap::new()))
@@ -56,6 +67,12 @@ where
         *self.client.remote_pow_timeout.write().await = remote_pow_timeout;
         #[cfg(not(target_family = "wasm"))]
         self.client.request_pool.resize(max_parallel_api_requests).await;
+        {
+            let mut current_cache = self.client.cache.write().await;
+            if current_cache.as_ref().map(|cache| cache.options()) != cache.as_ref() {
+                *current_cache = cache.map(|options| Arc::new(Cache::new(options)));
+            }
+        }
         #[cfg(not(target_family = "wasm"))]
         {
             *self.client.pow_worker_count.write().await = pow_worker_count;
@@ -65,14 +82,23 @@ where
             *self.client.mqtt.broker_options.write().await = broker_options;
         }
 
-        if change_in_node_manager {
+        if network_changed {
             // Update the protocol of the network_info to not have the default data, which can be wrong
             // Ignore errors, because there might be no node at all and then it should still not error
             if let Ok(info) = self.client.get_info().await {
-                network_info.protocol_parameters = info.node_info.protocol;
+                network_info.update_protocol_parameters(info.node_info.protocol)?;
             }
             *self.client.network_info.write().await = network_info;
 
+            if let Err(error) = self.validate_accounts_network(&self.accounts.read().await).await {
+                // Restore the previous network, so the wallet keeps using the one of its accounts
+                self.client
+                    .update_node_manager(current_client_options.node_manager_builder.build(HashMap::new()))
+                    .await?;
+                *self.client.network_info.write().await = current_client_options.network_info;
+                return Err(error);
+            }
+
             for account in self.accounts.write().await.iter_mut() {
                 account.update_account_bech32_hrp().await?;
             }
@@ -94,9 +120,9 @@ where
         let mut node_manager_builder = NodeManagerBuilder::from(&*self.client.node_manager.read().await);
 
         if let Some(primary_node) = &node_manager_builder.primary_node {
-            let (node_url, disabled) = match &primary_node {
-                NodeDto::Url(node_url) => (node_url, false),
-                NodeDto::Node(node) => (&node.url, node.disabled),
+            let (node_url, disabled, proxy) = match &primary_node {
+                NodeDto::Url(node_url) => (node_url, false, None),
+                NodeDto::Node(node) => (&node.url, node.disabled, node.proxy.clone()),
             };
 
             if node_url == &url {
@@ -104,14 +130,15 @@ where
                     url: url.clone(),
                     auth: auth.clone(),
                     disabled,
+                    proxy,
                 }));
             }
         }
 
         if let Some(primary_pow_node) = &node_manager_builder.primary_pow_node {
-            let (node_url, disabled) = match &primary_pow_node {
-                NodeDto::Url(node_url) => (node_url, false),
-                NodeDto::Node(node) => (&node.url, node.disabled),
+            let (node_url, disabled, proxy) = match &primary_pow_node {
+                NodeDto::Url(node_url) => (node_url, false, None),
+                NodeDto::Node(node) => (&node.url, node.disabled, node.proxy.clone()),
             };
 
             if node_url == &url {
@@ -119,6 +146,23 @@ where
                     url: url.clone(),
                     auth: auth.clone(),
                     disabled,
+                    proxy,
+                }));
+            }
+        }
+
+        if let Some(issuer_node) = &node_manager_builder.issuer_node {
+            let (node_url, disabled, proxy) = match &issuer_node {
+                NodeDto::Url(node_url) => (node_url, false, None),
+                NodeDto::Node(node) => (&node.url, node.disabled, node.proxy.clone()),
+            };
+
+            if node_url == &url {
+                node_manager_builder.issuer_node = Some(NodeDto::Node(Node {
+                    url: url.clone(),
+                    auth: auth.clone(),
+                    disabled,
+                    proxy,
                 }));
             }
         }
@@ -127,9 +171,9 @@ where
             .permanodes
             .into_iter()
             .map(|node| {
-                let (node_url, disabled) = match &node {
-                    NodeDto::Url(node_url) => (node_url, false),
-                    NodeDto::Node(node) => (&node.url, node.disabled),
+                let (node_url, disabled, proxy) = match &node {
+                    NodeDto::Url(node_url) => (node_url, false, None),
+                    NodeDto::Node(node) => (&node.url, node.disabled, node.proxy.clone()),
                 };
 
                 if node_url == &url {
@@ -137,6 +181,7 @@ where
                         url: url.clone(),
                         auth: auth.clone(),
                         disabled,
+                        proxy,
                     })
                 } else {
                     node
@@ -146,9 +191,9 @@ where
 
         let mut new_nodes = HashSet::new();
         for node in node_manager_builder.nodes.iter() {
-            let (node_url, disabled) = match &node {
-                NodeDto::Url(node_url) => (node_url, false),
-                NodeDto::Node(node) => (&node.url, node.disabled),
+            let (node_url, disabled, proxy) = match &node {
+                NodeDto::Url(node_url) => (node_url, false, None),
+                NodeDto::Node(node) => (&node.url, node.disabled, node.proxy.clone()),
             };
 
             if node_url == &url {
@@ -156,6 +201,7 @@ where
                     url: url.clone(),
                     auth: auth.clone(),
                     disabled,
+                    proxy,
                 }));
             } else {
                 new_nodes.insert(node.clone());
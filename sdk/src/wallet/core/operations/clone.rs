@@ -0,0 +1,65 @@
+// Copyright 2024 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use std::path::PathBuf;
+
+use crate::{
+    client::secret::SecretManage,
+    wallet::{
+        core::operations::storage::SaveLoadWallet, storage::StorageOptions, ClientOptions, Error, Wallet, WalletBuilder,
+    },
+};
+
+impl<S: 'static + SecretManage> Wallet<S>
+where
+    crate::wallet::Error: From<S::Error>,
+    WalletBuilder<S>: SaveLoadWallet,
+{
+    /// Copies the accounts, labels and settings of the wallet into a new wallet with its own storage, to safely try
+    /// out operations on the copy.
+    ///
+    /// With `client_options` the copy uses another network, e.g. a private tangle started from a snapshot of the
+    /// network, and the addresses of the accounts get its bech32 HRP. Both wallets share the secret manager, the
+    /// storage of the wallet isn't changed.
+    /// ```ignore
+    /// let what_if_wallet = wallet
+    ///     .clone_to("./what-if", ClientOptions::new().with_node("http://localhost:14265")?)
+    ///     .await?;
+    /// ```
+    pub async fn clone_to(
+        &self,
+        storage_path: impl Into<PathBuf> + Send,
+        client_options: impl Into<Option<ClientOptions>> + Send,
+    ) -> crate::wallet::Result<Self> {
+        let storage_path = storage_path.into();
+        log::debug!("[clone_to] {}", storage_path.display());
+        if storage_path.exists() {
+            return Err(Error::Storage(format!(
+                "can't clone the wallet to the existing path {}",
+                storage_path.display()
+            )));
+        }
+
+        let client_options = client_options.into();
+        let new_network = client_options.is_some();
+        let mut wallet_builder = WalletBuilder::from_wallet(self)
+            .await
+            .with_storage_options(StorageOptions {
+                path: storage_path,
+                ..self.storage_options.clone()
+            });
+        if let Some(client_options) = client_options {
+            wallet_builder = wallet_builder.with_client_options(client_options);
+        }
+        let wallet = wallet_builder.finish().await?;
+
+        wallet.load_state_json(self.dump_state_json().await?).await?;
+        if new_network {
+            for account in wallet.accounts.write().await.iter_mut() {
+                account.update_account_bech32_hrp().await?;
+            }
+        }
+
+        Ok(wallet)
+    }
+}
@@ -0,0 +1,245 @@
+// Copyright 2024 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use std::{collections::HashMap, sync::atomic::Ordering, time::Duration};
+
+use tokio::time::sleep;
+
+#[cfg(feature = "events")]
+use crate::wallet::events::types::{TransactionReissueEvent, TransactionReissueState, WalletEvent};
+use crate::{
+    client::{node_api::error::Error as NodeApiError, secret::SecretManage, Error as ClientError},
+    types::block::{
+        input::Input,
+        payload::transaction::{TransactionEssence, TransactionId},
+    },
+    utils::unix_timestamp_now,
+    wallet::{Account, Wallet},
+};
+
+/// The default interval in which the inclusion monitor checks pending transactions
+pub(crate) const DEFAULT_INCLUSION_MONITOR_INTERVAL: Duration = Duration::from_secs(10);
+/// The minimum interval in which the inclusion monitor checks pending transactions, so it doesn't flood the node
+pub(crate) const MIN_INCLUSION_MONITOR_INTERVAL: Duration = Duration::from_secs(1);
+/// The default time after which a pending transaction gets reissued
+pub(crate) const DEFAULT_INCLUSION_MONITOR_TIMEOUT: Duration = Duration::from_secs(60);
+/// The default amount of reissues before a transaction is given up
+pub(crate) const DEFAULT_INCLUSION_MONITOR_MAX_REISSUES: u32 = 5;
+
+/// Options for the inclusion monitor.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct InclusionMonitorOptions {
+    /// Interval in which the pending transactions of all accounts are checked, at least one second.
+    pub interval: Duration,
+    /// Time after which a pending transaction, that isn't included yet, gets reissued in a new block.
+    pub timeout: Duration,
+    /// Maximum amount of reissues of a transaction, before it's given up.
+    pub max_reissues: u32,
+}
+
+impl Default for InclusionMonitorOptions {
+    fn default() -> Self {
+        Self {
+            interval: DEFAULT_INCLUSION_MONITOR_INTERVAL,
+            timeout: DEFAULT_INCLUSION_MONITOR_TIMEOUT,
+            max_reissues: DEFAULT_INCLUSION_MONITOR_MAX_REISSUES,
+        }
+    }
+}
+
+// Reissue state of a single pending transaction
+#[derive(Debug, Clone, Copy)]
+struct ReissueTracker {
+    attempts: u32,
+    // Time in milliseconds when the transaction was last issued
+    last_issued: u128,
+    given_up: bool,
+}
+
+impl<S: 'static + SecretManage> Wallet<S>
+where
+    crate::wallet::Error: From<S::Error>,
+{
+    /// Start the inclusion monitor, which reissues pending transactions of all accounts that didn't get included in
+    /// time in a new block with the same payload, default interval is 10 seconds
+    pub async fn start_inclusion_monitor(&self, options: Option<InclusionMonitorOptions>) -> crate::wallet::Result<()> {
+        log::debug!("[start_inclusion_monitor]");
+        self.check_cancelled(None)?;
+        // stop existing process if running
+        if self.inclusion_monitor_status.load(Ordering::Relaxed) == 1 {
+            self.inclusion_monitor_status.store(2, Ordering::Relaxed);
+        };
+        while self.inclusion_monitor_status.load(Ordering::Relaxed) == 2 {
+            log::debug!("[inclusion_monitor]: waiting for the old process to stop");
+            sleep(Duration::from_secs(1)).await;
+        }
+
+        self.inclusion_monitor_status.store(1, Ordering::Relaxed);
+        let options = options.unwrap_or_default();
+        let wallet = self.clone();
+        let _inclusion_monitor = std::thread::spawn(move || {
+            #[cfg(not(target_family = "wasm"))]
+            let runtime = tokio::runtime::Builder::new_multi_thread()
+                .enable_all()
+                .build()
+                .unwrap();
+            #[cfg(target_family = "wasm")]
+            let runtime = tokio::runtime::Builder::new_current_thread()
+                .enable_all()
+                .build()
+                .unwrap();
+            runtime.block_on(async {
+                let mut trackers = HashMap::new();
+                'outer: loop {
+                    log::debug!("[inclusion_monitor]: checking pending transactions");
+                    for account in wallet.accounts.read().await.iter() {
+                        // Check if the process should stop before each account so it stops faster
+                        if wallet.inclusion_monitor_status.load(Ordering::Relaxed) == 2 {
+                            log::debug!("[inclusion_monitor]: stopping");
+                            break 'outer;
+                        }
+                        let account_trackers = trackers.entry(*account.details().await.index()).or_default();
+                        if let Err(err) = reissue_pending_transactions(account, &options, account_trackers).await {
+                            log::debug!("[inclusion_monitor] error: {}", err);
+                        }
+                    }
+                    // split interval to seconds so stopping the process doesn't have to wait long
+                    let mut remaining = options.interval.max(MIN_INCLUSION_MONITOR_INTERVAL);
+                    while !remaining.is_zero() {
+                        if wallet.inclusion_monitor_status.load(Ordering::Relaxed) == 2 {
+                            log::debug!("[inclusion_monitor]: stopping");
+                            break 'outer;
+                        }
+                        let step = remaining.min(Duration::from_secs(1));
+                        sleep(step).await;
+                        remaining -= step;
+                    }
+                }
+                wallet.inclusion_monitor_status.store(0, Ordering::Relaxed);
+                log::debug!("[inclusion_monitor]: stopped");
+            });
+        });
+        Ok(())
+    }
+
+    /// Stop the inclusion monitor
+    pub async fn stop_inclusion_monitor(&self) -> crate::wallet::Result<()> {
+        log::debug!("[stop_inclusion_monitor]");
+        // immediately return if not running
+        if self.inclusion_monitor_status.load(Ordering::Relaxed) == 0 {
+            return Ok(());
+        }
+        // send stop request
+        self.inclusion_monitor_status.store(2, Ordering::Relaxed);
+        // wait until it stopped
+        while self.inclusion_monitor_status.load(Ordering::Relaxed) != 0 {
+            #[cfg(target_family = "wasm")]
+            gloo_timers::future::TimeoutFuture::new(10).await;
+            #[cfg(not(target_family = "wasm"))]
+            tokio::time::sleep(std::time::Duration::from_millis(10)).await;
+        }
+        Ok(())
+    }
+}
+
+// Reissues the pending transactions of an account which weren't included within the timeout. The inclusion state
+// itself is still updated by syncing.
+async fn reissue_pending_transactions<S: 'static + SecretManage>(
+    account: &Account<S>,
+    options: &InclusionMonitorOptions,
+    trackers: &mut HashMap<TransactionId, ReissueTracker>,
+) -> crate::wallet::Result<()>
+where
+    crate::wallet::Error: From<S::Error>,
+{
+    let network_id = account.client().get_network_id().await?;
+    let pending_transactions = {
+        let account_details = account.details().await;
+        // Forget transactions which aren't pending anymore
+        trackers.retain(|transaction_id, _| account_details.pending_transactions().contains(transaction_id));
+        account_details
+            .pending_transactions()
+            .iter()
+            .filter_map(|transaction_id| account_details.transactions().get(transaction_id))
+            .filter(|transaction| transaction.network_id == network_id)
+            .filter(|transaction| {
+                // Transactions with spent inputs can't be included anymore, syncing will mark them as conflicting
+                let TransactionEssence::Regular(essence) = transaction.payload.essence();
+                !essence.inputs().iter().any(|input| {
+                    let Input::Utxo(input) = input else { return false };
+                    account_details
+                        .outputs()
+                        .get(input.output_id())
+                        .is_some_and(|output_data| output_data.is_spent)
+                })
+            })
+            .cloned()
+            .collect::<Vec<_>>()
+    };
+
+    for transaction in pending_transactions {
+        let transaction_id = transaction.transaction_id;
+        let tracker = trackers.entry(transaction_id).or_insert(ReissueTracker {
+            attempts: 0,
+            last_issued: transaction.timestamp,
+            given_up: false,
+        });
+
+        if tracker.given_up || unix_timestamp_now().as_millis() < tracker.last_issued + options.timeout.as_millis() {
+            continue;
+        }
+
+        // Skip transactions that got included in the meantime, but weren't synced yet
+        if let Some(block_id) = transaction.block_id {
+            match account.client().get_block_metadata(&block_id).await {
+                Ok(metadata) if metadata.ledger_inclusion_state.is_some() => continue,
+                Ok(_) | Err(ClientError::Node(NodeApiError::NotFound(_))) => {}
+                Err(e) => return Err(e.into()),
+            }
+        }
+
+        if tracker.attempts >= options.max_reissues {
+            log::debug!("[inclusion_monitor] giving up transaction {transaction_id}");
+            tracker.given_up = true;
+            #[cfg(feature = "events")]
+            account
+                .emit(
+                    *account.details().await.index(),
+                    WalletEvent::TransactionReissue(TransactionReissueEvent {
+                        transaction_id,
+                        reissue_state: TransactionReissueState::GivenUp,
+                    }),
+                )
+                .await;
+            continue;
+        }
+
+        let block_id = account.submit_transaction_payload(transaction.payload.clone()).await?;
+        log::debug!("[inclusion_monitor] reissued transaction {transaction_id} in block {block_id}");
+        tracker.attempts += 1;
+        tracker.last_issued = unix_timestamp_now().as_millis();
+        #[cfg(feature = "events")]
+        let attempt = tracker.attempts;
+
+        if !account
+            .update_pending_transaction_block_id(&transaction_id, block_id)
+            .await?
+        {
+            log::debug!("[inclusion_monitor] transaction {transaction_id} isn't pending anymore");
+            continue;
+        }
+
+        #[cfg(feature = "events")]
+        account
+            .emit(
+                *account.details().await.index(),
+                WalletEvent::TransactionReissue(TransactionReissueEvent {
+                    transaction_id,
+                    reissue_state: TransactionReissueState::Reissued { block_id, attempt },
+                }),
+            )
+            .await;
+    }
+
+    Ok(())
+}
@@ -0,0 +1,71 @@
+// Copyright 2024 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use std::collections::HashMap;
+
+use crate::{
+    client::secret::SecretManage,
+    types::block::output::ChainId,
+    wallet::{Error, Wallet},
+};
+
+impl<S: 'static + SecretManage> Wallet<S>
+where
+    crate::wallet::Error: From<S::Error>,
+{
+    /// Sets a local label for an alias, foundry or NFT id, replacing a previous one. Labels are only known to this
+    /// wallet and stored with it.
+    /// ```ignore
+    /// wallet.set_label(nft_id, "concert ticket").await?;
+    /// ```
+    pub async fn set_label(
+        &self,
+        chain_id: impl Into<ChainId> + Send,
+        label: impl Into<String> + Send,
+    ) -> crate::wallet::Result<()> {
+        let (chain_id, label) = (chain_id.into(), label.into());
+        if chain_id.is_null() {
+            return Err(Error::CustomInput("can't label a null chain id".to_string()));
+        }
+        if label.trim().is_empty() {
+            return Err(Error::CustomInput("label can't be empty".to_string()));
+        }
+
+        let mut chain_labels = self.chain_labels.write().await;
+        chain_labels.insert(chain_id, label);
+        #[cfg(feature = "storage")]
+        self.storage_manager
+            .read()
+            .await
+            .set_chain_labels(&chain_labels)
+            .await?;
+
+        Ok(())
+    }
+
+    /// Removes the label of an alias, foundry or NFT id and returns it, if there was one.
+    pub async fn remove_label(&self, chain_id: impl Into<ChainId> + Send) -> crate::wallet::Result<Option<String>> {
+        let mut chain_labels = self.chain_labels.write().await;
+        let label = chain_labels.remove(&chain_id.into());
+        #[cfg(feature = "storage")]
+        if label.is_some() {
+            self.storage_manager
+                .read()
+                .await
+                .set_chain_labels(&chain_labels)
+                .await?;
+        }
+
+        Ok(label)
+    }
+
+    /// Returns the label of an alias, foundry or NFT id, if it has one.
+    pub async fn label(&self, chain_id: impl Into<ChainId> + Send) -> Option<String> {
+        self.chain_labels.read().await.get(&chain_id.into()).cloned()
+    }
+
+    /// Returns all labels of alias, foundry and NFT ids.
+    pub async fn labels(&self) -> HashMap<ChainId, String> {
+        self.chain_labels.read().await.clone()
+    }
+}
@@ -0,0 +1,36 @@
+// Copyright 2024 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::{
+    client::secret::SecretManage,
+    wallet::{
+        account::{types::Balance, SyncOptions},
+        Wallet,
+    },
+};
+
+impl<S: 'static + SecretManage> Wallet<S>
+where
+    crate::wallet::Error: From<S::Error>,
+{
+    /// Discards the outputs and transactions of all accounts and syncs them from the node again, to recover from a
+    /// corrupted local state. See [`Account::rebuild_ledger_from_history()`](crate::wallet::Account) for what can be
+    /// recovered. [`LedgerRebuildEvent`](crate::wallet::events::types::LedgerRebuildEvent)s are emitted for each
+    /// account.
+    ///
+    /// Returns the balances of the accounts, in the order of the accounts.
+    pub async fn rebuild_ledger_from_history(
+        &self,
+        options: Option<SyncOptions>,
+    ) -> crate::wallet::Result<Vec<Balance>> {
+        log::debug!("[rebuild_ledger_from_history]");
+        let accounts = self.accounts.read().await.clone();
+
+        let mut balances = Vec::with_capacity(accounts.len());
+        for account in accounts {
+            balances.push(account.rebuild_ledger_from_history(options.clone()).await?);
+        }
+
+        Ok(balances)
+    }
+}
@@ -5,7 +5,19 @@ pub(crate) mod account_recovery;
 pub(crate) mod address_generation;
 pub(crate) mod background_syncing;
 pub(crate) mod client;
+#[cfg(feature = "storage")]
+pub(crate) mod clone;
 pub(crate) mod get_account;
+pub(crate) mod inclusion_monitor;
+pub(crate) mod labels;
+pub(crate) mod ledger_rebuild;
+pub(crate) mod network_validation;
+pub(crate) mod privacy_report;
+pub(crate) mod quarantine;
+pub(crate) mod roles;
+pub(crate) mod send_defaults;
+pub(crate) mod shutdown;
+pub(crate) mod state_json;
 #[cfg(feature = "ledger_nano")]
 pub(crate) mod ledger_nano;
 pub(crate) mod storage;
@@ -13,5 +25,6 @@ pub(crate) mod storage;
 pub(crate) mod stronghold;
 #[cfg(feature = "stronghold")]
 pub(crate) mod stronghold_backup;
+pub(crate) mod transaction_approval;
 #[cfg(debug_assertions)]
 pub(crate) mod verify_integrity;
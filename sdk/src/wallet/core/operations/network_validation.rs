@@ -0,0 +1,82 @@
+// Copyright 2024 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    client::{
+        constants::{
+            IOTA_BECH32_HRP, IOTA_COIN_TYPE, IOTA_TESTNET_BECH32_HRP, SHIMMER_BECH32_HRP, SHIMMER_COIN_TYPE,
+            SHIMMER_TESTNET_BECH32_HRP,
+        },
+        secret::SecretManage,
+    },
+    types::block::address::Hrp,
+    wallet::{core::WalletInner, Account, Error},
+};
+
+/// How the wallet reacts if the network of the node doesn't match the coin type or the addresses of its accounts.
+#[derive(Debug, Default, Clone, Copy, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum NetworkValidation {
+    /// Fail with [`Error::NetworkMismatch`].
+    Strict,
+    /// Log a warning and continue.
+    #[default]
+    Warn,
+    /// Don't validate the network, for example to intentionally move the accounts to another network.
+    Disabled,
+}
+
+/// Returns the coin type used on the network with the bech32 HRP, `None` for unknown networks.
+fn network_coin_type(bech32_hrp: &Hrp) -> Option<u32> {
+    if [IOTA_BECH32_HRP, IOTA_TESTNET_BECH32_HRP].contains(bech32_hrp) {
+        Some(IOTA_COIN_TYPE)
+    } else if [SHIMMER_BECH32_HRP, SHIMMER_TESTNET_BECH32_HRP].contains(bech32_hrp) {
+        Some(SHIMMER_COIN_TYPE)
+    } else {
+        None
+    }
+}
+
+impl<S: SecretManage> WalletInner<S> {
+    /// Validates that the network with the bech32 HRP matches the coin type and the HRP of the existing account
+    /// addresses, according to the [`NetworkValidation`] of the wallet.
+    pub(crate) fn validate_network(
+        &self,
+        bech32_hrp: Hrp,
+        coin_type: u32,
+        accounts_bech32_hrp: Option<Hrp>,
+    ) -> crate::wallet::Result<()> {
+        let reason = if network_coin_type(&bech32_hrp).is_some_and(|network_coin_type| network_coin_type != coin_type) {
+            format!("the coin type {coin_type} is used on another network")
+        } else if let Some(accounts_bech32_hrp) = accounts_bech32_hrp.filter(|hrp| hrp != &bech32_hrp) {
+            format!("the accounts use the bech32 HRP {accounts_bech32_hrp}")
+        } else {
+            return Ok(());
+        };
+
+        match self.network_validation {
+            NetworkValidation::Strict => Err(Error::NetworkMismatch { bech32_hrp, reason }),
+            NetworkValidation::Warn => {
+                log::warn!(
+                    "[validate_network] network with bech32 HRP {bech32_hrp} doesn't match the wallet: {reason}"
+                );
+                Ok(())
+            }
+            NetworkValidation::Disabled => Ok(()),
+        }
+    }
+
+    /// Validates that the network of the client matches the existing accounts.
+    pub(crate) async fn validate_accounts_network(&self, accounts: &[Account<S>]) -> crate::wallet::Result<()> {
+        let Some(account) = accounts.first() else {
+            return Ok(());
+        };
+        let bech32_hrp = self.client.get_bech32_hrp().await?;
+        let details = account.details().await;
+        let accounts_bech32_hrp = details.public_addresses().first().map(|address| address.address.hrp);
+
+        self.validate_network(bech32_hrp, *details.coin_type(), accounts_bech32_hrp)
+    }
+}
@@ -0,0 +1,231 @@
+// Copyright 2024 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use std::collections::{BTreeMap, BTreeSet, HashSet};
+
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    client::secret::SecretManage,
+    types::block::{
+        address::{Address, Bech32Address, Hrp},
+        input::Input,
+        output::Output,
+        payload::transaction::{TransactionEssence, TransactionId},
+    },
+    wallet::{account::AccountDetails, Wallet},
+};
+
+// Amounts which are a multiple of this are considered round, it's one coin for tokens with 6 decimals.
+const ROUND_AMOUNT: u64 = 1_000_000;
+
+/// Privacy issues found in the stored transactions of a wallet, see [`Wallet::privacy_report()`].
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PrivacyReport {
+    /// The found issues, ordered by account.
+    pub warnings: Vec<PrivacyWarning>,
+}
+
+impl PrivacyReport {
+    /// Returns whether no issues were found.
+    pub fn is_empty(&self) -> bool {
+        self.warnings.is_empty()
+    }
+}
+
+/// A privacy issue found in the stored transactions of an account.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "camelCase")]
+pub enum PrivacyWarning {
+    /// An address received outputs in multiple transactions, so everyone can link these transactions to the same
+    /// owner.
+    #[serde(rename_all = "camelCase")]
+    AddressReuse {
+        /// The index of the account owning the address.
+        account_index: u32,
+        /// The reused address.
+        address: Bech32Address,
+        /// The transactions which created outputs on the address.
+        transaction_ids: Vec<TransactionId>,
+    },
+    /// A transaction sent a round amount and returned a remainder which isn't round, which reveals which of the outputs
+    /// is the remainder.
+    #[serde(rename_all = "camelCase")]
+    RoundAmount {
+        /// The index of the sending account.
+        account_index: u32,
+        /// The transaction sending the round amount.
+        transaction_id: TransactionId,
+        /// The index of the output with the round amount in the transaction.
+        output_index: u16,
+        /// The round amount.
+        #[serde(with = "crate::utils::serde::string")]
+        amount: u64,
+    },
+    /// Outputs of multiple addresses were spent in the same transactions, so everyone can link these addresses to the
+    /// same owner.
+    #[serde(rename_all = "camelCase")]
+    CoSpendCluster {
+        /// The index of the account owning the addresses.
+        account_index: u32,
+        /// The linked addresses.
+        addresses: Vec<Bech32Address>,
+        /// The transactions which spent outputs of multiple of the addresses.
+        transaction_ids: Vec<TransactionId>,
+    },
+}
+
+impl PrivacyWarning {
+    /// Returns the index of the account the issue was found in.
+    pub fn account_index(&self) -> u32 {
+        match self {
+            Self::AddressReuse { account_index, .. }
+            | Self::RoundAmount { account_index, .. }
+            | Self::CoSpendCluster { account_index, .. } => *account_index,
+        }
+    }
+
+    /// Returns what can be done to avoid the issue in the future.
+    pub fn recommendation(&self) -> &'static str {
+        match self {
+            Self::AddressReuse { .. } => {
+                "Generate a new address for every payment you receive and send remainders to a new address with \
+                 `RemainderValueStrategy::ChangeAddress`."
+            }
+            Self::RoundAmount { .. } => {
+                "Avoid sending round amounts, or send the remainder to a new address so it can't be linked to other \
+                 outputs."
+            }
+            Self::CoSpendCluster { .. } => {
+                "Spend outputs of a single address per transaction with `CoinSelectionStrategy::SingleAddressOnly`."
+            }
+        }
+    }
+}
+
+impl<S: 'static + SecretManage> Wallet<S>
+where
+    crate::wallet::Error: From<S::Error>,
+{
+    /// Analyzes the stored transactions of all accounts for address reuse, round amounts and addresses linked by
+    /// spending their outputs together. Only the locally stored data is used, so the accounts should be synced first.
+    pub async fn privacy_report(&self) -> crate::wallet::Result<PrivacyReport> {
+        let bech32_hrp = self.client().get_bech32_hrp().await?;
+        let mut report = PrivacyReport::default();
+
+        for account in self.get_accounts().await? {
+            let account_details = account.details().await;
+            report
+                .warnings
+                .extend(account_privacy_warnings(&account_details, bech32_hrp));
+        }
+
+        Ok(report)
+    }
+}
+
+fn account_privacy_warnings(account: &AccountDetails, bech32_hrp: Hrp) -> Vec<PrivacyWarning> {
+    let account_index = *account.index();
+    let account_addresses = account
+        .addresses()
+        .into_iter()
+        .map(|address| *address.address().inner())
+        .collect::<HashSet<Address>>();
+    let mut warnings = Vec::new();
+
+    let mut receiving_transactions = BTreeMap::<Address, BTreeSet<TransactionId>>::new();
+    for output_data in account.outputs().values() {
+        receiving_transactions
+            .entry(output_data.address)
+            .or_default()
+            .insert(*output_data.output_id.transaction_id());
+    }
+    for (address, transaction_ids) in receiving_transactions {
+        if transaction_ids.len() > 1 {
+            warnings.push(PrivacyWarning::AddressReuse {
+                account_index,
+                address: Bech32Address::new(bech32_hrp, address),
+                transaction_ids: transaction_ids.into_iter().collect(),
+            });
+        }
+    }
+
+    let mut sent_transactions = account
+        .transactions()
+        .values()
+        .filter(|transaction| !transaction.incoming)
+        .collect::<Vec<_>>();
+    sent_transactions.sort_by_key(|transaction| transaction.timestamp);
+
+    // Each cluster is a set of linked addresses with the transactions linking them
+    let mut clusters = Vec::<(BTreeSet<Address>, BTreeSet<TransactionId>)>::new();
+
+    for transaction in sent_transactions {
+        let TransactionEssence::Regular(essence) = transaction.payload.essence();
+
+        let input_addresses = essence
+            .inputs()
+            .iter()
+            .filter_map(|input| match input {
+                Input::Utxo(input) => account.outputs().get(input.output_id()),
+                Input::Treasury(_) => None,
+            })
+            .map(|output_data| output_data.address)
+            .collect::<BTreeSet<Address>>();
+        if input_addresses.len() > 1 {
+            let mut cluster = (input_addresses, BTreeSet::from([transaction.transaction_id]));
+            // Merge all clusters sharing an address with the new one
+            clusters.retain(|(addresses, transaction_ids)| {
+                if addresses.is_disjoint(&cluster.0) {
+                    true
+                } else {
+                    cluster.0.extend(addresses);
+                    cluster.1.extend(transaction_ids);
+                    false
+                }
+            });
+            clusters.push(cluster);
+        }
+
+        let is_remainder = |output: &Output| {
+            output
+                .unlock_conditions()
+                .and_then(|unlock_conditions| unlock_conditions.address())
+                .is_some_and(|address| account_addresses.contains(address.address()))
+        };
+        let has_unround_remainder = essence
+            .outputs()
+            .iter()
+            .any(|output| is_remainder(output) && output.amount() % ROUND_AMOUNT != 0);
+        if has_unround_remainder {
+            for (output_index, output) in essence.outputs().iter().enumerate() {
+                // Outputs with native tokens usually only hold the required storage deposit
+                let is_round_payment = matches!(output, Output::Basic(basic) if basic.native_tokens().is_empty())
+                    && !is_remainder(output)
+                    && output.amount() % ROUND_AMOUNT == 0;
+                if is_round_payment {
+                    warnings.push(PrivacyWarning::RoundAmount {
+                        account_index,
+                        transaction_id: transaction.transaction_id,
+                        output_index: output_index as u16,
+                        amount: output.amount(),
+                    });
+                }
+            }
+        }
+    }
+
+    warnings.extend(clusters.into_iter().map(|(addresses, transaction_ids)| {
+        PrivacyWarning::CoSpendCluster {
+            account_index,
+            addresses: addresses
+                .into_iter()
+                .map(|address| Bech32Address::new(bech32_hrp, address))
+                .collect(),
+            transaction_ids: transaction_ids.into_iter().collect(),
+        }
+    }));
+
+    warnings
+}
@@ -0,0 +1,29 @@
+// Copyright 2024 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use std::collections::BTreeMap;
+
+use crate::{
+    client::secret::SecretManage,
+    wallet::{account::types::OutputData, Wallet},
+};
+
+impl<S: 'static + SecretManage> Wallet<S>
+where
+    crate::wallet::Error: From<S::Error>,
+{
+    /// Returns the quarantined spam outputs of the accounts by account index, accounts without quarantined outputs
+    /// are left out. See [`Account::quarantined_outputs()`](crate::wallet::Account::quarantined_outputs) for how they
+    /// can be released or burned.
+    pub async fn quarantined_outputs(&self) -> BTreeMap<u32, Vec<OutputData>> {
+        let mut quarantined_outputs = BTreeMap::new();
+        for account in self.accounts.read().await.iter() {
+            let outputs = account.quarantined_outputs().await;
+            if !outputs.is_empty() {
+                quarantined_outputs.insert(*account.details().await.index(), outputs);
+            }
+        }
+
+        quarantined_outputs
+    }
+}
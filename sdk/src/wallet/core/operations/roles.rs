@@ -0,0 +1,329 @@
+// Copyright 2024 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+//! Facades splitting the wallet API into reading, proposing and signing, so each can be given to a different
+//! component. A component only holding a [`ProposalBuilder`] can't sign, one only holding a [`Signer`] can't
+//! build outputs.
+
+use std::sync::Arc;
+
+use tokio::sync::RwLock;
+
+use crate::{
+    client::{
+        api::{transaction::validate_transaction_payload_length, PreparedTransactionData, SignedTransactionData},
+        secret::SecretManage,
+    },
+    types::block::{
+        input::Input,
+        output::{InputsCommitment, Output},
+        payload::{transaction::TransactionEssence, TransactionPayload},
+    },
+    wallet::{
+        account::{
+            types::{AccountAddress, AccountIdentifier, Balance, OutputData, Transaction},
+            FilterOptions, SyncOptions, TransactionOptions,
+        },
+        core::TransactionApprover,
+        Error, SendNativeTokensParams, SendNftParams, SendParams, Wallet,
+    },
+};
+
+/// Read-only access to the accounts of a wallet.
+#[derive(Debug)]
+pub struct WalletReader<S: SecretManage> {
+    wallet: Wallet<S>,
+}
+
+impl<S: SecretManage> Clone for WalletReader<S> {
+    fn clone(&self) -> Self {
+        Self {
+            wallet: self.wallet.clone(),
+        }
+    }
+}
+
+impl<S: 'static + SecretManage> WalletReader<S>
+where
+    crate::wallet::Error: From<S::Error>,
+{
+    /// Returns the balance of all accounts.
+    pub async fn balance(&self) -> crate::wallet::Result<Balance> {
+        self.wallet.balance().await
+    }
+
+    /// Returns the balance of an account.
+    pub async fn account_balance(
+        &self,
+        account: impl Into<AccountIdentifier> + Send,
+    ) -> crate::wallet::Result<Balance> {
+        self.wallet.get_account(account).await?.balance().await
+    }
+
+    /// Syncs an account with the node, which only updates the local state.
+    pub async fn sync(
+        &self,
+        account: impl Into<AccountIdentifier> + Send,
+        options: Option<SyncOptions>,
+    ) -> crate::wallet::Result<Balance> {
+        self.wallet.get_account(account).await?.sync(options).await
+    }
+
+    /// Returns the addresses of an account.
+    pub async fn addresses(
+        &self,
+        account: impl Into<AccountIdentifier> + Send,
+    ) -> crate::wallet::Result<Vec<AccountAddress>> {
+        self.wallet.get_account(account).await?.addresses().await
+    }
+
+    /// Returns the unspent outputs of an account.
+    pub async fn unspent_outputs(
+        &self,
+        account: impl Into<AccountIdentifier> + Send,
+        filter: impl Into<Option<FilterOptions>> + Send,
+    ) -> crate::wallet::Result<Vec<OutputData>> {
+        self.wallet.get_account(account).await?.unspent_outputs(filter).await
+    }
+
+    /// Returns the transactions of an account.
+    pub async fn transactions(
+        &self,
+        account: impl Into<AccountIdentifier> + Send,
+    ) -> crate::wallet::Result<Vec<Transaction>> {
+        Ok(self.wallet.get_account(account).await?.transactions().await)
+    }
+
+    /// Returns the pending transactions of an account.
+    pub async fn pending_transactions(
+        &self,
+        account: impl Into<AccountIdentifier> + Send,
+    ) -> crate::wallet::Result<Vec<Transaction>> {
+        Ok(self.wallet.get_account(account).await?.pending_transactions().await)
+    }
+}
+
+/// Prepares transactions of the accounts of a wallet and submits them once they were signed by a [`Signer`].
+///
+/// It never signs, so the wallet it's created from can use a placeholder secret manager, as long as the addresses of
+/// its accounts were already generated.
+#[derive(Debug)]
+pub struct ProposalBuilder<S: SecretManage> {
+    wallet: Wallet<S>,
+}
+
+impl<S: SecretManage> Clone for ProposalBuilder<S> {
+    fn clone(&self) -> Self {
+        Self {
+            wallet: self.wallet.clone(),
+        }
+    }
+}
+
+impl<S: 'static + SecretManage> ProposalBuilder<S>
+where
+    crate::wallet::Error: From<S::Error>,
+{
+    /// Returns read-only access to the same wallet.
+    pub fn reader(&self) -> WalletReader<S> {
+        self.wallet.reader()
+    }
+
+    /// Prepares a transaction sending base coins, see
+    /// [`Account::send_with_params()`](crate::wallet::Account::send_with_params).
+    pub async fn prepare_send(
+        &self,
+        account: impl Into<AccountIdentifier> + Send,
+        params: Vec<SendParams>,
+        options: impl Into<Option<TransactionOptions>> + Send,
+    ) -> crate::wallet::Result<PreparedTransactionData> {
+        self.wallet
+            .get_account(account)
+            .await?
+            .prepare_send(params, options)
+            .await
+    }
+
+    /// Prepares a transaction sending native tokens, see
+    /// [`Account::send_native_tokens()`](crate::wallet::Account::send_native_tokens).
+    pub async fn prepare_send_native_tokens(
+        &self,
+        account: impl Into<AccountIdentifier> + Send,
+        params: Vec<SendNativeTokensParams>,
+        options: impl Into<Option<TransactionOptions>> + Send,
+    ) -> crate::wallet::Result<PreparedTransactionData> {
+        self.wallet
+            .get_account(account)
+            .await?
+            .prepare_send_native_tokens(params, options)
+            .await
+    }
+
+    /// Prepares a transaction sending NFTs, see [`Account::send_nft()`](crate::wallet::Account::send_nft).
+    pub async fn prepare_send_nft(
+        &self,
+        account: impl Into<AccountIdentifier> + Send,
+        params: Vec<SendNftParams>,
+        options: impl Into<Option<TransactionOptions>> + Send,
+    ) -> crate::wallet::Result<PreparedTransactionData> {
+        self.wallet
+            .get_account(account)
+            .await?
+            .prepare_send_nft(params, options)
+            .await
+    }
+
+    /// Prepares a transaction creating the provided outputs.
+    pub async fn prepare_transaction(
+        &self,
+        account: impl Into<AccountIdentifier> + Send,
+        outputs: Vec<Output>,
+        options: impl Into<Option<TransactionOptions>> + Send,
+    ) -> crate::wallet::Result<PreparedTransactionData> {
+        self.wallet
+            .get_account(account)
+            .await?
+            .prepare_transaction(outputs, options)
+            .await
+    }
+
+    /// Submits a transaction signed by a [`Signer`] and stores it in the account which prepared it.
+    pub async fn submit_transaction(
+        &self,
+        account: impl Into<AccountIdentifier> + Send,
+        signed_transaction_data: SignedTransactionData,
+        options: impl Into<Option<TransactionOptions>> + Send,
+    ) -> crate::wallet::Result<Transaction> {
+        self.wallet
+            .get_account(account)
+            .await?
+            .submit_and_store_transaction(signed_transaction_data, options)
+            .await
+    }
+}
+
+/// Signs transactions prepared by a [`ProposalBuilder`], without access to the accounts or the node.
+#[derive(Debug)]
+pub struct Signer<S: SecretManage> {
+    secret_manager: Arc<RwLock<S>>,
+    transaction_approver: Option<Arc<dyn TransactionApprover>>,
+}
+
+impl<S: SecretManage> Clone for Signer<S> {
+    fn clone(&self) -> Self {
+        Self {
+            secret_manager: self.secret_manager.clone(),
+            transaction_approver: self.transaction_approver.clone(),
+        }
+    }
+}
+
+impl<S: 'static + SecretManage> Signer<S>
+where
+    crate::wallet::Error: From<S::Error>,
+{
+    /// Creates a [`Signer`] using the provided secret manager.
+    pub fn new(secret_manager: Arc<RwLock<S>>) -> Self {
+        Self {
+            secret_manager,
+            transaction_approver: None,
+        }
+    }
+
+    /// Sets a handler which has to approve every transaction before it gets signed, see
+    /// [`Wallet::set_approval_handler()`].
+    pub fn with_approval_handler(mut self, approver: impl TransactionApprover + 'static) -> Self {
+        self.transaction_approver = Some(Arc::new(approver));
+        self
+    }
+
+    /// Signs a prepared transaction after checking that its inputs data matches the inputs of the essence, so the
+    /// signed inputs are the ones the secret manager was told about. The approval handler is asked with the account
+    /// index of the first signed input.
+    pub async fn sign_transaction(
+        &self,
+        prepared_transaction_data: &PreparedTransactionData,
+    ) -> crate::wallet::Result<SignedTransactionData> {
+        log::debug!("[SIGNER] sign_transaction");
+        verify_proposal(prepared_transaction_data)?;
+
+        if let Some(transaction_approver) = &self.transaction_approver {
+            let account_index = prepared_transaction_data
+                .inputs_data
+                .iter()
+                .find_map(|input| input.chain)
+                .map_or(0, |chain| chain.account);
+            if !transaction_approver
+                .approve_transaction(account_index, prepared_transaction_data)
+                .await
+            {
+                log::debug!("[SIGNER] transaction rejected by the approval handler");
+                return Err(Error::TransactionRejected);
+            }
+        }
+
+        let unlocks = self
+            .secret_manager
+            .read()
+            .await
+            .sign_transaction_essence(prepared_transaction_data, None)
+            .await?;
+        let transaction_payload = TransactionPayload::new(prepared_transaction_data.essence.clone(), unlocks)?;
+
+        validate_transaction_payload_length(&transaction_payload)?;
+
+        Ok(SignedTransactionData {
+            transaction_payload,
+            inputs_data: prepared_transaction_data.inputs_data.clone(),
+        })
+    }
+}
+
+fn verify_proposal(prepared_transaction_data: &PreparedTransactionData) -> crate::wallet::Result<()> {
+    let TransactionEssence::Regular(essence) = &prepared_transaction_data.essence;
+
+    let input_ids = essence.inputs().iter().map(|input| match input {
+        Input::Utxo(utxo) => Some(utxo.output_id()),
+        Input::Treasury(_) => None,
+    });
+    let inputs_match = essence.inputs().len() == prepared_transaction_data.inputs_data.len()
+        && input_ids
+            .zip(&prepared_transaction_data.inputs_data)
+            .all(|(output_id, input_data)| output_id == Some(input_data.output_id()));
+    if !inputs_match {
+        return Err(Error::InvalidProposal("the inputs data doesn't match the inputs"));
+    }
+
+    let inputs_commitment = InputsCommitment::new(prepared_transaction_data.inputs_data.iter().map(|i| &i.output));
+    if &inputs_commitment != essence.inputs_commitment() {
+        return Err(Error::InvalidProposal(
+            "the inputs commitment doesn't match the inputs data",
+        ));
+    }
+
+    Ok(())
+}
+
+impl<S: 'static + SecretManage> Wallet<S>
+where
+    crate::wallet::Error: From<S::Error>,
+{
+    /// Returns a facade only allowing to read the accounts of the wallet.
+    pub fn reader(&self) -> WalletReader<S> {
+        WalletReader { wallet: self.clone() }
+    }
+
+    /// Returns a facade only allowing to prepare and submit transactions, not to sign them.
+    pub fn proposal_builder(&self) -> ProposalBuilder<S> {
+        ProposalBuilder { wallet: self.clone() }
+    }
+
+    /// Returns a facade only allowing to sign prepared transactions with the secret manager of the wallet, which asks
+    /// the approval handler the wallet has at this point.
+    pub async fn signer(&self) -> Signer<S> {
+        Signer {
+            secret_manager: self.secret_manager.clone(),
+            transaction_approver: self.transaction_approver.read().await.clone(),
+        }
+    }
+}
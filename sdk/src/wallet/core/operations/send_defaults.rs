@@ -0,0 +1,69 @@
+// Copyright 2024 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use serde::{Deserialize, Serialize};
+
+use super::storage::SaveLoadWallet;
+use crate::{
+    client::secret::SecretManage,
+    types::block::address::Bech32Address,
+    wallet::{Error, Wallet, WalletBuilder},
+};
+
+/// Wallet-wide defaults for the storage deposit return and expiration unlock conditions.
+///
+/// They're used for outputs whose amount doesn't cover their storage deposit, unless provided per call.
+#[derive(Debug, Default, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SendDefaults {
+    /// Bech32 encoded address to which the storage deposit is returned. Defaults to the first address of the sending
+    /// account.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub return_address: Option<Bech32Address>,
+    /// Expiration in seconds, after which the output is available for the sender again. Defaults to one day.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub expiration: Option<u32>,
+}
+
+impl<S: 'static + SecretManage> Wallet<S> {
+    /// Returns the wallet-wide send defaults.
+    pub async fn send_defaults(&self) -> SendDefaults {
+        self.send_defaults.read().await.clone()
+    }
+}
+
+impl<S: 'static + SecretManage> Wallet<S>
+where
+    crate::wallet::Error: From<S::Error>,
+    WalletBuilder<S>: SaveLoadWallet,
+{
+    /// Sets the wallet-wide defaults for the return address and expiration of outputs with a storage deposit return.
+    /// They are stored with the wallet.
+    /// ```ignore
+    /// wallet
+    ///     .set_send_defaults(SendDefaults {
+    ///         return_address: None,
+    ///         expiration: Some(60 * 60),
+    ///     })
+    ///     .await?;
+    /// ```
+    pub async fn set_send_defaults(&self, send_defaults: SendDefaults) -> crate::wallet::Result<()> {
+        log::debug!("[set_send_defaults]");
+        if send_defaults.expiration == Some(0) {
+            return Err(Error::CustomInput("expiration can't be zero".to_string()));
+        }
+        if let Some(return_address) = &send_defaults.return_address {
+            self.client().bech32_hrp_matches(return_address.hrp()).await?;
+        }
+        *self.send_defaults.write().await = send_defaults;
+
+        #[cfg(feature = "storage")]
+        {
+            WalletBuilder::from_wallet(self)
+                .await
+                .save(&*self.storage_manager.read().await)
+                .await?;
+        }
+        Ok(())
+    }
+}
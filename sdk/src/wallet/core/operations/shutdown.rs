@@ -0,0 +1,94 @@
+// Copyright 2024 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use std::sync::{
+    atomic::{AtomicBool, AtomicUsize, Ordering},
+    Arc,
+};
+
+use crate::{
+    client::secret::SecretManage,
+    wallet::{core::WalletInner, Error, Wallet},
+};
+
+/// A token to cancel long running operations, like syncing or bulk sending.
+///
+/// Operations check the token at points where they can stop without leaving the account partially updated, so
+/// they may still finish a request or a transaction in flight after it got cancelled.
+#[derive(Debug, Clone, Default)]
+pub struct CancellationToken(Arc<AtomicBool>);
+
+impl CancellationToken {
+    /// Creates a new [`CancellationToken`] which isn't cancelled.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Cancels the operations using this token, or clones of it.
+    pub fn cancel(&self) {
+        self.0.store(true, Ordering::Relaxed);
+    }
+
+    /// Returns whether the token got cancelled.
+    pub fn is_cancelled(&self) -> bool {
+        self.0.load(Ordering::Relaxed)
+    }
+}
+
+/// Counts a long running operation as in flight until it's dropped, so [`Wallet::shutdown()`] can wait for it.
+pub(crate) struct InFlightGuard<'a>(&'a AtomicUsize);
+
+impl Drop for InFlightGuard<'_> {
+    fn drop(&mut self) {
+        self.0.fetch_sub(1, Ordering::Relaxed);
+    }
+}
+
+impl<S: SecretManage> WalletInner<S> {
+    /// Registers a long running operation, fails if the wallet is shut down.
+    pub(crate) fn start_operation(&self) -> crate::wallet::Result<InFlightGuard<'_>> {
+        self.operations_in_flight.fetch_add(1, Ordering::Relaxed);
+        let guard = InFlightGuard(&self.operations_in_flight);
+        self.check_cancelled(None)?;
+        Ok(guard)
+    }
+
+    /// Returns [`Error::Cancelled`] if the token or the wallet got cancelled.
+    pub(crate) fn check_cancelled(&self, cancellation: Option<&CancellationToken>) -> crate::wallet::Result<()> {
+        if self.shutdown_token.is_cancelled() || cancellation.is_some_and(CancellationToken::is_cancelled) {
+            return Err(Error::Cancelled);
+        }
+        Ok(())
+    }
+
+    /// Returns whether [`Wallet::shutdown()`] was called.
+    pub fn is_shut_down(&self) -> bool {
+        self.shutdown_token.is_cancelled()
+    }
+}
+
+impl<S: 'static + SecretManage> Wallet<S>
+where
+    crate::wallet::Error: From<S::Error>,
+{
+    /// Cancels all long running operations, stops the background tasks and waits until the operations in flight
+    /// stopped, so the storage isn't left partially updated.
+    ///
+    /// Long running operations started afterwards fail with [`Error::Cancelled`].
+    pub async fn shutdown(&self) -> crate::wallet::Result<()> {
+        log::debug!("[shutdown]");
+        self.shutdown_token.cancel();
+        self.stop_background_syncing().await?;
+        self.stop_inclusion_monitor().await?;
+
+        while self.operations_in_flight.load(Ordering::Relaxed) != 0 {
+            log::debug!("[shutdown]: waiting for operations in flight");
+            #[cfg(target_family = "wasm")]
+            gloo_timers::future::TimeoutFuture::new(10).await;
+            #[cfg(not(target_family = "wasm"))]
+            tokio::time::sleep(std::time::Duration::from_millis(10)).await;
+        }
+        log::debug!("[shutdown]: finished");
+        Ok(())
+    }
+}
@@ -0,0 +1,206 @@
+// Copyright 2024 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use std::{collections::HashSet, sync::atomic::Ordering};
+
+use serde::{Deserialize, Serialize};
+use url::Url;
+
+use crate::{
+    client::{
+        node_manager::node::{Node, NodeDto},
+        secret::SecretManage,
+        ClientBuilder,
+    },
+    types::{block::output::ChainId, TryFromDto},
+    wallet::{
+        account::{AccountDetails, AccountDetailsDto, SyncOptions},
+        core::operations::storage::SaveLoadWallet,
+        Account, Error, Wallet, WalletBuilder,
+    },
+};
+
+/// The version of the [`WalletState`] schema, increased with every breaking change of it.
+pub const WALLET_STATE_VERSION: u8 = 1;
+
+/// The state of a wallet as returned by [`Wallet::dump_state_json()`].
+///
+/// It contains no secrets: neither the secret manager nor the credentials of the nodes are included.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct WalletState {
+    /// The version of the schema, [`WALLET_STATE_VERSION`] for states dumped by this SDK version
+    pub version: u8,
+    /// The coin type of the wallet
+    pub coin_type: u32,
+    /// The client options, without node credentials
+    pub client_options: ClientBuilder,
+    /// The local labels of alias, foundry and NFT ids, ordered by id
+    pub chain_labels: Vec<(ChainId, String)>,
+    /// The accounts, ordered by index
+    pub accounts: Vec<AccountState>,
+}
+
+/// The state of an account in a [`WalletState`].
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AccountState {
+    /// The addresses, outputs and transactions of the account
+    pub details: AccountDetailsDto,
+    /// The default sync options of the account
+    pub default_sync_options: SyncOptions,
+}
+
+// Removes the credentials of a node, in its auth options and its URLs
+fn redact_node(node: NodeDto) -> NodeDto {
+    match node {
+        NodeDto::Url(mut url) => {
+            redact_url(&mut url);
+            NodeDto::Url(url)
+        }
+        NodeDto::Node(mut node) => {
+            redact_url(&mut node.url);
+            if let Some(proxy) = &mut node.proxy {
+                redact_url(proxy);
+            }
+            NodeDto::Node(Node { auth: None, ..node })
+        }
+    }
+}
+
+fn redact_url(url: &mut Url) {
+    // Fails only for URLs which can't have credentials
+    url.set_username("").ok();
+    url.set_password(None).ok();
+}
+
+impl<S: 'static + SecretManage> Wallet<S>
+where
+    crate::wallet::Error: From<S::Error>,
+{
+    /// Returns the accounts, their default sync options, the labels and the client options of the wallet as JSON,
+    /// following the schema of [`WalletState`].
+    ///
+    /// Secrets are excluded, so the state can be attached to bug reports. With
+    /// [`Wallet::load_state_json()`] it can be moved to a wallet with another storage backend.
+    pub async fn dump_state_json(&self) -> crate::wallet::Result<serde_json::Value> {
+        log::debug!("[dump_state_json]");
+        let mut accounts = Vec::new();
+        for account in self.accounts.read().await.iter() {
+            accounts.push(AccountState {
+                details: AccountDetailsDto::from(&*account.details().await),
+                default_sync_options: account.default_sync_options().await,
+            });
+        }
+        accounts.sort_by_key(|account| account.details.index);
+
+        let mut chain_labels = self
+            .chain_labels
+            .read()
+            .await
+            .iter()
+            .map(|(chain_id, label)| (*chain_id, label.clone()))
+            .collect::<Vec<_>>();
+        chain_labels.sort();
+
+        let mut client_options = self.client_options().await;
+        let node_manager_builder = &mut client_options.node_manager_builder;
+        for node in [
+            &mut node_manager_builder.primary_node,
+            &mut node_manager_builder.primary_pow_node,
+            &mut node_manager_builder.issuer_node,
+        ] {
+            *node = node.take().map(redact_node);
+        }
+        node_manager_builder.nodes = node_manager_builder.nodes.drain().map(redact_node).collect();
+        node_manager_builder.permanodes = node_manager_builder.permanodes.drain().map(redact_node).collect();
+        if let Some(proxy) = &mut node_manager_builder.proxy {
+            redact_url(proxy);
+        }
+
+        Ok(serde_json::to_value(WalletState {
+            version: WALLET_STATE_VERSION,
+            coin_type: self.coin_type.load(Ordering::Relaxed),
+            client_options,
+            chain_labels,
+            accounts,
+        })?)
+    }
+
+    /// Loads the accounts, their default sync options, the labels and the coin type of a state returned by
+    /// [`Wallet::dump_state_json()`] into a wallet without accounts.
+    ///
+    /// The client options of the wallet are kept, as the state has no node credentials, but they need to be for the
+    /// network of the accounts.
+    pub async fn load_state_json(&self, state: serde_json::Value) -> crate::wallet::Result<()>
+    where
+        WalletBuilder<S>: SaveLoadWallet,
+    {
+        log::debug!("[load_state_json]");
+        let state = serde_json::from_value::<WalletState>(state)?;
+        if state.version != WALLET_STATE_VERSION {
+            return Err(Error::InvalidWalletState(format!(
+                "unsupported version {}, the supported version is {WALLET_STATE_VERSION}",
+                state.version
+            )));
+        }
+
+        let mut accounts = self.accounts.write().await;
+        if !accounts.is_empty() {
+            return Err(Error::InvalidWalletState(
+                "can't load a state when there are already accounts".to_string(),
+            ));
+        }
+
+        let mut account_indexes = HashSet::new();
+        for account in &state.accounts {
+            if !account_indexes.insert(account.details.index) {
+                return Err(Error::InvalidWalletState(format!(
+                    "account index {} is used multiple times",
+                    account.details.index
+                )));
+            }
+            if account.details.coin_type != state.coin_type {
+                return Err(Error::InvalidWalletState(format!(
+                    "account {} has coin type {}, the wallet has coin type {}",
+                    account.details.index, account.details.coin_type, state.coin_type
+                )));
+            }
+            if let Some(new_account_index) = self.account_index.filter(|index| *index != account.details.index) {
+                return Err(Error::AccountIndexMismatch {
+                    new_account_index,
+                    existing_account_index: account.details.index,
+                });
+            }
+        }
+
+        let mut loaded_accounts = Vec::with_capacity(state.accounts.len());
+        let mut default_sync_options = Vec::with_capacity(state.accounts.len());
+        for account in state.accounts {
+            let details = AccountDetails::try_from_dto(account.details)?;
+            loaded_accounts.push(Account::new(details, self.inner.clone()).await?);
+            default_sync_options.push(account.default_sync_options);
+        }
+        self.validate_accounts_network(&loaded_accounts).await?;
+
+        self.coin_type.store(state.coin_type, Ordering::Relaxed);
+        for (account, options) in loaded_accounts.iter().zip(default_sync_options) {
+            account.set_default_sync_options(options).await?;
+            #[cfg(feature = "storage")]
+            account.save(None).await?;
+        }
+        *accounts = loaded_accounts;
+        drop(accounts);
+
+        let mut chain_labels = self.chain_labels.write().await;
+        *chain_labels = state.chain_labels.into_iter().collect();
+        #[cfg(feature = "storage")]
+        {
+            let storage_manager = self.storage_manager.read().await;
+            storage_manager.set_chain_labels(&chain_labels).await?;
+            WalletBuilder::from_wallet(self).await.save(&*storage_manager).await?;
+        }
+
+        Ok(())
+    }
+}
@@ -110,6 +110,19 @@ impl Wallet {
         let (read_client_options, read_coin_type, read_secret_manager, read_accounts) =
             read_data_from_stronghold_snapshot::<SecretManager>(&new_stronghold).await?;
 
+        // Accounts with another index can't be restored into a wallet bound to an account index
+        if let (Some(new_account_index), Some(read_accounts)) = (self.account_index, &read_accounts) {
+            if let Some(account) = read_accounts
+                .iter()
+                .find(|account| *account.index() != new_account_index)
+            {
+                return Err(crate::wallet::Error::AccountIndexMismatch {
+                    new_account_index,
+                    existing_account_index: *account.index(),
+                });
+            }
+        }
+
         // If the coin type is not matching the current one, then the addresses in the accounts will also not be
         // correct, so we will not restore them
         let ignore_backup_values = ignore_if_coin_type_mismatch.map_or(false, |ignore| {
@@ -193,7 +206,7 @@ impl Wallet {
         #[cfg(feature = "storage")]
         {
             use crate::wallet::core::operations::storage::SaveLoadWallet;
-            let wallet_builder = WalletBuilder::new()
+            let mut wallet_builder = WalletBuilder::new()
                 .with_secret_manager_arc(self.secret_manager.clone())
                 .with_storage_path(
                     &self
@@ -206,6 +219,9 @@ impl Wallet {
                 )
                 .with_client_options(self.client_options().await)
                 .with_coin_type(self.coin_type.load(Ordering::Relaxed));
+            if let Some(account_index) = self.account_index {
+                wallet_builder = wallet_builder.for_account_index(account_index);
+            }
             wallet_builder.save(&*self.storage_manager.read().await).await?;
             // also save account to db
             for account in accounts.iter() {
@@ -292,6 +308,19 @@ impl Wallet<StrongholdSecretManager> {
         let (read_client_options, read_coin_type, read_secret_manager, read_accounts) =
             read_data_from_stronghold_snapshot::<StrongholdSecretManager>(&new_stronghold).await?;
 
+        // Accounts with another index can't be restored into a wallet bound to an account index
+        if let (Some(new_account_index), Some(read_accounts)) = (self.account_index, &read_accounts) {
+            if let Some(account) = read_accounts
+                .iter()
+                .find(|account| *account.index() != new_account_index)
+            {
+                return Err(crate::wallet::Error::AccountIndexMismatch {
+                    new_account_index,
+                    existing_account_index: *account.index(),
+                });
+            }
+        }
+
         // If the coin type is not matching the current one, then the addresses in the accounts will also not be
         // correct, so we will not restore them
         let ignore_backup_values = ignore_if_coin_type_mismatch.map_or(false, |ignore| {
@@ -366,7 +395,7 @@ impl Wallet<StrongholdSecretManager> {
         #[cfg(feature = "storage")]
         {
             use crate::wallet::core::operations::storage::SaveLoadWallet;
-            let wallet_builder = WalletBuilder::new()
+            let mut wallet_builder = WalletBuilder::new()
                 .with_secret_manager_arc(self.secret_manager.clone())
                 .with_storage_path(
                     &self
@@ -379,6 +408,9 @@ impl Wallet<StrongholdSecretManager> {
                 )
                 .with_client_options(self.client_options().await)
                 .with_coin_type(self.coin_type.load(Ordering::Relaxed));
+            if let Some(account_index) = self.account_index {
+                wallet_builder = wallet_builder.for_account_index(account_index);
+            }
             wallet_builder.save(&*self.storage_manager.read().await).await?;
             // also save account to db
             for account in accounts.iter() {
@@ -0,0 +1,68 @@
+// Copyright 2024 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use std::sync::Arc;
+
+use async_trait::async_trait;
+
+use crate::{
+    client::{api::PreparedTransactionData, secret::SecretManage},
+    wallet::Wallet,
+};
+
+/// Approves or rejects the transactions of all accounts of a wallet, before they get signed.
+///
+/// Signing waits until the approval completed, so an implementation can delay a transaction, e.g. until a user
+/// confirmed it.
+#[async_trait]
+pub trait TransactionApprover: Send + Sync {
+    /// Returns whether the prepared transaction of the account with `account_index` may be signed.
+    async fn approve_transaction(
+        &self,
+        account_index: u32,
+        prepared_transaction_data: &PreparedTransactionData,
+    ) -> bool;
+}
+
+#[async_trait]
+impl<F> TransactionApprover for F
+where
+    F: Fn(u32, &PreparedTransactionData) -> bool + Send + Sync,
+{
+    async fn approve_transaction(
+        &self,
+        account_index: u32,
+        prepared_transaction_data: &PreparedTransactionData,
+    ) -> bool {
+        self(account_index, prepared_transaction_data)
+    }
+}
+
+impl core::fmt::Debug for dyn TransactionApprover {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.write_str("TransactionApprover")
+    }
+}
+
+impl<S: 'static + SecretManage> Wallet<S>
+where
+    crate::wallet::Error: From<S::Error>,
+{
+    /// Sets a handler which has to approve every transaction before it gets signed, replacing a previous one.
+    /// Rejected transactions fail with [`Error::TransactionRejected`](crate::wallet::Error::TransactionRejected).
+    /// ```ignore
+    /// wallet
+    ///     .set_approval_handler(|account_index, prepared_transaction_data: &PreparedTransactionData| {
+    ///         prepared_transaction_data.inputs_data.len() < 10
+    ///     })
+    ///     .await;
+    /// ```
+    pub async fn set_approval_handler(&self, approver: impl TransactionApprover + 'static) {
+        *self.transaction_approver.write().await = Some(Arc::new(approver));
+    }
+
+    /// Removes the approval handler, transactions get signed without approval again.
+    pub async fn clear_approval_handler(&self) {
+        *self.transaction_approver.write().await = None;
+    }
+}
@@ -3,12 +3,17 @@
 
 use std::fmt::Debug;
 
+use primitive_types::U256;
 use serde::{
     ser::{SerializeMap, Serializer},
     Serialize,
 };
 
-use crate::types::block::{address::Bech32Address, payload::transaction::TransactionId};
+use crate::types::block::{
+    address::{Bech32Address, Hrp},
+    output::{AliasId, FoundryId, OutputId},
+    payload::transaction::TransactionId,
+};
 
 /// The wallet error type.
 #[derive(Debug, thiserror::Error)]
@@ -16,21 +21,61 @@ pub enum Error {
     /// Account alias must be unique.
     #[error("can't create account: account alias {0} already exists")]
     AccountAliasAlreadyExists(String),
+    /// The wallet is bound to an account index that is already used by its account.
+    #[error("can't create account: account index {0} already exists")]
+    AccountIndexAlreadyExists(u32),
+    /// The account index of the wallet doesn't match the one of the stored wallet or accounts.
+    #[error("account index mismatch: {new_account_index}, existing account index is: {existing_account_index}")]
+    AccountIndexMismatch {
+        new_account_index: u32,
+        existing_account_index: u32,
+    },
     /// Account not found
     #[error("account {0} not found")]
     AccountNotFound(String),
     /// Address not found in account
     #[error("address {0} not found in account")]
     AddressNotFoundInAccount(Bech32Address),
+    /// Inputs of multiple addresses would be spent in one transaction
+    #[error(
+        "inputs of multiple addresses would be spent in one transaction: {}",
+        .0.iter().map(ToString::to_string).collect::<Vec<_>>().join(", ")
+    )]
+    AddressLinkage(Vec<Bech32Address>),
+    /// Alias not found in unspent outputs or not controlled by the account
+    #[error("alias {0} not found in unspent outputs controlled by the account")]
+    AliasNotFoundInUnspentOutputs(AliasId),
     /// Errors during backup creation or restoring
     #[error("backup failed {0}")]
     Backup(&'static str),
     /// Error from block crate.
     #[error("{0}")]
     Block(Box<crate::types::block::Error>),
+    /// Bulk send failed after some transactions were sent already
+    #[error(
+        "bulk send stopped after sending to {} recipients in {} transactions: {error}",
+        report.recipients.len(),
+        report.transaction_ids.len()
+    )]
+    BulkSendIncomplete {
+        report: Box<crate::wallet::account::BulkSendReport>,
+        error: Box<Self>,
+    },
     /// Burning or melting failed
     #[error("burning or melting failed: {0}")]
     BurningOrMeltingFailed(String),
+    /// A burn plan failed after some transactions were sent already
+    #[error("burn plan stopped after {} transactions: {error}", transaction_ids.len())]
+    BurnPlanIncomplete {
+        transaction_ids: Vec<TransactionId>,
+        error: Box<Self>,
+    },
+    /// The funds of a claim link can't be claimed anymore
+    #[error("claim link for output {output_id} can't be redeemed: {reason}")]
+    ClaimLinkNotRedeemable { output_id: OutputId, reason: &'static str },
+    /// The operation was cancelled by a `CancellationToken` or `Wallet::shutdown()`
+    #[error("operation cancelled")]
+    Cancelled,
     /// Client error.
     #[error("`{0}`")]
     Client(Box<crate::client::Error>),
@@ -46,9 +91,24 @@ pub enum Error {
     /// Failed to get remainder
     #[error("failed to get remainder address")]
     FailedToGetRemainder,
+    /// A foundry can't be destroyed, because the account doesn't hold its circulating supply to melt it
+    #[error(
+        "foundry {foundry_id} can't be destroyed: circulating supply {circulating_supply}, held by the account {held}"
+    )]
+    FoundryTokensNotHeld {
+        foundry_id: FoundryId,
+        circulating_supply: U256,
+        held: U256,
+    },
     /// Insufficient funds to send transaction.
     #[error("address owns insufficient funds: {required} base unit required, but {available} base unit available")]
     InsufficientFunds { available: u64, required: u64 },
+    /// A transaction was already sent with the idempotency key
+    #[error("idempotency key {key} was already used for transaction {transaction_id}")]
+    IdempotencyKeyUsed { key: String, transaction_id: TransactionId },
+    /// Invalid claim link
+    #[error("invalid claim link: {0}")]
+    InvalidClaimLink(String),
     /// Invalid coin type, all accounts need to have the same coin type
     #[error("invalid coin type for new account: {new_coin_type}, existing coin type is: {existing_coin_type}")]
     InvalidCoinType {
@@ -61,6 +121,18 @@ pub enum Error {
     /// Invalid output kind.
     #[error("invalid output kind: {0}")]
     InvalidOutputKind(String),
+    /// A prepared transaction was rejected by the signer
+    #[error("invalid transaction proposal: {0}")]
+    InvalidProposal(&'static str),
+    /// Invalid swap offer or acceptance
+    #[error("invalid swap: {0}")]
+    InvalidSwap(String),
+    /// Invalid vesting schedule
+    #[error("invalid vesting schedule: {0}")]
+    InvalidVestingSchedule(String),
+    /// Invalid wallet state to load
+    #[error("invalid wallet state: {0}")]
+    InvalidWalletState(String),
     /// IO error. (storage, backup, restore)
     #[error("`{0}`")]
     Io(#[from] std::io::Error),
@@ -73,6 +145,9 @@ pub enum Error {
     /// Minting failed
     #[error("minting failed {0}")]
     MintingFailed(String),
+    /// The network of the node doesn't match the wallet
+    #[error("network with bech32 HRP {bech32_hrp} doesn't match the wallet: {reason}")]
+    NetworkMismatch { bech32_hrp: Hrp, reason: String },
     /// Missing parameter.
     #[error("missing parameter: {0}")]
     MissingParameter(&'static str),
@@ -89,6 +164,15 @@ pub enum Error {
         /// The consolidation threshold.
         consolidation_threshold: usize,
     },
+    /// No expired outputs available for reclaiming
+    #[error("nothing to reclaim: no expired storage deposit return outputs")]
+    NoOutputsToReclaim,
+    /// Output not found in the account
+    #[error("output {0} not found in account")]
+    OutputNotFoundInAccount(OutputId),
+    /// Output isn't quarantined
+    #[error("output {0} isn't quarantined")]
+    OutputNotQuarantined(OutputId),
     /// Errors not covered by other variants.
     #[error(transparent)]
     Other(#[from] Box<dyn std::error::Error + Send + Sync>),
@@ -106,9 +190,40 @@ pub enum Error {
     /// Tokio task join error
     #[error("{0}")]
     TaskJoin(#[from] tokio::task::JoinError),
+    /// Transaction isn't confirmed by a milestone
+    #[error("transaction {0} is not confirmed")]
+    TransactionNotConfirmed(TransactionId),
+    /// Transaction isn't conflicting
+    #[error("transaction {0} is not conflicting")]
+    TransactionNotConflicting(TransactionId),
     /// Transaction not found
     #[error("transaction {0} not found")]
     TransactionNotFound(TransactionId),
+    /// Transaction rejected by the approval handler
+    #[error("transaction rejected by the approval handler")]
+    TransactionRejected,
+    /// Transaction doesn't fit into a block
+    #[error(
+        "transaction length {length} exceeds the max length {max_length}, {}",
+        if outputs_to_move.is_empty() {
+            "consolidate the outputs of the account so less inputs are needed".to_string()
+        } else {
+            format!("send the outputs at the indexes {outputs_to_move:?} in a second transaction")
+        }
+    )]
+    TransactionTooLarge {
+        length: usize,
+        max_length: usize,
+        /// Indexes of the outputs which should be sent in a second transaction, empty if moving outputs isn't enough.
+        outputs_to_move: Vec<usize>,
+    },
+    /// The storage or backup was migrated by a newer SDK version
+    #[cfg(any(feature = "stronghold", feature = "storage"))]
+    #[error("unsupported migration version {found}, the latest known version is {latest}; update the SDK")]
+    UnsupportedMigrationVersion {
+        found: crate::wallet::migration::MigrationVersion,
+        latest: crate::wallet::migration::MigrationVersion,
+    },
     // TODO more precise error
     /// Voting error
     #[cfg(feature = "participation")]
@@ -119,6 +234,11 @@ pub enum Error {
     #[cfg_attr(docsrs, doc(cfg(feature = "participation")))]
     #[error("invalid voting power")]
     InvalidVotingPower,
+    /// Invalid webhook configuration
+    #[cfg(feature = "webhook")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "webhook")))]
+    #[error("invalid webhook: {0}")]
+    InvalidWebhook(String),
 }
 
 // Serialize type with Display error
@@ -2,19 +2,73 @@
 // SPDX-License-Identifier: Apache-2.0
 
 pub mod types;
+#[cfg(feature = "webhook")]
+#[cfg_attr(docsrs, doc(cfg(feature = "webhook")))]
+pub mod webhook;
 
-use alloc::sync::Arc;
+use alloc::sync::{Arc, Weak};
 use std::{
     collections::HashMap,
     fmt::{Debug, Formatter, Result},
 };
 
+use serde::{Deserialize, Serialize};
+use tokio::sync::RwLock;
+
 pub use self::types::{Event, WalletEvent, WalletEventType};
 
 type Handler<T> = Arc<dyn Fn(&T) + Send + Sync + 'static>;
 
+/// The identifier of a registered listener.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct ListenerId(u64);
+
+impl ListenerId {
+    /// Creates a listener identifier from its raw value.
+    pub fn new(id: u64) -> Self {
+        Self(id)
+    }
+
+    /// Returns the raw value of the listener identifier.
+    pub fn value(&self) -> u64 {
+        self.0
+    }
+}
+
+/// A handle to a listener registered with [`Wallet::listen()`](crate::wallet::Wallet::listen). Dropping it keeps the
+/// listener registered; it doesn't keep the wallet alive.
+#[derive(Clone)]
+pub struct ListenerHandle {
+    id: ListenerId,
+    emitter: Weak<RwLock<EventEmitter>>,
+}
+
+impl ListenerHandle {
+    /// Returns the identifier of the listener.
+    pub fn id(&self) -> ListenerId {
+        self.id
+    }
+
+    /// Removes the listener for all its event types. Returns `false` if it was already removed or the wallet was
+    /// dropped.
+    pub async fn unsubscribe(&self) -> bool {
+        match self.emitter.upgrade() {
+            Some(emitter) => emitter.write().await.remove(self.id),
+            None => false,
+        }
+    }
+}
+
+impl Debug for ListenerHandle {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        f.debug_struct("ListenerHandle").field("id", &self.id).finish()
+    }
+}
+
 pub struct EventEmitter {
-    handlers: HashMap<WalletEventType, Vec<Handler<Event>>>,
+    handlers: HashMap<WalletEventType, Vec<(ListenerId, Handler<Event>)>>,
+    next_listener_id: u64,
 }
 
 impl EventEmitter {
@@ -22,17 +76,20 @@ impl EventEmitter {
     pub fn new() -> Self {
         Self {
             handlers: HashMap::new(),
+            next_listener_id: 0,
         }
     }
 
     /// Registers function `handler` as a listener for a `WalletEventType`. There may be
-    /// multiple listeners for a single event.
-    pub fn on<F>(&mut self, events: impl IntoIterator<Item = WalletEventType>, handler: F)
+    /// multiple listeners for a single event. Returns the identifier with which the listener can be removed.
+    pub fn on<F>(&mut self, events: impl IntoIterator<Item = WalletEventType>, handler: F) -> ListenerId
     where
         F: Fn(&Event) + 'static + Send + Sync,
     {
+        let id = ListenerId(self.next_listener_id);
+        self.next_listener_id += 1;
         let mut events = events.into_iter().peekable();
-        let handler = Arc::new(handler);
+        let handler: Handler<Event> = Arc::new(handler);
         // if no event is provided the handler is registered for all event types
         if events.peek().is_none() {
             // we could use a crate like strum or a macro to iterate over all values, but not sure if it's worth it
@@ -41,16 +98,32 @@ impl EventEmitter {
                 WalletEventType::SpentOutput,
                 WalletEventType::TransactionInclusion,
                 WalletEventType::TransactionProgress,
+                WalletEventType::TransactionReissue,
+                WalletEventType::LedgerRebuild,
                 WalletEventType::ConsolidationRequired,
                 #[cfg(feature = "ledger_nano")]
                 WalletEventType::LedgerAddressGeneration,
             ] {
-                self.handlers.entry(event_type).or_default().push(handler.clone());
+                self.handlers.entry(event_type).or_default().push((id, handler.clone()));
             }
         }
         for event in events {
-            self.handlers.entry(event).or_default().push(handler.clone());
+            self.handlers.entry(event).or_default().push((id, handler.clone()));
         }
+        id
+    }
+
+    /// Removes the listener with the given identifier for all event types, dropping its handler.
+    /// Returns `false` if no such listener is registered.
+    pub fn remove(&mut self, id: ListenerId) -> bool {
+        let mut removed = false;
+        self.handlers.retain(|_, handlers| {
+            let count = handlers.len();
+            handlers.retain(|(listener_id, _)| *listener_id != id);
+            removed |= handlers.len() != count;
+            !handlers.is_empty()
+        });
+        removed
     }
 
     /// Removes handlers for each given `WalletEventType`.
@@ -66,21 +139,21 @@ impl EventEmitter {
         }
     }
 
+    /// Returns the number of listeners for each event type that has any.
+    pub fn listener_counts(&self) -> HashMap<WalletEventType, usize> {
+        self.handlers
+            .iter()
+            .map(|(event_type, handlers)| (*event_type, handlers.len()))
+            .collect()
+    }
+
     /// Invokes all listeners of `event`, passing a reference to `payload` as an
     /// argument to each of them.
     pub fn emit(&self, account_index: u32, event: WalletEvent) {
-        let event_type = match &event {
-            WalletEvent::NewOutput(_) => WalletEventType::NewOutput,
-            WalletEvent::SpentOutput(_) => WalletEventType::SpentOutput,
-            WalletEvent::TransactionInclusion(_) => WalletEventType::TransactionInclusion,
-            WalletEvent::TransactionProgress(_) => WalletEventType::TransactionProgress,
-            WalletEvent::ConsolidationRequired => WalletEventType::ConsolidationRequired,
-            #[cfg(feature = "ledger_nano")]
-            WalletEvent::LedgerAddressGeneration(_) => WalletEventType::LedgerAddressGeneration,
-        };
+        let event_type = WalletEventType::from(&event);
         let event = Event { account_index, event };
         if let Some(handlers) = self.handlers.get(&event_type) {
-            for handler in handlers {
+            for (_, handler) in handlers {
                 handler(&event);
             }
         }
@@ -95,11 +168,15 @@ impl Default for EventEmitter {
 
 impl Debug for EventEmitter {
     fn fmt(&self, f: &mut Formatter<'_>) -> Result {
-        write!(
-            f,
-            "event_types_with_handlers: {:?}",
-            self.handlers.keys().collect::<Vec<&WalletEventType>>()
-        )
+        write!(f, "listener_counts: {:?}", self.listener_counts())
+    }
+}
+
+/// Creates a handle for a listener of the shared emitter.
+pub(crate) fn listener_handle(emitter: &Arc<RwLock<EventEmitter>>, id: ListenerId) -> ListenerHandle {
+    ListenerHandle {
+        id,
+        emitter: Arc::downgrade(emitter),
     }
 }
 
@@ -206,4 +283,32 @@ mod tests {
         }
         assert_eq!(1_000_003, event_counter.load(Ordering::SeqCst));
     }
+
+    #[test]
+    fn remove_listener() {
+        let mut emitter = EventEmitter::new();
+        let event_counter = Arc::new(AtomicUsize::new(0));
+
+        let event_counter_clone = Arc::clone(&event_counter);
+        let all_events = emitter.on([], move |_name| {
+            event_counter_clone.fetch_add(1, Ordering::SeqCst);
+        });
+        let consolidation = emitter.on([WalletEventType::ConsolidationRequired], |_name| {});
+        assert_ne!(all_events, consolidation);
+        assert_eq!(emitter.listener_counts()[&WalletEventType::ConsolidationRequired], 2);
+        assert_eq!(emitter.listener_counts()[&WalletEventType::NewOutput], 1);
+
+        // the listener is removed for all its event types
+        assert!(emitter.remove(all_events));
+        assert!(!emitter.remove(all_events));
+        emitter.emit(0, WalletEvent::ConsolidationRequired);
+        assert_eq!(0, event_counter.load(Ordering::SeqCst));
+        assert_eq!(emitter.listener_counts().len(), 1);
+        assert_eq!(emitter.listener_counts()[&WalletEventType::ConsolidationRequired], 1);
+
+        assert!(emitter.remove(consolidation));
+        assert!(emitter.listener_counts().is_empty());
+        // the handler was dropped
+        assert_eq!(Arc::strong_count(&event_counter), 1);
+    }
 }
@@ -10,7 +10,9 @@ use crate::{
         api::core::response::OutputWithMetadataResponse,
         block::{
             address::Bech32Address,
+            output::dto::OutputDto,
             payload::transaction::{dto::TransactionPayloadDto, TransactionId},
+            BlockId,
         },
     },
     wallet::account::types::{InclusionState, OutputDataDto},
@@ -36,6 +38,8 @@ pub enum WalletEvent {
     SpentOutput(Box<SpentOutputEvent>),
     TransactionInclusion(TransactionInclusionEvent),
     TransactionProgress(TransactionProgressEvent),
+    TransactionReissue(TransactionReissueEvent),
+    LedgerRebuild(LedgerRebuildEvent),
 }
 
 impl Serialize for WalletEvent {
@@ -58,6 +62,8 @@ impl Serialize for WalletEvent {
             T3(&'a SpentOutputEvent),
             T4(&'a TransactionInclusionEvent),
             T5(TransactionProgressEvent_<'a>),
+            T6(&'a TransactionReissueEvent),
+            T7(&'a LedgerRebuildEvent),
         }
         #[derive(Serialize)]
         struct TypedWalletEvent_<'a> {
@@ -92,6 +98,14 @@ impl Serialize for WalletEvent {
                 kind: WalletEventType::TransactionProgress as u8,
                 event: WalletEvent_::T5(TransactionProgressEvent_ { progress: e }),
             },
+            Self::TransactionReissue(e) => TypedWalletEvent_ {
+                kind: WalletEventType::TransactionReissue as u8,
+                event: WalletEvent_::T6(e),
+            },
+            Self::LedgerRebuild(e) => TypedWalletEvent_ {
+                kind: WalletEventType::LedgerRebuild as u8,
+                event: WalletEvent_::T7(e),
+            },
         };
         event.serialize(serializer)
     }
@@ -143,6 +157,14 @@ impl<'de> Deserialize<'de> for WalletEvent {
                         })?
                         .progress,
                 ),
+                WalletEventType::TransactionReissue => Self::TransactionReissue(
+                    TransactionReissueEvent::deserialize(value)
+                        .map_err(|e| serde::de::Error::custom(format!("cannot deserialize TransactionReissue: {e}")))?,
+                ),
+                WalletEventType::LedgerRebuild => Self::LedgerRebuild(
+                    LedgerRebuildEvent::deserialize(value)
+                        .map_err(|e| serde::de::Error::custom(format!("cannot deserialize LedgerRebuild: {e}")))?,
+                ),
             },
         )
     }
@@ -160,6 +182,24 @@ pub enum WalletEventType {
     SpentOutput = 3,
     TransactionInclusion = 4,
     TransactionProgress = 5,
+    TransactionReissue = 6,
+    LedgerRebuild = 7,
+}
+
+impl From<&WalletEvent> for WalletEventType {
+    fn from(event: &WalletEvent) -> Self {
+        match event {
+            WalletEvent::ConsolidationRequired => Self::ConsolidationRequired,
+            #[cfg(feature = "ledger_nano")]
+            WalletEvent::LedgerAddressGeneration(_) => Self::LedgerAddressGeneration,
+            WalletEvent::NewOutput(_) => Self::NewOutput,
+            WalletEvent::SpentOutput(_) => Self::SpentOutput,
+            WalletEvent::TransactionInclusion(_) => Self::TransactionInclusion,
+            WalletEvent::TransactionProgress(_) => Self::TransactionProgress,
+            WalletEvent::TransactionReissue(_) => Self::TransactionReissue,
+            WalletEvent::LedgerRebuild(_) => Self::LedgerRebuild,
+        }
+    }
 }
 
 impl TryFrom<u8> for WalletEventType {
@@ -174,6 +214,8 @@ impl TryFrom<u8> for WalletEventType {
             3 => Self::SpentOutput,
             4 => Self::TransactionInclusion,
             5 => Self::TransactionProgress,
+            6 => Self::TransactionReissue,
+            7 => Self::LedgerRebuild,
             _ => return Err(format!("invalid event type {value}")),
         };
         Ok(event_type)
@@ -206,6 +248,42 @@ pub struct TransactionInclusionEvent {
     pub inclusion_state: InclusionState,
 }
 
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TransactionReissueEvent {
+    pub transaction_id: TransactionId,
+    pub reissue_state: TransactionReissueState,
+}
+
+/// States of a pending transaction that is handled by the inclusion monitor.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "state", rename_all = "camelCase")]
+#[non_exhaustive]
+pub enum TransactionReissueState {
+    /// The transaction payload was reissued in a new block.
+    #[serde(rename_all = "camelCase")]
+    Reissued { block_id: BlockId, attempt: u32 },
+    /// The transaction still wasn't included after the maximum number of reissues, it's not reissued anymore.
+    GivenUp,
+}
+
+/// Progress of the rebuild of the ledger of an account with
+/// [`Wallet::rebuild_ledger_from_history()`](crate::wallet::Wallet::rebuild_ledger_from_history).
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "state", rename_all = "camelCase")]
+#[non_exhaustive]
+pub enum LedgerRebuildEvent {
+    /// The local outputs and transactions of the account were discarded.
+    Cleared,
+    /// The outputs and transactions of the account were synced from the node again.
+    #[serde(rename_all = "camelCase")]
+    Finished {
+        unspent_outputs: usize,
+        transactions: usize,
+        incoming_transactions: usize,
+    },
+}
+
 #[derive(Clone, Debug, Eq, PartialEq)]
 #[non_exhaustive]
 pub enum TransactionProgressEvent {
@@ -223,6 +301,8 @@ pub enum TransactionProgressEvent {
     PerformingPow,
     /// Broadcasting.
     Broadcasting,
+    /// Adding the output paying the service fee of the wallet.
+    AddingServiceFee(Box<OutputDto>),
 }
 
 impl Serialize for TransactionProgressEvent {
@@ -235,6 +315,11 @@ impl Serialize for TransactionProgressEvent {
             hash: &'a str,
         }
 
+        #[derive(Serialize)]
+        struct AddingServiceFee_<'a> {
+            output: &'a OutputDto,
+        }
+
         #[derive(Serialize)]
         #[serde(untagged)]
         enum TransactionProgressEvent_<'a> {
@@ -245,6 +330,7 @@ impl Serialize for TransactionProgressEvent {
             T4,
             T5,
             T6,
+            T7(AddingServiceFee_<'a>),
         }
         #[derive(Serialize)]
         struct TypedTransactionProgressEvent_<'a> {
@@ -282,6 +368,10 @@ impl Serialize for TransactionProgressEvent {
                 kind: 6,
                 event: TransactionProgressEvent_::T6,
             },
+            Self::AddingServiceFee(output) => TypedTransactionProgressEvent_ {
+                kind: 7,
+                event: TransactionProgressEvent_::T7(AddingServiceFee_ { output }),
+            },
         };
         event.serialize(serializer)
     }
@@ -294,6 +384,11 @@ impl<'de> Deserialize<'de> for TransactionProgressEvent {
             hash: String,
         }
 
+        #[derive(Deserialize)]
+        struct AddingServiceFee_ {
+            output: OutputDto,
+        }
+
         let value = serde_json::Value::deserialize(d)?;
         Ok(
             match value
@@ -319,6 +414,11 @@ impl<'de> Deserialize<'de> for TransactionProgressEvent {
                 4 => Self::SigningTransaction,
                 5 => Self::PerformingPow,
                 6 => Self::Broadcasting,
+                7 => Self::AddingServiceFee(Box::new(
+                    AddingServiceFee_::deserialize(value)
+                        .map_err(|e| serde::de::Error::custom(format!("cannot deserialize AddingServiceFee: {e}")))?
+                        .output,
+                )),
                 _ => return Err(serde::de::Error::custom("invalid transaction progress event type")),
             },
         )
@@ -0,0 +1,222 @@
+// Copyright 2024 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+//! Webhooks to which the wallet POSTs its events, so server-side integrations don't have to forward them from an
+//! in-process listener.
+//!
+//! Every event is sent as the JSON of an [`Event`] in its own request. If a secret is configured, the request
+//! carries the header `X-Iota-Signature: sha256=<hex>`, the HMAC-SHA256 of `<timestamp>.<body>` with the timestamp
+//! from the `X-Iota-Timestamp` header, so the receiver can verify the sender and reject replayed requests.
+//!
+//! ```ignore
+//! let wallet = Wallet::builder()
+//!     .with_webhook(WebhookConfig::new("https://example.com/iota-events")?.with_secret("secret"))
+//!     // ...
+//!     .finish()
+//!     .await?;
+//! ```
+
+use std::{
+    fmt::{Debug, Formatter},
+    time::{Duration, SystemTime, UNIX_EPOCH},
+};
+
+use crypto::macs::hmac::HMAC_SHA256;
+use tokio::sync::mpsc::{unbounded_channel, UnboundedReceiver, UnboundedSender};
+use url::{Host, Url};
+
+use crate::wallet::{
+    events::types::{Event, WalletEvent, WalletEventType},
+    Error, Result,
+};
+
+/// Header with the unix timestamp in seconds at which a request was signed.
+pub const TIMESTAMP_HEADER: &str = "X-Iota-Timestamp";
+/// Header with the HMAC-SHA256 signature of a request.
+pub const SIGNATURE_HEADER: &str = "X-Iota-Signature";
+
+/// The configuration of a webhook, see the [module documentation](self).
+#[derive(Clone)]
+pub struct WebhookConfig {
+    /// The endpoint events are POSTed to. Has to use HTTPS, unless it's a loopback address.
+    pub url: Url,
+    /// The secret requests are signed with.
+    pub secret: Option<String>,
+    /// The event types which are sent, empty to send all events.
+    pub events: Vec<WalletEventType>,
+    /// How often a failed request is retried before the event is dropped.
+    pub max_retries: u32,
+    /// The time before the first retry, doubled for every further retry.
+    pub retry_interval: Duration,
+    /// The timeout of a single request.
+    pub timeout: Duration,
+}
+
+impl WebhookConfig {
+    /// Creates a webhook for all events which POSTs to the HTTPS `url`, retries failed requests up to 5 times and
+    /// doesn't sign them.
+    pub fn new(url: &str) -> Result<Self> {
+        let url = Url::parse(url).map_err(|err| Error::InvalidWebhook(err.to_string()))?;
+        let is_loopback = match url.host() {
+            Some(Host::Domain(domain)) => domain == "localhost",
+            Some(Host::Ipv4(ip)) => ip.is_loopback(),
+            Some(Host::Ipv6(ip)) => ip.is_loopback(),
+            None => false,
+        };
+        if url.scheme() != "https" && !(url.scheme() == "http" && is_loopback) {
+            return Err(Error::InvalidWebhook(format!("{url} doesn't use https")));
+        }
+        Ok(Self {
+            url,
+            secret: None,
+            events: Vec::new(),
+            max_retries: 5,
+            retry_interval: Duration::from_secs(1),
+            timeout: Duration::from_secs(10),
+        })
+    }
+
+    /// Sets the secret requests are signed with.
+    pub fn with_secret(mut self, secret: impl Into<String>) -> Self {
+        self.secret = Some(secret.into());
+        self
+    }
+
+    /// Sets the event types which are sent, empty to send all events.
+    pub fn with_events(mut self, events: impl IntoIterator<Item = WalletEventType>) -> Self {
+        self.events = events.into_iter().collect();
+        self
+    }
+
+    /// Sets how often a failed request is retried.
+    pub fn with_max_retries(mut self, max_retries: u32) -> Self {
+        self.max_retries = max_retries;
+        self
+    }
+
+    /// Sets the time before the first retry.
+    pub fn with_retry_interval(mut self, retry_interval: Duration) -> Self {
+        self.retry_interval = retry_interval;
+        self
+    }
+
+    /// Sets the timeout of a single request.
+    pub fn with_timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = timeout;
+        self
+    }
+}
+
+impl Debug for WebhookConfig {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("WebhookConfig")
+            .field("url", &self.url.as_str())
+            .field("secret", &self.secret.as_ref().map(|_| "<omitted>"))
+            .field("events", &self.events)
+            .field("max_retries", &self.max_retries)
+            .field("retry_interval", &self.retry_interval)
+            .field("timeout", &self.timeout)
+            .finish()
+    }
+}
+
+/// A running webhook, which delivers the events sent to it in the background, in the order they were emitted.
+#[derive(Debug)]
+pub(crate) struct Webhook {
+    events: Vec<WalletEventType>,
+    sender: UnboundedSender<Event>,
+}
+
+impl Webhook {
+    /// Spawns the task delivering the events.
+    pub(crate) fn spawn(config: WebhookConfig) -> Result<Self> {
+        let http_client = reqwest::Client::builder()
+            .timeout(config.timeout)
+            .build()
+            .map_err(|err| Error::InvalidWebhook(err.to_string()))?;
+        let (sender, receiver) = unbounded_channel();
+        let events = config.events.clone();
+        tokio::spawn(deliver(config, http_client, receiver));
+        Ok(Self { events, sender })
+    }
+
+    /// Queues the event if the webhook is configured for its type.
+    pub(crate) fn send(&self, account_index: u32, event: &WalletEvent) {
+        if !self.events.is_empty() && !self.events.contains(&WalletEventType::from(event)) {
+            return;
+        }
+        // Only fails if the task stopped, which happens when the runtime shuts down
+        self.sender
+            .send(Event {
+                account_index,
+                event: event.clone(),
+            })
+            .ok();
+    }
+}
+
+async fn deliver(config: WebhookConfig, http_client: reqwest::Client, mut receiver: UnboundedReceiver<Event>) {
+    while let Some(event) = receiver.recv().await {
+        let body = match serde_json::to_string(&event) {
+            Ok(body) => body,
+            Err(err) => {
+                log::error!("[Webhook] couldn't serialize event: {err}");
+                continue;
+            }
+        };
+
+        let mut retry_interval = config.retry_interval;
+        let mut attempt = 0;
+        loop {
+            match post(&config, &http_client, &body).await {
+                Ok(()) => break,
+                Err(err) if attempt < config.max_retries => {
+                    log::debug!("[Webhook] POST to {} failed, retrying: {err}", config.url);
+                    tokio::time::sleep(retry_interval).await;
+                    retry_interval *= 2;
+                    attempt += 1;
+                }
+                Err(err) => {
+                    log::error!(
+                        "[Webhook] dropping event after {} attempts to {}: {err}",
+                        attempt + 1,
+                        config.url
+                    );
+                    break;
+                }
+            }
+        }
+    }
+}
+
+async fn post(config: &WebhookConfig, http_client: &reqwest::Client, body: &str) -> core::result::Result<(), String> {
+    let timestamp = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map_err(|err| err.to_string())?
+        .as_secs()
+        .to_string();
+    let mut request = http_client
+        .post(config.url.clone())
+        .header(reqwest::header::CONTENT_TYPE, "application/json")
+        .header(TIMESTAMP_HEADER, &timestamp);
+    if let Some(secret) = &config.secret {
+        request = request.header(SIGNATURE_HEADER, signature(secret, &timestamp, body));
+    }
+    let response = request
+        .body(body.to_owned())
+        .send()
+        .await
+        .map_err(|err| err.to_string())?;
+    if response.status().is_success() {
+        Ok(())
+    } else {
+        Err(format!("status {}", response.status()))
+    }
+}
+
+/// Returns the value of the [`SIGNATURE_HEADER`] for a request, which receivers can compare against to verify it.
+pub fn signature(secret: &str, timestamp: &str, body: &str) -> String {
+    let mut mac = [0; 32];
+    HMAC_SHA256(format!("{timestamp}.{body}").as_bytes(), secret.as_bytes(), &mut mac);
+    format!("sha256={}", hex::encode(mac))
+}
@@ -25,6 +25,7 @@ use crate::{
 };
 
 pub(crate) const MIGRATION_VERSION_KEY: &str = "migration-version";
+pub(crate) const MIGRATION_HISTORY_KEY: &str = "migration-history";
 
 #[cfg(feature = "stronghold")]
 struct LatestBackupMigration(MigrationVersion);
@@ -80,6 +81,29 @@ impl std::fmt::Display for MigrationVersion {
     }
 }
 
+/// The migration state of a storage, see
+/// [`WalletBuilder::check_storage_version()`](crate::wallet::WalletBuilder::check_storage_version).
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct StorageVersion {
+    /// The last migration which was applied, `None` for a new or a pre-migration storage.
+    pub current: Option<MigrationVersion>,
+    /// The latest migration known by this SDK version.
+    pub latest: MigrationVersion,
+    /// The migrations which were applied by this or earlier SDK versions, in order. Storages migrated before the
+    /// history was recorded only contain the migrations applied since then.
+    pub applied: Vec<MigrationVersion>,
+    /// The migrations which will run when the wallet is built, in order.
+    pub pending: Vec<MigrationVersion>,
+}
+
+impl StorageVersion {
+    /// Returns whether no migrations are pending.
+    pub fn is_up_to_date(&self) -> bool {
+        self.pending.is_empty()
+    }
+}
+
 pub(crate) trait MigrationData {
     const ID: usize;
     const SDK_VERSION: &'static str;
@@ -120,6 +144,12 @@ where
         let version = self.version();
         log::info!("Migrating to version {}", version);
         T::migrate(storage).await?;
+        let mut history = storage
+            .get::<Vec<MigrationVersion>>(MIGRATION_HISTORY_KEY)
+            .await?
+            .unwrap_or_default();
+        history.push(version.clone());
+        storage.set(MIGRATION_HISTORY_KEY, &history).await?;
         storage.set(MIGRATION_VERSION_KEY, &version).await?;
         Ok(())
     }
@@ -137,17 +167,63 @@ where
     Ok(())
 }
 
-fn migrations<S: 'static + StorageAdapter>(
-    mut last_migration: Option<MigrationVersion>,
-) -> Result<Vec<&'static dyn DynMigration<S>>> {
-    let migrations = MIGRATIONS
+/// Returns the migration state of a storage without applying the pending migrations.
+#[cfg(feature = "storage")]
+pub(crate) async fn storage_version(storage: &super::storage::Storage) -> Result<StorageVersion> {
+    let current = storage.get::<MigrationVersion>(MIGRATION_VERSION_KEY).await?;
+    let applied = storage
+        .get::<Vec<MigrationVersion>>(MIGRATION_HISTORY_KEY)
+        .await?
+        .unwrap_or_default();
+    let pending = migrations::<super::storage::Storage>(current.clone())?
+        .into_iter()
+        .map(|migration| migration.version())
+        .collect();
+
+    Ok(StorageVersion {
+        current,
+        latest: latest_migration_version::<super::storage::Storage>()?,
+        applied,
+        pending,
+    })
+}
+
+fn registered_migrations<S: 'static + StorageAdapter>()
+-> Result<&'static HashMap<Option<usize>, &'static dyn DynMigration<S>>> {
+    MIGRATIONS
         .get::<HashMap<Option<usize>, &'static dyn DynMigration<S>>>()
         .ok_or_else(|| {
             Error::Migration(format!(
                 "invalid migration storage kind: {}",
                 std::any::type_name::<S>()
             ))
-        })?;
+        })
+}
+
+fn latest_migration_version<S: 'static + StorageAdapter>() -> Result<MigrationVersion> {
+    registered_migrations::<S>()?
+        .values()
+        .map(|migration| migration.version())
+        .max_by_key(|version| version.id)
+        .ok_or_else(|| Error::Migration("no migrations registered".to_string()))
+}
+
+fn migrations<S: 'static + StorageAdapter>(
+    mut last_migration: Option<MigrationVersion>,
+) -> Result<Vec<&'static dyn DynMigration<S>>> {
+    let migrations = registered_migrations::<S>()?;
+    // A version this SDK doesn't know was written by a newer one, its data would be misinterpreted
+    if let Some(last_migration) = &last_migration {
+        if !migrations
+            .values()
+            .any(|migration| migration.version().id == last_migration.id)
+        {
+            return Err(Error::UnsupportedMigrationVersion {
+                found: last_migration.clone(),
+                latest: latest_migration_version::<S>()?,
+            });
+        }
+    }
     let mut res = Vec::new();
     while let Some(next) = migrations.get(&last_migration.as_ref().map(|m| m.id)) {
         last_migration = Some(next.version());
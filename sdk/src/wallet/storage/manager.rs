@@ -1,12 +1,14 @@
 // Copyright 2021 IOTA Stiftung
 // SPDX-License-Identifier: Apache-2.0
 
+use std::collections::HashMap;
+
 use futures::{StreamExt, TryStreamExt};
 use zeroize::Zeroizing;
 
 use crate::{
     client::storage::StorageAdapter,
-    types::TryFromDto,
+    types::{block::output::ChainId, TryFromDto},
     wallet::{
         account::{AccountDetails, AccountDetailsDto, SyncOptions},
         migration::migrate,
@@ -27,10 +29,7 @@ impl StorageManager {
         storage: impl DynStorageAdapter + 'static,
         encryption_key: impl Into<Option<Zeroizing<[u8; 32]>>> + Send,
     ) -> crate::wallet::Result<Self> {
-        let storage = Storage {
-            inner: Box::new(storage) as _,
-            encryption_key: encryption_key.into(),
-        };
+        let storage = Storage::new(Box::new(storage), encryption_key.into());
         migrate(&storage).await?;
 
         // Get the db version or set it
@@ -112,6 +111,20 @@ impl StorageManager {
         let key = format!("{ACCOUNT_INDEXATION_KEY}{account_index}-{ACCOUNT_SYNC_OPTIONS}");
         self.get(&key).await
     }
+
+    pub(crate) async fn set_chain_labels(&self, labels: &HashMap<ChainId, String>) -> crate::wallet::Result<()> {
+        // Stored as list, because chain ids can't be used as JSON object keys
+        self.set(CHAIN_LABELS_KEY, &labels.iter().collect::<Vec<_>>()).await
+    }
+
+    pub(crate) async fn get_chain_labels(&self) -> crate::wallet::Result<HashMap<ChainId, String>> {
+        Ok(self
+            .get::<Vec<(ChainId, String)>>(CHAIN_LABELS_KEY)
+            .await?
+            .unwrap_or_default()
+            .into_iter()
+            .collect())
+    }
 }
 
 #[async_trait::async_trait]
@@ -163,6 +176,35 @@ mod tests {
         assert_eq!(Some(rec), storage_manager.get::<Record>("key").await.unwrap());
     }
 
+    #[tokio::test]
+    async fn migration_versions() {
+        use crate::wallet::migration::{storage_version, MigrationVersion, MIGRATION_VERSION_KEY};
+
+        let storage = Storage::new(Box::<Memory>::default(), None);
+        let version = storage_version(&storage).await.unwrap();
+        assert!(version.current.is_none());
+        assert!(!version.is_up_to_date());
+        assert_eq!(version.pending.last(), Some(&version.latest));
+
+        let storage_manager = StorageManager::new(Memory::default(), None).await.unwrap();
+        let version = storage_version(&storage_manager.storage).await.unwrap();
+        assert!(version.is_up_to_date());
+        assert_eq!(version.current.as_ref(), Some(&version.latest));
+        assert_eq!(version.applied.len(), version.latest.id + 1);
+
+        // A storage migrated by a newer SDK version isn't opened
+        let newer = MigrationVersion {
+            id: version.latest.id + 1,
+            ..version.latest.clone()
+        };
+        let storage = Memory::default();
+        storage.set(MIGRATION_VERSION_KEY, &newer).await.unwrap();
+        assert!(matches!(
+            StorageManager::new(storage, None).await,
+            Err(crate::wallet::Error::UnsupportedMigrationVersion { found, .. }) if found == newer
+        ));
+    }
+
     #[tokio::test]
     async fn save_remove_account() {
         let mut storage_manager = StorageManager::new(Memory::default(), None).await.unwrap();
@@ -199,4 +241,23 @@ mod tests {
                 .is_some()
         );
     }
+
+    #[tokio::test]
+    async fn save_get_chain_labels() {
+        use crate::types::block::output::{AliasId, FoundryId, NftId};
+
+        let storage_manager = StorageManager::new(Memory::default(), None).await.unwrap();
+        assert!(storage_manager.get_chain_labels().await.unwrap().is_empty());
+
+        let labels = HashMap::from([
+            (ChainId::from(AliasId::new([1; AliasId::LENGTH])), "alias".to_string()),
+            (
+                ChainId::from(FoundryId::new([2; FoundryId::LENGTH])),
+                "foundry".to_string(),
+            ),
+            (ChainId::from(NftId::new([3; NftId::LENGTH])), "nft".to_string()),
+        ]);
+        storage_manager.set_chain_labels(&labels).await.unwrap();
+        assert_eq!(storage_manager.get_chain_labels().await.unwrap(), labels);
+    }
 }
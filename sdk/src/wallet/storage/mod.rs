@@ -31,6 +31,12 @@ pub struct Storage {
     encryption_key: Option<Zeroizing<[u8; 32]>>,
 }
 
+impl Storage {
+    pub(crate) fn new(inner: Box<dyn DynStorageAdapter>, encryption_key: Option<Zeroizing<[u8; 32]>>) -> Self {
+        Self { inner, encryption_key }
+    }
+}
+
 #[async_trait]
 impl StorageAdapter for Storage {
     type Error = crate::wallet::Error;
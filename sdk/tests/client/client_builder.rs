@@ -1,7 +1,13 @@
 // Copyright 2022 IOTA Stiftung
 // SPDX-License-Identifier: Apache-2.0
 
-use iota_sdk::client::{Client, ClientBuilder};
+use iota_sdk::{
+    client::{
+        node_manager::node::{Node, NodeAuth, NodeDto},
+        Client, ClientBuilder, ProtocolParametersOverrides, RequestOptions,
+    },
+    types::block::{address::Hrp, protocol::ProtocolParameters},
+};
 
 #[tokio::test]
 async fn invalid_url() {
@@ -61,3 +67,92 @@ async fn client_builder() {
 
     let _client_builder = serde_json::from_str::<ClientBuilder>(client_builder_json).unwrap();
 }
+
+#[tokio::test]
+async fn client_builder_protocol_parameters_overrides() {
+    let client_builder_json = r#"{
+        "nodes":[],
+        "protocolParametersOverrides":{
+            "networkName":"private-tangle",
+            "bech32Hrp":"tst",
+            "tokenSupply":"1000000000"
+        }
+    }"#;
+
+    let client_builder = serde_json::from_str::<ClientBuilder>(client_builder_json).unwrap();
+    let overrides = ProtocolParametersOverrides::default()
+        .with_network_name("private-tangle".to_string())
+        .with_bech32_hrp(Hrp::from_str_unchecked("tst"))
+        .with_token_supply(1_000_000_000);
+    assert_eq!(
+        client_builder.network_info.protocol_parameters_overrides,
+        Some(overrides.clone())
+    );
+
+    let node_protocol_parameters = ProtocolParameters::default();
+    let protocol_parameters = overrides.apply(&node_protocol_parameters).unwrap();
+    assert_eq!(protocol_parameters.network_name(), "private-tangle");
+    assert_eq!(protocol_parameters.bech32_hrp(), &Hrp::from_str_unchecked("tst"));
+    assert_eq!(protocol_parameters.token_supply(), 1_000_000_000);
+    // Parameters that aren't overridden are taken from the node
+    assert_eq!(
+        protocol_parameters.min_pow_score(),
+        node_protocol_parameters.min_pow_score()
+    );
+    assert_eq!(
+        protocol_parameters.rent_structure(),
+        node_protocol_parameters.rent_structure()
+    );
+
+    // The overrides are also used without a node
+    let client = client_builder.finish().await.unwrap();
+    assert_eq!(client.get_protocol_parameters().await.unwrap(), protocol_parameters);
+}
+
+#[test]
+fn request_options_node() {
+    assert!(RequestOptions::new().with_node("data:text/plain,Hello?World#").is_err());
+
+    let options = RequestOptions::new().with_node("http://localhost:14265").unwrap();
+    let json = serde_json::to_value(&options).unwrap();
+    assert_eq!(
+        json,
+        serde_json::json!({ "node": { "url": "http://localhost:14265/", "disabled": false } })
+    );
+    // A plain url is accepted as node as well
+    let from_url: RequestOptions =
+        serde_json::from_value(serde_json::json!({ "node": "http://localhost:14265" })).unwrap();
+    assert_eq!(Node::from(from_url.node.unwrap()), Node::from(options.node.unwrap()));
+
+    assert_eq!(
+        serde_json::to_value(RequestOptions::default()).unwrap(),
+        serde_json::json!({})
+    );
+}
+
+#[test]
+fn node_auth_headers() {
+    let auth = NodeAuth {
+        basic_auth_name_pwd: Some(("name".to_string(), "secret-password".to_string())),
+        ..Default::default()
+    }
+    .with_header("x-api-key", "secret-key");
+    let client_builder = Client::builder()
+        .with_node_auth("http://localhost:14265", auth.clone())
+        .unwrap();
+
+    let NodeDto::Node(node) = client_builder.node_manager_builder.nodes.iter().next().unwrap() else {
+        panic!("expected a node with auth");
+    };
+    // Basic auth is sent as header instead of being added to the url
+    assert_eq!(node.url.as_str(), "http://localhost:14265/");
+    assert_eq!(node.auth.as_ref(), Some(&auth));
+
+    let json = serde_json::to_value(&auth).unwrap();
+    assert_eq!(json["headers"], serde_json::json!({ "x-api-key": "secret-key" }));
+    assert_eq!(serde_json::from_value::<NodeAuth>(json).unwrap(), auth);
+
+    let debug = format!("{client_builder:?}");
+    assert!(debug.contains("x-api-key"));
+    assert!(!debug.contains("secret"));
+}
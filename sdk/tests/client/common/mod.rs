@@ -5,8 +5,11 @@ mod constants;
 
 use crypto::keys::bip39::Mnemonic;
 use iota_sdk::client::{
-    api::GetAddressesOptions, constants::SHIMMER_COIN_TYPE, node_api::indexer::query_parameters::QueryParameter,
-    request_funds_from_faucet, secret::SecretManager, Client, Result,
+    api::GetAddressesOptions,
+    constants::SHIMMER_COIN_TYPE,
+    secret::SecretManager,
+    testing::{NodeHarness, NodeHarnessOptions},
+    Client, Result,
 };
 
 pub use self::constants::{FAUCET_URL, NODE_LOCAL};
@@ -27,7 +30,13 @@ pub async fn setup_client_with_node_health_ignored() -> Client {
 pub async fn create_client_and_secret_manager_with_funds(
     mnemonic: Option<Mnemonic>,
 ) -> Result<(Client, SecretManager)> {
-    let client = Client::builder().with_node(NODE_LOCAL)?.finish().await?;
+    let harness = NodeHarness::start(
+        NodeHarnessOptions::default()
+            .with_node_url(NODE_LOCAL)
+            .with_faucet_url(FAUCET_URL),
+    )
+    .await?;
+    let client = harness.client().await?;
 
     let secret_manager =
         SecretManager::try_from_mnemonic(mnemonic.unwrap_or_else(|| Client::generate_mnemonic().unwrap()))?;
@@ -41,23 +50,8 @@ pub async fn create_client_and_secret_manager_with_funds(
         )
         .await?[0];
 
-    request_funds_from_faucet(FAUCET_URL, &address).await?;
-
     // Continue only after funds are received
-    for _ in 0..30 {
-        tokio::time::sleep(std::time::Duration::from_secs(2)).await;
-        let output_ids_response = client
-            .basic_output_ids([
-                QueryParameter::Address(address),
-                QueryParameter::HasExpiration(false),
-                QueryParameter::HasTimelock(false),
-                QueryParameter::HasStorageDepositReturn(false),
-            ])
-            .await?;
-
-        if !output_ids_response.is_empty() {
-            return Ok((client, secret_manager));
-        }
-    }
-    panic!("Faucet no longer wants to hand over coins");
+    harness.fund(&address).await?;
+
+    Ok((client, secret_manager))
 }
@@ -0,0 +1,44 @@
+// Copyright 2024 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use iota_sdk::{
+    client::api::input_selection::{
+        test_utils::{arb_basic_output, arb_inputs, check_invariants, ed25519_addresses, token_ids},
+        InputSelection,
+    },
+    types::block::protocol::protocol_parameters,
+};
+use proptest::{collection::vec, prelude::*};
+
+proptest! {
+    #![proptest_config(ProptestConfig {
+        failure_persistence: None,
+        ..Default::default()
+    })]
+
+    #[test]
+    fn selection_invariants(
+        (inputs, outputs) in {
+            let addresses = ed25519_addresses(3);
+            let token_ids = token_ids(3);
+            (
+                arb_inputs(addresses[..2].to_vec(), token_ids.clone(), protocol_parameters(), 1..20),
+                vec(arb_basic_output(addresses[2..].to_vec(), token_ids, protocol_parameters()), 1..4),
+            )
+        }
+    ) {
+        let protocol_parameters = protocol_parameters();
+
+        // Selections can fail if the inputs don't hold enough funds, only successful ones have to hold the invariants
+        if let Ok(selected) = InputSelection::new(
+            inputs.clone(),
+            outputs.clone(),
+            ed25519_addresses(2),
+            protocol_parameters.clone(),
+        )
+        .select()
+        {
+            check_invariants(&inputs, &outputs, &selected, &protocol_parameters).map_err(TestCaseError::fail)?;
+        }
+    }
+}
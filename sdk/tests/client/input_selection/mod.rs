@@ -6,6 +6,8 @@ mod basic_outputs;
 mod burn;
 mod expiration;
 mod foundry_outputs;
+#[cfg(feature = "input_selection_test_utils")]
+mod invariants;
 mod native_tokens;
 mod nft_outputs;
 mod outputs;
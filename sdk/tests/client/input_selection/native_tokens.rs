@@ -5,14 +5,19 @@ use std::str::FromStr;
 
 use iota_sdk::{
     client::api::input_selection::{Burn, Error, InputSelection},
-    types::block::{output::TokenId, protocol::protocol_parameters, rand::bytes::rand_bytes_array},
+    types::block::{
+        output::{AliasId, TokenId},
+        protocol::protocol_parameters,
+        rand::bytes::rand_bytes_array,
+    },
 };
 use pretty_assertions::assert_eq;
 use primitive_types::U256;
 
 use crate::client::{
-    addresses, build_inputs, build_outputs, is_remainder_or_return, unsorted_eq, Build::Basic,
-    BECH32_ADDRESS_ED25519_0, TOKEN_ID_1, TOKEN_ID_2,
+    addresses, build_inputs, build_outputs, is_remainder_or_return, unsorted_eq,
+    Build::{Alias, Basic},
+    ALIAS_ID_1, BECH32_ADDRESS_ED25519_0, TOKEN_ID_1, TOKEN_ID_2,
 };
 
 #[test]
@@ -1546,3 +1551,51 @@ fn higher_nts_count_but_below_max_native_tokens() {
 // 200}] }] }] outputs: [basic{ amount: 500_000, native_tokens: [{‘a’: 200}] }]
 // expected selected: [basic{ amount: 1_000_000, native_tokens: [{‘a’: 200}] }]
 // expected remainder: Some(basic{ amount: 500_000 })
+
+#[test]
+fn native_tokens_kept_by_transitioned_alias() {
+    let protocol_parameters = protocol_parameters();
+    let alias_id_1 = AliasId::from_str(ALIAS_ID_1).unwrap();
+
+    let inputs = build_inputs([
+        Alias(
+            1_000_000,
+            alias_id_1,
+            1,
+            BECH32_ADDRESS_ED25519_0,
+            BECH32_ADDRESS_ED25519_0,
+            Some(vec![(TOKEN_ID_1, 100)]),
+            None,
+            None,
+            None,
+        ),
+        Basic(2_000_000, BECH32_ADDRESS_ED25519_0, None, None, None, None, None, None),
+    ]);
+    let outputs = build_outputs([Basic(
+        1_000_000,
+        BECH32_ADDRESS_ED25519_0,
+        Some(vec![(TOKEN_ID_1, 100)]),
+        None,
+        None,
+        None,
+        None,
+        None,
+    )]);
+
+    let selected = InputSelection::new(
+        inputs,
+        outputs,
+        addresses([BECH32_ADDRESS_ED25519_0]),
+        protocol_parameters,
+    )
+    .select();
+
+    // The automatically transitioned alias keeps its native tokens, so they can't be sent as well
+    assert!(matches!(
+        selected,
+        Err(Error::InsufficientNativeTokenAmount {
+            token_id,
+            found,
+            required,
+        }) if token_id == TokenId::from_str(TOKEN_ID_1).unwrap() && found == U256::from(0) && required == U256::from(100)));
+}
@@ -0,0 +1,64 @@
+// Copyright 2024 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use iota_sdk::{
+    client::isc::{AgentId, Allowance, Hname, IscRequest, ACCOUNTS_CONTRACT, DEPOSIT_ENTRY_POINT},
+    types::block::output::NftId,
+};
+use pretty_assertions::assert_eq;
+
+#[test]
+fn hnames() {
+    assert_eq!(Hname::new(ACCOUNTS_CONTRACT).as_u32(), 0x3c4b5e02);
+    assert_eq!(Hname::new(DEPOSIT_ENTRY_POINT).as_u32(), 0xbdc9102d);
+    assert_eq!(Hname::new("transferAllowanceTo").to_string(), "23f4e3a1");
+}
+
+#[test]
+fn deposit_request() {
+    assert_eq!(
+        IscRequest::deposit().pack_to_vec(),
+        [
+            0x00, 0x00, 0x00, 0x00, // sender contract
+            0x02, 0x5e, 0x4b, 0x3c, // accounts
+            0x2d, 0x10, 0xc9, 0xbd, // deposit
+            0x00, // no gas limit
+            0x00, // no params
+            0x00, // no allowance
+        ]
+    );
+}
+
+#[test]
+fn transfer_allowance_to_request() {
+    let request = IscRequest::transfer_allowance_to(
+        AgentId::Ethereum([0x11; 20]),
+        Allowance::new().with_base_tokens(1_000_000),
+    )
+    .with_gas_budget(999);
+
+    let mut expected = vec![
+        0x00, 0x00, 0x00, 0x00, // sender contract
+        0x02, 0x5e, 0x4b, 0x3c, // accounts
+        0xa1, 0xe3, 0xf4, 0x23, // transferAllowanceTo
+        0xe8, 0x07, // gas budget + 1
+        0x01, // one param
+        0x01, b'a', // agent id key
+        0x15, 0x03, // agent id length and ethereum kind
+    ];
+    expected.extend([0x11; 20]);
+    expected.extend([0x80, 0xc0, 0x84, 0x3d]); // base tokens allowance
+    assert_eq!(request.pack_to_vec(), expected);
+    assert_eq!(request.to_metadata_feature().unwrap().data(), expected);
+}
+
+#[test]
+fn deposit_nft_request() {
+    let nft_id = NftId::new([0x22; 32]);
+    let request = IscRequest::deposit_nft(AgentId::Ethereum([0x11; 20]), nft_id);
+
+    assert_eq!(request.allowance.nfts, [nft_id]);
+    let bytes = request.pack_to_vec();
+    assert_eq!(&bytes[bytes.len() - 34..bytes.len() - 32], [0x20, 0x01]);
+    assert_eq!(&bytes[bytes.len() - 32..], nft_id.as_slice());
+}
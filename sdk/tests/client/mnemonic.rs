@@ -1,8 +1,8 @@
 // Copyright 2021 IOTA Stiftung
 // SPDX-License-Identifier: Apache-2.0
 
-use crypto::keys::bip39::Mnemonic;
-use iota_sdk::client::{Client, Result};
+use crypto::keys::bip39::{Mnemonic, Passphrase};
+use iota_sdk::client::{Client, MnemonicStrength, Result, SeedSource};
 
 #[tokio::test]
 async fn mnemonic() -> Result<()> {
@@ -13,3 +13,70 @@ async fn mnemonic() -> Result<()> {
     assert!(Client::mnemonic_to_hex_seed(Mnemonic::from("invalid mnemonic".to_owned())).is_err());
     Ok(())
 }
+
+#[tokio::test]
+async fn mnemonic_with_passphrase() -> std::result::Result<(), Box<dyn std::error::Error>> {
+    // BIP-39 test vector
+    let mnemonic = Mnemonic::from(
+        "abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon about".to_owned(),
+    );
+    let seed = Client::mnemonic_to_seed_with_passphrase(mnemonic.clone(), Passphrase::from("TREZOR"))?;
+    assert_eq!(
+        prefix_hex::encode(seed.as_ref()),
+        "0xc55257c360c07c72029aebc1b53c05ed0362ada38ead3e3e9efa3708e53495531f09a6987599d18264c1e1c92f2cf141630c7a3c4ab7c81b2f001698e7463b04"
+    );
+    // An empty passphrase is the same as no passphrase
+    assert_eq!(
+        Client::mnemonic_to_seed_with_passphrase(mnemonic.clone(), Passphrase::default())?.as_ref(),
+        Client::mnemonic_to_seed(mnemonic)?.as_ref()
+    );
+    Ok(())
+}
+
+#[test]
+fn mnemonic_words() -> Result<()> {
+    let mnemonic = Mnemonic::from(
+        "until fire hat mountain zoo grocery real deny advance change marble taste goat ivory wheat bubble panic banner tattoo client ticket action race rocket".to_owned(),
+    );
+
+    assert!(Client::verify_mnemonic_words(&*mnemonic, &[(0, "until"), (4, "zoo"), (23, " rocket")])?);
+    assert!(!Client::verify_mnemonic_words(&*mnemonic, &[(0, "until"), (4, "zebra")])?);
+    // Out of range positions don't match
+    assert!(!Client::verify_mnemonic_words(&*mnemonic, &[(24, "rocket")])?);
+    assert!(Client::verify_mnemonic_words::<&str>(&*mnemonic, &[]).is_err());
+    assert!(Client::verify_mnemonic_words(Mnemonic::from("invalid mnemonic".to_owned()), &[(0, "invalid")]).is_err());
+    Ok(())
+}
+
+// Deterministic entropy, as it could be provided by a hardware RNG
+struct FixedEntropy(u8);
+
+impl SeedSource for FixedEntropy {
+    fn fill_entropy(&mut self, entropy: &mut [u8]) -> Result<()> {
+        entropy.fill(self.0);
+        Ok(())
+    }
+}
+
+#[test]
+fn mnemonic_with_rng() -> Result<()> {
+    // BIP-39 test vectors
+    for (strength, expected) in [
+        (MnemonicStrength::Words12, "about"),
+        (MnemonicStrength::Words18, "agent"),
+        (MnemonicStrength::Words24, "art"),
+    ] {
+        let mnemonic = Client::generate_mnemonic_with_rng(&mut FixedEntropy(0), strength)?;
+        let words = mnemonic.split_whitespace().collect::<Vec<_>>();
+        assert_eq!(words.len(), strength.word_count() as usize);
+        assert!(words[..words.len() - 1].iter().all(|word| *word == "abandon"));
+        assert_eq!(words.last(), Some(&expected));
+    }
+    let mnemonic = Client::generate_mnemonic_with_rng(&mut FixedEntropy(0xff), MnemonicStrength::Words12)?;
+    assert_eq!(&**mnemonic, "zoo zoo zoo zoo zoo zoo zoo zoo zoo zoo zoo wrong");
+
+    assert_eq!(Client::generate_mnemonic()?.split_whitespace().count(), 24);
+    assert_eq!(MnemonicStrength::try_from(18)?, MnemonicStrength::Words18);
+    assert!(MnemonicStrength::try_from(13).is_err());
+    Ok(())
+}
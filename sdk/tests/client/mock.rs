@@ -0,0 +1,464 @@
+// Copyright 2024 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use std::sync::{Arc, Mutex};
+
+use iota_sdk::{
+    client::{
+        cache::CacheOptions,
+        mock::MockClient,
+        node_api::{error::Error as NodeApiError, indexer::query_parameters::QueryParameter},
+        node_manager::issuer_node::IssuerNodeFallbackEvent,
+        Client, Error, ProtocolParametersOverrides, RequestOptions,
+    },
+    types::{
+        api::{
+            core::response::{LedgerInclusionState, SubmitBlockResponse},
+            plugins::indexer::OutputIdsResponse,
+        },
+        block::{
+            address::{Address, AliasAddress, Bech32Address, Ed25519Address, NftAddress},
+            output::{
+                feature::{IssuerFeature, MetadataFeature},
+                unlock_condition::{
+                    AddressUnlockCondition, GovernorAddressUnlockCondition, StateControllerAddressUnlockCondition,
+                },
+                AliasId, AliasOutputBuilder, BasicOutputBuilder, NftId, NftOutputBuilder, OutputId, OutputWithMetadata,
+                RentStructure,
+            },
+            payload::{Payload, TaggedDataPayload},
+            protocol::ProtocolParameters,
+            BlockBuilder, BlockDto,
+        },
+    },
+};
+
+#[tokio::test]
+async fn mock_client_requests() -> Result<(), Box<dyn std::error::Error>> {
+    let mock_client = MockClient::default();
+    let client = Client::builder().with_mock_client(mock_client.clone()).finish().await?;
+
+    let info = client.get_info().await?.node_info;
+    assert_eq!(info.protocol, mock_client.protocol_parameters());
+    assert_eq!(info.status.latest_milestone.index, mock_client.milestone_index());
+
+    let address = Bech32Address::new(client.get_bech32_hrp().await?, Ed25519Address::new([1; 32]));
+    let output_id = mock_client.add_output(
+        BasicOutputBuilder::new_with_amount(1_000_000)
+            .add_unlock_condition(AddressUnlockCondition::new(address))
+            .finish_output(client.get_token_supply().await?)?,
+    );
+    assert_eq!(
+        client.basic_output_ids([QueryParameter::Address(address)]).await?.items,
+        [output_id]
+    );
+    assert_eq!(client.get_output(&output_id).await?.output().amount(), 1_000_000);
+    assert!(matches!(
+        client.get_output(&OutputId::null()).await,
+        Err(Error::Node(NodeApiError::NotFound(_)))
+    ));
+
+    let block = client
+        .build_block()
+        .with_tag(b"mock".to_vec())
+        .with_data(b"data".to_vec())
+        .finish()
+        .await?;
+    let metadata = client.get_block_metadata(&block.id()).await?;
+    assert_eq!(
+        metadata.ledger_inclusion_state,
+        Some(LedgerInclusionState::NoTransaction)
+    );
+    assert_eq!(client.get_block(&block.id()).await?, block);
+    assert_eq!(client.get_tips().await?, [block.id()]);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn mock_client_protocol_parameters_overrides() -> Result<(), Box<dyn std::error::Error>> {
+    let mock_client = MockClient::default();
+    let client = Client::builder()
+        .with_mock_client(mock_client.clone())
+        .with_protocol_parameters_overrides(ProtocolParametersOverrides::default().with_below_max_depth(5))
+        .finish()
+        .await?;
+
+    let protocol_parameters = client.get_protocol_parameters().await?;
+    assert_eq!(protocol_parameters.below_max_depth(), 5);
+    assert_eq!(
+        protocol_parameters.network_name(),
+        mock_client.protocol_parameters().network_name()
+    );
+    // The node info isn't changed
+    assert_eq!(
+        client.get_info().await?.node_info.protocol,
+        mock_client.protocol_parameters()
+    );
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn mock_client_batched_outputs() -> Result<(), Box<dyn std::error::Error>> {
+    let mock_client = MockClient::default();
+    let client = Client::builder()
+        .with_mock_client(mock_client.clone())
+        .with_max_parallel_api_requests(2)
+        .finish()
+        .await?;
+
+    let address = Bech32Address::new(client.get_bech32_hrp().await?, Ed25519Address::new([1; 32]));
+    let token_supply = client.get_token_supply().await?;
+    let mut output_ids = (1..=5)
+        .map(|i| {
+            Ok(mock_client.add_output(
+                BasicOutputBuilder::new_with_amount(i * 1_000_000)
+                    .add_unlock_condition(AddressUnlockCondition::new(address))
+                    .finish_output(token_supply)?,
+            ))
+        })
+        .collect::<Result<Vec<_>, Box<dyn std::error::Error>>>()?;
+    output_ids.reverse();
+
+    // The outputs are requested in multiple batches, but returned in the order of the IDs
+    let amounts = |outputs: Vec<OutputWithMetadata>| {
+        outputs
+            .iter()
+            .map(|output| output.output().amount())
+            .collect::<Vec<_>>()
+    };
+    assert_eq!(
+        amounts(client.get_outputs(&output_ids).await?),
+        [5_000_000, 4_000_000, 3_000_000, 2_000_000, 1_000_000]
+    );
+
+    output_ids.insert(2, OutputId::null());
+    assert!(client.get_outputs(&output_ids).await.is_err());
+    assert_eq!(
+        amounts(client.get_outputs_ignore_errors(&output_ids).await?),
+        [5_000_000, 4_000_000, 3_000_000, 2_000_000, 1_000_000]
+    );
+    assert_eq!(client.get_outputs_metadata_ignore_errors(&output_ids).await?.len(), 5);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn mock_client_raw_output_ids() -> Result<(), Box<dyn std::error::Error>> {
+    let mock_client = MockClient::default();
+    let client = Client::builder().with_mock_client(mock_client.clone()).finish().await?;
+
+    let address = Bech32Address::new(client.get_bech32_hrp().await?, Ed25519Address::new([1; 32]));
+    let token_supply = client.get_token_supply().await?;
+    let mut output_ids = (1..=5)
+        .map(|i| {
+            Ok(mock_client.add_output(
+                BasicOutputBuilder::new_with_amount(i * 1_000_000)
+                    .add_unlock_condition(AddressUnlockCondition::new(address))
+                    .finish_output(token_supply)?,
+            ))
+        })
+        .collect::<Result<Vec<_>, Box<dyn std::error::Error>>>()?;
+    output_ids.sort();
+
+    let page = client.output_ids_raw([QueryParameter::PageSize(2)]).await?;
+    assert_eq!(page.cursor.as_deref(), Some("2"));
+    assert_eq!(OutputIdsResponse::try_from(page)?.items, output_ids[..2]);
+
+    let mut pages = 0;
+    let mut paged_output_ids = Vec::new();
+    client
+        .for_each_output_ids_raw([QueryParameter::PageSize(2)], |page| {
+            pages += 1;
+            page.for_each_output_id(|output_id| paged_output_ids.push(output_id))
+                .map_err(Error::from)
+        })
+        .await?;
+    assert_eq!(pages, 3);
+    assert_eq!(paged_output_ids, output_ids);
+
+    // All pages are merged
+    assert_eq!(
+        client
+            .basic_output_ids([QueryParameter::Address(address), QueryParameter::PageSize(2)])
+            .await?
+            .items,
+        output_ids
+    );
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn mock_client_block_issuance_context() -> Result<(), Box<dyn std::error::Error>> {
+    let mock_client = MockClient::default();
+    let client = Client::builder().with_mock_client(mock_client.clone()).finish().await?;
+    let payload = Payload::from(TaggedDataPayload::new(b"tag".to_vec(), b"data".to_vec())?);
+
+    let (block, context) = client.finish_block_builder_with_context(None, Some(payload.clone())).await?;
+    assert_eq!(context.protocol_parameters, mock_client.protocol_parameters());
+    Client::revalidate_block(&block, &context)?;
+
+    // The block has no proof of work, so it's only valid if the node was supposed to do it
+    let mut pow_context = context.clone();
+    pow_context.protocol_parameters = ProtocolParameters::new(
+        context.protocol_parameters.protocol_version(),
+        String::from("mock"),
+        "smr",
+        1500,
+        15,
+        RentStructure::default(),
+        context.protocol_parameters.token_supply(),
+    )?;
+    pow_context.local_pow = true;
+    assert!(matches!(
+        Client::revalidate_block(&block, &pow_context),
+        Err(Error::InsufficientPowScore { min_pow_score: 1500, .. })
+    ));
+    pow_context.local_pow = false;
+    Client::revalidate_block(&block, &pow_context)?;
+
+    let block = BlockBuilder::new(block.parents().clone())
+        .with_protocol_version(context.protocol_parameters.protocol_version() - 1)
+        .with_payload(payload)
+        .finish()?;
+    assert!(matches!(Client::revalidate_block(&block, &context), Err(Error::Unpack(_))));
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn mock_client_cache() -> Result<(), Box<dyn std::error::Error>> {
+    let storage_path = "test-storage/mock_client_cache";
+    let _ = std::fs::remove_dir_all(storage_path);
+    let cache_options = CacheOptions::new(10).with_storage_path(storage_path);
+
+    let client = Client::builder()
+        .with_mock_client(MockClient::default())
+        .with_cache(cache_options.clone())
+        .finish()
+        .await?;
+    let block = client.build_block().with_tag(b"cache".to_vec()).finish().await?;
+    assert_eq!(client.get_block(&block.id()).await?, block);
+    let block_bytes = client.get_block_raw(&block.id()).await?;
+
+    // A new mock doesn't know the block, so it can only come from the stored cache
+    let client = Client::builder()
+        .with_mock_client(MockClient::default())
+        .with_cache(cache_options)
+        .finish()
+        .await?;
+    assert_eq!(client.get_block(&block.id()).await?, block);
+    assert_eq!(client.get_block_raw(&block.id()).await?, block_bytes);
+    // The metadata can still change, so it isn't cached
+    assert!(matches!(
+        client.get_block_metadata(&block.id()).await,
+        Err(Error::Node(NodeApiError::NotFound(_)))
+    ));
+
+    client.clear_cache().await;
+    assert!(matches!(
+        client.get_block(&block.id()).await,
+        Err(Error::Node(NodeApiError::NotFound(_)))
+    ));
+    assert!(!std::path::Path::new(storage_path).exists());
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn mock_client_tagged_data_blocks() -> Result<(), Box<dyn std::error::Error>> {
+    let mock_client = MockClient::default();
+    let client = Client::builder().with_mock_client(mock_client.clone()).finish().await?;
+
+    let first = client
+        .build_tagged_data_block(b"anchor".to_vec(), b"first".to_vec())
+        .await?;
+    client
+        .build_tagged_data_block(b"other".to_vec(), b"data".to_vec())
+        .await?;
+    let second = client
+        .build_tagged_data_block(b"anchor".to_vec(), b"second".to_vec())
+        .await?;
+    let Some(Payload::TaggedData(tagged_data)) = second.payload() else {
+        panic!("expected a tagged data payload");
+    };
+    assert_eq!(tagged_data.data(), b"second");
+    assert!(
+        client
+            .get_block_metadata(&second.id())
+            .await?
+            .referenced_by_milestone_index
+            .is_some()
+    );
+
+    let blocks = client.find_blocks_by_tag(b"anchor", ..).await?;
+    assert_eq!(blocks, [first.clone(), second.clone()]);
+
+    let first_milestone = client
+        .get_block_metadata(&first.id())
+        .await?
+        .referenced_by_milestone_index
+        .unwrap();
+    assert_eq!(client.find_blocks_by_tag(b"anchor", ..=first_milestone).await?, [first]);
+    assert_eq!(
+        client.find_blocks_by_tag(b"anchor", first_milestone + 1..).await?,
+        [second]
+    );
+    assert!(client.find_blocks_by_tag(b"missing", ..).await?.is_empty());
+
+    assert!(matches!(
+        client.build_tagged_data_block(vec![0; 65], Vec::new()).await,
+        Err(Error::TaggedData(_))
+    ));
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn mock_client_issuer() -> Result<(), Box<dyn std::error::Error>> {
+    let storage_path = "test-storage/mock_client_issuer";
+    let _ = std::fs::remove_dir_all(storage_path);
+    let cache_options = CacheOptions::new(10).with_storage_path(storage_path);
+
+    let mock_client = MockClient::default();
+    let client = Client::builder()
+        .with_mock_client(mock_client.clone())
+        .with_cache(cache_options.clone())
+        .finish()
+        .await?;
+    let token_supply = client.get_token_supply().await?;
+    let hrp = client.get_bech32_hrp().await?;
+    let owner = Address::Ed25519(Ed25519Address::new([1; 32]));
+
+    // An alias issues a collection NFT, which issues the NFTs of the collection
+    let alias_address = AliasAddress::new(AliasId::new([2; 32]));
+    mock_client.add_output(
+        AliasOutputBuilder::new_with_amount(1_000_000, *alias_address.alias_id())
+            .add_unlock_condition(StateControllerAddressUnlockCondition::new(owner))
+            .add_unlock_condition(GovernorAddressUnlockCondition::new(owner))
+            .finish_output(token_supply)?,
+    );
+    let collection_address = NftAddress::new(NftId::new([3; 32]));
+    mock_client.add_output(
+        NftOutputBuilder::new_with_amount(1_000_000, *collection_address.nft_id())
+            .add_unlock_condition(AddressUnlockCondition::new(owner))
+            .add_immutable_feature(IssuerFeature::new(alias_address))
+            .add_immutable_feature(MetadataFeature::new(b"collection".to_vec())?)
+            .finish_output(token_supply)?,
+    );
+    let nft_id = NftId::new([4; 32]);
+    mock_client.add_output(
+        NftOutputBuilder::new_with_amount(1_000_000, nft_id)
+            .add_unlock_condition(AddressUnlockCondition::new(owner))
+            .add_immutable_feature(IssuerFeature::new(collection_address))
+            .finish_output(token_supply)?,
+    );
+
+    let collection = client.resolve_issuer(collection_address).await?;
+    assert_eq!(collection.address, Bech32Address::new(hrp, collection_address));
+    assert_eq!(collection.issuer, Some(Bech32Address::new(hrp, alias_address)));
+    assert_eq!(collection.immutable_metadata.as_deref(), Some(&b"collection"[..]));
+    let alias = client.resolve_issuer(alias_address).await?;
+    assert_eq!(alias.issuer, None);
+    assert!(matches!(
+        client.resolve_issuer(owner).await,
+        Err(Error::UnresolvableIssuer(_))
+    ));
+
+    assert!(client.verify_issuer(nft_id, collection_address).await?);
+    assert!(!client.verify_issuer(nft_id, alias_address).await?);
+    assert!(client.verify_issuer(*collection_address.nft_id(), alias_address).await?);
+
+    // A new mock doesn't know the collection NFT, so it can only come from the stored cache
+    let client = Client::builder()
+        .with_mock_client(MockClient::default())
+        .with_cache(cache_options)
+        .finish()
+        .await?;
+    assert_eq!(client.resolve_issuer(collection_address).await?, collection);
+    client.clear_cache().await;
+    assert!(client.resolve_issuer(collection_address).await.is_err());
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn mock_client_issuer_node_fallback() -> Result<(), Box<dyn std::error::Error>> {
+    // Nothing listens on the issuer node, so it's unhealthy after the first sync
+    let client = Client::builder()
+        .with_mock_client(MockClient::default())
+        .with_issuer_node("http://127.0.0.1:1", None)?
+        .finish()
+        .await?;
+    let events = Arc::new(Mutex::new(Vec::new()));
+    let events_clone = events.clone();
+    client
+        .listen_issuer_node_fallback(move |event| events_clone.lock().unwrap().push(event.clone()))
+        .await;
+
+    // Building a block also submits it
+    let block = client.build_block().with_tag(b"issuer".to_vec()).finish().await?;
+    assert_eq!(client.post_block(&block).await?, block.id());
+    let event = IssuerNodeFallbackEvent {
+        issuer_node: "http://127.0.0.1:1".parse()?,
+        error: None,
+    };
+    assert_eq!(*events.lock().unwrap(), [event.clone(), event]);
+
+    // An explicit node bypasses the issuer node
+    client
+        .post_block_with_options(&block, &RequestOptions::new().with_node("http://127.0.0.1:2")?)
+        .await?;
+    assert_eq!(events.lock().unwrap().len(), 2);
+
+    client.clear_issuer_node_fallback_listeners().await;
+    client.post_block(&block).await?;
+    assert_eq!(events.lock().unwrap().len(), 2);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn mock_client_rest() -> Result<(), Box<dyn std::error::Error>> {
+    let mock_client = MockClient::default();
+    let client = Client::builder().with_mock_client(mock_client.clone()).finish().await?;
+
+    let address = Bech32Address::new(client.get_bech32_hrp().await?, Ed25519Address::new([1; 32]));
+    let output_id = mock_client.add_output(
+        BasicOutputBuilder::new_with_amount(1_000_000)
+            .add_unlock_condition(AddressUnlockCondition::new(address))
+            .finish_output(client.get_token_supply().await?)?,
+    );
+
+    let response: OutputIdsResponse = client
+        .rest()
+        .get("/api/indexer/v1/outputs/basic", Some(&format!("address={address}")))
+        .await?;
+    assert_eq!(response.items, [output_id]);
+    assert_eq!(
+        client
+            .rest()
+            .get_bytes(&format!("api/core/v2/outputs/{output_id}"), None)
+            .await?,
+        client.get_output_raw(&output_id).await?
+    );
+
+    let block = client.build_block().with_tag(b"rest".to_vec()).finish().await?;
+    let response: SubmitBlockResponse = client
+        .rest()
+        .post("api/core/v2/blocks", &BlockDto::from(&block))
+        .await?;
+    assert_eq!(response.block_id, block.id());
+
+    assert!(matches!(
+        client
+            .rest()
+            .get::<serde_json::Value>("api/plugin/v1/missing", None)
+            .await,
+        Err(Error::Node(NodeApiError::NotFound(_)))
+    ));
+
+    Ok(())
+}
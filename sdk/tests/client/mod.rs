@@ -9,13 +9,18 @@ mod error;
 mod high_level;
 mod input_selection;
 mod input_signing_data;
+#[cfg(feature = "isc")]
+mod isc;
 mod mnemonic;
+#[cfg(feature = "testing")]
+mod mock;
 #[cfg(feature = "mqtt")]
 mod mqtt;
 mod node_api;
 mod secret_manager;
 mod signing;
 mod transactions;
+mod transport;
 
 use std::{
     collections::{BTreeSet, HashMap},
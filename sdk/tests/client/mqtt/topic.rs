@@ -1,7 +1,16 @@
 // Copyright 2023 IOTA Stiftung
 // SPDX-License-Identifier: Apache-2.0
 
-use iota_sdk::client::mqtt::{Error, Topic};
+use iota_sdk::{
+    client::mqtt::{Error, MqttPayload, Topic, TopicEvent, UnlockConditionTopic},
+    types::block::{
+        address::Bech32Address,
+        output::{AliasId, FoundryId, NftId, OutputId},
+        protocol::ProtocolParameters,
+        rand::{block::rand_block_id, transaction::rand_transaction_id},
+    },
+};
+use pretty_assertions::assert_eq;
 
 #[test]
 fn valid_topics() {
@@ -80,3 +89,87 @@ fn invalid_topics() {
         Err(Error::InvalidTopic(_))
     ));
 }
+
+#[test]
+fn topic_builder() {
+    let block_id = rand_block_id();
+    let transaction_id = rand_transaction_id();
+    let output_id = OutputId::new(transaction_id, 10).unwrap();
+    let address = "iota1qrwfnskm4f7utdrxqnkfntfqxehtpj8s0kf68zkcwm0yrhuemzjp5sjfw5v"
+        .parse::<Bech32Address>()
+        .unwrap();
+
+    assert_eq!(Topic::latest_milestone_info().as_str(), "milestone-info/latest");
+    assert_eq!(Topic::confirmed_milestone_info().as_str(), "milestone-info/confirmed");
+    assert_eq!(
+        Topic::tagged_data_transaction_blocks(None).unwrap().as_str(),
+        "blocks/transaction/tagged-data"
+    );
+    assert_eq!(
+        Topic::tagged_data_blocks(Some(b"hello")).unwrap().as_str(),
+        "blocks/tagged-data/0x68656c6c6f"
+    );
+    assert!(matches!(
+        Topic::tagged_data_blocks(Some(&[0; 65])),
+        Err(Error::InvalidTopic(_))
+    ));
+    assert_eq!(
+        Topic::transaction_included_block(&transaction_id).as_str(),
+        format!("transactions/{transaction_id}/included-block")
+    );
+    assert_eq!(
+        Topic::spent_unlock_condition_outputs(UnlockConditionTopic::Any, &address).as_str(),
+        format!("outputs/unlock/+/{address}/spent")
+    );
+
+    // All built topics are valid
+    for topic in [
+        Topic::milestones(),
+        Topic::blocks(),
+        Topic::transaction_blocks(),
+        Topic::tagged_data_transaction_blocks(Some(b"hello")).unwrap(),
+        Topic::tagged_data_blocks(None).unwrap(),
+        Topic::block_metadata(&block_id),
+        Topic::referenced_block_metadata(),
+        Topic::output(&output_id),
+        Topic::alias_outputs(&AliasId::from(&output_id)),
+        Topic::nft_outputs(&NftId::from(&output_id)),
+        Topic::foundry_outputs(&FoundryId::from([1; FoundryId::LENGTH])),
+        Topic::unlock_condition_outputs(UnlockConditionTopic::StorageReturn, &address),
+        Topic::unlock_condition_outputs(UnlockConditionTopic::ImmutableAlias, &address),
+        Topic::receipts(),
+    ] {
+        assert!(Topic::new(topic.as_str()).is_ok(), "{}", topic.as_str());
+    }
+}
+
+#[test]
+fn decode_payloads() {
+    let protocol_parameters = ProtocolParameters::default();
+
+    let event = TopicEvent::decode(
+        "milestone-info/latest",
+        br#"{"index":42,"timestamp":1700000000,"milestoneId":"0x36845227a59864ac12d3d2389fcb4ea0bdd1a5d1d4ed464bde3154216c3246c4"}"#,
+        &protocol_parameters,
+    )
+    .unwrap();
+    assert!(matches!(event.payload, MqttPayload::MilestoneInfo(info) if info.index == 42));
+
+    let block_id = rand_block_id();
+    let event = TopicEvent::decode(
+        format!("block-metadata/{block_id}"),
+        format!(r#"{{"blockId":"{block_id}","parents":[],"isSolid":true,"referencedByMilestoneIndex":42}}"#).as_bytes(),
+        &protocol_parameters,
+    )
+    .unwrap();
+    assert!(matches!(event.payload, MqttPayload::BlockMetadata(metadata) if metadata.block_id == block_id));
+
+    // Unexpected JSON is passed as is
+    let event = TopicEvent::decode("block-metadata/referenced", br#"{"unknown":1}"#, &protocol_parameters).unwrap();
+    assert!(matches!(event.payload, MqttPayload::Json(_)));
+
+    assert!(matches!(
+        TopicEvent::decode("blocks", &[1, 2, 3], &protocol_parameters),
+        Err(Error::InvalidPayload { .. })
+    ));
+}
@@ -28,3 +28,68 @@ async fn mnemonic_secret_manager() -> Result<()> {
 
     Ok(())
 }
+
+#[tokio::test]
+async fn mnemonic_with_passphrase_secret_manager() -> std::result::Result<(), Box<dyn std::error::Error>> {
+    let mnemonic = "acoustic trophy damage hint search taste love bicycle foster cradle brown govern endless depend situate athlete pudding blame question genius transfer van random vast";
+    let dto = format!(r#"{{"mnemonicWithPassphrase": {{"mnemonic": "{mnemonic}", "passphrase": "passphrase"}}}}"#);
+    let options = GetAddressesOptions::default()
+        .with_bech32_hrp(SHIMMER_TESTNET_BECH32_HRP)
+        .with_account_index(0)
+        .with_range(0..1);
+
+    let secret_manager: SecretManager = dto.parse()?;
+    let addresses = secret_manager.generate_ed25519_addresses(options.clone()).await?;
+
+    let secret_manager = SecretManager::try_from_mnemonic_with_passphrase(mnemonic.to_owned(), "passphrase")?;
+    assert_eq!(
+        secret_manager.generate_ed25519_addresses(options.clone()).await?,
+        addresses
+    );
+
+    // The passphrase leads to other addresses
+    let secret_manager = SecretManager::try_from_mnemonic(mnemonic.to_owned())?;
+    assert_ne!(secret_manager.generate_ed25519_addresses(options).await?, addresses);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn mnemonic_verify_backup() -> Result<()> {
+    let mnemonic = "acoustic trophy damage hint search taste love bicycle foster cradle brown govern endless depend situate athlete pudding blame question genius transfer van random vast";
+    let secret_manager = SecretManager::try_from_mnemonic(mnemonic.to_owned())?;
+
+    assert!(secret_manager.verify_backup(mnemonic.to_owned(), "").await?);
+    assert!(!secret_manager.verify_backup(mnemonic.to_owned(), "passphrase").await?);
+    assert!(
+        SecretManager::Placeholder
+            .verify_backup(mnemonic.to_owned(), "")
+            .await
+            .is_err()
+    );
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn mnemonic_derive_child_mnemonic() -> Result<()> {
+    let mnemonic = "acoustic trophy damage hint search taste love bicycle foster cradle brown govern endless depend situate athlete pudding blame question genius transfer van random vast";
+    let secret_manager = SecretManager::try_from_mnemonic(mnemonic.to_owned())?;
+
+    let child_mnemonic = secret_manager.derive_child_mnemonic(0).await?;
+    assert_eq!(child_mnemonic.split_whitespace().count(), 24);
+    // Derivation is deterministic
+    assert_eq!(&**secret_manager.derive_child_mnemonic(0).await?, &**child_mnemonic);
+    assert_ne!(&**secret_manager.derive_child_mnemonic(1).await?, &**child_mnemonic);
+
+    // The child mnemonic can be used as the root of its own secret manager
+    let child_secret_manager = SecretManager::try_from_mnemonic(child_mnemonic.clone())?;
+    assert_ne!(
+        &**child_secret_manager.derive_child_mnemonic(0).await?,
+        &**child_mnemonic
+    );
+
+    assert!(SecretManager::Placeholder.derive_child_mnemonic(0).await.is_err());
+
+    Ok(())
+}
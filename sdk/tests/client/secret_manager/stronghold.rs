@@ -2,7 +2,7 @@
 // SPDX-License-Identifier: Apache-2.0
 
 use iota_sdk::client::{
-    api::GetAddressesOptions, constants::SHIMMER_TESTNET_BECH32_HRP, secret::SecretManager, Result,
+    api::GetAddressesOptions, constants::SHIMMER_TESTNET_BECH32_HRP, secret::SecretManager, MnemonicStrength, Result,
 };
 use pretty_assertions::assert_eq;
 
@@ -81,3 +81,180 @@ async fn stronghold_mnemonic_missing() -> Result<()> {
     std::fs::remove_dir_all("stronghold_mnemonic_missing").ok();
     Ok(())
 }
+
+#[tokio::test]
+async fn stronghold_mnemonic_with_passphrase() -> Result<()> {
+    iota_stronghold::engine::snapshot::try_set_encrypt_work_factor(0).unwrap();
+
+    let stronghold_path = "stronghold_mnemonic_with_passphrase";
+    // Cleanup of a possibly failed run
+    std::fs::remove_dir_all(stronghold_path).ok();
+    let mnemonic = "acoustic trophy damage hint search taste love bicycle foster cradle brown govern endless depend situate athlete pudding blame question genius transfer van random vast";
+    let options = GetAddressesOptions::default()
+        .with_bech32_hrp(SHIMMER_TESTNET_BECH32_HRP)
+        .with_account_index(0)
+        .with_range(0..1);
+
+    let stronghold_secret_manager = iota_sdk::client::secret::stronghold::StrongholdSecretManager::builder()
+        .password("some_hopefully_secure_password".to_owned())
+        .build(format!("{stronghold_path}/test.stronghold"))?;
+    stronghold_secret_manager
+        .store_mnemonic_with_passphrase(crypto::keys::bip39::Mnemonic::from(mnemonic.to_owned()), "passphrase")
+        .await?;
+    let addresses = SecretManager::Stronghold(stronghold_secret_manager)
+        .generate_ed25519_addresses(options.clone())
+        .await?;
+
+    // Same addresses as with the mnemonic secret manager
+    let secret_manager = SecretManager::try_from_mnemonic_with_passphrase(mnemonic.to_owned(), "passphrase")?;
+    assert_eq!(secret_manager.generate_ed25519_addresses(options).await?, addresses);
+
+    // Remove garbage after test, but don't care about the result
+    std::fs::remove_dir_all(stronghold_path).ok();
+    Ok(())
+}
+
+#[tokio::test]
+async fn stronghold_verify_backup() -> Result<()> {
+    iota_stronghold::engine::snapshot::try_set_encrypt_work_factor(0).unwrap();
+
+    let stronghold_path = "stronghold_verify_backup";
+    // Cleanup of a possibly failed run
+    std::fs::remove_dir_all(stronghold_path).ok();
+    let mnemonic = "acoustic trophy damage hint search taste love bicycle foster cradle brown govern endless depend situate athlete pudding blame question genius transfer van random vast";
+
+    let stronghold_secret_manager = iota_sdk::client::secret::stronghold::StrongholdSecretManager::builder()
+        .password("some_hopefully_secure_password".to_owned())
+        .build(format!("{stronghold_path}/test.stronghold"))?;
+    stronghold_secret_manager
+        .store_mnemonic_with_passphrase(crypto::keys::bip39::Mnemonic::from(mnemonic.to_owned()), "passphrase")
+        .await?;
+    let secret_manager = SecretManager::Stronghold(stronghold_secret_manager);
+
+    assert!(secret_manager.verify_backup(mnemonic.to_owned(), "passphrase").await?);
+    // The passphrase is part of the backup
+    assert!(!secret_manager.verify_backup(mnemonic.to_owned(), "").await?);
+    assert!(
+        !secret_manager
+            .verify_backup(
+                "until fire hat mountain zoo grocery real deny advance change marble taste goat ivory wheat bubble panic banner tattoo client ticket action race rocket".to_owned(),
+                "passphrase"
+            )
+            .await?
+    );
+    // Words in the wrong order don't form a valid mnemonic
+    assert!(
+        secret_manager
+            .verify_backup(mnemonic.replacen("acoustic trophy", "trophy acoustic", 1), "passphrase")
+            .await
+            .is_err()
+    );
+
+    // Remove garbage after test, but don't care about the result
+    std::fs::remove_dir_all(stronghold_path).ok();
+    Ok(())
+}
+
+#[tokio::test]
+async fn stronghold_derive_child_mnemonic() -> Result<()> {
+    iota_stronghold::engine::snapshot::try_set_encrypt_work_factor(0).unwrap();
+
+    let stronghold_path = "stronghold_derive_child_mnemonic";
+    // Cleanup of a possibly failed run
+    std::fs::remove_dir_all(stronghold_path).ok();
+    let mnemonic = "acoustic trophy damage hint search taste love bicycle foster cradle brown govern endless depend situate athlete pudding blame question genius transfer van random vast";
+
+    let stronghold_secret_manager = iota_sdk::client::secret::stronghold::StrongholdSecretManager::builder()
+        .password("some_hopefully_secure_password".to_owned())
+        .build(format!("{stronghold_path}/test.stronghold"))?;
+    stronghold_secret_manager
+        .store_mnemonic(crypto::keys::bip39::Mnemonic::from(mnemonic.to_owned()))
+        .await?;
+    let stronghold_secret_manager = SecretManager::Stronghold(stronghold_secret_manager);
+    let mnemonic_secret_manager = SecretManager::try_from_mnemonic(mnemonic.to_owned())?;
+
+    // Same child mnemonics as with the mnemonic secret manager
+    for (index, strength) in [(0, MnemonicStrength::Words24), (1, MnemonicStrength::Words12)] {
+        assert_eq!(
+            &**stronghold_secret_manager
+                .derive_child_mnemonic_with_strength(index, strength)
+                .await?,
+            &**mnemonic_secret_manager
+                .derive_child_mnemonic_with_strength(index, strength)
+                .await?
+        );
+    }
+
+    // Remove garbage after test, but don't care about the result
+    std::fs::remove_dir_all(stronghold_path).ok();
+    Ok(())
+}
+
+#[tokio::test]
+async fn stronghold_inventory() -> Result<()> {
+    use iota_sdk::client::{
+        storage::StorageAdapter,
+        stronghold::{Error, StrongholdAdapter, StrongholdStoreKey},
+    };
+
+    iota_stronghold::engine::snapshot::try_set_encrypt_work_factor(0).unwrap();
+
+    let stronghold_path = "stronghold_inventory";
+    // Cleanup of a possibly failed run
+    std::fs::remove_dir_all(stronghold_path).ok();
+    let snapshot_path = format!("{stronghold_path}/test.stronghold");
+
+    let stronghold = StrongholdAdapter::builder()
+        .password("some_hopefully_secure_password".to_owned())
+        .build(&snapshot_path)?;
+    let inventory = stronghold.inventory().await?;
+    assert!(!inventory.mnemonic);
+    assert!(inventory.store_keys.is_empty());
+
+    stronghold
+        .store_mnemonic(crypto::keys::bip39::Mnemonic::from("acoustic trophy damage hint search taste love bicycle foster cradle brown govern endless depend situate athlete pudding blame question genius transfer van random vast".to_owned()))
+        .await?;
+    stronghold.set_bytes("key_1", b"value").await?;
+    stronghold.set_bytes("key_0", b"value").await?;
+    stronghold.write_stronghold_snapshot(None).await?;
+
+    // The content is persisted
+    let stronghold = StrongholdAdapter::builder()
+        .password("some_hopefully_secure_password".to_owned())
+        .build(&snapshot_path)?;
+    let inventory = stronghold.inventory().await?;
+    assert!(inventory.mnemonic);
+    assert_eq!(
+        inventory.store_keys,
+        ["key_0", "key_1"]
+            .into_iter()
+            .map(|key| StrongholdStoreKey {
+                namespace: "iota_seed".to_owned(),
+                key: key.to_owned(),
+            })
+            .collect::<Vec<_>>()
+    );
+
+    stronghold.delete_store_entry("key_0").await?;
+    assert!(matches!(
+        stronghold.delete_store_entry("key_0").await,
+        Err(Error::StoreEntryMissing(key)) if key == "key_0"
+    ));
+    stronghold.delete_mnemonic().await?;
+    assert!(matches!(
+        stronghold.delete_mnemonic().await,
+        Err(Error::MnemonicMissing)
+    ));
+
+    let stronghold = StrongholdAdapter::builder()
+        .password("some_hopefully_secure_password".to_owned())
+        .build(&snapshot_path)?;
+    let inventory = stronghold.inventory().await?;
+    assert!(!inventory.mnemonic);
+    assert_eq!(inventory.store_keys.len(), 1);
+    assert_eq!(stronghold.get_bytes("key_1").await?.as_deref(), Some(&b"value"[..]));
+
+    // Remove garbage after test, but don't care about the result
+    std::fs::remove_dir_all(stronghold_path).ok();
+    Ok(())
+}
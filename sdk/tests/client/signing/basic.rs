@@ -321,5 +321,17 @@ async fn two_signature_unlocks() -> Result<()> {
         panic!("{conflict:?}, with {tx_payload:#?}");
     }
 
+    // The inputs are matched by their output ID
+    let mut inputs_data = prepared_transaction_data.inputs_data.clone();
+    inputs_data.reverse();
+    assert_eq!(
+        verify_semantic(&inputs_data, &tx_payload, current_time)?,
+        ConflictReason::None
+    );
+    assert_eq!(
+        verify_semantic(&inputs_data[..1], &tx_payload, current_time)?,
+        ConflictReason::InputUtxoNotFound
+    );
+
     Ok(())
 }
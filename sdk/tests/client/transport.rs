@@ -0,0 +1,283 @@
+// Copyright 2024 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use std::sync::{Arc, Mutex};
+
+use async_trait::async_trait;
+use iota_sdk::client::{
+    node_api::error::{Error as NodeApiError, Result as NodeApiResult},
+    node_manager::{
+        node::NodeAuth,
+        tls_pin::TlsPin,
+        transport::{HttpTransport, TransportRequest, TransportResponse},
+    },
+    Client, ClientBuilder, Error,
+};
+
+// Answers the requests in-process and records them
+#[derive(Debug, Default)]
+struct RecordingTransport {
+    requests: Arc<Mutex<Vec<TransportRequest>>>,
+}
+
+#[async_trait]
+impl HttpTransport for RecordingTransport {
+    async fn send(&self, request: TransportRequest) -> NodeApiResult<TransportResponse> {
+        let response = match request.url.path() {
+            "/api/plugin/v1/status" => TransportResponse {
+                status: 200,
+                body: br#"{"running":true}"#.to_vec(),
+            },
+            "/api/plugin/v1/unreachable" => return Err(NodeApiError::Transport("connection refused".to_string())),
+            _ => TransportResponse {
+                status: 404,
+                body: Vec::new(),
+            },
+        };
+        self.requests.lock().unwrap().push(request);
+        Ok(response)
+    }
+}
+
+#[tokio::test]
+async fn custom_transport() -> Result<(), Box<dyn std::error::Error>> {
+    let transport = RecordingTransport::default();
+    let requests = transport.requests.clone();
+    let client = Client::builder()
+        .with_node_auth("http://node.local", NodeAuth::default().with_header("x-api-key", "key"))?
+        .with_ignore_node_health()
+        .with_transport(transport)
+        .finish()
+        .await?;
+
+    let status: serde_json::Value = client.rest().get("api/plugin/v1/status", Some("verbose=true")).await?;
+    assert_eq!(status, serde_json::json!({ "running": true }));
+    {
+        let requests = requests.lock().unwrap();
+        let request = requests.last().unwrap();
+        assert_eq!(
+            request.url.as_str(),
+            "http://node.local/api/plugin/v1/status?verbose=true"
+        );
+        assert_eq!(request.headers["x-api-key"], "key");
+    }
+
+    assert!(matches!(
+        client
+            .rest()
+            .get::<serde_json::Value>("api/plugin/v1/missing", None)
+            .await,
+        Err(Error::Node(NodeApiError::NotFound(_)))
+    ));
+    assert!(matches!(
+        client
+            .rest()
+            .get::<serde_json::Value>("api/plugin/v1/unreachable", None)
+            .await,
+        Err(Error::Node(NodeApiError::Transport(_)))
+    ));
+
+    // The transport is kept when the client options are rebuilt
+    let options = ClientBuilder::from_client(&client).await;
+    assert!(options.node_manager_builder.transport.is_some());
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn node_auth_header() -> Result<(), Box<dyn std::error::Error>> {
+    let basic_auth = NodeAuth {
+        basic_auth_name_pwd: Some(("name".to_string(), "password".to_string())),
+        ..Default::default()
+    };
+    let jwt_and_basic_auth = NodeAuth {
+        jwt: Some("jwt".to_string()),
+        ..basic_auth.clone()
+    };
+
+    for (auth, expected) in [
+        (basic_auth, "Basic bmFtZTpwYXNzd29yZA=="),
+        (jwt_and_basic_auth, "Bearer jwt"),
+    ] {
+        let transport = RecordingTransport::default();
+        let requests = transport.requests.clone();
+        let client = Client::builder()
+            .with_node_auth("http://node.local", auth)?
+            .with_ignore_node_health()
+            .with_transport(transport)
+            .finish()
+            .await?;
+        client
+            .rest()
+            .get::<serde_json::Value>("api/plugin/v1/status", None)
+            .await?;
+
+        let requests = requests.lock().unwrap();
+        let authorization = requests[0].headers.get_all("authorization").iter().collect::<Vec<_>>();
+        assert_eq!(authorization, [expected]);
+        assert!(requests[0].url.username().is_empty());
+    }
+
+    Ok(())
+}
+
+#[cfg(all(unix, feature = "unix_socket"))]
+#[tokio::test]
+async fn unix_socket_transport() -> Result<(), Box<dyn std::error::Error>> {
+    use iota_sdk::client::node_manager::transport::UnixSocketTransport;
+    use tokio::io::{AsyncReadExt, AsyncWriteExt};
+
+    let path = std::env::temp_dir().join(format!("iota-sdk-transport-{}.sock", std::process::id()));
+    let _ = std::fs::remove_file(&path);
+    let listener = tokio::net::UnixListener::bind(&path)?;
+    let request_lines = Arc::new(Mutex::new(Vec::new()));
+    let request_lines_clone = request_lines.clone();
+    let server = tokio::spawn(async move {
+        loop {
+            let (mut stream, _) = listener.accept().await.unwrap();
+            let mut request = Vec::new();
+            let mut buffer = [0; 1024];
+            while !request.ends_with(b"\r\n\r\n") {
+                let read = stream.read(&mut buffer).await.unwrap();
+                request.extend_from_slice(&buffer[..read]);
+            }
+            let request = String::from_utf8(request).unwrap();
+            request_lines_clone
+                .lock()
+                .unwrap()
+                .push(request.lines().next().unwrap().to_string());
+
+            let body = r#"{"running":true}"#;
+            let response = format!(
+                "HTTP/1.1 200 OK\r\ncontent-type: application/json\r\ncontent-length: {}\r\n\r\n{body}",
+                body.len()
+            );
+            stream.write_all(response.as_bytes()).await.unwrap();
+        }
+    });
+
+    let client = Client::builder()
+        .with_node("http://node.local")?
+        .with_ignore_node_health()
+        .with_transport(UnixSocketTransport::new(&path))
+        .finish()
+        .await?;
+    let status: serde_json::Value = client.rest().get("api/plugin/v1/status", None).await?;
+    assert_eq!(status, serde_json::json!({ "running": true }));
+
+    let request_line = "GET /api/plugin/v1/status HTTP/1.1".to_string();
+    assert!(request_lines.lock().unwrap().contains(&request_line));
+    server.abort();
+    std::fs::remove_file(&path)?;
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn proxy() -> Result<(), Box<dyn std::error::Error>> {
+    use std::io::{BufRead, BufReader, Write};
+
+    assert!(Client::builder().with_proxy("ftp://127.0.0.1:21").is_err());
+
+    // An http proxy which answers every request itself and closes the connection afterwards
+    let listener = std::net::TcpListener::bind("127.0.0.1:0")?;
+    let proxy = format!("http://{}", listener.local_addr()?);
+    let request_lines = Arc::new(Mutex::new(Vec::new()));
+    let request_lines_clone = request_lines.clone();
+    std::thread::spawn(move || {
+        for stream in listener.incoming() {
+            let mut stream = stream.unwrap();
+            let mut reader = BufReader::new(stream.try_clone().unwrap());
+            let mut request_line = String::new();
+            reader.read_line(&mut request_line).unwrap();
+            let mut line = String::new();
+            while line != "\r\n" {
+                line.clear();
+                reader.read_line(&mut line).unwrap();
+            }
+            request_lines_clone
+                .lock()
+                .unwrap()
+                .push(request_line.trim_end().to_string());
+
+            let body = r#"{"running":true}"#;
+            let response = format!(
+                "HTTP/1.1 200 OK\r\ncontent-type: application/json\r\ncontent-length: {}\r\nconnection: close\r\n\r\n\
+                 {body}",
+                body.len()
+            );
+            stream.write_all(response.as_bytes()).unwrap();
+        }
+    });
+
+    // The node can't be resolved, so the request only succeeds through the proxy
+    let client = Client::builder()
+        .with_node("http://node.invalid")?
+        .with_ignore_node_health()
+        .with_proxy(&proxy)?
+        .finish()
+        .await?;
+    let status: serde_json::Value = client.rest().get("api/plugin/v1/status", None).await?;
+    assert_eq!(status, serde_json::json!({ "running": true }));
+    let request_line = "GET http://node.invalid/api/plugin/v1/status HTTP/1.1".to_string();
+    assert!(request_lines.lock().unwrap().contains(&request_line));
+
+    // A node with its own proxy doesn't use the proxy of the client
+    let client = Client::builder()
+        .with_node_proxy("http://other.invalid", None, &proxy)?
+        .with_ignore_node_health()
+        .with_proxy("http://127.0.0.1:1")?
+        .finish()
+        .await?;
+    let status: serde_json::Value = client.rest().get("api/plugin/v1/status", None).await?;
+    assert_eq!(status, serde_json::json!({ "running": true }));
+
+    Ok(())
+}
+
+// A self-signed certificate for node.local
+const CERTIFICATE: &str = "0x3082017e30820124a00302010202134c3e5f128a27f9839d0c974fe8b7a46dd2a06a300a06082a8648ce3d04030230153113301106035504030c0a6e6f64652e6c6f63616c301e170d3236313031363233313435305a170d3336313031333233313435305a30153113301106035504030c0a6e6f64652e6c6f63616c3059301306072a8648ce3d020106082a8648ce3d0301070342000405a8d8f600f4d2872d12918c77b5464101d77d5393533019147e7aa1fa0d8879d7594b67d2355f8c52841416ed028b61c23cd7d48cf8e3933e89cfd34373987da3533051301d0603551d0e04160414fa0f4ba396bac681bee7b1ef33796a71c430c3ae301f0603551d23041830168014fa0f4ba396bac681bee7b1ef33796a71c430c3ae300f0603551d130101ff040530030101ff300a06082a8648ce3d040302034800304502206b5a3dbd32599222d5a3ee601119a5ff4632a9d5990fb81fd8c246274515b3cf022100a6bddc4d38f38fb0ff3b043b3c228d02a9eac8c9901ac351a0a1edb8fe79d308";
+
+#[tokio::test]
+async fn tls_pin() -> Result<(), Box<dyn std::error::Error>> {
+    let certificate = prefix_hex::decode::<Vec<u8>>(CERTIFICATE)?;
+
+    // Hashes of the certificate and its public key computed with openssl
+    let certificate_pin = serde_json::from_value::<TlsPin>(serde_json::json!({
+        "type": "certificate",
+        "sha256": "0xfed83280b630bf776c5ca626b8bde9d205e5666f367acf0ed78f40fff0b4b389"
+    }))?;
+    let public_key_pin = serde_json::from_value::<TlsPin>(serde_json::json!({
+        "type": "publicKey",
+        "sha256": "0x5cd5d79d0f7f19d30edf010c1ac22f2d7118c652872c6a729db8af13f0961ccf"
+    }))?;
+    assert_eq!(TlsPin::certificate(&certificate), certificate_pin);
+    assert_eq!(TlsPin::public_key(&certificate), Some(public_key_pin));
+    assert!(certificate_pin.matches(&certificate));
+    assert!(public_key_pin.matches(&certificate));
+
+    let mut other_certificate = certificate.clone();
+    *other_certificate.last_mut().unwrap() ^= 1;
+    assert!(!certificate_pin.matches(&other_certificate));
+    assert!(public_key_pin.matches(&other_certificate));
+    assert_eq!(TlsPin::public_key(&certificate[..100]), None);
+
+    // Pins are only accepted for https nodes
+    let http_node = Client::builder().with_tls_pin("http://node.local", public_key_pin);
+    assert!(http_node.is_err());
+
+    let client = Client::builder()
+        .with_node("https://node.local:14265")?
+        .with_ignore_node_health()
+        .with_tls_pin("https://node.local:14265", public_key_pin)?
+        .with_tls_pin("https://node.local", certificate_pin)?
+        .finish()
+        .await?;
+    let options = serde_json::to_value(ClientBuilder::from_client(&client).await)?;
+    assert_eq!(
+        options["tlsPins"],
+        serde_json::json!({ "node.local": [public_key_pin, certificate_pin] })
+    );
+
+    Ok(())
+}
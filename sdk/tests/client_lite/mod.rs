@@ -0,0 +1,101 @@
+// Copyright 2024 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use std::collections::HashMap;
+
+use iota_sdk::{
+    client_lite::{Error, LiteClient, LiteMethod, LiteRequest, LiteResponse, LiteTransport, BINARY_CONTENT_TYPE},
+    types::{
+        api::core::response::{OutputWithMetadataResponse, SubmitBlockResponse},
+        block::{
+            output::OutputWithMetadata,
+            protocol::protocol_parameters,
+            rand::{
+                block::{rand_block, rand_block_id},
+                output::{metadata::rand_output_metadata, rand_output, rand_output_id},
+            },
+        },
+    },
+};
+use packable::PackableExt;
+
+// Answers the requests with the responses of their paths and records them
+#[derive(Default)]
+struct MockTransport {
+    responses: HashMap<String, LiteResponse>,
+    requests: Vec<(LiteMethod, String, String, Vec<u8>)>,
+}
+
+impl MockTransport {
+    fn with_json(mut self, path: String, status: u16, body: &impl serde::Serialize) -> Self {
+        let body = serde_json::to_vec(body).unwrap();
+        self.responses.insert(path, LiteResponse { status, body });
+        self
+    }
+}
+
+impl LiteTransport for MockTransport {
+    type Error = &'static str;
+
+    fn send(&mut self, request: LiteRequest<'_>) -> Result<LiteResponse, Self::Error> {
+        self.requests.push((
+            request.method,
+            request.path.to_string(),
+            request.content_type.to_string(),
+            request.body.to_vec(),
+        ));
+        Ok(self.responses.get(request.path).cloned().unwrap_or(LiteResponse {
+            status: 404,
+            body: Vec::new(),
+        }))
+    }
+}
+
+#[test]
+fn lite_client() {
+    let protocol_parameters = protocol_parameters();
+    let output_id = rand_output_id();
+    let output = OutputWithMetadata::new(rand_output(protocol_parameters.token_supply()), rand_output_metadata());
+    let block = rand_block();
+    let block_id = rand_block_id();
+
+    let transport = MockTransport::default()
+        .with_json(
+            format!("/api/core/v2/outputs/{output_id}"),
+            200,
+            &OutputWithMetadataResponse::from(&output),
+        )
+        .with_json(
+            format!("/api/core/v2/outputs/{output_id}/metadata"),
+            200,
+            output.metadata(),
+        )
+        .with_json(
+            "/api/core/v2/blocks".to_string(),
+            201,
+            &SubmitBlockResponse { block_id },
+        );
+    let mut client = LiteClient::new(transport).with_protocol_parameters(protocol_parameters);
+
+    let received_output = client.get_output(&output_id).unwrap();
+    assert_eq!(received_output.output(), output.output());
+    assert_eq!(received_output.metadata(), output.metadata());
+    assert_eq!(&client.get_output_metadata(&output_id).unwrap(), output.metadata());
+    let missing_output = client.get_output(&rand_output_id());
+    assert!(matches!(missing_output, Err(Error::NotFound)));
+
+    assert_eq!(client.post_block(&block).unwrap(), block_id);
+    let (method, path, content_type, body) = client.transport().requests.last().unwrap();
+    assert_eq!(*method, LiteMethod::Post);
+    assert_eq!(path, "/api/core/v2/blocks");
+    assert_eq!(content_type, BINARY_CONTENT_TYPE);
+    assert_eq!(*body, block.pack_to_vec());
+
+    // The protocol parameters were set, so the info wasn't requested
+    let requested_info = client
+        .transport()
+        .requests
+        .iter()
+        .any(|(_, path, _, _)| path == "/api/core/v2/info");
+    assert!(!requested_info);
+}
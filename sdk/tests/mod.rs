@@ -3,7 +3,9 @@
 
 #[cfg(feature = "client")]
 mod client;
-#[cfg(feature = "pow")]
+#[cfg(feature = "client_lite")]
+mod client_lite;
+#[cfg(feature = "pow_score")]
 mod pow;
 mod types;
 mod utils;
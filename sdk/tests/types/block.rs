@@ -37,6 +37,7 @@ fn pow_provider() {
     let score = PowScorer::new().score(&block_bytes);
 
     assert!(score >= min_pow_score as f64);
+    assert_eq!(block.pow_score(), score);
 }
 
 #[test]
@@ -21,6 +21,7 @@ mod receipt_milestone_option;
 mod rent;
 mod tagged_data_payload;
 mod tail_transaction_hash;
+mod token_amount;
 mod transaction_essence;
 mod transaction_id;
 mod transaction_payload;
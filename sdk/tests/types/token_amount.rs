@@ -0,0 +1,124 @@
+// Copyright 2024 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use iota_sdk::types::block::{
+    Error,
+    output::{NativeToken, NativeTokens, TokenAmount, TokenId},
+};
+use pretty_assertions::assert_eq;
+use primitive_types::U256;
+
+#[test]
+fn checked_arithmetic() {
+    let token_id = TokenId::from([1; TokenId::LENGTH]);
+    let amount = TokenAmount::new(token_id, 100u32);
+
+    assert_eq!(
+        amount.checked_add(TokenAmount::new(token_id, 50u32)).unwrap().amount(),
+        U256::from(150)
+    );
+    assert_eq!(
+        amount.checked_sub(TokenAmount::new(token_id, 100u32)).unwrap(),
+        TokenAmount::zero(token_id)
+    );
+    assert!(matches!(
+        amount.checked_sub(TokenAmount::new(token_id, 101u32)),
+        Err(Error::NativeTokensUnderflow)
+    ));
+    assert!(matches!(
+        TokenAmount::new(token_id, U256::MAX).checked_add(amount),
+        Err(Error::NativeTokensOverflow)
+    ));
+
+    assert_eq!(
+        TokenAmount::new(token_id, U256::MAX)
+            .saturating_add(amount)
+            .unwrap()
+            .amount(),
+        U256::MAX
+    );
+    assert!(
+        amount
+            .saturating_sub(TokenAmount::new(token_id, 101u32))
+            .unwrap()
+            .is_zero()
+    );
+}
+
+#[test]
+fn different_tokens() {
+    let token_id = TokenId::from([1; TokenId::LENGTH]);
+    let other_token_id = TokenId::from([2; TokenId::LENGTH]);
+    let amount = TokenAmount::new(token_id, 100u32);
+    let other_amount = TokenAmount::new(other_token_id, 100u32);
+
+    for result in [
+        amount.checked_add(other_amount),
+        amount.checked_sub(other_amount),
+        amount.saturating_add(other_amount),
+        amount.saturating_sub(other_amount),
+    ] {
+        assert!(matches!(
+            result,
+            Err(Error::TokenIdMismatch { expected, actual }) if expected == token_id && actual == other_token_id
+        ));
+    }
+}
+
+#[test]
+fn ratios() {
+    let token_id = TokenId::from([1; TokenId::LENGTH]);
+    let amount = TokenAmount::new(token_id, 1_000u32);
+
+    assert_eq!(amount.checked_percentage(15).unwrap().amount(), U256::from(150));
+    assert_eq!(amount.checked_mul_ratio(1u8, 3u8).unwrap().amount(), U256::from(333));
+    assert!(matches!(
+        amount.checked_mul_ratio(1u8, 0u8),
+        Err(Error::NativeTokensDivisionByZero)
+    ));
+    // The intermediate product doesn't overflow
+    assert_eq!(
+        TokenAmount::new(token_id, U256::MAX)
+            .checked_mul_ratio(2u8, 4u8)
+            .unwrap()
+            .amount(),
+        U256::MAX / 2
+    );
+    assert!(matches!(
+        TokenAmount::new(token_id, U256::MAX).checked_mul_ratio(2u8, 1u8),
+        Err(Error::NativeTokensOverflow)
+    ));
+
+    assert_eq!(
+        TokenAmount::new(token_id, 250u32).basis_points_of(&amount).unwrap(),
+        U256::from(2_500)
+    );
+    assert!(matches!(
+        amount.basis_points_of(&TokenAmount::zero(token_id)),
+        Err(Error::NativeTokensDivisionByZero)
+    ));
+}
+
+#[test]
+fn native_token_conversions() {
+    let token_id = TokenId::from([1; TokenId::LENGTH]);
+    let native_token = NativeToken::new(token_id, 100u32).unwrap();
+
+    assert_eq!(TokenAmount::from(native_token), TokenAmount::new(token_id, 100u32));
+    assert_eq!(
+        NativeToken::try_from(TokenAmount::from(native_token)).unwrap(),
+        native_token
+    );
+    assert!(matches!(
+        NativeToken::try_from(TokenAmount::zero(token_id)),
+        Err(Error::NativeTokensNullAmount)
+    ));
+
+    let native_tokens = NativeTokens::from_vec(vec![native_token]).unwrap();
+    assert_eq!(native_tokens.token_amount(&token_id), TokenAmount::from(native_token));
+    let other_token_id = TokenId::from([2; TokenId::LENGTH]);
+    assert_eq!(
+        native_tokens.token_amount(&other_token_id),
+        TokenAmount::zero(other_token_id)
+    );
+}
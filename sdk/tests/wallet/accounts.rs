@@ -2,6 +2,22 @@
 // SPDX-License-Identifier: Apache-2.0
 
 use iota_sdk::wallet::Result;
+#[cfg(feature = "testing")]
+use iota_sdk::{
+    types::block::{
+        address::{Address, AliasAddress},
+        output::{
+            unlock_condition::{
+                AddressUnlockCondition, GovernorAddressUnlockCondition, StateControllerAddressUnlockCondition,
+            },
+            AliasId, AliasOutputBuilder, BasicOutputBuilder, OutputId,
+        },
+    },
+    wallet::{
+        account::{types::OutputFilter, SyncOptions},
+        Error,
+    },
+};
 use pretty_assertions::assert_eq;
 #[cfg(feature = "stronghold")]
 use {
@@ -12,6 +28,8 @@ use {
     iota_sdk::wallet::{ClientOptions, Wallet},
 };
 
+#[cfg(feature = "testing")]
+use crate::wallet::common::{add_basic_output, create_account_and_address, setup_mock_wallet};
 use crate::wallet::common::{make_wallet, setup, tear_down};
 
 #[tokio::test]
@@ -190,3 +208,293 @@ async fn account_creation_stronghold() -> Result<()> {
 
     tear_down(storage_path)
 }
+
+#[cfg(feature = "testing")]
+#[tokio::test]
+async fn mock_identity_alias() -> Result<()> {
+    let storage_path = "test-storage/mock_identity_alias";
+    let (mock_client, wallet) = setup_mock_wallet(storage_path).await?;
+    let token_supply = mock_client.protocol_parameters().token_supply();
+    let (account_0, address_0) = create_account_and_address(&wallet).await?;
+    let (account_1, address_1) = create_account_and_address(&wallet).await?;
+
+    let alias_output_id = mock_client.add_output(
+        AliasOutputBuilder::new_with_amount(100_000, AliasId::null())
+            .add_unlock_condition(StateControllerAddressUnlockCondition::new(address_0))
+            .add_unlock_condition(GovernorAddressUnlockCondition::new(address_0))
+            .finish_output(token_supply)?,
+    );
+    let alias_id = AliasId::from(&alias_output_id);
+    add_basic_output(&mock_client, address_0, 1_000_000)?;
+
+    // The alias has to be synced first
+    assert!(matches!(
+        account_0.set_identity_alias(alias_id).await,
+        Err(Error::AliasNotFoundInUnspentOutputs(id)) if id == alias_id
+    ));
+    account_0.sync(None).await?;
+    account_0.set_identity_alias(alias_id).await?;
+    assert_eq!(
+        account_0.identity_address().await?.map(|address| *address.inner()),
+        Some(Address::Alias(AliasAddress::new(alias_id)))
+    );
+
+    // The remainder is sent to the alias address
+    account_0.send(200_000, address_1, None).await?;
+    let alias_owned_amount = || {
+        mock_client
+            .unspent_outputs()
+            .iter()
+            .filter(|output| {
+                output
+                    .output()
+                    .unlock_conditions()
+                    .and_then(|u| u.address())
+                    .map(|u| *u.address())
+                    == Some(Address::Alias(AliasAddress::new(alias_id)))
+            })
+            .map(|output| output.output().amount())
+            .sum::<u64>()
+    };
+    assert_eq!(alias_owned_amount(), 800_000);
+
+    // Outputs owned by the alias are synced and unlocked by transitioning the alias
+    let balance = account_0.sync(None).await?;
+    assert_eq!(balance.base_coin().total(), 900_000);
+    account_0.send(300_000, address_1, None).await?;
+    assert!(mock_client.output(&alias_output_id).unwrap().metadata().is_spent());
+    assert_eq!(alias_owned_amount(), 500_000);
+
+    let balance = account_1.sync(None).await?;
+    assert_eq!(balance.base_coin().available(), 500_000);
+
+    account_0.set_identity_alias(None).await?;
+    assert_eq!(account_0.identity_address().await?, None);
+
+    tear_down(storage_path)
+}
+
+#[cfg(feature = "testing")]
+#[tokio::test]
+async fn mock_output_annotations() -> Result<()> {
+    let storage_path = "test-storage/mock_output_annotations";
+    let (mock_client, wallet) = setup_mock_wallet(storage_path).await?;
+    let token_supply = mock_client.protocol_parameters().token_supply();
+    let (account, address) = create_account_and_address(&wallet).await?;
+
+    let output_ids = [1_000_000, 2_000_000].map(|amount| {
+        mock_client.add_output(
+            BasicOutputBuilder::new_with_amount(amount)
+                .add_unlock_condition(AddressUnlockCondition::new(address))
+                .finish_output(token_supply)
+                .unwrap(),
+        )
+    });
+    account.sync(None).await?;
+
+    account.set_output_annotation(&output_ids[0], "escrow", "42").await?;
+    account.set_output_annotation(&output_ids[1], "escrow", "43").await?;
+    assert!(matches!(
+        account.set_output_annotation(&OutputId::null(), "escrow", "44").await,
+        Err(Error::OutputNotFoundInAccount(_))
+    ));
+
+    // Annotations are kept when syncing the outputs again
+    account.sync(None).await?;
+    let output_data = account.get_output(&output_ids[0]).await.unwrap();
+    assert_eq!(output_data.annotations.get("escrow").map(String::as_str), Some("42"));
+    let unspent_outputs = account.unspent_outputs(None).await?;
+    assert!(unspent_outputs
+        .iter()
+        .all(|output_data| output_data.annotations.contains_key("escrow")));
+
+    let escrow_42 = OutputFilter::Annotation {
+        key: "escrow".to_string(),
+        value: Some("42".to_string()),
+    };
+    let outputs = account.query_outputs(escrow_42.clone()).await;
+    assert_eq!(outputs.len(), 1);
+    assert_eq!(outputs[0].output_id, output_ids[0]);
+
+    assert_eq!(
+        account
+            .remove_output_annotation(&output_ids[0], "escrow")
+            .await?
+            .as_deref(),
+        Some("42")
+    );
+    assert!(account.query_outputs(escrow_42).await.is_empty());
+    let outputs = account
+        .query_outputs(OutputFilter::Annotation {
+            key: "escrow".to_string(),
+            value: None,
+        })
+        .await;
+    assert_eq!(outputs.len(), 1);
+    assert_eq!(outputs[0].output_id, output_ids[1]);
+
+    tear_down(storage_path)
+}
+
+#[cfg(all(feature = "rocksdb", feature = "testing"))]
+#[tokio::test]
+async fn mock_account_index_bound_wallet() -> Result<()> {
+    use iota_sdk::{
+        client::{constants::SHIMMER_COIN_TYPE, mock::MockClient},
+        crypto::keys::bip44::Bip44,
+    };
+
+    use crate::wallet::common::{make_mock_wallet, mock_wallet_builder};
+
+    let storage_path = "test-storage/mock_account_index_bound_wallet";
+    setup(storage_path)?;
+
+    let mock_client = MockClient::default();
+    let wallet = mock_wallet_builder(storage_path, mock_client.clone())?
+        .for_account_index(5)
+        .finish()
+        .await?;
+    assert_eq!(wallet.account_index(), Some(5));
+
+    let account = wallet.create_account().finish().await?;
+    assert_eq!(*account.details().await.index(), 5);
+    assert!(matches!(
+        wallet.create_account().finish().await,
+        Err(Error::AccountIndexAlreadyExists(5))
+    ));
+
+    // The same account as in an unbound wallet that created six accounts
+    let unbound_storage_path = "test-storage/mock_account_index_bound_wallet_unbound";
+    setup(unbound_storage_path)?;
+    let unbound_wallet = make_mock_wallet(unbound_storage_path, mock_client.clone()).await?;
+    let mut unbound_account = unbound_wallet.create_account().finish().await?;
+    for _ in 0..5 {
+        unbound_account = unbound_wallet.create_account().finish().await?;
+    }
+    assert_eq!(account.addresses().await?, unbound_account.addresses().await?);
+
+    tear_down(unbound_storage_path)?;
+
+    let bip_path_storage_path = "test-storage/mock_account_index_bound_wallet_bip_path";
+    setup(bip_path_storage_path)?;
+    let bip_path_wallet = mock_wallet_builder(bip_path_storage_path, mock_client.clone())?
+        .with_bip_path(Bip44::new(SHIMMER_COIN_TYPE).with_account(7))
+        .finish()
+        .await?;
+    assert_eq!(bip_path_wallet.account_index(), Some(7));
+    tear_down(bip_path_storage_path)?;
+
+    // The storage of the wallet can't be opened for another account index
+    drop(account);
+    drop(wallet);
+    assert!(matches!(
+        mock_wallet_builder(storage_path, mock_client.clone())?
+            .for_account_index(6)
+            .finish()
+            .await,
+        Err(Error::AccountIndexMismatch {
+            new_account_index: 6,
+            existing_account_index: 5,
+        })
+    ));
+    // Without an account index the stored one is used
+    let wallet = mock_wallet_builder(storage_path, mock_client)?.finish().await?;
+    assert_eq!(wallet.account_index(), Some(5));
+    drop(wallet);
+
+    tear_down(storage_path)
+}
+
+#[cfg(feature = "testing")]
+#[tokio::test]
+async fn mock_chain_graph() -> Result<()> {
+    use iota_sdk::{
+        types::block::output::{
+            unlock_condition::ImmutableAliasAddressUnlockCondition, ChainId, FoundryId, FoundryOutputBuilder,
+            SimpleTokenScheme, TokenScheme,
+        },
+        wallet::account::{AliasSyncOptions, ChainNodeKind, ChainRelation},
+    };
+
+    let storage_path = "test-storage/mock_chain_graph";
+    let (mock_client, wallet) = setup_mock_wallet(storage_path).await?;
+    let protocol_parameters = mock_client.protocol_parameters();
+    let token_supply = protocol_parameters.token_supply();
+    let (account, address) = create_account_and_address(&wallet).await?;
+
+    let alias_id = AliasId::new([1; AliasId::LENGTH]);
+    let alias_output_id = mock_client.add_output(
+        AliasOutputBuilder::new_with_amount(100_000, alias_id)
+            .with_state_index(1)
+            .with_foundry_counter(1)
+            .add_unlock_condition(StateControllerAddressUnlockCondition::new(address))
+            .add_unlock_condition(GovernorAddressUnlockCondition::new(address))
+            .finish_output(token_supply)?,
+    );
+    let foundry_output_id = mock_client.add_output(
+        FoundryOutputBuilder::new_with_minimum_storage_deposit(
+            *protocol_parameters.rent_structure(),
+            1,
+            TokenScheme::Simple(SimpleTokenScheme::new(100, 0, 500)?),
+        )
+        .add_unlock_condition(ImmutableAliasAddressUnlockCondition::new(AliasAddress::from(alias_id)))
+        .finish_output(token_supply)?,
+    );
+    let alias_owned_output_id = mock_client.add_output(
+        BasicOutputBuilder::new_with_amount(1_000_000)
+            .add_unlock_condition(AddressUnlockCondition::new(AliasAddress::from(alias_id)))
+            .finish_output(token_supply)?,
+    );
+    // Not part of the graph, it doesn't depend on a chain
+    add_basic_output(&mock_client, address, 1_000_000)?;
+
+    account
+        .sync(Some(SyncOptions {
+            alias: AliasSyncOptions {
+                basic_outputs: true,
+                ..Default::default()
+            },
+            ..Default::default()
+        }))
+        .await?;
+
+    let graph = account.chain_graph().await;
+    assert_eq!(graph.nodes.len(), 3);
+    let alias_node = graph
+        .nodes
+        .iter()
+        .find(|node| node.output_id == alias_output_id)
+        .unwrap();
+    assert_eq!(alias_node.kind, ChainNodeKind::Alias);
+    assert_eq!(alias_node.chain_id, Some(ChainId::Alias(alias_id)));
+    let foundry_node = graph
+        .nodes
+        .iter()
+        .find(|node| node.output_id == foundry_output_id)
+        .unwrap();
+    assert_eq!(
+        foundry_node.chain_id,
+        Some(ChainId::Foundry(FoundryId::build(&AliasAddress::from(alias_id), 1, 0)))
+    );
+
+    let mut dependents = graph
+        .dependents(&alias_output_id)
+        .map(|edge| (edge.dependent, edge.relation))
+        .collect::<Vec<_>>();
+    dependents.sort_by_key(|(output_id, _)| *output_id);
+    let mut expected = vec![
+        (foundry_output_id, ChainRelation::ControlsFoundry),
+        (alias_owned_output_id, ChainRelation::Owns),
+    ];
+    expected.sort_by_key(|(output_id, _)| *output_id);
+    assert_eq!(dependents, expected);
+    assert_eq!(graph.edges.len(), 2);
+
+    let dot = graph.to_dot();
+    assert!(dot.starts_with("digraph chains {"));
+    assert!(dot.contains(&format!(
+        "\"{alias_output_id}\" -> \"{foundry_output_id}\" [label=\"ControlsFoundry\"];"
+    )));
+
+    tear_down(storage_path)
+}
@@ -3,6 +3,8 @@
 
 #[cfg(feature = "stronghold")]
 use crypto::keys::bip39::Mnemonic;
+#[cfg(all(feature = "ledger_nano", feature = "testing"))]
+use iota_sdk::client::mock::MockClient;
 #[cfg(feature = "stronghold")]
 use iota_sdk::client::secret::stronghold::StrongholdSecretManager;
 #[cfg(feature = "ledger_nano")]
@@ -20,6 +22,10 @@ use iota_sdk::{
 };
 use pretty_assertions::assert_eq;
 
+#[cfg(feature = "ledger_nano")]
+use crate::wallet::common::make_ledger_nano_wallet;
+#[cfg(all(feature = "ledger_nano", feature = "testing"))]
+use crate::wallet::common::make_mock_wallet;
 use crate::wallet::common::{setup, tear_down, DEFAULT_MNEMONIC, NODE_LOCAL};
 
 #[tokio::test]
@@ -176,6 +182,45 @@ async fn wallet_address_generation_ledger() -> Result<()> {
     tear_down(storage_path)
 }
 
+#[tokio::test]
+#[cfg(feature = "ledger_nano")]
+#[ignore = "requires ledger nano instance"]
+async fn account_verify_address_on_device_ledger() -> Result<()> {
+    let storage_path = "test-storage/account_verify_address_on_device_ledger";
+    setup(storage_path)?;
+
+    let wallet = make_ledger_nano_wallet(storage_path, None).await?;
+    let account = wallet.create_account().finish().await?;
+
+    // The simulator confirms the address in non-interactive mode
+    assert!(account.verify_address_on_device(0, false).await?);
+    // The address has to be generated first
+    assert!(matches!(
+        account.verify_address_on_device(1, false).await,
+        Err(Error::AddressNotFoundInAccount(_))
+    ));
+
+    tear_down(storage_path)
+}
+
+#[tokio::test]
+#[cfg(all(feature = "ledger_nano", feature = "testing"))]
+async fn account_verify_address_on_device_mnemonic() -> Result<()> {
+    let storage_path = "test-storage/account_verify_address_on_device_mnemonic";
+    setup(storage_path)?;
+
+    let wallet = make_mock_wallet(storage_path, MockClient::default()).await?;
+    let account = wallet.create_account().finish().await?;
+
+    if let Err(Error::Client(error)) = account.verify_address_on_device(0, false).await {
+        assert!(matches!(*error, ClientError::SecretManagerMismatch))
+    } else {
+        panic!("expected SecretManagerMismatch")
+    }
+
+    tear_down(storage_path)
+}
+
 #[tokio::test]
 async fn wallet_address_generation_placeholder() -> Result<()> {
     let storage_path = "test-storage/wallet_address_generation_placeholder";
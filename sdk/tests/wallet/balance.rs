@@ -9,8 +9,18 @@ use iota_sdk::{
     },
     wallet::{account::types::Balance, Result},
 };
+#[cfg(feature = "testing")]
+use iota_sdk::{
+    types::block::{
+        address::Bech32Address,
+        output::unlock_condition::{StorageDepositReturnUnlockCondition, TimelockUnlockCondition},
+    },
+    wallet::SendParams,
+};
 use pretty_assertions::assert_eq;
 
+#[cfg(feature = "testing")]
+use crate::wallet::common::{add_basic_output, create_account_and_address, setup_mock_wallet};
 use crate::wallet::common::{create_accounts_with_funds, make_wallet, setup, tear_down};
 
 #[test]
@@ -278,3 +288,160 @@ async fn balance_voting_power() -> Result<()> {
 
     tear_down(storage_path)
 }
+
+#[cfg(feature = "testing")]
+#[tokio::test]
+async fn mock_balance_breakdown() -> Result<()> {
+    use iota_sdk::types::block::output::{NftId, NftOutputBuilder};
+
+    let storage_path = "test-storage/mock_balance_breakdown";
+    let (mock_client, wallet) = setup_mock_wallet(storage_path).await?;
+    let protocol_parameters = mock_client.protocol_parameters();
+    let token_supply = protocol_parameters.token_supply();
+    let (account_0, address_0) = create_account_and_address(&wallet).await?;
+    let (_, address_1) = create_account_and_address(&wallet).await?;
+
+    let now = mock_client.milestone_timestamp();
+    add_basic_output(&mock_client, address_0, 1_000_000)?;
+    let nft_output = NftOutputBuilder::new_with_minimum_storage_deposit(
+        *protocol_parameters.rent_structure(),
+        NftId::new([1; NftId::LENGTH]),
+    )
+    .add_unlock_condition(AddressUnlockCondition::new(address_0))
+    .finish_output(token_supply)?;
+    let nft_amount = nft_output.amount();
+    mock_client.add_output(nft_output);
+    for timelock in [now + 7200, now + 3600] {
+        mock_client.add_output(
+            BasicOutputBuilder::new_with_amount(100_000)
+                .add_unlock_condition(AddressUnlockCondition::new(address_0))
+                .add_unlock_condition(TimelockUnlockCondition::new(timelock)?)
+                .finish_output(token_supply)?,
+        );
+    }
+    let storage_deposit_return =
+        |address: Bech32Address| StorageDepositReturnUnlockCondition::new(address, 50_000, token_supply);
+    // Received, the storage deposit has to be returned
+    mock_client.add_output(
+        BasicOutputBuilder::new_with_amount(150_000)
+            .add_unlock_condition(AddressUnlockCondition::new(address_0))
+            .add_unlock_condition(storage_deposit_return(address_1)?)
+            .add_unlock_condition(ExpirationUnlockCondition::new(address_1, now + 3600)?)
+            .finish_output(token_supply)?,
+    );
+    // Sent, but not claimed yet
+    mock_client.add_output(
+        BasicOutputBuilder::new_with_amount(80_000)
+            .add_unlock_condition(AddressUnlockCondition::new(address_1))
+            .add_unlock_condition(storage_deposit_return(address_0)?)
+            .add_unlock_condition(ExpirationUnlockCondition::new(address_0, now + 3600)?)
+            .finish_output(token_supply)?,
+    );
+
+    let balance = account_0.sync(None).await?;
+    let breakdown = balance.breakdown();
+    assert_eq!(breakdown.spendable(), 1_000_000);
+    assert_eq!(breakdown.spendable(), balance.base_coin().available());
+    assert_eq!(breakdown.timelocked(), 200_000);
+    assert_eq!(breakdown.next_unlock(), Some(now + 3600));
+    assert_eq!(breakdown.expiring_away(), 100_000);
+    assert_eq!(breakdown.expiring_to_us(), 80_000);
+    assert_eq!(breakdown.storage_deposit_locked(), nft_amount);
+    assert_eq!(breakdown.pending_reserved(), 0);
+
+    // The inputs of a prepared transaction are reserved until it's sent or the inputs are unlocked
+    account_0
+        .prepare_send([SendParams::new(500_000, address_1)?], None)
+        .await?;
+    let balance = account_0.balance().await?;
+    let breakdown = balance.breakdown();
+    assert_eq!(breakdown.spendable(), 0);
+    assert_eq!(breakdown.pending_reserved(), 1_000_000);
+
+    tear_down(storage_path)
+}
+
+#[cfg(feature = "testing")]
+#[tokio::test]
+async fn mock_faucet_funds() -> Result<()> {
+    use std::io::{BufRead, BufReader, Read, Write};
+
+    use iota_sdk::client::{faucet::FaucetClient, Error as ClientError};
+
+    let storage_path = "test-storage/mock_faucet_funds";
+    let (mock_client, wallet) = setup_mock_wallet(storage_path).await?;
+    let (account, address) = create_account_and_address(&wallet).await?;
+
+    // A faucet which sends the funds of the first request to the mocked node, rate limits the second one and rejects
+    // all others
+    let listener = std::net::TcpListener::bind("127.0.0.1:0")?;
+    let faucet_url = format!("http://{}/api/enqueue", listener.local_addr()?);
+    let faucet_mock_client = mock_client.clone();
+    std::thread::spawn(move || {
+        for (request_index, stream) in listener.incoming().enumerate() {
+            let mut stream = stream.unwrap();
+            let mut reader = BufReader::new(stream.try_clone().unwrap());
+            let mut content_length = 0;
+            let mut line = String::new();
+            while line != "\r\n" {
+                line.clear();
+                reader.read_line(&mut line).unwrap();
+                if let Some(value) = line.to_lowercase().strip_prefix("content-length:") {
+                    content_length = value.trim().parse().unwrap();
+                }
+            }
+            let mut body = vec![0; content_length];
+            reader.read_exact(&mut body).unwrap();
+            let address = serde_json::from_slice::<serde_json::Value>(&body).unwrap()["address"]
+                .as_str()
+                .unwrap()
+                .parse::<Bech32Address>()
+                .unwrap();
+
+            let (status, headers, body) = match request_index {
+                0 => {
+                    add_basic_output(&faucet_mock_client, address, 1_000_000).unwrap();
+                    (
+                        "202 Accepted",
+                        "",
+                        serde_json::json!({ "address": address, "waitingRequests": 1 }).to_string(),
+                    )
+                }
+                1 => ("429 Too Many Requests", "retry-after: 30\r\n", String::new()),
+                _ => (
+                    "400 Bad Request",
+                    "",
+                    r#"{"error":{"message":"address already has enough funds"}}"#.to_string(),
+                ),
+            };
+            let response = format!(
+                "HTTP/1.1 {status}\r\n{headers}content-type: application/json\r\ncontent-length: {}\r\nconnection: \
+                 close\r\n\r\n{body}",
+                body.len()
+            );
+            stream.write_all(response.as_bytes()).unwrap();
+        }
+    });
+
+    let faucet = FaucetClient::new(&faucet_url)?;
+    let output_ids = faucet
+        .request_funds_and_wait(account.client(), &address, std::time::Duration::from_secs(10))
+        .await?;
+    assert_eq!(output_ids.len(), 1);
+    let balance = account.sync(None).await?;
+    assert_eq!(balance.base_coin().total(), 1_000_000);
+
+    assert!(matches!(
+        faucet.request_funds(&address).await,
+        Err(ClientError::FaucetRateLimited { retry_after: Some(30) })
+    ));
+    match faucet.request_funds(&address).await {
+        Err(ClientError::Faucet { code, message }) => {
+            assert_eq!(code, 400);
+            assert_eq!(message, "address already has enough funds");
+        }
+        other => panic!("expected a faucet error, got {other:?}"),
+    }
+
+    tear_down(storage_path)
+}
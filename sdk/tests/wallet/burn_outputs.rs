@@ -10,8 +10,21 @@ use iota_sdk::{
     wallet::{Account, CreateNativeTokenParams, MintNftParams, Result},
     U256,
 };
+#[cfg(feature = "testing")]
+use iota_sdk::{
+    types::block::{
+        address::AliasAddress,
+        output::{
+            unlock_condition::{GovernorAddressUnlockCondition, StateControllerAddressUnlockCondition},
+            AliasId, AliasOutputBuilder, BasicOutputBuilder,
+        },
+    },
+    wallet::Error,
+};
 use pretty_assertions::assert_eq;
 
+#[cfg(feature = "testing")]
+use crate::wallet::common::{add_basic_output, create_account_and_address, setup_mock_wallet};
 use crate::wallet::common::{create_accounts_with_funds, make_wallet, setup, tear_down};
 
 #[ignore]
@@ -317,3 +330,120 @@ async fn mint_and_burn_nft_with_alias() -> Result<()> {
 
     tear_down(storage_path)
 }
+
+#[cfg(feature = "testing")]
+#[tokio::test]
+async fn mock_burn_plan() -> Result<()> {
+    use iota_sdk::{
+        types::block::output::{
+            unlock_condition::ImmutableAliasAddressUnlockCondition, FoundryOutputBuilder, NativeToken,
+            SimpleTokenScheme, TokenId, TokenScheme,
+        },
+        wallet::{
+            account::{BurnPlanStep, OutputsToClaim},
+            SendNativeTokensParams,
+        },
+        U256,
+    };
+
+    let storage_path = "test-storage/mock_burn_plan";
+    let (mock_client, wallet) = setup_mock_wallet(storage_path).await?;
+    let protocol_parameters = mock_client.protocol_parameters();
+    let token_supply = protocol_parameters.token_supply();
+    let (account_0, address_0) = create_account_and_address(&wallet).await?;
+    let (account_1, address_1) = create_account_and_address(&wallet).await?;
+
+    let alias_id = AliasId::new([1; AliasId::LENGTH]);
+    mock_client.add_output(
+        AliasOutputBuilder::new_with_amount(100_000, alias_id)
+            .with_state_index(1)
+            .with_foundry_counter(1)
+            .add_unlock_condition(StateControllerAddressUnlockCondition::new(address_0))
+            .add_unlock_condition(GovernorAddressUnlockCondition::new(address_0))
+            .finish_output(token_supply)?,
+    );
+    let foundry_output = FoundryOutputBuilder::new_with_minimum_storage_deposit(
+        *protocol_parameters.rent_structure(),
+        1,
+        TokenScheme::Simple(SimpleTokenScheme::new(100, 0, 500)?),
+    )
+    .add_unlock_condition(ImmutableAliasAddressUnlockCondition::new(AliasAddress::from(alias_id)))
+    .finish_output(token_supply)?;
+    let foundry_id = foundry_output.as_foundry().id();
+    let token_id = TokenId::from(foundry_id);
+    mock_client.add_output(foundry_output);
+    mock_client.add_output(
+        BasicOutputBuilder::new_with_amount(1_000_000)
+            .add_native_token(NativeToken::new(token_id, 100)?)
+            .add_unlock_condition(AddressUnlockCondition::new(address_0))
+            .finish_output(token_supply)?,
+    );
+    add_basic_output(&mock_client, address_1, 1_000_000)?;
+    account_0.sync(None).await?;
+
+    // The whole circulating supply is held by the account, so it's melted before the foundry is destroyed
+    let plan = account_0.burn_plan(alias_id).await?;
+    assert_eq!(plan.steps.len(), 3);
+    assert_eq!(
+        plan.steps[0],
+        BurnPlanStep::MeltNativeToken {
+            token_id,
+            amount: U256::from(100)
+        }
+    );
+    assert_eq!(plan.foundries(), [foundry_id].into());
+
+    // Tokens held by someone else can't be melted
+    let transaction = account_0
+        .send_native_tokens(
+            [SendNativeTokensParams::new(address_1, [(token_id, U256::from(10))])?],
+            None,
+        )
+        .await?;
+    account_0
+        .retry_transaction_until_included(&transaction.transaction_id, None, None)
+        .await?;
+    account_0.sync(None).await?;
+    assert!(matches!(
+        account_0.burn_plan(alias_id).await,
+        Err(Error::FoundryTokensNotHeld { foundry_id: id, held, .. }) if id == foundry_id && held == U256::from(90)
+    ));
+
+    // After getting the tokens back, the plan is sent
+    account_1.sync(None).await?;
+    let claim_transaction = account_1
+        .claim_outputs(account_1.claimable_outputs(OutputsToClaim::NativeTokens).await?)
+        .await?;
+    account_1
+        .retry_transaction_until_included(&claim_transaction.transaction_id, None, None)
+        .await?;
+    account_1.sync(None).await?;
+    account_1
+        .send_native_tokens(
+            [SendNativeTokensParams::new(address_0, [(token_id, U256::from(10))])?],
+            None,
+        )
+        .await?;
+    account_0.sync(None).await?;
+    // The returned tokens have to be claimed first
+    assert!(matches!(
+        account_0.burn_plan(alias_id).await,
+        Err(Error::FoundryTokensNotHeld { .. })
+    ));
+    let claim_transaction = account_0
+        .claim_outputs(account_0.claimable_outputs(OutputsToClaim::NativeTokens).await?)
+        .await?;
+    account_0
+        .retry_transaction_until_included(&claim_transaction.transaction_id, None, None)
+        .await?;
+    account_0.sync(None).await?;
+    let plan = account_0.burn_plan(alias_id).await?;
+    let transactions = account_0.burn_with_plan(plan, None).await?;
+    assert_eq!(transactions.len(), 3);
+
+    account_0.sync(None).await?;
+    assert!(account_0.unspent_alias_output(&alias_id).await?.is_none());
+    assert!(account_0.unspent_foundry_output(&foundry_id).await?.is_none());
+
+    tear_down(storage_path)
+}
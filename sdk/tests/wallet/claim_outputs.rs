@@ -1,6 +1,14 @@
 // Copyright 2023 IOTA Stiftung
 // SPDX-License-Identifier: Apache-2.0
 
+#[cfg(feature = "testing")]
+use iota_sdk::{
+    types::block::output::unlock_condition::{StorageDepositReturnUnlockCondition, TimelockUnlockCondition},
+    wallet::{
+        account::{types::Unlockability, ClaimLink, ClaimLinkTransaction},
+        Error,
+    },
+};
 use iota_sdk::{
     types::block::output::{
         unlock_condition::{AddressUnlockCondition, ExpirationUnlockCondition},
@@ -14,6 +22,8 @@ use iota_sdk::{
 };
 use pretty_assertions::assert_eq;
 
+#[cfg(feature = "testing")]
+use crate::wallet::common::{add_basic_output, create_account_and_address, setup_mock_wallet};
 use crate::wallet::common::{create_accounts_with_funds, make_wallet, setup, tear_down};
 
 #[ignore]
@@ -547,3 +557,162 @@ async fn claim_basic_micro_output_error() -> Result<()> {
 
     tear_down(storage_path)
 }
+
+#[cfg(feature = "testing")]
+#[tokio::test]
+async fn mock_reclaim_expired_outputs() -> Result<()> {
+    let storage_path = "test-storage/mock_reclaim_expired_outputs";
+    let (mock_client, wallet) = setup_mock_wallet(storage_path).await?;
+    let token_supply = mock_client.protocol_parameters().token_supply();
+    let (account_0, address_0) = create_account_and_address(&wallet).await?;
+    let (account_1, address_1) = create_account_and_address(&wallet).await?;
+
+    let now = mock_client.milestone_timestamp();
+    let sdr_output = |expiration: u32| {
+        BasicOutputBuilder::new_with_amount(1_000_000)
+            .add_unlock_condition(AddressUnlockCondition::new(address_1))
+            .add_unlock_condition(StorageDepositReturnUnlockCondition::new(address_0, 50_000, token_supply).unwrap())
+            .add_unlock_condition(ExpirationUnlockCondition::new(address_0, expiration).unwrap())
+            .finish_output(token_supply)
+    };
+    let expired_output_id = mock_client.add_output(sdr_output(now - 60)?);
+    let pending_output_id = mock_client.add_output(sdr_output(now + 3600)?);
+
+    account_0.sync(None).await?;
+    let reclaimable_outputs = account_0.reclaimable_outputs().await?;
+    assert_eq!(reclaimable_outputs.len(), 1);
+    assert_eq!(reclaimable_outputs[0].output_id, expired_output_id);
+    // The receiver can only claim the pending output
+    account_1.sync(None).await?;
+    assert!(account_1.reclaimable_outputs().await?.is_empty());
+
+    account_0.reclaim_expired_outputs().await?;
+    assert!(mock_client.output(&expired_output_id).unwrap().metadata().is_spent());
+    assert!(!mock_client.output(&pending_output_id).unwrap().metadata().is_spent());
+
+    let balance = account_0.sync(None).await?;
+    assert_eq!(balance.base_coin().available(), 1_000_000);
+    assert!(account_0.reclaimable_outputs().await?.is_empty());
+    assert!(matches!(
+        account_0.reclaim_expired_outputs().await,
+        Err(Error::NoOutputsToReclaim)
+    ));
+
+    tear_down(storage_path)
+}
+
+#[cfg(feature = "testing")]
+#[tokio::test]
+async fn mock_output_unlockability() -> Result<()> {
+    let storage_path = "test-storage/mock_output_unlockability";
+    let (mock_client, wallet) = setup_mock_wallet(storage_path).await?;
+    let token_supply = mock_client.protocol_parameters().token_supply();
+    let (account_0, address_0) = create_account_and_address(&wallet).await?;
+    let (account_1, address_1) = create_account_and_address(&wallet).await?;
+
+    let now = mock_client.milestone_timestamp();
+    let sdr_output = |expiration: u32| {
+        BasicOutputBuilder::new_with_amount(1_000_000)
+            .add_unlock_condition(AddressUnlockCondition::new(address_1))
+            .add_unlock_condition(StorageDepositReturnUnlockCondition::new(address_0, 50_000, token_supply).unwrap())
+            .add_unlock_condition(ExpirationUnlockCondition::new(address_0, expiration).unwrap())
+            .finish_output(token_supply)
+    };
+    let basic_output_id = add_basic_output(&mock_client, address_1, 1_000_000)?;
+    let pending_output_id = mock_client.add_output(sdr_output(now + 3600)?);
+    let expired_output_id = mock_client.add_output(sdr_output(now - 60)?);
+    let timelocked_output_id = mock_client.add_output(
+        BasicOutputBuilder::new_with_amount(1_000_000)
+            .add_unlock_condition(AddressUnlockCondition::new(address_1))
+            .add_unlock_condition(TimelockUnlockCondition::new(now + 60)?)
+            .finish_output(token_supply)?,
+    );
+
+    account_0.sync(None).await?;
+    account_1.sync(None).await?;
+
+    assert_eq!(
+        account_1.output_unlockability(&basic_output_id).await?,
+        Some(Unlockability::Unlockable {
+            expires_at: None,
+            storage_deposit_return: None,
+        })
+    );
+    assert_eq!(
+        account_1.output_unlockability(&pending_output_id).await?,
+        Some(Unlockability::Unlockable {
+            expires_at: Some(now + 3600),
+            storage_deposit_return: Some(50_000),
+        })
+    );
+    assert_eq!(
+        account_1.output_unlockability(&expired_output_id).await?,
+        Some(Unlockability::Expired {
+            timestamp: now - 60,
+            return_address: address_0,
+        })
+    );
+    assert_eq!(
+        account_1.output_unlockability(&timelocked_output_id).await?,
+        Some(Unlockability::TimelockedUntil { timestamp: now + 60 })
+    );
+    // The sender only has to wait for the expiration
+    assert_eq!(
+        account_0.output_unlockability(&pending_output_id).await?,
+        Some(Unlockability::RequiresUnlockBy { address: address_1 })
+    );
+    assert_eq!(
+        account_0.output_unlockability(&expired_output_id).await?,
+        Some(Unlockability::Unlockable {
+            expires_at: None,
+            storage_deposit_return: None,
+        })
+    );
+    assert_eq!(account_0.output_unlockability(&basic_output_id).await?, None);
+
+    tear_down(storage_path)
+}
+
+#[cfg(feature = "testing")]
+#[tokio::test]
+async fn mock_claim_link() -> Result<()> {
+    let storage_path = "test-storage/mock_claim_link";
+    let (mock_client, wallet) = setup_mock_wallet(storage_path).await?;
+    let (account_0, address_0) = create_account_and_address(&wallet).await?;
+    let account_1 = wallet.create_account().finish().await?;
+
+    add_basic_output(&mock_client, address_0, 1_000_000)?;
+    account_0.sync(None).await?;
+
+    let ClaimLinkTransaction { claim_link, .. } = account_0.create_claim_link(200_000, None, None).await?;
+    let url = claim_link.to_url("https://example.com/claim");
+    let parsed = url.parse::<ClaimLink>()?;
+    assert_eq!(parsed.output_id(), claim_link.output_id());
+    assert_eq!(parsed.address(), claim_link.address());
+    assert!(matches!(
+        "iota-claim:0x00:0x00".parse::<ClaimLink>(),
+        Err(Error::InvalidClaimLink(_))
+    ));
+
+    account_1.redeem_claim_link(&parsed).await?;
+    let balance = account_1.sync(None).await?;
+    assert_eq!(balance.base_coin().available(), 200_000);
+
+    // The funds can only be claimed once
+    assert!(matches!(
+        account_1.redeem_claim_link(&parsed).await,
+        Err(Error::ClaimLinkNotRedeemable { output_id, .. }) if output_id == *claim_link.output_id()
+    ));
+
+    // Expired funds are only available for the sender again
+    account_0.sync(None).await?;
+    let ClaimLinkTransaction { claim_link, .. } = account_0.create_claim_link(100_000, 0, None).await?;
+    assert!(matches!(
+        account_1.redeem_claim_link(&claim_link).await,
+        Err(Error::ClaimLinkNotRedeemable { .. })
+    ));
+    let balance = account_0.sync(None).await?;
+    assert_eq!(balance.base_coin().available(), 800_000);
+
+    tear_down(storage_path)
+}
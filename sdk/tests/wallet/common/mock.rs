@@ -0,0 +1,75 @@
+// Copyright 2024 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use iota_sdk::{
+    client::{
+        constants::SHIMMER_COIN_TYPE,
+        mock::MockClient,
+        secret::{mnemonic::MnemonicSecretManager, SecretManager},
+    },
+    types::block::{
+        address::{Address, Bech32Address, Ed25519Address},
+        output::{unlock_condition::AddressUnlockCondition, BasicOutputBuilder, OutputId},
+    },
+    wallet::{Account, ClientOptions, Result, Wallet, WalletBuilder},
+};
+
+use super::{setup, DEFAULT_MNEMONIC};
+
+/// Creates a new wallet with a mnemonic secret manager, which uses the mocked node instead of a real one.
+pub(crate) async fn make_mock_wallet(storage_path: &str, mock_client: MockClient) -> Result<Wallet> {
+    mock_wallet_builder(storage_path, mock_client)?.finish().await
+}
+
+/// Creates a wallet builder with a mnemonic secret manager, which uses the mocked node instead of a real one.
+#[allow(unused_variables)]
+pub(crate) fn mock_wallet_builder(storage_path: &str, mock_client: MockClient) -> Result<WalletBuilder> {
+    let client_options = ClientOptions::new().with_mock_client(mock_client);
+    let secret_manager = MnemonicSecretManager::try_from_mnemonic(DEFAULT_MNEMONIC.to_owned())?;
+
+    #[allow(unused_mut)]
+    let mut wallet_builder = Wallet::builder()
+        .with_secret_manager(SecretManager::Mnemonic(secret_manager))
+        .with_client_options(client_options)
+        .with_coin_type(SHIMMER_COIN_TYPE);
+    #[cfg(feature = "storage")]
+    {
+        wallet_builder = wallet_builder.with_storage_path(storage_path);
+    }
+
+    Ok(wallet_builder)
+}
+
+/// Removes the data of a previous run at `storage_path` and creates a wallet there, which uses a new mocked node.
+pub(crate) async fn setup_mock_wallet(storage_path: &str) -> Result<(MockClient, Wallet)> {
+    setup(storage_path)?;
+    let mock_client = MockClient::default();
+    let wallet = make_mock_wallet(storage_path, mock_client.clone()).await?;
+
+    Ok((mock_client, wallet))
+}
+
+/// Creates a new account and returns it with its first address.
+pub(crate) async fn create_account_and_address(wallet: &Wallet) -> Result<(Account, Bech32Address)> {
+    let account = wallet.create_account().finish().await?;
+    let address = account.addresses().await?[0].clone().into_bech32();
+
+    Ok((account, address))
+}
+
+/// Adds an unspent basic output, which is only locked to `address`, to the ledger of the mocked node.
+pub(crate) fn add_basic_output(mock_client: &MockClient, address: impl Into<Address>, amount: u64) -> Result<OutputId> {
+    let output = BasicOutputBuilder::new_with_amount(amount)
+        .add_unlock_condition(AddressUnlockCondition::new(address))
+        .finish_output(mock_client.protocol_parameters().token_supply())?;
+
+    Ok(mock_client.add_output(output))
+}
+
+/// Returns an address on the network of the mocked node which doesn't belong to the wallet.
+pub(crate) fn foreign_address(mock_client: &MockClient) -> Bech32Address {
+    Bech32Address::new(
+        *mock_client.protocol_parameters().bech32_hrp(),
+        Ed25519Address::new([1; 32]),
+    )
+}
@@ -2,19 +2,23 @@
 // SPDX-License-Identifier: Apache-2.0
 
 mod constants;
+#[cfg(feature = "testing")]
+mod mock;
 
 use crypto::keys::bip39::Mnemonic;
 use iota_sdk::{
     client::{
         constants::SHIMMER_COIN_TYPE,
-        request_funds_from_faucet,
         secret::{mnemonic::MnemonicSecretManager, SecretManager},
+        testing::{NodeHarness, NodeHarnessOptions},
         Client,
     },
     wallet::{Account, ClientOptions, Result, Wallet},
 };
 
 pub use self::constants::*;
+#[cfg(feature = "testing")]
+pub(crate) use self::mock::*;
 
 /// It creates a new wallet with a mnemonic secret manager, a client options object,
 /// SHIMMER_COIN_TYPE, and a storage path
@@ -71,21 +75,19 @@ pub(crate) async fn make_ledger_nano_wallet(storage_path: &str, node: Option<&st
 /// is available. Returns the new accounts.
 #[allow(dead_code)]
 pub(crate) async fn create_accounts_with_funds(wallet: &Wallet, amount: usize) -> Result<Vec<Account>> {
+    let harness = NodeHarness::start(
+        NodeHarnessOptions::default()
+            .with_node_url(NODE_LOCAL)
+            .with_faucet_url(FAUCET_URL),
+    )
+    .await?;
     let mut new_accounts = Vec::new();
-    'accounts: for _ in 0..amount {
+    for _ in 0..amount {
         let account = wallet.create_account().finish().await?;
-        request_funds_from_faucet(FAUCET_URL, account.addresses().await?[0].address()).await?;
-
         // Continue only after funds are received
-        for _ in 0..30 {
-            tokio::time::sleep(std::time::Duration::from_secs(2)).await;
-            let balance = account.sync(None).await?;
-            if balance.base_coin().available() > 0 {
-                new_accounts.push(account);
-                continue 'accounts;
-            }
-        }
-        panic!("Faucet no longer wants to hand over coins");
+        harness.fund(account.addresses().await?[0].address()).await?;
+        account.sync(None).await?;
+        new_accounts.push(account);
     }
 
     Ok(new_accounts)
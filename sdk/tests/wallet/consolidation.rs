@@ -1,9 +1,16 @@
 // Copyright 2023 IOTA Stiftung
 // SPDX-License-Identifier: Apache-2.0
 
+#[cfg(feature = "testing")]
+use iota_sdk::types::block::output::{
+    unlock_condition::{AddressUnlockCondition, TimelockUnlockCondition},
+    BasicOutputBuilder,
+};
 use iota_sdk::wallet::{account::ConsolidationParams, Result, SendParams};
 use pretty_assertions::assert_eq;
 
+#[cfg(feature = "testing")]
+use crate::wallet::common::{add_basic_output, create_account_and_address, setup_mock_wallet};
 use crate::wallet::common::{create_accounts_with_funds, make_wallet, setup, tear_down};
 
 #[ignore]
@@ -49,3 +56,55 @@ async fn consolidation() -> Result<()> {
 
     tear_down(storage_path)
 }
+
+#[cfg(feature = "testing")]
+#[tokio::test]
+async fn mock_consolidation_preview() -> Result<()> {
+    use iota_sdk::wallet::account::ConsolidationParams;
+
+    let storage_path = "test-storage/mock_consolidation_preview";
+    let (mock_client, wallet) = setup_mock_wallet(storage_path).await?;
+    let token_supply = mock_client.protocol_parameters().token_supply();
+    let (account, address) = create_account_and_address(&wallet).await?;
+
+    for _ in 0..5 {
+        add_basic_output(&mock_client, address, 1_000_000)?;
+    }
+    // Can't be consolidated before the timelock expired
+    mock_client.add_output(
+        BasicOutputBuilder::new_with_amount(1_000_000)
+            .add_unlock_condition(AddressUnlockCondition::new(address))
+            .add_unlock_condition(TimelockUnlockCondition::new(mock_client.milestone_timestamp() + 3600)?)
+            .finish_output(token_supply)?,
+    );
+    account.sync(None).await?;
+
+    // Below the default threshold
+    let preview = account.consolidation_preview(ConsolidationParams::new()).await?;
+    assert!(!preview.would_consolidate);
+    assert_eq!(preview.consolidatable_outputs, 5);
+    assert_eq!(preview.output_threshold, 100);
+
+    let preview = account
+        .consolidation_preview(ConsolidationParams::new().with_force(true))
+        .await?;
+    assert!(preview.would_consolidate);
+    assert_eq!(preview.next_transaction_inputs, 5);
+    assert_eq!(preview.transactions_needed, 1);
+    assert_eq!(preview.resulting_output_count, 2);
+    assert_eq!(preview.amount, 5_000_000);
+    assert_eq!(preview.storage_deposit_before, 5 * preview.storage_deposit_after);
+
+    let preview = account
+        .consolidation_preview(ConsolidationParams::new().with_output_threshold(5))
+        .await?;
+    assert!(preview.would_consolidate);
+
+    // Nothing was reserved by the preview
+    let prepared = account
+        .prepare_consolidate_outputs(ConsolidationParams::new().with_force(true))
+        .await?;
+    assert_eq!(prepared.inputs_data.len(), 5);
+
+    tear_down(storage_path)
+}
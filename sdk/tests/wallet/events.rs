@@ -6,21 +6,29 @@ use iota_sdk::{
     types::block::{
         address::{Address, Bech32Address, Ed25519Address},
         input::{Input, UtxoInput},
-        output::{unlock_condition::AddressUnlockCondition, BasicOutput, Output, OutputId},
+        output::{dto::OutputDto, unlock_condition::AddressUnlockCondition, BasicOutput, Output, OutputId},
         payload::transaction::{RegularTransactionEssence, TransactionEssence, TransactionId},
         protocol::protocol_parameters,
-        rand::output::{rand_basic_output, rand_inputs_commitment, rand_output_metadata},
+        rand::{
+            block::rand_block_id,
+            output::{rand_basic_output, rand_inputs_commitment, rand_output_metadata},
+        },
     },
     wallet::{
         account::types::{InclusionState, OutputData, OutputDataDto},
         events::types::{
-            AddressData, NewOutputEvent, SpentOutputEvent, TransactionInclusionEvent, TransactionProgressEvent,
-            WalletEvent,
+            AddressData, LedgerRebuildEvent, NewOutputEvent, SpentOutputEvent, TransactionInclusionEvent,
+            TransactionProgressEvent, TransactionReissueEvent, TransactionReissueState, WalletEvent,
         },
     },
 };
+#[cfg(feature = "testing")]
+use iota_sdk::{client::mock::MockClient, wallet::Result};
 use pretty_assertions::assert_eq;
 
+#[cfg(feature = "testing")]
+use crate::wallet::common::{make_mock_wallet, setup, tear_down};
+
 const ED25519_ADDRESS: &str = "0x52fdfc072182654f163f5f0f9a621d729566c74d10037c4d7bbb0407d1e2c649";
 const TRANSACTION_ID: &str = "0x52fdfc072182654f163f5f0f9a621d729566c74d10037c4d7bbb0407d1e2c649";
 
@@ -50,6 +58,7 @@ fn wallet_events_serde() {
         network_id: 42,
         remainder: true,
         chain: None,
+        annotations: [("escrow".to_string(), "42".to_string())].into(),
     });
 
     assert_serde_eq(WalletEvent::NewOutput(Box::new(NewOutputEvent {
@@ -122,4 +131,166 @@ fn wallet_events_serde() {
     ));
 
     assert_serde_eq(WalletEvent::TransactionProgress(TransactionProgressEvent::Broadcasting));
+
+    assert_serde_eq(WalletEvent::TransactionProgress(
+        TransactionProgressEvent::AddingServiceFee(Box::new(OutputDto::from(&Output::Basic(rand_basic_output(
+            protocol_parameters().token_supply(),
+        ))))),
+    ));
+
+    assert_serde_eq(WalletEvent::TransactionReissue(TransactionReissueEvent {
+        transaction_id: TransactionId::null(),
+        reissue_state: TransactionReissueState::Reissued {
+            block_id: rand_block_id(),
+            attempt: 1,
+        },
+    }));
+
+    assert_serde_eq(WalletEvent::TransactionReissue(TransactionReissueEvent {
+        transaction_id: TransactionId::null(),
+        reissue_state: TransactionReissueState::GivenUp,
+    }));
+
+    assert_serde_eq(WalletEvent::LedgerRebuild(LedgerRebuildEvent::Cleared));
+
+    assert_serde_eq(WalletEvent::LedgerRebuild(LedgerRebuildEvent::Finished {
+        unspent_outputs: 3,
+        transactions: 2,
+        incoming_transactions: 1,
+    }));
+}
+
+#[cfg(all(feature = "testing", feature = "webhook"))]
+#[tokio::test]
+async fn mock_webhook() -> Result<()> {
+    use std::{
+        io::{BufRead, BufReader, Read, Write},
+        net::TcpListener,
+        time::Duration,
+    };
+
+    use iota_sdk::wallet::{
+        events::{
+            types::TransactionProgressEvent,
+            webhook::{signature, WebhookConfig, SIGNATURE_HEADER, TIMESTAMP_HEADER},
+            WalletEvent, WalletEventType,
+        },
+        Error,
+    };
+
+    use crate::wallet::common::mock_wallet_builder;
+
+    let storage_path = "test-storage/mock_webhook";
+    setup(storage_path)?;
+
+    assert!(matches!(
+        WebhookConfig::new("http://example.com/events"),
+        Err(Error::InvalidWebhook(_))
+    ));
+
+    // Receiver which fails the first request, so it has to be retried
+    let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+    let port = listener.local_addr().unwrap().port();
+    let (sender, mut receiver) = tokio::sync::mpsc::unbounded_channel();
+    std::thread::spawn(move || {
+        for (i, stream) in listener.incoming().enumerate() {
+            let mut reader = BufReader::new(stream.unwrap());
+            let mut headers = std::collections::HashMap::new();
+            loop {
+                let mut line = String::new();
+                reader.read_line(&mut line).unwrap();
+                if line.trim().is_empty() {
+                    break;
+                }
+                if let Some((name, value)) = line.split_once(':') {
+                    headers.insert(name.to_lowercase(), value.trim().to_owned());
+                }
+            }
+            let mut body = vec![0; headers["content-length"].parse().unwrap()];
+            reader.read_exact(&mut body).unwrap();
+            let status = if i == 0 { "500 Internal Server Error" } else { "200 OK" };
+            write!(
+                reader.get_mut(),
+                "HTTP/1.1 {status}\r\ncontent-length: 0\r\nconnection: close\r\n\r\n"
+            )
+            .unwrap();
+            sender.send((headers, String::from_utf8(body).unwrap())).unwrap();
+        }
+    });
+
+    let wallet = mock_wallet_builder(storage_path, MockClient::default())?
+        .with_webhook(
+            WebhookConfig::new(&format!("http://127.0.0.1:{port}/events"))?
+                .with_secret("secret")
+                .with_events([WalletEventType::ConsolidationRequired])
+                .with_retry_interval(Duration::from_millis(10)),
+        )
+        .finish()
+        .await?;
+
+    // Not configured for the webhook
+    wallet
+        .emit_test_event(WalletEvent::TransactionProgress(
+            TransactionProgressEvent::SelectingInputs,
+        ))
+        .await;
+    wallet.emit_test_event(WalletEvent::ConsolidationRequired).await;
+
+    let timeout = Duration::from_secs(10);
+    let (_, failed_body) = tokio::time::timeout(timeout, receiver.recv()).await.unwrap().unwrap();
+    let (headers, body) = tokio::time::timeout(timeout, receiver.recv()).await.unwrap().unwrap();
+    assert_eq!(failed_body, body);
+    assert_eq!(body, r#"{"accountIndex":0,"event":{"type":0}}"#);
+    assert_eq!(
+        headers[&SIGNATURE_HEADER.to_lowercase()],
+        signature("secret", &headers[&TIMESTAMP_HEADER.to_lowercase()], &body)
+    );
+    assert!(tokio::time::timeout(Duration::from_millis(100), receiver.recv())
+        .await
+        .is_err());
+
+    tear_down(storage_path)
+}
+
+#[cfg(feature = "testing")]
+#[tokio::test]
+async fn mock_listener_handle() -> Result<()> {
+    use std::sync::{
+        atomic::{AtomicUsize, Ordering},
+        Arc,
+    };
+
+    use iota_sdk::wallet::events::{WalletEvent, WalletEventType};
+
+    let storage_path = "test-storage/mock_listener_handle";
+    setup(storage_path)?;
+
+    let wallet = make_mock_wallet(storage_path, MockClient::default()).await?;
+    let event_counter = Arc::new(AtomicUsize::new(0));
+
+    let event_counter_clone = Arc::clone(&event_counter);
+    let handle = wallet
+        .listen([], move |_event| {
+            event_counter_clone.fetch_add(1, Ordering::SeqCst);
+        })
+        .await;
+    let other_handle = wallet.listen([WalletEventType::NewOutput], |_event| {}).await;
+    assert_eq!(wallet.listener_counts().await[&WalletEventType::NewOutput], 2);
+
+    wallet.emit_test_event(WalletEvent::ConsolidationRequired).await;
+    assert_eq!(event_counter.load(Ordering::SeqCst), 1);
+
+    assert!(handle.unsubscribe().await);
+    assert!(!handle.unsubscribe().await);
+    wallet.emit_test_event(WalletEvent::ConsolidationRequired).await;
+    assert_eq!(event_counter.load(Ordering::SeqCst), 1);
+    // The handler was dropped
+    assert_eq!(Arc::strong_count(&event_counter), 1);
+    assert_eq!(wallet.listener_counts().await.len(), 1);
+
+    // The handle doesn't keep the wallet alive
+    drop(wallet);
+    assert!(!other_handle.unsubscribe().await);
+
+    tear_down(storage_path)
 }
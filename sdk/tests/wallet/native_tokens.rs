@@ -1,12 +1,27 @@
 // Copyright 2023 IOTA Stiftung
 // SPDX-License-Identifier: Apache-2.0
 
+#[cfg(feature = "testing")]
+use iota_sdk::{
+    types::block::{
+        address::AliasAddress,
+        output::{
+            unlock_condition::{
+                AddressUnlockCondition, GovernorAddressUnlockCondition, StateControllerAddressUnlockCondition,
+            },
+            AliasId, AliasOutputBuilder, BasicOutputBuilder, Output,
+        },
+    },
+    wallet::Error,
+};
 use iota_sdk::{
     wallet::{account::SyncOptions, CreateNativeTokenParams, Result},
     U256,
 };
 use pretty_assertions::assert_eq;
 
+#[cfg(feature = "testing")]
+use crate::wallet::common::{create_account_and_address, setup_mock_wallet};
 use crate::wallet::common::{create_accounts_with_funds, make_wallet, setup, tear_down};
 
 #[ignore]
@@ -126,3 +141,212 @@ async fn native_token_foundry_metadata() -> Result<()> {
 
     tear_down(storage_path)
 }
+
+#[cfg(feature = "testing")]
+#[tokio::test]
+async fn mock_send_native_tokens_storage_deposit() -> Result<()> {
+    use iota_sdk::{
+        types::block::{
+            output::{MinimumStorageDepositBasicOutput, NativeToken, TokenId},
+            payload::transaction::TransactionEssence,
+        },
+        wallet::{
+            account::{NativeTokensTransaction, StorageDepositChoice},
+            SendNativeTokensParams,
+        },
+        U256,
+    };
+
+    let storage_path = "test-storage/mock_send_native_tokens_storage_deposit";
+    let (mock_client, wallet) = setup_mock_wallet(storage_path).await?;
+    let protocol_parameters = mock_client.protocol_parameters();
+    let token_supply = protocol_parameters.token_supply();
+    let (account_0, address_0) = create_account_and_address(&wallet).await?;
+    let (account_1, address_1) = create_account_and_address(&wallet).await?;
+
+    let token_id = TokenId::new([1; TokenId::LENGTH]);
+    mock_client.add_output(
+        BasicOutputBuilder::new_with_amount(1_000_000)
+            .add_native_token(NativeToken::new(token_id, 100)?)
+            .add_unlock_condition(AddressUnlockCondition::new(address_0))
+            .finish_output(token_supply)?,
+    );
+    account_0.sync(None).await?;
+
+    let min_storage_deposit_return =
+        MinimumStorageDepositBasicOutput::new(*protocol_parameters.rent_structure(), token_supply).finish()?;
+    let NativeTokensTransaction {
+        transaction,
+        storage_deposits,
+    } = account_0
+        .send_native_tokens_with_storage_deposits(
+            [
+                SendNativeTokensParams::new(address_1, [(token_id, U256::from(10))])?,
+                // Too low for a storage deposit return
+                SendNativeTokensParams::new(address_1, [(token_id, U256::from(20))])?
+                    .with_max_storage_deposit_return(min_storage_deposit_return - 1),
+                SendNativeTokensParams::new(address_1, [(token_id, U256::from(30))])?
+                    .with_max_storage_deposit_return(min_storage_deposit_return),
+            ],
+            None,
+        )
+        .await?;
+
+    let TransactionEssence::Regular(essence) = transaction.payload.essence();
+    let output_with_tokens = |amount: u64| {
+        essence
+            .outputs()
+            .iter()
+            .find(|output| {
+                output
+                    .native_tokens()
+                    .and_then(|native_tokens| native_tokens.get(&token_id))
+                    .is_some_and(|native_token| native_token.amount() == U256::from(amount))
+            })
+            .unwrap()
+    };
+
+    let output = output_with_tokens(10);
+    let return_amount = output
+        .unlock_conditions()
+        .unwrap()
+        .storage_deposit_return()
+        .unwrap()
+        .amount();
+    assert_eq!(return_amount, output.amount());
+    assert_eq!(
+        storage_deposits[0],
+        StorageDepositChoice::Return {
+            returned: output.amount(),
+            gifted: 0
+        }
+    );
+
+    let output = output_with_tokens(20);
+    let unlock_conditions = output.unlock_conditions().unwrap();
+    assert!(unlock_conditions.storage_deposit_return().is_none());
+    assert!(unlock_conditions.expiration().is_none());
+    assert_eq!(
+        storage_deposits[1],
+        StorageDepositChoice::Gift {
+            gifted: output.amount()
+        }
+    );
+
+    let output = output_with_tokens(30);
+    let return_amount = output
+        .unlock_conditions()
+        .unwrap()
+        .storage_deposit_return()
+        .unwrap()
+        .amount();
+    assert_eq!(return_amount, min_storage_deposit_return);
+    assert_eq!(
+        storage_deposits[2],
+        StorageDepositChoice::Return {
+            returned: min_storage_deposit_return,
+            gifted: output.amount() - min_storage_deposit_return
+        }
+    );
+
+    // The native tokens of the gifted output are available to the recipient right away, the others have to be claimed
+    let balance = account_1.sync(None).await?;
+    let native_token = balance
+        .native_tokens()
+        .iter()
+        .find(|native_token| native_token.token_id() == &token_id)
+        .unwrap();
+    assert_eq!(native_token.available(), U256::from(20));
+
+    tear_down(storage_path)
+}
+
+#[cfg(feature = "testing")]
+#[tokio::test]
+async fn mock_melt_mint_unsynced_foundry() -> Result<()> {
+    use iota_sdk::{
+        types::block::output::{
+            unlock_condition::ImmutableAliasAddressUnlockCondition, FoundryId, FoundryOutputBuilder, NativeToken,
+            SimpleTokenScheme, TokenId, TokenScheme,
+        },
+        wallet::account::AliasSyncOptions,
+    };
+
+    let storage_path = "test-storage/mock_melt_mint_unsynced_foundry";
+    let (mock_client, wallet) = setup_mock_wallet(storage_path).await?;
+    let protocol_parameters = mock_client.protocol_parameters();
+    let token_supply = protocol_parameters.token_supply();
+    let (account, address) = create_account_and_address(&wallet).await?;
+
+    let alias_id = AliasId::new([1; AliasId::LENGTH]);
+    mock_client.add_output(
+        AliasOutputBuilder::new_with_amount(100_000, alias_id)
+            .with_state_index(1)
+            .with_foundry_counter(1)
+            .add_unlock_condition(StateControllerAddressUnlockCondition::new(address))
+            .add_unlock_condition(GovernorAddressUnlockCondition::new(address))
+            .finish_output(token_supply)?,
+    );
+    let foundry_id = FoundryId::build(&AliasAddress::from(alias_id), 1, SimpleTokenScheme::KIND);
+    let token_id = TokenId::from(foundry_id);
+    mock_client.add_output(
+        FoundryOutputBuilder::new_with_minimum_storage_deposit(
+            *protocol_parameters.rent_structure(),
+            1,
+            TokenScheme::Simple(SimpleTokenScheme::new(100, 0, 500)?),
+        )
+        .add_unlock_condition(ImmutableAliasAddressUnlockCondition::new(AliasAddress::from(alias_id)))
+        .finish_output(token_supply)?,
+    );
+    mock_client.add_output(
+        BasicOutputBuilder::new_with_amount(1_000_000)
+            .add_native_token(NativeToken::new(token_id, 100)?)
+            .add_unlock_condition(AddressUnlockCondition::new(address))
+            .finish_output(token_supply)?,
+    );
+
+    // The foundry isn't synced
+    let sync_options = SyncOptions {
+        force_syncing: true,
+        alias: AliasSyncOptions {
+            foundry_outputs: false,
+            ..Default::default()
+        },
+        ..Default::default()
+    };
+    account.sync(Some(sync_options.clone())).await?;
+    assert!(account.unspent_foundry_output(&foundry_id).await?.is_none());
+
+    // It's requested from the node when melting and minting
+    account.melt_native_token(token_id, 10, None).await?;
+    account.sync(Some(sync_options.clone())).await?;
+    assert!(account.unspent_foundry_output(&foundry_id).await?.is_none());
+    account.mint_native_token(token_id, 20, None).await?;
+
+    let foundry_output = mock_client
+        .unspent_outputs()
+        .into_iter()
+        .find_map(|output| match output.output() {
+            Output::Foundry(foundry) if foundry.id() == foundry_id => Some(foundry.clone()),
+            _ => None,
+        })
+        .unwrap();
+    let TokenScheme::Simple(token_scheme) = foundry_output.token_scheme();
+    assert_eq!(token_scheme.minted_tokens(), 120.into());
+    assert_eq!(token_scheme.melted_tokens(), 10.into());
+    let balance = account.sync(Some(sync_options)).await?;
+    assert_eq!(balance.native_tokens()[0].total(), 110.into());
+
+    // Foundries which don't exist are still not found
+    let unknown_token_id = TokenId::from(FoundryId::build(
+        &AliasAddress::from(alias_id),
+        2,
+        SimpleTokenScheme::KIND,
+    ));
+    assert!(matches!(
+        account.melt_native_token(unknown_token_id, 10, None).await,
+        Err(Error::BurningOrMeltingFailed(_))
+    ));
+
+    tear_down(storage_path)
+}
@@ -3,6 +3,11 @@
 
 use std::str::FromStr;
 
+#[cfg(feature = "testing")]
+use iota_sdk::{
+    client::{api::PreparedTransactionData, mock::MockClient},
+    wallet::{account::TransactionOptions, Error, SendParams},
+};
 use iota_sdk::{
     types::block::{
         address::{Address, Bech32Address, ToBech32Ext},
@@ -15,6 +20,8 @@ use iota_sdk::{
 };
 use pretty_assertions::assert_eq;
 
+#[cfg(feature = "testing")]
+use crate::wallet::common::{add_basic_output, create_account_and_address, foreign_address, mock_wallet_builder};
 use crate::wallet::common::{create_accounts_with_funds, make_wallet, setup, tear_down};
 
 #[ignore]
@@ -867,3 +874,105 @@ async fn prepare_existing_nft_output_gift() -> Result<()> {
 
     tear_down(storage_path)
 }
+
+#[cfg(feature = "testing")]
+#[tokio::test]
+async fn mock_send_defaults() -> Result<()> {
+    use iota_sdk::{utils::unix_timestamp_now, wallet::core::SendDefaults};
+
+    let storage_path = "test-storage/mock_send_defaults";
+    setup(storage_path)?;
+
+    let mock_client = MockClient::default();
+    let return_address = foreign_address(&mock_client);
+    let wallet = mock_wallet_builder(storage_path, mock_client.clone())?
+        .with_send_defaults(SendDefaults {
+            return_address: Some(return_address),
+            expiration: None,
+        })
+        .finish()
+        .await?;
+    let (account_0, address_0) = create_account_and_address(&wallet).await?;
+    let (_, address_1) = create_account_and_address(&wallet).await?;
+
+    // Prepared transactions lock their inputs, so every one needs its own
+    for _ in 0..3 {
+        add_basic_output(&mock_client, address_0, 1_000_000)?;
+    }
+    account_0.sync(None).await?;
+
+    let options = TransactionOptions {
+        allow_micro_amount: true,
+        ..Default::default()
+    };
+    let micro_amount_output = |prepared_transaction: PreparedTransactionData| {
+        prepared_transaction
+            .essence
+            .as_regular()
+            .outputs()
+            .iter()
+            .find(|output| output.unlock_conditions().unwrap().expiration().is_some())
+            .unwrap()
+            .clone()
+    };
+
+    // The default expiration is one day
+    let now = unix_timestamp_now().as_secs() as u32;
+    let output = micro_amount_output(
+        account_0
+            .prepare_send([SendParams::new(1, address_1)?], options.clone())
+            .await?,
+    );
+    let unlock_conditions = output.unlock_conditions().unwrap();
+    assert_eq!(
+        unlock_conditions.storage_deposit_return().unwrap().return_address(),
+        return_address.inner()
+    );
+    let expiration = unlock_conditions.expiration().unwrap();
+    assert_eq!(expiration.return_address(), return_address.inner());
+    assert!((now + 24 * 60 * 60..now + 24 * 60 * 60 + 5).contains(&expiration.timestamp()));
+
+    wallet
+        .set_send_defaults(SendDefaults {
+            return_address: None,
+            expiration: Some(60 * 60),
+        })
+        .await?;
+    assert_eq!(wallet.send_defaults().await.expiration, Some(60 * 60));
+    assert!(matches!(
+        wallet
+            .set_send_defaults(SendDefaults {
+                return_address: None,
+                expiration: Some(0),
+            })
+            .await,
+        Err(Error::CustomInput(_))
+    ));
+
+    let now = unix_timestamp_now().as_secs() as u32;
+    let output = micro_amount_output(
+        account_0
+            .prepare_send([SendParams::new(1, address_1)?], options.clone())
+            .await?,
+    );
+    let expiration = output.unlock_conditions().unwrap().expiration().unwrap();
+    assert_eq!(expiration.return_address(), address_0.inner());
+    assert!((now + 60 * 60..now + 60 * 60 + 5).contains(&expiration.timestamp()));
+
+    // Values provided per call take precedence
+    let output = micro_amount_output(
+        account_0
+            .prepare_send(
+                [SendParams::new(1, address_1)?
+                    .with_return_address(address_1)
+                    .with_expiration(60)],
+                options,
+            )
+            .await?,
+    );
+    let expiration = output.unlock_conditions().unwrap().expiration().unwrap();
+    assert_eq!(expiration.return_address(), address_1.inner());
+    assert!(expiration.timestamp() < now + 60 * 60);
+
+    tear_down(storage_path)
+}
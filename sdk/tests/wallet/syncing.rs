@@ -1,6 +1,8 @@
 // Copyright 2023 IOTA Stiftung
 // SPDX-License-Identifier: Apache-2.0
 
+#[cfg(feature = "testing")]
+use iota_sdk::{client::mock::MockClient, types::block::output::feature::MetadataFeature, wallet::Error};
 use iota_sdk::{
     types::block::output::{
         unlock_condition::{
@@ -13,6 +15,10 @@ use iota_sdk::{
 };
 use pretty_assertions::assert_eq;
 
+#[cfg(feature = "testing")]
+use crate::wallet::common::{
+    add_basic_output, create_account_and_address, foreign_address, mock_wallet_builder, setup_mock_wallet,
+};
 use crate::wallet::common::{create_accounts_with_funds, make_wallet, setup, tear_down};
 
 #[tokio::test]
@@ -234,3 +240,411 @@ async fn background_syncing() -> Result<()> {
 
     tear_down(storage_path)
 }
+
+#[cfg(feature = "testing")]
+#[tokio::test]
+async fn mock_incremental_sync() -> Result<()> {
+    let storage_path = "test-storage/mock_incremental_sync";
+    let (mock_client, wallet) = setup_mock_wallet(storage_path).await?;
+    let (account, address) = create_account_and_address(&wallet).await?;
+    let recipient = foreign_address(&mock_client);
+    let token_supply = mock_client.protocol_parameters().token_supply();
+    let timestamp = mock_client.milestone_timestamp();
+    let incremental_options = SyncOptions {
+        incremental: true,
+        force_syncing: true,
+        ..Default::default()
+    };
+    let basic_output = |amount| {
+        BasicOutputBuilder::new_with_amount(amount)
+            .add_unlock_condition(AddressUnlockCondition::new(address))
+            .finish_output(token_supply)
+    };
+
+    mock_client.set_milestone(mock_client.milestone_index(), timestamp - 100);
+    mock_client.add_output(basic_output(1_000_000)?);
+    // Without a cursor, the address is fully synced
+    let balance = account.sync(Some(incremental_options.clone())).await?;
+    assert_eq!(balance.base_coin().total(), 1_000_000);
+
+    // An output booked before the cursor isn't requested again by an incremental sync
+    mock_client.set_milestone(mock_client.milestone_index() + 1, timestamp - 200);
+    mock_client.add_output(basic_output(500_000)?);
+    mock_client.set_milestone(mock_client.milestone_index() + 1, timestamp);
+    mock_client.add_output(basic_output(2_000_000)?);
+    let balance = account.sync(Some(incremental_options.clone())).await?;
+    assert_eq!(balance.base_coin().total(), 3_000_000);
+
+    let balance = account
+        .sync(Some(SyncOptions {
+            force_syncing: true,
+            ..Default::default()
+        }))
+        .await?;
+    assert_eq!(balance.base_coin().total(), 3_500_000);
+
+    // The spent outputs are taken from the utxo changes of the milestones since the cursor
+    account.send(3_000_000, recipient, None).await?;
+    let balance = account.sync(Some(incremental_options)).await?;
+    assert_eq!(balance.base_coin().total(), 500_000);
+    assert_eq!(account.unspent_outputs(None).await?.len(), 1);
+    assert!(account.pending_transactions().await.is_empty());
+
+    tear_down(storage_path)
+}
+
+#[cfg(feature = "testing")]
+#[tokio::test]
+async fn mock_sync_parallel_requests() -> Result<()> {
+    let storage_path = "test-storage/mock_sync_parallel_requests";
+    let (mock_client, wallet) = setup_mock_wallet(storage_path).await?;
+    let account = wallet.create_account().finish().await?;
+    let addresses = account.generate_ed25519_addresses(5, None).await?;
+
+    for address in &addresses {
+        add_basic_output(&mock_client, address.address(), 1_000_000)?;
+    }
+
+    // The result doesn't depend on how many addresses are synced at the same time
+    for parallel_requests in [1, 2] {
+        let balance = account
+            .sync(Some(SyncOptions {
+                parallel_requests,
+                force_syncing: true,
+                ..Default::default()
+            }))
+            .await?;
+        assert_eq!(balance.base_coin().total(), 5_000_000);
+        assert_eq!(account.unspent_outputs(None).await?.len(), 5);
+    }
+
+    account.send(4_500_000, *addresses[0].address(), None).await?;
+    let balance = account
+        .sync(Some(SyncOptions {
+            parallel_requests: 1,
+            force_syncing: true,
+            ..Default::default()
+        }))
+        .await?;
+    assert_eq!(balance.base_coin().total(), 5_000_000);
+    assert_eq!(account.unspent_outputs(None).await?.len(), 2);
+
+    tear_down(storage_path)
+}
+
+#[cfg(feature = "testing")]
+#[tokio::test]
+async fn mock_native_token_filter() -> Result<()> {
+    use std::collections::BTreeSet;
+
+    use iota_sdk::{
+        types::block::output::{NativeToken, TokenId},
+        wallet::account::NativeTokenFilter,
+    };
+
+    let storage_path = "test-storage/mock_native_token_filter";
+    let (mock_client, wallet) = setup_mock_wallet(storage_path).await?;
+    let token_supply = mock_client.protocol_parameters().token_supply();
+    let (account, address) = create_account_and_address(&wallet).await?;
+
+    let token_id = TokenId::new([1; TokenId::LENGTH]);
+    let spam_token_id = TokenId::new([2; TokenId::LENGTH]);
+    for (amount, token_id) in [(1_000_000, token_id), (2_000_000, spam_token_id)] {
+        mock_client.add_output(
+            BasicOutputBuilder::new_with_amount(amount)
+                .add_native_token(NativeToken::new(token_id, 100)?)
+                .add_unlock_condition(AddressUnlockCondition::new(address))
+                .finish_output(token_supply)?,
+        );
+    }
+    add_basic_output(&mock_client, address, 3_000_000)?;
+    let balance = account.sync(None).await?;
+    assert_eq!(balance.base_coin().total(), 6_000_000);
+    assert_eq!(balance.native_tokens().len(), 2);
+
+    // Outputs which were synced before are dropped once their token is denied
+    let balance = account
+        .sync(Some(SyncOptions {
+            force_syncing: true,
+            native_token_filter: Some(NativeTokenFilter::Denylist(BTreeSet::from([spam_token_id]))),
+            ..Default::default()
+        }))
+        .await?;
+    assert_eq!(balance.base_coin().total(), 4_000_000);
+    assert_eq!(balance.native_tokens().len(), 1);
+    assert_eq!(balance.native_tokens()[0].token_id(), &token_id);
+    assert_eq!(account.unspent_outputs(None).await?.len(), 2);
+
+    // With an allowlist, outputs without native tokens are still synced
+    let balance = account
+        .sync(Some(SyncOptions {
+            force_syncing: true,
+            native_token_filter: Some(NativeTokenFilter::Allowlist(BTreeSet::from([spam_token_id]))),
+            ..Default::default()
+        }))
+        .await?;
+    assert_eq!(balance.base_coin().total(), 5_000_000);
+    assert_eq!(balance.native_tokens().len(), 1);
+    assert_eq!(balance.native_tokens()[0].token_id(), &spam_token_id);
+
+    tear_down(storage_path)
+}
+
+#[cfg(feature = "testing")]
+#[tokio::test]
+async fn mock_quarantine_spam_outputs() -> Result<()> {
+    use iota_sdk::types::block::output::{feature::SenderFeature, NativeToken, TokenId};
+
+    let storage_path = "test-storage/mock_quarantine_spam_outputs";
+    let (mock_client, wallet) = setup_mock_wallet(storage_path).await?;
+    let protocol_parameters = mock_client.protocol_parameters();
+    let token_supply = protocol_parameters.token_supply();
+    let (account, address) = create_account_and_address(&wallet).await?;
+    let sync_options = SyncOptions {
+        force_syncing: true,
+        quarantine_spam_outputs: true,
+        ..Default::default()
+    };
+    let dust_output = |metadata: &str| {
+        BasicOutputBuilder::new_with_minimum_storage_deposit(*protocol_parameters.rent_structure())
+            .add_feature(MetadataFeature::new(metadata.as_bytes().to_vec()).unwrap())
+            .add_unlock_condition(AddressUnlockCondition::new(address))
+    };
+
+    add_basic_output(&mock_client, address, 1_000_000)?;
+    // Dust with a link, but from a known sender
+    let own_output = dust_output("Invoice at https://example.com")
+        .add_feature(SenderFeature::new(address))
+        .finish_output(token_supply)?;
+    mock_client.add_output(own_output.clone());
+    let spam_output = dust_output("Claim your airdrop at https://scam.example").finish_output(token_supply)?;
+    mock_client.add_output(spam_output.clone());
+
+    let balance = account.sync(Some(sync_options.clone())).await?;
+    assert_eq!(balance.base_coin().total(), 1_000_000 + own_output.amount());
+    let quarantined_outputs = account.quarantined_outputs().await;
+    assert_eq!(quarantined_outputs.len(), 1);
+    assert_eq!(quarantined_outputs[0].output, spam_output);
+    let spam_output_id = quarantined_outputs[0].output_id;
+    assert_eq!(wallet.quarantined_outputs().await.get(&0).map(Vec::len), Some(1));
+
+    // Released outputs are part of the balance and stay released
+    account.release_quarantined_outputs(&[spam_output_id]).await?;
+    let balance = account.sync(Some(sync_options.clone())).await?;
+    assert_eq!(
+        balance.base_coin().total(),
+        1_000_000 + own_output.amount() + spam_output.amount()
+    );
+    assert!(account.quarantined_outputs().await.is_empty());
+    assert!(matches!(
+        account.release_quarantined_outputs(&[spam_output_id]).await,
+        Err(Error::OutputNotQuarantined(_))
+    ));
+
+    // Burning consumes the output, the native tokens are burned and the base coins are kept
+    let token_id = TokenId::new([1; TokenId::LENGTH]);
+    let token_spam_output = dust_output("www.scam.example")
+        .add_native_token(NativeToken::new(token_id, 100)?)
+        .finish_output(token_supply)?;
+    mock_client.add_output(token_spam_output.clone());
+    let balance = account.sync(Some(sync_options.clone())).await?;
+    assert!(balance.native_tokens().is_empty());
+    let quarantined_output_ids = account
+        .quarantined_outputs()
+        .await
+        .into_iter()
+        .map(|output_data| output_data.output_id)
+        .collect::<Vec<_>>();
+    assert_eq!(quarantined_output_ids.len(), 1);
+    let transaction = account.burn_quarantined_outputs(quarantined_output_ids, None).await?;
+    account
+        .retry_transaction_until_included(&transaction.transaction_id, None, None)
+        .await?;
+    let balance = account.sync(Some(sync_options)).await?;
+    assert_eq!(
+        balance.base_coin().total(),
+        1_000_000 + own_output.amount() + spam_output.amount() + token_spam_output.amount()
+    );
+    assert!(balance.native_tokens().is_empty());
+    assert!(account.quarantined_outputs().await.is_empty());
+
+    tear_down(storage_path)
+}
+
+#[cfg(feature = "testing")]
+#[tokio::test]
+async fn mock_bootstrap() -> Result<()> {
+    let storage_path = "test-storage/mock_bootstrap";
+    let restored_storage_path = "test-storage/mock_bootstrap_restored";
+    setup(restored_storage_path)?;
+    let (mock_client, wallet) = setup_mock_wallet(storage_path).await?;
+    let (account, address_0) = create_account_and_address(&wallet).await?;
+    let addresses = account.generate_ed25519_addresses(2, None).await?;
+    let (address_1, address_2) = (*addresses[0].address(), *addresses[1].address());
+
+    let bootstrap_output_id = add_basic_output(&mock_client, address_0, 1_000_000)?;
+    add_basic_output(&mock_client, address_1, 2_000_000)?;
+    add_basic_output(&mock_client, address_2, 4_000_000)?;
+
+    // A wallet with the same mnemonic is restored from a known output and address
+    let restored_wallet = mock_wallet_builder(restored_storage_path, mock_client.clone())?
+        .with_bootstrap_outputs(vec![bootstrap_output_id])
+        .with_bootstrap_addresses(vec![address_1])
+        .finish()
+        .await?;
+    let restored_account = restored_wallet.create_account().finish().await?;
+    restored_account.generate_ed25519_addresses(2, None).await?;
+
+    // The first sync only starts from the bootstrap set, the output of the other address isn't found yet
+    let balance = restored_account.sync(None).await?;
+    assert_eq!(balance.base_coin().total(), 3_000_000);
+
+    // Later syncs aren't limited anymore
+    let balance = restored_account
+        .sync(Some(SyncOptions {
+            force_syncing: true,
+            ..Default::default()
+        }))
+        .await?;
+    assert_eq!(balance.base_coin().total(), 7_000_000);
+
+    // Only the first sync is bootstrapped, also if no outputs were found: the bootstrap output is created afterwards
+    // on a new node, but the second sync still finds all outputs instead of only the bootstrapped ones
+    let late_storage_path = "test-storage/mock_bootstrap_late";
+    setup(late_storage_path)?;
+    let late_output_id = add_basic_output(&MockClient::default(), address_0, 1_000_000)?;
+    let late_mock_client = MockClient::default();
+    let late_wallet = mock_wallet_builder(late_storage_path, late_mock_client.clone())?
+        .with_bootstrap_outputs(vec![late_output_id])
+        .finish()
+        .await?;
+    let late_account = late_wallet.create_account().finish().await?;
+    late_account.generate_ed25519_addresses(2, None).await?;
+    let balance = late_account.sync(None).await?;
+    assert_eq!(balance.base_coin().total(), 0);
+
+    assert_eq!(
+        add_basic_output(&late_mock_client, address_0, 1_000_000)?,
+        late_output_id
+    );
+    add_basic_output(&late_mock_client, address_2, 4_000_000)?;
+    let balance = late_account
+        .sync(Some(SyncOptions {
+            force_syncing: true,
+            ..Default::default()
+        }))
+        .await?;
+    assert_eq!(balance.base_coin().total(), 5_000_000);
+
+    tear_down(late_storage_path)?;
+    tear_down(restored_storage_path)?;
+    tear_down(storage_path)
+}
+
+#[cfg(all(feature = "events", feature = "testing"))]
+#[tokio::test]
+async fn mock_rebuild_ledger_from_history() -> Result<()> {
+    use std::sync::{Arc, Mutex};
+
+    use iota_sdk::{
+        types::{block::output::OutputId, TryFromDto},
+        wallet::{
+            account::{AccountDetails, AccountDetailsDto, TransactionOptions},
+            events::{
+                types::{LedgerRebuildEvent, WalletEvent},
+                WalletEventType,
+            },
+        },
+    };
+
+    let storage_path = "test-storage/mock_rebuild_ledger_from_history";
+    let (mock_client, wallet) = setup_mock_wallet(storage_path).await?;
+    let (account, address) = create_account_and_address(&wallet).await?;
+    let recipient = foreign_address(&mock_client);
+    let annotated_output_id = add_basic_output(&mock_client, address, 1_000_000)?;
+    let spent_output_id = add_basic_output(&mock_client, address, 2_000_000)?;
+    let pending_input_id = add_basic_output(&mock_client, address, 500_000)?;
+    account.sync(None).await?;
+    account
+        .set_output_annotation(&annotated_output_id, "purpose", "savings")
+        .await?;
+
+    let sent_transaction = account
+        .send(
+            300_000,
+            recipient,
+            TransactionOptions {
+                custom_inputs: Some(vec![spent_output_id]),
+                ..Default::default()
+            },
+        )
+        .await?;
+    account.sync(None).await?;
+    // Sent, but not synced, so it's still pending
+    let idempotency_options = TransactionOptions {
+        custom_inputs: Some(vec![pending_input_id]),
+        idempotency_key: Some("payment".to_string()),
+        ..Default::default()
+    };
+    let pending_transaction = account.send(200_000, recipient, idempotency_options.clone()).await?;
+
+    // Corrupt the local state
+    {
+        let mut account_details = account.details_mut().await;
+        let mut dto = AccountDetailsDto::from(&*account_details);
+        dto.unspent_outputs.clear();
+        dto.addresses_with_unspent_outputs.clear();
+        dto.transactions.remove(&sent_transaction.transaction_id);
+        dto.locked_outputs.insert(OutputId::null());
+        *account_details = AccountDetails::try_from_dto(dto)?;
+    }
+
+    let events = Arc::new(Mutex::new(Vec::new()));
+    let events_clone = events.clone();
+    wallet
+        .listen([WalletEventType::LedgerRebuild], move |event| {
+            if let WalletEvent::LedgerRebuild(event) = &event.event {
+                events_clone.lock().unwrap().push(*event);
+            }
+        })
+        .await;
+
+    let balance = account.rebuild_ledger_from_history(None).await?;
+    // The annotated output and the remainders of both transactions
+    assert_eq!(balance.base_coin().total(), 1_000_000 + 1_700_000 + 300_000);
+    assert_eq!(account.unspent_outputs(None).await?.len(), 3);
+    let annotated_output = account.get_output(&annotated_output_id).await.unwrap();
+    assert_eq!(
+        annotated_output.annotations.get("purpose").map(String::as_str),
+        Some("savings")
+    );
+
+    // The pending transaction was synced again and the sent transaction restored from the node
+    let account_details = account.details().await;
+    assert!(account_details.locked_outputs().is_empty());
+    assert!(account_details.pending_transactions().is_empty());
+    assert!(account_details
+        .transactions()
+        .contains_key(&sent_transaction.transaction_id));
+    assert!(account_details
+        .transactions()
+        .contains_key(&pending_transaction.transaction_id));
+    drop(account_details);
+    // Retrying with the idempotency key doesn't send again
+    let retried_transaction = account.send(200_000, recipient, idempotency_options).await?;
+    assert_eq!(retried_transaction.transaction_id, pending_transaction.transaction_id);
+
+    assert_eq!(
+        *events.lock().unwrap(),
+        [
+            LedgerRebuildEvent::Cleared,
+            LedgerRebuildEvent::Finished {
+                unspent_outputs: 3,
+                transactions: 2,
+                incoming_transactions: 0,
+            }
+        ]
+    );
+
+    tear_down(storage_path)
+}
@@ -2,8 +2,35 @@
 // SPDX-License-Identifier: Apache-2.0
 
 use iota_sdk::wallet::{account::TransactionOptions, MintNftParams, Result, SendNftParams, SendParams};
+#[cfg(feature = "testing")]
+use iota_sdk::{
+    client::{api::PreparedTransactionData, mock::MockClient},
+    types::{
+        api::core::response::LedgerInclusionState,
+        block::{
+            address::{Address, AliasAddress},
+            output::{
+                feature::MetadataFeature, unlock_condition::AddressUnlockCondition, AliasId, BasicOutputBuilder, Output,
+            },
+            payload::transaction::TransactionEssence,
+        },
+        TryFromDto,
+    },
+    wallet::{
+        account::{
+            types::InclusionState, AccountDetails, AccountDetailsDto, CoinSelectionStrategy, ServiceFee,
+            ServiceFeeAmount, SyncOptions,
+        },
+        Error,
+    },
+};
 use pretty_assertions::assert_eq;
 
+#[cfg(feature = "testing")]
+use crate::wallet::common::{
+    add_basic_output, create_account_and_address, foreign_address, make_mock_wallet, mock_wallet_builder,
+    setup_mock_wallet,
+};
 use crate::wallet::common::{create_accounts_with_funds, make_wallet, setup, tear_down};
 
 #[ignore]
@@ -257,6 +284,36 @@ async fn conflicting_transaction() -> Result<()> {
     tear_down(storage_path_1)
 }
 
+#[ignore]
+#[tokio::test]
+async fn concurrent_transactions() -> Result<()> {
+    let storage_path = "test-storage/concurrent_transactions";
+    setup(storage_path)?;
+
+    let wallet = make_wallet(storage_path, None, None).await?;
+
+    let account_0 = &create_accounts_with_funds(&wallet, 1).await?[0];
+    let account_1 = wallet.create_account().finish().await?;
+    let address = *account_1.addresses().await?[0].address();
+
+    // Send multiple transactions at the same time without syncing in between
+    let results = futures::future::join_all(
+        (1..=3).map(|i| account_0.send_with_params([SendParams::new(i * 1_000_000, address).unwrap()], None)),
+    )
+    .await;
+
+    // The transactions that could be created must not use the same inputs
+    let mut inputs = std::collections::HashSet::new();
+    for tx in results.into_iter().filter_map(|result| result.ok()) {
+        for input in &tx.inputs {
+            assert!(inputs.insert(*input.metadata.output_id()));
+        }
+    }
+    assert!(!inputs.is_empty());
+
+    tear_down(storage_path)
+}
+
 #[tokio::test]
 #[cfg(all(feature = "ledger_nano", feature = "events"))]
 #[ignore = "requires ledger nano instance"]
@@ -305,3 +362,1110 @@ async fn prepare_transaction_ledger() -> Result<()> {
 
     tear_down(storage_path)
 }
+
+#[cfg(feature = "testing")]
+#[tokio::test]
+async fn mock_sync_and_send() -> Result<()> {
+    let storage_path = "test-storage/mock_sync_and_send";
+    let (mock_client, wallet) = setup_mock_wallet(storage_path).await?;
+    let (account_0, address_0) = create_account_and_address(&wallet).await?;
+    let (account_1, address_1) = create_account_and_address(&wallet).await?;
+
+    add_basic_output(&mock_client, address_0, 1_000_000)?;
+
+    let balance = account_0.sync(None).await?;
+    assert_eq!(balance.base_coin().available(), 1_000_000);
+
+    let transaction = account_0.send(200_000, address_1, None).await?;
+    let (_, metadata) = mock_client.block(&transaction.block_id.unwrap()).unwrap();
+    assert_eq!(metadata.ledger_inclusion_state, Some(LedgerInclusionState::Included));
+
+    let balance = account_0.sync(None).await?;
+    assert_eq!(balance.base_coin().available(), 800_000);
+    assert!(account_0.pending_transactions().await.is_empty());
+
+    let balance = account_1.sync(None).await?;
+    assert_eq!(balance.base_coin().available(), 200_000);
+    // The spent output isn't returned anymore
+    assert_eq!(mock_client.unspent_outputs().len(), 2);
+
+    tear_down(storage_path)
+}
+
+#[cfg(feature = "testing")]
+#[tokio::test]
+async fn mock_transaction_approval() -> Result<()> {
+    let storage_path = "test-storage/mock_transaction_approval";
+    let (mock_client, wallet) = setup_mock_wallet(storage_path).await?;
+    let (account, address) = create_account_and_address(&wallet).await?;
+
+    add_basic_output(&mock_client, address, 1_000_000)?;
+    account.sync(None).await?;
+
+    wallet
+        .set_approval_handler(|_, prepared_transaction_data: &PreparedTransactionData| {
+            prepared_transaction_data.essence.as_regular().outputs().len() > 2
+        })
+        .await;
+    assert!(matches!(
+        account.send(200_000, address, None).await,
+        Err(Error::TransactionRejected)
+    ));
+    // The inputs of the rejected transaction are unlocked again
+    assert!(account.details().await.locked_outputs().is_empty());
+
+    wallet.clear_approval_handler().await;
+    account.send(200_000, address, None).await?;
+
+    tear_down(storage_path)
+}
+
+#[cfg(feature = "testing")]
+#[tokio::test]
+async fn mock_output_maturity() -> Result<()> {
+    let storage_path = "test-storage/mock_output_maturity";
+    setup(storage_path)?;
+
+    let mock_client = MockClient::default();
+    let wallet = mock_wallet_builder(storage_path, mock_client.clone())?
+        .with_output_maturity(60)
+        .finish()
+        .await?;
+    let (account, address) = create_account_and_address(&wallet).await?;
+
+    let now = mock_client.milestone_timestamp();
+    mock_client.set_milestone(1, now - 120);
+    let mature_output_id = add_basic_output(&mock_client, address, 1_000_000)?;
+    mock_client.set_milestone(2, now);
+    let fresh_output_id = add_basic_output(&mock_client, address, 1_000_000)?;
+
+    let balance = account.sync(None).await?;
+    assert_eq!(balance.base_coin().available(), 2_000_000);
+
+    // Only the mature output can be selected
+    assert!(account.send(1_500_000, address, None).await.is_err());
+    account.send(500_000, address, None).await?;
+    assert!(mock_client.output(&mature_output_id).unwrap().metadata().is_spent());
+    assert!(!mock_client.output(&fresh_output_id).unwrap().metadata().is_spent());
+
+    tear_down(storage_path)
+}
+
+#[cfg(feature = "testing")]
+#[tokio::test]
+async fn mock_bulk_send() -> Result<()> {
+    let storage_path = "test-storage/mock_bulk_send";
+    let (mock_client, wallet) = setup_mock_wallet(storage_path).await?;
+    let (account_0, address_0) = create_account_and_address(&wallet).await?;
+    let (account_1, address_1) = create_account_and_address(&wallet).await?;
+
+    add_basic_output(&mock_client, address_0, 50_000_000)?;
+    account_0.sync(None).await?;
+
+    // More recipients than fit into a single transaction, funded by the remainders of the previous ones
+    let params = (0..300)
+        .map(|_| SendParams::new(100_000, address_1))
+        .collect::<Result<Vec<_>>>()?;
+    let report = account_0.bulk_send(params, None).await?;
+
+    assert_eq!(report.transaction_ids.len(), 3);
+    assert_eq!(report.recipients.len(), 300);
+    assert!(report.recipients[..127]
+        .iter()
+        .all(|id| id == &report.transaction_ids[0]));
+    assert!(report.recipients[127..254]
+        .iter()
+        .all(|id| id == &report.transaction_ids[1]));
+    assert!(report.recipients[254..]
+        .iter()
+        .all(|id| id == &report.transaction_ids[2]));
+
+    let balance = account_0.sync(None).await?;
+    assert_eq!(balance.base_coin().available(), 20_000_000);
+    let balance = account_1.sync(None).await?;
+    assert_eq!(balance.base_coin().available(), 30_000_000);
+
+    // Nothing is sent if the first transaction already fails
+    let params = vec![SendParams::new(30_000_000, address_1)?];
+    assert!(matches!(
+        account_0.bulk_send(params, None).await,
+        Err(Error::InsufficientFunds { .. })
+    ));
+
+    tear_down(storage_path)
+}
+
+#[cfg(feature = "testing")]
+#[tokio::test]
+async fn mock_bulk_send_resume() -> Result<()> {
+    let storage_path = "test-storage/mock_bulk_send_resume";
+    let (mock_client, wallet) = setup_mock_wallet(storage_path).await?;
+    let (account_0, address_0) = create_account_and_address(&wallet).await?;
+    let (account_1, address_1) = create_account_and_address(&wallet).await?;
+
+    add_basic_output(&mock_client, address_0, 27_000_000)?;
+    account_0.sync(None).await?;
+
+    // The funds run out in the third transaction, the report has the recipients of the first two
+    let params = (0..300)
+        .map(|_| SendParams::new(100_000, address_1))
+        .collect::<Result<Vec<_>>>()?;
+    let report = match account_0.bulk_send(params.clone(), None).await {
+        Err(Error::BulkSendIncomplete { report, error }) => {
+            assert!(matches!(*error, Error::InsufficientFunds { .. }));
+            report
+        }
+        other => panic!("expected BulkSendIncomplete, got {other:?}"),
+    };
+    assert_eq!(report.transaction_ids.len(), 2);
+    assert_eq!(report.recipients.len(), 254);
+
+    // After topping up, the remaining recipients are sent to
+    add_basic_output(&mock_client, address_0, 5_000_000)?;
+    account_0
+        .sync(Some(SyncOptions {
+            force_syncing: true,
+            ..Default::default()
+        }))
+        .await?;
+    let resumed_report = account_0
+        .bulk_send(params[report.recipients.len()..].to_vec(), None)
+        .await?;
+    assert_eq!(resumed_report.transaction_ids.len(), 1);
+    assert_eq!(resumed_report.recipients.len(), 46);
+
+    let balance = account_1.sync(None).await?;
+    assert_eq!(balance.base_coin().available(), 30_000_000);
+
+    tear_down(storage_path)
+}
+
+#[cfg(feature = "testing")]
+#[tokio::test]
+async fn mock_transaction_views() -> Result<()> {
+    use iota_sdk::{
+        types::block::payload::{Payload, TaggedDataPayload},
+        wallet::account::types::{OutputData, OutputDataDto, Transaction, TransactionDto},
+    };
+
+    let storage_path = "test-storage/mock_transaction_views";
+    let (mock_client, wallet) = setup_mock_wallet(storage_path).await?;
+    let (account, address) = create_account_and_address(&wallet).await?;
+    let input_id = add_basic_output(&mock_client, address, 1_000_000)?;
+    account.sync(None).await?;
+
+    let options = TransactionOptions {
+        tagged_data_payload: Some(TaggedDataPayload::new(b"airdrop".to_vec(), b"\x00\x01".to_vec())?),
+        note: Some("views".to_string()),
+        ..Default::default()
+    };
+    let transaction = account.send(200_000, foreign_address(&mock_client), options).await?;
+    account
+        .sync(Some(SyncOptions {
+            force_syncing: true,
+            ..Default::default()
+        }))
+        .await?;
+    let transaction = account.get_transaction(&transaction.transaction_id).await.unwrap();
+
+    // The decoded view shows the inputs with their outputs and the tagged data of the essence
+    assert_eq!(transaction.inputs.len(), 1);
+    assert_eq!(transaction.inputs[0].metadata.output_id(), &input_id);
+    let TransactionEssence::Regular(essence) = transaction.payload.essence();
+    let Some(Payload::TaggedData(tagged_data)) = essence.payload() else {
+        panic!("expected a tagged data payload");
+    };
+    assert_eq!(tagged_data.tag(), b"airdrop");
+    assert_eq!(tagged_data.data(), b"\x00\x01");
+    assert_eq!(transaction.inclusion_state, InclusionState::Confirmed);
+    assert_eq!(transaction.note.as_deref(), Some("views"));
+
+    // The JSON views can be read back
+    let json = serde_json::to_string(&TransactionDto::from(&transaction))?;
+    assert_eq!(
+        Transaction::try_from_dto(serde_json::from_str::<TransactionDto>(&json)?)?,
+        transaction
+    );
+    let remainder = account.unspent_outputs(None).await?.remove(0);
+    assert_eq!(remainder.output.kind_str(), "Basic");
+    assert_eq!(remainder.output_id.transaction_id(), &transaction.transaction_id);
+    let json = serde_json::to_string(&OutputDataDto::from(&remainder))?;
+    assert_eq!(
+        OutputData::try_from_dto(serde_json::from_str::<OutputDataDto>(&json)?)?,
+        remainder
+    );
+
+    tear_down(storage_path)
+}
+
+#[cfg(feature = "testing")]
+#[tokio::test]
+async fn mock_validate_transaction() -> Result<()> {
+    use iota_sdk::{
+        client::{
+            api::{verify_semantic, SignedTransactionData, SignedTransactionDataDto},
+            secret::types::InputSigningData,
+        },
+        types::block::semantic::ConflictReason,
+    };
+
+    let storage_path = "test-storage/mock_validate_transaction";
+    let (mock_client, wallet) = setup_mock_wallet(storage_path).await?;
+    let (account, address) = create_account_and_address(&wallet).await?;
+    add_basic_output(&mock_client, address, 1_000_000)?;
+    add_basic_output(&mock_client, address, 1_000_000)?;
+    account.sync(None).await?;
+
+    let prepared_transaction = account
+        .prepare_send([SendParams::new(1_500_000, foreign_address(&mock_client))?], None)
+        .await?;
+    let signed_transaction = account.sign_transaction_essence(&prepared_transaction).await?;
+
+    // A signed transaction exported as JSON is valid with the inputs data it contains
+    let json = serde_json::to_string(&SignedTransactionDataDto::from(&signed_transaction))?;
+    let protocol_parameters = account.client().get_protocol_parameters().await?;
+    let imported_transaction = SignedTransactionData::try_from_dto_with_params(
+        serde_json::from_str::<SignedTransactionDataDto>(&json)?,
+        &protocol_parameters,
+    )?;
+    let current_time = account.client().get_time_checked().await?;
+    let payload = &imported_transaction.transaction_payload;
+    assert_eq!(
+        verify_semantic(&imported_transaction.inputs_data, payload, current_time)?,
+        ConflictReason::None
+    );
+    assert_eq!(
+        verify_semantic(&imported_transaction.inputs_data[..1], payload, current_time)?,
+        ConflictReason::InputUtxoNotFound
+    );
+
+    // Without inputs data, the inputs are fetched from the node
+    let TransactionEssence::Regular(essence) = payload.essence();
+    let output_ids = essence
+        .inputs()
+        .iter()
+        .map(|input| *input.as_utxo().output_id())
+        .collect::<Vec<_>>();
+    let outputs = account.client().get_outputs(&output_ids).await?;
+    assert!(outputs.iter().all(|output| !output.metadata().is_spent()));
+    let fetched_inputs = outputs
+        .into_iter()
+        .map(|output| InputSigningData {
+            output_metadata: *output.metadata(),
+            output: output.into_output(),
+            chain: None,
+        })
+        .collect::<Vec<_>>();
+    assert_eq!(
+        verify_semantic(&fetched_inputs, payload, current_time)?,
+        ConflictReason::None
+    );
+
+    // Once the transaction is sent, its inputs are spent on the node
+    account.submit_and_store_transaction(signed_transaction, None).await?;
+    let outputs = account.client().get_outputs(&output_ids).await?;
+    assert!(outputs.iter().all(|output| output.metadata().is_spent()));
+
+    tear_down(storage_path)
+}
+
+#[cfg(feature = "testing")]
+#[tokio::test]
+async fn mock_payment_processing() -> Result<()> {
+    use iota_sdk::wallet::account::types::OutputData;
+
+    // Like the payment processor example: payments are reported after 3 confirming milestones and marked with an
+    // annotation, then swept to the cold storage address
+    let pending_payments = |outputs: Vec<OutputData>| {
+        outputs
+            .into_iter()
+            .filter(|output_data| {
+                !output_data.remainder
+                    && output_data.output.is_basic()
+                    && !output_data.annotations.contains_key("payment")
+            })
+            .collect::<Vec<_>>()
+    };
+    let confirmed_payments = |outputs: Vec<OutputData>, latest_milestone: u32| {
+        pending_payments(outputs)
+            .into_iter()
+            .filter(|output_data| latest_milestone.saturating_sub(output_data.metadata.milestone_index_booked()) >= 3)
+            .map(|output_data| output_data.output_id)
+            .collect::<Vec<_>>()
+    };
+    let force_sync = || {
+        Some(SyncOptions {
+            force_syncing: true,
+            ..Default::default()
+        })
+    };
+
+    let storage_path = "test-storage/mock_payment_processing";
+    let (mock_client, wallet) = setup_mock_wallet(storage_path).await?;
+    let (account, address_0) = create_account_and_address(&wallet).await?;
+    let addresses = account.generate_ed25519_addresses(2, None).await?;
+    let cold_storage_address = foreign_address(&mock_client);
+
+    let now = mock_client.milestone_timestamp();
+    mock_client.set_milestone(10, now);
+    let payment_0 = add_basic_output(&mock_client, address_0, 1_000_000)?;
+    let payment_1 = add_basic_output(&mock_client, *addresses[0].address(), 2_000_000)?;
+    mock_client.set_milestone(13, now);
+    let payment_2 = add_basic_output(&mock_client, *addresses[1].address(), 4_000_000)?;
+    account.sync(None).await?;
+
+    // Only the payments booked 3 milestones ago are confirmed
+    assert_eq!(pending_payments(account.unspent_outputs(None).await?).len(), 3);
+    let mut confirmed = confirmed_payments(account.unspent_outputs(None).await?, mock_client.milestone_index());
+    confirmed.sort();
+    assert_eq!(confirmed, [payment_0, payment_1]);
+    for output_id in &confirmed {
+        account.set_output_annotation(output_id, "payment", "confirmed").await?;
+    }
+
+    // The sweep only uses the confirmed payments as inputs
+    let options = TransactionOptions {
+        custom_inputs: Some(confirmed.clone()),
+        ..Default::default()
+    };
+    let transaction = account.send(3_000_000, cold_storage_address, options).await?;
+    let mut inputs = transaction
+        .inputs
+        .iter()
+        .map(|input| *input.metadata.output_id())
+        .collect::<Vec<_>>();
+    inputs.sort();
+    assert_eq!(inputs, confirmed);
+    account.sync(force_sync()).await?;
+    let unspent_outputs = account.unspent_outputs(None).await?;
+    assert_eq!(unspent_outputs.len(), 1);
+    assert_eq!(pending_payments(unspent_outputs)[0].output_id, payment_2);
+
+    mock_client.set_milestone(16, now);
+    account.sync(force_sync()).await?;
+    assert_eq!(
+        confirmed_payments(account.unspent_outputs(None).await?, mock_client.milestone_index()),
+        [payment_2]
+    );
+    account
+        .set_output_annotation(&payment_2, "payment", "confirmed")
+        .await?;
+
+    // Reported payments aren't reported again after a restart, the annotations are stored with the account
+    let json = serde_json::to_string(&AccountDetailsDto::from(&*account.details().await))?;
+    let stored_account = AccountDetails::try_from_dto(serde_json::from_str::<AccountDetailsDto>(&json)?)?;
+    assert!(pending_payments(stored_account.unspent_outputs().values().cloned().collect()).is_empty());
+
+    tear_down(storage_path)
+}
+
+#[cfg(feature = "testing")]
+#[tokio::test]
+async fn mock_block_pow_score() -> Result<()> {
+    use iota_sdk::types::block::{output::RentStructure, protocol::ProtocolParameters};
+
+    let storage_path = "test-storage/mock_block_pow_score";
+    setup(storage_path)?;
+    // A network which requires some proof of work, so the blocks get a nonce
+    let min_pow_score = 100;
+    let protocol_parameters = ProtocolParameters::new(
+        MockClient::default().protocol_parameters().protocol_version(),
+        String::from("mock"),
+        "smr",
+        min_pow_score,
+        15,
+        RentStructure::default(),
+        1_813_620_509_061_365,
+    )?;
+    let mock_client = MockClient::new(protocol_parameters);
+    let wallet = make_mock_wallet(storage_path, mock_client.clone()).await?;
+    let (account, address) = create_account_and_address(&wallet).await?;
+    add_basic_output(&mock_client, address, 1_000_000)?;
+    account.sync(None).await?;
+
+    let transaction = account.send(200_000, foreign_address(&mock_client), None).await?;
+    let (block, _) = mock_client.block(&transaction.block_id.unwrap()).unwrap();
+    assert!(block.pow_score() >= min_pow_score as f64);
+
+    tear_down(storage_path)
+}
+
+#[cfg(feature = "testing")]
+#[tokio::test]
+async fn mock_service_fee() -> Result<()> {
+    let storage_path = "test-storage/mock_service_fee";
+    setup(storage_path)?;
+
+    let mock_client = MockClient::default();
+    let fee_address = foreign_address(&mock_client);
+    // 1% of the sent amount
+    let wallet = mock_wallet_builder(storage_path, mock_client.clone())?
+        .with_service_fee(ServiceFee::new(fee_address, ServiceFeeAmount::BasisPoints(100)))
+        .finish()
+        .await?;
+    let (account_0, address_0) = create_account_and_address(&wallet).await?;
+    let (_, address_1) = create_account_and_address(&wallet).await?;
+    let fee_outputs = || {
+        mock_client
+            .unspent_outputs()
+            .into_iter()
+            .filter(|output| {
+                output
+                    .output()
+                    .unlock_conditions()
+                    .and_then(|unlock_conditions| unlock_conditions.address())
+                    .is_some_and(|unlock_condition| unlock_condition.address() == fee_address.inner())
+            })
+            .map(|output| output.output().amount())
+            .collect::<Vec<_>>()
+    };
+
+    add_basic_output(&mock_client, address_0, 10_000_000)?;
+    account_0.sync(None).await?;
+
+    let prepared_transaction = account_0
+        .prepare_send([SendParams::new(5_000_000, address_1)?], None)
+        .await?;
+    assert_eq!(prepared_transaction.essence.as_regular().outputs().len(), 3);
+    account_0
+        .sign_and_submit_transaction(prepared_transaction, None)
+        .await?;
+    assert_eq!(fee_outputs(), [50_000]);
+
+    let balance = account_0.sync(None).await?;
+    assert_eq!(balance.base_coin().available(), 4_950_000);
+
+    // Nothing leaves the account, so no fee is due
+    account_0.send(1_000_000, address_0, None).await?;
+    assert_eq!(fee_outputs(), [50_000]);
+
+    tear_down(storage_path)
+}
+
+#[cfg(feature = "testing")]
+#[tokio::test]
+async fn mock_single_address_coin_selection() -> Result<()> {
+    let storage_path = "test-storage/mock_single_address_coin_selection";
+    let (mock_client, wallet) = setup_mock_wallet(storage_path).await?;
+    let (account, address_0) = create_account_and_address(&wallet).await?;
+    let address_1 = account.generate_ed25519_addresses(1, None).await?[0]
+        .clone()
+        .into_bech32();
+    let recipient = foreign_address(&mock_client);
+
+    add_basic_output(&mock_client, address_0, 1_000_000)?;
+    add_basic_output(&mock_client, address_1, 2_000_000)?;
+    account.sync(None).await?;
+
+    let options = TransactionOptions {
+        coin_selection_strategy: CoinSelectionStrategy::SingleAddressOnly,
+        ..Default::default()
+    };
+
+    // Neither address can cover the amount on its own
+    match account
+        .prepare_send([SendParams::new(2_500_000, recipient)?], options.clone())
+        .await
+    {
+        Err(Error::AddressLinkage(addresses)) => {
+            assert_eq!(addresses.len(), 2);
+            assert!(addresses.contains(&address_0) && addresses.contains(&address_1));
+        }
+        other => panic!("expected address linkage error, got {other:?}"),
+    }
+
+    let prepared_transaction = account
+        .prepare_send([SendParams::new(500_000, recipient)?], options)
+        .await?;
+    assert_eq!(prepared_transaction.inputs_data.len(), 1);
+    assert_eq!(
+        prepared_transaction.inputs_data[0]
+            .output
+            .unlock_conditions()
+            .and_then(|unlock_conditions| unlock_conditions.address())
+            .map(|unlock_condition| unlock_condition.address()),
+        Some(address_1.inner())
+    );
+
+    tear_down(storage_path)
+}
+
+#[cfg(feature = "testing")]
+#[tokio::test]
+async fn mock_transaction_too_large() -> Result<()> {
+    let storage_path = "test-storage/mock_transaction_too_large";
+    let (mock_client, wallet) = setup_mock_wallet(storage_path).await?;
+    let (account, address) = create_account_and_address(&wallet).await?;
+    let recipient = foreign_address(&mock_client);
+    let token_supply = mock_client.protocol_parameters().token_supply();
+
+    add_basic_output(&mock_client, address, 100_000_000)?;
+    account.sync(None).await?;
+
+    // Each output is more than 8 kB long, so not all of them fit into one block
+    let outputs = (0..6)
+        .map(|_| {
+            BasicOutputBuilder::new_with_amount(1_000_000)
+                .add_unlock_condition(AddressUnlockCondition::new(recipient))
+                .add_feature(MetadataFeature::new([0; 8000])?)
+                .finish_output(token_supply)
+        })
+        .collect::<std::result::Result<Vec<Output>, _>>()?;
+
+    let outputs_to_move = match account.prepare_transaction(outputs.clone(), None).await {
+        Err(Error::TransactionTooLarge { outputs_to_move, .. }) => outputs_to_move,
+        other => panic!("expected transaction too large error, got {other:?}"),
+    };
+    assert_eq!(outputs_to_move, [4, 5]);
+
+    // The inputs got unlocked again and the suggested split fits
+    account
+        .prepare_transaction(outputs[..outputs_to_move[0]].to_vec(), None)
+        .await?;
+
+    tear_down(storage_path)
+}
+
+#[cfg(feature = "testing")]
+#[tokio::test]
+async fn mock_idempotency_key() -> Result<()> {
+    let storage_path = "test-storage/mock_idempotency_key";
+    let (mock_client, wallet) = setup_mock_wallet(storage_path).await?;
+    let (account_0, address_0) = create_account_and_address(&wallet).await?;
+    let (account_1, address_1) = create_account_and_address(&wallet).await?;
+
+    for _ in 0..3 {
+        add_basic_output(&mock_client, address_0, 1_000_000)?;
+    }
+    account_0.sync(None).await?;
+
+    let options = |key: &str| TransactionOptions {
+        idempotency_key: Some(key.to_string()),
+        ..Default::default()
+    };
+    let transaction = account_0.send(200_000, address_1, options("payout-1")).await?;
+
+    // Retrying returns the original transaction instead of sending another one
+    let retried = account_0.send(200_000, address_1, options("payout-1")).await?;
+    assert_eq!(retried.transaction_id, transaction.transaction_id);
+    let retried = account_0
+        .send_with_params([SendParams::new(200_000, address_1)?], options("payout-1"))
+        .await?;
+    assert_eq!(retried.transaction_id, transaction.transaction_id);
+    assert_eq!(account_0.transactions().await.len(), 1);
+    assert_eq!(
+        account_0
+            .get_transaction_by_idempotency_key("payout-1")
+            .await
+            .map(|transaction| transaction.transaction_id),
+        Some(transaction.transaction_id)
+    );
+    match account_0
+        .prepare_send([SendParams::new(200_000, address_1)?], options("payout-1"))
+        .await
+    {
+        Err(Error::IdempotencyKeyUsed { key, transaction_id }) => {
+            assert_eq!(key, "payout-1");
+            assert_eq!(transaction_id, transaction.transaction_id);
+        }
+        other => panic!("expected IdempotencyKeyUsed, got {other:?}"),
+    }
+
+    // Concurrent calls with the same key only send one transaction
+    let (first, second) = tokio::join!(
+        account_0.send(100_000, address_1, options("payout-2")),
+        account_0.send(100_000, address_1, options("payout-2")),
+    );
+    assert_eq!(first?.transaction_id, second?.transaction_id);
+    assert_eq!(account_0.transactions().await.len(), 2);
+    // The inputs of the transaction which wasn't sent are unlocked again
+    assert_eq!(account_0.details().await.locked_outputs().len(), 2);
+
+    // Another key sends a new transaction
+    account_0.send(200_000, address_1, options("payout-3")).await?;
+    assert_eq!(account_0.transactions().await.len(), 3);
+
+    let params = vec![SendParams::new(100_000, address_1)?];
+    assert!(matches!(
+        account_0.bulk_send(params, options("bulk")).await,
+        Err(Error::CustomInput(_))
+    ));
+
+    let balance = account_1.sync(None).await?;
+    assert_eq!(balance.base_coin().available(), 500_000);
+
+    // Keys of confirmed transactions are removed after the retention window
+    account_0.sync(None).await?;
+    {
+        let mut account_details = account_0.details_mut().await;
+        let mut dto = AccountDetailsDto::from(&*account_details);
+        let eight_days_ago = iota_sdk::utils::unix_timestamp_now().as_millis() - 8 * 24 * 60 * 60 * 1000;
+        dto.transactions.get_mut(&transaction.transaction_id).unwrap().timestamp = eight_days_ago.to_string();
+        *account_details = AccountDetails::try_from_dto(dto)?;
+    }
+    account_0
+        .sync(Some(SyncOptions {
+            force_syncing: true,
+            ..Default::default()
+        }))
+        .await?;
+    assert!(account_0.get_transaction_by_idempotency_key("payout-1").await.is_none());
+    assert!(account_0.get_transaction_by_idempotency_key("payout-2").await.is_some());
+    let new_transaction = account_0.send(200_000, address_1, options("payout-1")).await?;
+    assert_ne!(new_transaction.transaction_id, transaction.transaction_id);
+
+    tear_down(storage_path)
+}
+
+#[cfg(feature = "testing")]
+#[tokio::test]
+async fn mock_transaction_proof() -> Result<()> {
+    use iota_sdk::{
+        client::{
+            utils::{verify_transaction_proof, TransactionProof},
+            Error as ClientError,
+        },
+        types::block::{output::dto::OutputDto, payload::transaction::TransactionId, semantic::ConflictReason},
+    };
+
+    let storage_path = "test-storage/mock_transaction_proof";
+    let (mock_client, wallet) = setup_mock_wallet(storage_path).await?;
+    let (account_0, address_0) = create_account_and_address(&wallet).await?;
+    let (_, address_1) = create_account_and_address(&wallet).await?;
+
+    add_basic_output(&mock_client, address_0, 1_000_000)?;
+    account_0.sync(None).await?;
+    let transaction = account_0.send(200_000, address_1, None).await?;
+
+    // The proof is verified from its JSON, like a third party without access to the wallet would
+    let proof = account_0.export_transaction_proof(&transaction.transaction_id).await?;
+    assert_eq!(proof.milestone_index, mock_client.milestone_index());
+    assert_eq!(proof.milestone_timestamp, mock_client.milestone_timestamp());
+    assert_eq!(Some(proof.block_id), transaction.block_id);
+    let proof = serde_json::from_str::<TransactionProof>(&serde_json::to_string(&proof)?)?;
+    assert_eq!(verify_transaction_proof(&proof)?, transaction.transaction_id);
+
+    // A proof can't be moved to another milestone
+    let mut tampered = proof.clone();
+    tampered.milestone_index -= 1;
+    assert!(matches!(
+        verify_transaction_proof(&tampered),
+        Err(ClientError::InvalidTransactionProof(_))
+    ));
+
+    // Or claim other consumed outputs
+    let mut tampered = proof.clone();
+    if let OutputDto::Basic(output) = &mut tampered.inputs[0].output {
+        output.amount = "2000000".to_string();
+    }
+    assert!(matches!(
+        verify_transaction_proof(&tampered),
+        Err(ClientError::TransactionSemantic(
+            ConflictReason::InputsCommitmentsMismatch
+        ))
+    ));
+
+    assert!(matches!(
+        account_0.export_transaction_proof(&TransactionId::null()).await,
+        Err(Error::TransactionNotFound(_))
+    ));
+
+    tear_down(storage_path)
+}
+
+#[cfg(feature = "testing")]
+#[tokio::test]
+async fn mock_swap() -> Result<()> {
+    use iota_sdk::{
+        types::block::output::{NativeToken, TokenId},
+        wallet::account::{SwapAsset, SwapOffer},
+        U256,
+    };
+
+    let storage_path = "test-storage/mock_swap";
+    let (mock_client, wallet) = setup_mock_wallet(storage_path).await?;
+    let token_supply = mock_client.protocol_parameters().token_supply();
+    let (account_0, address_0) = create_account_and_address(&wallet).await?;
+    let (account_1, address_1) = create_account_and_address(&wallet).await?;
+
+    let token_id = TokenId::new([1; TokenId::LENGTH]);
+    mock_client.add_output(
+        BasicOutputBuilder::new_with_amount(1_000_000)
+            .add_native_token(NativeToken::new(token_id, 100)?)
+            .add_unlock_condition(AddressUnlockCondition::new(address_0))
+            .finish_output(token_supply)?,
+    );
+    add_basic_output(&mock_client, address_1, 2_000_000)?;
+    account_0.sync(None).await?;
+    account_1.sync(None).await?;
+
+    let offer = account_0
+        .create_swap_offer(
+            SwapAsset::NativeToken {
+                token_id,
+                amount: U256::from(40),
+            },
+            SwapAsset::BaseCoin { amount: 500_000 },
+            None,
+        )
+        .await?;
+    // Offers are exchanged out-of-band in their serialized form
+    let received_offer = offer.to_string().parse::<SwapOffer>()?;
+    assert_eq!(received_offer, offer);
+    let acceptance = account_1.accept_swap_offer(&received_offer).await?;
+
+    // The maker only completes the swap for its own offer
+    let mut altered = acceptance.clone();
+    altered.offer.asked = SwapAsset::BaseCoin { amount: 1 };
+    assert!(matches!(
+        account_0.complete_swap(&offer, &altered).await,
+        Err(Error::InvalidSwap(_))
+    ));
+
+    // Both parties ask the approval handler before signing
+    wallet
+        .set_approval_handler(|_, _: &PreparedTransactionData| false)
+        .await;
+    assert!(matches!(
+        account_1.accept_swap_offer(&received_offer).await,
+        Err(Error::TransactionRejected)
+    ));
+    assert!(matches!(
+        account_0.complete_swap(&offer, &acceptance).await,
+        Err(Error::TransactionRejected)
+    ));
+    wallet.clear_approval_handler().await;
+    account_0.complete_swap(&offer, &acceptance).await?;
+
+    let token_balance = |balance: &iota_sdk::wallet::account::types::Balance| {
+        balance
+            .native_tokens()
+            .iter()
+            .find(|native_token| native_token.token_id() == &token_id)
+            .map(|native_token| native_token.total())
+    };
+    let balance_0 = account_0.sync(None).await?;
+    assert_eq!(balance_0.base_coin().total(), 1_500_000);
+    assert_eq!(token_balance(&balance_0), Some(U256::from(60)));
+    let balance_1 = account_1.sync(None).await?;
+    assert_eq!(balance_1.base_coin().total(), 1_500_000);
+    assert_eq!(token_balance(&balance_1), Some(U256::from(40)));
+
+    // A cancelled offer can still be accepted, but isn't completed anymore
+    let offer = account_0
+        .create_swap_offer(
+            SwapAsset::BaseCoin { amount: 100_000 },
+            SwapAsset::NativeToken {
+                token_id,
+                amount: U256::from(10),
+            },
+            None,
+        )
+        .await?;
+    account_0.cancel_swap(&offer).await?;
+    let acceptance = account_1.accept_swap_offer(&offer).await?;
+    assert!(matches!(
+        account_0.complete_swap(&offer, &acceptance).await,
+        Err(Error::InvalidSwap(_))
+    ));
+
+    tear_down(storage_path)
+}
+
+#[cfg(feature = "testing")]
+#[tokio::test]
+async fn mock_vesting() -> Result<()> {
+    use iota_sdk::wallet::account::{VestingRelease, VestingSchedule};
+
+    let storage_path = "test-storage/mock_vesting";
+    let (mock_client, wallet) = setup_mock_wallet(storage_path).await?;
+    let (account_0, address_0) = create_account_and_address(&wallet).await?;
+    let (account_1, address_1) = create_account_and_address(&wallet).await?;
+
+    add_basic_output(&mock_client, address_0, 10_000_000)?;
+    account_0.sync(None).await?;
+
+    assert!(matches!(
+        account_0
+            .create_vesting(address_1, 3_000_000, VestingSchedule::new(0, 0, 2), None)
+            .await,
+        Err(Error::InvalidVestingSchedule(_))
+    ));
+
+    // The first release is already unlocked
+    let now = mock_client.milestone_timestamp();
+    let schedule = VestingSchedule::new(100, 1000, 3).with_start(now - 200);
+    let transaction = account_0.create_vesting(address_1, 3_000_001, schedule, None).await?;
+
+    account_1.sync(None).await?;
+    let status = account_1.vesting_status().await?;
+    assert_eq!(status.len(), 1);
+    let status = &status[0];
+    assert_eq!(status.transaction_id, transaction.transaction_id);
+    assert_eq!(
+        status
+            .releases
+            .iter()
+            .map(
+                |VestingRelease {
+                     amount, unlock_time, ..
+                 }| (*amount, *unlock_time)
+            )
+            .collect::<Vec<_>>(),
+        [(1_000_000, now - 100), (1_000_000, now + 900), (1_000_001, now + 1900)]
+    );
+    assert_eq!(status.released_amount, 1_000_000);
+    assert_eq!(status.locked_amount, 2_000_001);
+    assert_eq!(status.next_release, Some(now + 900));
+
+    tear_down(storage_path)
+}
+
+#[cfg(feature = "testing")]
+#[tokio::test]
+async fn mock_distribute_nfts() -> Result<()> {
+    use iota_sdk::{
+        types::block::output::{feature::IssuerFeature, NftId, NftOutputBuilder},
+        wallet::SendNftParams,
+    };
+
+    let storage_path = "test-storage/mock_distribute_nfts";
+    let (mock_client, wallet) = setup_mock_wallet(storage_path).await?;
+    let protocol_parameters = mock_client.protocol_parameters();
+    let (account_0, address_0) = create_account_and_address(&wallet).await?;
+    let (account_1, address_1) = create_account_and_address(&wallet).await?;
+    let collection_address = Address::Alias(AliasAddress::new(AliasId::new([9; AliasId::LENGTH])));
+
+    let nft_ids = (1..=150u8).map(|i| NftId::new([i; NftId::LENGTH])).collect::<Vec<_>>();
+    for nft_id in &nft_ids {
+        mock_client.add_output(
+            NftOutputBuilder::new_with_minimum_storage_deposit(*protocol_parameters.rent_structure(), *nft_id)
+                .add_unlock_condition(AddressUnlockCondition::new(address_0))
+                .add_immutable_feature(IssuerFeature::new(collection_address))
+                .finish_output(protocol_parameters.token_supply())?,
+        );
+    }
+    account_0.sync(None).await?;
+
+    // Nothing is sent if one of the NFTs isn't owned by the account or sent twice
+    let unknown = SendNftParams::new(address_1, NftId::new([200; NftId::LENGTH]))?;
+    let params = vec![SendNftParams::new(address_1, nft_ids[0])?, unknown];
+    assert!(matches!(
+        account_0.distribute_nfts(params, None).await,
+        Err(Error::NftNotFoundInUnspentOutputs)
+    ));
+    let params = vec![SendNftParams::new(address_1, nft_ids[0])?; 2];
+    assert!(matches!(
+        account_0.distribute_nfts(params, None).await,
+        Err(Error::CustomInput(_))
+    ));
+    assert!(account_0.pending_transactions().await.is_empty());
+
+    // More NFTs than fit into a single transaction
+    let params = nft_ids
+        .iter()
+        .map(|nft_id| SendNftParams::new(address_1, *nft_id))
+        .collect::<Result<Vec<_>>>()?;
+    let report = account_0.distribute_nfts(params, None).await?;
+
+    assert_eq!(report.transaction_ids.len(), 2);
+    assert_eq!(report.recipients.len(), 150);
+    assert!(report.recipients[..127]
+        .iter()
+        .all(|id| id == &report.transaction_ids[0]));
+    assert!(report.recipients[127..]
+        .iter()
+        .all(|id| id == &report.transaction_ids[1]));
+
+    let balance = account_1.sync(None).await?;
+    assert_eq!(balance.nfts().len(), 150);
+    let outputs = account_1.unspent_outputs(None).await?;
+    assert!(outputs.iter().all(|output_data| match &output_data.output {
+        Output::Nft(nft_output) =>
+            nft_output.immutable_features().issuer().map(|issuer| *issuer.address()) == Some(collection_address),
+        _ => false,
+    }));
+
+    tear_down(storage_path)
+}
+
+#[cfg(feature = "testing")]
+#[tokio::test]
+async fn mock_rebuild_conflicting_transaction() -> Result<()> {
+    let storage_path = "test-storage/mock_rebuild_conflicting_transaction";
+    let other_storage_path = "test-storage/mock_rebuild_conflicting_transaction_other";
+    setup(other_storage_path)?;
+    let (mock_client, wallet) = setup_mock_wallet(storage_path).await?;
+    let (account, address) = create_account_and_address(&wallet).await?;
+    add_basic_output(&mock_client, address, 3_000_000)?;
+    account.sync(None).await?;
+
+    // A payment to the own address, which has the same shape as the remainder
+    let self_payment = BasicOutputBuilder::new_with_amount(1_000_000)
+        .add_unlock_condition(AddressUnlockCondition::new(address))
+        .finish_output(mock_client.protocol_parameters().token_supply())?;
+    let prepared_transaction = account.prepare_transaction([self_payment.clone()], None).await?;
+    assert!(prepared_transaction.remainder.is_some());
+
+    // A wallet with the same mnemonic spends the input first
+    let other_wallet = make_mock_wallet(other_storage_path, mock_client.clone()).await?;
+    let (other_account, _) = create_account_and_address(&other_wallet).await?;
+    other_account.sync(None).await?;
+    other_account
+        .send(3_000_000, foreign_address(&mock_client), None)
+        .await?;
+
+    let transaction = account.sign_and_submit_transaction(prepared_transaction, None).await?;
+    add_basic_output(&mock_client, address, 2_500_000)?;
+    account.sync(None).await?;
+    assert_eq!(
+        account
+            .get_transaction(&transaction.transaction_id)
+            .await
+            .map(|transaction| transaction.inclusion_state),
+        Some(InclusionState::Conflicting)
+    );
+
+    // Only the remainder is left out and created again, the payment to the own address is kept
+    let rebuilt = account
+        .rebuild_conflicting_transaction(&transaction.transaction_id)
+        .await?;
+    let TransactionEssence::Regular(essence) = &rebuilt.essence;
+    assert_eq!(essence.outputs().len(), 2);
+    assert!(essence.outputs().contains(&self_payment));
+    assert_eq!(
+        rebuilt.remainder.map(|remainder| remainder.output.amount()),
+        Some(1_500_000)
+    );
+
+    tear_down(other_storage_path)?;
+    tear_down(storage_path)
+}
+
+#[cfg(feature = "testing")]
+#[tokio::test]
+async fn mock_concurrent_transactions() -> Result<()> {
+    let storage_path = "test-storage/mock_concurrent_transactions";
+    let (mock_client, wallet) = setup_mock_wallet(storage_path).await?;
+    let (account, address) = create_account_and_address(&wallet).await?;
+    for _ in 0..3 {
+        add_basic_output(&mock_client, address, 1_000_000)?;
+    }
+    account.sync(None).await?;
+
+    // Send multiple transactions at the same time without syncing in between
+    let foreign_address = foreign_address(&mock_client);
+    let results = futures::future::join_all((0..3).map(|_| account.send(1_000_000, foreign_address, None))).await;
+
+    // Every transaction could be created and they don't use the same inputs
+    let mut inputs = std::collections::HashSet::new();
+    for transaction in results {
+        for input in &transaction?.inputs {
+            assert!(inputs.insert(*input.metadata.output_id()));
+        }
+    }
+    assert_eq!(inputs.len(), 3);
+
+    tear_down(storage_path)
+}
+
+#[cfg(all(feature = "events", feature = "testing"))]
+#[tokio::test]
+async fn mock_inclusion_monitor() -> Result<()> {
+    use std::time::Duration;
+
+    use iota_sdk::wallet::{
+        account::types::TransactionDto,
+        core::InclusionMonitorOptions,
+        events::{
+            types::{TransactionReissueEvent, TransactionReissueState, WalletEvent},
+            WalletEventType,
+        },
+    };
+
+    let storage_path = "test-storage/mock_inclusion_monitor";
+    let other_storage_path = "test-storage/mock_inclusion_monitor_other";
+    setup(other_storage_path)?;
+    let (mock_client, wallet) = setup_mock_wallet(storage_path).await?;
+    let (account, address) = create_account_and_address(&wallet).await?;
+    add_basic_output(&mock_client, address, 1_000_000)?;
+    account.sync(None).await?;
+
+    // A transaction which was sent to another node, so this one doesn't know its block
+    let other_mock_client = MockClient::default();
+    add_basic_output(&other_mock_client, address, 1_000_000)?;
+    let other_wallet = make_mock_wallet(other_storage_path, other_mock_client.clone()).await?;
+    let (other_account, _) = create_account_and_address(&other_wallet).await?;
+    other_account.sync(None).await?;
+    let transaction = other_account
+        .send(200_000, foreign_address(&other_mock_client), None)
+        .await?;
+    {
+        let mut account_details = account.details_mut().await;
+        let mut dto = AccountDetailsDto::from(&*account_details);
+        dto.transactions
+            .insert(transaction.transaction_id, TransactionDto::from(&transaction));
+        dto.pending_transactions.insert(transaction.transaction_id);
+        *account_details = AccountDetails::try_from_dto(dto)?;
+    }
+
+    let (sender, mut receiver) = tokio::sync::mpsc::unbounded_channel();
+    wallet
+        .listen([WalletEventType::TransactionReissue], move |event| {
+            if let WalletEvent::TransactionReissue(event) = &event.event {
+                sender.send(*event).ok();
+            }
+        })
+        .await;
+
+    // Without reissues, the transaction is given up right away
+    wallet
+        .start_inclusion_monitor(Some(InclusionMonitorOptions {
+            timeout: Duration::ZERO,
+            max_reissues: 0,
+            ..Default::default()
+        }))
+        .await?;
+    let event = tokio::time::timeout(Duration::from_secs(10), receiver.recv())
+        .await
+        .unwrap();
+    assert_eq!(
+        event,
+        Some(TransactionReissueEvent {
+            transaction_id: transaction.transaction_id,
+            reissue_state: TransactionReissueState::GivenUp,
+        })
+    );
+    wallet.stop_inclusion_monitor().await?;
+    assert!(mock_client.block(&transaction.block_id.unwrap()).is_none());
+
+    // Otherwise it's reissued in a new block, which gets included
+    wallet
+        .start_inclusion_monitor(Some(InclusionMonitorOptions {
+            timeout: Duration::ZERO,
+            max_reissues: 1,
+            ..Default::default()
+        }))
+        .await?;
+    let event = tokio::time::timeout(Duration::from_secs(10), receiver.recv())
+        .await
+        .unwrap()
+        .unwrap();
+    wallet.stop_inclusion_monitor().await?;
+    let TransactionReissueState::Reissued { block_id, attempt } = event.reissue_state else {
+        panic!("expected a reissue, got {event:?}");
+    };
+    assert_eq!(attempt, 1);
+    assert_eq!(
+        account
+            .get_transaction(&transaction.transaction_id)
+            .await
+            .and_then(|transaction| transaction.block_id),
+        Some(block_id)
+    );
+    let (_, metadata) = mock_client.block(&block_id).unwrap();
+    assert_eq!(metadata.ledger_inclusion_state, Some(LedgerInclusionState::Included));
+
+    tear_down(other_storage_path)?;
+    tear_down(storage_path)
+}